@@ -30,7 +30,17 @@ use super::Recover;
 pub use entry::{Entry, OccupiedEntry, OccupiedError, VacantEntry};
 mod entry;
 
-pub(crate) type CmpFn<C, Q, E> = fn(&mut C, &Q, &Q) -> Result<Ordering, E>;
+/// A fallible comparison function used by the `*_with` family of methods on
+/// [`BTreeMap`].
+///
+/// The function receives a mutable reference to a caller-provided context
+/// `C`, which is threaded through every comparison performed during a single
+/// operation. If the comparison returns an error, the operation is aborted and
+/// the error is returned to the caller with the map left unmodified.
+///
+/// The comparison must implement a total order which is consistent across
+/// all keys stored in the map, just like [`Ord`] would.
+pub type CmpFn<C, Q, E> = fn(&mut C, &Q, &Q) -> Result<Ordering, E>;
 
 use Entry::*;
 
@@ -872,7 +882,35 @@ impl<K, V, A: Allocator> BTreeMap<K, V, A> {
         into_ok(self.get_with(&mut (), key, infallible_cmp))
     }
 
-    pub(crate) fn get_with<C, Q, E>(
+    /// Returns a reference to the value corresponding to the key, using a
+    /// fallible comparison function with a custom context.
+    ///
+    /// This is the same as [`BTreeMap::get`], except that keys are compared
+    /// using `cmp` instead of [`Ord`]. The first error produced by `cmp` is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::cmp::Ordering;
+    ///
+    /// use rune::alloc::BTreeMap;
+    ///
+    /// fn cmp(count: &mut usize, a: &u32, b: &u32) -> Result<Ordering, ()> {
+    ///     *count += 1;
+    ///     Ok(a.cmp(b))
+    /// }
+    ///
+    /// let mut map = BTreeMap::new();
+    /// map.try_insert(1, "a")?;
+    ///
+    /// let mut count = 0;
+    /// assert_eq!(map.get_with(&mut count, &1, cmp), Ok(Some(&"a")));
+    /// assert_eq!(map.get_with(&mut count, &2, cmp), Ok(None));
+    /// assert!(count > 0);
+    /// # Ok::<_, rune::alloc::Error>(())
+    /// ```
+    pub fn get_with<C, Q, E>(
         &self,
         cx: &mut C,
         key: &Q,
@@ -1115,7 +1153,35 @@ impl<K, V, A: Allocator> BTreeMap<K, V, A> {
         into_ok(self.contains_key_with(&mut (), key, infallible_cmp))
     }
 
-    pub(crate) fn contains_key_with<C, Q, E>(
+    /// Returns `true` if the map contains a value for the specified key, using
+    /// a fallible comparison function with a custom context.
+    ///
+    /// See [`BTreeMap::get_with`] for how `cx` and `cmp` are used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::cmp::Ordering;
+    ///
+    /// use rune::alloc::BTreeMap;
+    ///
+    /// fn cmp(_: &mut (), a: &u32, b: &u32) -> Result<Ordering, &'static str> {
+    ///     if *a == 0 || *b == 0 {
+    ///         return Err("zero is not comparable");
+    ///     }
+    ///
+    ///     Ok(a.cmp(b))
+    /// }
+    ///
+    /// let mut map = BTreeMap::new();
+    /// map.try_insert(1, "a")?;
+    ///
+    /// assert_eq!(map.contains_key_with(&mut (), &1, cmp), Ok(true));
+    /// assert_eq!(map.contains_key_with(&mut (), &2, cmp), Ok(false));
+    /// assert_eq!(map.contains_key_with(&mut (), &0, cmp), Err("zero is not comparable"));
+    /// # Ok::<_, rune::alloc::Error>(())
+    /// ```
+    pub fn contains_key_with<C, Q, E>(
         &self,
         cx: &mut C,
         key: &Q,
@@ -1123,8 +1189,8 @@ impl<K, V, A: Allocator> BTreeMap<K, V, A> {
     ) -> Result<bool, E>
     where
         C: ?Sized,
-        Q: ?Sized + Ord,
-        K: Borrow<Q> + Ord,
+        Q: ?Sized,
+        K: Borrow<Q>,
     {
         Ok(self.get_with(cx, key, cmp)?.is_some())
     }
@@ -1336,7 +1402,32 @@ impl<K, V, A: Allocator> BTreeMap<K, V, A> {
         into_ok(self.remove_entry_with(&mut (), key, infallible_cmp))
     }
 
-    pub(crate) fn remove_entry_with<C: ?Sized, Q: ?Sized, E>(
+    /// Removes a key from the map, returning the stored key and value if the
+    /// key was previously in the map, using a fallible comparison function with
+    /// a custom context.
+    ///
+    /// See [`BTreeMap::get_with`] for how `cx` and `cmp` are used. If `cmp`
+    /// errors, the map is left unmodified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::cmp::Ordering;
+    ///
+    /// use rune::alloc::BTreeMap;
+    ///
+    /// fn cmp(_: &mut (), a: &u32, b: &u32) -> Result<Ordering, ()> {
+    ///     Ok(a.cmp(b))
+    /// }
+    ///
+    /// let mut map = BTreeMap::new();
+    /// map.try_insert(1, "a")?;
+    ///
+    /// assert_eq!(map.remove_entry_with(&mut (), &1, cmp), Ok(Some((1, "a"))));
+    /// assert_eq!(map.remove_entry_with(&mut (), &1, cmp), Ok(None));
+    /// # Ok::<_, rune::alloc::Error>(())
+    /// ```
+    pub fn remove_entry_with<C: ?Sized, Q: ?Sized, E>(
         &mut self,
         cx: &mut C,
         key: &Q,
@@ -1499,7 +1590,40 @@ impl<K, V, A: Allocator> BTreeMap<K, V, A> {
         into_ok(self.range_with(&mut (), range, infallible_cmp))
     }
 
-    pub(crate) fn range_with<C, Q, R, E>(
+    /// Constructs a double-ended iterator over a sub-range of elements in the
+    /// map, using a fallible comparison function with a custom context.
+    ///
+    /// See [`BTreeMap::get_with`] for how `cx` and `cmp` are used. Comparisons
+    /// are only performed while locating the bounds of the range, so the
+    /// returned iterator itself is infallible.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`BTreeMap::range`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::cmp::Ordering;
+    ///
+    /// use rune::alloc::BTreeMap;
+    ///
+    /// fn cmp(_: &mut (), a: &u32, b: &u32) -> Result<Ordering, ()> {
+    ///     Ok(a.cmp(b))
+    /// }
+    ///
+    /// let mut map = BTreeMap::new();
+    /// map.try_insert(3, "a")?;
+    /// map.try_insert(5, "b")?;
+    /// map.try_insert(8, "c")?;
+    ///
+    /// let mut range = map.range_with(&mut (), 4.., cmp).unwrap();
+    /// assert_eq!(range.next(), Some((&5, &"b")));
+    /// assert_eq!(range.next(), Some((&8, &"c")));
+    /// assert_eq!(range.next(), None);
+    /// # Ok::<_, rune::alloc::Error>(())
+    /// ```
+    pub fn range_with<C, Q, R, E>(
         &self,
         cx: &mut C,
         range: R,
@@ -1614,7 +1738,38 @@ impl<K, V, A: Allocator> BTreeMap<K, V, A> {
         into_ok(self.entry_with(&mut (), key, infallible_cmp))
     }
 
-    pub(crate) fn entry_with<C: ?Sized, E>(
+    /// Gets the given key's corresponding entry in the map for in-place
+    /// manipulation, using a fallible comparison function with a custom
+    /// context.
+    ///
+    /// See [`BTreeMap::get_with`] for how `cx` and `cmp` are used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::cmp::Ordering;
+    ///
+    /// use rune::alloc::BTreeMap;
+    ///
+    /// fn cmp(_: &mut (), a: &&str, b: &&str) -> Result<Ordering, ()> {
+    ///     Ok(a.cmp(b))
+    /// }
+    ///
+    /// let mut count: BTreeMap<&str, usize> = BTreeMap::new();
+    ///
+    /// for x in ["a", "b", "a"] {
+    ///     count
+    ///         .entry_with(&mut (), x, cmp)
+    ///         .unwrap()
+    ///         .and_modify(|curr| *curr += 1)
+    ///         .or_try_insert(1)?;
+    /// }
+    ///
+    /// assert_eq!(count["a"], 2);
+    /// assert_eq!(count["b"], 1);
+    /// # Ok::<_, rune::alloc::Error>(())
+    /// ```
+    pub fn entry_with<C: ?Sized, E>(
         &mut self,
         cx: &mut C,
         key: K,
@@ -369,6 +369,13 @@ define! {
         /// Allows the `>>=` operator to apply to values of this type, where the current type is the left-hand side.
     };
 
+    /// The function to implement for the negation operation.
+    pub const NEG: Protocol = Protocol {
+        hash: 0x7f271497d234de26u64,
+        repr: "let $out = -$value",
+        /// Allows the `-` prefix operator to apply to values of this type.
+    };
+
     /// Protocol function used by template strings.
     pub const DISPLAY_FMT: Protocol = Protocol {
         hash: 0x811b62957ea9d9f9u64,
@@ -225,6 +225,12 @@ pub(crate) fn expand_install_with(
         }
     }
 
+    if let Some(span) = attr.serde {
+        installers.push(quote_spanned! { span =>
+            module.serde::<Self>()?;
+        });
+    }
+
     if let Some(install_with) = &attr.install_with {
         installers.push(quote_spanned! { input.span() =>
             #install_with(module)?;
@@ -87,6 +87,8 @@ pub(crate) struct TypeAttr {
     pub(crate) item: Option<syn::Path>,
     /// `#[rune(constructor)]`.
     pub(crate) constructor: Option<Span>,
+    /// `#[rune(serde)]`.
+    pub(crate) serde: Option<Span>,
     /// Parsed documentation.
     pub(crate) docs: Vec<syn::Expr>,
     /// Method to use to convert from value.
@@ -659,6 +661,11 @@ impl Context {
                     return Ok(());
                 }
 
+                if meta.path.is_ident("serde") {
+                    attr.serde = Some(meta.path.span());
+                    return Ok(());
+                }
+
                 if meta.path.is_ident("impl_params") {
                     meta.input.parse::<Token![=]>()?;
                     let content;
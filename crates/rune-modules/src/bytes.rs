@@ -0,0 +1,15 @@
+//! Helpers for native functions which accept byte-like values.
+
+use rune::runtime::{Bytes, Value, VmError, VmResult};
+
+/// Coerce a string or bytes into the bytes to write.
+pub(crate) fn to_bytes(value: &Value) -> VmResult<Vec<u8>> {
+    if let Ok(string) = value.borrow_string_ref() {
+        return VmResult::Ok(string.as_bytes().to_vec());
+    }
+
+    match value.borrow_ref::<Bytes>() {
+        Ok(bytes) => VmResult::Ok(bytes.as_slice().to_vec()),
+        Err(..) => VmResult::Err(VmError::expected::<String>(value.type_info())),
+    }
+}
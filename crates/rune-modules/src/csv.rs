@@ -0,0 +1,570 @@
+//! The native `csv` module for the [Rune Language].
+//!
+//! [Rune Language]: https://rune-rs.github.io
+//!
+//! ## Usage
+//!
+//! Add the following to your `Cargo.toml`:
+//!
+//! ```toml
+//! rune-modules = { version = "0.14.0", features = ["csv"] }
+//! ```
+//!
+//! Install it into your context:
+//!
+//! ```rust
+//! let mut context = rune::Context::with_default_modules()?;
+//! context.install(rune_modules::csv::module(true)?)?;
+//! # Ok::<_, rune::support::Error>(())
+//! ```
+//!
+//! Use it in Rune:
+//!
+//! ```rust,ignore
+//! use csv::Reader;
+//!
+//! fn main() {
+//!     for record in Reader::from_string("name,age\nBob,42\n")? {
+//!         dbg(record?);
+//!     }
+//! }
+//! ```
+
+use core::fmt;
+use std::collections::HashMap;
+use std::io::Cursor;
+
+use rune::alloc::fmt::TryWrite;
+use rune::alloc::{String, Vec};
+use rune::runtime::{self, Bytes, Formatter, Object, RuntimeError, Type, Value, VmResult};
+use rune::{item, vm_write, Any, ContextError, Module, TypeHash};
+
+#[rune::module(::csv)]
+/// Module for reading and writing CSV.
+///
+/// # Examples
+///
+/// ```rune
+/// use csv::{Reader, Writer};
+///
+/// let writer = Writer::with_headers(["name", "age"])?;
+/// writer.write(#{"name": "Bob", "age": 42})?;
+///
+/// let reader = Reader::from_string(writer.into_string()?)?;
+/// reader.set_type("age", i64)?;
+///
+/// assert_eq!(reader.next(), Some(Ok(#{"name": "Bob", "age": 42})));
+/// ```
+pub fn module(_stdio: bool) -> Result<Module, ContextError> {
+    let mut module = Module::from_meta(self::module_meta)?;
+    module.ty::<Error>()?;
+    module.function_meta(Error::display__meta)?;
+    module.function_meta(Error::debug__meta)?;
+
+    module.ty::<Reader>()?;
+    module.function_meta(Reader::from_string__meta)?;
+    module.function_meta(Reader::from_bytes__meta)?;
+    module.function_meta(Reader::headers__meta)?;
+    module.function_meta(Reader::set_type__meta)?;
+    module.function_meta(Reader::next__meta)?;
+    module.implement_trait::<Reader>(item!(::std::iter::Iterator))?;
+
+    module.ty::<Writer>()?;
+    module.function_meta(Writer::new__meta)?;
+    module.function_meta(Writer::with_headers__meta)?;
+    module.function_meta(Writer::write__meta)?;
+    module.function_meta(Writer::write_record__meta)?;
+    module.function_meta(Writer::into_string__meta)?;
+    module.function_meta(Writer::into_bytes__meta)?;
+    Ok(module)
+}
+
+#[derive(Any)]
+#[rune(item = ::csv)]
+/// Error type raised when reading or writing CSV.
+struct Error {
+    kind: ErrorKind,
+}
+
+enum ErrorKind {
+    Csv(csv::Error),
+    Message(::std::string::String),
+}
+
+impl Error {
+    fn message(message: impl fmt::Display) -> Self {
+        Self {
+            kind: ErrorKind::Message(message.to_string()),
+        }
+    }
+
+    #[rune::function(keep, protocol = DISPLAY_FMT)]
+    pub(crate) fn display(&self, f: &mut Formatter) -> VmResult<()> {
+        match &self.kind {
+            ErrorKind::Csv(error) => vm_write!(f, "{error}"),
+            ErrorKind::Message(message) => vm_write!(f, "{message}"),
+        }
+    }
+
+    #[rune::function(keep, protocol = DEBUG_FMT)]
+    pub(crate) fn debug(&self, f: &mut Formatter) -> VmResult<()> {
+        match &self.kind {
+            ErrorKind::Csv(error) => vm_write!(f, "{error:?}"),
+            ErrorKind::Message(message) => vm_write!(f, "Message({message:?})"),
+        }
+    }
+}
+
+impl From<csv::Error> for Error {
+    fn from(error: csv::Error) -> Self {
+        Self {
+            kind: ErrorKind::Csv(error),
+        }
+    }
+}
+
+/// The type a column is parsed as.
+#[derive(Clone, Copy)]
+enum Column {
+    Signed,
+    Unsigned,
+    Float,
+    Bool,
+    String,
+}
+
+impl Column {
+    fn name(self) -> &'static str {
+        match self {
+            Column::Signed => "i64",
+            Column::Unsigned => "u64",
+            Column::Float => "f64",
+            Column::Bool => "bool",
+            Column::String => "String",
+        }
+    }
+
+    /// Parse a field, returning `None` if it's not valid for the column.
+    fn parse(self, field: &str) -> Result<Option<Value>, RuntimeError> {
+        let value = match self {
+            Column::Signed => field.parse::<i64>().ok().map(rune::to_value),
+            Column::Unsigned => field.parse::<u64>().ok().map(rune::to_value),
+            Column::Float => field.parse::<f64>().ok().map(rune::to_value),
+            Column::Bool => field.parse::<bool>().ok().map(rune::to_value),
+            Column::String => Some(rune::to_value(String::try_from(field)?)),
+        };
+
+        value.transpose()
+    }
+}
+
+/// A header-aware CSV reader, which yields each record as an object keyed by
+/// the names in the header row.
+///
+/// Fields are read as strings unless a type has been set for their column
+/// using [`Reader::set_type`].
+///
+/// # Examples
+///
+/// ```rune
+/// use csv::Reader;
+///
+/// let reader = Reader::from_string("name,role\nBob,admin\nAlice,user\n")?;
+/// let names = [];
+///
+/// for record in reader {
+///     names.push(record?.name);
+/// }
+///
+/// assert_eq!(names, ["Bob", "Alice"]);
+/// ```
+#[derive(Any)]
+#[rune(item = ::csv)]
+struct Reader {
+    reader: csv::Reader<Cursor<::std::vec::Vec<u8>>>,
+    headers: csv::StringRecord,
+    types: HashMap<::std::string::String, Column>,
+    record: csv::StringRecord,
+}
+
+impl Reader {
+    /// Construct a reader over the given string, reading the header row.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the header row can't be read.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use csv::Reader;
+    ///
+    /// let reader = Reader::from_string("a,b\n1,2\n")?;
+    /// assert_eq!(reader.next(), Some(Ok(#{"a": "1", "b": "2"})));
+    /// assert_eq!(reader.next(), None);
+    /// ```
+    #[rune::function(keep, path = Self::from_string)]
+    fn from_string(string: &str) -> Result<Self, Error> {
+        Self::new(string.as_bytes())
+    }
+
+    /// Construct a reader over the given bytes, reading the header row.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the header row can't be read.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use csv::Reader;
+    ///
+    /// let reader = Reader::from_bytes(b"a,b\r\n1,2\r\n")?;
+    /// assert_eq!(reader.next(), Some(Ok(#{"a": "1", "b": "2"})));
+    /// ```
+    #[rune::function(keep, path = Self::from_bytes)]
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Self::new(bytes)
+    }
+
+    fn new(bytes: &[u8]) -> Result<Self, Error> {
+        let mut reader = csv::Reader::from_reader(Cursor::new(bytes.to_vec()));
+        let headers = reader.headers()?.clone();
+
+        Ok(Self {
+            reader,
+            headers,
+            types: HashMap::new(),
+            record: csv::StringRecord::new(),
+        })
+    }
+
+    /// Get the names of the columns in the header row.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use csv::Reader;
+    ///
+    /// let reader = Reader::from_string("name,age\n")?;
+    /// assert_eq!(reader.headers(), ["name", "age"]);
+    /// ```
+    #[rune::function(keep, vm_result)]
+    fn headers(&self) -> Vec<String> {
+        let mut headers = Vec::new();
+
+        for header in &self.headers {
+            headers.try_push(String::try_from(header).vm?).vm?;
+        }
+
+        headers
+    }
+
+    /// Set the type the given column is parsed as, which is one of `i64`,
+    /// `u64`, `f64`, `bool` or `String`.
+    ///
+    /// Reading a record errors if a field can't be parsed as the type of its
+    /// column, which includes empty fields in columns which are not strings.
+    ///
+    /// # Errors
+    ///
+    /// Errors if there is no column with the given name or if the type isn't
+    /// supported.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use csv::Reader;
+    ///
+    /// let reader = Reader::from_string("name,age,score,active\nBob,42,9.5,true\nAlice,x,1.0,false\n")?;
+    /// reader.set_type("age", i64)?;
+    /// reader.set_type("score", f64)?;
+    /// reader.set_type("active", bool)?;
+    ///
+    /// assert_eq!(reader.next(), Some(Ok(#{"name": "Bob", "age": 42, "score": 9.5, "active": true})));
+    /// assert!(reader.next().unwrap().is_err());
+    ///
+    /// assert!(reader.set_type("missing", i64).is_err());
+    /// assert!(reader.set_type("age", char).is_err());
+    /// ```
+    #[rune::function(keep)]
+    fn set_type(&mut self, column: &str, ty: Type) -> Result<(), Error> {
+        if !self.headers.iter().any(|header| header == column) {
+            return Err(Error::message(format_args!("No column named `{column}`")));
+        }
+
+        let parsed = match ty.into_hash() {
+            i64::HASH => Column::Signed,
+            u64::HASH => Column::Unsigned,
+            f64::HASH => Column::Float,
+            bool::HASH => Column::Bool,
+            String::HASH => Column::String,
+            _ => {
+                return Err(Error::message(format_args!(
+                    "Unsupported type for column `{column}`, expected one of i64, u64, f64, bool or String"
+                )));
+            }
+        };
+
+        self.types.insert(column.to_owned(), parsed);
+        Ok(())
+    }
+
+    /// Read the next record.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use csv::Reader;
+    ///
+    /// let reader = Reader::from_string("a,b\n1,2\n3\n")?;
+    /// assert_eq!(reader.next(), Some(Ok(#{"a": "1", "b": "2"})));
+    /// assert!(reader.next().unwrap().is_err());
+    /// ```
+    #[rune::function(keep, protocol = NEXT, vm_result)]
+    fn next(&mut self) -> Option<Result<Object, Error>> {
+        match self.reader.read_record(&mut self.record) {
+            Ok(true) => {}
+            Ok(false) => return None,
+            Err(error) => return Some(Err(Error::from(error))),
+        }
+
+        let line = self.record.position().map(|p| p.line()).unwrap_or_default();
+        let mut object = Object::new();
+
+        for (header, field) in self.headers.iter().zip(&self.record) {
+            let value = match self.types.get(header) {
+                Some(column) => match column.parse(field).vm? {
+                    Some(value) => value,
+                    None => {
+                        return Some(Err(Error::message(format_args!(
+                            "Invalid value `{field}` for column `{header}` on line {line}, expected {}",
+                            column.name()
+                        ))));
+                    }
+                },
+                None => rune::to_value(String::try_from(field).vm?).vm?,
+            };
+
+            object.insert(String::try_from(header).vm?, value).vm?;
+        }
+
+        Some(Ok(object))
+    }
+}
+
+/// A CSV writer.
+///
+/// # Examples
+///
+/// ```rune
+/// use csv::Writer;
+///
+/// let writer = Writer::new();
+/// writer.write_record(["name", "note"])?;
+/// writer.write_record(["Bob", "says \"hi\", twice"])?;
+///
+/// assert_eq!(writer.into_string()?, "name,note\nBob,\"says \"\"hi\"\", twice\"\n");
+/// ```
+#[derive(Any)]
+#[rune(item = ::csv)]
+struct Writer {
+    writer: csv::Writer<::std::vec::Vec<u8>>,
+    headers: Option<::std::vec::Vec<::std::string::String>>,
+}
+
+impl Writer {
+    /// Construct a new writer without a header row.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use csv::Writer;
+    ///
+    /// let writer = Writer::new();
+    /// writer.write_record([1, 2.5, true])?;
+    /// assert_eq!(writer.into_string()?, "1,2.5,true\n");
+    /// ```
+    #[rune::function(keep, path = Self::new)]
+    fn new() -> Self {
+        Self {
+            writer: csv::Writer::from_writer(::std::vec::Vec::new()),
+            headers: None,
+        }
+    }
+
+    /// Construct a new writer and write the given header row, which
+    /// determines how objects passed to [`Writer::write`] are written.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use csv::Writer;
+    ///
+    /// let writer = Writer::with_headers(["a", "b"])?;
+    /// assert_eq!(writer.into_string()?, "a,b\n");
+    /// ```
+    #[rune::function(keep, path = Self::with_headers)]
+    fn with_headers(headers: &runtime::Vec) -> Result<Self, Error> {
+        let mut writer = Self::new();
+        let headers = cells(headers)?;
+        writer.writer.write_record(&headers)?;
+        writer.headers = Some(headers);
+        Ok(writer)
+    }
+
+    /// Write an object as a record, with one field for each header in the
+    /// order of the header row.
+    ///
+    /// Fields missing from the object are written as empty, while fields
+    /// which are not in the header row are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the writer has no header row.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use csv::Writer;
+    ///
+    /// let writer = Writer::with_headers(["name", "age"])?;
+    /// writer.write(#{"age": 42, "name": "Bob"})?;
+    /// writer.write(#{"name": "Alice"})?;
+    ///
+    /// assert_eq!(writer.into_string()?, "name,age\nBob,42\nAlice,\n");
+    ///
+    /// let writer = Writer::new();
+    /// assert!(writer.write(#{"name": "Bob"}).is_err());
+    /// ```
+    #[rune::function(keep)]
+    fn write(&mut self, record: &Object) -> Result<(), Error> {
+        let Some(headers) = &self.headers else {
+            return Err(Error::message(
+                "Writer has no header row, use `write_record` instead",
+            ));
+        };
+
+        let mut fields = ::std::vec::Vec::with_capacity(headers.len());
+
+        for header in headers {
+            fields.push(match record.get(header.as_str()) {
+                Some(value) => cell(value)?,
+                None => ::std::string::String::new(),
+            });
+        }
+
+        self.writer.write_record(&fields)?;
+        Ok(())
+    }
+
+    /// Write a vector of values as a record.
+    ///
+    /// Strings, numbers, booleans and characters are written using their
+    /// display representation, and the unit value is written as an empty
+    /// field.
+    ///
+    /// # Errors
+    ///
+    /// Errors if a value can't be written as a field.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use csv::Writer;
+    ///
+    /// let writer = Writer::new();
+    /// writer.write_record(["a", 'b', (), -1])?;
+    /// assert!(writer.write_record([[1, 2]]).is_err());
+    ///
+    /// assert_eq!(writer.into_string()?, "a,b,,-1\n");
+    /// ```
+    #[rune::function(keep)]
+    fn write_record(&mut self, record: &runtime::Vec) -> Result<(), Error> {
+        self.writer.write_record(&cells(record)?)?;
+        Ok(())
+    }
+
+    /// Consume the writer and return the written CSV as a string.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use csv::Writer;
+    ///
+    /// let writer = Writer::new();
+    /// writer.write_record(["a"])?;
+    /// assert_eq!(writer.into_string()?, "a\n");
+    /// ```
+    #[rune::function(keep, vm_result)]
+    fn into_string(self) -> Result<String, Error> {
+        let bytes = match self.writer.into_inner() {
+            Ok(bytes) => bytes,
+            Err(error) => return Err(Error::message(error.error())),
+        };
+
+        // NB: All fields are written from strings, so this can't fail.
+        let string = ::std::string::String::from_utf8(bytes).map_err(Error::message)?;
+        Ok(String::try_from(string).vm?)
+    }
+
+    /// Consume the writer and return the written CSV as bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use csv::Writer;
+    ///
+    /// let writer = Writer::new();
+    /// writer.write_record(["a"])?;
+    /// assert_eq!(writer.into_bytes()?, b"a\n");
+    /// ```
+    #[rune::function(keep, vm_result)]
+    fn into_bytes(self) -> Result<Bytes, Error> {
+        let bytes = match self.writer.into_inner() {
+            Ok(bytes) => bytes,
+            Err(error) => return Err(Error::message(error.error())),
+        };
+
+        Ok(Bytes::from_vec(Vec::try_from(bytes).vm?))
+    }
+}
+
+/// Convert a vector of values into fields.
+fn cells(values: &runtime::Vec) -> Result<::std::vec::Vec<::std::string::String>, Error> {
+    let mut fields = ::std::vec::Vec::with_capacity(values.len());
+
+    for value in values.iter() {
+        fields.push(cell(value)?);
+    }
+
+    Ok(fields)
+}
+
+/// Convert a single value into a field.
+fn cell(value: &Value) -> Result<::std::string::String, Error> {
+    fn convert<T>(value: &Value) -> Result<T, Error>
+    where
+        T: rune::FromValue,
+    {
+        rune::from_value(value.clone()).map_err(Error::message)
+    }
+
+    let field = match value.type_hash() {
+        String::HASH => convert::<String>(value)?.to_string(),
+        i64::HASH => convert::<i64>(value)?.to_string(),
+        u64::HASH => convert::<u64>(value)?.to_string(),
+        f64::HASH => convert::<f64>(value)?.to_string(),
+        bool::HASH => convert::<bool>(value)?.to_string(),
+        char::HASH => convert::<char>(value)?.to_string(),
+        _ if value.into_unit().is_ok() => ::std::string::String::new(),
+        _ => {
+            return Err(Error::message(format_args!(
+                "Cannot write value of type {} as a CSV field",
+                value.type_info()
+            )));
+        }
+    };
+
+    Ok(field)
+}
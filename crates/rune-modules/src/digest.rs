@@ -0,0 +1,527 @@
+//! The native `digest` module for the [Rune Language].
+//!
+//! [Rune Language]: https://rune-rs.github.io
+//!
+//! ## Usage
+//!
+//! Add the following to your `Cargo.toml`:
+//!
+//! ```toml
+//! rune-modules = { version = "0.14.0", features = ["digest"] }
+//! ```
+//!
+//! Install it into your context:
+//!
+//! ```rust
+//! let mut context = rune::Context::with_default_modules()?;
+//! context.install(rune_modules::digest::module(true)?)?;
+//! # Ok::<_, rune::support::Error>(())
+//! ```
+//!
+//! Use it in Rune:
+//!
+//! ```rust,ignore
+//! fn main() {
+//!     let hash = digest::sha256("Hello World");
+//!     dbg(digest::to_hex(hash));
+//! }
+//! ```
+
+use core::fmt;
+use core::hash::Hasher as _;
+
+use hmac::{Hmac, Mac};
+use sha2::Digest;
+use subtle::ConstantTimeEq;
+
+use rune::alloc::fmt::TryWrite;
+use rune::alloc::String;
+use rune::runtime::{Bytes, Formatter, Value, VmResult};
+use rune::{vm_try, vm_write, Any, ContextError, Module};
+
+use crate::hex::{self, with_data};
+
+#[rune::module(::digest)]
+/// Checksums, cryptographic hashes and message authentication codes.
+///
+/// All functions accept either `Bytes` or a `String` as input, where strings
+/// are hashed as their UTF-8 encoding.
+///
+/// # Examples
+///
+/// Verifying the signature of a webhook:
+///
+/// ```rune
+/// use digest::Hasher;
+///
+/// let secret = "It's a Secret to Everybody";
+/// let body = "Hello, World!";
+/// let signature = "757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17";
+///
+/// let mac = Hasher::hmac("sha256", secret)?;
+/// mac.update(body);
+/// assert!(mac.verify(digest::from_hex(signature)?));
+/// ```
+pub fn module(_stdio: bool) -> Result<Module, ContextError> {
+    let mut module = Module::from_meta(self::module_meta)?;
+    module.ty::<Error>()?;
+    module.function_meta(Error::display__meta)?;
+    module.function_meta(Error::debug__meta)?;
+
+    module.function_meta(sha1)?;
+    module.function_meta(sha256)?;
+    module.function_meta(sha512)?;
+    module.function_meta(blake3)?;
+    module.function_meta(crc32)?;
+    module.function_meta(xxh64)?;
+    module.function_meta(xxh3)?;
+    module.function_meta(hmac)?;
+    module.function_meta(to_hex)?;
+    module.function_meta(from_hex)?;
+    module.function_meta(constant_time_eq)?;
+
+    module.ty::<Hasher>()?;
+    module.function_meta(Hasher::new__meta)?;
+    module.function_meta(Hasher::hmac__meta)?;
+    module.function_meta(Hasher::update__meta)?;
+    module.function_meta(Hasher::finalize__meta)?;
+    module.function_meta(Hasher::finalize_hex__meta)?;
+    module.function_meta(Hasher::verify__meta)?;
+    Ok(module)
+}
+
+#[derive(Any)]
+#[rune(item = ::digest)]
+/// Error raised by the digest module.
+struct Error {
+    message: ::std::string::String,
+}
+
+impl Error {
+    fn new(message: impl fmt::Display) -> Self {
+        Self {
+            message: message.to_string(),
+        }
+    }
+
+    #[rune::function(keep, protocol = DISPLAY_FMT)]
+    pub(crate) fn display(&self, f: &mut Formatter) -> VmResult<()> {
+        vm_write!(f, "{}", self.message)
+    }
+
+    #[rune::function(keep, protocol = DEBUG_FMT)]
+    pub(crate) fn debug(&self, f: &mut Formatter) -> VmResult<()> {
+        vm_write!(f, "Error({:?})", self.message)
+    }
+}
+
+fn to_bytes(bytes: &[u8]) -> VmResult<Bytes> {
+    VmResult::Ok(vm_try!(Bytes::from_slice(bytes)))
+}
+
+/// Compute the SHA-1 hash of the given data.
+///
+/// SHA-1 is not collision resistant and should only be used for compatibility
+/// with existing systems.
+///
+/// # Examples
+///
+/// ```rune
+/// let hash = digest::sha1("abc");
+/// assert_eq!(digest::to_hex(hash), "a9993e364706816aba3e25717850c26c9cd0d89d");
+/// ```
+#[rune::function]
+fn sha1(data: Value) -> VmResult<Bytes> {
+    to_bytes(&vm_try!(with_data(&data, |data| sha1::Sha1::digest(data))))
+}
+
+/// Compute the SHA-256 hash of the given data.
+///
+/// # Examples
+///
+/// ```rune
+/// let hash = digest::sha256(b"abc");
+/// assert_eq!(digest::to_hex(hash), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+/// assert_eq!(digest::sha256("abc"), hash);
+/// ```
+#[rune::function]
+fn sha256(data: Value) -> VmResult<Bytes> {
+    to_bytes(&vm_try!(with_data(&data, |data| sha2::Sha256::digest(
+        data
+    ))))
+}
+
+/// Compute the SHA-512 hash of the given data.
+///
+/// # Examples
+///
+/// ```rune
+/// let hash = digest::sha512("abc");
+/// assert_eq!(hash.len(), 64);
+/// assert_eq!(digest::to_hex(hash), "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f");
+/// ```
+#[rune::function]
+fn sha512(data: Value) -> VmResult<Bytes> {
+    to_bytes(&vm_try!(with_data(&data, |data| sha2::Sha512::digest(
+        data
+    ))))
+}
+
+/// Compute the BLAKE3 hash of the given data.
+///
+/// # Examples
+///
+/// ```rune
+/// let hash = digest::blake3("");
+/// assert_eq!(digest::to_hex(hash), "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262");
+/// ```
+#[rune::function]
+fn blake3(data: Value) -> VmResult<Bytes> {
+    let hash = vm_try!(with_data(&data, blake3::hash));
+    to_bytes(hash.as_bytes())
+}
+
+/// Compute the CRC32 (IEEE) checksum of the given data.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(digest::crc32("123456789"), 0xcbf43926);
+/// ```
+#[rune::function]
+fn crc32(data: Value) -> VmResult<u32> {
+    with_data(&data, crc32fast::hash)
+}
+
+/// Compute the 64-bit xxHash (XXH64) of the given data, using a seed of zero.
+///
+/// xxHash is fast but not cryptographically secure.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(digest::xxh64(""), 0xef46db3751d8e999u64);
+/// ```
+#[rune::function]
+fn xxh64(data: Value) -> VmResult<u64> {
+    with_data(&data, |data| twox_hash::XxHash64::oneshot(0, data))
+}
+
+/// Compute the 64-bit XXH3 hash of the given data.
+///
+/// XXH3 is fast but not cryptographically secure.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(digest::xxh3(""), 0x2d06800538d394c2u64);
+/// ```
+#[rune::function]
+fn xxh3(data: Value) -> VmResult<u64> {
+    with_data(&data, twox_hash::XxHash3_64::oneshot)
+}
+
+/// Compute the HMAC of the given data with a key, using one of the `sha1`,
+/// `sha256` or `sha512` algorithms.
+///
+/// # Errors
+///
+/// Errors if the algorithm is not supported.
+///
+/// # Examples
+///
+/// ```rune
+/// let mac = digest::hmac("sha256", "key", "The quick brown fox jumps over the lazy dog")?;
+/// assert_eq!(digest::to_hex(mac), "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8");
+///
+/// assert!(digest::hmac("md5", "key", "data").is_err());
+/// ```
+#[rune::function(vm_result)]
+fn hmac(algorithm: &str, key: Value, data: Value) -> Result<Bytes, Error> {
+    let mut hasher = Hasher::new_hmac(algorithm, &key).vm??;
+    with_data(&data, |data| hasher.inner.update(data)).vm?;
+    Ok(hasher.inner.finalize().vm?)
+}
+
+/// Encode bytes as a lowercase hexadecimal string.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(digest::to_hex(b"\x00\xffrune"), "00ff72756e65");
+/// assert_eq!(digest::to_hex("rune"), "72756e65");
+/// ```
+#[rune::function(vm_result)]
+fn to_hex(data: Value) -> String {
+    with_data(&data, |bytes| hex::encode(bytes, hex::LOWER))
+        .vm?
+        .vm?
+}
+
+/// Decode a hexadecimal string into bytes, accepting both lower and upper
+/// case digits.
+///
+/// # Errors
+///
+/// Errors if the string is not valid hexadecimal.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(digest::from_hex("00FF72756e65")?, b"\x00\xffrune");
+///
+/// assert!(digest::from_hex("abc").is_err());
+/// assert!(digest::from_hex("zz").is_err());
+/// ```
+#[rune::function(vm_result)]
+fn from_hex(string: &str) -> Result<Bytes, Error> {
+    if string.len() % 2 != 0 {
+        return Err(Error::new("Hexadecimal string has an odd length"));
+    }
+
+    match hex::decode(string).vm? {
+        Ok(bytes) => Ok(Bytes::from_vec(bytes)),
+        Err(hex::DecodeError::Invalid(position) | hex::DecodeError::Missing(position)) => {
+            Err(Error::new(format_args!(
+                "Invalid hexadecimal digit at offset {position}"
+            )))
+        }
+    }
+}
+
+/// Compare two values in constant time, which should be used when comparing
+/// secrets like signatures to avoid leaking information through timing.
+///
+/// # Examples
+///
+/// ```rune
+/// assert!(digest::constant_time_eq(b"secret", "secret"));
+/// assert!(!digest::constant_time_eq(b"secret", b"secreT"));
+/// assert!(!digest::constant_time_eq(b"secret", b"secret!"));
+/// ```
+#[rune::function]
+fn constant_time_eq(a: Value, b: Value) -> VmResult<bool> {
+    let eq = vm_try!(with_data(&a, |a| with_data(&b, |b| bool::from(a.ct_eq(b)))));
+    VmResult::Ok(vm_try!(eq))
+}
+
+enum Inner {
+    Sha1(sha1::Sha1),
+    Sha256(sha2::Sha256),
+    Sha512(sha2::Sha512),
+    Blake3(Box<blake3::Hasher>),
+    Crc32(crc32fast::Hasher),
+    Xxh64(twox_hash::XxHash64),
+    Xxh3(Box<twox_hash::XxHash3_64>),
+    HmacSha1(Hmac<sha1::Sha1>),
+    HmacSha256(Hmac<sha2::Sha256>),
+    HmacSha512(Hmac<sha2::Sha512>),
+}
+
+impl Inner {
+    fn update(&mut self, data: &[u8]) {
+        match self {
+            Inner::Sha1(hasher) => hasher.update(data),
+            Inner::Sha256(hasher) => hasher.update(data),
+            Inner::Sha512(hasher) => hasher.update(data),
+            Inner::Blake3(hasher) => {
+                hasher.update(data);
+            }
+            Inner::Crc32(hasher) => hasher.update(data),
+            Inner::Xxh64(hasher) => hasher.write(data),
+            Inner::Xxh3(hasher) => hasher.write(data),
+            Inner::HmacSha1(mac) => mac.update(data),
+            Inner::HmacSha256(mac) => mac.update(data),
+            Inner::HmacSha512(mac) => mac.update(data),
+        }
+    }
+
+    fn finalize(self) -> VmResult<Bytes> {
+        match self {
+            Inner::Sha1(hasher) => to_bytes(&hasher.finalize()),
+            Inner::Sha256(hasher) => to_bytes(&hasher.finalize()),
+            Inner::Sha512(hasher) => to_bytes(&hasher.finalize()),
+            Inner::Blake3(hasher) => to_bytes(hasher.finalize().as_bytes()),
+            Inner::Crc32(hasher) => to_bytes(&hasher.finalize().to_be_bytes()),
+            Inner::Xxh64(hasher) => to_bytes(&hasher.finish().to_be_bytes()),
+            Inner::Xxh3(hasher) => to_bytes(&hasher.finish().to_be_bytes()),
+            Inner::HmacSha1(mac) => to_bytes(&mac.finalize().into_bytes()),
+            Inner::HmacSha256(mac) => to_bytes(&mac.finalize().into_bytes()),
+            Inner::HmacSha512(mac) => to_bytes(&mac.finalize().into_bytes()),
+        }
+    }
+}
+
+/// An incremental hasher, which is fed data through [`Hasher::update`] and
+/// produces a digest once finalized.
+///
+/// Checksums like `crc32`, `xxh64` and `xxh3` are finalized into their
+/// big-endian bytes, which matches their usual hexadecimal representation.
+///
+/// # Examples
+///
+/// ```rune
+/// use digest::Hasher;
+///
+/// let hasher = Hasher::new("sha256")?;
+/// hasher.update("a");
+/// hasher.update(b"bc");
+///
+/// assert_eq!(hasher.finalize(), digest::sha256("abc"));
+/// ```
+#[derive(Any)]
+#[rune(item = ::digest)]
+struct Hasher {
+    inner: Inner,
+}
+
+impl Hasher {
+    /// Construct a new hasher for the given algorithm, which is one of `sha1`,
+    /// `sha256`, `sha512`, `blake3`, `crc32`, `xxh64` or `xxh3`.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the algorithm is not supported.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use digest::Hasher;
+    ///
+    /// let hasher = Hasher::new("crc32")?;
+    /// hasher.update("123456789");
+    /// assert_eq!(hasher.finalize_hex(), "cbf43926");
+    ///
+    /// assert!(Hasher::new("md5").is_err());
+    /// ```
+    #[rune::function(keep, path = Self::new)]
+    fn new(algorithm: &str) -> Result<Self, Error> {
+        let inner = match algorithm {
+            "sha1" => Inner::Sha1(sha1::Sha1::new()),
+            "sha256" => Inner::Sha256(sha2::Sha256::new()),
+            "sha512" => Inner::Sha512(sha2::Sha512::new()),
+            "blake3" => Inner::Blake3(Box::new(blake3::Hasher::new())),
+            "crc32" => Inner::Crc32(crc32fast::Hasher::new()),
+            "xxh64" => Inner::Xxh64(twox_hash::XxHash64::with_seed(0)),
+            "xxh3" => Inner::Xxh3(Box::new(twox_hash::XxHash3_64::new())),
+            _ => {
+                return Err(Error::new(format_args!(
+                    "Unsupported algorithm `{algorithm}`"
+                )));
+            }
+        };
+
+        Ok(Self { inner })
+    }
+
+    /// Construct a new HMAC hasher with the given key, using one of the
+    /// `sha1`, `sha256` or `sha512` algorithms.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the algorithm is not supported.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use digest::Hasher;
+    ///
+    /// let mac = Hasher::hmac("sha1", b"key")?;
+    /// mac.update("The quick brown fox jumps over the lazy dog");
+    /// assert_eq!(mac.finalize_hex(), "de7c9b85b8b78aa6bc8a7a36f70a90701c9db4d9");
+    /// ```
+    #[rune::function(keep, path = Self::hmac)]
+    fn hmac(algorithm: &str, key: Value) -> VmResult<Result<Self, Error>> {
+        Self::new_hmac(algorithm, &key)
+    }
+
+    fn new_hmac(algorithm: &str, key: &Value) -> VmResult<Result<Self, Error>> {
+        let inner = vm_try!(with_data(key, |key| {
+            // NB: HMAC accepts keys of any length.
+            Some(match algorithm {
+                "sha1" => Inner::HmacSha1(Hmac::new_from_slice(key).ok()?),
+                "sha256" => Inner::HmacSha256(Hmac::new_from_slice(key).ok()?),
+                "sha512" => Inner::HmacSha512(Hmac::new_from_slice(key).ok()?),
+                _ => return None,
+            })
+        }));
+
+        let Some(inner) = inner else {
+            return VmResult::Ok(Err(Error::new(format_args!(
+                "Unsupported HMAC algorithm `{algorithm}`"
+            ))));
+        };
+
+        VmResult::Ok(Ok(Self { inner }))
+    }
+
+    /// Feed data into the hasher.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use digest::Hasher;
+    ///
+    /// let hasher = Hasher::new("xxh64")?;
+    /// hasher.update("");
+    /// assert_eq!(hasher.finalize_hex(), "ef46db3751d8e999");
+    /// ```
+    #[rune::function(keep)]
+    fn update(&mut self, data: Value) -> VmResult<()> {
+        with_data(&data, |data| self.inner.update(data))
+    }
+
+    /// Consume the hasher and return the digest.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use digest::Hasher;
+    ///
+    /// let hasher = Hasher::new("blake3")?;
+    /// hasher.update("rune");
+    /// assert_eq!(hasher.finalize(), digest::blake3("rune"));
+    /// ```
+    #[rune::function(keep)]
+    fn finalize(self) -> VmResult<Bytes> {
+        self.inner.finalize()
+    }
+
+    /// Consume the hasher and return the digest as a lowercase hexadecimal
+    /// string.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use digest::Hasher;
+    ///
+    /// let hasher = Hasher::new("sha1")?;
+    /// hasher.update("abc");
+    /// assert_eq!(hasher.finalize_hex(), "a9993e364706816aba3e25717850c26c9cd0d89d");
+    /// ```
+    #[rune::function(keep)]
+    fn finalize_hex(self) -> VmResult<String> {
+        let bytes = vm_try!(self.inner.finalize());
+        hex::encode(bytes.as_slice(), hex::LOWER)
+    }
+
+    /// Consume the hasher and compare its digest against the expected one in
+    /// constant time.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use digest::Hasher;
+    ///
+    /// let mac = Hasher::hmac("sha256", "key")?;
+    /// mac.update("The quick brown fox jumps over the lazy dog");
+    /// assert!(!mac.verify(b"not the signature"));
+    /// ```
+    #[rune::function(keep)]
+    fn verify(self, expected: Value) -> VmResult<bool> {
+        let bytes = vm_try!(self.inner.finalize());
+        with_data(&expected, |expected| {
+            bool::from(bytes.as_slice().ct_eq(expected))
+        })
+    }
+}
@@ -0,0 +1,628 @@
+//! The native `encoding` module for the [Rune Language].
+//!
+//! [Rune Language]: https://rune-rs.github.io
+//!
+//! ## Usage
+//!
+//! Add the following to your `Cargo.toml`:
+//!
+//! ```toml
+//! rune-modules = { version = "0.14.0", features = ["encoding"] }
+//! ```
+//!
+//! Install it into your context:
+//!
+//! ```rust
+//! let mut context = rune::Context::with_default_modules()?;
+//! context.install(rune_modules::encoding::module(true)?)?;
+//! # Ok::<_, rune::support::Error>(())
+//! ```
+//!
+//! Use it in Rune:
+//!
+//! ```rust,ignore
+//! fn main() {
+//!     let token = encoding::to_base64_url_no_pad(b"\xfb\xff\xfe");
+//!     let query = encoding::percent_encode("name=rune & co");
+//!     dbg(token, query);
+//! }
+//! ```
+
+use base64::alphabet;
+use base64::engine::general_purpose::{GeneralPurpose, GeneralPurposeConfig};
+use base64::engine::DecodePaddingMode;
+use base64::Engine;
+
+use rune::alloc::fmt::TryWrite;
+use rune::alloc::{String, Vec};
+use rune::runtime::{Bytes, Formatter, Value, VmResult};
+use rune::{vm_try, vm_write, Any, ContextError, Module};
+
+use crate::hex::{self, with_data};
+
+#[rune::module(::encoding)]
+/// Encoding and decoding of binary data as text.
+///
+/// Functions which encode accept either `Bytes` or a `String`, where strings
+/// are encoded as their UTF-8 representation. Functions which decode produce
+/// `Bytes`, and report the position of the offending input through a
+/// [`DecodeError`] when they fail.
+///
+/// # Examples
+///
+/// ```rune
+/// let encoded = encoding::to_base32("rune");
+/// assert_eq!(encoded, "OJ2W4ZI=");
+/// assert_eq!(encoding::from_base32(encoded)?, b"rune");
+///
+/// let error = encoding::from_hex("00fg").unwrap_err();
+/// assert_eq!(error.position(), 3);
+/// ```
+pub fn module(_stdio: bool) -> Result<Module, ContextError> {
+    let mut module = Module::from_meta(self::module_meta)?;
+    module.ty::<DecodeError>()?;
+    module.function_meta(DecodeError::position__meta)?;
+    module.function_meta(DecodeError::display__meta)?;
+    module.function_meta(DecodeError::debug__meta)?;
+
+    module.function_meta(to_hex)?;
+    module.function_meta(to_hex_upper)?;
+    module.function_meta(from_hex)?;
+    module.function_meta(to_base32)?;
+    module.function_meta(to_base32_no_pad)?;
+    module.function_meta(from_base32)?;
+    module.function_meta(to_base64)?;
+    module.function_meta(to_base64_no_pad)?;
+    module.function_meta(to_base64_url)?;
+    module.function_meta(to_base64_url_no_pad)?;
+    module.function_meta(from_base64)?;
+    module.function_meta(from_base64_url)?;
+    module.function_meta(percent_encode)?;
+    module.function_meta(percent_decode)?;
+    module.function_meta(to_utf16_le)?;
+    module.function_meta(to_utf16_be)?;
+    module.function_meta(from_utf16_le)?;
+    module.function_meta(from_utf16_be)?;
+    Ok(module)
+}
+
+/// An error raised when decoding invalid input.
+#[derive(Debug, Any)]
+#[rune(item = ::encoding)]
+pub struct DecodeError {
+    message: &'static str,
+    position: usize,
+}
+
+impl DecodeError {
+    fn new(message: &'static str, position: usize) -> Self {
+        Self { message, position }
+    }
+
+    /// The position in the input where decoding failed.
+    ///
+    /// For strings this is a byte offset into their UTF-8 representation.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// let error = encoding::from_base64("cnVu!w==").unwrap_err();
+    /// assert_eq!(error.position(), 4);
+    /// ```
+    #[rune::function(keep, instance)]
+    fn position(&self) -> usize {
+        self.position
+    }
+
+    #[rune::function(keep, protocol = DISPLAY_FMT)]
+    fn display(&self, f: &mut Formatter) -> VmResult<()> {
+        vm_write!(f, "{} at position {}", self.message, self.position)
+    }
+
+    #[rune::function(keep, protocol = DEBUG_FMT)]
+    fn debug(&self, f: &mut Formatter) -> VmResult<()> {
+        vm_write!(
+            f,
+            "DecodeError {{ message: {:?}, position: {} }}",
+            self.message,
+            self.position
+        )
+    }
+}
+
+/// Encode bytes as a lowercase hexadecimal string.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(encoding::to_hex(b"\x00\xffrune"), "00ff72756e65");
+/// assert_eq!(encoding::to_hex("rune"), "72756e65");
+/// ```
+#[rune::function(vm_result)]
+fn to_hex(data: Value) -> String {
+    with_data(&data, |bytes| hex::encode(bytes, hex::LOWER))
+        .vm?
+        .vm?
+}
+
+/// Encode bytes as an uppercase hexadecimal string.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(encoding::to_hex_upper(b"\x00\xffrune"), "00FF72756E65");
+/// ```
+#[rune::function(vm_result)]
+fn to_hex_upper(data: Value) -> String {
+    with_data(&data, |bytes| hex::encode(bytes, hex::UPPER))
+        .vm?
+        .vm?
+}
+
+/// Decode a hexadecimal string into bytes, accepting both lower and upper
+/// case digits.
+///
+/// # Errors
+///
+/// Errors if the string contains a character which isn't a hexadecimal digit,
+/// or if it has an odd length.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(encoding::from_hex("00FF72756e65")?, b"\x00\xffrune");
+///
+/// assert_eq!(encoding::from_hex("abc").unwrap_err().position(), 3);
+/// assert_eq!(encoding::from_hex("0z").unwrap_err().position(), 1);
+/// ```
+#[rune::function(vm_result)]
+fn from_hex(string: &str) -> Result<Bytes, DecodeError> {
+    match hex::decode(string).vm? {
+        Ok(bytes) => Ok(Bytes::from_vec(bytes)),
+        Err(hex::DecodeError::Invalid(position)) => {
+            Err(DecodeError::new("Invalid hexadecimal digit", position))
+        }
+        Err(hex::DecodeError::Missing(position)) => {
+            Err(DecodeError::new("Missing hexadecimal digit", position))
+        }
+    }
+}
+
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// Encode bytes as base32 using the standard alphabet from [RFC 4648], padded
+/// with `=` to a multiple of eight characters.
+///
+/// [RFC 4648]: https://datatracker.ietf.org/doc/html/rfc4648#section-6
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(encoding::to_base32("rune"), "OJ2W4ZI=");
+/// assert_eq!(encoding::to_base32(b""), "");
+/// ```
+#[rune::function(vm_result)]
+fn to_base32(data: Value) -> String {
+    with_data(&data, |bytes| base32(bytes, true)).vm?.vm?
+}
+
+/// Encode bytes as base32 using the standard alphabet from [RFC 4648], without
+/// padding.
+///
+/// [RFC 4648]: https://datatracker.ietf.org/doc/html/rfc4648#section-6
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(encoding::to_base32_no_pad("rune"), "OJ2W4ZI");
+/// ```
+#[rune::function(vm_result)]
+fn to_base32_no_pad(data: Value) -> String {
+    with_data(&data, |bytes| base32(bytes, false)).vm?.vm?
+}
+
+fn base32(bytes: &[u8], pad: bool) -> VmResult<String> {
+    let mut string = vm_try!(String::try_with_capacity(bytes.len().div_ceil(5) * 8));
+
+    for chunk in bytes.chunks(5) {
+        let mut buf = [0; 8];
+        buf[3..3 + chunk.len()].copy_from_slice(chunk);
+        let bits = u64::from_be_bytes(buf);
+
+        let symbols = (chunk.len() * 8).div_ceil(5);
+
+        for n in 0..8 {
+            if n < symbols {
+                let index = (bits >> (35 - n * 5)) & 0x1f;
+                vm_try!(string.try_push(BASE32_ALPHABET[index as usize] as char));
+            } else if pad {
+                vm_try!(string.try_push('='));
+            }
+        }
+    }
+
+    VmResult::Ok(string)
+}
+
+/// Decode a base32 string using the standard alphabet from [RFC 4648].
+///
+/// Both upper and lower case letters are accepted, and padding is optional.
+///
+/// [RFC 4648]: https://datatracker.ietf.org/doc/html/rfc4648#section-6
+///
+/// # Errors
+///
+/// Errors if the string contains a character outside of the alphabet, is
+/// incorrectly padded, or has a length which no input encodes to.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(encoding::from_base32("OJ2W4ZI=")?, b"rune");
+/// assert_eq!(encoding::from_base32("oj2w4zi")?, b"rune");
+///
+/// assert_eq!(encoding::from_base32("OJ2W1ZI=").unwrap_err().position(), 4);
+/// assert_eq!(encoding::from_base32("OJ2W4ZI==").unwrap_err().position(), 7);
+/// ```
+#[rune::function(vm_result)]
+fn from_base32(string: &str) -> Result<Bytes, DecodeError> {
+    fn symbol(b: u8) -> Option<u8> {
+        match b {
+            b'A'..=b'Z' => Some(b - b'A'),
+            b'a'..=b'z' => Some(b - b'a'),
+            b'2'..=b'7' => Some(b - b'2' + 26),
+            _ => None,
+        }
+    }
+
+    let data = string.trim_end_matches('=');
+
+    if data.len() != string.len() && string.len() % 8 != 0 {
+        return Err(DecodeError::new("Invalid padding", data.len()));
+    }
+
+    if matches!(data.len() % 8, 1 | 3 | 6) {
+        return Err(DecodeError::new("Invalid length", data.len()));
+    }
+
+    let mut bytes = Vec::try_with_capacity(data.len() * 5 / 8).vm?;
+    let mut bits = 0u16;
+    let mut count = 0;
+
+    for (position, b) in data.bytes().enumerate() {
+        let Some(value) = symbol(b) else {
+            return Err(DecodeError::new("Invalid base32 symbol", position));
+        };
+
+        bits = (bits << 5) | u16::from(value);
+        count += 5;
+
+        if count >= 8 {
+            count -= 8;
+            bytes.try_push((bits >> count) as u8).vm?;
+            bits &= (1 << count) - 1;
+        }
+    }
+
+    Ok(Bytes::from_vec(bytes))
+}
+
+const fn engine(alphabet: &alphabet::Alphabet, pad: bool) -> GeneralPurpose {
+    let config = GeneralPurposeConfig::new()
+        .with_encode_padding(pad)
+        .with_decode_padding_mode(DecodePaddingMode::Indifferent);
+
+    GeneralPurpose::new(alphabet, config)
+}
+
+const STANDARD: GeneralPurpose = engine(&alphabet::STANDARD, true);
+const STANDARD_NO_PAD: GeneralPurpose = engine(&alphabet::STANDARD, false);
+const URL_SAFE: GeneralPurpose = engine(&alphabet::URL_SAFE, true);
+const URL_SAFE_NO_PAD: GeneralPurpose = engine(&alphabet::URL_SAFE, false);
+
+/// Encode bytes as base64 using the standard alphabet, with padding.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(encoding::to_base64(b"\xfb\xff\xfe\x00"), "+//+AA==");
+/// ```
+#[rune::function(vm_result)]
+fn to_base64(data: Value) -> String {
+    with_data(&data, |bytes| base64(&STANDARD, bytes)).vm?.vm?
+}
+
+/// Encode bytes as base64 using the standard alphabet, without padding.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(encoding::to_base64_no_pad(b"\xfb\xff\xfe\x00"), "+//+AA");
+/// ```
+#[rune::function(vm_result)]
+fn to_base64_no_pad(data: Value) -> String {
+    with_data(&data, |bytes| base64(&STANDARD_NO_PAD, bytes))
+        .vm?
+        .vm?
+}
+
+/// Encode bytes as base64 using the URL and filename safe alphabet, with
+/// padding.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(encoding::to_base64_url(b"\xfb\xff\xfe\x00"), "-__-AA==");
+/// ```
+#[rune::function(vm_result)]
+fn to_base64_url(data: Value) -> String {
+    with_data(&data, |bytes| base64(&URL_SAFE, bytes)).vm?.vm?
+}
+
+/// Encode bytes as base64 using the URL and filename safe alphabet, without
+/// padding.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(encoding::to_base64_url_no_pad(b"\xfb\xff\xfe\x00"), "-__-AA");
+/// ```
+#[rune::function(vm_result)]
+fn to_base64_url_no_pad(data: Value) -> String {
+    with_data(&data, |bytes| base64(&URL_SAFE_NO_PAD, bytes))
+        .vm?
+        .vm?
+}
+
+fn base64(engine: &GeneralPurpose, bytes: &[u8]) -> VmResult<String> {
+    VmResult::Ok(vm_try!(String::try_from(engine.encode(bytes))))
+}
+
+/// Decode a base64 string using the standard alphabet.
+///
+/// Padding is optional, but must be correct if present.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(encoding::from_base64("+//+AA==")?, b"\xfb\xff\xfe\x00");
+/// assert_eq!(encoding::from_base64("+//+AA")?, b"\xfb\xff\xfe\x00");
+///
+/// assert_eq!(encoding::from_base64("-__-AA").unwrap_err().position(), 0);
+/// ```
+#[rune::function(vm_result)]
+fn from_base64(string: &str) -> Result<Bytes, DecodeError> {
+    match STANDARD.decode(string) {
+        Ok(bytes) => Ok(Bytes::try_from(bytes).vm?),
+        Err(error) => Err(base64_error(string, error)),
+    }
+}
+
+/// Decode a base64 string using the URL and filename safe alphabet.
+///
+/// Padding is optional, but must be correct if present.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(encoding::from_base64_url("-__-AA==")?, b"\xfb\xff\xfe\x00");
+/// assert_eq!(encoding::from_base64_url("-__-AA")?, b"\xfb\xff\xfe\x00");
+///
+/// assert_eq!(encoding::from_base64_url("+//+AA").unwrap_err().position(), 0);
+/// ```
+#[rune::function(vm_result)]
+fn from_base64_url(string: &str) -> Result<Bytes, DecodeError> {
+    match URL_SAFE.decode(string) {
+        Ok(bytes) => Ok(Bytes::try_from(bytes).vm?),
+        Err(error) => Err(base64_error(string, error)),
+    }
+}
+
+fn base64_error(string: &str, error: base64::DecodeError) -> DecodeError {
+    match error {
+        base64::DecodeError::InvalidByte(position, _) => {
+            DecodeError::new("Invalid base64 symbol", position)
+        }
+        base64::DecodeError::InvalidLastSymbol(position, _) => {
+            DecodeError::new("Invalid last base64 symbol", position)
+        }
+        base64::DecodeError::InvalidLength(position) => {
+            DecodeError::new("Invalid length", position)
+        }
+        base64::DecodeError::InvalidPadding => {
+            let position = string.find('=').unwrap_or(string.len());
+            DecodeError::new("Invalid padding", position)
+        }
+    }
+}
+
+/// Percent-encode bytes as described in [RFC 3986].
+///
+/// Every byte except for ASCII letters, digits and the characters `-`, `.`,
+/// `_` and `~` is encoded, which makes the result safe to use anywhere in a
+/// URL, like in a path segment or a query parameter.
+///
+/// [RFC 3986]: https://datatracker.ietf.org/doc/html/rfc3986#section-2.1
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(encoding::percent_encode("name=rune & co"), "name%3Drune%20%26%20co");
+/// assert_eq!(encoding::percent_encode("ö"), "%C3%B6");
+/// ```
+#[rune::function(vm_result)]
+fn percent_encode(data: Value) -> String {
+    with_data(&data, percent).vm?.vm?
+}
+
+fn percent(bytes: &[u8]) -> VmResult<String> {
+    let mut string = vm_try!(String::try_with_capacity(bytes.len()));
+
+    for &b in bytes {
+        if b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~') {
+            vm_try!(string.try_push(b as char));
+        } else {
+            vm_try!(string.try_push('%'));
+            vm_try!(string.try_push(hex::UPPER[usize::from(b >> 4)] as char));
+            vm_try!(string.try_push(hex::UPPER[usize::from(b & 0xf)] as char));
+        }
+    }
+
+    VmResult::Ok(string)
+}
+
+/// Decode a percent-encoded string.
+///
+/// Characters which aren't encoded are kept as they are. Note that `+` is not
+/// decoded into a space.
+///
+/// # Errors
+///
+/// Errors if a `%` isn't followed by two hexadecimal digits, or if the decoded
+/// bytes aren't valid UTF-8.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(encoding::percent_decode("name%3Drune%20%26%20co")?, "name=rune & co");
+///
+/// assert_eq!(encoding::percent_decode("100%").unwrap_err().position(), 3);
+/// assert_eq!(encoding::percent_decode("a%FFb").unwrap_err().position(), 1);
+/// ```
+#[rune::function(vm_result)]
+fn percent_decode(string: &str) -> Result<String, DecodeError> {
+    let input = string.as_bytes();
+
+    let mut bytes = Vec::try_with_capacity(input.len()).vm?;
+    // The position in the input of every decoded byte, used to report where
+    // invalid UTF-8 starts.
+    let mut positions = Vec::try_with_capacity(input.len()).vm?;
+    let mut n = 0;
+
+    while let Some(&b) = input.get(n) {
+        positions.try_push(n).vm?;
+
+        if b != b'%' {
+            bytes.try_push(b).vm?;
+            n += 1;
+            continue;
+        }
+
+        let hi = input.get(n + 1).copied().and_then(hex::digit);
+        let lo = input.get(n + 2).copied().and_then(hex::digit);
+
+        let (Some(hi), Some(lo)) = (hi, lo) else {
+            return Err(DecodeError::new("Invalid percent-encoding", n));
+        };
+
+        bytes.try_push((hi << 4) | lo).vm?;
+        n += 3;
+    }
+
+    match String::from_utf8(bytes) {
+        Ok(string) => Ok(string),
+        Err(error) => {
+            let position = positions[error.utf8_error().valid_up_to()];
+            Err(DecodeError::new("Invalid UTF-8", position))
+        }
+    }
+}
+
+/// Encode a string as UTF-16 with little-endian code units.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(encoding::to_utf16_le("a€"), b"a\x00\xac\x20");
+/// ```
+#[rune::function(vm_result)]
+fn to_utf16_le(string: &str) -> Bytes {
+    utf16(string, u16::to_le_bytes).vm?
+}
+
+/// Encode a string as UTF-16 with big-endian code units.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(encoding::to_utf16_be("a€"), b"\x00a\x20\xac");
+/// ```
+#[rune::function(vm_result)]
+fn to_utf16_be(string: &str) -> Bytes {
+    utf16(string, u16::to_be_bytes).vm?
+}
+
+fn utf16(string: &str, to_bytes: fn(u16) -> [u8; 2]) -> VmResult<Bytes> {
+    let mut bytes = vm_try!(Vec::try_with_capacity(string.len() * 2));
+
+    for unit in string.encode_utf16() {
+        vm_try!(bytes.try_extend_from_slice(&to_bytes(unit)));
+    }
+
+    VmResult::Ok(Bytes::from_vec(bytes))
+}
+
+/// Decode UTF-16 with little-endian code units into a string.
+///
+/// # Errors
+///
+/// Errors if the input has an odd length, or contains an unpaired surrogate.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(encoding::from_utf16_le(b"a\x00\xac\x20")?, "a€");
+///
+/// assert_eq!(encoding::from_utf16_le(b"a\x00\x00\xd8").unwrap_err().position(), 2);
+/// assert_eq!(encoding::from_utf16_le(b"a\x00b").unwrap_err().position(), 2);
+/// ```
+#[rune::function(vm_result)]
+fn from_utf16_le(bytes: &[u8]) -> Result<String, DecodeError> {
+    from_utf16(bytes, u16::from_le_bytes).vm?
+}
+
+/// Decode UTF-16 with big-endian code units into a string.
+///
+/// # Errors
+///
+/// Errors if the input has an odd length, or contains an unpaired surrogate.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(encoding::from_utf16_be(b"\x00a\x20\xac")?, "a€");
+/// ```
+#[rune::function(vm_result)]
+fn from_utf16_be(bytes: &[u8]) -> Result<String, DecodeError> {
+    from_utf16(bytes, u16::from_be_bytes).vm?
+}
+
+fn from_utf16(
+    bytes: &[u8],
+    from_bytes: fn([u8; 2]) -> u16,
+) -> VmResult<Result<String, DecodeError>> {
+    if bytes.len() % 2 != 0 {
+        return VmResult::Ok(Err(DecodeError::new(
+            "Incomplete UTF-16 code unit",
+            bytes.len() - 1,
+        )));
+    }
+
+    let units = bytes
+        .chunks_exact(2)
+        .map(|pair| from_bytes([pair[0], pair[1]]));
+
+    let mut string = vm_try!(String::try_with_capacity(bytes.len() / 2));
+    let mut position = 0;
+
+    for c in char::decode_utf16(units) {
+        let Ok(c) = c else {
+            return VmResult::Ok(Err(DecodeError::new("Unpaired surrogate", position)));
+        };
+
+        vm_try!(string.try_push(c));
+        position += c.len_utf16() * 2;
+    }
+
+    VmResult::Ok(Ok(string))
+}
@@ -0,0 +1,322 @@
+//! The native `env` module for the [Rune Language].
+//!
+//! [Rune Language]: https://rune-rs.github.io
+//!
+//! ## Usage
+//!
+//! Add the following to your `Cargo.toml`:
+//!
+//! ```toml
+//! rune-modules = { version = "0.14.0", features = ["env"] }
+//! ```
+//!
+//! Install it into your context:
+//!
+//! ```rust
+//! let mut context = rune::Context::with_default_modules()?;
+//! context.install(rune_modules::env::module(true)?)?;
+//! # Ok::<_, rune::support::Error>(())
+//! ```
+//!
+//! Use it in Rune:
+//!
+//! ```rust,ignore
+//! fn main() {
+//!     let home = env::var("HOME");
+//!     dbg(home);
+//! }
+//! ```
+//!
+//! ## Capabilities
+//!
+//! The module installed through [`module`] can read the environment of the
+//! process, but can't modify it. Hosts which want scripts to be able to set
+//! and remove environment variables, or which want to provide scripts with
+//! their own arguments, can install it through [`module_with_config`]:
+//!
+//! ```rust
+//! use rune_modules::env::{self, Config};
+//!
+//! let config = Config::new()
+//!     .allow_set_var(true)
+//!     .args(["script.rn", "--verbose"]);
+//!
+//! let mut context = rune::Context::with_default_modules()?;
+//! context.install(env::module_with_config(config)?)?;
+//! # Ok::<_, rune::support::Error>(())
+//! ```
+//!
+//! Modifying the environment when it's not permitted fails with an I/O error
+//! of the kind `PermissionDenied`.
+
+use std::io;
+use std::sync::Arc;
+
+use rune::alloc::{String, Vec};
+use rune::runtime::{Object, VmResult};
+use rune::{docstring, vm_try, ContextError, Module};
+
+use crate::path::Path;
+
+/// Inspecting the environment of the process.
+///
+/// # Examples
+///
+/// ```rune
+/// let dir = env::current_dir()?;
+/// assert!(dir.is_absolute());
+/// ```
+#[rune::module(::env)]
+pub fn module(_stdio: bool) -> Result<Module, ContextError> {
+    module_with_config(Config::default())
+}
+
+/// Construct the `env` module, with the capabilities granted by the given
+/// [`Config`].
+pub fn module_with_config(config: Config) -> Result<Module, ContextError> {
+    let mut m = Module::from_meta(self::module_meta)?;
+
+    m.function_meta(var)?;
+    m.function_meta(vars)?;
+    m.function_meta(current_dir)?;
+    m.function_meta(temp_dir)?;
+
+    let args = Arc::new(config.args.unwrap_or_else(|| {
+        std::env::args_os()
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect()
+    }));
+
+    m.function("args", move || {
+        let mut output = Vec::new();
+
+        for arg in args.iter() {
+            vm_try!(output.try_push(vm_try!(String::try_from(arg.as_str()))));
+        }
+
+        VmResult::Ok(output)
+    })
+    .build()?
+    .docs(docstring! {
+        /// Get the arguments the script was started with.
+        ///
+        /// Unless the host provides its own arguments, these are the
+        /// arguments of the process, starting with the path to its
+        /// executable. Arguments which aren't valid UTF-8 are converted
+        /// lossily, with invalid sequences replaced by `U+FFFD`.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        /// let args = env::args();
+        /// assert!(args.len() > 0);
+        /// ```
+    })?;
+
+    let allow_set_var = config.allow_set_var;
+
+    m.function("set_var", move |key: &str, value: &str| {
+        if !allow_set_var {
+            return Err(denied());
+        }
+
+        std::env::set_var(key, value);
+        Ok(())
+    })
+    .build()?
+    .docs(docstring! {
+        /// Set an environment variable of the process.
+        ///
+        /// Modifying the environment is not thread-safe on most platforms,
+        /// so this may race with other threads reading it, including
+        /// through libraries the host uses.
+        ///
+        /// # Errors
+        ///
+        /// Errors with `PermissionDenied` unless the host has allowed
+        /// scripts to modify the environment.
+        ///
+        /// # Examples
+        ///
+        /// ```rune,no_run
+        /// env::set_var("RUST_LOG", "debug")?;
+        /// assert_eq!(env::var("RUST_LOG"), Some("debug"));
+        /// ```
+    })?
+    .return_type::<io::Result<()>>()?;
+
+    m.function("remove_var", move |key: &str| {
+        if !allow_set_var {
+            return Err(denied());
+        }
+
+        std::env::remove_var(key);
+        Ok(())
+    })
+    .build()?
+    .docs(docstring! {
+        /// Remove an environment variable from the process.
+        ///
+        /// Modifying the environment is not thread-safe on most platforms,
+        /// so this may race with other threads reading it, including
+        /// through libraries the host uses.
+        ///
+        /// # Errors
+        ///
+        /// Errors with `PermissionDenied` unless the host has allowed
+        /// scripts to modify the environment.
+        ///
+        /// # Examples
+        ///
+        /// ```rune,no_run
+        /// env::remove_var("RUST_LOG")?;
+        /// assert_eq!(env::var("RUST_LOG"), None);
+        /// ```
+    })?
+    .return_type::<io::Result<()>>()?;
+
+    m.constant("OS", std::env::consts::OS)
+        .build()?
+        .docs(docstring! {
+            /// The operating system the process runs on, like `linux`,
+            /// `macos` or `windows`.
+        })?;
+
+    m.constant("ARCH", std::env::consts::ARCH)
+        .build()?
+        .docs(docstring! {
+            /// The CPU architecture the process runs on, like `x86_64` or
+            /// `aarch64`.
+        })?;
+
+    m.constant("FAMILY", std::env::consts::FAMILY)
+        .build()?
+        .docs(docstring! {
+            /// The family of the operating system the process runs on, which
+            /// is either `unix` or `windows`.
+        })?;
+
+    Ok(m)
+}
+
+/// Configuration for the capabilities of the [`env`][self] module.
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct Config {
+    allow_set_var: bool,
+    args: Option<std::vec::Vec<std::string::String>>,
+}
+
+impl Config {
+    /// Construct a new configuration which can only read the environment.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set if scripts are allowed to set and remove environment variables.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// # Warning
+    ///
+    /// On most platforms other than Windows, modifying the environment while
+    /// other threads read it is undefined behavior, which is why
+    /// [`std::env::set_var`] and [`std::env::remove_var`] are `unsafe` as of
+    /// the 2024 edition. Reads happen implicitly in many places, like in DNS
+    /// resolution or when formatting time, so only enable this for hosts which
+    /// run scripts while no other threads are running.
+    pub fn allow_set_var(mut self, allow_set_var: bool) -> Self {
+        self.allow_set_var = allow_set_var;
+        self
+    }
+
+    /// Set the arguments returned by `env::args`.
+    ///
+    /// Defaults to the arguments of the process.
+    pub fn args<I>(mut self, args: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<std::string::String>,
+    {
+        self.args = Some(args.into_iter().map(Into::into).collect());
+        self
+    }
+}
+
+fn denied() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::PermissionDenied,
+        "Modifying the environment is not permitted",
+    )
+}
+
+/// Get the value of an environment variable.
+///
+/// Returns `None` if the variable isn't set or if its value isn't valid
+/// UTF-8.
+///
+/// # Examples
+///
+/// ```rune
+/// assert!(env::var("PATH").is_some());
+/// assert_eq!(env::var("SURELY_THIS_VARIABLE_IS_NOT_SET"), None);
+/// ```
+#[rune::function(vm_result)]
+fn var(key: &str) -> Option<String> {
+    match std::env::var(key) {
+        Ok(value) => Some(String::try_from(value).vm?),
+        Err(..) => None,
+    }
+}
+
+/// Get all environment variables of the process as an object.
+///
+/// Variables whose name or value isn't valid UTF-8 are skipped.
+///
+/// # Examples
+///
+/// ```rune
+/// let vars = env::vars();
+/// assert_eq!(vars.get("PATH"), env::var("PATH"));
+/// ```
+#[rune::function(vm_result)]
+fn vars() -> Object {
+    let mut object = Object::new();
+
+    for (key, value) in std::env::vars_os() {
+        let (Some(key), Some(value)) = (key.to_str(), value.to_str()) else {
+            continue;
+        };
+
+        let value = rune::to_value(String::try_from(value).vm?).vm?;
+        object.insert(String::try_from(key).vm?, value).vm?;
+    }
+
+    object
+}
+
+/// Get the current working directory.
+///
+/// # Examples
+///
+/// ```rune
+/// let dir = env::current_dir()?;
+/// assert!(dir.is_absolute());
+/// ```
+#[rune::function]
+fn current_dir() -> io::Result<Path> {
+    Ok(Path::from(std::env::current_dir()?))
+}
+
+/// Get the directory used for temporary files.
+///
+/// # Examples
+///
+/// ```rune
+/// let dir = env::temp_dir();
+/// assert!(dir.is_absolute());
+/// ```
+#[rune::function]
+fn temp_dir() -> Path {
+    Path::from(std::env::temp_dir())
+}
@@ -26,18 +26,889 @@
 //!     println(`{file}`);
 //! }
 //! ```
+//!
+//! ## Capabilities
+//!
+//! The module installed through [`module`] can access the whole filesystem.
+//! Hosts which run untrusted scripts can restrict it to a set of root
+//! directories, and optionally to read-only access, by installing it through
+//! [`module_with_config`]:
+//!
+//! ```rust
+//! use rune_modules::fs::{self, Config};
+//!
+//! let config = Config::new().allow("data").read_only(true);
+//!
+//! let mut context = rune::Context::with_default_modules()?;
+//! context.install(fs::module_with_config(config)?)?;
+//! # Ok::<_, rune::support::Error>(())
+//! ```
+//!
+//! Operations on paths which are not permitted fail with an I/O error of the
+//! kind `PermissionDenied`. Paths are resolved, including symbolic links,
+//! before they are checked, so `..` components and links can't be used to
+//! escape an allowed root.
 
-use rune::{ContextError, Module};
 use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+
+use rune::alloc::fmt::TryWrite;
+use rune::runtime::{Bytes, Formatter, Mut, Value, VmResult};
+use rune::{docstring, vm_try, vm_write, Any, ContextError, Module};
 use tokio::fs;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader, BufWriter};
+
+use crate::bytes::to_bytes;
+use crate::path::PathArg;
 
-/// Construct the `fs` module.
+/// Asynchronous filesystem access.
+///
+/// All functions in this module are asynchronous and require the Tokio runtime
+/// to be in scope. Paths can be given either as strings or as [`Path`] values
+/// from the `path` module.
+///
+/// [`Path`]: path::Path
+///
+/// # Examples
+///
+/// ```rune,no_run
+/// fs::create_dir_all("out").await?;
+/// fs::write("out/hello.txt", "Hello World").await?;
+///
+/// let contents = fs::read_to_string("out/hello.txt").await?;
+/// assert_eq!(contents, "Hello World");
+/// ```
+#[rune::module(::fs)]
 pub fn module(_stdio: bool) -> Result<Module, ContextError> {
-    let mut module = Module::with_crate("fs")?;
-    module.function("read_to_string", read_to_string).build()?;
-    Ok(module)
+    module_with_config(Config::default())
+}
+
+/// Construct the `fs` module, restricting filesystem access to what is
+/// permitted by the given [`Config`].
+pub fn module_with_config(config: Config) -> Result<Module, ContextError> {
+    let mut m = Module::from_meta(self::module_meta)?;
+
+    let access = Arc::new(Access::new(config));
+
+    m.function("read", {
+        let access = access.clone();
+        move |path: PathArg| read(access.clone(), path)
+    })
+    .build()?
+    .docs(docstring! {
+        /// Reads the entire contents of a file into bytes.
+        ///
+        /// # Examples
+        ///
+        /// ```rune,no_run
+        /// let contents = fs::read("foo.txt").await?;
+        /// println!("{} bytes", contents.len());
+        /// ```
+    })?
+    .argument_types::<(PathArg,)>()?
+    .return_type::<io::Result<Bytes>>()?;
+
+    m.function("read_to_string", {
+        let access = access.clone();
+        move |path: PathArg| read_to_string(access.clone(), path)
+    })
+    .build()?
+    .docs(docstring! {
+        /// Reads the entire contents of a file into a string.
+        ///
+        /// Fails if the file doesn't contain valid UTF-8.
+        ///
+        /// # Examples
+        ///
+        /// ```rune,no_run
+        /// let contents = fs::read_to_string("foo.txt").await?;
+        /// println!("{contents}");
+        /// ```
+    })?
+    .argument_types::<(PathArg,)>()?
+    .return_type::<io::Result<String>>()?;
+
+    m.function("write", {
+        let access = access.clone();
+        move |path: PathArg, contents: Value| write(access.clone(), path, contents)
+    })
+    .build()?
+    .docs(docstring! {
+        /// Writes a string or bytes as the entire contents of a file.
+        ///
+        /// This function will create a file if it does not exist, and will
+        /// entirely replace its contents if it does.
+        ///
+        /// # Examples
+        ///
+        /// ```rune,no_run
+        /// fs::write("foo.txt", "Hello World").await?;
+        /// fs::write("bar.bin", b"\x00\x01").await?;
+        /// ```
+    })?
+    .argument_types::<(PathArg, Value)>()?
+    .return_type::<io::Result<()>>()?;
+
+    m.function("append", {
+        let access = access.clone();
+        move |path: PathArg, contents: Value| append(access.clone(), path, contents)
+    })
+    .build()?
+    .docs(docstring! {
+        /// Appends a string or bytes to the end of a file.
+        ///
+        /// The file is created if it does not exist.
+        ///
+        /// # Examples
+        ///
+        /// ```rune,no_run
+        /// fs::append("log.txt", "first line\n").await?;
+        /// fs::append("log.txt", "second line\n").await?;
+        /// ```
+    })?
+    .argument_types::<(PathArg, Value)>()?
+    .return_type::<io::Result<()>>()?;
+
+    m.function("create_dir_all", {
+        let access = access.clone();
+        move |path: PathArg| create_dir_all(access.clone(), path)
+    })
+    .build()?
+    .docs(docstring! {
+        /// Recursively creates a directory and all of its parent components
+        /// if they are missing.
+        ///
+        /// # Examples
+        ///
+        /// ```rune,no_run
+        /// fs::create_dir_all("some/nested/dir").await?;
+        /// ```
+    })?
+    .argument_types::<(PathArg,)>()?
+    .return_type::<io::Result<()>>()?;
+
+    m.function("remove_file", {
+        let access = access.clone();
+        move |path: PathArg| remove_file(access.clone(), path)
+    })
+    .build()?
+    .docs(docstring! {
+        /// Removes a file from the filesystem.
+        ///
+        /// # Examples
+        ///
+        /// ```rune,no_run
+        /// fs::remove_file("foo.txt").await?;
+        /// ```
+    })?
+    .argument_types::<(PathArg,)>()?
+    .return_type::<io::Result<()>>()?;
+
+    m.function("remove_dir_all", {
+        let access = access.clone();
+        move |path: PathArg| remove_dir_all(access.clone(), path)
+    })
+    .build()?
+    .docs(docstring! {
+        /// Removes a directory at this path, after removing all its contents.
+        ///
+        /// Use carefully!
+        ///
+        /// # Examples
+        ///
+        /// ```rune,no_run
+        /// fs::remove_dir_all("some/nested").await?;
+        /// ```
+    })?
+    .argument_types::<(PathArg,)>()?
+    .return_type::<io::Result<()>>()?;
+
+    m.function("rename", {
+        let access = access.clone();
+        move |from: PathArg, to: PathArg| rename(access.clone(), from, to)
+    })
+    .build()?
+    .docs(docstring! {
+        /// Renames a file or directory to a new name, replacing the original
+        /// file if `to` already exists.
+        ///
+        /// # Examples
+        ///
+        /// ```rune,no_run
+        /// fs::rename("a.txt", "b.txt").await?;
+        /// ```
+    })?
+    .argument_types::<(PathArg, PathArg)>()?
+    .return_type::<io::Result<()>>()?;
+
+    m.function("copy", {
+        let access = access.clone();
+        move |from: PathArg, to: PathArg| copy(access.clone(), from, to)
+    })
+    .build()?
+    .docs(docstring! {
+        /// Copies the contents of one file to another, returning the number of
+        /// bytes copied.
+        ///
+        /// This function will overwrite the contents of `to`.
+        ///
+        /// # Examples
+        ///
+        /// ```rune,no_run
+        /// let copied = fs::copy("foo.txt", "bar.txt").await?;
+        /// ```
+    })?
+    .argument_types::<(PathArg, PathArg)>()?
+    .return_type::<io::Result<u64>>()?;
+
+    m.function("metadata", {
+        let access = access.clone();
+        move |path: PathArg| metadata(access.clone(), path)
+    })
+    .build()?
+    .docs(docstring! {
+        /// Queries the file system metadata for a path.
+        ///
+        /// Symbolic links are followed.
+        ///
+        /// # Examples
+        ///
+        /// ```rune,no_run
+        /// let metadata = fs::metadata("foo.txt").await?;
+        ///
+        /// if metadata.is_file() {
+        ///     println!("{} bytes", metadata.len());
+        /// }
+        /// ```
+    })?
+    .argument_types::<(PathArg,)>()?
+    .return_type::<io::Result<Metadata>>()?;
+
+    m.function("read_dir", {
+        let access = access.clone();
+        move |path: PathArg| read_dir(access.clone(), path)
+    })
+    .build()?
+    .docs(docstring! {
+        /// Returns a stream over the entries within a directory.
+        ///
+        /// # Examples
+        ///
+        /// ```rune,no_run
+        /// let entries = fs::read_dir(".").await?;
+        ///
+        /// while let Some(entry) = entries.next().await {
+        ///     let entry = entry?;
+        ///     println!("{}", entry.path());
+        /// }
+        /// ```
+    })?
+    .argument_types::<(PathArg,)>()?
+    .return_type::<io::Result<ReadDir>>()?;
+
+    m.function("exists", {
+        let access = access.clone();
+        move |path: PathArg| exists(access.clone(), path)
+    })
+    .build()?
+    .docs(docstring! {
+        /// Returns `true` if the path points at an existing entity.
+        ///
+        /// Symbolic links are followed, so a broken link is reported as not
+        /// existing.
+        ///
+        /// # Examples
+        ///
+        /// ```rune,no_run
+        /// if !fs::exists("foo.txt").await? {
+        ///     fs::write("foo.txt", "").await?;
+        /// }
+        /// ```
+    })?
+    .argument_types::<(PathArg,)>()?
+    .return_type::<io::Result<bool>>()?;
+
+    m.ty::<File>()?;
+
+    m.function("open", {
+        let access = access.clone();
+        move |path: PathArg| File::open(access.clone(), path)
+    })
+    .build_associated::<File>()?
+    .docs(docstring! {
+        /// Opens a file in read-only mode.
+        ///
+        /// Reads from the file are buffered.
+        ///
+        /// # Examples
+        ///
+        /// ```rune,no_run
+        /// use fs::File;
+        ///
+        /// let file = File::open("foo.txt").await?;
+        /// let contents = file.read_to_string().await?;
+        /// ```
+    })?
+    .argument_types::<(PathArg,)>()?
+    .return_type::<io::Result<File>>()?;
+
+    m.function("create", {
+        let access = access.clone();
+        move |path: PathArg| File::create(access.clone(), path)
+    })
+    .build_associated::<File>()?
+    .docs(docstring! {
+        /// Opens a file in write-only mode.
+        ///
+        /// This function will create a file if it does not exist, and will
+        /// truncate it if it does. Writes to the file are buffered, so
+        /// [`flush`] must be called once all data has been written.
+        ///
+        /// [`flush`]: File::flush
+        ///
+        /// # Examples
+        ///
+        /// ```rune,no_run
+        /// use fs::File;
+        ///
+        /// let file = File::create("foo.txt").await?;
+        /// file.write("Hello World\n").await?;
+        /// file.flush().await?;
+        /// ```
+    })?
+    .argument_types::<(PathArg,)>()?
+    .return_type::<io::Result<File>>()?;
+
+    m.function_meta(File::read_to_string__meta)?;
+    m.function_meta(File::lines__meta)?;
+    m.function_meta(File::write__meta)?;
+    m.function_meta(File::flush__meta)?;
+    m.function_meta(File::debug_fmt__meta)?;
+
+    m.ty::<Lines>()?;
+    m.function_meta(Lines::next__meta)?;
+
+    m.ty::<ReadDir>()?;
+    m.function_meta(ReadDir::next__meta)?;
+
+    m.ty::<DirEntry>()?;
+    m.function_meta(DirEntry::path__meta)?;
+    m.function_meta(DirEntry::file_name__meta)?;
+    m.function_meta(DirEntry::metadata__meta)?;
+    m.function_meta(DirEntry::debug_fmt__meta)?;
+
+    m.ty::<Metadata>()?;
+    m.function_meta(Metadata::is_file__meta)?;
+    m.function_meta(Metadata::is_dir__meta)?;
+    m.function_meta(Metadata::is_symlink__meta)?;
+    m.function_meta(Metadata::len__meta)?;
+    m.function_meta(Metadata::is_readonly__meta)?;
+    m.function_meta(Metadata::debug_fmt__meta)?;
+    Ok(m)
+}
+
+/// The filesystem access granted to scripts.
+///
+/// By default, the whole filesystem can be read and written.
+///
+/// # Examples
+///
+/// ```rust
+/// use rune_modules::fs::{self, Config};
+///
+/// let config = Config::new().allow("/srv/data").allow("/tmp").read_only(true);
+///
+/// let mut context = rune::Context::with_default_modules()?;
+/// context.install(fs::module_with_config(config)?)?;
+/// # Ok::<_, rune::support::Error>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct Config {
+    roots: Option<Vec<PathBuf>>,
+    read_only: bool,
+}
+
+impl Config {
+    /// Construct a new configuration which grants full access.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allow access to the directory `root` and everything below it.
+    ///
+    /// Once a root has been allowed, paths outside of all allowed roots can no
+    /// longer be accessed. Relative roots are resolved against the current
+    /// directory when the module is constructed.
+    pub fn allow(mut self, root: impl Into<PathBuf>) -> Self {
+        self.roots.get_or_insert_with(Vec::new).push(root.into());
+        self
+    }
+
+    /// Set if the filesystem should only be accessible for reading.
+    ///
+    /// Defaults to `false`.
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+}
+
+/// The resolved access rules of a [`Config`].
+struct Access {
+    roots: Option<Vec<PathBuf>>,
+    read_only: bool,
+}
+
+impl Access {
+    fn new(config: Config) -> Self {
+        let roots = config.roots.map(|roots| {
+            roots
+                .into_iter()
+                .map(|root| resolve(&root).unwrap_or(root))
+                .collect()
+        });
+
+        Self {
+            roots,
+            read_only: config.read_only,
+        }
+    }
+
+    /// Check that `path` may be read, returning the path to access.
+    ///
+    /// When access is restricted the returned path is the resolved one which
+    /// was checked, so that the path can't be swapped out for a symbolic link
+    /// between the check and its use.
+    fn read(&self, path: impl AsRef<Path>) -> io::Result<PathBuf> {
+        self.check(path.as_ref(), resolve)
+    }
+
+    /// Check that `path` may be written, returning the path to access.
+    fn write(&self, path: impl AsRef<Path>) -> io::Result<PathBuf> {
+        self.check_writable(path.as_ref())?;
+        self.check(path.as_ref(), resolve)
+    }
+
+    /// Check that the directory entry at `path` may be modified, returning
+    /// the path to access.
+    ///
+    /// In contrast to [`Access::write`], a symbolic link in the last component
+    /// is not followed, so that removing or renaming a link affects the link
+    /// and not what it points to.
+    fn write_entry(&self, path: impl AsRef<Path>) -> io::Result<PathBuf> {
+        self.check_writable(path.as_ref())?;
+        self.check(path.as_ref(), resolve_entry)
+    }
+
+    fn check_writable(&self, path: &Path) -> io::Result<()> {
+        if self.read_only {
+            return Err(denied(path, "the filesystem is read-only"));
+        }
+
+        Ok(())
+    }
+
+    fn check(&self, path: &Path, resolve: fn(&Path) -> io::Result<PathBuf>) -> io::Result<PathBuf> {
+        let Some(roots) = &self.roots else {
+            return Ok(path.to_path_buf());
+        };
+
+        let resolved = resolve(path)?;
+
+        if !roots.iter().any(|root| resolved.starts_with(root)) {
+            return Err(denied(path, "path is outside of the allowed directories"));
+        }
+
+        Ok(resolved)
+    }
+}
+
+fn denied(path: &Path, reason: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::PermissionDenied,
+        format!("Access to `{}` denied: {reason}", path.display()),
+    )
+}
+
+/// Resolve `path` into an absolute path with all symbolic links and `..`
+/// components resolved.
+///
+/// Components which do not exist yet are appended to the deepest ancestor
+/// that exists.
+fn resolve(path: &Path) -> io::Result<PathBuf> {
+    let path = std::env::current_dir()?.join(path);
+
+    let mut existing = path.as_path();
+    let mut missing = Vec::new();
+
+    let mut resolved = loop {
+        match std::fs::canonicalize(existing) {
+            Ok(resolved) => break resolved,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                let (Some(parent), Some(name)) =
+                    (existing.parent(), existing.components().next_back())
+                else {
+                    return Err(error);
+                };
+
+                missing.push(name);
+                existing = parent;
+            }
+            Err(error) => return Err(error),
+        }
+    };
+
+    for component in missing.into_iter().rev() {
+        match component {
+            Component::ParentDir => {
+                resolved.pop();
+            }
+            Component::CurDir => {}
+            component => {
+                resolved.push(component);
+            }
+        }
+    }
+
+    Ok(resolved)
+}
+
+/// Resolve `path` like [`resolve`], except that a symbolic link in the last
+/// component is left as is.
+fn resolve_entry(path: &Path) -> io::Result<PathBuf> {
+    let path = std::env::current_dir()?.join(path);
+
+    match (path.parent(), path.file_name()) {
+        (Some(parent), Some(name)) => Ok(resolve(parent)?.join(name)),
+        _ => resolve(&path),
+    }
+}
+
+async fn read(access: Arc<Access>, path: PathArg) -> io::Result<Bytes> {
+    let data = fs::read(access.read(&path)?).await?;
+    Bytes::try_from(data).map_err(io::Error::other)
 }
 
-async fn read_to_string(path: String) -> io::Result<String> {
-    fs::read_to_string(&path).await
+async fn read_to_string(access: Arc<Access>, path: PathArg) -> io::Result<String> {
+    fs::read_to_string(access.read(&path)?).await
+}
+
+async fn write(access: Arc<Access>, path: PathArg, contents: Value) -> VmResult<io::Result<()>> {
+    let contents = vm_try!(to_bytes(&contents));
+    VmResult::Ok(async { fs::write(access.write(&path)?, contents).await }.await)
+}
+
+async fn append(access: Arc<Access>, path: PathArg, contents: Value) -> VmResult<io::Result<()>> {
+    let contents = vm_try!(to_bytes(&contents));
+
+    VmResult::Ok(
+        async {
+            let mut file = fs::OpenOptions::new()
+                .append(true)
+                .create(true)
+                .open(access.write(&path)?)
+                .await?;
+
+            file.write_all(&contents).await?;
+            file.flush().await
+        }
+        .await,
+    )
+}
+
+async fn create_dir_all(access: Arc<Access>, path: PathArg) -> io::Result<()> {
+    fs::create_dir_all(access.write(&path)?).await
+}
+
+async fn remove_file(access: Arc<Access>, path: PathArg) -> io::Result<()> {
+    fs::remove_file(access.write_entry(&path)?).await
+}
+
+async fn remove_dir_all(access: Arc<Access>, path: PathArg) -> io::Result<()> {
+    fs::remove_dir_all(access.write_entry(&path)?).await
+}
+
+async fn rename(access: Arc<Access>, from: PathArg, to: PathArg) -> io::Result<()> {
+    fs::rename(access.write_entry(&from)?, access.write_entry(&to)?).await
+}
+
+async fn copy(access: Arc<Access>, from: PathArg, to: PathArg) -> io::Result<u64> {
+    fs::copy(access.read(&from)?, access.write(&to)?).await
+}
+
+async fn metadata(access: Arc<Access>, path: PathArg) -> io::Result<Metadata> {
+    let inner = fs::metadata(access.read(&path)?).await?;
+    Ok(Metadata { inner })
+}
+
+async fn read_dir(access: Arc<Access>, path: PathArg) -> io::Result<ReadDir> {
+    let inner = fs::read_dir(access.read(&path)?).await?;
+    Ok(ReadDir { inner })
+}
+
+async fn exists(access: Arc<Access>, path: PathArg) -> io::Result<bool> {
+    fs::try_exists(access.read(&path)?).await
+}
+
+/// A handle to an open file.
+///
+/// Files are opened either for reading through [`File::open`] or for writing
+/// through [`File::create`], and all I/O is buffered.
+#[derive(Any)]
+#[rune(item = ::fs)]
+pub struct File {
+    inner: FileInner,
+}
+
+enum FileInner {
+    Read(BufReader<fs::File>),
+    Write(BufWriter<fs::File>),
+}
+
+impl File {
+    async fn open(access: Arc<Access>, path: PathArg) -> io::Result<Self> {
+        let file = fs::File::open(access.read(&path)?).await?;
+
+        Ok(Self {
+            inner: FileInner::Read(BufReader::new(file)),
+        })
+    }
+
+    async fn create(access: Arc<Access>, path: PathArg) -> io::Result<Self> {
+        let file = fs::File::create(access.write(&path)?).await?;
+
+        Ok(Self {
+            inner: FileInner::Write(BufWriter::new(file)),
+        })
+    }
+
+    /// Reads the remaining contents of the file into a string.
+    ///
+    /// # Examples
+    ///
+    /// ```rune,no_run
+    /// use fs::File;
+    ///
+    /// let file = File::open("foo.txt").await?;
+    /// let contents = file.read_to_string().await?;
+    /// ```
+    #[rune::function(keep, instance, path = Self::read_to_string)]
+    async fn read_to_string(mut this: Mut<Self>) -> io::Result<String> {
+        let FileInner::Read(reader) = &mut this.inner else {
+            return Err(not_readable());
+        };
+
+        let mut string = String::new();
+        reader.read_to_string(&mut string).await?;
+        Ok(string)
+    }
+
+    /// Consumes the file and returns a stream over its lines.
+    ///
+    /// Line terminators are not included in the lines returned by the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```rune,no_run
+    /// use fs::File;
+    ///
+    /// let lines = File::open("foo.txt").await?.lines()?;
+    ///
+    /// while let Some(line) = lines.next().await {
+    ///     println!("{}", line?);
+    /// }
+    /// ```
+    #[rune::function(keep, instance)]
+    fn lines(self) -> io::Result<Lines> {
+        let FileInner::Read(reader) = self.inner else {
+            return Err(not_readable());
+        };
+
+        Ok(Lines {
+            inner: reader.lines(),
+        })
+    }
+
+    /// Writes a string or bytes to the file.
+    ///
+    /// # Examples
+    ///
+    /// ```rune,no_run
+    /// use fs::File;
+    ///
+    /// let file = File::create("foo.txt").await?;
+    /// file.write("Hello").await?;
+    /// file.write(b" World").await?;
+    /// file.flush().await?;
+    /// ```
+    #[rune::function(keep, instance, path = Self::write)]
+    async fn write(mut this: Mut<Self>, contents: Value) -> VmResult<io::Result<()>> {
+        let contents = vm_try!(to_bytes(&contents));
+
+        let FileInner::Write(writer) = &mut this.inner else {
+            return VmResult::Ok(Err(not_writable()));
+        };
+
+        VmResult::Ok(writer.write_all(&contents).await)
+    }
+
+    /// Flushes buffered writes to the file.
+    ///
+    /// Data which has not been flushed when the file is dropped is lost.
+    ///
+    /// # Examples
+    ///
+    /// ```rune,no_run
+    /// use fs::File;
+    ///
+    /// let file = File::create("foo.txt").await?;
+    /// file.write("Hello World").await?;
+    /// file.flush().await?;
+    /// ```
+    #[rune::function(keep, instance, path = Self::flush)]
+    async fn flush(mut this: Mut<Self>) -> io::Result<()> {
+        match &mut this.inner {
+            FileInner::Read(..) => Ok(()),
+            FileInner::Write(writer) => writer.flush().await,
+        }
+    }
+
+    #[rune::function(keep, protocol = DEBUG_FMT)]
+    fn debug_fmt(&self, f: &mut Formatter) -> VmResult<()> {
+        match &self.inner {
+            FileInner::Read(reader) => vm_write!(f, "{:?}", reader.get_ref()),
+            FileInner::Write(writer) => vm_write!(f, "{:?}", writer.get_ref()),
+        }
+    }
+}
+
+fn not_readable() -> io::Error {
+    io::Error::new(io::ErrorKind::Unsupported, "File is not open for reading")
+}
+
+fn not_writable() -> io::Error {
+    io::Error::new(io::ErrorKind::Unsupported, "File is not open for writing")
+}
+
+/// A stream over the lines of a [`File`].
+#[derive(Any)]
+#[rune(item = ::fs)]
+pub struct Lines {
+    inner: tokio::io::Lines<BufReader<fs::File>>,
+}
+
+impl Lines {
+    /// Returns the next line, or `None` once the end of the file is reached.
+    #[rune::function(keep, instance, path = Self::next)]
+    async fn next(mut this: Mut<Self>) -> Option<io::Result<String>> {
+        this.inner.next_line().await.transpose()
+    }
+}
+
+/// A stream over the entries of a directory.
+#[derive(Any)]
+#[rune(item = ::fs)]
+pub struct ReadDir {
+    inner: fs::ReadDir,
+}
+
+impl ReadDir {
+    /// Returns the next entry, or `None` once all entries have been read.
+    ///
+    /// # Examples
+    ///
+    /// ```rune,no_run
+    /// let entries = fs::read_dir(".").await?;
+    ///
+    /// while let Some(entry) = entries.next().await {
+    ///     println!("{}", entry?.file_name());
+    /// }
+    /// ```
+    #[rune::function(keep, instance, path = Self::next)]
+    async fn next(mut this: Mut<Self>) -> Option<io::Result<DirEntry>> {
+        let entry = this.inner.next_entry().await.transpose()?;
+        Some(entry.map(|inner| DirEntry { inner }))
+    }
+}
+
+/// An entry returned by [`ReadDir`].
+#[derive(Any)]
+#[rune(item = ::fs)]
+pub struct DirEntry {
+    inner: fs::DirEntry,
+}
+
+impl DirEntry {
+    /// Returns the full path to the file that this entry represents.
+    #[rune::function(keep, instance)]
+    fn path(&self) -> crate::path::Path {
+        crate::path::Path::from(self.inner.path())
+    }
+
+    /// Returns the bare file name of this entry without any other leading
+    /// path component.
+    #[rune::function(keep, instance)]
+    fn file_name(&self) -> String {
+        self.inner.file_name().to_string_lossy().into_owned()
+    }
+
+    /// Returns the metadata for the file that this entry points at.
+    ///
+    /// Symbolic links are not followed.
+    #[rune::function(keep, instance, path = Self::metadata)]
+    async fn metadata(this: Mut<Self>) -> io::Result<Metadata> {
+        let inner = this.inner.metadata().await?;
+        Ok(Metadata { inner })
+    }
+
+    #[rune::function(keep, protocol = DEBUG_FMT)]
+    fn debug_fmt(&self, f: &mut Formatter) -> VmResult<()> {
+        vm_write!(f, "{:?}", self.inner)
+    }
+}
+
+/// Metadata information about a file.
+#[derive(Any)]
+#[rune(item = ::fs)]
+pub struct Metadata {
+    inner: std::fs::Metadata,
+}
+
+impl Metadata {
+    /// Returns `true` if this metadata is for a regular file.
+    #[rune::function(keep, instance)]
+    fn is_file(&self) -> bool {
+        self.inner.is_file()
+    }
+
+    /// Returns `true` if this metadata is for a directory.
+    #[rune::function(keep, instance)]
+    fn is_dir(&self) -> bool {
+        self.inner.is_dir()
+    }
+
+    /// Returns `true` if this metadata is for a symbolic link.
+    #[rune::function(keep, instance)]
+    fn is_symlink(&self) -> bool {
+        self.inner.is_symlink()
+    }
+
+    /// Returns the size of the file, in bytes, this metadata is for.
+    #[rune::function(keep, instance)]
+    fn len(&self) -> u64 {
+        self.inner.len()
+    }
+
+    /// Returns `true` if the file this metadata is for is read-only.
+    #[rune::function(keep, instance)]
+    fn is_readonly(&self) -> bool {
+        self.inner.permissions().readonly()
+    }
+
+    #[rune::function(keep, protocol = DEBUG_FMT)]
+    fn debug_fmt(&self, f: &mut Formatter) -> VmResult<()> {
+        vm_write!(f, "{:?}", self.inner)
+    }
 }
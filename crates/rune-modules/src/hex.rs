@@ -0,0 +1,84 @@
+//! Hexadecimal encoding shared by the `digest` and `encoding` modules, along
+//! with how they accept either bytes or strings as input.
+
+use rune::alloc::{String, Vec};
+use rune::runtime::{Bytes, Value, VmError, VmResult};
+use rune::{vm_try, TypeHash};
+
+/// Lowercase hexadecimal digits.
+pub(crate) const LOWER: &[u8; 16] = b"0123456789abcdef";
+
+/// Uppercase hexadecimal digits.
+pub(crate) const UPPER: &[u8; 16] = b"0123456789ABCDEF";
+
+/// Why hexadecimal input couldn't be decoded.
+pub(crate) enum DecodeError {
+    /// The character at the given position isn't a hexadecimal digit.
+    Invalid(usize),
+    /// The input has an odd length, so the digit at the given position is
+    /// missing.
+    Missing(usize),
+}
+
+/// Access the bytes of a value which is either `Bytes` or a `String`.
+pub(crate) fn with_data<T>(data: &Value, f: impl FnOnce(&[u8]) -> T) -> VmResult<T> {
+    match data.type_hash() {
+        Bytes::HASH => {
+            let bytes = vm_try!(data.borrow_ref::<Bytes>());
+            VmResult::Ok(f(bytes.as_slice()))
+        }
+        String::HASH => {
+            let string = vm_try!(data.borrow_string_ref());
+            VmResult::Ok(f(string.as_bytes()))
+        }
+        _ => VmResult::err(VmError::expected::<Bytes>(data.type_info())),
+    }
+}
+
+/// Encode `bytes` using the given set of `digits`.
+pub(crate) fn encode(bytes: &[u8], digits: &[u8; 16]) -> VmResult<String> {
+    let mut string = vm_try!(String::try_with_capacity(bytes.len() * 2));
+
+    for &b in bytes {
+        vm_try!(string.try_push(digits[usize::from(b >> 4)] as char));
+        vm_try!(string.try_push(digits[usize::from(b & 0xf)] as char));
+    }
+
+    VmResult::Ok(string)
+}
+
+/// Decode a hexadecimal string, accepting both lower and upper case digits.
+pub(crate) fn decode(string: &str) -> VmResult<Result<Vec<u8>, DecodeError>> {
+    let input = string.as_bytes();
+    let mut bytes = vm_try!(Vec::try_with_capacity(input.len() / 2));
+
+    for (n, pair) in input.chunks(2).enumerate() {
+        let position = n * 2;
+
+        let Some(hi) = digit(pair[0]) else {
+            return VmResult::Ok(Err(DecodeError::Invalid(position)));
+        };
+
+        let Some(&lo) = pair.get(1) else {
+            return VmResult::Ok(Err(DecodeError::Missing(position + 1)));
+        };
+
+        let Some(lo) = digit(lo) else {
+            return VmResult::Ok(Err(DecodeError::Invalid(position + 1)));
+        };
+
+        vm_try!(bytes.try_push((hi << 4) | lo));
+    }
+
+    VmResult::Ok(Ok(bytes))
+}
+
+/// The value of a single hexadecimal digit.
+pub(crate) fn digit(b: u8) -> Option<u8> {
+    match b {
+        b'0'..=b'9' => Some(b - b'0'),
+        b'a'..=b'f' => Some(b - b'a' + 10),
+        b'A'..=b'F' => Some(b - b'A' + 10),
+        _ => None,
+    }
+}
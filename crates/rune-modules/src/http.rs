@@ -19,6 +19,10 @@
 //! # Ok::<_, rune::support::Error>(())
 //! ```
 //!
+//! Timeouts are specified using `time::Duration`, so they are only available
+//! with the `time` feature enabled, and scripts which use them also need the
+//! [`time`][crate::time] module to be installed.
+//!
 //! Use it in Rune:
 //!
 //! ```rust,ignore
@@ -53,8 +57,11 @@ use core::hash::Hash;
 
 use rune::alloc::fmt::TryWrite;
 use rune::alloc::prelude::*;
-use rune::runtime::{Bytes, Formatter, Hasher, Ref, VmResult};
-use rune::{docstring, item, Any, ContextError, Module, ToConstValue, Value};
+use rune::runtime::{self, Bytes, Formatter, Hasher, Object, Ref, VmResult};
+use rune::{docstring, item, vm_try, Any, ContextError, Module, ToConstValue, TypeHash, Value};
+
+#[cfg(feature = "time")]
+use crate::time::Duration;
 
 /// A simple HTTP module for Rune.
 ///
@@ -73,12 +80,30 @@ pub fn module(_stdio: bool) -> Result<Module, ContextError> {
 
     module.ty::<Client>()?;
     module.function_meta(Client::new__meta)?;
+    module.function_meta(Client::builder__meta)?;
     module.function_meta(Client::get__meta)?;
     module.function_meta(Client::post__meta)?;
     module.function_meta(Client::put__meta)?;
+    module.function_meta(Client::patch__meta)?;
     module.function_meta(Client::delete__meta)?;
     module.function_meta(Client::head__meta)?;
 
+    module.ty::<ClientBuilder>()?;
+    module.function_meta(ClientBuilder::build__meta)?;
+    #[cfg(feature = "time")]
+    module.function_meta(ClientBuilder::timeout__meta)?;
+    #[cfg(feature = "time")]
+    module.function_meta(ClientBuilder::connect_timeout__meta)?;
+    module.function_meta(ClientBuilder::user_agent__meta)?;
+    module.function_meta(ClientBuilder::default_headers__meta)?;
+    module.function_meta(ClientBuilder::max_redirects__meta)?;
+    module.function_meta(ClientBuilder::no_redirects__meta)?;
+    module.function_meta(ClientBuilder::proxy__meta)?;
+    module.function_meta(ClientBuilder::no_proxy__meta)?;
+    module.function_meta(ClientBuilder::https_only__meta)?;
+    module.function_meta(ClientBuilder::danger_accept_invalid_certs__meta)?;
+    module.function_meta(ClientBuilder::gzip__meta)?;
+
     module.ty::<Response>()?;
     module.function_meta(Response::text__meta)?;
     module.function_meta(Response::json__meta)?;
@@ -86,6 +111,10 @@ pub fn module(_stdio: bool) -> Result<Module, ContextError> {
     module.function_meta(Response::status__meta)?;
     module.function_meta(Response::version__meta)?;
     module.function_meta(Response::content_length__meta)?;
+    module.function_meta(Response::url__meta)?;
+    module.function_meta(Response::header__meta)?;
+    module.function_meta(Response::header_all__meta)?;
+    module.function_meta(Response::headers__meta)?;
 
     module.ty::<RequestBuilder>()?;
     module.function_meta(RequestBuilder::send__meta)?;
@@ -94,6 +123,22 @@ pub fn module(_stdio: bool) -> Result<Module, ContextError> {
     module.function_meta(RequestBuilder::bearer_auth__meta)?;
     module.function_meta(RequestBuilder::fetch_mode_no_cors__meta)?;
     module.function_meta(RequestBuilder::body_bytes__meta)?;
+    #[cfg(feature = "time")]
+    module.function_meta(RequestBuilder::timeout__meta)?;
+    module.function_meta(RequestBuilder::query__meta)?;
+    module.function_meta(RequestBuilder::form__meta)?;
+    module.function_meta(RequestBuilder::multipart__meta)?;
+
+    module.ty::<Form>()?;
+    module.function_meta(Form::new__meta)?;
+    module.function_meta(Form::text__meta)?;
+    module.function_meta(Form::part__meta)?;
+
+    module.ty::<Part>()?;
+    module.function_meta(Part::text__meta)?;
+    module.function_meta(Part::bytes__meta)?;
+    module.function_meta(Part::file_name__meta)?;
+    module.function_meta(Part::mime_str__meta)?;
 
     module.ty::<StatusCode>()?;
     module.function_meta(StatusCode::as_u16__meta)?;
@@ -1430,6 +1475,10 @@ pub fn module(_stdio: bool) -> Result<Module, ContextError> {
 
     module.ty::<Error>()?;
     module.function_meta(Error::display_fmt__meta)?;
+    module.function_meta(Error::is_timeout__meta)?;
+    module.function_meta(Error::is_connect__meta)?;
+    module.function_meta(Error::is_redirect__meta)?;
+    module.function_meta(Error::status__meta)?;
     Ok(module)
 }
 
@@ -1437,20 +1486,85 @@ pub fn module(_stdio: bool) -> Result<Module, ContextError> {
 #[derive(Debug, Any)]
 #[rune(item = ::http)]
 pub struct Error {
-    inner: reqwest::Error,
+    inner: ErrorKind,
+}
+
+#[derive(Debug)]
+enum ErrorKind {
+    Reqwest(reqwest::Error),
+    Message(std::string::String),
 }
 
 impl From<reqwest::Error> for Error {
-    fn from(inner: reqwest::Error) -> Self {
-        Self { inner }
+    fn from(error: reqwest::Error) -> Self {
+        Self {
+            inner: ErrorKind::Reqwest(error),
+        }
     }
 }
 
 impl Error {
+    fn message(message: impl core::fmt::Display) -> Self {
+        Self {
+            inner: ErrorKind::Message(message.to_string()),
+        }
+    }
+
+    fn reqwest(&self) -> Option<&reqwest::Error> {
+        match &self.inner {
+            ErrorKind::Reqwest(error) => Some(error),
+            ErrorKind::Message(..) => None,
+        }
+    }
+
     /// Write a display representation the error.
     #[rune::function(keep, instance, protocol = DISPLAY_FMT)]
     fn display_fmt(&self, f: &mut Formatter) -> VmResult<()> {
-        rune::vm_write!(f, "{}", self.inner)
+        match &self.inner {
+            ErrorKind::Reqwest(error) => rune::vm_write!(f, "{error}"),
+            ErrorKind::Message(message) => rune::vm_write!(f, "{message}"),
+        }
+    }
+
+    /// Test if the error is caused by a timeout.
+    ///
+    /// ```rune,no_run
+    /// use time::Duration;
+    ///
+    /// let client = http::Client::new();
+    ///
+    /// let result = client.get("http://example.com")
+    ///     .timeout(Duration::from_millis(1))
+    ///     .send()
+    ///     .await;
+    ///
+    /// if let Err(error) = result {
+    ///     assert!(error.is_timeout());
+    /// }
+    /// ```
+    #[rune::function(keep, instance)]
+    fn is_timeout(&self) -> bool {
+        self.reqwest().is_some_and(reqwest::Error::is_timeout)
+    }
+
+    /// Test if the error is related to connecting to the server.
+    #[rune::function(keep, instance)]
+    fn is_connect(&self) -> bool {
+        self.reqwest().is_some_and(reqwest::Error::is_connect)
+    }
+
+    /// Test if the error is caused by the redirect policy of the client, like
+    /// when too many redirects are followed.
+    #[rune::function(keep, instance)]
+    fn is_redirect(&self) -> bool {
+        self.reqwest().is_some_and(reqwest::Error::is_redirect)
+    }
+
+    /// Get the status code of the response which caused the error, if any.
+    #[rune::function(keep, instance)]
+    fn status(&self) -> Option<StatusCode> {
+        let inner = self.reqwest()?.status()?;
+        Some(StatusCode { inner })
     }
 }
 
@@ -1556,6 +1670,102 @@ impl Response {
     fn content_length(&self) -> Option<u64> {
         self.response.content_length()
     }
+
+    /// Get the final url of the response, after any redirects have been
+    /// followed.
+    ///
+    /// ```rune,no_run
+    /// let response = http::get("http://example.com").await?;
+    /// assert_eq!(response.url(), "http://example.com/");
+    /// ```
+    #[rune::function(keep, instance, vm_result)]
+    fn url(&self) -> String {
+        String::try_from(self.response.url().as_str()).vm?
+    }
+
+    /// Get the first value of the header with the given name, if it's
+    /// present.
+    ///
+    /// Header names are case-insensitive, and values which aren't valid UTF-8
+    /// are decoded lossily.
+    ///
+    /// ```rune,no_run
+    /// let response = http::get("http://example.com").await?;
+    /// let content_type = response.header("Content-Type");
+    /// ```
+    #[rune::function(keep, instance, vm_result)]
+    fn header(&self, name: &str) -> Option<String> {
+        match self.response.headers().get(name) {
+            Some(value) => Some(header_value(value).vm?),
+            None => None,
+        }
+    }
+
+    /// Get all values of the header with the given name, in the order they
+    /// were received.
+    ///
+    /// ```rune,no_run
+    /// let response = http::get("http://example.com").await?;
+    ///
+    /// for cookie in response.header_all("Set-Cookie")? {
+    ///     println!("{cookie}");
+    /// }
+    /// ```
+    #[rune::function(keep, instance, vm_result)]
+    fn header_all(&self, name: &str) -> Vec<String> {
+        let mut values = Vec::new();
+
+        for value in self.response.headers().get_all(name) {
+            values.try_push(header_value(value).vm?).vm?;
+        }
+
+        values
+    }
+
+    /// Get all headers of the response as an object, where the names of
+    /// headers are lowercase.
+    ///
+    /// Headers which are present multiple times have their values joined by
+    /// `, `. Use [`header_all`] to get them individually.
+    ///
+    /// [`header_all`]: Self::header_all
+    ///
+    /// ```rune,no_run
+    /// let response = http::get("http://example.com").await?;
+    /// let headers = response.headers();
+    ///
+    /// for (name, value) in headers {
+    ///     println!("{name}: {value}");
+    /// }
+    /// ```
+    #[rune::function(keep, instance, vm_result)]
+    fn headers(&self) -> Object {
+        let headers = self.response.headers();
+        let mut object = Object::new();
+
+        for name in headers.keys() {
+            let mut value = String::new();
+
+            for (index, part) in headers.get_all(name).into_iter().enumerate() {
+                if index > 0 {
+                    value.try_push_str(", ").vm?;
+                }
+
+                value.try_push_str(&header_value(part).vm?).vm?;
+            }
+
+            let value = rune::to_value(value).vm?;
+            object
+                .insert(String::try_from(name.as_str()).vm?, value)
+                .vm?;
+        }
+
+        object
+    }
+}
+
+fn header_value(value: &reqwest::header::HeaderValue) -> Result<String, rune::alloc::Error> {
+    String::try_from(std::string::String::from_utf8_lossy(value.as_bytes()).as_ref())
 }
 
 /// An HTTP status code.
@@ -2136,6 +2346,501 @@ impl RequestBuilder {
             request: self.request.body(bytes.into_std()),
         }
     }
+
+    /// Set a timeout for the request, which applies from when the request
+    /// starts connecting until the response body has finished.
+    ///
+    /// This overrides any timeout configured on the [`Client`].
+    ///
+    /// ```rune,no_run
+    /// use time::Duration;
+    ///
+    /// let client = http::Client::new();
+    ///
+    /// let response = client.get("http://example.com")
+    ///     .timeout(Duration::from_secs(5))
+    ///     .send()
+    ///     .await?;
+    /// ```
+    #[rune::function(keep)]
+    #[cfg(feature = "time")]
+    fn timeout(self, timeout: Duration) -> Self {
+        Self {
+            request: self.request.timeout(timeout.into_std()),
+        }
+    }
+
+    /// Append query parameters from an object to the url of the request.
+    ///
+    /// Values can be strings, numbers, booleans, or vectors of them in which
+    /// case the parameter is repeated for each value.
+    ///
+    /// ```rune,no_run
+    /// let client = http::Client::new();
+    ///
+    /// // Requests `http://example.com/search?q=rune&page=2&tag=a&tag=b`.
+    /// let response = client.get("http://example.com/search")
+    ///     .query(#{"q": "rune", "page": 2, "tag": ["a", "b"]})
+    ///     .send()
+    ///     .await?;
+    /// ```
+    #[rune::function(keep)]
+    fn query(self, query: &Object) -> VmResult<Self> {
+        let params = vm_try!(to_params(query));
+
+        VmResult::Ok(Self {
+            request: self.request.query(&params),
+        })
+    }
+
+    /// Set the body of the request to the fields of an object, encoded as
+    /// `application/x-www-form-urlencoded`.
+    ///
+    /// Values follow the same rules as for [`query`].
+    ///
+    /// [`query`]: Self::query
+    ///
+    /// ```rune,no_run
+    /// let client = http::Client::new();
+    ///
+    /// let response = client.post("http://example.com/login")
+    ///     .form(#{"username": "admin", "remember": true})
+    ///     .send()
+    ///     .await?;
+    /// ```
+    #[rune::function(keep)]
+    fn form(self, form: &Object) -> VmResult<Self> {
+        let params = vm_try!(to_params(form));
+
+        VmResult::Ok(Self {
+            request: self.request.form(&params),
+        })
+    }
+
+    /// Set the body of the request to a multipart [`Form`].
+    ///
+    /// ```rune,no_run
+    /// use http::multipart::{Form, Part};
+    ///
+    /// let client = http::Client::new();
+    ///
+    /// let form = Form::new()
+    ///     .text("description", "A small file")
+    ///     .part("file", Part::bytes(b"Hello World").file_name("hello.txt"));
+    ///
+    /// let response = client.post("http://example.com/upload")
+    ///     .multipart(form)
+    ///     .send()
+    ///     .await?;
+    /// ```
+    #[rune::function(keep)]
+    fn multipart(self, form: Form) -> Self {
+        Self {
+            request: self.request.multipart(form.inner),
+        }
+    }
+}
+
+/// Convert the fields of an object into parameters for a url-encoded query
+/// or form.
+fn to_params(object: &Object) -> VmResult<std::vec::Vec<(&str, std::string::String)>> {
+    let mut params = std::vec::Vec::new();
+
+    for (key, value) in object.iter() {
+        if value.type_hash() == runtime::Vec::HASH {
+            for value in vm_try!(value.borrow_ref::<runtime::Vec>()).iter() {
+                params.push((key.as_str(), vm_try!(to_param(value))));
+            }
+        } else {
+            params.push((key.as_str(), vm_try!(to_param(value))));
+        }
+    }
+
+    VmResult::Ok(params)
+}
+
+fn to_param(value: &Value) -> VmResult<std::string::String> {
+    let param = match value.type_hash() {
+        String::HASH => vm_try!(value.borrow_string_ref()).to_string(),
+        i64::HASH => vm_try!(rune::from_value::<i64>(value)).to_string(),
+        u64::HASH => vm_try!(rune::from_value::<u64>(value)).to_string(),
+        f64::HASH => vm_try!(rune::from_value::<f64>(value)).to_string(),
+        bool::HASH => vm_try!(rune::from_value::<bool>(value)).to_string(),
+        _ => return VmResult::expected::<String>(value.type_info()),
+    };
+
+    VmResult::Ok(param)
+}
+
+/// A multipart form, for use with [`RequestBuilder::multipart`].
+///
+/// ```rune,no_run
+/// use http::multipart::Form;
+///
+/// let form = Form::new()
+///     .text("name", "rune")
+///     .text("kind", "language");
+/// ```
+#[derive(Debug, Any)]
+#[rune(item = ::http::multipart)]
+pub struct Form {
+    inner: reqwest::multipart::Form,
+}
+
+impl Form {
+    /// Construct a new empty form.
+    #[rune::function(keep, path = Self::new)]
+    fn new() -> Self {
+        Self {
+            inner: reqwest::multipart::Form::new(),
+        }
+    }
+
+    /// Add a text field to the form.
+    ///
+    /// ```rune,no_run
+    /// use http::multipart::Form;
+    ///
+    /// let form = Form::new().text("username", "admin");
+    /// ```
+    #[rune::function(keep)]
+    fn text(self, name: &str, value: &str) -> Self {
+        Self {
+            inner: self.inner.text(name.to_owned(), value.to_owned()),
+        }
+    }
+
+    /// Add a custom [`Part`] to the form.
+    ///
+    /// ```rune,no_run
+    /// use http::multipart::{Form, Part};
+    ///
+    /// let part = Part::text("{}").mime_str("application/json")?;
+    /// let form = Form::new().part("metadata", part);
+    /// ```
+    #[rune::function(keep)]
+    fn part(self, name: &str, part: Part) -> Self {
+        Self {
+            inner: self.inner.part(name.to_owned(), part.inner),
+        }
+    }
+}
+
+/// A field in a multipart [`Form`].
+#[derive(Debug, Any)]
+#[rune(item = ::http::multipart)]
+pub struct Part {
+    inner: reqwest::multipart::Part,
+}
+
+impl Part {
+    /// Construct a new part from text.
+    ///
+    /// ```rune,no_run
+    /// use http::multipart::Part;
+    ///
+    /// let part = Part::text("Hello World");
+    /// ```
+    #[rune::function(keep, path = Self::text)]
+    fn text(value: &str) -> Self {
+        Self {
+            inner: reqwest::multipart::Part::text(value.to_owned()),
+        }
+    }
+
+    /// Construct a new part from bytes.
+    ///
+    /// ```rune,no_run
+    /// use http::multipart::Part;
+    ///
+    /// let part = Part::bytes(b"Hello World");
+    /// ```
+    #[rune::function(keep, path = Self::bytes)]
+    fn bytes(bytes: Bytes) -> Self {
+        Self {
+            inner: reqwest::multipart::Part::bytes(bytes.into_vec().into_std()),
+        }
+    }
+
+    /// Set the file name of the part.
+    ///
+    /// ```rune,no_run
+    /// use http::multipart::Part;
+    ///
+    /// let part = Part::bytes(b"Hello World").file_name("hello.txt");
+    /// ```
+    #[rune::function(keep)]
+    fn file_name(self, file_name: &str) -> Self {
+        Self {
+            inner: self.inner.file_name(file_name.to_owned()),
+        }
+    }
+
+    /// Set the mime type of the part.
+    ///
+    /// Errors if the mime type can't be parsed.
+    ///
+    /// ```rune,no_run
+    /// use http::multipart::Part;
+    ///
+    /// let part = Part::text("{}").mime_str("application/json")?;
+    /// ```
+    #[rune::function(keep)]
+    fn mime_str(self, mime: &str) -> Result<Self, Error> {
+        Ok(Self {
+            inner: self.inner.mime_str(mime)?,
+        })
+    }
+}
+
+/// A builder to configure a [`Client`].
+///
+/// To construct a `ClientBuilder`, use [`Client::builder`].
+#[derive(Debug, Any)]
+#[rune(item = ::http)]
+pub struct ClientBuilder {
+    inner: reqwest::ClientBuilder,
+    // The first error raised while configuring the builder, which is returned
+    // when the client is built.
+    error: Option<Error>,
+}
+
+impl ClientBuilder {
+    /// Build the configured client.
+    ///
+    /// Errors if the configuration is invalid, like if a header or proxy url
+    /// couldn't be parsed.
+    ///
+    /// ```rune
+    /// let client = http::Client::builder().build()?;
+    /// ```
+    #[rune::function(keep)]
+    fn build(self) -> Result<Client, Error> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+
+        Ok(Client {
+            client: self.inner.build()?,
+        })
+    }
+
+    /// Set a timeout for every request made by the client, which applies from
+    /// when a request starts connecting until its response body has finished.
+    ///
+    /// Defaults to no timeout.
+    ///
+    /// ```rune
+    /// use time::Duration;
+    ///
+    /// let client = http::Client::builder()
+    ///     .timeout(Duration::from_secs(30))
+    ///     .build()?;
+    /// ```
+    #[rune::function(keep)]
+    #[cfg(feature = "time")]
+    fn timeout(self, timeout: Duration) -> Self {
+        Self {
+            inner: self.inner.timeout(timeout.into_std()),
+            ..self
+        }
+    }
+
+    /// Set a timeout for only the connect phase of the client.
+    ///
+    /// Defaults to no timeout.
+    ///
+    /// ```rune
+    /// use time::Duration;
+    ///
+    /// let client = http::Client::builder()
+    ///     .connect_timeout(Duration::from_secs(5))
+    ///     .build()?;
+    /// ```
+    #[rune::function(keep)]
+    #[cfg(feature = "time")]
+    fn connect_timeout(self, timeout: Duration) -> Self {
+        Self {
+            inner: self.inner.connect_timeout(timeout.into_std()),
+            ..self
+        }
+    }
+
+    /// Set the `User-Agent` header used by every request.
+    ///
+    /// ```rune
+    /// let client = http::Client::builder()
+    ///     .user_agent("my-script/1.0")
+    ///     .build()?;
+    /// ```
+    #[rune::function(keep)]
+    fn user_agent(self, user_agent: &str) -> Self {
+        Self {
+            inner: self.inner.user_agent(user_agent),
+            ..self
+        }
+    }
+
+    /// Set headers from an object which are added to every request, unless
+    /// the request sets them itself.
+    ///
+    /// ```rune
+    /// let client = http::Client::builder()
+    ///     .default_headers(#{"Accept": "application/json"})
+    ///     .build()?;
+    ///
+    /// let client = http::Client::builder()
+    ///     .default_headers(#{"Not a header name": "value"})
+    ///     .build();
+    ///
+    /// assert!(client.is_err());
+    /// ```
+    #[rune::function(keep)]
+    fn default_headers(mut self, headers: &Object) -> VmResult<Self> {
+        let mut map = reqwest::header::HeaderMap::new();
+
+        for (key, value) in headers.iter() {
+            let value = vm_try!(value.borrow_string_ref());
+
+            let name = match reqwest::header::HeaderName::from_bytes(key.as_bytes()) {
+                Ok(name) => name,
+                Err(error) => return VmResult::Ok(self.with_error(Error::message(error))),
+            };
+
+            let value = match reqwest::header::HeaderValue::from_str(value.as_ref()) {
+                Ok(value) => value,
+                Err(error) => return VmResult::Ok(self.with_error(Error::message(error))),
+            };
+
+            map.append(name, value);
+        }
+
+        self.inner = self.inner.default_headers(map);
+        VmResult::Ok(self)
+    }
+
+    /// Follow at most `max` redirects, after which the request errors.
+    ///
+    /// Defaults to following at most 10 redirects.
+    ///
+    /// ```rune
+    /// let client = http::Client::builder()
+    ///     .max_redirects(3)
+    ///     .build()?;
+    /// ```
+    #[rune::function(keep)]
+    fn max_redirects(self, max: usize) -> Self {
+        Self {
+            inner: self.inner.redirect(reqwest::redirect::Policy::limited(max)),
+            ..self
+        }
+    }
+
+    /// Don't follow redirects, and instead return redirect responses as they
+    /// are.
+    ///
+    /// ```rune
+    /// let client = http::Client::builder()
+    ///     .no_redirects()
+    ///     .build()?;
+    /// ```
+    #[rune::function(keep)]
+    fn no_redirects(self) -> Self {
+        Self {
+            inner: self.inner.redirect(reqwest::redirect::Policy::none()),
+            ..self
+        }
+    }
+
+    /// Send every request through the proxy at the given url.
+    ///
+    /// By default the proxies configured through environment variables like
+    /// `HTTP_PROXY` and `HTTPS_PROXY` are used.
+    ///
+    /// ```rune
+    /// let client = http::Client::builder()
+    ///     .proxy("http://localhost:3128")
+    ///     .build()?;
+    /// ```
+    #[rune::function(keep)]
+    fn proxy(self, url: &str) -> Self {
+        match reqwest::Proxy::all(url) {
+            Ok(proxy) => Self {
+                inner: self.inner.proxy(proxy),
+                ..self
+            },
+            Err(error) => self.with_error(Error::from(error)),
+        }
+    }
+
+    /// Don't use any proxies, including the ones configured through
+    /// environment variables.
+    ///
+    /// ```rune
+    /// let client = http::Client::builder()
+    ///     .no_proxy()
+    ///     .build()?;
+    /// ```
+    #[rune::function(keep)]
+    fn no_proxy(self) -> Self {
+        Self {
+            inner: self.inner.no_proxy(),
+            ..self
+        }
+    }
+
+    /// Only allow requests to `https` urls.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// ```rune
+    /// let client = http::Client::builder()
+    ///     .https_only(true)
+    ///     .build()?;
+    /// ```
+    #[rune::function(keep)]
+    fn https_only(self, enabled: bool) -> Self {
+        Self {
+            inner: self.inner.https_only(enabled),
+            ..self
+        }
+    }
+
+    /// Accept invalid TLS certificates, like self-signed or expired ones.
+    ///
+    /// This is dangerous, since anyone in between the client and the server
+    /// could intercept or modify the traffic. Only use it for testing.
+    ///
+    /// Defaults to `false`.
+    #[rune::function(keep)]
+    fn danger_accept_invalid_certs(self, enabled: bool) -> Self {
+        Self {
+            inner: self.inner.danger_accept_invalid_certs(enabled),
+            ..self
+        }
+    }
+
+    /// Request and automatically decompress gzip encoded responses.
+    ///
+    /// Defaults to `true`.
+    ///
+    /// ```rune
+    /// let client = http::Client::builder()
+    ///     .gzip(false)
+    ///     .build()?;
+    /// ```
+    #[rune::function(keep)]
+    fn gzip(self, enabled: bool) -> Self {
+        Self {
+            inner: self.inner.gzip(enabled),
+            ..self
+        }
+    }
+
+    fn with_error(mut self, error: Error) -> Self {
+        self.error.get_or_insert(error);
+        self
+    }
 }
 
 impl Client {
@@ -2153,6 +2858,27 @@ impl Client {
         }
     }
 
+    /// Construct a builder to configure a new http client.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::Duration;
+    ///
+    /// let client = http::Client::builder()
+    ///     .timeout(Duration::from_secs(30))
+    ///     .user_agent("my-script/1.0")
+    ///     .max_redirects(5)
+    ///     .build()?;
+    /// ```
+    #[rune::function(keep, path = Self::builder)]
+    fn builder() -> ClientBuilder {
+        ClientBuilder {
+            inner: reqwest::Client::builder(),
+            error: None,
+        }
+    }
+
     /// Construct a builder to GET the given `url`.
     ///
     /// # Examples
@@ -2227,7 +2953,7 @@ impl Client {
     ///
     /// let response = response.json().await?;
     /// ```
-    #[rune::function(instance)]
+    #[rune::function(keep, instance)]
     fn patch(&self, url: &str) -> RequestBuilder {
         let request = self.client.patch(url);
         RequestBuilder { request }
@@ -0,0 +1,996 @@
+//! The native `http_server` module for the [Rune Language].
+//!
+//! [Rune Language]: https://rune-rs.github.io
+//!
+//! ## Usage
+//!
+//! Add the following to your `Cargo.toml`:
+//!
+//! ```toml
+//! rune-modules = { version = "0.14.0", features = ["http_server"] }
+//! ```
+//!
+//! Install it into your context:
+//!
+//! ```rust
+//! let mut context = rune::Context::with_default_modules()?;
+//! context.install(rune_modules::http_server::module(true)?)?;
+//! # Ok::<_, rune::support::Error>(())
+//! ```
+//!
+//! Use it in Rune:
+//!
+//! ```rust,ignore
+//! use http_server::{Response, Router};
+//!
+//! async fn hello(request) {
+//!     let name = request.param("name").unwrap_or("world");
+//!     Response::text(`Hello ${name}!`)
+//! }
+//!
+//! async fn webhook(request) {
+//!     println!("Received: {}", request.text()?);
+//!     Ok(Response::new().status(204)?)
+//! }
+//!
+//! pub async fn main() {
+//!     let router = Router::new()
+//!         .get("/hello/:name", hello)
+//!         .post("/webhook", webhook);
+//!
+//!     http_server::serve("127.0.0.1:8080", router).await?;
+//! }
+//! ```
+//!
+//! Graceful shutdown on ctrl-c uses the [`signal`] module, which is enabled
+//! together with this one and needs to be installed as well:
+//!
+//! ```rust
+//! let mut context = rune::Context::with_default_modules()?;
+//! context.install(rune_modules::http_server::module(true)?)?;
+//! context.install(rune_modules::signal::module(true)?)?;
+//! # Ok::<_, rune::support::Error>(())
+//! ```
+//!
+//! [`signal`]: crate::signal
+//!
+//! ## Request bodies
+//!
+//! Request bodies are read in full before the handler is called. To protect
+//! the server from running out of memory they are limited to 2 MiB by default,
+//! which can be changed with `Server::body_limit`. Requests with larger bodies
+//! get a `413 Payload Too Large` response.
+//!
+//! ## Errors
+//!
+//! Handlers which return an error or fail get a `500 Internal Server Error`
+//! response. The error itself is logged using [`tracing`].
+//!
+//! [`tracing`]: https://docs.rs/tracing
+//!
+//! ## Concurrency
+//!
+//! Every connection is served by its own task on the host [Tokio] runtime, and
+//! every request calls its handler in a fresh virtual machine. Handlers can
+//! therefore run concurrently, but they can't share mutable state with the
+//! script that started the server. Closures used as handlers may only capture
+//! constant values, like strings and numbers.
+//!
+//! [Tokio]: https://tokio.rs
+
+use core::convert::Infallible;
+use core::pin::pin;
+
+use std::io;
+use std::sync::Arc;
+
+use http_body_util::{BodyExt, Full, LengthLimitError, Limited};
+use hyper::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Method, StatusCode};
+use hyper_util::rt::TokioIo;
+use hyper_util::server::graceful::GracefulShutdown;
+use rune::alloc::fmt::TryWrite;
+use rune::alloc::prelude::*;
+use rune::runtime::{
+    Bytes, Formatter, FromValue, Function, Object, Ref, RuntimeError, SyncFunction, Value, VmError,
+    VmResult,
+};
+use rune::{vm_try, Any, ContextError, Module, TypeHash};
+use tokio::net::TcpListener;
+
+type Body = Full<hyper::body::Bytes>;
+
+/// The default limit for the size of request bodies.
+const DEFAULT_BODY_LIMIT: usize = 2 * 1024 * 1024;
+
+/// A minimal HTTP/1.1 server for Rune.
+///
+/// # Examples
+///
+/// ```rune,no_run
+/// use http_server::Response;
+///
+/// http_server::serve("127.0.0.1:8080", |request| Response::text("Hello World")).await?;
+/// ```
+#[rune::module(::http_server)]
+pub fn module(_stdio: bool) -> Result<Module, ContextError> {
+    let mut module = Module::from_meta(self::module_meta)?;
+
+    module.function_meta(serve)?;
+
+    module.ty::<Server>()?;
+    module.function_meta(Server::bind__meta)?;
+    module.function_meta(Server::local_addr__meta)?;
+    module.function_meta(Server::body_limit__meta)?;
+    module.function_meta(Server::serve__meta)?;
+    module.function_meta(Server::serve_with_shutdown__meta)?;
+
+    module.ty::<Router>()?;
+    module.function_meta(Router::new__meta)?;
+    module.function_meta(Router::route__meta)?;
+    module.function_meta(Router::get__meta)?;
+    module.function_meta(Router::post__meta)?;
+    module.function_meta(Router::put__meta)?;
+    module.function_meta(Router::patch__meta)?;
+    module.function_meta(Router::delete__meta)?;
+    module.function_meta(Router::any__meta)?;
+    module.function_meta(Router::fallback__meta)?;
+
+    module.ty::<Request>()?;
+    module.function_meta(Request::method__meta)?;
+    module.function_meta(Request::path__meta)?;
+    module.function_meta(Request::query__meta)?;
+    module.function_meta(Request::param__meta)?;
+    module.function_meta(Request::params__meta)?;
+    module.function_meta(Request::header__meta)?;
+    module.function_meta(Request::header_all__meta)?;
+    module.function_meta(Request::headers__meta)?;
+    module.function_meta(Request::body__meta)?;
+    module.function_meta(Request::text__meta)?;
+
+    module.ty::<Response>()?;
+    module.function_meta(Response::new__meta)?;
+    module.function_meta(Response::text__meta)?;
+    module.function_meta(Response::html__meta)?;
+    module.function_meta(Response::bytes__meta)?;
+    module.function_meta(Response::status__meta)?;
+    module.function_meta(Response::header__meta)?;
+
+    module.ty::<Error>()?;
+    module.function_meta(Error::display_fmt__meta)?;
+    Ok(module)
+}
+
+/// An error returned by methods in the `http_server` module.
+#[derive(Debug, Any)]
+#[rune(item = ::http_server)]
+pub struct Error {
+    inner: ErrorKind,
+}
+
+#[derive(Debug)]
+enum ErrorKind {
+    Io(io::Error),
+    Message(std::string::String),
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self {
+            inner: ErrorKind::Io(error),
+        }
+    }
+}
+
+impl Error {
+    fn message(message: impl core::fmt::Display) -> Self {
+        Self {
+            inner: ErrorKind::Message(message.to_string()),
+        }
+    }
+
+    /// Write a display representation the error.
+    #[rune::function(keep, instance, protocol = DISPLAY_FMT)]
+    fn display_fmt(&self, f: &mut Formatter) -> VmResult<()> {
+        match &self.inner {
+            ErrorKind::Io(error) => rune::vm_write!(f, "{error}"),
+            ErrorKind::Message(message) => rune::vm_write!(f, "{message}"),
+        }
+    }
+}
+
+/// Bind a server to the given address and serve requests with `handler` until
+/// an error occurs.
+///
+/// The handler is either a function which is called with a [`Request`] and
+/// returns a [`Response`], or a [`Router`].
+///
+/// # Examples
+///
+/// ```rune,no_run
+/// use http_server::Response;
+///
+/// async fn handler(request) {
+///     Response::text(`You requested ${request.path()}`)
+/// }
+///
+/// http_server::serve("127.0.0.1:8080", handler).await?;
+/// ```
+#[rune::function]
+async fn serve(addr: Ref<str>, handler: Value) -> VmResult<Result<(), Error>> {
+    let handler = vm_try!(Handler::new(handler));
+
+    let listener = match TcpListener::bind(addr.as_ref()).await {
+        Ok(listener) => listener,
+        Err(error) => return VmResult::Ok(Err(Error::from(error))),
+    };
+
+    run(
+        listener,
+        handler,
+        DEFAULT_BODY_LIMIT,
+        core::future::pending(),
+    )
+    .await
+}
+
+/// A server which is bound to an address, but not yet serving requests.
+///
+/// Binding separately from serving makes it possible to bind to port `0` and
+/// find out which port was picked, and to shut the server down gracefully.
+#[derive(Debug, Any)]
+#[rune(item = ::http_server)]
+pub struct Server {
+    listener: TcpListener,
+    body_limit: usize,
+}
+
+impl Server {
+    /// Bind a server to the given address.
+    ///
+    /// # Examples
+    ///
+    /// ```rune,no_run
+    /// use http_server::Server;
+    ///
+    /// let server = Server::bind("127.0.0.1:0").await?;
+    /// println!("Listening on {}", server.local_addr()?);
+    /// ```
+    #[rune::function(keep, path = Self::bind)]
+    async fn bind(addr: Ref<str>) -> Result<Self, Error> {
+        let listener = TcpListener::bind(addr.as_ref()).await?;
+
+        Ok(Self {
+            listener,
+            body_limit: DEFAULT_BODY_LIMIT,
+        })
+    }
+
+    /// Get the local address that the server is bound to.
+    #[rune::function(keep, instance, vm_result)]
+    fn local_addr(&self) -> Result<String, Error> {
+        let addr = self.listener.local_addr()?;
+        Ok(String::try_from(addr.to_string()).vm?)
+    }
+
+    /// Set the maximum size in bytes of request bodies, which defaults to 2
+    /// MiB.
+    ///
+    /// Requests with larger bodies get a `413 Payload Too Large` response
+    /// without calling the handler.
+    ///
+    /// # Examples
+    ///
+    /// ```rune,no_run
+    /// use http_server::Server;
+    ///
+    /// let server = Server::bind("127.0.0.1:8080").await?.body_limit(64 * 1024);
+    /// ```
+    #[rune::function(keep, instance)]
+    fn body_limit(mut self, limit: usize) -> Self {
+        self.body_limit = limit;
+        self
+    }
+
+    /// Serve requests with `handler` until an error occurs.
+    ///
+    /// See [`serve`] for the kinds of handlers which are supported.
+    ///
+    /// # Examples
+    ///
+    /// ```rune,no_run
+    /// use http_server::{Response, Server};
+    ///
+    /// let server = Server::bind("127.0.0.1:8080").await?;
+    /// server.serve(|request| Response::text("Hello World")).await?;
+    /// ```
+    #[rune::function(keep, instance)]
+    async fn serve(self, handler: Value) -> VmResult<Result<(), Error>> {
+        let handler = vm_try!(Handler::new(handler));
+        run(
+            self.listener,
+            handler,
+            self.body_limit,
+            core::future::pending(),
+        )
+        .await
+    }
+
+    /// Serve requests with `handler` until the `shutdown` future completes.
+    ///
+    /// Once `shutdown` completes the server stops accepting new connections,
+    /// and waits for requests which are already being handled to finish.
+    ///
+    /// # Examples
+    ///
+    /// Shutting down when ctrl-c is pressed, using the `signal` module:
+    ///
+    /// ```rune,no_run
+    /// use http_server::{Response, Server};
+    ///
+    /// let server = Server::bind("127.0.0.1:8080").await?;
+    ///
+    /// server.serve_with_shutdown(|request| Response::text("Hello World"), signal::ctrl_c()).await?;
+    /// println!("Server stopped");
+    /// ```
+    #[rune::function(keep, instance)]
+    async fn serve_with_shutdown(
+        self,
+        handler: Value,
+        shutdown: Value,
+    ) -> VmResult<Result<(), Error>> {
+        let handler = vm_try!(Handler::new(handler));
+        let shutdown = vm_try!(shutdown.into_future());
+        run(self.listener, handler, self.body_limit, shutdown).await
+    }
+}
+
+/// Accept connections from `listener` until `shutdown` completes.
+async fn run<S>(
+    listener: TcpListener,
+    handler: Handler,
+    body_limit: usize,
+    shutdown: S,
+) -> VmResult<Result<(), Error>>
+where
+    S: core::future::Future<Output = VmResult<Value>>,
+{
+    let handler = Arc::new(handler);
+    let graceful = GracefulShutdown::new();
+    let mut shutdown = pin!(shutdown);
+
+    loop {
+        let stream = tokio::select! {
+            result = listener.accept() => match result {
+                Ok((stream, _)) => stream,
+                Err(error) => return VmResult::Ok(Err(Error::from(error))),
+            },
+            result = &mut shutdown => {
+                vm_try!(result);
+                break;
+            }
+        };
+
+        let service = service_fn({
+            let handler = handler.clone();
+
+            move |request| {
+                let handler = handler.clone();
+                async move { Ok::<_, Infallible>(handler.dispatch(request, body_limit).await) }
+            }
+        });
+
+        let connection = http1::Builder::new().serve_connection(TokioIo::new(stream), service);
+        let connection = graceful.watch(connection);
+
+        tokio::spawn(async move {
+            // Errors on individual connections, like clients disconnecting
+            // half-way through a request, don't affect the server.
+            _ = connection.await;
+        });
+    }
+
+    graceful.shutdown().await;
+    VmResult::Ok(Ok(()))
+}
+
+/// What requests are dispatched to.
+enum Handler {
+    Function(SyncFunction),
+    Router(Router),
+}
+
+impl Handler {
+    fn new(handler: Value) -> VmResult<Self> {
+        if handler.type_hash() == Router::HASH {
+            return VmResult::Ok(Self::Router(vm_try!(handler.downcast::<Router>())));
+        }
+
+        let function = vm_try!(Function::from_value(handler));
+        VmResult::Ok(Self::Function(vm_try!(function.into_sync())))
+    }
+
+    async fn dispatch(
+        &self,
+        request: hyper::Request<hyper::body::Incoming>,
+        body_limit: usize,
+    ) -> hyper::Response<Body> {
+        let (parts, body) = request.into_parts();
+
+        let body = match Limited::new(body, body_limit).collect().await {
+            Ok(body) => body,
+            Err(error) if error.is::<LengthLimitError>() => {
+                return empty(StatusCode::PAYLOAD_TOO_LARGE);
+            }
+            Err(..) => return empty(StatusCode::BAD_REQUEST),
+        };
+
+        let mut request = Request {
+            method: parts.method,
+            uri: parts.uri,
+            headers: parts.headers,
+            body: body.to_bytes(),
+            params: std::vec::Vec::new(),
+        };
+
+        let function = match self {
+            Self::Function(function) => function,
+            Self::Router(router) => match router.find(&mut request) {
+                Ok(function) => function,
+                Err(status) => return empty(status),
+            },
+        };
+
+        let method = request.method.clone();
+        let path = std::string::String::from(request.uri.path());
+
+        match function.async_send_call::<Reply>((request,)).await {
+            VmResult::Ok(Reply(Ok(response))) => response.into_hyper(),
+            VmResult::Ok(Reply(Err(error))) => {
+                tracing::error!(%method, path, error, "Handler returned an error");
+                empty(StatusCode::INTERNAL_SERVER_ERROR)
+            }
+            VmResult::Err(error) => {
+                tracing::error!(%method, path, %error, "Handler failed");
+                empty(StatusCode::INTERNAL_SERVER_ERROR)
+            }
+        }
+    }
+}
+
+/// The value returned by a handler, which is either a [`Response`] or a
+/// `Result` containing one.
+///
+/// Handlers which return an error or panic produce a `500 Internal Server
+/// Error` response. Since values can't be sent across threads, the error is
+/// captured in its debug representation so that it can be logged.
+struct Reply(Result<Response, std::string::String>);
+
+impl FromValue for Reply {
+    fn from_value(value: Value) -> Result<Self, RuntimeError> {
+        if value.type_hash() == <Result<Value, Value> as TypeHash>::HASH {
+            return Ok(Self(
+                Result::<Response, Value>::from_value(value)?.map_err(|error| format!("{error:?}")),
+            ));
+        }
+
+        Ok(Self(Ok(Response::from_value(value)?)))
+    }
+}
+
+fn empty(status: StatusCode) -> hyper::Response<Body> {
+    let mut response = hyper::Response::new(Body::default());
+    *response.status_mut() = status;
+    response
+}
+
+/// Routes requests to handlers based on their method and path.
+///
+/// Path patterns are made up of segments separated by `/`. A segment starting
+/// with `:` matches any single segment and captures it as a parameter, and a
+/// final segment starting with `*` captures the rest of the path. Parameters
+/// are available through [`Request::param`].
+///
+/// Routes are tried in the order they were added. Requests which don't match
+/// any route are passed to the [`fallback`] handler, or get a `404 Not Found`
+/// response if there is none. Requests which match the path of a route but not
+/// its method get a `405 Method Not Allowed` response.
+///
+/// [`fallback`]: Self::fallback
+///
+/// # Examples
+///
+/// ```rune,no_run
+/// use http_server::{Response, Router};
+///
+/// let router = Router::new()
+///     .get("/", |request| Response::text("index"))
+///     .get("/users/:id", |request| Response::text(request.param("id")?))
+///     .get("/static/*path", |request| Response::text(request.param("path")?));
+///
+/// http_server::serve("127.0.0.1:8080", router).await?;
+/// ```
+#[derive(Any)]
+#[rune(item = ::http_server)]
+pub struct Router {
+    routes: std::vec::Vec<Route>,
+    fallback: Option<SyncFunction>,
+}
+
+struct Route {
+    method: Option<Method>,
+    segments: std::vec::Vec<Segment>,
+    handler: SyncFunction,
+}
+
+enum Segment {
+    Literal(std::string::String),
+    Param(std::string::String),
+    Rest(std::string::String),
+}
+
+impl Router {
+    /// Construct a new router without any routes.
+    #[rune::function(keep, path = Self::new)]
+    fn new() -> Self {
+        Self {
+            routes: std::vec::Vec::new(),
+            fallback: None,
+        }
+    }
+
+    /// Add a route for requests with the given method.
+    ///
+    /// ```rune,no_run
+    /// use http_server::{Response, Router};
+    ///
+    /// let router = Router::new()
+    ///     .route("OPTIONS", "/", |request| Response::new().header("Allow", "GET")?);
+    /// ```
+    #[rune::function(keep, vm_result)]
+    fn route(self, method: &str, path: &str, handler: Function) -> Self {
+        let method = Method::from_bytes(method.as_bytes())
+            .map_err(|_| VmError::panic(format!("Invalid HTTP method `{method}`")))
+            .vm?;
+
+        self.add(Some(method), path, handler).vm?
+    }
+
+    /// Add a route for `GET` requests.
+    #[rune::function(keep, vm_result)]
+    fn get(self, path: &str, handler: Function) -> Self {
+        self.add(Some(Method::GET), path, handler).vm?
+    }
+
+    /// Add a route for `POST` requests.
+    #[rune::function(keep, vm_result)]
+    fn post(self, path: &str, handler: Function) -> Self {
+        self.add(Some(Method::POST), path, handler).vm?
+    }
+
+    /// Add a route for `PUT` requests.
+    #[rune::function(keep, vm_result)]
+    fn put(self, path: &str, handler: Function) -> Self {
+        self.add(Some(Method::PUT), path, handler).vm?
+    }
+
+    /// Add a route for `PATCH` requests.
+    #[rune::function(keep, vm_result)]
+    fn patch(self, path: &str, handler: Function) -> Self {
+        self.add(Some(Method::PATCH), path, handler).vm?
+    }
+
+    /// Add a route for `DELETE` requests.
+    #[rune::function(keep, vm_result)]
+    fn delete(self, path: &str, handler: Function) -> Self {
+        self.add(Some(Method::DELETE), path, handler).vm?
+    }
+
+    /// Add a route for requests with any method.
+    #[rune::function(keep, vm_result)]
+    fn any(self, path: &str, handler: Function) -> Self {
+        self.add(None, path, handler).vm?
+    }
+
+    /// Set the handler for requests which don't match any route.
+    ///
+    /// ```rune,no_run
+    /// use http_server::{Response, Router};
+    ///
+    /// let router = Router::new()
+    ///     .fallback(|request| Response::text(`No such page: ${request.path()}`).status(404)?);
+    /// ```
+    #[rune::function(keep, vm_result)]
+    fn fallback(mut self, handler: Function) -> Self {
+        self.fallback = Some(handler.into_sync().vm?);
+        self
+    }
+
+    fn add(
+        mut self,
+        method: Option<Method>,
+        path: &str,
+        handler: Function,
+    ) -> Result<Self, RuntimeError> {
+        let segments = segments(path)
+            .map(|segment| {
+                if let Some(name) = segment.strip_prefix(':') {
+                    Segment::Param(name.to_owned())
+                } else if let Some(name) = segment.strip_prefix('*') {
+                    Segment::Rest(name.to_owned())
+                } else {
+                    Segment::Literal(segment.to_owned())
+                }
+            })
+            .collect();
+
+        self.routes.push(Route {
+            method,
+            segments,
+            handler: handler.into_sync()?,
+        });
+
+        Ok(self)
+    }
+
+    /// Find the handler for the given request, storing any parameters captured
+    /// by its path in the request.
+    fn find(&self, request: &mut Request) -> Result<&SyncFunction, StatusCode> {
+        let mut path_matched = false;
+
+        for route in &self.routes {
+            let Some(params) = route.matches(request.uri.path()) else {
+                continue;
+            };
+
+            if route
+                .method
+                .as_ref()
+                .is_some_and(|method| *method != request.method)
+            {
+                path_matched = true;
+                continue;
+            }
+
+            request.params = params;
+            return Ok(&route.handler);
+        }
+
+        if path_matched {
+            return Err(StatusCode::METHOD_NOT_ALLOWED);
+        }
+
+        self.fallback.as_ref().ok_or(StatusCode::NOT_FOUND)
+    }
+}
+
+impl Route {
+    /// Match the given path against the route, returning the captured
+    /// parameters if it matches.
+    fn matches(
+        &self,
+        path: &str,
+    ) -> Option<std::vec::Vec<(std::string::String, std::string::String)>> {
+        let mut params = std::vec::Vec::new();
+        let mut parts = segments(path);
+
+        for segment in &self.segments {
+            match segment {
+                Segment::Literal(literal) => {
+                    if parts.next()? != literal {
+                        return None;
+                    }
+                }
+                Segment::Param(name) => {
+                    params.push((name.clone(), parts.next()?.to_owned()));
+                }
+                Segment::Rest(name) => {
+                    let rest = parts.collect::<std::vec::Vec<_>>().join("/");
+                    params.push((name.clone(), rest));
+                    return Some(params);
+                }
+            }
+        }
+
+        if parts.next().is_some() {
+            return None;
+        }
+
+        Some(params)
+    }
+}
+
+/// Split a path into its non-empty segments.
+fn segments(path: &str) -> impl Iterator<Item = &str> {
+    path.split('/').filter(|segment| !segment.is_empty())
+}
+
+/// A request received by the server.
+#[derive(Debug, Any)]
+#[rune(item = ::http_server)]
+pub struct Request {
+    method: Method,
+    uri: hyper::Uri,
+    headers: HeaderMap,
+    body: hyper::body::Bytes,
+    params: std::vec::Vec<(std::string::String, std::string::String)>,
+}
+
+impl Request {
+    /// Get the method of the request, like `GET` or `POST`.
+    #[rune::function(keep, instance, vm_result)]
+    fn method(&self) -> String {
+        String::try_from(self.method.as_str()).vm?
+    }
+
+    /// Get the path of the request, without the query string.
+    ///
+    /// ```rune,no_run
+    /// use http_server::Response;
+    ///
+    /// async fn handler(request) {
+    ///     Response::text(`You requested ${request.path()}`)
+    /// }
+    /// ```
+    #[rune::function(keep, instance, vm_result)]
+    fn path(&self) -> String {
+        String::try_from(self.uri.path()).vm?
+    }
+
+    /// Get the query string of the request, if there is one.
+    #[rune::function(keep, instance, vm_result)]
+    fn query(&self) -> Option<String> {
+        match self.uri.query() {
+            Some(query) => Some(String::try_from(query).vm?),
+            None => None,
+        }
+    }
+
+    /// Get the parameter with the given name, as captured by the path of the
+    /// route which matched the request.
+    ///
+    /// ```rune,no_run
+    /// use http_server::{Response, Router};
+    ///
+    /// let router = Router::new()
+    ///     .get("/users/:id", |request| Response::text(`User ${request.param("id")?}`));
+    /// ```
+    #[rune::function(keep, instance, vm_result)]
+    fn param(&self, name: &str) -> Option<String> {
+        for (key, value) in &self.params {
+            if key == name {
+                return Some(String::try_from(value.as_str()).vm?);
+            }
+        }
+
+        None
+    }
+
+    /// Get all parameters captured by the path of the route which matched the
+    /// request as an object.
+    #[rune::function(keep, instance, vm_result)]
+    fn params(&self) -> Object {
+        let mut object = Object::new();
+
+        for (key, value) in &self.params {
+            let value = rune::to_value(String::try_from(value.as_str()).vm?).vm?;
+            object
+                .insert(String::try_from(key.as_str()).vm?, value)
+                .vm?;
+        }
+
+        object
+    }
+
+    /// Get the first value of the header with the given name, if it's
+    /// present.
+    ///
+    /// Header names are case-insensitive, and values which aren't valid UTF-8
+    /// are decoded lossily.
+    #[rune::function(keep, instance, vm_result)]
+    fn header(&self, name: &str) -> Option<String> {
+        match self.headers.get(name) {
+            Some(value) => Some(header_value(value).vm?),
+            None => None,
+        }
+    }
+
+    /// Get all values of the header with the given name, in the order they
+    /// were received.
+    #[rune::function(keep, instance, vm_result)]
+    fn header_all(&self, name: &str) -> Vec<String> {
+        let mut values = Vec::new();
+
+        for value in self.headers.get_all(name) {
+            values.try_push(header_value(value).vm?).vm?;
+        }
+
+        values
+    }
+
+    /// Get all headers of the request as an object, where the names of
+    /// headers are lowercase.
+    ///
+    /// Headers which are present multiple times have their values joined by
+    /// `, `. Use [`header_all`] to get them individually.
+    ///
+    /// [`header_all`]: Self::header_all
+    #[rune::function(keep, instance, vm_result)]
+    fn headers(&self) -> Object {
+        let mut object = Object::new();
+
+        for name in self.headers.keys() {
+            let mut value = String::new();
+
+            for (index, part) in self.headers.get_all(name).into_iter().enumerate() {
+                if index > 0 {
+                    value.try_push_str(", ").vm?;
+                }
+
+                value.try_push_str(&header_value(part).vm?).vm?;
+            }
+
+            let value = rune::to_value(value).vm?;
+            object
+                .insert(String::try_from(name.as_str()).vm?, value)
+                .vm?;
+        }
+
+        object
+    }
+
+    /// Get the body of the request as bytes.
+    #[rune::function(keep, instance, vm_result)]
+    fn body(&self) -> Bytes {
+        let mut bytes = Vec::try_with_capacity(self.body.len()).vm?;
+        bytes.try_extend_from_slice(&self.body).vm?;
+        Bytes::from_vec(bytes)
+    }
+
+    /// Get the body of the request as text, erroring if it isn't valid UTF-8.
+    ///
+    /// ```rune,no_run
+    /// use http_server::Response;
+    ///
+    /// async fn echo(request) {
+    ///     Ok(Response::text(request.text()?))
+    /// }
+    /// ```
+    #[rune::function(keep, instance, vm_result)]
+    fn text(&self) -> Result<String, Error> {
+        let text = match core::str::from_utf8(&self.body) {
+            Ok(text) => text,
+            Err(error) => return Err(Error::message(error)),
+        };
+
+        Ok(String::try_from(text).vm?)
+    }
+}
+
+fn header_value(value: &HeaderValue) -> Result<String, rune::alloc::Error> {
+    String::try_from(std::string::String::from_utf8_lossy(value.as_bytes()).as_ref())
+}
+
+/// A response to be sent by the server, as returned by a handler.
+///
+/// # Examples
+///
+/// ```rune,no_run
+/// use http_server::Response;
+///
+/// let response = Response::bytes(json::to_bytes(#{"hello": "world"})?)
+///     .header("Content-Type", "application/json")?;
+/// ```
+#[derive(Debug, Any)]
+#[rune(item = ::http_server)]
+pub struct Response {
+    status: StatusCode,
+    headers: HeaderMap,
+    body: hyper::body::Bytes,
+}
+
+impl Response {
+    fn with_body(content_type: &'static str, body: hyper::body::Bytes) -> Self {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+
+        Self {
+            status: StatusCode::OK,
+            headers,
+            body,
+        }
+    }
+
+    /// Construct an empty `200 OK` response.
+    ///
+    /// ```rune,no_run
+    /// use http_server::Response;
+    ///
+    /// let response = Response::new().status(204)?;
+    /// ```
+    #[rune::function(keep, path = Self::new)]
+    fn new() -> Self {
+        Self {
+            status: StatusCode::OK,
+            headers: HeaderMap::new(),
+            body: hyper::body::Bytes::new(),
+        }
+    }
+
+    /// Construct a `200 OK` response with a plain text body.
+    #[rune::function(keep, path = Self::text)]
+    fn text(text: &str) -> Self {
+        Self::with_body(
+            "text/plain; charset=utf-8",
+            hyper::body::Bytes::copy_from_slice(text.as_bytes()),
+        )
+    }
+
+    /// Construct a `200 OK` response with an HTML body.
+    #[rune::function(keep, path = Self::html)]
+    fn html(html: &str) -> Self {
+        Self::with_body(
+            "text/html; charset=utf-8",
+            hyper::body::Bytes::copy_from_slice(html.as_bytes()),
+        )
+    }
+
+    /// Construct a `200 OK` response with a binary body.
+    #[rune::function(keep, path = Self::bytes)]
+    fn bytes(bytes: Bytes) -> Self {
+        let bytes = bytes.into_vec();
+
+        Self::with_body(
+            "application/octet-stream",
+            hyper::body::Bytes::from(bytes.into_std()),
+        )
+    }
+
+    /// Set the status code of the response.
+    ///
+    /// ```rune,no_run
+    /// use http_server::Response;
+    ///
+    /// let response = Response::text("Not Found").status(404)?;
+    /// ```
+    #[rune::function(keep)]
+    fn status(mut self, status: u16) -> Result<Self, Error> {
+        self.status = StatusCode::from_u16(status).map_err(Error::message)?;
+        Ok(self)
+    }
+
+    /// Add a header to the response.
+    ///
+    /// Headers which are already set are not replaced, but sent multiple
+    /// times, except for `Content-Type` which replaces the one set by the
+    /// constructor.
+    ///
+    /// ```rune,no_run
+    /// use http_server::Response;
+    ///
+    /// let response = Response::text("Hello World")
+    ///     .header("Cache-Control", "no-cache")?;
+    /// ```
+    #[rune::function(keep)]
+    fn header(mut self, name: &str, value: &str) -> Result<Self, Error> {
+        let name = HeaderName::from_bytes(name.as_bytes()).map_err(Error::message)?;
+        let value = HeaderValue::from_str(value).map_err(Error::message)?;
+
+        if name == CONTENT_TYPE {
+            self.headers.insert(name, value);
+        } else {
+            self.headers.append(name, value);
+        }
+
+        Ok(self)
+    }
+
+    fn into_hyper(self) -> hyper::Response<Body> {
+        let mut response = hyper::Response::new(Full::new(self.body));
+        *response.status_mut() = self.status;
+        *response.headers_mut() = self.headers;
+        response
+    }
+}
@@ -29,10 +29,13 @@
 //! }
 //! ```
 
+use core::fmt;
+
 use rune::alloc::fmt::TryWrite;
 use rune::alloc::{String, Vec};
-use rune::runtime::{Bytes, Formatter, Value, VmResult};
-use rune::{vm_write, Any, ContextError, Module};
+use rune::runtime::{self, Bytes, Formatter, Object, TypeSeed, Value, VmResult};
+use rune::{item, vm_write, Any, ContextError, Module, TypeHash};
+use serde::de::DeserializeSeed;
 
 #[rune::module(::json)]
 /// Module for processing JSON.
@@ -47,12 +50,32 @@ use rune::{vm_write, Any, ContextError, Module};
 pub fn module(_stdio: bool) -> Result<Module, ContextError> {
     let mut module = Module::from_meta(self::module_meta)?;
     module.ty::<Error>()?;
-    module.function_meta(Error::display)?;
-    module.function_meta(Error::debug)?;
+    module.function_meta(Error::line__meta)?;
+    module.function_meta(Error::column__meta)?;
+    module.function_meta(Error::path__meta)?;
+    module.function_meta(Error::display__meta)?;
+    module.function_meta(Error::debug__meta)?;
     module.function_meta(from_bytes)?;
     module.function_meta(from_string)?;
+    module.function_meta(from_bytes_as)?;
+    module.function_meta(from_string_as)?;
     module.function_meta(to_string)?;
+    module.function_meta(to_string_pretty)?;
     module.function_meta(to_bytes)?;
+    module.function_meta(get_pointer)?;
+    module.function_meta(set_pointer)?;
+
+    module.ty::<NdjsonReader>()?;
+    module.function_meta(NdjsonReader::from_string__meta)?;
+    module.function_meta(NdjsonReader::from_bytes__meta)?;
+    module.function_meta(NdjsonReader::next__meta)?;
+    module.implement_trait::<NdjsonReader>(item!(::std::iter::Iterator))?;
+
+    module.ty::<NdjsonWriter>()?;
+    module.function_meta(NdjsonWriter::new__meta)?;
+    module.function_meta(NdjsonWriter::write__meta)?;
+    module.function_meta(NdjsonWriter::into_string__meta)?;
+    module.function_meta(NdjsonWriter::into_bytes__meta)?;
     Ok(module)
 }
 
@@ -60,24 +83,139 @@ pub fn module(_stdio: bool) -> Result<Module, ContextError> {
 #[rune(item = ::json)]
 /// Error type raised during JSON serialization.
 struct Error {
-    error: serde_json::Error,
+    kind: ErrorKind,
+    /// The path to the value the error was raised for, if known.
+    path: Option<::std::string::String>,
+    /// The line the error was raised on, overriding the one reported by the
+    /// parser. This is used for NDJSON, where each line is parsed separately.
+    line: Option<usize>,
+}
+
+enum ErrorKind {
+    Json(serde_json::Error),
+    Pointer(::std::string::String),
 }
 
 impl Error {
-    #[rune::function(protocol = DISPLAY_FMT)]
+    fn pointer(message: impl fmt::Display) -> Self {
+        Self {
+            kind: ErrorKind::Pointer(message.to_string()),
+            path: None,
+            line: None,
+        }
+    }
+
+    /// The one-based line number at which the error was raised, if the error
+    /// was raised while parsing.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// let error = match json::from_string("{\n  \"a\": }") {
+    ///     Err(error) => error,
+    ///     Ok(..) => panic!("expected an error"),
+    /// };
+    ///
+    /// assert_eq!(error.line(), Some(2));
+    /// assert_eq!(error.column(), Some(8));
+    /// ```
+    #[rune::function(keep, instance)]
+    fn line(&self) -> Option<usize> {
+        if let Some(line) = self.line {
+            return Some(line);
+        }
+
+        match &self.kind {
+            ErrorKind::Json(error) if error.line() != 0 => Some(error.line()),
+            _ => None,
+        }
+    }
+
+    /// The one-based column number at which the error was raised, if the error
+    /// was raised while parsing.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// let error = match json::from_string("[1, 2,]") {
+    ///     Err(error) => error,
+    ///     Ok(..) => panic!("expected an error"),
+    /// };
+    ///
+    /// assert_eq!(error.line(), Some(1));
+    /// assert_eq!(error.column(), Some(7));
+    /// ```
+    #[rune::function(keep, instance)]
+    fn column(&self) -> Option<usize> {
+        match &self.kind {
+            ErrorKind::Json(error) if error.line() != 0 => Some(error.column()),
+            _ => None,
+        }
+    }
+
+    /// The path to the field the error was raised for when decoding into a
+    /// specific type, like `items[2].name`.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// struct Order { items }
+    ///
+    /// let error = match json::from_string_as("{\"items\": [{\"name\": 1}, {\"name\": }]}", Order) {
+    ///     Err(error) => error,
+    ///     Ok(..) => panic!("expected an error"),
+    /// };
+    ///
+    /// assert_eq!(error.path(), Some("items[1].name"));
+    /// ```
+    #[rune::function(keep, instance, vm_result)]
+    fn path(&self) -> Option<String> {
+        match &self.path {
+            Some(path) => Some(String::try_from(path.as_str()).vm?),
+            None => None,
+        }
+    }
+
+    #[rune::function(keep, protocol = DISPLAY_FMT)]
     pub(crate) fn display(&self, f: &mut Formatter) -> VmResult<()> {
-        vm_write!(f, "{}", self.error)
+        vm_write!(f, "{self}")
     }
 
-    #[rune::function(protocol = DEBUG_FMT)]
+    #[rune::function(keep, protocol = DEBUG_FMT)]
     pub(crate) fn debug(&self, f: &mut Formatter) -> VmResult<()> {
-        vm_write!(f, "{:?}", self.error)
+        match &self.kind {
+            ErrorKind::Json(error) => vm_write!(f, "{error:?}"),
+            ErrorKind::Pointer(error) => vm_write!(f, "Pointer({error:?})"),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(path) = &self.path {
+            write!(f, "{path}: ")?;
+        }
+
+        match &self.kind {
+            ErrorKind::Json(error) => {
+                if let Some(line) = self.line {
+                    write!(f, "{error} on line {line}")
+                } else {
+                    error.fmt(f)
+                }
+            }
+            ErrorKind::Pointer(error) => error.fmt(f),
+        }
     }
 }
 
 impl From<serde_json::Error> for Error {
     fn from(error: serde_json::Error) -> Self {
-        Self { error }
+        Self {
+            kind: ErrorKind::Json(error),
+            path: None,
+            line: None,
+        }
     }
 }
 
@@ -107,6 +245,94 @@ fn from_string(string: &str) -> Result<Value, Error> {
     Ok(serde_json::from_str(string)?)
 }
 
+/// Convert JSON bytes into a value of the given type.
+///
+/// Structs are decoded from objects, tuple structs from arrays and enum
+/// variants from their externally tagged representation, the same way they are
+/// encoded by [`to_bytes`].
+///
+/// If decoding fails, the error reports the [path] to the offending field.
+///
+/// [path]: Error::path
+///
+/// # Examples
+///
+/// ```rune
+/// struct Point { x, y }
+///
+/// let point = json::from_bytes_as(b"{\"x\": 1, \"y\": 2}", Point)?;
+/// assert_eq!(point.x, 1);
+/// assert_eq!(point.y, 2);
+/// ```
+#[rune::function]
+fn from_bytes_as(bytes: &[u8], ty: TypeSeed) -> Result<Value, Error> {
+    decode_as(serde_json::Deserializer::from_slice(bytes), ty)
+}
+
+/// Convert a JSON string into a value of the given type.
+///
+/// Structs are decoded from objects, tuple structs from arrays and enum
+/// variants from their externally tagged representation, the same way they are
+/// encoded by [`to_string`].
+///
+/// If decoding fails, the error reports the [path] to the offending field.
+///
+/// [path]: Error::path
+///
+/// # Examples
+///
+/// ```rune
+/// enum Shape {
+///     Circle { radius },
+///     Square(side),
+/// }
+///
+/// let shapes = [Shape::Circle { radius: 2 }, Shape::Square(4)];
+/// let json = json::to_string(shapes)?;
+/// assert_eq!(json, "[{\"Circle\":{\"radius\":2}},{\"Square\":4}]");
+///
+/// let shape = json::from_string_as("{\"Square\":4}", Shape)?;
+/// assert!(shape is Shape);
+///
+/// assert!(json::from_string_as("{\"Triangle\":3}", Shape).is_err());
+/// ```
+#[rune::function]
+fn from_string_as(string: &str, ty: TypeSeed) -> Result<Value, Error> {
+    decode_as(serde_json::Deserializer::from_str(string), ty)
+}
+
+fn decode_as<'de, R>(
+    mut deserializer: serde_json::Deserializer<R>,
+    ty: TypeSeed,
+) -> Result<Value, Error>
+where
+    R: serde_json::de::Read<'de>,
+{
+    let mut track = serde_path_to_error::Track::new();
+
+    let result = ty.deserialize(serde_path_to_error::Deserializer::new(
+        &mut deserializer,
+        &mut track,
+    ));
+
+    match result {
+        Ok(value) => {
+            deserializer.end()?;
+            Ok(value)
+        }
+        Err(error) => {
+            let path = track.path();
+            let mut error = Error::from(error);
+
+            if path.iter().next().is_some() {
+                error.path = Some(path.to_string());
+            }
+
+            Err(error)
+        }
+    }
+}
+
 /// Convert any value to a json string.
 ///
 /// # Examples
@@ -121,6 +347,20 @@ fn to_string(value: Value) -> Result<String, Error> {
     Ok(String::try_from(serde_json::to_string(&value)?).vm?)
 }
 
+/// Convert any value to a pretty-printed json string, indented with two
+/// spaces.
+///
+/// # Examples
+///
+/// ```rune
+/// let json = json::to_string_pretty([1, #{"a": true}])?;
+/// assert_eq!(json, "[\n  1,\n  {\n    \"a\": true\n  }\n]");
+/// ```
+#[rune::function(vm_result)]
+fn to_string_pretty(value: Value) -> Result<String, Error> {
+    Ok(String::try_from(serde_json::to_string_pretty(&value)?).vm?)
+}
+
 /// Convert any value to json bytes.
 ///
 /// # Examples
@@ -136,3 +376,374 @@ fn to_bytes(value: Value) -> Result<Bytes, Error> {
         Vec::try_from(serde_json::to_vec(&value)?).vm?,
     ))
 }
+
+/// Look up a value using a [JSON Pointer], like `/items/0/name`.
+///
+/// Pointers navigate through objects by key and through vectors by index.
+/// Returns `None` if there is no value at the pointer, or if the pointer is
+/// not valid.
+///
+/// [JSON Pointer]: https://www.rfc-editor.org/rfc/rfc6901
+///
+/// # Examples
+///
+/// ```rune
+/// let value = json::from_string("{\"items\": [{\"name\": \"apple\"}], \"a/b\": 1}")?;
+///
+/// assert_eq!(json::get_pointer(value, "/items/0/name"), Some("apple"));
+/// assert_eq!(json::get_pointer(value, "/a~1b"), Some(1));
+/// assert_eq!(json::get_pointer(value, "/items/1"), None);
+/// assert_eq!(json::get_pointer(value, ""), Some(value));
+/// ```
+#[rune::function]
+fn get_pointer(value: Value, pointer: &str) -> Option<Value> {
+    let mut current = value;
+
+    for token in tokens(pointer).ok()? {
+        current = step(&current, &token).ok()??;
+    }
+
+    Some(current)
+}
+
+/// Set a value using a [JSON Pointer], like `/items/0/name`.
+///
+/// The parent of the pointed to value must exist and be an object or a vector.
+/// Object keys are inserted or replaced, while vector elements can be replaced
+/// or appended by using either the length of the vector or `-` as the index.
+///
+/// [JSON Pointer]: https://www.rfc-editor.org/rfc/rfc6901
+///
+/// # Errors
+///
+/// Errors if the pointer is invalid or its parent doesn't exist.
+///
+/// # Examples
+///
+/// ```rune
+/// let value = #{"items": [#{"name": "apple"}]};
+///
+/// json::set_pointer(value, "/items/0/name", "pear")?;
+/// json::set_pointer(value, "/items/-", #{"name": "plum"})?;
+/// json::set_pointer(value, "/count", 2)?;
+///
+/// assert_eq!(value, #{"items": [#{"name": "pear"}, #{"name": "plum"}], "count": 2});
+/// assert!(json::set_pointer(value, "/missing/name", 1).is_err());
+/// ```
+#[rune::function(vm_result)]
+fn set_pointer(value: Value, pointer: &str, new: Value) -> Result<(), Error> {
+    let mut tokens = tokens(pointer)?;
+
+    let Some(last) = tokens.pop() else {
+        return Err(Error::pointer("Cannot replace the root value"));
+    };
+
+    let mut current = value;
+
+    for token in tokens {
+        let Some(next) = step(&current, &token)? else {
+            return Err(Error::pointer(format_args!(
+                "No value at `{token}` in `{pointer}`"
+            )));
+        };
+
+        current = next;
+    }
+
+    match current.type_hash() {
+        Object::HASH => {
+            let mut object = current.borrow_mut::<Object>().map_err(Error::pointer)?;
+            object.insert(last, new).vm?;
+        }
+        runtime::Vec::HASH => {
+            let mut vec = current
+                .borrow_mut::<runtime::Vec>()
+                .map_err(Error::pointer)?;
+            let len = vec.len();
+
+            let index = match last.as_str() {
+                "-" => len,
+                index => parse_index(index).ok_or_else(|| {
+                    Error::pointer(format_args!("Invalid index `{index}` in `{pointer}`"))
+                })?,
+            };
+
+            if index == len {
+                vec.push(new).vm?;
+            } else if let Some(slot) = vec.get_mut(index) {
+                *slot = new;
+            } else {
+                return Err(Error::pointer(format_args!(
+                    "Index {index} out of bounds in `{pointer}`"
+                )));
+            }
+        }
+        _ => {
+            return Err(Error::pointer(format_args!(
+                "Cannot set `{last}` on {} in `{pointer}`",
+                current.type_info()
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Split a JSON pointer into its unescaped reference tokens.
+fn tokens(pointer: &str) -> Result<::std::vec::Vec<String>, Error> {
+    let mut tokens = ::std::vec::Vec::new();
+
+    if pointer.is_empty() {
+        return Ok(tokens);
+    }
+
+    let Some(pointer) = pointer.strip_prefix('/') else {
+        return Err(Error::pointer(format_args!(
+            "Invalid JSON pointer `{pointer}`, expected it to start with `/`"
+        )));
+    };
+
+    for token in pointer.split('/') {
+        let token = token.replace("~1", "/").replace("~0", "~");
+        tokens.push(String::try_from(token).map_err(Error::pointer)?);
+    }
+
+    Ok(tokens)
+}
+
+/// Parse an array index, which can't have leading zeros.
+fn parse_index(token: &str) -> Option<usize> {
+    if token.len() > 1 && token.starts_with('0') {
+        return None;
+    }
+
+    if !token.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    token.parse().ok()
+}
+
+/// Take a single step into the given value.
+fn step(value: &Value, token: &str) -> Result<Option<Value>, Error> {
+    match value.type_hash() {
+        Object::HASH => {
+            let object = value.borrow_ref::<Object>().map_err(Error::pointer)?;
+            Ok(object.get(token).cloned())
+        }
+        runtime::Vec::HASH => {
+            let vec = value.borrow_ref::<runtime::Vec>().map_err(Error::pointer)?;
+            Ok(parse_index(token).and_then(|index| vec.get(index).cloned()))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// A reader of newline-delimited JSON (NDJSON), where each line holds one
+/// JSON value.
+///
+/// Empty lines are skipped, and errors report the line they were raised on.
+///
+/// # Examples
+///
+/// ```rune
+/// use json::NdjsonReader;
+///
+/// let reader = NdjsonReader::from_string("{\"id\": 1}\n\n{\"id\": 2}\n");
+/// let ids = [];
+///
+/// for value in reader {
+///     ids.push(value?.id);
+/// }
+///
+/// assert_eq!(ids, [1, 2]);
+/// ```
+#[derive(Any)]
+#[rune(item = ::json)]
+struct NdjsonReader {
+    data: Vec<u8>,
+    position: usize,
+    line: usize,
+}
+
+impl NdjsonReader {
+    /// Construct a reader over the given string.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use json::NdjsonReader;
+    ///
+    /// let reader = NdjsonReader::from_string("1\n[2]\n");
+    /// assert_eq!(reader.next(), Some(Ok(1)));
+    /// assert_eq!(reader.next(), Some(Ok([2])));
+    /// assert_eq!(reader.next(), None);
+    /// ```
+    #[rune::function(keep, path = Self::from_string, vm_result)]
+    fn from_string(string: &str) -> Self {
+        Self::from_bytes(string.as_bytes()).vm?
+    }
+
+    /// Construct a reader over the given bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use json::NdjsonReader;
+    ///
+    /// let reader = NdjsonReader::from_bytes(b"1\r\n2\r\n");
+    /// assert_eq!(reader.next(), Some(Ok(1)));
+    /// assert_eq!(reader.next(), Some(Ok(2)));
+    /// assert_eq!(reader.next(), None);
+    /// ```
+    #[rune::function(keep, path = Self::from_bytes, vm_result)]
+    fn from_bytes(bytes: &[u8]) -> Self {
+        Self {
+            data: Vec::try_from(bytes).vm?,
+            position: 0,
+            line: 0,
+        }
+    }
+
+    /// Read the next value.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use json::NdjsonReader;
+    ///
+    /// let reader = NdjsonReader::from_string("1\n{\n");
+    /// assert_eq!(reader.next(), Some(Ok(1)));
+    ///
+    /// let error = match reader.next() {
+    ///     Some(Err(error)) => error,
+    ///     _ => panic!("expected an error"),
+    /// };
+    ///
+    /// assert_eq!(error.line(), Some(2));
+    /// assert_eq!(reader.next(), None);
+    /// ```
+    #[rune::function(keep, protocol = NEXT)]
+    fn next(&mut self) -> Option<Result<Value, Error>> {
+        loop {
+            let rest = self.data.get(self.position..)?;
+
+            if rest.is_empty() {
+                return None;
+            }
+
+            let (line, advance) = match rest.iter().position(|&b| b == b'\n') {
+                Some(n) => (&rest[..n], n + 1),
+                None => (rest, rest.len()),
+            };
+
+            self.position += advance;
+            self.line += 1;
+
+            let line = line.trim_ascii();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            return Some(serde_json::from_slice(line).map_err(|error| Error {
+                kind: ErrorKind::Json(error),
+                path: None,
+                line: Some(self.line),
+            }));
+        }
+    }
+}
+
+/// A writer of newline-delimited JSON (NDJSON), which writes each value as
+/// JSON on a line of its own.
+///
+/// # Examples
+///
+/// ```rune
+/// use json::NdjsonWriter;
+///
+/// let writer = NdjsonWriter::new();
+/// writer.write(#{"id": 1})?;
+/// writer.write([2, 3])?;
+///
+/// assert_eq!(writer.into_string(), "{\"id\":1}\n[2,3]\n");
+/// ```
+#[derive(Any)]
+#[rune(item = ::json)]
+struct NdjsonWriter {
+    data: String,
+}
+
+impl NdjsonWriter {
+    /// Construct a new empty writer.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use json::NdjsonWriter;
+    ///
+    /// let writer = NdjsonWriter::new();
+    /// assert_eq!(writer.into_string(), "");
+    /// ```
+    #[rune::function(keep, path = Self::new)]
+    fn new() -> Self {
+        Self {
+            data: String::new(),
+        }
+    }
+
+    /// Write a value as a single line of JSON.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use json::NdjsonWriter;
+    ///
+    /// let writer = NdjsonWriter::new();
+    /// writer.write("a\nb")?;
+    ///
+    /// assert_eq!(writer.into_string(), "\"a\\nb\"\n");
+    /// ```
+    #[rune::function(keep, vm_result)]
+    fn write(&mut self, value: Value) -> Result<(), Error> {
+        let line = serde_json::to_string(&value)?;
+        self.data.try_push_str(&line).vm?;
+        self.data.try_push('\n').vm?;
+        Ok(())
+    }
+
+    /// Consume the writer and return the written JSON as a string.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use json::NdjsonWriter;
+    ///
+    /// let writer = NdjsonWriter::new();
+    /// writer.write(1)?;
+    ///
+    /// assert_eq!(writer.into_string(), "1\n");
+    /// ```
+    #[rune::function(keep)]
+    fn into_string(self) -> String {
+        self.data
+    }
+
+    /// Consume the writer and return the written JSON as bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use json::NdjsonWriter;
+    ///
+    /// let writer = NdjsonWriter::new();
+    /// writer.write(1)?;
+    ///
+    /// assert_eq!(writer.into_bytes(), b"1\n");
+    /// ```
+    #[rune::function(keep)]
+    fn into_bytes(self) -> Bytes {
+        Bytes::from_vec(self.data.into_bytes())
+    }
+}
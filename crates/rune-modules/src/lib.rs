@@ -27,51 +27,75 @@
 //! See each module for documentation:
 //! * [base64]
 //! * [core]
+//! * [csv]
+//! * [digest]
+//! * [encoding]
+//! * [env]
 //! * [fmt]
 //! * [fs]
 //! * [http]
+//! * [http_server]
 //! * [io]
 //! * [json]
 //! * [macros]
+//! * [path]
 //! * [process]
 //! * [rand]
+//! * [regex]
 //! * [signal]
 //! * [test]
 //! * [time]
 //! * [toml]
+//! * [yaml]
 //!
 //! <br>
 //!
 //! ## Features
 //!
 //! * `core` for the [core module][toml]
+//! * `csv` for the [csv module][csv]
+//! * `digest` for the [digest module][digest]
+//! * `encoding` for the [encoding module][encoding]
+//! * `env` for the [env module][env]
 //! * `fmt` for the [fmt module][fmt]
 //! * `fs` for the [fs module][fs]
 //! * `full` includes all modules.
 //! * `http` for the [http module][http]
+//! * `http_server` for the [http_server module][http_server]
 //! * `io` for the [io module][io]
 //! * `json` for the [json module][json]
 //! * `macros` for the [macros module][macros]
+//! * `path` for the [path module][path]
 //! * `process` for the [process module][process]
 //! * `rand` for the [rand module][rand]
+//! * `regex` for the [regex module][regex]
 //! * `signal` for the [signal module][signal]
 //! * `test` for the [test module][test]
 //! * `time` for the [time module][time]
 //! * `toml` for the [toml module][toml]
+//! * `yaml` for the [yaml module][yaml]
 //!
 //! [core]: https://docs.rs/rune-modules/0/rune_modules/core/
+//! [csv]: https://docs.rs/rune-modules/0/rune_modules/csv/
+//! [digest]: https://docs.rs/rune-modules/0/rune_modules/digest/
+//! [encoding]: https://docs.rs/rune-modules/0/rune_modules/encoding/
+//! [env]: https://docs.rs/rune-modules/0/rune_modules/env/
 //! [fmt]: https://docs.rs/rune-modules/0/rune_modules/fmt/
 //! [fs]: https://docs.rs/rune-modules/0/rune_modules/fs/
 //! [http]: https://docs.rs/rune-modules/0/rune_modules/http/
+//! [http_server]: https://docs.rs/rune-modules/0/rune_modules/http_server/
 //! [io]: https://docs.rs/rune-modules/0/rune_modules/io/
 //! [json]: https://docs.rs/rune-modules/0/rune_modules/json/
 //! [macros]: https://docs.rs/rune-modules/0/rune_modules/macros/
+//! [path]: https://docs.rs/rune-modules/0/rune_modules/path/
 //! [process]: https://docs.rs/rune-modules/0/rune_modules/process/
 //! [rand]: https://docs.rs/rune-modules/0/rune_modules/rand/
+//! [regex]: https://docs.rs/rune-modules/0/rune_modules/regex/
 //! [signal]: https://docs.rs/rune-modules/0/rune_modules/signal/
 //! [test]: https://docs.rs/rune-modules/0/rune_modules/test/
 //! [time]: https://docs.rs/rune-modules/0/rune_modules/time/
 //! [toml]: https://docs.rs/rune-modules/0/rune_modules/toml/
+//! [yaml]: https://docs.rs/rune-modules/0/rune_modules/yaml/
 
 // Note: The above links to docs.rs are needed because cargo-readme does not
 // support intra-doc links (yet):
@@ -105,24 +129,51 @@ macro_rules! entry {
     }
 }
 
+#[cfg(any(feature = "fs", feature = "process"))]
+mod bytes;
+
+#[cfg(any(feature = "digest", feature = "encoding"))]
+mod hex;
+
 #[cfg(feature = "base64")]
 pub mod base64;
 
+#[cfg(feature = "csv")]
+pub mod csv;
+
+#[cfg(feature = "digest")]
+pub mod digest;
+
+#[cfg(feature = "encoding")]
+pub mod encoding;
+
+#[cfg(feature = "env")]
+pub mod env;
+
 #[cfg(feature = "fs")]
 pub mod fs;
 
 #[cfg(feature = "http")]
 pub mod http;
 
+#[cfg(feature = "http_server")]
+pub mod http_server;
+
 #[cfg(feature = "json")]
 pub mod json;
 
+#[cfg(feature = "path")]
+pub mod path;
+
 #[cfg(feature = "process")]
 pub mod process;
 
 #[cfg(feature = "rand")]
 pub mod rand;
 
+#[cfg(feature = "regex")]
+pub mod regex;
+
 #[cfg(feature = "signal")]
 pub mod signal;
 
@@ -135,15 +186,26 @@ pub mod time;
 #[cfg(feature = "toml")]
 pub mod toml;
 
+#[cfg(feature = "yaml")]
+pub mod yaml;
+
 entry! {
     {base64, "base64"},
+    {csv, "csv"},
+    {digest, "digest"},
+    {encoding, "encoding"},
+    {env, "env"},
     {fs, "fs"},
     {http, "http"},
+    {http_server, "http_server"},
     {json, "json"},
+    {path, "path"},
     {process, "process"},
     {rand, "rand"},
+    {regex, "regex"},
     {signal, "signal"},
     {test, "test"},
     {time, "time"},
     {toml, "toml", ser, de},
+    {yaml, "yaml"},
 }
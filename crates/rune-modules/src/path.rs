@@ -0,0 +1,541 @@
+//! The native `path` module for the [Rune Language].
+//!
+//! [Rune Language]: https://rune-rs.github.io
+//!
+//! ## Usage
+//!
+//! Add the following to your `Cargo.toml`:
+//!
+//! ```toml
+//! rune-modules = { version = "0.14.0", features = ["path"] }
+//! ```
+//!
+//! Install it into your context:
+//!
+//! ```rust
+//! let mut context = rune::Context::with_default_modules()?;
+//! context.install(rune_modules::path::module(true)?)?;
+//! # Ok::<_, rune::support::Error>(())
+//! ```
+//!
+//! Use it in Rune:
+//!
+//! ```rust,ignore
+//! use path::Path;
+//!
+//! fn main() {
+//!     let path = Path::new("logs").join("today.txt");
+//!     dbg(path.extension());
+//! }
+//! ```
+
+use std::path::{Component, PathBuf};
+
+use rune::alloc::fmt::TryWrite;
+use rune::alloc::{self, String, Vec};
+use rune::compile::meta;
+use rune::runtime::{Formatter, FromValue, MaybeTypeOf, RuntimeError, Value, VmResult};
+use rune::{vm_write, Any, ContextError, Module, TypeHash};
+
+/// Cross-platform path manipulation.
+///
+/// Paths are manipulated lexically and never touch the filesystem. Functions
+/// which accept a path, including those in the `fs` and `process` modules,
+/// accept either a [`Path`] or a string.
+///
+/// # Examples
+///
+/// ```rune
+/// use path::Path;
+///
+/// let path = Path::new("/var/log").join("app.log");
+/// assert_eq!(path.file_name(), Some("app.log"));
+/// assert_eq!(path.parent(), Some(Path::new("/var/log")));
+/// ```
+#[rune::module(::path)]
+pub fn module(_stdio: bool) -> Result<Module, ContextError> {
+    let mut m = Module::from_meta(self::module_meta)?;
+    m.ty::<Path>()?;
+    m.function_meta(Path::new__meta)?;
+    m.function_meta(Path::join__meta)?;
+    m.function_meta(Path::push__meta)?;
+    m.function_meta(Path::parent__meta)?;
+    m.function_meta(Path::file_name__meta)?;
+    m.function_meta(Path::file_stem__meta)?;
+    m.function_meta(Path::extension__meta)?;
+    m.function_meta(Path::with_file_name__meta)?;
+    m.function_meta(Path::with_extension__meta)?;
+    m.function_meta(Path::components__meta)?;
+    m.function_meta(Path::normalize__meta)?;
+    m.function_meta(Path::is_absolute__meta)?;
+    m.function_meta(Path::is_relative__meta)?;
+    m.function_meta(Path::starts_with__meta)?;
+    m.function_meta(Path::strip_prefix__meta)?;
+    m.function_meta(Path::to_string__meta)?;
+    m.function_meta(Path::clone__meta)?;
+    m.implement_trait::<Path>(rune::item!(::std::clone::Clone))?;
+    m.function_meta(Path::partial_eq__meta)?;
+    m.implement_trait::<Path>(rune::item!(::std::cmp::PartialEq))?;
+    m.function_meta(Path::eq__meta)?;
+    m.implement_trait::<Path>(rune::item!(::std::cmp::Eq))?;
+    m.function_meta(Path::display_fmt__meta)?;
+    m.function_meta(Path::debug_fmt__meta)?;
+    Ok(m)
+}
+
+/// A file system path.
+///
+/// # Examples
+///
+/// ```rune
+/// use path::Path;
+///
+/// let path = Path::new("src");
+/// path.push("main.rn");
+/// assert_eq!(path.to_string(), "src/main.rn");
+/// ```
+#[derive(Any, Clone, PartialEq, Eq)]
+#[rune(item = ::path)]
+pub struct Path {
+    inner: PathBuf,
+}
+
+impl Path {
+    /// Construct a path from a string.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use path::Path;
+    ///
+    /// let path = Path::new("foo.txt");
+    /// assert_eq!(path.to_string(), "foo.txt");
+    /// ```
+    #[rune::function(keep, path = Self::new)]
+    fn new(path: &str) -> Self {
+        Self {
+            inner: PathBuf::from(path),
+        }
+    }
+
+    /// Construct a new path by joining `other` onto this one.
+    ///
+    /// If `other` is absolute, it replaces this path.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use path::Path;
+    ///
+    /// assert_eq!(Path::new("/etc").join("passwd"), Path::new("/etc/passwd"));
+    /// assert_eq!(Path::new("/etc").join(Path::new("/bin")), Path::new("/bin"));
+    /// ```
+    #[rune::function(keep, instance)]
+    fn join(&self, other: PathArg) -> Self {
+        Self {
+            inner: self.inner.join(other.0),
+        }
+    }
+
+    /// Extend this path with `other` in place.
+    ///
+    /// If `other` is absolute, it replaces this path.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use path::Path;
+    ///
+    /// let path = Path::new("/tmp");
+    /// path.push("file.bk");
+    /// assert_eq!(path, Path::new("/tmp/file.bk"));
+    /// ```
+    #[rune::function(keep, instance)]
+    fn push(&mut self, other: PathArg) {
+        self.inner.push(other.0);
+    }
+
+    /// Get the path without its final component, if there is one.
+    ///
+    /// Returns `None` if the path terminates in a root or prefix, or if it's
+    /// the empty string.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use path::Path;
+    ///
+    /// assert_eq!(Path::new("/foo/bar").parent(), Some(Path::new("/foo")));
+    /// assert_eq!(Path::new("foo").parent(), Some(Path::new("")));
+    /// assert_eq!(Path::new("/").parent(), None);
+    /// ```
+    #[rune::function(keep, instance)]
+    fn parent(&self) -> Option<Self> {
+        Some(Self {
+            inner: self.inner.parent()?.to_path_buf(),
+        })
+    }
+
+    /// Get the final component of the path, if it's a normal file or
+    /// directory name.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use path::Path;
+    ///
+    /// assert_eq!(Path::new("/usr/bin/").file_name(), Some("bin"));
+    /// assert_eq!(Path::new("foo.txt/.").file_name(), Some("foo.txt"));
+    /// assert_eq!(Path::new("foo.txt/..").file_name(), None);
+    /// ```
+    #[rune::function(keep, instance, vm_result)]
+    fn file_name(&self) -> Option<String> {
+        match self.inner.file_name() {
+            Some(name) => Some(lossy(name.as_encoded_bytes()).vm?),
+            None => None,
+        }
+    }
+
+    /// Get the file name without its extension.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use path::Path;
+    ///
+    /// assert_eq!(Path::new("foo.tar.gz").file_stem(), Some("foo.tar"));
+    /// assert_eq!(Path::new(".profile").file_stem(), Some(".profile"));
+    /// ```
+    #[rune::function(keep, instance, vm_result)]
+    fn file_stem(&self) -> Option<String> {
+        match self.inner.file_stem() {
+            Some(stem) => Some(lossy(stem.as_encoded_bytes()).vm?),
+            None => None,
+        }
+    }
+
+    /// Get the extension of the file name, if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use path::Path;
+    ///
+    /// assert_eq!(Path::new("foo.tar.gz").extension(), Some("gz"));
+    /// assert_eq!(Path::new("foo").extension(), None);
+    /// assert_eq!(Path::new(".profile").extension(), None);
+    /// ```
+    #[rune::function(keep, instance, vm_result)]
+    fn extension(&self) -> Option<String> {
+        match self.inner.extension() {
+            Some(extension) => Some(lossy(extension.as_encoded_bytes()).vm?),
+            None => None,
+        }
+    }
+
+    /// Construct a new path with the file name replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use path::Path;
+    ///
+    /// let path = Path::new("/tmp/foo.txt");
+    /// assert_eq!(path.with_file_name("bar.txt"), Path::new("/tmp/bar.txt"));
+    /// ```
+    #[rune::function(keep, instance)]
+    fn with_file_name(&self, file_name: &str) -> Self {
+        Self {
+            inner: self.inner.with_file_name(file_name),
+        }
+    }
+
+    /// Construct a new path with the extension replaced, or removed if
+    /// `extension` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use path::Path;
+    ///
+    /// let path = Path::new("report.csv");
+    /// assert_eq!(path.with_extension("json"), Path::new("report.json"));
+    /// assert_eq!(path.with_extension(""), Path::new("report"));
+    /// ```
+    #[rune::function(keep, instance)]
+    fn with_extension(&self, extension: &str) -> Self {
+        Self {
+            inner: self.inner.with_extension(extension),
+        }
+    }
+
+    /// Get the components of the path as strings.
+    ///
+    /// A root is represented by the path separator, and redundant separators
+    /// and `.` components other than at the start of the path are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use path::Path;
+    ///
+    /// assert_eq!(Path::new("/tmp//foo/./bar.txt").components(), ["/", "tmp", "foo", "bar.txt"]);
+    /// assert_eq!(Path::new("../src").components(), ["..", "src"]);
+    /// ```
+    #[rune::function(keep, instance, vm_result)]
+    fn components(&self) -> Vec<String> {
+        let mut components = Vec::new();
+
+        for component in self.inner.components() {
+            let component = lossy(component.as_os_str().as_encoded_bytes()).vm?;
+            components.try_push(component).vm?;
+        }
+
+        components
+    }
+
+    /// Normalize the path lexically, by removing `.` components and resolving
+    /// `..` components against the preceding component.
+    ///
+    /// Since the filesystem is not consulted, symbolic links are not resolved,
+    /// and `..` components at the start of a relative path are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use path::Path;
+    ///
+    /// assert_eq!(Path::new("/a/./b/../c").normalize(), Path::new("/a/c"));
+    /// assert_eq!(Path::new("/../a").normalize(), Path::new("/a"));
+    /// assert_eq!(Path::new("../a/../../b").normalize(), Path::new("../../b"));
+    /// assert_eq!(Path::new("a/..").normalize(), Path::new("."));
+    /// ```
+    #[rune::function(keep, instance)]
+    fn normalize(&self) -> Self {
+        let mut components = std::vec::Vec::new();
+
+        for component in self.inner.components() {
+            match component {
+                Component::CurDir => {}
+                Component::ParentDir => match components.last() {
+                    Some(Component::Normal(..)) => {
+                        components.pop();
+                    }
+                    Some(Component::RootDir | Component::Prefix(..)) => {}
+                    _ => components.push(component),
+                },
+                component => components.push(component),
+            }
+        }
+
+        let mut inner = components.into_iter().collect::<PathBuf>();
+
+        if inner.as_os_str().is_empty() {
+            inner.push(".");
+        }
+
+        Self { inner }
+    }
+
+    /// Test if the path is absolute.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use path::Path;
+    ///
+    /// assert!(Path::new("/etc").is_absolute());
+    /// assert!(!Path::new("etc").is_absolute());
+    /// ```
+    #[rune::function(keep, instance)]
+    fn is_absolute(&self) -> bool {
+        self.inner.is_absolute()
+    }
+
+    /// Test if the path is relative.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use path::Path;
+    ///
+    /// assert!(Path::new("etc").is_relative());
+    /// assert!(!Path::new("/etc").is_relative());
+    /// ```
+    #[rune::function(keep, instance)]
+    fn is_relative(&self) -> bool {
+        self.inner.is_relative()
+    }
+
+    /// Test if the path starts with `base`, comparing whole components.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use path::Path;
+    ///
+    /// let path = Path::new("/etc/passwd");
+    /// assert!(path.starts_with("/etc"));
+    /// assert!(!path.starts_with("/e"));
+    /// ```
+    #[rune::function(keep, instance)]
+    fn starts_with(&self, base: PathArg) -> bool {
+        self.inner.starts_with(base.0)
+    }
+
+    /// Get the path relative to `base`, or `None` if it doesn't start with
+    /// `base`.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use path::Path;
+    ///
+    /// let path = Path::new("/srv/app/config.toml");
+    /// assert_eq!(path.strip_prefix("/srv"), Some(Path::new("app/config.toml")));
+    /// assert_eq!(path.strip_prefix("/etc"), None);
+    /// ```
+    #[rune::function(keep, instance)]
+    fn strip_prefix(&self, base: PathArg) -> Option<Self> {
+        Some(Self {
+            inner: self.inner.strip_prefix(base.0).ok()?.to_path_buf(),
+        })
+    }
+
+    /// Convert the path into a string, replacing any sequences which are not
+    /// valid UTF-8 with the replacement character.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use path::Path;
+    ///
+    /// assert_eq!(Path::new("a").join("b").to_string(), "a/b");
+    /// ```
+    #[rune::function(keep, instance, vm_result)]
+    fn to_string(&self) -> String {
+        lossy(self.inner.as_os_str().as_encoded_bytes()).vm?
+    }
+
+    /// Clone the path.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use path::Path;
+    ///
+    /// let a = Path::new("a");
+    /// let b = a.clone();
+    /// b.push("b");
+    /// assert_eq!(a, Path::new("a"));
+    /// ```
+    #[rune::function(keep, instance, protocol = CLONE)]
+    fn clone(&self) -> Self {
+        Clone::clone(self)
+    }
+
+    /// Test two paths for partial equality, which compares them component by
+    /// component.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use path::Path;
+    ///
+    /// assert_eq!(Path::new("a//b"), Path::new("a/b"));
+    /// assert_ne!(Path::new("a/b"), Path::new("a/c"));
+    /// ```
+    #[rune::function(keep, instance, protocol = PARTIAL_EQ)]
+    fn partial_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    /// Test two paths for total equality, which compares them component by
+    /// component.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::ops::eq;
+    /// use path::Path;
+    ///
+    /// assert!(eq(Path::new("a//b"), Path::new("a/b")));
+    /// ```
+    #[rune::function(keep, instance, protocol = EQ)]
+    fn eq(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    /// Write the path as a string.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use path::Path;
+    ///
+    /// assert_eq!(format!("{}", Path::new("a/b")), "a/b");
+    /// ```
+    #[rune::function(keep, protocol = DISPLAY_FMT)]
+    fn display_fmt(&self, f: &mut Formatter) -> VmResult<()> {
+        vm_write!(f, "{}", self.inner.display())
+    }
+
+    /// Write a debug representation of the path.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use path::Path;
+    ///
+    /// assert_eq!(format!("{:?}", Path::new("a/b")), "\"a/b\"");
+    /// ```
+    #[rune::function(keep, protocol = DEBUG_FMT)]
+    fn debug_fmt(&self, f: &mut Formatter) -> VmResult<()> {
+        vm_write!(f, "{:?}", self.inner)
+    }
+}
+
+impl From<PathBuf> for Path {
+    #[inline]
+    fn from(inner: PathBuf) -> Self {
+        Self { inner }
+    }
+}
+
+impl AsRef<std::path::Path> for Path {
+    #[inline]
+    fn as_ref(&self) -> &std::path::Path {
+        &self.inner
+    }
+}
+
+/// A function argument which is either a [`Path`] or a string.
+pub(crate) struct PathArg(pub(crate) PathBuf);
+
+impl FromValue for PathArg {
+    fn from_value(value: Value) -> Result<Self, RuntimeError> {
+        match value.type_hash() {
+            Path::HASH => Ok(Self(value.borrow_ref::<Path>()?.inner.clone())),
+            String::HASH => Ok(Self(PathBuf::from(&*value.borrow_string_ref()?))),
+            _ => Err(RuntimeError::expected::<Path>(value.type_info())),
+        }
+    }
+}
+
+impl MaybeTypeOf for PathArg {
+    #[inline]
+    fn maybe_type_of() -> alloc::Result<meta::DocType> {
+        Path::maybe_type_of()
+    }
+}
+
+impl AsRef<std::path::Path> for PathArg {
+    #[inline]
+    fn as_ref(&self) -> &std::path::Path {
+        &self.0
+    }
+}
+
+fn lossy(bytes: &[u8]) -> alloc::Result<String> {
+    String::try_from(std::string::String::from_utf8_lossy(bytes).as_ref())
+}
@@ -35,12 +35,17 @@
 use rune::alloc::clone::TryClone;
 use rune::alloc::fmt::TryWrite;
 use rune::alloc::Vec;
-use rune::runtime::{Bytes, Formatter, Mut, Value, VmResult};
+use rune::runtime::{Bytes, Formatter, FromValue, Mut, Object, Value, VmError, VmResult};
 use rune::{vm_try, vm_write, Any, ContextError, Module};
 
 use std::io;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWriteExt, BufReader};
 use tokio::process;
 
+use crate::bytes::to_bytes;
+use crate::path::PathArg;
+
 /// A module for working with processes.
 ///
 /// This allows spawning child processes, capturing their output, and creating
@@ -63,11 +68,28 @@ pub fn module(_stdio: bool) -> Result<Module, ContextError> {
     module.function_meta(Command::debug_fmt__meta)?;
     #[cfg(unix)]
     module.function_meta(Command::arg0__meta)?;
+    module.function_meta(Command::current_dir__meta)?;
+    module.function_meta(Command::env__meta)?;
+    module.function_meta(Command::envs__meta)?;
+    module.function_meta(Command::env_remove__meta)?;
+    module.function_meta(Command::env_clear__meta)?;
     module.function_meta(Command::stdin__meta)?;
     module.function_meta(Command::stdout__meta)?;
     module.function_meta(Command::stderr__meta)?;
     module.function_meta(Command::kill_on_drop__meta)?;
+    #[cfg(feature = "time")]
+    module.function_meta(Command::timeout__meta)?;
     module.function_meta(Command::spawn__meta)?;
+    module.function_meta(Command::status__meta)?;
+    module.function_meta(Command::output__meta)?;
+    module.function_meta(Command::pipe__meta)?;
+
+    module.ty::<Pipeline>()?;
+    module.function_meta(Pipeline::pipe__meta)?;
+    module.function_meta(Pipeline::spawn__meta)?;
+    module.function_meta(Pipeline::status__meta)?;
+    module.function_meta(Pipeline::output__meta)?;
+    module.function_meta(Pipeline::debug_fmt__meta)?;
 
     module.ty::<Child>()?;
     module.function_meta(Child::debug_fmt__meta)?;
@@ -78,6 +100,8 @@ pub fn module(_stdio: bool) -> Result<Module, ContextError> {
     module.function_meta(Child::start_kill__meta)?;
     module.function_meta(Child::kill__meta)?;
     module.function_meta(Child::wait__meta)?;
+    #[cfg(feature = "time")]
+    module.function_meta(Child::wait_timeout__meta)?;
     module.function_meta(Child::wait_with_output__meta)?;
 
     module.ty::<ExitStatus>()?;
@@ -98,14 +122,22 @@ pub fn module(_stdio: bool) -> Result<Module, ContextError> {
     module.ty::<ChildStdin>()?;
     module.function_meta(ChildStdin::debug_fmt__meta)?;
     module.function_meta(ChildStdin::try_into_stdio__meta)?;
+    module.function_meta(ChildStdin::write__meta)?;
+    module.function_meta(ChildStdin::flush__meta)?;
+    module.function_meta(ChildStdin::close__meta)?;
 
     module.ty::<ChildStdout>()?;
     module.function_meta(ChildStdout::debug_fmt__meta)?;
     module.function_meta(ChildStdout::try_into_stdio__meta)?;
+    module.function_meta(ChildStdout::lines__meta)?;
 
     module.ty::<ChildStderr>()?;
     module.function_meta(ChildStderr::debug_fmt__meta)?;
     module.function_meta(ChildStderr::try_into_stdio__meta)?;
+    module.function_meta(ChildStderr::lines__meta)?;
+
+    module.ty::<Lines>()?;
+    module.function_meta(Lines::next__meta)?;
 
     Ok(module)
 }
@@ -126,6 +158,7 @@ pub fn module(_stdio: bool) -> Result<Module, ContextError> {
 #[rune(item = ::process)]
 struct Command {
     inner: process::Command,
+    timeout: Option<Duration>,
 }
 
 impl Command {
@@ -159,13 +192,15 @@ impl Command {
     ///
     /// [rust-lang/rust#37519]: https://github.com/rust-lang/rust/issues/37519
     #[rune::function(keep, path = Self::new)]
-    fn new(command: &str) -> Self {
+    fn new(command: PathArg) -> Self {
         Self {
-            inner: process::Command::new(command),
+            inner: process::Command::new(command.0),
+            timeout: None,
         }
     }
 
-    /// Adds an argument to pass to the program.
+    /// Adds an argument to pass to the program, which can be either a string
+    /// or a [`Path`].
     ///
     /// Only one argument can be passed per use. So instead of:
     ///
@@ -189,6 +224,7 @@ impl Command {
     /// To pass multiple arguments see [`args`].
     ///
     /// [`args`]: method@Self::args
+    /// [`Path`]: path::Path
     ///
     /// # Examples
     ///
@@ -204,8 +240,8 @@ impl Command {
     /// let output = command.output().await?;
     /// ```
     #[rune::function(keep, instance)]
-    fn arg(&mut self, arg: &str) {
-        self.inner.arg(arg);
+    fn arg(&mut self, arg: PathArg) {
+        self.inner.arg(arg.0);
     }
 
     /// Adds multiple arguments to pass to the program.
@@ -229,7 +265,7 @@ impl Command {
     #[rune::function(keep, instance)]
     fn args(&mut self, args: &[Value]) -> VmResult<()> {
         for arg in args {
-            self.inner.arg(&*vm_try!(arg.borrow_string_ref()));
+            self.inner.arg(vm_try!(PathArg::from_value(arg.clone())).0);
         }
 
         VmResult::Ok(())
@@ -245,6 +281,111 @@ impl Command {
         self.inner.arg0(arg);
     }
 
+    /// Sets the working directory for the child process.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rune,no_run
+    /// use process::Command;
+    /// use path::Path;
+    ///
+    /// let command = Command::new("ls");
+    /// command.current_dir(Path::new("/").join("bin"));
+    ///
+    /// let output = command.output().await?;
+    /// ```
+    #[rune::function(keep, instance)]
+    fn current_dir(&mut self, dir: PathArg) {
+        self.inner.current_dir(dir.0);
+    }
+
+    /// Inserts or updates an environment variable mapping.
+    ///
+    /// Note that environment variable names are case-insensitive (but
+    /// case-preserving) on Windows, and case-sensitive on all other platforms.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rune,no_run
+    /// use process::Command;
+    ///
+    /// let command = Command::new("ls");
+    /// command.env("PATH", "/bin");
+    ///
+    /// let output = command.output().await?;
+    /// ```
+    #[rune::function(keep, instance)]
+    fn env(&mut self, key: &str, value: &str) {
+        self.inner.env(key, value);
+    }
+
+    /// Adds or updates multiple environment variable mappings from an object.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rune,no_run
+    /// use process::Command;
+    ///
+    /// let command = Command::new("printenv");
+    /// command.env_clear();
+    /// command.envs(#{"PATH": "/bin", "LANG": "C"});
+    ///
+    /// let output = command.output().await?;
+    /// ```
+    #[rune::function(keep, instance)]
+    fn envs(&mut self, vars: &Object) -> VmResult<()> {
+        for (key, value) in vars.iter() {
+            let value = vm_try!(value.borrow_string_ref());
+            self.inner.env(key.as_str(), value.as_ref());
+        }
+
+        VmResult::Ok(())
+    }
+
+    /// Removes an environment variable mapping.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rune,no_run
+    /// use process::Command;
+    ///
+    /// let command = Command::new("ls");
+    /// command.env_remove("PATH");
+    ///
+    /// let output = command.output().await?;
+    /// ```
+    #[rune::function(keep, instance)]
+    fn env_remove(&mut self, key: &str) {
+        self.inner.env_remove(key);
+    }
+
+    /// Clears the entire environment map for the child process.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rune,no_run
+    /// use process::Command;
+    ///
+    /// let command = Command::new("ls");
+    /// command.env_clear();
+    ///
+    /// let output = command.output().await?;
+    /// ```
+    #[rune::function(keep, instance)]
+    fn env_clear(&mut self) {
+        self.inner.env_clear();
+    }
+
     /// Sets configuration for the child process's standard input (stdin)
     /// handle.
     ///
@@ -352,6 +493,33 @@ impl Command {
         self.inner.kill_on_drop(kill_on_drop);
     }
 
+    /// Sets the maximum amount of time [`status`] and [`output`] wait for the
+    /// child process to exit.
+    ///
+    /// If the child is still running once the timeout has elapsed it is
+    /// killed, and an error of the kind `TimedOut` is returned.
+    ///
+    /// [`status`]: method@Self::status
+    /// [`output`]: method@Self::output
+    ///
+    /// # Examples
+    ///
+    /// ```rune,no_run
+    /// use process::Command;
+    /// use time::Duration;
+    ///
+    /// let command = Command::new("sleep");
+    /// command.arg("10");
+    /// command.timeout(Duration::from_secs(1));
+    ///
+    /// assert!(command.status().await.is_err());
+    /// ```
+    #[rune::function(keep, instance)]
+    #[cfg(feature = "time")]
+    fn timeout(&mut self, timeout: crate::time::Duration) {
+        self.timeout = Some(timeout.into_tokio());
+    }
+
     /// Executes the command as a child process, returning a handle to it.
     ///
     /// By default, stdin, stdout and stderr are inherited from the parent.
@@ -424,12 +592,377 @@ impl Command {
         })
     }
 
+    /// Executes the command as a child process, waiting for it to finish and
+    /// collecting its exit status.
+    ///
+    /// By default, stdin, stdout and stderr are inherited from the parent.
+    ///
+    /// If a [`timeout`] has been set and the child doesn't exit in time, it is
+    /// killed and an error of the kind `TimedOut` is returned.
+    ///
+    /// [`timeout`]: method@Self::timeout
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rune,no_run
+    /// use process::Command;
+    ///
+    /// let command = Command::new("ls");
+    /// let status = command.status().await?;
+    ///
+    /// println!("the command exited with: {status}");
+    /// ```
+    #[rune::function(keep, instance, path = Self::status)]
+    async fn status(mut this: Mut<Self>) -> io::Result<ExitStatus> {
+        let Some(timeout) = this.timeout else {
+            let inner = this.inner.status().await?;
+            return Ok(ExitStatus { inner });
+        };
+
+        let mut child = this.inner.spawn()?;
+
+        // NB: close the stdio handles of the child so that we can't deadlock
+        // waiting on a child which is waiting on a pipe we're holding.
+        child.stdin.take();
+        child.stdout.take();
+        child.stderr.take();
+
+        let result = tokio::time::timeout(timeout, child.wait()).await;
+
+        match result {
+            Ok(inner) => Ok(ExitStatus { inner: inner? }),
+            Err(..) => {
+                // NB: the child might have exited after the timeout elapsed,
+                // in which case it can't be killed.
+                _ = child.start_kill();
+                Err(timed_out())
+            }
+        }
+    }
+
+    /// Executes the command as a child process, waiting for it to finish and
+    /// collecting all of its output.
+    ///
+    /// Stdout and stderr are captured, and stdin is closed. Any configuration
+    /// of stdout and stderr on the command is ignored.
+    ///
+    /// If a [`timeout`] has been set and the child doesn't exit in time, it is
+    /// killed and an error of the kind `TimedOut` is returned.
+    ///
+    /// [`timeout`]: method@Self::timeout
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rune,no_run
+    /// use process::Command;
+    ///
+    /// let command = Command::new("echo");
+    /// command.arg("Hello world");
+    ///
+    /// let output = command.output().await?;
+    /// assert!(output.status.success());
+    /// assert_eq!(output.stdout, b"Hello world\n");
+    /// ```
+    #[rune::function(keep, instance, vm_result, path = Self::output)]
+    async fn output(mut this: Mut<Self>) -> io::Result<Output> {
+        let Some(timeout) = this.timeout else {
+            return Ok(Output::new(this.inner.output().await?).vm?);
+        };
+
+        this.inner.stdout(std::process::Stdio::piped());
+        this.inner.stderr(std::process::Stdio::piped());
+
+        let mut child = this.inner.spawn()?;
+        child.stdin.take();
+
+        let stdout = child.stdout.take();
+        let stderr = child.stderr.take();
+
+        let output = async {
+            let (status, stdout, stderr) =
+                tokio::try_join!(child.wait(), read_to_end(stdout), read_to_end(stderr))?;
+
+            Ok::<_, io::Error>(std::process::Output {
+                status,
+                stdout,
+                stderr,
+            })
+        };
+
+        let result = tokio::time::timeout(timeout, output).await;
+
+        match result {
+            Ok(output) => Ok(Output::new(output?).vm?),
+            Err(..) => {
+                // NB: the child might have exited after the timeout elapsed,
+                // in which case it can't be killed.
+                _ = child.start_kill();
+                Err(timed_out())
+            }
+        }
+    }
+
+    /// Creates a pipeline, where the standard output of this command is
+    /// connected to the standard input of `next`.
+    ///
+    /// # Examples
+    ///
+    /// ```rune,no_run
+    /// use process::Command;
+    ///
+    /// let ls = Command::new("ls");
+    /// let wc = Command::new("wc");
+    /// wc.arg("-l");
+    ///
+    /// let output = ls.pipe(wc).output().await?;
+    /// println!("there are {} files", String::from_utf8(output.stdout)?.trim());
+    /// ```
+    #[rune::function(keep, instance, vm_result)]
+    fn pipe(self, next: Command) -> Pipeline {
+        let mut commands = Vec::new();
+        commands.try_push(self).vm?;
+        commands.try_push(next).vm?;
+        Pipeline { commands }
+    }
+
     #[rune::function(keep, protocol = DEBUG_FMT)]
     fn debug_fmt(&self, f: &mut Formatter) -> VmResult<()> {
         vm_write!(f, "{self:?}")
     }
 }
 
+/// Read everything from an optional stdio handle of a child.
+async fn read_to_end<R>(io: Option<R>) -> io::Result<std::vec::Vec<u8>>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut buf = std::vec::Vec::new();
+
+    if let Some(mut io) = io {
+        io.read_to_end(&mut buf).await?;
+    }
+
+    Ok(buf)
+}
+
+fn timed_out() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::TimedOut,
+        "The process did not exit before the timeout elapsed",
+    )
+}
+
+/// A pipeline of commands, where the standard output of each command is
+/// connected to the standard input of the next one.
+///
+/// Pipelines are constructed through [`Command::pipe`].
+///
+/// # Examples
+///
+/// ```rune,no_run
+/// use process::Command;
+///
+/// let cat = Command::new("cat");
+/// cat.arg("Cargo.toml");
+///
+/// let grep = Command::new("grep");
+/// grep.arg("version");
+///
+/// let head = Command::new("head");
+/// head.args(["-n", "1"]);
+///
+/// let output = cat.pipe(grep).pipe(head).output().await?;
+/// ```
+#[derive(Debug, Any)]
+#[rune(item = ::process)]
+struct Pipeline {
+    commands: Vec<Command>,
+}
+
+impl Pipeline {
+    /// Adds a command to the end of the pipeline, which receives the standard
+    /// output of the current last command as its standard input.
+    #[rune::function(keep, instance, vm_result)]
+    fn pipe(mut self, next: Command) -> Pipeline {
+        self.commands.try_push(next).vm?;
+        self
+    }
+
+    /// Spawns every command in the pipeline, returning handles to the
+    /// children in the same order as the commands.
+    ///
+    /// The standard input of the first command and the standard output of the
+    /// last command are configured as on their respective commands.
+    ///
+    /// # Examples
+    ///
+    /// ```rune,no_run
+    /// use process::{Command, Stdio};
+    ///
+    /// let ls = Command::new("ls");
+    /// let sort = Command::new("sort");
+    /// sort.stdout(Stdio::piped());
+    ///
+    /// let children = ls.pipe(sort).spawn()?;
+    /// let lines = children[1].stdout()?.lines();
+    ///
+    /// while let Some(line) = lines.next().await {
+    ///     println!("{}", line?);
+    /// }
+    /// ```
+    #[rune::function(keep, instance, vm_result)]
+    fn spawn(&mut self) -> io::Result<Vec<Child>> {
+        let mut children = Vec::new();
+
+        for inner in self.spawn_all()? {
+            children.try_push(Child { inner }).vm?;
+        }
+
+        Ok(children)
+    }
+
+    /// Executes every command in the pipeline, waiting for all of them to
+    /// finish.
+    ///
+    /// Returns the status of the last command which didn't exit successfully,
+    /// or the status of the last command in the pipeline if all of them
+    /// succeeded.
+    ///
+    /// # Examples
+    ///
+    /// ```rune,no_run
+    /// use process::Command;
+    ///
+    /// let status = Command::new("true").pipe(Command::new("false")).status().await?;
+    /// assert!(!status.success());
+    /// ```
+    #[rune::function(keep, instance, path = Self::status)]
+    async fn status(mut this: Mut<Self>) -> io::Result<ExitStatus> {
+        let mut status = None::<std::process::ExitStatus>;
+
+        for mut child in this.spawn_all()? {
+            let current = child.wait().await?;
+
+            if status.map_or(true, |status| status.success()) || !current.success() {
+                status = Some(current);
+            }
+        }
+
+        let inner = status.ok_or_else(|| io::Error::other("Pipeline has no commands"))?;
+        Ok(ExitStatus { inner })
+    }
+
+    /// Executes every command in the pipeline, waiting for all of them to
+    /// finish and collecting the output of the last command.
+    ///
+    /// The status of the output follows the same rules as [`status`].
+    ///
+    /// [`status`]: method@Self::status
+    ///
+    /// # Examples
+    ///
+    /// ```rune,no_run
+    /// use process::Command;
+    ///
+    /// let echo = Command::new("echo");
+    /// echo.arg("b\na");
+    ///
+    /// let output = echo.pipe(Command::new("sort")).output().await?;
+    /// assert_eq!(output.stdout, b"a\nb\n");
+    /// ```
+    #[rune::function(keep, instance, vm_result, path = Self::output)]
+    async fn output(mut this: Mut<Self>) -> io::Result<Output> {
+        if let Some(last) = this.commands.last_mut() {
+            last.inner.stdout(std::process::Stdio::piped());
+            last.inner.stderr(std::process::Stdio::piped());
+        }
+
+        let mut children = this.spawn_all()?;
+
+        let Some(last) = children.pop() else {
+            return Err(io::Error::other("Pipeline has no commands"));
+        };
+
+        let mut output = last.wait_with_output().await?;
+
+        for mut child in children {
+            let status = child.wait().await?;
+
+            if output.status.success() && !status.success() {
+                output.status = status;
+            }
+        }
+
+        Ok(Output::new(output).vm?)
+    }
+
+    #[rune::function(keep, protocol = DEBUG_FMT)]
+    fn debug_fmt(&self, f: &mut Formatter) -> VmResult<()> {
+        vm_write!(f, "{self:?}")
+    }
+
+    fn spawn_all(&mut self) -> io::Result<std::vec::Vec<process::Child>> {
+        let mut children = std::vec::Vec::<process::Child>::new();
+        let mut stdin = None;
+        let last = self.commands.len().saturating_sub(1);
+
+        for (index, command) in self.commands.iter_mut().enumerate() {
+            let result = spawn_stage(command, stdin.take(), index < last);
+
+            match result {
+                Ok((child, stdout)) => {
+                    children.push(child);
+                    stdin = stdout;
+                }
+                Err(error) => {
+                    // Don't leave the already spawned commands of a
+                    // partially started pipeline running.
+                    for child in &mut children {
+                        _ = child.start_kill();
+                    }
+
+                    return Err(error);
+                }
+            }
+        }
+
+        Ok(children)
+    }
+}
+
+/// Spawn a single stage of a pipeline, returning the standard output of the
+/// child if it should be connected to the next stage.
+fn spawn_stage(
+    command: &mut Command,
+    stdin: Option<std::process::Stdio>,
+    piped: bool,
+) -> io::Result<(process::Child, Option<std::process::Stdio>)> {
+    if let Some(stdin) = stdin {
+        command.inner.stdin(stdin);
+    }
+
+    if piped {
+        command.inner.stdout(std::process::Stdio::piped());
+    }
+
+    let mut child = command.inner.spawn()?;
+
+    if !piped {
+        return Ok((child, None));
+    }
+
+    let stdout = match child.stdout.take() {
+        Some(stdout) => Some(stdout.try_into()?),
+        None => None,
+    };
+
+    Ok((child, stdout))
+}
+
 /// Representation of a child process spawned onto an event loop.
 ///
 /// # Caveats
@@ -587,6 +1120,41 @@ impl Child {
         Ok(ExitStatus { inner })
     }
 
+    /// Waits for the child to exit for at most the given duration, returning
+    /// the status that it exited with or `None` if it's still running.
+    ///
+    /// Unlike [`Command::timeout`], the child is not killed when the timeout
+    /// elapses.
+    ///
+    /// # Cancel safety
+    ///
+    /// This function is cancel safe.
+    ///
+    /// ```rune,no_run
+    /// use process::Command;
+    /// use time::Duration;
+    ///
+    /// let child = Command::new("sleep");
+    /// child.arg("10");
+    ///
+    /// let child = child.spawn()?;
+    ///
+    /// if child.wait_timeout(Duration::from_secs(1)).await?.is_none() {
+    ///     child.kill().await?;
+    /// }
+    /// ```
+    #[rune::function(keep, instance, path = Self::wait_timeout)]
+    #[cfg(feature = "time")]
+    async fn wait_timeout(
+        mut this: Mut<Self>,
+        timeout: crate::time::Duration,
+    ) -> io::Result<Option<ExitStatus>> {
+        match tokio::time::timeout(timeout.into_tokio(), this.inner.wait()).await {
+            Ok(inner) => Ok(Some(ExitStatus { inner: inner? })),
+            Err(..) => Ok(None),
+        }
+    }
+
     /// Returns a future that will resolve to an `Output`, containing the exit
     /// status, stdout, and stderr of the child process.
     ///
@@ -606,14 +1174,7 @@ impl Child {
     #[rune::function(keep, vm_result, instance)]
     async fn wait_with_output(self) -> io::Result<Output> {
         let output = self.inner.wait_with_output().await?;
-
-        Ok(Output {
-            status: ExitStatus {
-                inner: output.status,
-            },
-            stdout: Value::new(Bytes::from_vec(Vec::try_from(output.stdout).vm?)).vm?,
-            stderr: Value::new(Bytes::from_vec(Vec::try_from(output.stderr).vm?)).vm?,
-        })
+        Ok(Output::new(output).vm?)
     }
 
     #[rune::function(keep, protocol = DEBUG_FMT)]
@@ -644,6 +1205,16 @@ struct Output {
 }
 
 impl Output {
+    fn new(output: std::process::Output) -> Result<Self, VmError> {
+        Ok(Self {
+            status: ExitStatus {
+                inner: output.status,
+            },
+            stdout: Value::new(Bytes::from_vec(Vec::try_from(output.stdout)?))?,
+            stderr: Value::new(Bytes::from_vec(Vec::try_from(output.stderr)?))?,
+        })
+    }
+
     #[rune::function(keep, protocol = DEBUG_FMT)]
     fn debug_fmt(&self, f: &mut Formatter) -> VmResult<()> {
         vm_write!(f, "{self:?}")
@@ -794,3 +1365,104 @@ macro_rules! stdio_stream {
 stdio_stream!(ChildStdin, "stdin");
 stdio_stream!(ChildStdout, "stdout");
 stdio_stream!(ChildStderr, "stderr");
+
+impl ChildStdin {
+    /// Writes a string or bytes to the standard input of the child.
+    ///
+    /// # Examples
+    ///
+    /// ```rune,no_run
+    /// use process::{Command, Stdio};
+    ///
+    /// let command = Command::new("cat");
+    /// command.stdin(Stdio::piped());
+    /// command.stdout(Stdio::piped());
+    ///
+    /// let child = command.spawn()?;
+    /// let stdin = child.stdin()?;
+    /// stdin.write("Hello").await?;
+    /// stdin.write(b" World").await?;
+    /// stdin.close().await?;
+    ///
+    /// let output = child.wait_with_output().await?;
+    /// assert_eq!(output.stdout, b"Hello World");
+    /// ```
+    #[rune::function(keep, instance, path = Self::write)]
+    async fn write(mut this: Mut<Self>, contents: Value) -> VmResult<io::Result<()>> {
+        let contents = vm_try!(to_bytes(&contents));
+        VmResult::Ok(this.inner.write_all(&contents).await)
+    }
+
+    /// Flushes data written to the standard input of the child.
+    #[rune::function(keep, instance, path = Self::flush)]
+    async fn flush(mut this: Mut<Self>) -> io::Result<()> {
+        this.inner.flush().await
+    }
+
+    /// Flushes and closes the standard input of the child, signalling to it
+    /// that no more input is coming.
+    #[rune::function(keep, instance)]
+    async fn close(mut self) -> io::Result<()> {
+        self.inner.flush().await
+    }
+}
+
+macro_rules! output_stream {
+    ($name:ident, $stream:tt) => {
+        impl $name {
+            #[doc = concat!("Consumes the ", $stream, " stream, returning a stream over the lines the child")]
+            /// writes to it as they become available.
+            ///
+            /// # Examples
+            ///
+            /// ```rune,no_run
+            /// use process::{Command, Stdio};
+            ///
+            /// let command = Command::new("ping");
+            /// command.args(["-c", "3", "localhost"]);
+            #[doc = concat!(" command.", $stream, "(Stdio::piped());")]
+            ///
+            /// let child = command.spawn()?;
+            #[doc = concat!(" let lines = child.", $stream, "()?.lines();")]
+            ///
+            /// while let Some(line) = lines.next().await {
+            ///     println!("{}", line?);
+            /// }
+            /// ```
+            #[rune::function(keep, instance)]
+            fn lines(self) -> Lines {
+                Lines {
+                    inner: LinesInner::$name(BufReader::new(self.inner).lines()),
+                }
+            }
+        }
+    };
+}
+
+output_stream!(ChildStdout, "stdout");
+output_stream!(ChildStderr, "stderr");
+
+enum LinesInner {
+    ChildStdout(tokio::io::Lines<BufReader<process::ChildStdout>>),
+    ChildStderr(tokio::io::Lines<BufReader<process::ChildStderr>>),
+}
+
+/// A stream over the lines written by a child to its stdout or stderr.
+#[derive(Any)]
+#[rune(item = ::process)]
+struct Lines {
+    inner: LinesInner,
+}
+
+impl Lines {
+    /// Returns the next line, or `None` once the stream is closed.
+    #[rune::function(keep, instance, path = Self::next)]
+    async fn next(mut this: Mut<Self>) -> Option<io::Result<String>> {
+        let line = match &mut this.inner {
+            LinesInner::ChildStdout(lines) => lines.next_line().await,
+            LinesInner::ChildStderr(lines) => lines.next_line().await,
+        };
+
+        line.transpose()
+    }
+}
@@ -1,4 +1,3 @@
-#![allow(dead_code)]
 //! The native `rand` module for the [Rune Language].
 //!
 //! [Rune Language]: https://rune-rs.github.io
@@ -26,43 +25,631 @@
 //!     let rng = rand::WyRand::new();
 //!     let rand_int = rng.int();
 //!     println(`Random int: {rand_int}`);
-//!     let rand_int_range = rng.int_range(-100, 100);
+//!     let rand_int_range = rng.range(-100..100);
 //!     println(`Random int between -100 and 100: {rand_int_range}`);
+//!     let rand_float = rng.float();
+//!     println(`Random float between 0 and 1: {rand_float}`);
 //! }
 //! ```
+//!
+//! ## Reproducible runs
+//!
+//! By default every generator, including the one used by the free functions
+//! in the module, is seeded from the entropy of the operating system. Hosts
+//! which want whole script runs to be deterministic, like in tests, can
+//! install the module with a fixed seed through [`module_with_config`]:
+//!
+//! ```rust
+//! use rune_modules::rand::{self, Config};
+//!
+//! let mut context = rune::Context::with_default_modules()?;
+//! context.install(rand::module_with_config(Config::new().seed(42))?)?;
+//! # Ok::<_, rune::support::Error>(())
+//! ```
+//!
+//! With a seed, generators constructed through `new` are seeded from the
+//! same deterministic source, so a script produces the same values every
+//! time it runs from a fresh runtime context.
+
+use std::sync::{Arc, Mutex};
 
 use nanorand::Rng;
-use rune::{Any, ContextError, Module};
+use rune::alloc::Vec;
+use rune::runtime::{self, Bytes, Range, RangeInclusive, Value, VmResult};
+use rune::{docstring, vm_try, Any, ContextError, Module, TypeHash};
+
+/// Register the methods shared by all generators.
+macro_rules! rng_methods {
+    ($m:ident, $ty:ty, $name:literal) => {
+        $m.associated_function("int", |this: &mut $ty| int(&mut this.inner))?
+            .docs(docstring! {
+                /// Generate a random integer.
+                ///
+                /// # Examples
+                ///
+                /// ```rune
+                #[doc = concat!(" let rng = rand::", $name, "::new();")]
+                /// let value = rng.int();
+                /// ```
+            })?;
+
+        $m.associated_function("int_range", |this: &mut $ty, lower: i64, upper: i64| {
+            int_range(&mut this.inner, lower, upper)
+        })?
+        .docs(docstring! {
+            /// Generate a random integer in the range from `lower` up to, but
+            /// not including, `upper`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the range is empty.
+            ///
+            /// # Examples
+            ///
+            /// ```rune
+            #[doc = concat!(" let rng = rand::", $name, "::new();")]
+            /// let value = rng.int_range(-100, 100);
+            /// assert!(value >= -100 && value < 100);
+            /// ```
+        })?
+        .argument_types::<($ty, i64, i64)>()?;
+
+        $m.associated_function("float", |this: &mut $ty| float(&mut this.inner))?
+            .docs(docstring! {
+                /// Generate a random float in the range from `0.0` up to, but
+                /// not including, `1.0`.
+                ///
+                /// # Examples
+                ///
+                /// ```rune
+                #[doc = concat!(" let rng = rand::", $name, "::new();")]
+                /// let value = rng.float();
+                /// assert!(value >= 0.0 && value < 1.0);
+                /// ```
+            })?;
+
+        $m.associated_function("bool", |this: &mut $ty| boolean(&mut this.inner))?
+            .docs(docstring! {
+                /// Generate a random boolean.
+                ///
+                /// # Examples
+                ///
+                /// ```rune
+                #[doc = concat!(" let rng = rand::", $name, "::new();")]
+                /// let value = rng.bool();
+                /// assert!(value || !value);
+                /// ```
+            })?;
+
+        $m.associated_function("range", |this: &mut $ty, range: Value| {
+            range_value(&mut this.inner, &range)
+        })?
+        .docs(docstring! {
+            /// Generate a random number in the given range.
+            ///
+            /// Both exclusive and inclusive ranges are supported, with either
+            /// integer or float bounds.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the range is empty.
+            ///
+            /// # Examples
+            ///
+            /// ```rune
+            #[doc = concat!(" let rng = rand::", $name, "::new();")]
+            /// let value = rng.range(1..=6);
+            /// assert!(value >= 1 && value <= 6);
+            ///
+            /// let value = rng.range(-1.0..1.0);
+            /// assert!(value >= -1.0 && value < 1.0);
+            /// ```
+        })?
+        .argument_types::<($ty, Value)>()?
+        .return_type::<Value>()?;
+
+        $m.associated_function("shuffle", |this: &mut $ty, values: &mut runtime::Vec| {
+            shuffle(&mut this.inner, values)
+        })?
+        .docs(docstring! {
+            /// Shuffle the values of a vector in place.
+            ///
+            /// # Examples
+            ///
+            /// ```rune
+            #[doc = concat!(" let rng = rand::", $name, "::new();")]
+            /// let values = [1, 2, 3, 4, 5];
+            /// rng.shuffle(values);
+            /// values.sort();
+            /// assert_eq!(values, [1, 2, 3, 4, 5]);
+            /// ```
+        })?
+        .argument_types::<($ty, runtime::Vec)>()?;
+
+        $m.associated_function("choose", |this: &mut $ty, values: &runtime::Vec| {
+            choose(&mut this.inner, values)
+        })?
+        .docs(docstring! {
+            /// Choose a random value from a vector, or `None` if the vector is
+            /// empty.
+            ///
+            /// # Examples
+            ///
+            /// ```rune
+            #[doc = concat!(" let rng = rand::", $name, "::new();")]
+            /// let value = rng.choose(["rock", "paper", "scissors"]);
+            /// assert!(value.is_some());
+            /// ```
+        })?
+        .argument_types::<($ty, runtime::Vec)>()?
+        .return_type::<Option<Value>>()?;
 
-/// Construct the `rand` module.
+        $m.associated_function(
+            "choose_weighted",
+            |this: &mut $ty, values: &runtime::Vec, weights: &runtime::Vec| {
+                choose_weighted(&mut this.inner, values, weights)
+            },
+        )?
+        .docs(docstring! {
+            /// Choose a random value from a vector, where the chance of each
+            /// value being chosen is proportional to the weight at the same
+            /// index in `weights`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the number of weights doesn't match the number of
+            /// values, or if any weight is negative or not finite.
+            ///
+            /// # Examples
+            ///
+            /// ```rune
+            #[doc = concat!(" let rng = rand::", $name, "::new();")]
+            /// let value = rng.choose_weighted(["a", "b"], [0.0, 1.0]);
+            /// assert_eq!(value, Some("b"));
+            /// ```
+        })?
+        .argument_types::<($ty, runtime::Vec, runtime::Vec)>()?
+        .return_type::<Option<Value>>()?;
+
+        $m.associated_function("bytes", |this: &mut $ty, len: usize| {
+            bytes(&mut this.inner, len)
+        })?
+        .docs(docstring! {
+            /// Generate the given number of random bytes.
+            ///
+            /// # Examples
+            ///
+            /// ```rune
+            #[doc = concat!(" let rng = rand::", $name, "::new();")]
+            /// let bytes = rng.bytes(16);
+            /// assert_eq!(bytes.len(), 16);
+            /// ```
+        })?
+        .argument_types::<($ty, usize)>()?;
+
+        $m.associated_function("normal", |this: &mut $ty, mean: f64, std_dev: f64| {
+            normal(&mut this.inner, mean, std_dev)
+        })?
+        .docs(docstring! {
+            /// Sample a value from a normal distribution with the given mean
+            /// and standard deviation.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the standard deviation is negative or not finite.
+            ///
+            /// # Examples
+            ///
+            /// ```rune
+            #[doc = concat!(" let rng = rand::", $name, "::new();")]
+            /// let value = rng.normal(0.0, 1.0);
+            /// ```
+        })?
+        .argument_types::<($ty, f64, f64)>()?;
+
+        $m.associated_function("exponential", |this: &mut $ty, lambda: f64| {
+            exponential(&mut this.inner, lambda)
+        })?
+        .docs(docstring! {
+            /// Sample a value from an exponential distribution with the rate
+            /// `lambda`.
+            ///
+            /// # Panics
+            ///
+            /// Panics unless `lambda` is positive and finite.
+            ///
+            /// # Examples
+            ///
+            /// ```rune
+            #[doc = concat!(" let rng = rand::", $name, "::new();")]
+            /// let value = rng.exponential(0.5);
+            /// assert!(value >= 0.0);
+            /// ```
+        })?
+        .argument_types::<($ty, f64)>()?;
+    };
+}
+
+/// Generating random numbers.
+///
+/// # Examples
+///
+/// ```rune
+/// let value = rand::range(1..=6);
+/// assert!(value >= 1 && value <= 6);
+/// ```
+#[rune::module(::rand)]
 pub fn module(_stdio: bool) -> Result<Module, ContextError> {
-    let mut module = Module::with_crate("rand")?;
-
-    module.ty::<WyRand>()?;
-    module
-        .function("new", WyRand::new)
-        .build_associated::<WyRand>()?;
-    module
-        .function("new_seed", WyRand::new_seed)
-        .build_associated::<WyRand>()?;
-    module.associated_function("int", WyRand::int)?;
-    module.associated_function("int_range", WyRand::int_range)?;
-
-    module.ty::<Pcg64>()?;
-    module
-        .function("new", Pcg64::new)
-        .build_associated::<Pcg64>()?;
-    module
-        .function("new_seed", Pcg64::new_seed)
-        .build_associated::<Pcg64>()?;
-    module.associated_function("int", Pcg64::int)?;
-    module.associated_function("int_range", Pcg64::int_range)?;
-
-    module.function("int", int).build()?;
-    module.function("int_range", int_range).build()?;
-    Ok(module)
+    module_with_config(Config::default())
+}
+
+/// Construct the `rand` module, using the given [`Config`].
+pub fn module_with_config(config: Config) -> Result<Module, ContextError> {
+    let mut m = Module::from_meta(self::module_meta)?;
+
+    let source = Arc::new(Source::new(config.seed));
+
+    m.ty::<WyRand>()?;
+
+    m.function("new", {
+        let source = source.clone();
+        move || WyRand::from_source(&source)
+    })
+    .build_associated::<WyRand>()?
+    .docs(docstring! {
+        /// Create a new random number generator.
+        ///
+        /// The generator is seeded from the entropy of the operating system,
+        /// unless the host has provided a seed for the module.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        /// let rng = rand::WyRand::new();
+        /// let value = rng.int();
+        /// ```
+    })?;
+
+    m.function("new_seed", WyRand::new_seed)
+        .build_associated::<WyRand>()?
+        .docs(docstring! {
+            /// Create a new random number generator, using a custom seed.
+            ///
+            /// # Examples
+            ///
+            /// ```rune
+            /// let a = rand::WyRand::new_seed(42);
+            /// let b = rand::WyRand::new_seed(42);
+            /// assert_eq!(a.int(), b.int());
+            /// ```
+        })?;
+
+    rng_methods!(m, WyRand, "WyRand");
+
+    m.ty::<Pcg64>()?;
+
+    m.function("new", {
+        let source = source.clone();
+        move || Pcg64::from_source(&source)
+    })
+    .build_associated::<Pcg64>()?
+    .docs(docstring! {
+        /// Create a new random number generator.
+        ///
+        /// The generator is seeded from the entropy of the operating system,
+        /// unless the host has provided a seed for the module.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        /// let rng = rand::Pcg64::new();
+        /// let value = rng.int();
+        /// ```
+    })?;
+
+    m.function("new_seed", Pcg64::new_seed)
+        .build_associated::<Pcg64>()?
+        .docs(docstring! {
+            /// Create a new random number generator, using a custom seed.
+            ///
+            /// # Examples
+            ///
+            /// ```rune
+            /// let a = rand::Pcg64::new_seed(42);
+            /// let b = rand::Pcg64::new_seed(42);
+            /// assert_eq!(a.int(), b.int());
+            /// ```
+        })?;
+
+    rng_methods!(m, Pcg64, "Pcg64");
+
+    m.function("int", {
+        let source = source.clone();
+        move || source.with(int)
+    })
+    .build()?
+    .docs(docstring! {
+        /// Generate a random integer.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        /// let value = rand::int();
+        /// ```
+    })?;
+
+    m.function("int_range", {
+        let source = source.clone();
+        move |lower: i64, upper: i64| source.with(|rng| int_range(rng, lower, upper))
+    })
+    .build()?
+    .docs(docstring! {
+        /// Generate a random integer in the range from `lower` up to, but
+        /// not including, `upper`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the range is empty.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        /// let value = rand::int_range(-100, 100);
+        /// assert!(value >= -100 && value < 100);
+        /// ```
+    })?
+    .argument_types::<(i64, i64)>()?;
+
+    m.function("float", {
+        let source = source.clone();
+        move || source.with(float)
+    })
+    .build()?
+    .docs(docstring! {
+        /// Generate a random float in the range from `0.0` up to, but not
+        /// including, `1.0`.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        /// let value = rand::float();
+        /// assert!(value >= 0.0 && value < 1.0);
+        /// ```
+    })?;
+
+    m.function("bool", {
+        let source = source.clone();
+        move || source.with(boolean)
+    })
+    .build()?
+    .docs(docstring! {
+        /// Generate a random boolean.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        /// let value = rand::bool();
+        /// assert!(value || !value);
+        /// ```
+    })?;
+
+    m.function("range", {
+        let source = source.clone();
+        move |range: Value| source.with(|rng| range_value(rng, &range))
+    })
+    .build()?
+    .docs(docstring! {
+        /// Generate a random number in the given range.
+        ///
+        /// Both exclusive and inclusive ranges are supported, with either
+        /// integer or float bounds.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the range is empty.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        /// let value = rand::range(1..=6);
+        /// assert!(value >= 1 && value <= 6);
+        ///
+        /// let value = rand::range(-1.0..1.0);
+        /// assert!(value >= -1.0 && value < 1.0);
+        /// ```
+    })?
+    .argument_types::<(Value,)>()?
+    .return_type::<Value>()?;
+
+    m.function("shuffle", {
+        let source = source.clone();
+        move |values: &mut runtime::Vec| source.with(|rng| shuffle(rng, values))
+    })
+    .build()?
+    .docs(docstring! {
+        /// Shuffle the values of a vector in place.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        /// let values = [1, 2, 3, 4, 5];
+        /// rand::shuffle(values);
+        /// values.sort();
+        /// assert_eq!(values, [1, 2, 3, 4, 5]);
+        /// ```
+    })?
+    .argument_types::<(runtime::Vec,)>()?;
+
+    m.function("choose", {
+        let source = source.clone();
+        move |values: &runtime::Vec| source.with(|rng| choose(rng, values))
+    })
+    .build()?
+    .docs(docstring! {
+        /// Choose a random value from a vector, or `None` if the vector is
+        /// empty.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        /// let value = rand::choose(["rock", "paper", "scissors"]);
+        /// assert!(value.is_some());
+        ///
+        /// assert_eq!(rand::choose([]), None);
+        /// ```
+    })?
+    .argument_types::<(runtime::Vec,)>()?
+    .return_type::<Option<Value>>()?;
+
+    m.function("choose_weighted", {
+        let source = source.clone();
+        move |values: &runtime::Vec, weights: &runtime::Vec| {
+            source.with(|rng| choose_weighted(rng, values, weights))
+        }
+    })
+    .build()?
+    .docs(docstring! {
+        /// Choose a random value from a vector, where the chance of each
+        /// value being chosen is proportional to the weight at the same index
+        /// in `weights`.
+        ///
+        /// Weights can be integers or floats. Returns `None` if there are no
+        /// values or if all weights are zero.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the number of weights doesn't match the number of values,
+        /// or if any weight is negative or not finite.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        /// let value = rand::choose_weighted(["common", "rare", "never"], [9, 1, 0]);
+        /// assert!(value == Some("common") || value == Some("rare"));
+        ///
+        /// assert_eq!(rand::choose_weighted(["never"], [0]), None);
+        /// ```
+    })?
+    .argument_types::<(runtime::Vec, runtime::Vec)>()?
+    .return_type::<Option<Value>>()?;
+
+    m.function("bytes", {
+        let source = source.clone();
+        move |len: usize| source.with(|rng| bytes(rng, len))
+    })
+    .build()?
+    .docs(docstring! {
+        /// Generate the given number of random bytes.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        /// let bytes = rand::bytes(16);
+        /// assert_eq!(bytes.len(), 16);
+        /// ```
+    })?
+    .argument_types::<(usize,)>()?;
+
+    m.function("normal", {
+        let source = source.clone();
+        move |mean: f64, std_dev: f64| source.with(|rng| normal(rng, mean, std_dev))
+    })
+    .build()?
+    .docs(docstring! {
+        /// Sample a value from a normal distribution with the given mean and
+        /// standard deviation.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the standard deviation is negative or not finite.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        /// let value = rand::normal(10.0, 0.0);
+        /// assert_eq!(value, 10.0);
+        /// ```
+    })?
+    .argument_types::<(f64, f64)>()?;
+
+    m.function("exponential", {
+        let source = source.clone();
+        move |lambda: f64| source.with(|rng| exponential(rng, lambda))
+    })
+    .build()?
+    .docs(docstring! {
+        /// Sample a value from an exponential distribution with the rate
+        /// `lambda`.
+        ///
+        /// # Panics
+        ///
+        /// Panics unless `lambda` is positive and finite.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        /// let value = rand::exponential(2.0);
+        /// assert!(value >= 0.0);
+        /// ```
+    })?
+    .argument_types::<(f64,)>()?;
+
+    Ok(m)
 }
 
+/// Configuration for the [`rand`][self] module.
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct Config {
+    seed: Option<u64>,
+}
+
+impl Config {
+    /// Construct a new configuration, where generators are seeded from the
+    /// entropy of the operating system.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Seed all generators constructed by scripts, and the free functions in
+    /// the module, from the given seed.
+    ///
+    /// Defaults to no seed.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+}
+
+/// The source of randomness used by the module.
+struct Source {
+    rng: Option<Mutex<nanorand::WyRand>>,
+}
+
+impl Source {
+    fn new(seed: Option<u64>) -> Self {
+        Self {
+            rng: seed.map(|seed| Mutex::new(nanorand::WyRand::new_seed(seed))),
+        }
+    }
+
+    /// Call the given function with the generator of the source.
+    fn with<T>(&self, f: impl FnOnce(&mut nanorand::WyRand) -> T) -> T {
+        match &self.rng {
+            Some(rng) => f(&mut rng.lock().unwrap_or_else(|error| error.into_inner())),
+            None => f(&mut nanorand::WyRand::new()),
+        }
+    }
+
+    /// Generate a seed, if the source is seeded.
+    fn seed(&self) -> Option<u64> {
+        let rng = self.rng.as_ref()?;
+        let mut rng = rng.lock().unwrap_or_else(|error| error.into_inner());
+        Some(rng.generate::<u64>())
+    }
+}
+
+/// A fast random number generator which isn't cryptographically secure.
 #[derive(Any)]
 #[rune(item = ::rand)]
 struct WyRand {
@@ -70,31 +657,24 @@ struct WyRand {
 }
 
 impl WyRand {
-    /// Create a new RNG instance.
-    fn new() -> Self {
-        Self {
-            inner: nanorand::WyRand::new(),
+    fn from_source(source: &Source) -> Self {
+        match source.seed() {
+            Some(seed) => Self::new_seed(seed as i64),
+            None => Self {
+                inner: nanorand::WyRand::new(),
+            },
         }
     }
 
-    /// Create a new RNG instance, using a custom seed.
     fn new_seed(seed: i64) -> Self {
         Self {
             inner: nanorand::WyRand::new_seed(seed as u64),
         }
     }
-
-    /// Generate a random integer
-    fn int(&mut self) -> i64 {
-        self.inner.generate::<u64>() as i64
-    }
-
-    /// Generate a random integer within the specified range
-    fn int_range(&mut self, lower: i64, upper: i64) -> i64 {
-        self.inner.generate_range(0..(upper - lower) as u64) as i64 + lower
-    }
 }
 
+/// A permuted congruential random number generator which isn't
+/// cryptographically secure.
 #[derive(Any)]
 #[rune(item = ::rand)]
 struct Pcg64 {
@@ -102,54 +682,214 @@ struct Pcg64 {
 }
 
 impl Pcg64 {
-    /// Create a new RNG instance.
-    fn new() -> Self {
-        Self {
-            inner: nanorand::Pcg64::new(),
+    fn from_source(source: &Source) -> Self {
+        match source.seed() {
+            Some(seed) => Self::new_seed(seed as i64),
+            None => Self {
+                inner: nanorand::Pcg64::new(),
+            },
         }
     }
 
-    /// Create a new RNG instance, using a custom seed.
     fn new_seed(seed: i64) -> Self {
         Self {
             inner: nanorand::Pcg64::new_seed(seed as u128),
         }
     }
+}
+
+fn int<const N: usize>(rng: &mut impl Rng<N>) -> i64 {
+    rng.generate::<u64>() as i64
+}
 
-    /// Generate a random integer
-    fn int(&mut self) -> i64 {
-        self.inner.generate::<u64>() as i64
+fn int_range<const N: usize>(rng: &mut impl Rng<N>, lower: i64, upper: i64) -> VmResult<i64> {
+    if lower >= upper {
+        return VmResult::panic("Cannot generate a number in an empty range");
     }
 
-    /// Generate a random integer within the specified range
-    fn int_range(&mut self, lower: i64, upper: i64) -> i64 {
-        self.inner.generate_range(0..(upper - lower) as u64) as i64 + lower
+    VmResult::Ok(int_between(rng, lower, upper - 1))
+}
+
+/// Generate an integer between `lower` and `upper`, inclusive.
+fn int_between<const N: usize>(rng: &mut impl Rng<N>, lower: i64, upper: i64) -> i64 {
+    let span = upper.wrapping_sub(lower) as u64;
+
+    if span == u64::MAX {
+        return int(rng);
     }
+
+    lower.wrapping_add(rng.generate_range(0..=span) as i64)
+}
+
+/// Generate a float in the range `[0, 1)` with 53 bits of precision.
+fn float<const N: usize>(rng: &mut impl Rng<N>) -> f64 {
+    (rng.generate::<u64>() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
 }
 
-fn int() -> rune::support::Result<i64> {
-    Ok(nanorand::WyRand::new().generate::<u64>() as i64)
+/// Generate a float in the range `[0, 1]` with 53 bits of precision.
+fn float_inclusive<const N: usize>(rng: &mut impl Rng<N>) -> f64 {
+    (rng.generate::<u64>() >> 11) as f64 * (1.0 / ((1u64 << 53) - 1) as f64)
 }
 
-fn int_range(lower: i64, upper: i64) -> rune::support::Result<i64> {
-    Ok(nanorand::WyRand::new().generate_range(0..(upper - lower) as u64) as i64 + lower)
+fn boolean<const N: usize>(rng: &mut impl Rng<N>) -> bool {
+    rng.generate::<u64>() & 1 == 1
+}
+
+fn range_value<const N: usize>(rng: &mut impl Rng<N>, range: &Value) -> VmResult<Value> {
+    let (start, end, inclusive) = match range.type_hash() {
+        Range::HASH => {
+            let range = vm_try!(range.borrow_ref::<Range>());
+            (range.start.clone(), range.end.clone(), false)
+        }
+        RangeInclusive::HASH => {
+            let range = vm_try!(range.borrow_ref::<RangeInclusive>());
+            (range.start.clone(), range.end.clone(), true)
+        }
+        _ => return VmResult::expected::<Range>(range.type_info()),
+    };
+
+    if start.type_hash() == f64::HASH || end.type_hash() == f64::HASH {
+        let start = vm_try!(rune::from_value::<f64>(start));
+        let end = vm_try!(rune::from_value::<f64>(end));
+
+        let value = if inclusive {
+            if start.is_nan() || end.is_nan() || start > end {
+                return VmResult::panic("Cannot generate a number in an empty range");
+            }
+
+            start + float_inclusive(rng) * (end - start)
+        } else {
+            if start.is_nan() || end.is_nan() || start >= end {
+                return VmResult::panic("Cannot generate a number in an empty range");
+            }
+
+            // Rounding might land on the end of the range, which is excluded.
+            let value = start + float(rng) * (end - start);
+            if value < end {
+                value
+            } else {
+                start
+            }
+        };
+
+        return VmResult::Ok(vm_try!(rune::to_value(value)));
+    }
+
+    let start = vm_try!(start.as_integer::<i64>());
+    let end = vm_try!(end.as_integer::<i64>());
+
+    let value = if inclusive {
+        if start > end {
+            return VmResult::panic("Cannot generate a number in an empty range");
+        }
+
+        int_between(rng, start, end)
+    } else {
+        vm_try!(int_range(rng, start, end))
+    };
+
+    VmResult::Ok(vm_try!(rune::to_value(value)))
+}
+
+fn shuffle<const N: usize>(rng: &mut impl Rng<N>, values: &mut runtime::Vec) {
+    rng.shuffle(&mut values[..]);
+}
+
+fn choose<const N: usize>(rng: &mut impl Rng<N>, values: &runtime::Vec) -> Option<Value> {
+    if values.is_empty() {
+        return None;
+    }
+
+    values.get(rng.generate_range(0..values.len())).cloned()
+}
+
+fn choose_weighted<const N: usize>(
+    rng: &mut impl Rng<N>,
+    values: &runtime::Vec,
+    weights: &runtime::Vec,
+) -> VmResult<Option<Value>> {
+    if values.len() != weights.len() {
+        return VmResult::panic("Expected one weight for each value");
+    }
+
+    let mut cumulative = Vec::new();
+    let mut total = 0.0;
+
+    for weight in weights.iter() {
+        let weight = if weight.type_hash() == f64::HASH {
+            vm_try!(rune::from_value::<f64>(weight.clone()))
+        } else {
+            vm_try!(weight.as_integer::<i64>()) as f64
+        };
+
+        if !weight.is_finite() || weight < 0.0 {
+            return VmResult::panic("Weights must be finite and non-negative");
+        }
+
+        total += weight;
+        vm_try!(cumulative.try_push(total));
+    }
+
+    // NB: every weight is finite and non-negative, so the total can't be NaN.
+    if total <= 0.0 {
+        return VmResult::Ok(None);
+    }
+
+    let target = float(rng) * total;
+
+    // Entries with a zero weight never increase the cumulative weight, so the
+    // first entry past the target always has a positive weight.
+    let index = cumulative
+        .iter()
+        .position(|&weight| weight > target)
+        .or_else(|| cumulative.iter().rposition(|&weight| weight > 0.0));
+
+    VmResult::Ok(index.and_then(|index| values.get(index).cloned()))
+}
+
+fn bytes<const N: usize>(rng: &mut impl Rng<N>, len: usize) -> VmResult<Bytes> {
+    let mut bytes = vm_try!(Vec::try_with_capacity(len));
+    vm_try!(bytes.try_resize(len, 0));
+    rng.fill_bytes(&mut bytes[..]);
+    VmResult::Ok(Bytes::from_vec(bytes))
+}
+
+fn normal<const N: usize>(rng: &mut impl Rng<N>, mean: f64, std_dev: f64) -> VmResult<f64> {
+    if !std_dev.is_finite() || std_dev < 0.0 {
+        return VmResult::panic("Standard deviation must be finite and non-negative");
+    }
+
+    // Box-Muller transform, where the first sample is kept away from zero so
+    // that its logarithm is finite.
+    let u1 = 1.0 - float(rng);
+    let u2 = float(rng);
+    let z = (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos();
+    VmResult::Ok(mean + std_dev * z)
+}
+
+fn exponential<const N: usize>(rng: &mut impl Rng<N>, lambda: f64) -> VmResult<f64> {
+    if !lambda.is_finite() || lambda <= 0.0 {
+        return VmResult::panic("Rate must be positive and finite");
+    }
+
+    VmResult::Ok(-(1.0 - float(rng)).ln() / lambda)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{int, int_range};
+    use super::{int, int_range, Source};
 
     #[test]
     fn test_range_is_exclusive() {
         for _ in 0..100 {
-            assert_eq!(int_range(0, 1).unwrap(), 0);
+            assert_eq!(int_range(&mut nanorand::WyRand::new(), 0, 1).unwrap(), 0);
         }
     }
 
     #[test]
     fn test_range_can_be_negative() {
         for _ in 0..100 {
-            assert_eq!(int_range(-2, -1).unwrap(), -2);
+            assert_eq!(int_range(&mut nanorand::WyRand::new(), -2, -1).unwrap(), -2);
         }
     }
 
@@ -159,7 +899,7 @@ mod tests {
         let mut any_positive = false;
 
         for _ in 0..100 {
-            let v: i64 = int().unwrap();
+            let v: i64 = int(&mut nanorand::WyRand::new());
             any_negative = any_negative || v < 0;
             any_positive = any_positive || v > 0;
         }
@@ -167,4 +907,15 @@ mod tests {
         assert!(any_positive);
         assert!(any_negative);
     }
+
+    #[test]
+    fn test_seeded_source_is_deterministic() {
+        let a = Source::new(Some(42));
+        let b = Source::new(Some(42));
+
+        for _ in 0..100 {
+            assert_eq!(a.with(int), b.with(int));
+            assert_eq!(a.seed(), b.seed());
+        }
+    }
 }
@@ -0,0 +1,1050 @@
+//! The native `regex` module for the [Rune Language].
+//!
+//! [Rune Language]: https://rune-rs.github.io
+//!
+//! ## Usage
+//!
+//! Add the following to your `Cargo.toml`:
+//!
+//! ```toml
+//! rune-modules = { version = "0.14.0", features = ["regex"] }
+//! ```
+//!
+//! Install it into your context:
+//!
+//! ```rust
+//! let mut context = rune::Context::with_default_modules()?;
+//! context.install(rune_modules::regex::module(true)?)?;
+//! # Ok::<_, rune::support::Error>(())
+//! ```
+//!
+//! Use it in Rune:
+//!
+//! ```rust,ignore
+//! use regex::Regex;
+//!
+//! fn main() {
+//!     let re = Regex::new("(?<year>\\d{4})-(?<month>\\d{2})")?;
+//!     let caps = re.captures("Released 2024-05").unwrap();
+//!     dbg(caps["year"]);
+//! }
+//! ```
+//!
+//! ## Limits
+//!
+//! Patterns are compiled with limits which make it safe to compile patterns
+//! provided by untrusted sources. The underlying [`regex`] crate guarantees
+//! that searching is linear in the size of the haystack, and the limits in
+//! [`Config`] bound the size of compiled patterns and the memory used while
+//! searching. Use [`module_with_config`] to install the module with different
+//! limits.
+//!
+//! Compiled patterns are cached by the module, so constructing the same
+//! [`Regex`] repeatedly does not compile it more than once. A compiled `Regex`
+//! can also be provided to scripts as a constant through
+//! [`Module::constant`].
+//!
+//! ```rust
+//! use rune::Module;
+//! use rune_modules::regex::Regex;
+//!
+//! let mut module = Module::new();
+//! module.constant("DATE", Regex::new(r"\d{4}-\d{2}-\d{2}")?).build()?;
+//! # Ok::<_, rune::support::Error>(())
+//! ```
+//!
+//! [`regex`]: https://docs.rs/regex
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use rune::alloc::fmt::TryWrite;
+use rune::alloc::prelude::*;
+use rune::alloc::{String, Vec};
+use rune::runtime::{Formatter, Function, Value, VmResult};
+use rune::{docstring, item, vm_panic, vm_try, vm_write, Any, ContextError, Module, ToConstValue};
+
+/// Regular expressions based on the [`regex`] crate.
+///
+/// Matching is guaranteed to run in time linear to the size of the haystack,
+/// no matter which pattern is used.
+///
+/// [`regex`]: https://docs.rs/regex
+///
+/// # Examples
+///
+/// ```rune
+/// use regex::Regex;
+///
+/// let re = Regex::new("\\b\\w{5}\\b")?;
+/// let words = re.find_iter("Hello there, small world").map(|m| m.as_str()).collect::<Vec>();
+/// assert_eq!(words, ["Hello", "there", "small", "world"]);
+/// ```
+#[rune::module(::regex)]
+pub fn module(_stdio: bool) -> Result<Module, ContextError> {
+    module_with_config(Config::default())
+}
+
+/// Construct the `regex` module using the limits specified in the given
+/// [`Config`].
+pub fn module_with_config(config: Config) -> Result<Module, ContextError> {
+    let mut m = Module::from_meta(self::module_meta)?;
+
+    let cache = Arc::new(Cache::new(config));
+
+    m.ty::<Regex>()?;
+
+    m.function("new", move |pattern: &str| cache.compile(pattern))
+        .build_associated::<Regex>()?
+        .docs(docstring! {
+            /// Compiles a regular expression.
+            ///
+            /// Once compiled, it can be used repeatedly to search, split or
+            /// replace substrings in a text. Patterns are cached, so compiling
+            /// the same pattern twice reuses the first compilation.
+            ///
+            /// Compilation fails if the pattern is invalid or if it exceeds
+            /// the limits the module was configured with.
+            ///
+            /// # Examples
+            ///
+            /// ```rune
+            /// use regex::Regex;
+            ///
+            /// let re = Regex::new("^\\d+$")?;
+            /// assert!(re.is_match("1234"));
+            ///
+            /// assert!(Regex::new("(unclosed").is_err());
+            /// ```
+        })?
+        .argument_types::<(&str,)>()?
+        .return_type::<Result<Regex, Error>>()?;
+
+    m.function_meta(Regex::as_str__meta)?;
+    m.function_meta(Regex::is_match__meta)?;
+    m.function_meta(Regex::find__meta)?;
+    m.function_meta(Regex::find_iter__meta)?;
+    m.function_meta(Regex::captures__meta)?;
+    m.function_meta(Regex::captures_len__meta)?;
+    m.function_meta(Regex::replace__meta)?;
+    m.function_meta(Regex::replace_all__meta)?;
+    m.function_meta(Regex::split__meta)?;
+    m.function_meta(Regex::display_fmt__meta)?;
+    m.function_meta(Regex::debug_fmt__meta)?;
+    m.function_meta(Regex::clone__meta)?;
+    m.implement_trait::<Regex>(item!(::std::clone::Clone))?;
+
+    m.ty::<Match>()?;
+    m.function_meta(Match::start__meta)?;
+    m.function_meta(Match::end__meta)?;
+    m.function_meta(Match::len__meta)?;
+    m.function_meta(Match::is_empty__meta)?;
+    m.function_meta(Match::as_str__meta)?;
+    m.function_meta(Match::display_fmt__meta)?;
+    m.function_meta(Match::debug_fmt__meta)?;
+
+    m.ty::<Matches>()?;
+    m.function_meta(Matches::next__meta)?;
+    m.implement_trait::<Matches>(item!(::std::iter::Iterator))?;
+
+    m.ty::<Captures>()?;
+    m.function_meta(Captures::get__meta)?;
+    m.function_meta(Captures::name__meta)?;
+    m.function_meta(Captures::len__meta)?;
+    m.function_meta(Captures::index_get__meta)?;
+    m.function_meta(Captures::debug_fmt__meta)?;
+
+    m.ty::<Error>()?;
+    m.function_meta(Error::display_fmt__meta)?;
+    m.function_meta(Error::debug_fmt__meta)?;
+
+    m.function_meta(escape)?;
+    Ok(m)
+}
+
+/// Limits used when compiling regular expressions.
+///
+/// The defaults are conservative enough for patterns provided by untrusted
+/// sources.
+///
+/// # Examples
+///
+/// ```rust
+/// use rune_modules::regex::{self, Config};
+///
+/// let config = Config::new().size_limit(1 << 16).pattern_limit(256);
+///
+/// let mut context = rune::Context::with_default_modules()?;
+/// context.install(regex::module_with_config(config)?)?;
+/// # Ok::<_, rune::support::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Config {
+    size_limit: usize,
+    dfa_size_limit: usize,
+    nest_limit: u32,
+    pattern_limit: usize,
+    cache_limit: usize,
+}
+
+impl Config {
+    /// Construct a new default configuration.
+    pub fn new() -> Self {
+        Self {
+            size_limit: 1 << 20,
+            dfa_size_limit: 1 << 20,
+            nest_limit: 64,
+            pattern_limit: 4096,
+            cache_limit: 128,
+        }
+    }
+
+    /// Set the approximate size limit in bytes of a compiled pattern.
+    ///
+    /// Defaults to 1 MiB.
+    pub fn size_limit(mut self, limit: usize) -> Self {
+        self.size_limit = limit;
+        self
+    }
+
+    /// Set the approximate size limit in bytes of the cache used by lazy DFAs
+    /// while searching.
+    ///
+    /// Defaults to 1 MiB.
+    pub fn dfa_size_limit(mut self, limit: usize) -> Self {
+        self.dfa_size_limit = limit;
+        self
+    }
+
+    /// Set the maximum nesting depth of a pattern.
+    ///
+    /// Defaults to 64.
+    pub fn nest_limit(mut self, limit: u32) -> Self {
+        self.nest_limit = limit;
+        self
+    }
+
+    /// Set the maximum length in bytes of a pattern.
+    ///
+    /// Defaults to 4096.
+    pub fn pattern_limit(mut self, limit: usize) -> Self {
+        self.pattern_limit = limit;
+        self
+    }
+
+    /// Set the maximum number of compiled patterns to keep cached. Setting it
+    /// to zero disables caching.
+    ///
+    /// Defaults to 128.
+    pub fn cache_limit(mut self, limit: usize) -> Self {
+        self.cache_limit = limit;
+        self
+    }
+
+    fn compile(&self, pattern: &str) -> Result<regex::Regex, Error> {
+        if pattern.len() > self.pattern_limit {
+            return Err(Error {
+                kind: ErrorKind::PatternTooLong {
+                    actual: pattern.len(),
+                    limit: self.pattern_limit,
+                },
+            });
+        }
+
+        let regex = regex::RegexBuilder::new(pattern)
+            .size_limit(self.size_limit)
+            .dfa_size_limit(self.dfa_size_limit)
+            .nest_limit(self.nest_limit)
+            .build()?;
+
+        Ok(regex)
+    }
+}
+
+impl Default for Config {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Cache of compiled patterns.
+struct Cache {
+    config: Config,
+    patterns: Mutex<HashMap<std::string::String, regex::Regex>>,
+}
+
+impl Cache {
+    fn new(config: Config) -> Self {
+        Self {
+            config,
+            patterns: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn compile(&self, pattern: &str) -> Result<Regex, Error> {
+        let mut patterns = self.patterns.lock().unwrap_or_else(PoisonError::into_inner);
+
+        if let Some(inner) = patterns.get(pattern) {
+            return Ok(Regex {
+                inner: inner.clone(),
+            });
+        }
+
+        let inner = self.config.compile(pattern)?;
+
+        if self.config.cache_limit > 0 {
+            // NB: Evicting everything keeps the cache bounded without having
+            // to track usage, scripts rarely use more than a handful of
+            // patterns.
+            if patterns.len() >= self.config.cache_limit {
+                patterns.clear();
+            }
+
+            patterns.insert(pattern.to_owned(), inner.clone());
+        }
+
+        Ok(Regex { inner })
+    }
+}
+
+/// A compiled regular expression.
+///
+/// # Examples
+///
+/// ```rune
+/// use regex::Regex;
+///
+/// let re = Regex::new("[a-z]+")?;
+/// assert_eq!(re.find("123abc456")?.as_str(), "abc");
+/// ```
+#[derive(Any, ToConstValue)]
+#[rune(item = ::regex)]
+pub struct Regex {
+    #[const_value(with = self::const_regex)]
+    inner: regex::Regex,
+}
+
+impl Regex {
+    /// Compile a regular expression using the default [`Config`].
+    pub fn new(pattern: &str) -> Result<Self, Error> {
+        Ok(Self {
+            inner: Config::default().compile(pattern)?,
+        })
+    }
+
+    /// Returns the original pattern of this regular expression.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use regex::Regex;
+    ///
+    /// let re = Regex::new("\\d+")?;
+    /// assert_eq!(re.as_str(), "\\d+");
+    /// ```
+    #[rune::function(keep, vm_result)]
+    fn as_str(&self) -> String {
+        self.inner.as_str().try_to_owned().vm?
+    }
+
+    /// Returns `true` if and only if there is a match for the regex anywhere
+    /// in the given haystack.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use regex::Regex;
+    ///
+    /// let re = Regex::new("\\b\\w{13}\\b")?;
+    /// assert!(re.is_match("I categorically deny having triskaidekaphobia."));
+    /// assert!(!re.is_match("No long words here."));
+    /// ```
+    #[rune::function(keep)]
+    fn is_match(&self, haystack: &str) -> bool {
+        self.inner.is_match(haystack)
+    }
+
+    /// Returns the leftmost-first match in the haystack, if one exists.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use regex::Regex;
+    ///
+    /// let re = Regex::new("\\d+")?;
+    /// let m = re.find("abc 1234 def")?;
+    ///
+    /// assert_eq!(m.as_str(), "1234");
+    /// assert_eq!(m.start(), 4);
+    /// assert_eq!(m.end(), 8);
+    ///
+    /// assert!(re.find("no digits").is_none());
+    /// ```
+    #[rune::function(keep, vm_result)]
+    fn find(&self, haystack: &str) -> Option<Match> {
+        let Some(m) = self.inner.find(haystack) else {
+            return None;
+        };
+
+        Some(Match::new(m).vm?)
+    }
+
+    /// Returns an iterator over all successive non-overlapping matches in the
+    /// haystack.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use regex::Regex;
+    ///
+    /// let re = Regex::new("\\d+")?;
+    /// let numbers = re.find_iter("1, 22 and 333").map(|m| m.as_str()).collect::<Vec>();
+    /// assert_eq!(numbers, ["1", "22", "333"]);
+    /// ```
+    #[rune::function(keep, vm_result)]
+    fn find_iter(&self, haystack: &str) -> Matches {
+        Matches {
+            regex: self.inner.clone(),
+            haystack: haystack.try_to_owned().vm?,
+            at: 0,
+            last_end: None,
+        }
+    }
+
+    /// Returns the capture groups of the leftmost-first match in the
+    /// haystack, if one exists.
+    ///
+    /// Groups can be accessed by index, where index `0` is the whole match,
+    /// or by name.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use regex::Regex;
+    ///
+    /// let re = Regex::new("(?<key>\\w+)=(\\d+)")?;
+    /// let caps = re.captures("width=640")?;
+    ///
+    /// assert_eq!(caps[0], "width=640");
+    /// assert_eq!(caps["key"], "width");
+    /// assert_eq!(caps.get(2)?.as_str(), "640");
+    /// assert_eq!(caps.name("key")?.start(), 0);
+    /// ```
+    #[rune::function(keep, vm_result)]
+    fn captures(&self, haystack: &str) -> Option<Captures> {
+        let Some(caps) = self.inner.captures(haystack) else {
+            return None;
+        };
+
+        Some(Captures::new(&self.inner, &caps).vm?)
+    }
+
+    /// Returns the number of capture groups in this regex, including the
+    /// implicit group for the whole match.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use regex::Regex;
+    ///
+    /// let re = Regex::new("(a)(b)?")?;
+    /// assert_eq!(re.captures_len(), 3);
+    /// ```
+    #[rune::function(keep)]
+    fn captures_len(&self) -> usize {
+        self.inner.captures_len()
+    }
+
+    /// Replaces the leftmost-first match in the haystack with the
+    /// replacement.
+    ///
+    /// See [`Regex::replace_all`] for how the replacement is interpreted.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use regex::Regex;
+    ///
+    /// let re = Regex::new("\\d+")?;
+    /// assert_eq!(re.replace("1 2 3", "x"), "x 2 3");
+    /// ```
+    #[rune::function(keep)]
+    fn replace(&self, haystack: &str, replacement: Value) -> VmResult<String> {
+        self.replacen(haystack, 1, replacement)
+    }
+
+    /// Replaces all non-overlapping matches in the haystack with the
+    /// replacement.
+    ///
+    /// The replacement is either a string, in which case `$name` and `$1`
+    /// references are expanded to the corresponding capture group, or a
+    /// function which is called with the [`Captures`] of each match and
+    /// returns the string to replace it with.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use regex::Regex;
+    ///
+    /// let re = Regex::new("(?<first>\\w+)\\s+(?<last>\\w+)")?;
+    /// assert_eq!(re.replace_all("Springsteen Bruce", "$last $first"), "Bruce Springsteen");
+    ///
+    /// let re = Regex::new("\\d+")?;
+    /// let doubled = re.replace_all("1 and 20", |caps| {
+    ///     let n = std::i64::parse(caps[0])?;
+    ///     (n * 2).to_string()
+    /// });
+    /// assert_eq!(doubled, "2 and 40");
+    /// ```
+    #[rune::function(keep)]
+    fn replace_all(&self, haystack: &str, replacement: Value) -> VmResult<String> {
+        self.replacen(haystack, 0, replacement)
+    }
+
+    fn replacen(&self, haystack: &str, limit: usize, replacement: Value) -> VmResult<String> {
+        if let Ok(replacement) = replacement.borrow_string_ref() {
+            let output = self.inner.replacen(haystack, limit, &*replacement);
+            return VmResult::Ok(vm_try!(output.as_ref().try_to_owned()));
+        }
+
+        let function = vm_try!(rune::from_value::<Function>(replacement));
+
+        let mut output = String::new();
+        let mut last = 0;
+
+        for (n, caps) in self.inner.captures_iter(haystack).enumerate() {
+            if limit > 0 && n >= limit {
+                break;
+            }
+
+            // NB: Group 0 always participates in a match.
+            let Some(m) = caps.get(0) else {
+                continue;
+            };
+
+            vm_try!(output.try_push_str(&haystack[last..m.start()]));
+            let captures = vm_try!(Captures::new(&self.inner, &caps));
+            let string = vm_try!(function.call::<String>((captures,)));
+            vm_try!(output.try_push_str(&string));
+            last = m.end();
+        }
+
+        vm_try!(output.try_push_str(&haystack[last..]));
+        VmResult::Ok(output)
+    }
+
+    /// Splits the haystack by the matches of this regex, returning the
+    /// substrings between them.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use regex::Regex;
+    ///
+    /// let re = Regex::new("[ \\t]+")?;
+    /// assert_eq!(re.split("a b \t  c\td"), ["a", "b", "c", "d"]);
+    /// ```
+    #[rune::function(keep, vm_result)]
+    fn split(&self, haystack: &str) -> Vec<String> {
+        let mut output = Vec::new();
+
+        for part in self.inner.split(haystack) {
+            output.try_push(part.try_to_owned().vm?).vm?;
+        }
+
+        output
+    }
+
+    /// Clone the regex. This is cheap, since the compiled program is shared.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use regex::Regex;
+    ///
+    /// let a = Regex::new("\\d")?;
+    /// let b = a.clone();
+    /// assert_eq!(a.as_str(), b.as_str());
+    /// ```
+    #[rune::function(keep, instance, protocol = CLONE)]
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+
+    /// Display the pattern of the regex.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use regex::Regex;
+    ///
+    /// let re = Regex::new("\\d+")?;
+    /// assert_eq!(format!("{}", re), "\\d+");
+    /// ```
+    #[rune::function(keep, protocol = DISPLAY_FMT)]
+    fn display_fmt(&self, f: &mut Formatter) -> VmResult<()> {
+        vm_write!(f, "{}", self.inner)
+    }
+
+    /// Debug format the regex.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use regex::Regex;
+    ///
+    /// let re = Regex::new("\\d+")?;
+    /// assert_eq!(format!("{:?}", re), "Regex(\\d+)");
+    /// ```
+    #[rune::function(keep, protocol = DEBUG_FMT)]
+    fn debug_fmt(&self, f: &mut Formatter) -> VmResult<()> {
+        vm_write!(f, "Regex({})", self.inner)
+    }
+}
+
+/// A single match of a regex in a haystack.
+#[derive(Any)]
+#[rune(item = ::regex)]
+pub struct Match {
+    start: usize,
+    end: usize,
+    text: String,
+}
+
+impl Match {
+    fn new(m: regex::Match<'_>) -> rune::alloc::Result<Self> {
+        Ok(Self {
+            start: m.start(),
+            end: m.end(),
+            text: m.as_str().try_to_owned()?,
+        })
+    }
+
+    /// Returns the byte offset of the start of the match in the haystack.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use regex::Regex;
+    ///
+    /// let re = Regex::new("b+")?;
+    /// assert_eq!(re.find("abbc")?.start(), 1);
+    /// ```
+    #[rune::function(keep)]
+    fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the byte offset of the end of the match in the haystack.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use regex::Regex;
+    ///
+    /// let re = Regex::new("b+")?;
+    /// assert_eq!(re.find("abbc")?.end(), 3);
+    /// ```
+    #[rune::function(keep)]
+    fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Returns the length in bytes of the match.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use regex::Regex;
+    ///
+    /// let re = Regex::new("b+")?;
+    /// assert_eq!(re.find("abbc")?.len(), 2);
+    /// ```
+    #[rune::function(keep)]
+    fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Returns `true` if the match is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use regex::Regex;
+    ///
+    /// let re = Regex::new("b*")?;
+    /// assert!(re.find("abbc")?.is_empty());
+    /// ```
+    #[rune::function(keep)]
+    fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns the matched text.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use regex::Regex;
+    ///
+    /// let re = Regex::new("b+")?;
+    /// assert_eq!(re.find("abbc")?.as_str(), "bb");
+    /// ```
+    #[rune::function(keep, vm_result)]
+    fn as_str(&self) -> String {
+        self.text.try_clone().vm?
+    }
+
+    /// Display the matched text.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use regex::Regex;
+    ///
+    /// let re = Regex::new("b+")?;
+    /// assert_eq!(format!("{}", re.find("abbc")?), "bb");
+    /// ```
+    #[rune::function(keep, protocol = DISPLAY_FMT)]
+    fn display_fmt(&self, f: &mut Formatter) -> VmResult<()> {
+        vm_write!(f, "{}", self.text)
+    }
+
+    /// Debug format the match.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use regex::Regex;
+    ///
+    /// let re = Regex::new("b+")?;
+    /// assert_eq!(format!("{:?}", re.find("abbc")?), "Match { start: 1, end: 3, text: \"bb\" }");
+    /// ```
+    #[rune::function(keep, protocol = DEBUG_FMT)]
+    fn debug_fmt(&self, f: &mut Formatter) -> VmResult<()> {
+        vm_write!(
+            f,
+            "Match {{ start: {}, end: {}, text: {:?} }}",
+            self.start,
+            self.end,
+            self.text
+        )
+    }
+}
+
+/// An iterator over all non-overlapping matches in a haystack.
+#[derive(Any)]
+#[rune(item = ::regex)]
+pub struct Matches {
+    regex: regex::Regex,
+    haystack: String,
+    at: usize,
+    last_end: Option<usize>,
+}
+
+impl Matches {
+    #[rune::function(keep, protocol = NEXT)]
+    fn next(&mut self) -> VmResult<Option<Match>> {
+        while self.at <= self.haystack.len() {
+            let Some(m) = self.regex.find_at(&self.haystack, self.at) else {
+                break;
+            };
+
+            // NB: An empty match right after the previous match is not
+            // allowed, so we step over one character and try again.
+            if m.is_empty() && Some(m.end()) == self.last_end {
+                self.at = match self.haystack[self.at..].chars().next() {
+                    Some(c) => self.at + c.len_utf8(),
+                    None => self.haystack.len() + 1,
+                };
+
+                continue;
+            }
+
+            self.at = m.end();
+            self.last_end = Some(m.end());
+            return VmResult::Ok(Some(vm_try!(Match::new(m))));
+        }
+
+        self.at = self.haystack.len() + 1;
+        VmResult::Ok(None)
+    }
+}
+
+/// The capture groups of a single match.
+#[derive(Any)]
+#[rune(item = ::regex)]
+pub struct Captures {
+    regex: regex::Regex,
+    groups: Vec<Option<Match>>,
+}
+
+impl Captures {
+    fn new(regex: &regex::Regex, caps: &regex::Captures<'_>) -> rune::alloc::Result<Self> {
+        let mut groups = Vec::try_with_capacity(caps.len())?;
+
+        for m in caps.iter() {
+            groups.try_push(m.map(Match::new).transpose()?)?;
+        }
+
+        Ok(Self {
+            regex: regex.clone(),
+            groups,
+        })
+    }
+
+    fn index_of(&self, name: &str) -> Option<usize> {
+        self.regex
+            .capture_names()
+            .position(|n| n.is_some_and(|n| n == name))
+    }
+
+    fn group(&self, index: usize) -> rune::alloc::Result<Option<Match>> {
+        let Some(Some(m)) = self.groups.get(index) else {
+            return Ok(None);
+        };
+
+        Ok(Some(Match {
+            start: m.start,
+            end: m.end,
+            text: m.text.try_clone()?,
+        }))
+    }
+
+    /// Returns the match associated with the capture group at the given
+    /// index, or `None` if the group did not participate in the match.
+    ///
+    /// Index `0` always corresponds to the overall match.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use regex::Regex;
+    ///
+    /// let re = Regex::new("(a)|(b)")?;
+    /// let caps = re.captures("b")?;
+    ///
+    /// assert_eq!(caps.get(0)?.as_str(), "b");
+    /// assert!(caps.get(1).is_none());
+    /// assert_eq!(caps.get(2)?.as_str(), "b");
+    /// ```
+    #[rune::function(keep, vm_result)]
+    fn get(&self, index: usize) -> Option<Match> {
+        self.group(index).vm?
+    }
+
+    /// Returns the match for the capture group with the given name, or `None`
+    /// if there is no such group or it did not participate in the match.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use regex::Regex;
+    ///
+    /// let re = Regex::new("(?<word>\\w+)")?;
+    /// let caps = re.captures("hello")?;
+    ///
+    /// assert_eq!(caps.name("word")?.as_str(), "hello");
+    /// assert!(caps.name("missing").is_none());
+    /// ```
+    #[rune::function(keep, vm_result)]
+    fn name(&self, name: &str) -> Option<Match> {
+        let Some(index) = self.index_of(name) else {
+            return None;
+        };
+
+        self.group(index).vm?
+    }
+
+    /// Returns the number of capture groups, including the group for the
+    /// whole match.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use regex::Regex;
+    ///
+    /// let re = Regex::new("(a)(b)?")?;
+    /// assert_eq!(re.captures("a")?.len(), 3);
+    /// ```
+    #[rune::function(keep)]
+    fn len(&self) -> usize {
+        self.groups.len()
+    }
+
+    /// Get the text of a capture group, either by index or by name.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the group does not exist or did not participate in the
+    /// match.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use regex::Regex;
+    ///
+    /// let re = Regex::new("(?<y>\\d{4})-(\\d{2})")?;
+    /// let caps = re.captures("2024-05")?;
+    ///
+    /// assert_eq!(caps[0], "2024-05");
+    /// assert_eq!(caps["y"], "2024");
+    /// assert_eq!(caps[2], "05");
+    /// ```
+    #[rune::function(keep, protocol = INDEX_GET)]
+    fn index_get(&self, key: Value) -> VmResult<String> {
+        let index = if let Ok(name) = key.borrow_string_ref() {
+            match self.index_of(&name) {
+                Some(index) => index,
+                None => vm_panic!(format!("No capture group named `{}`", &*name)),
+            }
+        } else {
+            vm_try!(rune::from_value::<usize>(key))
+        };
+
+        let Some(Some(m)) = self.groups.get(index) else {
+            vm_panic!(format!("No match for capture group {index}"));
+        };
+
+        VmResult::Ok(vm_try!(m.text.try_clone()))
+    }
+
+    /// Debug format the capture groups.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use regex::Regex;
+    ///
+    /// let re = Regex::new("(a)(b)?")?;
+    /// assert_eq!(format!("{:?}", re.captures("a")?), "Captures([Some(\"a\"), Some(\"a\"), None])");
+    /// ```
+    #[rune::function(keep, protocol = DEBUG_FMT)]
+    fn debug_fmt(&self, f: &mut Formatter) -> VmResult<()> {
+        vm_try!(vm_write!(f, "Captures(["));
+
+        let mut it = self.groups.iter().peekable();
+
+        while let Some(group) = it.next() {
+            match group {
+                Some(m) => vm_try!(vm_write!(f, "Some({:?})", m.text)),
+                None => vm_try!(vm_write!(f, "None")),
+            }
+
+            if it.peek().is_some() {
+                vm_try!(vm_write!(f, ", "));
+            }
+        }
+
+        vm_write!(f, "])")
+    }
+}
+
+/// An error raised when compiling a regular expression.
+#[derive(Debug, Any)]
+#[rune(item = ::regex)]
+pub struct Error {
+    kind: ErrorKind,
+}
+
+#[derive(Debug)]
+enum ErrorKind {
+    Regex(regex::Error),
+    PatternTooLong { actual: usize, limit: usize },
+}
+
+impl From<regex::Error> for Error {
+    #[inline]
+    fn from(error: regex::Error) -> Self {
+        Self {
+            kind: ErrorKind::Regex(error),
+        }
+    }
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match &self.kind {
+            ErrorKind::Regex(error) => error.fmt(f),
+            ErrorKind::PatternTooLong { actual, limit } => write!(
+                f,
+                "Pattern of {actual} bytes exceeds the limit of {limit} bytes"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+impl Error {
+    /// Display the error.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use regex::Regex;
+    ///
+    /// if let Err(error) = Regex::new("(") {
+    ///     assert!(format!("{}", error).contains("unclosed group"));
+    /// }
+    /// ```
+    #[rune::function(keep, protocol = DISPLAY_FMT)]
+    fn display_fmt(&self, f: &mut Formatter) -> VmResult<()> {
+        vm_write!(f, "{}", self)
+    }
+
+    #[rune::function(keep, protocol = DEBUG_FMT)]
+    fn debug_fmt(&self, f: &mut Formatter) -> VmResult<()> {
+        vm_write!(f, "{:?}", self)
+    }
+}
+
+/// Escapes all regular expression meta characters in the given text, so that
+/// it can be matched literally.
+///
+/// # Examples
+///
+/// ```rune
+/// use regex::Regex;
+///
+/// let re = Regex::new(regex::escape("1.5+2"))?;
+/// assert!(re.is_match("1.5+2"));
+/// assert!(!re.is_match("105+2"));
+/// ```
+#[rune::function(vm_result)]
+fn escape(text: &str) -> String {
+    let escaped = regex::escape(text);
+    escaped.as_str().try_to_owned().vm?
+}
+
+mod const_regex {
+    use rune::alloc::prelude::*;
+    use rune::alloc::String;
+    use rune::runtime::{ConstValue, RuntimeError, Value};
+
+    #[inline]
+    pub(super) fn to_const_value(regex: regex::Regex) -> Result<ConstValue, RuntimeError> {
+        Ok(ConstValue::from(regex.as_str().try_to_owned()?))
+    }
+
+    #[inline]
+    pub(super) fn from_const_value(value: &ConstValue) -> Result<regex::Regex, RuntimeError> {
+        compile(&rune::from_const_value::<String>(value)?)
+    }
+
+    #[inline]
+    pub(super) fn from_value(value: Value) -> Result<regex::Regex, RuntimeError> {
+        compile(&rune::from_value::<String>(value)?)
+    }
+
+    fn compile(pattern: &str) -> Result<regex::Regex, RuntimeError> {
+        match super::Config::default().compile(pattern) {
+            Ok(regex) => Ok(regex),
+            Err(error) => Err(RuntimeError::panic(error)),
+        }
+    }
+}
@@ -30,16 +30,28 @@
 //! ```
 
 use core::cmp::Ordering;
+use core::fmt;
 use core::hash::Hash;
 
+use std::sync::Arc;
+
 use rune::alloc::fmt::TryWrite;
+use rune::alloc::String;
 use rune::runtime::{Formatter, Hasher, Mut, VmResult};
-use rune::{docstring, item, vm_panic, Any, ContextError, Module, ToConstValue};
+use rune::{docstring, item, vm_panic, vm_try, Any, ContextError, Module, ToConstValue};
 
 const NANOS_PER_SEC: u32 = 1_000_000_000;
 
 /// Construct the `time` module.
 pub fn module(_stdio: bool) -> Result<Module, ContextError> {
+    module_with_config(Config::default())
+}
+
+/// Construct the `time` module with the given configuration.
+///
+/// See [`Config`] for how to freeze the clocks used by [`SystemTime::now`],
+/// [`DateTime::now`], [`Instant::now`] and [`Instant::elapsed`].
+pub fn module_with_config(config: Config) -> Result<Module, ContextError> {
     let mut m = Module::with_crate("time")?;
 
     m.function_meta(sleep)?;
@@ -194,9 +206,56 @@ pub fn module(_stdio: bool) -> Result<Module, ContextError> {
     })?;
 
     m.ty::<Instant>()?;
-    m.function_meta(Instant::now__meta)?;
+
+    m.function("now", {
+        let clock = config.clock.clone();
+        move || Instant::from_std(clock.instant())
+    })
+    .build_associated::<Instant>()?
+    .docs(docstring! {
+        /// Returns an instant corresponding to `now`.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        /// use time::{Duration, Instant};
+        ///
+        /// let instant = Instant::now();
+        /// ```
+    })?;
+
     m.function_meta(Instant::duration_since__meta)?;
-    m.function_meta(Instant::elapsed__meta)?;
+
+    m.function("elapsed", {
+        let clock = config.clock.clone();
+
+        move |this: &Instant| {
+            let now = tokio::time::Instant::from_std(clock.instant());
+
+            Duration {
+                inner: now.saturating_duration_since(this.inner),
+            }
+        }
+    })
+    .build_associated::<Instant>()?
+    .docs(docstring! {
+        /// Returns the amount of time elapsed since this instant was created,
+        /// or zero duration if that this instant is in the future.
+        ///
+        /// # Examples
+        ///
+        /// ```rune,no_run
+        /// use time::{Duration, Instant};
+        ///
+        /// let instant = Instant::now();
+        ///
+        /// let three_secs = Duration::from_secs(3);
+        /// time::sleep(three_secs).await;
+        ///
+        /// let elapsed = instant.elapsed();
+        /// ```
+    })?;
+
     m.function_meta(Instant::add__meta)?;
     m.function_meta(Instant::add_assign__meta)?;
     m.function_meta(Instant::partial_eq__meta)?;
@@ -220,6 +279,133 @@ pub fn module(_stdio: bool) -> Result<Module, ContextError> {
     m.function_meta(Interval::reset_after__meta)?;
     m.function_meta(Interval::reset_at__meta)?;
 
+    m.ty::<SystemTime>()?;
+
+    m.function("now", {
+        let clock = config.clock.clone();
+        move || SystemTime { inner: clock.now() }
+    })
+    .build_associated::<SystemTime>()?
+    .docs(docstring! {
+        /// Returns the current time of the system clock.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        /// use time::SystemTime;
+        ///
+        /// let now = SystemTime::now();
+        /// assert!(now > SystemTime::UNIX_EPOCH);
+        /// ```
+    })?;
+
+    m.constant(
+        "UNIX_EPOCH",
+        SystemTime {
+            inner: std::time::UNIX_EPOCH,
+        },
+    )
+    .build_associated::<SystemTime>()?
+    .docs(docstring! {
+        /// An anchor in time which is defined as 1970-01-01 00:00:00 UTC.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        /// use time::{DateTime, SystemTime};
+        ///
+        /// let date = DateTime::from_system_time(SystemTime::UNIX_EPOCH);
+        /// assert_eq!(date.year(), 1970);
+        /// ```
+    })?;
+
+    m.function_meta(SystemTime::duration_since__meta)?;
+    m.function_meta(SystemTime::add__meta)?;
+    m.function_meta(SystemTime::add_assign__meta)?;
+    m.function_meta(SystemTime::sub__meta)?;
+    m.function_meta(SystemTime::sub_assign__meta)?;
+    m.function_meta(SystemTime::partial_eq__meta)?;
+    m.implement_trait::<SystemTime>(item!(::std::cmp::PartialEq))?;
+    m.function_meta(SystemTime::eq__meta)?;
+    m.implement_trait::<SystemTime>(item!(::std::cmp::Eq))?;
+    m.function_meta(SystemTime::partial_cmp__meta)?;
+    m.implement_trait::<SystemTime>(item!(::std::cmp::PartialOrd))?;
+    m.function_meta(SystemTime::cmp__meta)?;
+    m.implement_trait::<SystemTime>(item!(::std::cmp::Ord))?;
+    m.function_meta(SystemTime::hash__meta)?;
+    m.function_meta(SystemTime::debug_fmt__meta)?;
+    m.function_meta(SystemTime::clone__meta)?;
+    m.implement_trait::<SystemTime>(item!(::std::clone::Clone))?;
+
+    m.ty::<DateTime>()?;
+
+    m.function("now", {
+        let clock = config.clock.clone();
+        move || DateTime::from_utc(clock.now().into())
+    })
+    .build_associated::<DateTime>()?
+    .docs(docstring! {
+        /// Returns the current date and time in UTC.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        /// use time::DateTime;
+        ///
+        /// let now = DateTime::now();
+        /// assert_eq!(now.offset(), 0);
+        /// assert!(now.year() >= 1970);
+        /// ```
+    })?;
+
+    m.function_meta(DateTime::from_timestamp__meta)?;
+    m.function_meta(DateTime::from_timestamp_millis__meta)?;
+    m.function_meta(DateTime::from_ymd__meta)?;
+    m.function_meta(DateTime::from_system_time__meta)?;
+    m.function_meta(DateTime::parse_rfc3339__meta)?;
+    m.function_meta(DateTime::parse__meta)?;
+    m.function_meta(DateTime::parse_from_str__meta)?;
+    m.function_meta(DateTime::rune_to_rfc3339__meta)?;
+    m.function_meta(DateTime::format__meta)?;
+    m.function_meta(DateTime::with_offset__meta)?;
+    m.function_meta(DateTime::with_hms__meta)?;
+    m.function_meta(DateTime::rune_to_utc__meta)?;
+    m.function_meta(DateTime::rune_to_system_time__meta)?;
+    m.function_meta(DateTime::offset__meta)?;
+    m.function_meta(DateTime::timestamp__meta)?;
+    m.function_meta(DateTime::timestamp_millis__meta)?;
+    m.function_meta(DateTime::year__meta)?;
+    m.function_meta(DateTime::month__meta)?;
+    m.function_meta(DateTime::day__meta)?;
+    m.function_meta(DateTime::ordinal__meta)?;
+    m.function_meta(DateTime::weekday__meta)?;
+    m.function_meta(DateTime::hour__meta)?;
+    m.function_meta(DateTime::minute__meta)?;
+    m.function_meta(DateTime::second__meta)?;
+    m.function_meta(DateTime::nanosecond__meta)?;
+    m.function_meta(DateTime::duration_since__meta)?;
+    m.function_meta(DateTime::add__meta)?;
+    m.function_meta(DateTime::add_assign__meta)?;
+    m.function_meta(DateTime::sub__meta)?;
+    m.function_meta(DateTime::sub_assign__meta)?;
+    m.function_meta(DateTime::partial_eq__meta)?;
+    m.implement_trait::<DateTime>(item!(::std::cmp::PartialEq))?;
+    m.function_meta(DateTime::eq__meta)?;
+    m.implement_trait::<DateTime>(item!(::std::cmp::Eq))?;
+    m.function_meta(DateTime::partial_cmp__meta)?;
+    m.implement_trait::<DateTime>(item!(::std::cmp::PartialOrd))?;
+    m.function_meta(DateTime::cmp__meta)?;
+    m.implement_trait::<DateTime>(item!(::std::cmp::Ord))?;
+    m.function_meta(DateTime::hash__meta)?;
+    m.function_meta(DateTime::display_fmt__meta)?;
+    m.function_meta(DateTime::debug_fmt__meta)?;
+    m.function_meta(DateTime::clone__meta)?;
+    m.implement_trait::<DateTime>(item!(::std::clone::Clone))?;
+
+    m.ty::<Error>()?;
+    m.function_meta(Error::display_fmt__meta)?;
+    m.function_meta(Error::debug_fmt__meta)?;
+
     Ok(m)
 }
 
@@ -889,6 +1075,43 @@ mod const_duration {
     }
 }
 
+mod const_system_time {
+    use rune::runtime::{ConstValue, RuntimeError, Value};
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    #[inline]
+    pub(super) fn to_const_value(time: SystemTime) -> Result<ConstValue, RuntimeError> {
+        let (before, duration) = match time.duration_since(UNIX_EPOCH) {
+            Ok(duration) => (false, duration),
+            Err(error) => (true, error.duration()),
+        };
+
+        rune::to_const_value((before, duration.as_secs(), duration.subsec_nanos()))
+    }
+
+    #[inline]
+    pub(super) fn from_const_value(value: &ConstValue) -> Result<SystemTime, RuntimeError> {
+        let (before, secs, nanos) = rune::from_const_value::<(bool, u64, u32)>(value)?;
+        Ok(from_parts(before, secs, nanos))
+    }
+
+    #[inline]
+    pub(super) fn from_value(value: Value) -> Result<SystemTime, RuntimeError> {
+        let (before, secs, nanos) = rune::from_value::<(bool, u64, u32)>(value)?;
+        Ok(from_parts(before, secs, nanos))
+    }
+
+    fn from_parts(before: bool, secs: u64, nanos: u32) -> SystemTime {
+        let duration = Duration::new(secs, nanos);
+
+        if before {
+            UNIX_EPOCH - duration
+        } else {
+            UNIX_EPOCH + duration
+        }
+    }
+}
+
 /// Interval returned by [`interval`] and [`interval_at`].
 ///
 /// This type allows you to wait on a sequence of instants with a certain
@@ -1056,20 +1279,20 @@ pub struct Instant {
 impl Instant {
     /// Returns an instant corresponding to `now`.
     ///
-    /// # Examples
-    ///
-    /// ```rune
-    /// use time::{Duration, Instant};
-    ///
-    /// let instant = Instant::now();
-    /// ```
-    #[rune::function(keep, path = Self::now)]
+    /// This always uses the clock of the Tokio runtime, unlike `Instant::now`
+    /// in scripts which uses the [`Clock`] the module was configured with.
     pub fn now() -> Instant {
         Instant {
             inner: tokio::time::Instant::now(),
         }
     }
 
+    fn from_std(instant: std::time::Instant) -> Self {
+        Self {
+            inner: tokio::time::Instant::from_std(instant),
+        }
+    }
+
     /// Returns the amount of time elapsed from another instant to this one, or
     /// zero duration if that instant is later than this one.
     ///
@@ -1096,19 +1319,7 @@ impl Instant {
     /// Returns the amount of time elapsed since this instant was created, or
     /// zero duration if that this instant is in the future.
     ///
-    /// # Examples
-    ///
-    /// ```rune,no_run
-    /// use time::{Duration, Instant};
-    ///
-    /// let instant = Instant::now();
-    ///
-    /// let three_secs = Duration::from_secs(3);
-    /// time::sleep(three_secs).await;
-    ///
-    /// let elapsed = instant.elapsed();
-    /// ```
-    #[rune::function(instance, keep)]
+    /// Like [`Instant::now`], this always uses the clock of the Tokio runtime.
     pub fn elapsed(&self) -> Duration {
         Duration {
             inner: tokio::time::Instant::elapsed(&self.inner),
@@ -1313,3 +1524,1220 @@ impl Instant {
         Self { inner: self.inner }
     }
 }
+
+/// A source of time for the `time` module.
+///
+/// The wall-clock time returned by [`Clock::now`] is used by
+/// [`SystemTime::now`] and [`DateTime::now`], and the monotonic time returned
+/// by [`Clock::instant`] is used by [`Instant::now`] and [`Instant::elapsed`].
+///
+/// This is implemented for any function returning a
+/// [`std::time::SystemTime`], so the wall-clock can be frozen in tests. To
+/// also control the monotonic clock, implement this trait directly.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// use rune::{Context, Source, Sources, Vm};
+/// use rune_modules::time::{self, Config};
+///
+/// let config = Config::new().clock(|| UNIX_EPOCH + Duration::from_secs(1_700_000_000));
+///
+/// let mut context = Context::with_default_modules()?;
+/// context.install(time::module_with_config(config)?)?;
+/// let runtime = Arc::new(context.runtime()?);
+///
+/// let mut sources = Sources::new();
+/// sources.insert(Source::memory("pub fn main() { time::DateTime::now().to_rfc3339() }")?)?;
+///
+/// let unit = rune::prepare(&mut sources).with_context(&context).build()?;
+/// let mut vm = Vm::new(runtime, Arc::new(unit));
+///
+/// let output: String = rune::from_value(vm.call(["main"], ())?)?;
+/// assert_eq!(output, "2023-11-14T22:13:20+00:00");
+/// # Ok::<_, rune::support::Error>(())
+/// ```
+pub trait Clock: 'static + Send + Sync {
+    /// Get the current wall-clock time.
+    fn now(&self) -> std::time::SystemTime;
+
+    /// Get the current time of the monotonic clock.
+    ///
+    /// Defaults to the clock of the Tokio runtime, which can be paused and
+    /// advanced with [`tokio::time::pause`] and [`tokio::time::advance`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+    ///
+    /// use rune::{Context, Source, Sources, Vm};
+    /// use rune_modules::time::{self, Clock, Config};
+    ///
+    /// struct Frozen {
+    ///     instant: Instant,
+    /// }
+    ///
+    /// impl Clock for Frozen {
+    ///     fn now(&self) -> SystemTime {
+    ///         UNIX_EPOCH
+    ///     }
+    ///
+    ///     fn instant(&self) -> Instant {
+    ///         self.instant
+    ///     }
+    /// }
+    ///
+    /// let config = Config::new().clock(Frozen { instant: Instant::now() });
+    ///
+    /// let mut context = Context::with_default_modules()?;
+    /// context.install(time::module_with_config(config)?)?;
+    /// let runtime = Arc::new(context.runtime()?);
+    ///
+    /// let mut sources = Sources::new();
+    /// sources.insert(Source::memory("pub fn main() { time::Instant::now().elapsed() }")?)?;
+    ///
+    /// let unit = rune::prepare(&mut sources).with_context(&context).build()?;
+    /// let mut vm = Vm::new(runtime, Arc::new(unit));
+    ///
+    /// let output: time::Duration = rune::from_value(vm.call(["main"], ())?)?;
+    /// assert_eq!(output.into_std(), Duration::ZERO);
+    /// # Ok::<_, rune::support::Error>(())
+    /// ```
+    #[inline]
+    fn instant(&self) -> std::time::Instant {
+        tokio::time::Instant::now().into_std()
+    }
+}
+
+impl<F> Clock for F
+where
+    F: 'static + Send + Sync + Fn() -> std::time::SystemTime,
+{
+    #[inline]
+    fn now(&self) -> std::time::SystemTime {
+        self()
+    }
+}
+
+/// The clock of the operating system.
+struct SystemClock;
+
+impl Clock for SystemClock {
+    #[inline]
+    fn now(&self) -> std::time::SystemTime {
+        std::time::SystemTime::now()
+    }
+}
+
+/// Configuration for the `time` module.
+pub struct Config {
+    clock: Arc<dyn Clock>,
+}
+
+impl Config {
+    /// Construct a new default configuration, which uses the clock of the
+    /// operating system.
+    pub fn new() -> Self {
+        Self {
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    /// Set the clock used to get the current wall-clock and monotonic time.
+    pub fn clock(mut self, clock: impl Clock) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+}
+
+impl Default for Config {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A measurement of the system clock.
+///
+/// Unlike [`Instant`], a `SystemTime` is not guaranteed to be monotonic, but it
+/// can be converted into a calendar date through [`DateTime`].
+///
+/// # Examples
+///
+/// ```rune
+/// use time::{Duration, SystemTime};
+///
+/// let now = SystemTime::now();
+/// assert!(now > SystemTime::UNIX_EPOCH);
+///
+/// let later = now + Duration::SECOND;
+/// assert_eq!(later.duration_since(now), Some(Duration::SECOND));
+/// ```
+#[derive(Debug, Clone, Copy, Any, ToConstValue)]
+#[rune(item = ::time)]
+pub struct SystemTime {
+    #[const_value(with = self::const_system_time)]
+    inner: std::time::SystemTime,
+}
+
+impl SystemTime {
+    /// Returns the amount of time elapsed from an earlier point in time.
+    ///
+    /// Returns `None` if `earlier` is later than this time.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::{Duration, SystemTime};
+    ///
+    /// let epoch = SystemTime::UNIX_EPOCH;
+    /// let later = epoch + Duration::from_secs(10);
+    ///
+    /// assert_eq!(later.duration_since(epoch), Some(Duration::from_secs(10)));
+    /// assert_eq!(epoch.duration_since(later), None);
+    /// ```
+    #[rune::function(keep, instance)]
+    fn duration_since(&self, earlier: &SystemTime) -> Option<Duration> {
+        let duration = self.inner.duration_since(earlier.inner).ok()?;
+        Some(Duration::from_std(duration))
+    }
+
+    /// Add a duration to this time and return a new time.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::{Duration, SystemTime};
+    ///
+    /// let first = SystemTime::UNIX_EPOCH;
+    /// let second = first + Duration::SECOND;
+    ///
+    /// assert!(first < second);
+    /// ```
+    #[rune::function(keep, instance, protocol = ADD)]
+    fn add(&self, rhs: &Duration) -> VmResult<Self> {
+        let Some(inner) = self.inner.checked_add(rhs.into_std()) else {
+            vm_panic!("overflow when adding duration to system time")
+        };
+
+        VmResult::Ok(Self { inner })
+    }
+
+    /// Add a duration to this time.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::{Duration, SystemTime};
+    ///
+    /// let time = SystemTime::UNIX_EPOCH;
+    /// time += Duration::SECOND;
+    ///
+    /// assert!(time > SystemTime::UNIX_EPOCH);
+    /// ```
+    #[rune::function(keep, instance, protocol = ADD_ASSIGN)]
+    fn add_assign(&mut self, rhs: &Duration) -> VmResult<()> {
+        let Some(inner) = self.inner.checked_add(rhs.into_std()) else {
+            vm_panic!("overflow when adding duration to system time")
+        };
+
+        self.inner = inner;
+        VmResult::Ok(())
+    }
+
+    /// Subtract a duration from this time and return a new time.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::{Duration, SystemTime};
+    ///
+    /// let now = SystemTime::now();
+    /// let earlier = now - Duration::SECOND;
+    ///
+    /// assert!(earlier < now);
+    /// ```
+    #[rune::function(keep, instance, protocol = SUB)]
+    fn sub(&self, rhs: &Duration) -> VmResult<Self> {
+        let Some(inner) = self.inner.checked_sub(rhs.into_std()) else {
+            vm_panic!("overflow when subtracting duration from system time")
+        };
+
+        VmResult::Ok(Self { inner })
+    }
+
+    /// Subtract a duration from this time.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::{Duration, SystemTime};
+    ///
+    /// let now = SystemTime::now();
+    /// let time = now.clone();
+    /// time -= Duration::SECOND;
+    ///
+    /// assert!(time < now);
+    /// ```
+    #[rune::function(keep, instance, protocol = SUB_ASSIGN)]
+    fn sub_assign(&mut self, rhs: &Duration) -> VmResult<()> {
+        let Some(inner) = self.inner.checked_sub(rhs.into_std()) else {
+            vm_panic!("overflow when subtracting duration from system time")
+        };
+
+        self.inner = inner;
+        VmResult::Ok(())
+    }
+
+    /// Test two times for partial equality.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::{Duration, SystemTime};
+    ///
+    /// let epoch = SystemTime::UNIX_EPOCH;
+    ///
+    /// assert_eq!(epoch, epoch);
+    /// assert_ne!(epoch, epoch + Duration::SECOND);
+    /// ```
+    #[rune::function(keep, instance, protocol = PARTIAL_EQ)]
+    #[inline]
+    fn partial_eq(&self, rhs: &Self) -> bool {
+        PartialEq::eq(&self.inner, &rhs.inner)
+    }
+
+    /// Test two times for total equality.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::ops::eq;
+    /// use time::{Duration, SystemTime};
+    ///
+    /// let epoch = SystemTime::UNIX_EPOCH;
+    ///
+    /// assert!(eq(epoch, epoch));
+    /// assert!(!eq(epoch, epoch + Duration::SECOND));
+    /// ```
+    #[rune::function(keep, instance, protocol = EQ)]
+    #[inline]
+    fn eq(&self, rhs: &Self) -> bool {
+        PartialEq::eq(&self.inner, &rhs.inner)
+    }
+
+    /// Perform a partial ordered comparison between two times.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::{Duration, SystemTime};
+    ///
+    /// let first = SystemTime::UNIX_EPOCH;
+    /// let second = first + Duration::SECOND;
+    ///
+    /// assert!(first < second);
+    /// ```
+    #[rune::function(keep, instance, protocol = PARTIAL_CMP)]
+    #[inline]
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        PartialOrd::partial_cmp(&self.inner, &rhs.inner)
+    }
+
+    /// Perform a totally ordered comparison between two times.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::cmp::Ordering;
+    /// use std::ops::cmp;
+    /// use time::{Duration, SystemTime};
+    ///
+    /// let first = SystemTime::UNIX_EPOCH;
+    /// let second = first + Duration::SECOND;
+    ///
+    /// assert_eq!(cmp(first, second), Ordering::Less);
+    /// ```
+    #[rune::function(keep, instance, protocol = CMP)]
+    #[inline]
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        Ord::cmp(&self.inner, &rhs.inner)
+    }
+
+    /// Hash the time.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::ops::hash;
+    /// use time::SystemTime;
+    ///
+    /// let epoch = SystemTime::UNIX_EPOCH;
+    ///
+    /// assert_eq!(hash(epoch), hash(epoch));
+    /// ```
+    #[rune::function(keep, instance, protocol = HASH)]
+    fn hash(&self, hasher: &mut Hasher) {
+        self.inner.hash(hasher);
+    }
+
+    /// Write a debug representation of the time.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::SystemTime;
+    ///
+    /// println!("{:?}", SystemTime::UNIX_EPOCH);
+    /// ```
+    #[rune::function(keep, instance, protocol = DEBUG_FMT)]
+    fn debug_fmt(&self, f: &mut Formatter) -> VmResult<()> {
+        rune::vm_write!(f, "{:?}", self.inner)
+    }
+
+    /// Clone the time.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::SystemTime;
+    ///
+    /// let epoch = SystemTime::UNIX_EPOCH;
+    /// assert_eq!(epoch.clone(), epoch);
+    /// ```
+    #[rune::function(keep, instance, protocol = CLONE)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+/// A date and time with a fixed offset from UTC.
+///
+/// Dates can be parsed from and formatted as [RFC 3339] and ISO 8601 strings,
+/// or formatted using `strftime`-style format strings.
+///
+/// Two dates are equal if they refer to the same instant, regardless of their
+/// offsets.
+///
+/// [RFC 3339]: https://www.rfc-editor.org/rfc/rfc3339
+///
+/// # Examples
+///
+/// ```rune
+/// use time::{DateTime, Duration};
+///
+/// let date = DateTime::parse_rfc3339("2024-02-28T12:30:00Z")?;
+/// let date = date + Duration::from_secs(24 * 60 * 60);
+///
+/// assert_eq!(date.month(), 2);
+/// assert_eq!(date.day(), 29);
+/// assert_eq!(date.format("%Y-%m-%d %H:%M")?, "2024-02-29 12:30");
+/// ```
+#[derive(Debug, Clone, Copy, Any)]
+#[rune(item = ::time)]
+pub struct DateTime {
+    inner: chrono::DateTime<chrono::FixedOffset>,
+}
+
+impl DateTime {
+    /// Construct a UTC date from the given number of seconds and nanoseconds
+    /// since the UNIX epoch.
+    ///
+    /// Returns `None` if the timestamp is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::DateTime;
+    ///
+    /// let date = DateTime::from_timestamp(1_700_000_000, 0)?;
+    /// assert_eq!(date.to_rfc3339(), "2023-11-14T22:13:20+00:00");
+    /// ```
+    #[rune::function(keep, path = Self::from_timestamp)]
+    fn from_timestamp(secs: i64, nanos: u32) -> Option<Self> {
+        let inner = chrono::DateTime::from_timestamp(secs, nanos)?;
+        Some(Self::from_utc(inner))
+    }
+
+    /// Construct a UTC date from the given number of milliseconds since the
+    /// UNIX epoch.
+    ///
+    /// Returns `None` if the timestamp is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::DateTime;
+    ///
+    /// let date = DateTime::from_timestamp_millis(1_700_000_000_123)?;
+    /// assert_eq!(date.nanosecond(), 123_000_000);
+    /// ```
+    #[rune::function(keep, path = Self::from_timestamp_millis)]
+    fn from_timestamp_millis(millis: i64) -> Option<Self> {
+        let inner = chrono::DateTime::from_timestamp_millis(millis)?;
+        Some(Self::from_utc(inner))
+    }
+
+    /// Construct a UTC date at midnight from its calendar components.
+    ///
+    /// Returns `None` if the components don't describe a valid date.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::DateTime;
+    ///
+    /// let date = DateTime::from_ymd(2024, 2, 29)?;
+    /// assert_eq!(date.to_rfc3339(), "2024-02-29T00:00:00+00:00");
+    ///
+    /// assert!(DateTime::from_ymd(2023, 2, 29).is_none());
+    /// ```
+    #[rune::function(keep, path = Self::from_ymd)]
+    fn from_ymd(year: i32, month: u32, day: u32) -> Option<Self> {
+        let date = chrono::NaiveDate::from_ymd_opt(year, month, day)?;
+        Some(Self::from_utc(
+            date.and_time(chrono::NaiveTime::MIN).and_utc(),
+        ))
+    }
+
+    /// Construct a date from a [`SystemTime`], in UTC.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::{DateTime, SystemTime};
+    ///
+    /// let date = DateTime::from_system_time(SystemTime::UNIX_EPOCH);
+    /// assert_eq!(date.timestamp(), 0);
+    /// ```
+    #[rune::function(keep, path = Self::from_system_time)]
+    fn from_system_time(time: &SystemTime) -> Self {
+        Self::from_utc(chrono::DateTime::<chrono::Utc>::from(time.inner))
+    }
+
+    /// Parse a date from an [RFC 3339] string, such as
+    /// `2024-05-01T12:00:00+02:00`.
+    ///
+    /// [RFC 3339]: https://www.rfc-editor.org/rfc/rfc3339
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::DateTime;
+    ///
+    /// let date = DateTime::parse_rfc3339("2024-05-01T12:00:00+02:00")?;
+    /// assert_eq!(date.hour(), 12);
+    /// assert_eq!(date.offset(), 2 * 60 * 60);
+    ///
+    /// assert!(DateTime::parse_rfc3339("2024-05-01").is_err());
+    /// ```
+    #[rune::function(keep, path = Self::parse_rfc3339)]
+    fn parse_rfc3339(s: &str) -> Result<Self, Error> {
+        let inner = chrono::DateTime::parse_from_rfc3339(s)?;
+        Ok(Self { inner })
+    }
+
+    /// Parse a date from an ISO 8601 string.
+    ///
+    /// This is more lenient than [`DateTime::parse_rfc3339`], and for example
+    /// accepts a space instead of the `T` separating the date and time.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::DateTime;
+    ///
+    /// let a = DateTime::parse("2024-05-01 12:00:00Z")?;
+    /// let b = DateTime::parse("2024-05-01T14:00:00+02:00")?;
+    ///
+    /// assert_eq!(a, b);
+    /// ```
+    #[rune::function(keep, path = Self::parse)]
+    fn parse(s: &str) -> Result<Self, Error> {
+        let inner = s.parse::<chrono::DateTime<chrono::FixedOffset>>()?;
+        Ok(Self { inner })
+    }
+
+    /// Parse a date using a `strftime`-style format string.
+    ///
+    /// The input must contain an offset, for example through the `%z`
+    /// specifier.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::DateTime;
+    ///
+    /// let date = DateTime::parse_from_str("01/05/2024 08:30 +0000", "%d/%m/%Y %H:%M %z")?;
+    /// assert_eq!(date.to_rfc3339(), "2024-05-01T08:30:00+00:00");
+    /// ```
+    #[rune::function(keep, path = Self::parse_from_str)]
+    fn parse_from_str(s: &str, format: &str) -> Result<Self, Error> {
+        let inner = chrono::DateTime::parse_from_str(s, format)?;
+        Ok(Self { inner })
+    }
+
+    fn from_utc(inner: chrono::DateTime<chrono::Utc>) -> Self {
+        Self {
+            inner: inner.fixed_offset(),
+        }
+    }
+
+    /// Format the date as an [RFC 3339] string.
+    ///
+    /// [RFC 3339]: https://www.rfc-editor.org/rfc/rfc3339
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::DateTime;
+    ///
+    /// let date = DateTime::from_ymd(2024, 5, 1)?.with_hms(12, 0, 0)?;
+    /// assert_eq!(date.to_rfc3339(), "2024-05-01T12:00:00+00:00");
+    /// ```
+    #[rune::function(keep, instance, path = Self::to_rfc3339)]
+    fn rune_to_rfc3339(&self) -> VmResult<String> {
+        let mut string = String::new();
+        vm_try!(write!(string, "{}", self.to_rfc3339()));
+        VmResult::Ok(string)
+    }
+
+    /// Format the date using a `strftime`-style format string.
+    ///
+    /// See the [chrono documentation] for the supported specifiers.
+    ///
+    /// [chrono documentation]: https://docs.rs/chrono/latest/chrono/format/strftime/index.html
+    ///
+    /// # Errors
+    ///
+    /// Errors if the format string is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::DateTime;
+    ///
+    /// let date = DateTime::from_ymd(2024, 5, 1)?.with_hms(8, 5, 0)?;
+    ///
+    /// assert_eq!(date.format("%A, %B %-d %Y at %H:%M")?, "Wednesday, May 1 2024 at 08:05");
+    /// assert!(date.format("%Q").is_err());
+    /// ```
+    #[rune::function(keep, instance)]
+    fn format(&self, format: &str) -> VmResult<Result<String, Error>> {
+        let items = chrono::format::StrftimeItems::new(format);
+
+        if items
+            .clone()
+            .any(|item| matches!(item, chrono::format::Item::Error))
+        {
+            return VmResult::Ok(Err(Error::invalid_format(format)));
+        }
+
+        let mut string = String::new();
+        vm_try!(write!(string, "{}", self.inner.format_with_items(items)));
+        VmResult::Ok(Ok(string))
+    }
+
+    /// Convert the date into a date with the given offset from UTC, in
+    /// seconds.
+    ///
+    /// The returned date refers to the same instant. Returns `None` if the
+    /// offset is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::DateTime;
+    ///
+    /// let utc = DateTime::from_ymd(2024, 5, 1)?.with_hms(12, 0, 0)?;
+    /// let local = utc.with_offset(-5 * 60 * 60)?;
+    ///
+    /// assert_eq!(local.hour(), 7);
+    /// assert_eq!(local.to_rfc3339(), "2024-05-01T07:00:00-05:00");
+    /// assert_eq!(local, utc);
+    /// ```
+    #[rune::function(keep, instance)]
+    fn with_offset(&self, offset: i32) -> Option<Self> {
+        let offset = chrono::FixedOffset::east_opt(offset)?;
+
+        Some(Self {
+            inner: self.inner.with_timezone(&offset),
+        })
+    }
+
+    /// Replace the time of day of this date, keeping its offset.
+    ///
+    /// Returns `None` if the components don't describe a valid time.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::DateTime;
+    ///
+    /// let date = DateTime::from_ymd(2024, 5, 1)?.with_hms(13, 14, 15)?;
+    /// assert_eq!(date.to_rfc3339(), "2024-05-01T13:14:15+00:00");
+    ///
+    /// assert!(date.with_hms(24, 0, 0).is_none());
+    /// ```
+    #[rune::function(keep, instance)]
+    fn with_hms(&self, hour: u32, min: u32, sec: u32) -> Option<Self> {
+        let time = chrono::NaiveTime::from_hms_opt(hour, min, sec)?;
+        let local = self.inner.date_naive().and_time(time);
+        let inner = local.and_local_timezone(*self.inner.offset()).single()?;
+        Some(Self { inner })
+    }
+
+    /// Convert the date into UTC.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::DateTime;
+    ///
+    /// let date = DateTime::parse_rfc3339("2024-05-01T12:00:00+02:00")?;
+    /// assert_eq!(date.to_utc().to_rfc3339(), "2024-05-01T10:00:00+00:00");
+    /// ```
+    #[rune::function(keep, instance, path = Self::to_utc)]
+    fn rune_to_utc(&self) -> Self {
+        self.to_utc()
+    }
+
+    /// Convert the date into a [`SystemTime`].
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::{DateTime, SystemTime};
+    ///
+    /// let date = DateTime::from_timestamp(0, 0)?;
+    /// assert_eq!(date.to_system_time(), SystemTime::UNIX_EPOCH);
+    /// ```
+    #[rune::function(keep, instance, path = Self::to_system_time)]
+    fn rune_to_system_time(&self) -> SystemTime {
+        self.to_system_time()
+    }
+
+    /// Format the date as an [RFC 3339] string.
+    ///
+    /// Unlike the script functions, which borrow the date so that it isn't
+    /// moved out of the calling script, these conversions take it by value.
+    ///
+    /// [RFC 3339]: https://www.rfc-editor.org/rfc/rfc3339
+    pub fn to_rfc3339(self) -> std::string::String {
+        self.inner.to_rfc3339()
+    }
+
+    /// Convert the date into UTC.
+    pub fn to_utc(self) -> Self {
+        Self::from_utc(self.inner.to_utc())
+    }
+
+    /// Convert the date into a [`SystemTime`].
+    pub fn to_system_time(self) -> SystemTime {
+        SystemTime {
+            inner: self.inner.into(),
+        }
+    }
+
+    /// The offset from UTC of this date, in seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::DateTime;
+    ///
+    /// let date = DateTime::parse_rfc3339("2024-05-01T12:00:00-01:30")?;
+    /// assert_eq!(date.offset(), -90 * 60);
+    /// ```
+    #[rune::function(keep, instance)]
+    fn offset(&self) -> i32 {
+        chrono::Offset::fix(self.inner.offset()).local_minus_utc()
+    }
+
+    /// The number of non-leap seconds since the UNIX epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::DateTime;
+    ///
+    /// let date = DateTime::parse_rfc3339("1970-01-01T00:01:00Z")?;
+    /// assert_eq!(date.timestamp(), 60);
+    /// ```
+    #[rune::function(keep, instance)]
+    fn timestamp(&self) -> i64 {
+        self.inner.timestamp()
+    }
+
+    /// The number of non-leap milliseconds since the UNIX epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::DateTime;
+    ///
+    /// let date = DateTime::parse_rfc3339("1970-01-01T00:00:01.5Z")?;
+    /// assert_eq!(date.timestamp_millis(), 1500);
+    /// ```
+    #[rune::function(keep, instance)]
+    fn timestamp_millis(&self) -> i64 {
+        self.inner.timestamp_millis()
+    }
+
+    /// The year of the date.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::DateTime;
+    ///
+    /// let date = DateTime::from_ymd(2024, 5, 1)?;
+    /// assert_eq!(date.year(), 2024);
+    /// ```
+    #[rune::function(keep, instance)]
+    fn year(&self) -> i32 {
+        chrono::Datelike::year(&self.inner)
+    }
+
+    /// The month of the date, starting from 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::DateTime;
+    ///
+    /// let date = DateTime::from_ymd(2024, 5, 1)?;
+    /// assert_eq!(date.month(), 5);
+    /// ```
+    #[rune::function(keep, instance)]
+    fn month(&self) -> u32 {
+        chrono::Datelike::month(&self.inner)
+    }
+
+    /// The day of the month, starting from 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::DateTime;
+    ///
+    /// let date = DateTime::from_ymd(2024, 5, 1)?;
+    /// assert_eq!(date.day(), 1);
+    /// ```
+    #[rune::function(keep, instance)]
+    fn day(&self) -> u32 {
+        chrono::Datelike::day(&self.inner)
+    }
+
+    /// The day of the year, starting from 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::DateTime;
+    ///
+    /// let date = DateTime::from_ymd(2024, 2, 1)?;
+    /// assert_eq!(date.ordinal(), 32);
+    /// ```
+    #[rune::function(keep, instance)]
+    fn ordinal(&self) -> u32 {
+        chrono::Datelike::ordinal(&self.inner)
+    }
+
+    /// The day of the week, where Monday is 1 and Sunday is 7.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::DateTime;
+    ///
+    /// let date = DateTime::from_ymd(2024, 5, 1)?;
+    /// assert_eq!(date.weekday(), 3);
+    /// ```
+    #[rune::function(keep, instance)]
+    fn weekday(&self) -> u32 {
+        chrono::Datelike::weekday(&self.inner).number_from_monday()
+    }
+
+    /// The hour of the day, from 0 to 23.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::DateTime;
+    ///
+    /// let date = DateTime::from_ymd(2024, 5, 1)?.with_hms(13, 14, 15)?;
+    /// assert_eq!(date.hour(), 13);
+    /// ```
+    #[rune::function(keep, instance)]
+    fn hour(&self) -> u32 {
+        chrono::Timelike::hour(&self.inner)
+    }
+
+    /// The minute of the hour, from 0 to 59.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::DateTime;
+    ///
+    /// let date = DateTime::from_ymd(2024, 5, 1)?.with_hms(13, 14, 15)?;
+    /// assert_eq!(date.minute(), 14);
+    /// ```
+    #[rune::function(keep, instance)]
+    fn minute(&self) -> u32 {
+        chrono::Timelike::minute(&self.inner)
+    }
+
+    /// The second of the minute, from 0 to 59.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::DateTime;
+    ///
+    /// let date = DateTime::from_ymd(2024, 5, 1)?.with_hms(13, 14, 15)?;
+    /// assert_eq!(date.second(), 15);
+    /// ```
+    #[rune::function(keep, instance)]
+    fn second(&self) -> u32 {
+        chrono::Timelike::second(&self.inner)
+    }
+
+    /// The number of nanoseconds since the last whole second.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::DateTime;
+    ///
+    /// let date = DateTime::parse_rfc3339("2024-05-01T12:00:00.25Z")?;
+    /// assert_eq!(date.nanosecond(), 250_000_000);
+    /// ```
+    #[rune::function(keep, instance)]
+    fn nanosecond(&self) -> u32 {
+        chrono::Timelike::nanosecond(&self.inner)
+    }
+
+    /// Returns the amount of time elapsed from an earlier date.
+    ///
+    /// Returns `None` if `earlier` is later than this date.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::{DateTime, Duration};
+    ///
+    /// let a = DateTime::parse_rfc3339("2024-05-01T12:00:00Z")?;
+    /// let b = DateTime::parse_rfc3339("2024-05-01T14:00:30+02:00")?;
+    ///
+    /// assert_eq!(b.duration_since(a), Some(Duration::from_secs(30)));
+    /// assert_eq!(a.duration_since(b), None);
+    /// ```
+    #[rune::function(keep, instance)]
+    fn duration_since(&self, earlier: &DateTime) -> Option<Duration> {
+        let delta = self.inner.signed_duration_since(earlier.inner);
+        Some(Duration::from_std(delta.to_std().ok()?))
+    }
+
+    fn checked_add(&self, rhs: &Duration) -> Option<Self> {
+        let delta = chrono::TimeDelta::from_std(rhs.into_std()).ok()?;
+        let inner = self.inner.checked_add_signed(delta)?;
+        Some(Self { inner })
+    }
+
+    fn checked_sub(&self, rhs: &Duration) -> Option<Self> {
+        let delta = chrono::TimeDelta::from_std(rhs.into_std()).ok()?;
+        let inner = self.inner.checked_sub_signed(delta)?;
+        Some(Self { inner })
+    }
+
+    /// Add a duration to this date and return a new date.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::{DateTime, Duration};
+    ///
+    /// let date = DateTime::parse_rfc3339("2024-12-31T23:59:59Z")?;
+    /// let date = date + Duration::SECOND;
+    ///
+    /// assert_eq!(date.to_rfc3339(), "2025-01-01T00:00:00+00:00");
+    /// ```
+    #[rune::function(keep, instance, protocol = ADD)]
+    fn add(&self, rhs: &Duration) -> VmResult<Self> {
+        let Some(date) = self.checked_add(rhs) else {
+            vm_panic!("overflow when adding duration to date")
+        };
+
+        VmResult::Ok(date)
+    }
+
+    /// Add a duration to this date.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::{DateTime, Duration};
+    ///
+    /// let date = DateTime::parse_rfc3339("2024-05-01T12:00:00Z")?;
+    /// date += Duration::from_secs(60);
+    ///
+    /// assert_eq!(date.minute(), 1);
+    /// ```
+    #[rune::function(keep, instance, protocol = ADD_ASSIGN)]
+    fn add_assign(&mut self, rhs: &Duration) -> VmResult<()> {
+        let Some(date) = self.checked_add(rhs) else {
+            vm_panic!("overflow when adding duration to date")
+        };
+
+        *self = date;
+        VmResult::Ok(())
+    }
+
+    /// Subtract a duration from this date and return a new date.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::{DateTime, Duration};
+    ///
+    /// let date = DateTime::parse_rfc3339("2024-03-01T00:00:00Z")?;
+    /// let date = date - Duration::SECOND;
+    ///
+    /// assert_eq!(date.to_rfc3339(), "2024-02-29T23:59:59+00:00");
+    /// ```
+    #[rune::function(keep, instance, protocol = SUB)]
+    fn sub(&self, rhs: &Duration) -> VmResult<Self> {
+        let Some(date) = self.checked_sub(rhs) else {
+            vm_panic!("overflow when subtracting duration from date")
+        };
+
+        VmResult::Ok(date)
+    }
+
+    /// Subtract a duration from this date.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::{DateTime, Duration};
+    ///
+    /// let date = DateTime::parse_rfc3339("2024-05-01T12:00:00Z")?;
+    /// date -= Duration::from_secs(60 * 60);
+    ///
+    /// assert_eq!(date.hour(), 11);
+    /// ```
+    #[rune::function(keep, instance, protocol = SUB_ASSIGN)]
+    fn sub_assign(&mut self, rhs: &Duration) -> VmResult<()> {
+        let Some(date) = self.checked_sub(rhs) else {
+            vm_panic!("overflow when subtracting duration from date")
+        };
+
+        *self = date;
+        VmResult::Ok(())
+    }
+
+    /// Test two dates for partial equality.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::DateTime;
+    ///
+    /// let a = DateTime::parse_rfc3339("2024-05-01T12:00:00Z")?;
+    /// let b = DateTime::parse_rfc3339("2024-05-01T13:00:00+01:00")?;
+    ///
+    /// assert_eq!(a, b);
+    /// ```
+    #[rune::function(keep, instance, protocol = PARTIAL_EQ)]
+    #[inline]
+    fn partial_eq(&self, rhs: &Self) -> bool {
+        PartialEq::eq(&self.inner, &rhs.inner)
+    }
+
+    /// Test two dates for total equality.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::ops::eq;
+    /// use time::DateTime;
+    ///
+    /// let a = DateTime::parse_rfc3339("2024-05-01T12:00:00Z")?;
+    /// let b = DateTime::parse_rfc3339("2024-05-01T12:00:01Z")?;
+    ///
+    /// assert!(eq(a, a));
+    /// assert!(!eq(a, b));
+    /// ```
+    #[rune::function(keep, instance, protocol = EQ)]
+    #[inline]
+    fn eq(&self, rhs: &Self) -> bool {
+        PartialEq::eq(&self.inner, &rhs.inner)
+    }
+
+    /// Perform a partial ordered comparison between two dates.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::DateTime;
+    ///
+    /// let a = DateTime::parse_rfc3339("2024-05-01T12:00:00Z")?;
+    /// let b = DateTime::parse_rfc3339("2024-05-01T12:30:00+01:00")?;
+    ///
+    /// assert!(b < a);
+    /// ```
+    #[rune::function(keep, instance, protocol = PARTIAL_CMP)]
+    #[inline]
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        PartialOrd::partial_cmp(&self.inner, &rhs.inner)
+    }
+
+    /// Perform a totally ordered comparison between two dates.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::cmp::Ordering;
+    /// use std::ops::cmp;
+    /// use time::DateTime;
+    ///
+    /// let a = DateTime::parse_rfc3339("2024-05-01T12:00:00Z")?;
+    /// let b = DateTime::parse_rfc3339("2024-05-02T12:00:00Z")?;
+    ///
+    /// assert_eq!(cmp(a, b), Ordering::Less);
+    /// ```
+    #[rune::function(keep, instance, protocol = CMP)]
+    #[inline]
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        Ord::cmp(&self.inner, &rhs.inner)
+    }
+
+    /// Hash the date.
+    ///
+    /// Dates which refer to the same instant have the same hash, regardless
+    /// of their offsets.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::ops::hash;
+    /// use time::DateTime;
+    ///
+    /// let a = DateTime::parse_rfc3339("2024-05-01T12:00:00Z")?;
+    /// let b = DateTime::parse_rfc3339("2024-05-01T13:00:00+01:00")?;
+    ///
+    /// assert_eq!(hash(a), hash(b));
+    /// ```
+    #[rune::function(keep, instance, protocol = HASH)]
+    fn hash(&self, hasher: &mut Hasher) {
+        self.inner.hash(hasher);
+    }
+
+    /// Write the date as an [RFC 3339] string.
+    ///
+    /// [RFC 3339]: https://www.rfc-editor.org/rfc/rfc3339
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::DateTime;
+    ///
+    /// let date = DateTime::from_ymd(2024, 5, 1)?.with_hms(12, 0, 0)?;
+    /// assert_eq!(format!("{date}"), "2024-05-01T12:00:00+00:00");
+    /// ```
+    #[rune::function(keep, instance, protocol = DISPLAY_FMT)]
+    fn display_fmt(&self, f: &mut Formatter) -> VmResult<()> {
+        rune::vm_write!(f, "{}", self.inner.to_rfc3339())
+    }
+
+    /// Write a debug representation of the date.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::DateTime;
+    ///
+    /// let date = DateTime::from_ymd(2024, 5, 1)?.with_hms(12, 0, 0)?;
+    /// assert_eq!(format!("{date:?}"), "2024-05-01T12:00:00+00:00");
+    /// ```
+    #[rune::function(keep, instance, protocol = DEBUG_FMT)]
+    fn debug_fmt(&self, f: &mut Formatter) -> VmResult<()> {
+        rune::vm_write!(f, "{:?}", self.inner)
+    }
+
+    /// Clone the date.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use time::{DateTime, Duration};
+    ///
+    /// let a = DateTime::from_ymd(2024, 5, 1)?.with_hms(12, 0, 0)?;
+    /// let b = a.clone();
+    /// b += Duration::SECOND;
+    ///
+    /// assert!(a < b);
+    /// ```
+    #[rune::function(keep, instance, protocol = CLONE)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+/// An error raised when parsing or formatting a [`DateTime`].
+#[derive(Debug, Any)]
+#[rune(item = ::time)]
+pub struct Error {
+    kind: ErrorKind,
+}
+
+#[derive(Debug)]
+enum ErrorKind {
+    Parse(chrono::ParseError),
+    InvalidFormat(std::string::String),
+}
+
+impl Error {
+    fn invalid_format(format: &str) -> Self {
+        Self {
+            kind: ErrorKind::InvalidFormat(format.to_owned()),
+        }
+    }
+
+    #[rune::function(keep, instance, protocol = DISPLAY_FMT)]
+    fn display_fmt(&self, f: &mut Formatter) -> VmResult<()> {
+        rune::vm_write!(f, "{self}")
+    }
+
+    #[rune::function(keep, instance, protocol = DEBUG_FMT)]
+    fn debug_fmt(&self, f: &mut Formatter) -> VmResult<()> {
+        rune::vm_write!(f, "{:?}", self.kind)
+    }
+}
+
+impl From<chrono::ParseError> for Error {
+    #[inline]
+    fn from(error: chrono::ParseError) -> Self {
+        Self {
+            kind: ErrorKind::Parse(error),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ErrorKind::Parse(error) => error.fmt(f),
+            ErrorKind::InvalidFormat(format) => write!(f, "Invalid format string `{format}`"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
@@ -0,0 +1,181 @@
+//! The native `yaml` module for the [Rune Language].
+//!
+//! [Rune Language]: https://rune-rs.github.io
+//!
+//! ## Usage
+//!
+//! Add the following to your `Cargo.toml`:
+//!
+//! ```toml
+//! rune-modules = { version = "0.14.0", features = ["yaml"] }
+//! ```
+//!
+//! Install it into your context:
+//!
+//! ```rust
+//! let mut context = rune::Context::with_default_modules()?;
+//! context.install(rune_modules::yaml::module(true)?)?;
+//! # Ok::<_, rune::support::Error>(())
+//! ```
+//!
+//! Use it in Rune:
+//!
+//! ```rust,ignore
+//! use yaml;
+//!
+//! fn main() {
+//!     let data = yaml::from_string("key: 42");
+//!     dbg(data);
+//! }
+//! ```
+
+use rune::alloc::fmt::TryWrite;
+use rune::alloc::{String, Vec};
+use rune::runtime::{Bytes, Formatter, Value, VmResult};
+use rune::{vm_write, Any, ContextError, Module};
+use serde::Deserialize;
+
+#[rune::module(::yaml)]
+/// Module for processing YAML.
+///
+/// # Examples
+///
+/// ```rune
+/// let object = #{"number": 42, "string": "Hello World"};
+/// let object = yaml::from_string(yaml::to_string(object)?)?;
+/// assert_eq!(object, #{"number": 42, "string": "Hello World"});
+/// ```
+pub fn module(_stdio: bool) -> Result<Module, ContextError> {
+    let mut module = Module::from_meta(self::module_meta)?;
+    module.ty::<Error>()?;
+    module.function_meta(Error::display__meta)?;
+    module.function_meta(Error::debug__meta)?;
+    module.function_meta(from_bytes)?;
+    module.function_meta(from_string)?;
+    module.function_meta(from_bytes_all)?;
+    module.function_meta(from_string_all)?;
+    module.function_meta(to_string)?;
+    module.function_meta(to_bytes)?;
+    Ok(module)
+}
+
+#[derive(Any)]
+#[rune(item = ::yaml)]
+/// Error type raised during YAML serialization.
+struct Error {
+    error: serde_norway::Error,
+}
+
+impl Error {
+    #[rune::function(keep, protocol = DISPLAY_FMT)]
+    pub(crate) fn display(&self, f: &mut Formatter) -> VmResult<()> {
+        vm_write!(f, "{}", self.error)
+    }
+
+    #[rune::function(keep, protocol = DEBUG_FMT)]
+    pub(crate) fn debug(&self, f: &mut Formatter) -> VmResult<()> {
+        vm_write!(f, "{:?}", self.error)
+    }
+}
+
+impl From<serde_norway::Error> for Error {
+    fn from(error: serde_norway::Error) -> Self {
+        Self { error }
+    }
+}
+
+/// Convert YAML bytes holding a single document into a rune value.
+///
+/// # Examples
+///
+/// ```rune
+/// let object = yaml::from_bytes(b"number: 42\nstring: Hello World\n")?;
+/// assert_eq!(object, #{"number": 42, "string": "Hello World"});
+/// ```
+#[rune::function]
+fn from_bytes(bytes: &[u8]) -> Result<Value, Error> {
+    Ok(serde_norway::from_slice(bytes)?)
+}
+
+/// Convert a YAML string holding a single document into a rune value.
+///
+/// # Examples
+///
+/// ```rune
+/// let object = yaml::from_string("items:\n  - 1\n  - two\nenabled: true\n")?;
+/// assert_eq!(object, #{"items": [1, "two"], "enabled": true});
+///
+/// assert!(yaml::from_string("a: 1\n---\nb: 2\n").is_err());
+/// ```
+#[rune::function]
+fn from_string(string: &str) -> Result<Value, Error> {
+    Ok(serde_norway::from_str(string)?)
+}
+
+/// Convert YAML bytes holding a stream of documents separated by `---` into a
+/// vector of rune values.
+///
+/// # Examples
+///
+/// ```rune
+/// let documents = yaml::from_bytes_all(b"a: 1\n---\nb: 2\n")?;
+/// assert_eq!(documents, [#{"a": 1}, #{"b": 2}]);
+/// ```
+#[rune::function(vm_result)]
+fn from_bytes_all(bytes: &[u8]) -> Result<Vec<Value>, Error> {
+    let mut documents = Vec::new();
+
+    for document in serde_norway::Deserializer::from_slice(bytes) {
+        documents.try_push(Value::deserialize(document)?).vm?;
+    }
+
+    Ok(documents)
+}
+
+/// Convert a YAML string holding a stream of documents separated by `---` into
+/// a vector of rune values.
+///
+/// # Examples
+///
+/// ```rune
+/// let documents = yaml::from_string_all("---\nname: first\n---\nname: second\n")?;
+/// assert_eq!(documents, [#{"name": "first"}, #{"name": "second"}]);
+/// ```
+#[rune::function(vm_result)]
+fn from_string_all(string: &str) -> Result<Vec<Value>, Error> {
+    let mut documents = Vec::new();
+
+    for document in serde_norway::Deserializer::from_str(string) {
+        documents.try_push(Value::deserialize(document)?).vm?;
+    }
+
+    Ok(documents)
+}
+
+/// Convert any value to a YAML string.
+///
+/// # Examples
+///
+/// ```rune
+/// let yaml = yaml::to_string(#{"items": [1, 2]})?;
+/// assert_eq!(yaml, "items:\n- 1\n- 2\n");
+/// ```
+#[rune::function(vm_result)]
+fn to_string(value: Value) -> Result<String, Error> {
+    Ok(String::try_from(serde_norway::to_string(&value)?).vm?)
+}
+
+/// Convert any value to YAML bytes.
+///
+/// # Examples
+///
+/// ```rune
+/// let object = #{"number": 42, "string": "Hello World"};
+/// let object = yaml::from_bytes(yaml::to_bytes(object)?)?;
+/// assert_eq!(object, #{"number": 42, "string": "Hello World"});
+/// ```
+#[rune::function(vm_result)]
+fn to_bytes(value: Value) -> Result<Bytes, Error> {
+    let string = serde_norway::to_string(&value)?;
+    Ok(Bytes::from_vec(Vec::try_from(string.into_bytes()).vm?))
+}
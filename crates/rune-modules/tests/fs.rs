@@ -0,0 +1,235 @@
+//! Tests for the capabilities of the `fs` module, which run scripts against a
+//! temporary directory layout with one allowed root and one directory outside
+//! of it.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use rune::runtime::Value;
+use rune::termcolor::{ColorChoice, StandardStream};
+use rune::{Diagnostics, Source, Sources, Vm};
+use rune_modules::fs::Config;
+
+/// Helpers prepended to every script.
+const PRELUDE: &str = r#"
+fn denied(result) {
+    match result {
+        Ok(..) => false,
+        Err(error) => `${error}`.contains("denied"),
+    }
+}
+"#;
+
+/// A temporary directory containing an `allowed` root and an `outside`
+/// directory, which is removed when dropped.
+struct Layout {
+    base: PathBuf,
+}
+
+impl Layout {
+    fn new(name: &str) -> Self {
+        let base =
+            std::env::temp_dir().join(format!("rune-modules-fs-{}-{name}", std::process::id()));
+
+        let _ = fs::remove_dir_all(&base);
+        fs::create_dir_all(base.join("allowed")).unwrap();
+        fs::create_dir_all(base.join("outside")).unwrap();
+        fs::write(base.join("allowed").join("inside.txt"), "inside").unwrap();
+        fs::write(base.join("outside").join("secret.txt"), "secret").unwrap();
+        Self { base }
+    }
+
+    fn allowed(&self) -> PathBuf {
+        self.base.join("allowed")
+    }
+
+    fn outside(&self) -> PathBuf {
+        self.base.join("outside")
+    }
+
+    fn config(&self) -> Config {
+        Config::new().allow(self.allowed())
+    }
+}
+
+impl Drop for Layout {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.base);
+    }
+}
+
+/// Run `main(allowed, outside)` in the given script, failing if the script
+/// panics or returns an error.
+async fn run(layout: &Layout, config: Config, source: &str) -> rune::support::Result<()> {
+    let mut context = rune::Context::with_default_modules()?;
+    context.install(rune_modules::fs::module_with_config(config)?)?;
+    let runtime = Arc::new(context.runtime()?);
+
+    let mut sources = Sources::new();
+    sources.insert(Source::memory(format!("{PRELUDE}{source}"))?)?;
+
+    let mut diagnostics = Diagnostics::new();
+
+    let result = rune::prepare(&mut sources)
+        .with_context(&context)
+        .with_diagnostics(&mut diagnostics)
+        .build();
+
+    if !diagnostics.is_empty() {
+        let mut writer = StandardStream::stderr(ColorChoice::Always);
+        diagnostics.emit(&mut writer, &sources)?;
+    }
+
+    let allowed = path_string(&layout.allowed());
+    let outside = path_string(&layout.outside());
+
+    let mut vm = Vm::new(runtime, Arc::new(result?));
+    let output = vm.async_call(["main"], (allowed, outside)).await?;
+
+    if let Err(error) = rune::from_value::<Result<(), Value>>(output)? {
+        panic!("script errored: {error:?}");
+    }
+
+    Ok(())
+}
+
+fn path_string(path: &Path) -> String {
+    path.to_str().expect("temporary paths are UTF-8").to_owned()
+}
+
+#[tokio::test]
+async fn outside_roots_denied() -> rune::support::Result<()> {
+    let layout = Layout::new("outside-roots");
+
+    run(
+        &layout,
+        layout.config(),
+        r#"
+        pub async fn main(allowed, outside) {
+            assert_eq!(fs::read_to_string(allowed + "/inside.txt").await?, "inside");
+
+            assert!(denied(fs::read_to_string(outside + "/secret.txt").await));
+            assert!(denied(fs::metadata(outside + "/secret.txt").await));
+            assert!(denied(fs::read_dir(outside).await));
+            assert!(denied(fs::write(outside + "/new.txt", "new").await));
+            assert!(denied(fs::copy(allowed + "/inside.txt", outside + "/copy.txt").await));
+            assert!(denied(fs::copy(outside + "/secret.txt", allowed + "/copy.txt").await));
+            Ok(())
+        }
+        "#,
+    )
+    .await?;
+
+    assert!(!layout.outside().join("new.txt").exists());
+    assert!(!layout.outside().join("copy.txt").exists());
+    assert!(!layout.allowed().join("copy.txt").exists());
+    Ok(())
+}
+
+#[tokio::test]
+async fn parent_dir_escape_denied() -> rune::support::Result<()> {
+    let layout = Layout::new("parent-dir");
+
+    run(
+        &layout,
+        layout.config(),
+        r#"
+        pub async fn main(allowed, outside) {
+            assert!(denied(fs::read_to_string(allowed + "/../outside/secret.txt").await));
+            assert!(denied(fs::write(allowed + "/../outside/new.txt", "new").await));
+            assert!(denied(fs::create_dir_all(allowed + "/a/../../outside/dir").await));
+            assert!(denied(fs::remove_file(allowed + "/../outside/secret.txt").await));
+            assert!(denied(fs::remove_dir_all(allowed + "/..").await));
+
+            // Parent components which stay inside of the root are fine.
+            assert_eq!(fs::read_to_string(allowed + "/missing/../inside.txt").await?, "inside");
+            Ok(())
+        }
+        "#,
+    )
+    .await?;
+
+    assert!(layout.outside().join("secret.txt").exists());
+    assert!(!layout.outside().join("new.txt").exists());
+    assert!(!layout.outside().join("dir").exists());
+    Ok(())
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn symlink_escape_denied() -> rune::support::Result<()> {
+    use std::os::unix::fs::symlink;
+
+    let layout = Layout::new("symlink");
+    symlink(layout.outside(), layout.allowed().join("dir-link"))?;
+    symlink(
+        layout.outside().join("secret.txt"),
+        layout.allowed().join("file-link"),
+    )?;
+
+    run(
+        &layout,
+        layout.config(),
+        r#"
+        pub async fn main(allowed, outside) {
+            assert!(denied(fs::read_to_string(allowed + "/dir-link/secret.txt").await));
+            assert!(denied(fs::read_to_string(allowed + "/file-link").await));
+            assert!(denied(fs::write(allowed + "/dir-link/new.txt", "new").await));
+            assert!(denied(fs::write(allowed + "/file-link", "overwritten").await));
+            assert!(denied(fs::create_dir_all(allowed + "/dir-link/dir").await));
+
+            // Removing a link inside of the root removes the link itself.
+            fs::remove_file(allowed + "/file-link").await?;
+            Ok(())
+        }
+        "#,
+    )
+    .await?;
+
+    assert_eq!(
+        fs::read_to_string(layout.outside().join("secret.txt"))?,
+        "secret"
+    );
+    assert!(!layout.outside().join("new.txt").exists());
+    assert!(!layout.outside().join("dir").exists());
+    assert!(!layout.allowed().join("file-link").exists());
+    Ok(())
+}
+
+#[tokio::test]
+async fn read_only_denies_writes() -> rune::support::Result<()> {
+    let layout = Layout::new("read-only");
+
+    run(
+        &layout,
+        layout.config().read_only(true),
+        r#"
+        pub async fn main(allowed, outside) {
+            assert_eq!(fs::read_to_string(allowed + "/inside.txt").await?, "inside");
+
+            assert!(denied(fs::write(allowed + "/inside.txt", "overwritten").await));
+            assert!(denied(fs::write(allowed + "/new.txt", "new").await));
+            assert!(denied(fs::append(allowed + "/inside.txt", "appended").await));
+            assert!(denied(fs::remove_file(allowed + "/inside.txt").await));
+            assert!(denied(fs::rename(allowed + "/inside.txt", allowed + "/renamed.txt").await));
+            assert!(denied(fs::create_dir_all(allowed + "/dir").await));
+            assert!(denied(fs::File::create(allowed + "/created.txt").await));
+            Ok(())
+        }
+        "#,
+    )
+    .await?;
+
+    assert_eq!(
+        fs::read_to_string(layout.allowed().join("inside.txt"))?,
+        "inside"
+    );
+
+    let mut entries = fs::read_dir(layout.allowed())?
+        .map(|entry| entry.map(|entry| entry.file_name()))
+        .collect::<Result<Vec<_>, _>>()?;
+    entries.sort();
+    assert_eq!(entries, ["inside.txt"]);
+    Ok(())
+}
@@ -0,0 +1,308 @@
+//! Tests for the `http` module, which run scripts against a local in-process
+//! HTTP server.
+
+use std::io;
+use std::sync::Arc;
+use std::time::Duration;
+
+use rune::runtime::Value;
+use rune::termcolor::{ColorChoice, StandardStream};
+use rune::{Diagnostics, Sources, Vm};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+
+/// Start a server on a random local port, returning its base url.
+///
+/// The server understands the following paths:
+/// * `/echo` responds with a description of the request in its body, and with
+///   the `x-multi` header set twice.
+/// * `/redirect` redirects to `/echo`.
+/// * `/slow` responds after five seconds.
+async fn serve() -> io::Result<String> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let address = listener.local_addr()?;
+
+    tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            tokio::spawn(handle(stream));
+        }
+    });
+
+    Ok(format!("http://{address}"))
+}
+
+async fn handle(stream: TcpStream) -> io::Result<()> {
+    let mut stream = BufReader::new(stream);
+
+    let mut line = String::new();
+    stream.read_line(&mut line).await?;
+
+    let mut parts = line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_owned();
+    let target = parts.next().unwrap_or_default().to_owned();
+
+    let mut headers = Vec::new();
+    let mut content_length = 0;
+
+    loop {
+        let mut line = String::new();
+        stream.read_line(&mut line).await?;
+
+        let Some((name, value)) = line.trim_end().split_once(':') else {
+            break;
+        };
+
+        let name = name.trim().to_lowercase();
+        let value = value.trim().to_owned();
+
+        if name == "content-length" {
+            content_length = value.parse().unwrap_or_default();
+        }
+
+        headers.push((name, value));
+    }
+
+    let mut body = vec![0; content_length];
+    stream.read_exact(&mut body).await?;
+
+    let path = target.split('?').next().unwrap_or_default();
+
+    let response = match path {
+        "/echo" => {
+            let mut echo = format!("{method} {target}\n");
+
+            for (name, value) in &headers {
+                echo.push_str(&format!("{name}: {value}\n"));
+            }
+
+            echo.push('\n');
+            echo.push_str(&String::from_utf8_lossy(&body));
+
+            format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nX-Multi: a\r\nX-Multi: b\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{echo}",
+                echo.len()
+            )
+        }
+        "/redirect" => String::from(
+            "HTTP/1.1 302 Found\r\nLocation: /echo\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        ),
+        "/slow" => {
+            tokio::time::sleep(Duration::from_secs(5)).await;
+            String::from("HTTP/1.1 200 OK\r\nContent-Length: 0\r\nConnection: close\r\n\r\n")
+        }
+        _ => String::from(
+            "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        ),
+    };
+
+    stream.get_mut().write_all(response.as_bytes()).await?;
+    stream.get_mut().shutdown().await?;
+    Ok(())
+}
+
+/// Run the `main` function of the given sources with the base url of a test
+/// server, failing if the script panics or returns an error.
+async fn run(mut sources: Sources) -> rune::support::Result<()> {
+    let base = serve().await?;
+
+    let mut context = rune::Context::with_default_modules()?;
+    context.install(rune_modules::http::module(true)?)?;
+    context.install(rune_modules::time::module(true)?)?;
+    let runtime = Arc::new(context.runtime()?);
+
+    let mut diagnostics = Diagnostics::new();
+
+    let result = rune::prepare(&mut sources)
+        .with_context(&context)
+        .with_diagnostics(&mut diagnostics)
+        .build();
+
+    if !diagnostics.is_empty() {
+        let mut writer = StandardStream::stderr(ColorChoice::Always);
+        diagnostics.emit(&mut writer, &sources)?;
+    }
+
+    let mut vm = Vm::new(runtime, Arc::new(result?));
+    let output = vm.async_call(["main"], (base,)).await?;
+
+    if let Err(error) = rune::from_value::<Result<(), Value>>(output)? {
+        let error = rune::from_value::<rune_modules::http::Error>(error);
+        panic!("script errored: {error:?}");
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn query_and_response_headers() -> rune::support::Result<()> {
+    run(rune::sources! {
+        entry => {
+            pub async fn main(base) {
+                let client = http::Client::new();
+
+                let response = client.get(base + "/echo")
+                    .query(#{"q": "rune lang", "page": 2, "tag": ["a", "b"]})
+                    .header("X-Custom", "custom")
+                    .send()
+                    .await?;
+
+                assert_eq!(response.status(), http::StatusCode::OK);
+                assert!(response.url().starts_with(base + "/echo?"));
+                assert_eq!(response.header("X-Multi"), Some("a"));
+                assert_eq!(response.header("Missing"), None);
+                assert_eq!(response.header_all("x-multi"), ["a", "b"]);
+
+                let headers = response.headers();
+                assert_eq!(headers["x-multi"], "a, b");
+                assert_eq!(headers["content-type"], "text/plain");
+
+                let body = response.text().await?;
+                assert!(body.starts_with("GET /echo?"));
+                assert!(body.contains("q=rune+lang"));
+                assert!(body.contains("page=2"));
+                assert!(body.contains("tag=a&tag=b"));
+                assert!(body.contains("x-custom: custom"));
+                Ok(())
+            }
+        }
+    })
+    .await
+}
+
+#[tokio::test]
+async fn form_body() -> rune::support::Result<()> {
+    run(rune::sources! {
+        entry => {
+            pub async fn main(base) {
+                let client = http::Client::new();
+
+                let response = client.post(base + "/echo")
+                    .form(#{"name": "rune & co"})
+                    .send()
+                    .await?;
+
+                let body = response.text().await?;
+                assert!(body.starts_with("POST /echo\n"));
+                assert!(body.contains("content-type: application/x-www-form-urlencoded"));
+                assert!(body.ends_with("\n\nname=rune+%26+co"));
+                Ok(())
+            }
+        }
+    })
+    .await
+}
+
+#[tokio::test]
+async fn multipart_body() -> rune::support::Result<()> {
+    run(rune::sources! {
+        entry => {
+            use http::multipart::{Form, Part};
+
+            pub async fn main(base) {
+                let client = http::Client::new();
+
+                let form = Form::new()
+                    .text("description", "greeting")
+                    .part("file", Part::bytes(b"Hello World").file_name("hello.txt").mime_str("text/plain")?);
+
+                let response = client.post(base + "/echo")
+                    .multipart(form)
+                    .send()
+                    .await?;
+
+                let body = response.text().await?;
+                assert!(body.contains("content-type: multipart/form-data; boundary="));
+                assert!(body.contains("name=\"description\"\r\n\r\ngreeting\r\n"));
+                assert!(body.contains("name=\"file\"; filename=\"hello.txt\"\r\nContent-Type: text/plain\r\n\r\nHello World\r\n"));
+                Ok(())
+            }
+        }
+    })
+    .await
+}
+
+#[tokio::test]
+async fn timeouts() -> rune::support::Result<()> {
+    run(rune::sources! {
+        entry => {
+            use time::Duration;
+
+            pub async fn main(base) {
+                let client = http::Client::new();
+
+                let result = client.get(base + "/slow")
+                    .timeout(Duration::from_millis(100))
+                    .send()
+                    .await;
+
+                assert!(match result { Err(error) => error.is_timeout(), Ok(..) => false });
+
+                let client = http::Client::builder()
+                    .timeout(Duration::from_millis(100))
+                    .build()?;
+
+                let result = client.get(base + "/slow").send().await;
+                assert!(match result { Err(error) => error.is_timeout(), Ok(..) => false });
+
+                let response = client.get(base + "/echo").send().await?;
+                assert!(response.status().is_success());
+                Ok(())
+            }
+        }
+    })
+    .await
+}
+
+#[tokio::test]
+async fn redirect_policy() -> rune::support::Result<()> {
+    run(rune::sources! {
+        entry => {
+            pub async fn main(base) {
+                let response = http::Client::new().get(base + "/redirect").send().await?;
+                assert_eq!(response.status(), http::StatusCode::OK);
+                assert_eq!(response.url(), base + "/echo");
+
+                let client = http::Client::builder().no_redirects().build()?;
+                let response = client.get(base + "/redirect").send().await?;
+                assert_eq!(response.status(), http::StatusCode::FOUND);
+                assert_eq!(response.header("location"), Some("/echo"));
+
+                let client = http::Client::builder().max_redirects(0).build()?;
+                let result = client.get(base + "/redirect").send().await;
+                assert!(match result { Err(error) => error.is_redirect(), Ok(..) => false });
+                Ok(())
+            }
+        }
+    })
+    .await
+}
+
+#[tokio::test]
+async fn client_builder() -> rune::support::Result<()> {
+    run(rune::sources! {
+        entry => {
+            pub async fn main(base) {
+                let client = http::Client::builder()
+                    .user_agent("rune-test")
+                    .default_headers(#{"X-Default": "yes"})
+                    .no_proxy()
+                    .build()?;
+
+                let body = client.get(base + "/echo").send().await?.text().await?;
+                assert!(body.contains("user-agent: rune-test"));
+                assert!(body.contains("x-default: yes"));
+
+                let response = client.get(base + "/missing").send().await?;
+                assert!(response.status().is_client_error());
+
+                assert!(http::Client::builder().default_headers(#{"Not a name": "value"}).build().is_err());
+                assert!(http::Client::builder().proxy("not a url").build().is_err());
+
+                let client = http::Client::builder().https_only(true).build()?;
+                assert!(client.get(base + "/echo").send().await.is_err());
+                Ok(())
+            }
+        }
+    })
+    .await
+}
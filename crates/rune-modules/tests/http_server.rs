@@ -0,0 +1,210 @@
+//! Tests for the `http_server` module, which run scripts serving requests to
+//! themselves through the `http` module.
+
+use std::sync::Arc;
+
+use rune::runtime::Value;
+use rune::termcolor::{ColorChoice, StandardStream};
+use rune::{Diagnostics, Module, Sources, Vm};
+
+/// Run the `main` function of the given sources, failing if the script panics
+/// or returns an error.
+async fn run(mut sources: Sources) -> rune::support::Result<()> {
+    let mut context = rune::Context::with_default_modules()?;
+    context.install(rune_modules::http::module(true)?)?;
+    context.install(rune_modules::http_server::module(true)?)?;
+    context.install(rune_modules::signal::module(true)?)?;
+    context.install(rune_modules::time::module(true)?)?;
+    context.install(testing()?)?;
+    let runtime = Arc::new(context.runtime()?);
+
+    let mut diagnostics = Diagnostics::new();
+
+    let result = rune::prepare(&mut sources)
+        .with_context(&context)
+        .with_diagnostics(&mut diagnostics)
+        .build();
+
+    if !diagnostics.is_empty() {
+        let mut writer = StandardStream::stderr(ColorChoice::Always);
+        diagnostics.emit(&mut writer, &sources)?;
+    }
+
+    let mut vm = Vm::new(runtime, Arc::new(result?));
+    let output = vm.async_call(["main"], ()).await?;
+
+    if let Err(error) = rune::from_value::<Result<(), Value>>(output)? {
+        panic!("script errored: {error:?}");
+    }
+
+    Ok(())
+}
+
+/// Helpers for scripts under test.
+fn testing() -> rune::support::Result<Module> {
+    let mut module = Module::with_crate("testing")?;
+
+    #[cfg(unix)]
+    module
+        .function("raise_interrupt", || {
+            let status = std::process::Command::new("kill")
+                .args(["-INT", &std::process::id().to_string()])
+                .status()
+                .expect("failed to run kill");
+
+            assert!(status.success(), "kill failed with {status}");
+        })
+        .build()?;
+
+    Ok(module)
+}
+
+#[tokio::test]
+async fn function_handler() -> rune::support::Result<()> {
+    run(rune::sources! {
+        entry => {
+            use http_server::{Response, Server};
+            use std::channel;
+            use std::future;
+
+            async fn handler(request) {
+                let body = request.method() + " " + request.path() + " " + request.query().unwrap_or("-");
+                Response::text(body).header("X-Agent", request.header("User-Agent").unwrap_or("none"))
+            }
+
+            pub async fn main() {
+                let server = Server::bind("127.0.0.1:0").await?;
+                let base = "http://" + server.local_addr()?;
+
+                let (tx, rx) = channel::unbounded();
+                let serving = server.serve_with_shutdown(handler, async { rx.recv().await });
+
+                let requests = async {
+                    let client = http::Client::builder().user_agent("tester").build()?;
+
+                    let response = client.get(base + "/hello?name=rune").send().await?;
+                    assert_eq!(response.status(), http::StatusCode::OK);
+                    assert_eq!(response.header("content-type"), Some("text/plain; charset=utf-8"));
+                    assert_eq!(response.header("x-agent"), Some("tester"));
+                    assert_eq!(response.text().await?, "GET /hello name=rune");
+
+                    tx.send(()).await?;
+                    Ok(())
+                };
+
+                let (served, requested) = future::join((serving, requests)).await;
+                served?;
+                requested
+            }
+        }
+    })
+    .await
+}
+
+#[tokio::test]
+async fn router() -> rune::support::Result<()> {
+    run(rune::sources! {
+        entry => {
+            use http_server::{Response, Router, Server};
+            use std::channel;
+            use std::future;
+
+            async fn user(request) {
+                Response::text("user " + request.param("id")?)
+            }
+
+            async fn echo(request) {
+                Ok(Response::text(request.text()?).status(201)?.header("X-Method", request.method())?)
+            }
+
+            fn fail(_request) {
+                Err("boom")
+            }
+
+            pub async fn main() {
+                let server = Server::bind("127.0.0.1:0").await?.body_limit(8);
+                let base = "http://" + server.local_addr()?;
+
+                let router = Router::new()
+                    .get("/users/:id", user)
+                    .post("/echo", echo)
+                    .get("/fail", fail)
+                    .get("/files/*path", |request| Response::text(request.param("path")?));
+
+                let (tx, rx) = channel::unbounded();
+                let serving = server.serve_with_shutdown(router, async { rx.recv().await });
+
+                let requests = async {
+                    let client = http::Client::new();
+
+                    let response = client.get(base + "/users/42/").send().await?;
+                    assert_eq!(response.text().await?, "user 42");
+
+                    let response = client.get(base + "/files/a/b/c.txt").send().await?;
+                    assert_eq!(response.text().await?, "a/b/c.txt");
+
+                    let response = client.post(base + "/echo").body_bytes(b"ping").send().await?;
+                    assert_eq!(response.status(), http::StatusCode::CREATED);
+                    assert_eq!(response.header("x-method"), Some("POST"));
+                    assert_eq!(response.text().await?, "ping");
+
+                    let response = client.post(base + "/echo").body_bytes(b"too large").send().await?;
+                    assert_eq!(response.status(), http::StatusCode::PAYLOAD_TOO_LARGE);
+
+                    let response = client.get(base + "/fail").send().await?;
+                    assert_eq!(response.status(), http::StatusCode::INTERNAL_SERVER_ERROR);
+
+                    let response = client.delete(base + "/users/42").send().await?;
+                    assert_eq!(response.status(), http::StatusCode::METHOD_NOT_ALLOWED);
+
+                    let response = client.get(base + "/missing").send().await?;
+                    assert_eq!(response.status(), http::StatusCode::NOT_FOUND);
+
+                    tx.send(()).await?;
+                    Ok(())
+                };
+
+                let (served, requested) = future::join((serving, requests)).await;
+                served?;
+                requested
+            }
+        }
+    })
+    .await
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn shutdown_on_ctrl_c() -> rune::support::Result<()> {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    // Registering a listener replaces the default behavior of terminating the
+    // process on SIGINT for the rest of the test run.
+    let _interrupt = signal(SignalKind::interrupt())?;
+
+    run(rune::sources! {
+        entry => {
+            use http_server::{Response, Server};
+            use std::future;
+
+            pub async fn main() {
+                let server = Server::bind("127.0.0.1:0").await?;
+                let base = "http://" + server.local_addr()?;
+
+                let serving = server.serve_with_shutdown(|request| Response::text("up"), signal::ctrl_c());
+
+                let requests = async {
+                    let response = http::get(base).await?;
+                    assert_eq!(response.text().await?, "up");
+                    testing::raise_interrupt();
+                    Ok(())
+                };
+
+                let (served, requested) = future::join((serving, requests)).await;
+                served?;
+                requested
+            }
+        }
+    })
+    .await
+}
@@ -0,0 +1,86 @@
+//! Tests for the timeouts of the `process` module, which run short-lived shell
+//! commands.
+
+#![cfg(unix)]
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use rune::runtime::Value;
+use rune::termcolor::{ColorChoice, StandardStream};
+use rune::{Diagnostics, Source, Sources, Vm};
+
+/// Run `main()` in the given script, failing if the script panics or returns
+/// an error.
+async fn run(source: &str) -> rune::support::Result<()> {
+    let mut context = rune::Context::with_default_modules()?;
+    context.install(rune_modules::process::module(true)?)?;
+    context.install(rune_modules::time::module(true)?)?;
+    let runtime = Arc::new(context.runtime()?);
+
+    let mut sources = Sources::new();
+    sources.insert(Source::memory(source)?)?;
+
+    let mut diagnostics = Diagnostics::new();
+
+    let result = rune::prepare(&mut sources)
+        .with_context(&context)
+        .with_diagnostics(&mut diagnostics)
+        .build();
+
+    if !diagnostics.is_empty() {
+        let mut writer = StandardStream::stderr(ColorChoice::Always);
+        diagnostics.emit(&mut writer, &sources)?;
+    }
+
+    let mut vm = Vm::new(runtime, Arc::new(result?));
+    let output = vm.async_call(["main"], ()).await?;
+
+    if let Err(error) = rune::from_value::<Result<(), Value>>(output)? {
+        panic!("script errored: {error:?}");
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn timeouts() -> rune::support::Result<()> {
+    let start = Instant::now();
+
+    run(r#"
+        use process::Command;
+        use time::Duration;
+
+        fn timed_out(result) {
+            match result {
+                Ok(..) => false,
+                Err(error) => `${error}`.contains("timeout"),
+            }
+        }
+
+        pub async fn main() {
+            let command = Command::new("sleep");
+            command.arg("10");
+            command.timeout(Duration::from_millis(100));
+            assert!(timed_out(command.status().await));
+
+            let command = Command::new("sh");
+            command.args(["-c", "echo partial; sleep 10"]);
+            command.timeout(Duration::from_millis(100));
+            assert!(timed_out(command.output().await));
+
+            let command = Command::new("echo");
+            command.arg("hello");
+            command.timeout(Duration::from_secs(10));
+            let output = command.output().await?;
+            assert!(output.status.success());
+            assert_eq!(output.stdout, b"hello\n");
+            Ok(())
+        }
+        "#)
+    .await?;
+
+    // NB: the children are killed rather than waited for.
+    assert!(start.elapsed() < Duration::from_secs(5));
+    Ok(())
+}
@@ -54,6 +54,35 @@ use crate::runtime::{AnyTypeInfo, TypeHash};
 ///     Ok(module)
 /// }
 /// ```
+///
+/// ## `#[rune(serde)]` attribute
+///
+/// Register the type's [`Serialize`] and [`Deserialize`] implementations when
+/// it's installed, so that it can be serialized from inside of scripts and
+/// deserialized through [`TypeSeed`]. See [`Module::serde`].
+///
+/// [`Serialize`]: serde::Serialize
+/// [`Deserialize`]: serde::Deserialize
+/// [`TypeSeed`]: crate::runtime::TypeSeed
+/// [`Module::serde`]: crate::Module::serde
+///
+/// ```
+/// use rune::Any;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Any, Serialize, Deserialize)]
+/// #[rune(serde)]
+/// struct Point {
+///     x: i64,
+///     y: i64,
+/// }
+///
+/// fn install() -> Result<rune::Module, rune::ContextError> {
+///     let mut module = rune::Module::new();
+///     module.ty::<Point>()?;
+///     Ok(module)
+/// }
+/// ```
 pub use rune_macros::Any;
 
 /// Derive for types which can be used inside of Rune.
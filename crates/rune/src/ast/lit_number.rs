@@ -103,6 +103,8 @@ impl<'a> Resolve<'a> for LitNumber {
             "i32" => Some(ast::NumberSuffix::Signed(text.suffix, NumberSize::S32)),
             "i64" => Some(ast::NumberSuffix::Signed(text.suffix, NumberSize::S64)),
             "f32" | "f64" => Some(ast::NumberSuffix::Float(text.suffix)),
+            "n" => Some(ast::NumberSuffix::BigInt(text.suffix)),
+            "d" => Some(ast::NumberSuffix::Decimal(text.suffix)),
             "" => None,
             _ => {
                 return Err(compile::Error::new(
@@ -112,6 +114,19 @@ impl<'a> Resolve<'a> for LitNumber {
             }
         };
 
+        if let Some(ast::NumberSuffix::Decimal(..)) = suffix {
+            let number: crate::runtime::Decimal = string.parse().map_err(err_span(span))?;
+
+            return Ok(ast::Number {
+                value: ast::NumberValue::Decimal(number),
+                suffix,
+            });
+        }
+
+        if let (Some(ast::NumberSuffix::BigInt(..)), true) = (suffix, text.is_fractional) {
+            return Err(compile::Error::new(span, ErrorKind::BadNumberLiteral));
+        }
+
         if matches!(
             (suffix, text.is_fractional),
             (Some(ast::NumberSuffix::Float(..)), _) | (None, true)
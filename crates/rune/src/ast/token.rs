@@ -199,6 +199,8 @@ pub enum NumberValue {
     Float(f64),
     /// An integer literal number.
     Integer(#[try_clone(with = num::BigInt::clone)] num::BigInt),
+    /// A decimal literal number, like `1.10d`.
+    Decimal(#[try_clone(with = crate::runtime::Decimal::clone)] crate::runtime::Decimal),
 }
 
 /// The literal size of a number.
@@ -275,6 +277,10 @@ pub enum NumberSuffix {
     Signed(Span, NumberSize),
     /// The `f64` suffix.
     Float(Span),
+    /// The `n` suffix, for arbitrary-precision integers.
+    BigInt(Span),
+    /// The `d` suffix, for decimals.
+    Decimal(Span),
 }
 
 /// A resolved number literal.
@@ -368,6 +374,7 @@ impl fmt::Display for Number {
         match &self.value {
             NumberValue::Float(n) => write!(f, "{}", n),
             NumberValue::Integer(n) => write!(f, "{}", n),
+            NumberValue::Decimal(n) => write!(f, "{}", n),
         }
     }
 }
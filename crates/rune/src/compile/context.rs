@@ -20,8 +20,8 @@ use crate::module::{
     TypeSpecification,
 };
 use crate::runtime::{
-    AnyTypeInfo, ConstConstruct, ConstContext, ConstValue, FunctionHandler, InstAddress, Memory,
-    Output, Protocol, Rtti, RttiKind, RuntimeContext, TypeCheck, TypeInfo, VmResult,
+    AnySerde, AnyTypeInfo, ConstConstruct, ConstContext, ConstValue, FunctionHandler, InstAddress,
+    Memory, Output, Protocol, Rtti, RttiKind, RuntimeContext, TypeCheck, TypeInfo, VmResult,
 };
 use crate::{Hash, Item, ItemBuf};
 
@@ -309,6 +309,8 @@ pub struct Context {
     constants: hash::Map<ConstValue>,
     /// Constant constructor.
     construct: hash::Map<Arc<dyn ConstConstruct>>,
+    /// Serde implementations of native types.
+    serde: hash::Map<Arc<dyn AnySerde>>,
 }
 
 impl Context {
@@ -350,19 +352,30 @@ impl Context {
 
         this.install(crate::modules::collections::module()?)?;
         #[cfg(feature = "alloc")]
+        this.install(crate::modules::collections::binary_heap::module()?)?;
+        #[cfg(feature = "alloc")]
+        this.install(crate::modules::collections::btree_map::module()?)?;
+        #[cfg(feature = "alloc")]
+        this.install(crate::modules::collections::btree_set::module()?)?;
+        #[cfg(feature = "alloc")]
         this.install(crate::modules::collections::hash_map::module()?)?;
         #[cfg(feature = "alloc")]
         this.install(crate::modules::collections::hash_set::module()?)?;
         #[cfg(feature = "alloc")]
         this.install(crate::modules::collections::vec_deque::module()?)?;
 
+        this.install(crate::modules::channel::module()?)?;
         this.install(crate::modules::char::module()?)?;
         this.install(crate::modules::f64::module()?)?;
+        this.install(crate::modules::f64::consts::module()?)?;
         this.install(crate::modules::tuple::module()?)?;
         this.install(crate::modules::fmt::module()?)?;
         this.install(crate::modules::future::module()?)?;
         this.install(crate::modules::i64::module()?)?;
         this.install(crate::modules::u64::module()?)?;
+        this.install(crate::modules::u8::module()?)?;
+        this.install(crate::modules::i32::module()?)?;
+        this.install(crate::modules::u32::module()?)?;
         this.install(crate::modules::io::module(stdio)?)?;
         this.install(crate::modules::macros::module()?)?;
         this.install(crate::modules::macros::builtin::module()?)?;
@@ -373,6 +386,7 @@ impl Context {
         this.install(crate::modules::option::module()?)?;
         this.install(crate::modules::result::module()?)?;
         this.install(crate::modules::stream::module()?)?;
+        this.install(crate::modules::task::module()?)?;
         this.install(crate::modules::test::module()?)?;
         this.install(crate::modules::vec::module()?)?;
         this.install(crate::modules::slice::module()?)?;
@@ -407,6 +421,7 @@ impl Context {
             self.functions.try_clone()?,
             self.constants.try_clone()?,
             self.construct.try_clone()?,
+            self.serde.try_clone()?,
         ))
     }
 
@@ -471,6 +486,11 @@ impl Context {
             self.install_construct(*hash, type_info, construct)?;
         }
 
+        tracing::trace!(serde = module.serde.len(), "serde");
+        for (hash, type_info, serde) in &module.serde {
+            self.install_serde(*hash, type_info, serde)?;
+        }
+
         Ok(())
     }
 
@@ -994,6 +1014,24 @@ impl Context {
         Ok(())
     }
 
+    fn install_serde(
+        &mut self,
+        hash: Hash,
+        type_info: &AnyTypeInfo,
+        serde: &Arc<dyn AnySerde>,
+    ) -> Result<(), ContextError> {
+        let old = self.serde.try_insert(hash, serde.clone())?;
+
+        if old.is_some() {
+            return Err(ContextError::ConflictingSerde {
+                type_info: TypeInfo::from(*type_info),
+                hash,
+            });
+        }
+
+        Ok(())
+    }
+
     fn install_type_info(&mut self, ty: ContextType) -> Result<(), ContextError> {
         let item_hash = Hash::type_hash(&ty.item).with_type_parameters(ty.type_parameters);
 
@@ -129,6 +129,10 @@ pub enum ContextError {
         type_info: TypeInfo,
         hash: Hash,
     },
+    ConflictingSerde {
+        type_info: TypeInfo,
+        hash: Hash,
+    },
     MissingType {
         item: ItemBuf,
         type_info: TypeInfo,
@@ -346,6 +350,12 @@ impl fmt::Display for ContextError {
                     "Conflicting constant constructor with hash {hash} for type {type_info}"
                 )?;
             }
+            ContextError::ConflictingSerde { type_info, hash } => {
+                write!(
+                    f,
+                    "Conflicting serde implementation with hash {hash} for type {type_info}"
+                )?;
+            }
             ContextError::MissingType { item, type_info } => {
                 write!(f, "Type `{item}` with info `{type_info}` isn't registered")?;
             }
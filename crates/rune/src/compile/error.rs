@@ -1145,7 +1145,7 @@ impl fmt::Display for ErrorKind {
             ErrorKind::UnsupportedSuffix => {
                 write!(
                     f,
-                    "Unsupported suffix, expected one of `u8`, `i64`, `u64`, or `f64`"
+                    "Unsupported suffix, expected one of `u8`, `i64`, `u64`, `f64`, `n`, or `d`"
                 )?;
             }
             ErrorKind::ClosureInConst => {
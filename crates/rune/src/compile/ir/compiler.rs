@@ -7,7 +7,7 @@ use crate::compile::ir;
 use crate::compile::{self, ErrorKind, WithSpan};
 use crate::hir;
 use crate::query::Query;
-use crate::runtime::{Bytes, Value};
+use crate::runtime::{Bytes, Decimal, Value};
 use crate::SourceId;
 
 use tracing::instrument_ast;
@@ -220,6 +220,22 @@ fn lit(_c: &mut Ctxt<'_, '_>, span: Span, hir: hir::Lit<'_>) -> compile::Result<
             let value = Value::from(n);
             ir::Ir::new(span, value)
         }
+        hir::Lit::BigInt(string) => {
+            let Ok(n) = string.parse::<num::BigInt>() else {
+                return Err(compile::Error::new(span, ErrorKind::BadNumberLiteral));
+            };
+
+            let value = Value::new(n).with_span(span)?;
+            ir::Ir::new(span, value)
+        }
+        hir::Lit::Decimal(string) => {
+            let Ok(n) = string.parse::<Decimal>() else {
+                return Err(compile::Error::new(span, ErrorKind::BadNumberLiteral));
+            };
+
+            let value = Value::new(n).with_span(span)?;
+            ir::Ir::new(span, value)
+        }
         hir::Lit::Char(c) => {
             let value = Value::from(c);
             ir::Ir::new(span, value)
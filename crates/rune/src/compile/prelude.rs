@@ -24,11 +24,15 @@ impl Prelude {
         this.add_prelude("f64", ["f64"])?;
         this.add_prelude("i64", ["i64"])?;
         this.add_prelude("u64", ["u64"])?;
+        this.add_prelude("i32", ["i32"])?;
+        this.add_prelude("u32", ["u32"])?;
         this.add_prelude("char", ["char"])?;
         this.add_prelude("dbg", ["io", "dbg"])?;
         this.add_prelude("drop", ["mem", "drop"])?;
         this.add_prelude("clone", ["clone", "clone"])?;
         this.add_prelude("Err", ["result", "Result", "Err"])?;
+        this.add_prelude("eprint", ["io", "eprint"])?;
+        this.add_prelude("eprintln", ["io", "eprintln"])?;
         this.add_prelude("file", ["macros", "builtin", "file"])?;
         this.add_prelude("format", ["fmt", "format"])?;
         this.add_prelude("is_readable", ["is_readable"])?;
@@ -21,7 +21,7 @@ use crate::runtime::{
     Call, ConstValue, DebugInfo, DebugInst, Inst, InstAddress, Label, Protocol, Rtti, RttiKind,
     StaticString, Unit, UnitFn,
 };
-use crate::{Context, Diagnostics, Hash, Item, SourceId};
+use crate::{Context, Diagnostics, Hash, Item, ItemBuf, SourceId};
 
 /// Errors that can be raised when linking units.
 #[derive(Debug)]
@@ -88,6 +88,8 @@ pub(crate) struct UnitBuilder {
     constants: hash::Map<ConstValue>,
     /// Hash to identifiers.
     hash_to_ident: HashMap<Hash, Box<str>>,
+    /// Hash to the items of called functions.
+    hash_to_item: HashMap<Hash, ItemBuf>,
 }
 
 impl UnitBuilder {
@@ -106,6 +108,15 @@ impl UnitBuilder {
         Ok(())
     }
 
+    /// Insert the item of a called function for debug purposes.
+    pub(crate) fn insert_debug_item(&mut self, hash: Hash, item: &Item) -> alloc::Result<()> {
+        if !self.hash_to_item.contains_key(&hash) {
+            self.hash_to_item.try_insert(hash, item.try_to_owned()?)?;
+        }
+
+        Ok(())
+    }
+
     /// Convert into a runtime unit, shedding our build metadata in the process.
     ///
     /// Returns `None` if the builder is still in use.
@@ -113,6 +124,7 @@ impl UnitBuilder {
         if let Some(debug) = &mut self.debug {
             debug.functions_rev = self.functions_rev;
             debug.hash_to_ident = self.hash_to_ident;
+            debug.hash_to_item = self.hash_to_item;
         }
 
         for (from, to) in self.reexports {
@@ -12,9 +12,9 @@ use crate::compile::{self, Assembly, ErrorKind, ItemId, ModId, Options, WithSpan
 use crate::hir;
 use crate::query::{ConstFn, Query, Used};
 use crate::runtime::{
-    ConstValue, ConstValueKind, Inline, Inst, InstAddress, InstArithmeticOp, InstBitwiseOp, InstOp,
-    InstRange, InstShiftOp, InstTarget, InstValue, InstVariant, Label, Output, PanicReason,
-    Protocol, TypeCheck,
+    ConstValue, ConstValueKind, Decimal, Inline, Inst, InstAddress, InstArithmeticOp,
+    InstBitwiseOp, InstOp, InstRange, InstShiftOp, InstTarget, InstValue, InstVariant, Label,
+    Output, PanicReason, Protocol, ToConstValue, TypeCheck,
 };
 use crate::shared::FixedVec;
 use crate::{Hash, SourceId};
@@ -3290,6 +3290,23 @@ fn lit<'a, 'hir>(
         hir::Lit::Float(v) => {
             cx.asm.push(Inst::float(v, out), span)?;
         }
+        hir::Lit::BigInt(string) => {
+            let Ok(n) = string.parse::<num::BigInt>() else {
+                return Err(compile::Error::new(span, ErrorKind::BadNumberLiteral));
+            };
+
+            // NB: the number is parsed once here and stored as a constant.
+            let value = n.to_const_value().with_span(span)?;
+            const_(cx, &value, span, addr)?;
+        }
+        hir::Lit::Decimal(string) => {
+            let Ok(n) = string.parse::<Decimal>() else {
+                return Err(compile::Error::new(span, ErrorKind::BadNumberLiteral));
+            };
+
+            let value = n.to_const_value().with_span(span)?;
+            const_(cx, &value, span, addr)?;
+        }
         hir::Lit::Str(string) => {
             let slot = cx.q.unit.new_static_string(span, string)?;
             cx.asm.push(Inst::String { slot, out }, span)?;
@@ -20,15 +20,12 @@ use crate::diagnostics::{
 };
 use crate::hash::Hash;
 use crate::runtime::DebugInfo;
-use crate::runtime::{DebugInst, Protocol, Unit, VmError, VmErrorAt, VmErrorKind};
+use crate::runtime::{
+    BacktraceFrameKind, DebugInst, Protocol, Unit, VmError, VmErrorAt, VmErrorKind,
+};
 use crate::Context;
 use crate::{Diagnostics, Source, SourceId, Sources};
 
-struct StackFrame {
-    source_id: SourceId,
-    span: Span,
-}
-
 /// Errors that can be raised when formatting diagnostics.
 #[derive(Debug)]
 #[non_exhaustive]
@@ -171,31 +168,8 @@ impl VmError {
         let mut red = termcolor::ColorSpec::new();
         red.set_fg(Some(termcolor::Color::Red));
 
-        let mut backtrace = vec![];
         let config = term::Config::default();
 
-        for l in &self.inner.stacktrace {
-            let debug_info = match l.unit.debug_info() {
-                Some(debug_info) => debug_info,
-                None => continue,
-            };
-
-            for ip in [l.ip]
-                .into_iter()
-                .chain(l.frames.iter().rev().map(|v| v.ip))
-            {
-                let debug_inst = match debug_info.instruction_at(ip) {
-                    Some(debug_inst) => debug_inst,
-                    None => continue,
-                };
-
-                let source_id = debug_inst.source_id;
-                let span = debug_inst.span;
-
-                backtrace.push(StackFrame { source_id, span });
-            }
-        }
-
         let mut labels = ::rust_alloc::vec::Vec::new();
         let mut notes = ::rust_alloc::vec::Vec::new();
 
@@ -290,23 +264,48 @@ impl VmError {
 
         term::emit(out, &config, sources, &diagnostic)?;
 
-        if !backtrace.is_empty() {
+        let mut backtrace = self.raw_backtrace().enumerate().peekable();
+
+        if backtrace.peek().is_some() {
             writeln!(out, "Backtrace:")?;
 
-            for frame in &backtrace {
-                let Some(source) = sources.get(frame.source_id) else {
+            for (index, raw) in backtrace {
+                let frame = raw.resolve(sources)?;
+
+                match (frame.kind, &frame.function) {
+                    (BacktraceFrameKind::Native, Some(function)) => {
+                        writeln!(out, "{index:>4}: {function} (native)")?;
+                    }
+                    (BacktraceFrameKind::Native, None) => {
+                        writeln!(out, "{index:>4}: <native>")?;
+                    }
+                    (_, Some(function)) => {
+                        writeln!(out, "{index:>4}: {function}")?;
+                    }
+                    (_, None) => {
+                        writeln!(out, "{index:>4}: <unknown>")?;
+                    }
+                }
+
+                let Some(location) = &frame.location else {
                     continue;
                 };
 
-                let (line, line_count, [prefix, mid, suffix]) = match source.line(frame.span) {
-                    Some((line, line_count, text)) => {
-                        (line.saturating_add(1), line_count.saturating_add(1), text)
-                    }
-                    None => continue,
+                writeln!(out, "             at {location}")?;
+
+                let Some(source) = sources.get(location.source_id) else {
+                    continue;
+                };
+
+                let Some((_, span)) = raw.span() else {
+                    continue;
+                };
+
+                let Some((_, _, [prefix, mid, suffix])) = source.line(span) else {
+                    continue;
                 };
 
-                writeln!(out, "{}:{line}:{line_count}:", source.name())?;
-                write!(out, "{prefix}")?;
+                write!(out, "             {}", prefix.trim_start())?;
                 out.set_color(&red)?;
                 write!(out, "{mid}")?;
                 out.reset()?;
@@ -193,6 +193,10 @@ pub(crate) enum Lit<'hir> {
     Unsigned(u64),
     Signed(i64),
     Float(f64),
+    /// An arbitrary-precision integer, stored in its canonical string form.
+    BigInt(&'hir str),
+    /// A decimal, stored in its canonical string form.
+    Decimal(&'hir str),
     Char(char),
     Str(&'hir str),
     ByteStr(&'hir [u8]),
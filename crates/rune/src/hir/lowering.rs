@@ -781,6 +781,12 @@ fn lit<'hir>(cx: &mut Ctxt<'hir, '_, '_>, ast: &ast::Lit) -> compile::Result<hir
 
             match (n.value, n.suffix) {
                 (ast::NumberValue::Float(n), _) => Ok(hir::Lit::Float(n)),
+                (ast::NumberValue::Decimal(n), _) => {
+                    Ok(hir::Lit::Decimal(alloc_str!(&n.try_to_string()?)))
+                }
+                (ast::NumberValue::Integer(int), Some(ast::NumberSuffix::BigInt(..))) => {
+                    Ok(hir::Lit::BigInt(alloc_str!(&int.try_to_string()?)))
+                }
                 (ast::NumberValue::Integer(int), Some(ast::NumberSuffix::Unsigned(_, size))) => {
                     let Some(n) = int.to_u64() else {
                         return Err(compile::Error::new(
@@ -883,6 +889,14 @@ fn expr_unary<'hir>(
 
     match (number.value, number.suffix) {
         (ast::NumberValue::Float(n), _) => Ok(hir::ExprKind::Lit(hir::Lit::Float(-n))),
+        (ast::NumberValue::Decimal(n), _) => {
+            let n = (-&n).try_to_string()?;
+            Ok(hir::ExprKind::Lit(hir::Lit::Decimal(alloc_str!(&n))))
+        }
+        (ast::NumberValue::Integer(int), Some(ast::NumberSuffix::BigInt(..))) => {
+            let n = int.neg().try_to_string()?;
+            Ok(hir::ExprKind::Lit(hir::Lit::BigInt(alloc_str!(&n))))
+        }
         (ast::NumberValue::Integer(int), Some(ast::NumberSuffix::Unsigned(_, size))) => {
             let Some(n) = int.neg().to_u64() else {
                 return Err(compile::Error::new(
@@ -1859,6 +1873,9 @@ fn expr_call<'hir>(
                                 message.try_into()?,
                             )?;
                         };
+
+                        let item = cx.q.pool.item(meta.item_meta.item);
+                        cx.q.unit.insert_debug_item(meta.hash, item)?;
                     }
                     meta::Kind::ConstFn => {
                         let from =
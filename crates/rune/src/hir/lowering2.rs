@@ -362,6 +362,9 @@ impl<'hir, 'a> ExprInner<'hir, 'a> {
                                 message.try_into()?,
                             )?;
                         };
+
+                        let item = cx.q.pool.item(meta.item_meta.item);
+                        cx.q.unit.insert_debug_item(meta.hash, item)?;
                     }
                     meta::Kind::ConstFn => {
                         let from =
@@ -2757,6 +2760,14 @@ fn lit<'hir>(cx: &mut Ctxt<'hir, '_, '_>, p: &mut Stream<'_>) -> Result<hir::Lit
                     let n = if neg { -n } else { n };
                     Ok(hir::Lit::Float(n))
                 }
+                (ast::NumberValue::Decimal(n), _) => {
+                    let n = if neg { -&n } else { n };
+                    Ok(hir::Lit::Decimal(alloc_str!(&n.try_to_string()?)))
+                }
+                (ast::NumberValue::Integer(int), Some(ast::NumberSuffix::BigInt(..))) => {
+                    let int = if neg { int.neg() } else { int };
+                    Ok(hir::Lit::BigInt(alloc_str!(&int.try_to_string()?)))
+                }
                 (ast::NumberValue::Integer(int), Some(ast::NumberSuffix::Unsigned(_, size))) => {
                     let int = if neg { int.neg() } else { int };
 
@@ -692,6 +692,8 @@ rune_macros::binding! {
     impl ::std::char::ParseCharError for core::char::ParseCharError;
     impl ::std::num::ParseFloatError for core::num::ParseFloatError;
     impl ::std::num::ParseIntError for core::num::ParseIntError;
+    impl ::std::num::BigInt for num::BigInt;
+    impl ::std::num::ParseBigIntError for num::bigint::ParseBigIntError;
     impl ::std::string::Utf8Error for core::str::Utf8Error;
     #[any]
     impl ::std::option::Option for Option<Value>;
@@ -17,10 +17,10 @@ use crate::item::IntoComponent;
 use crate::macros::{MacroContext, TokenStream};
 use crate::module::DocFunction;
 use crate::runtime::{
-    AnyTypeInfo, ConstConstruct, InstAddress, MaybeTypeOf, Memory, Output, Protocol, ToConstValue,
-    TypeHash, TypeOf, VmResult,
+    AnySerde, AnySerdeImpl, AnyTypeInfo, ConstConstruct, InstAddress, MaybeTypeOf, Memory, Output,
+    Protocol, ToConstValue, TypeHash, TypeOf, VmResult,
 };
-use crate::{Hash, Item, ItemBuf};
+use crate::{Any, Hash, Item, ItemBuf};
 
 use super::{
     AssociatedKey, EnumMut, InstallWith, ItemFnMut, ItemMut, ModuleAssociated,
@@ -74,6 +74,8 @@ pub struct Module {
     pub(crate) construct: Vec<(Hash, AnyTypeInfo, Arc<dyn ConstConstruct>)>,
     /// Defines construct hashes.
     pub(crate) construct_hash: HashSet<Hash>,
+    /// Serde implementations of native types.
+    pub(crate) serde: Vec<(Hash, AnyTypeInfo, Arc<dyn AnySerde>)>,
     /// Module level metadata.
     pub(crate) common: ModuleItemCommon,
 }
@@ -135,6 +137,7 @@ impl Module {
             reexports: Vec::new(),
             construct: Vec::new(),
             construct_hash: HashSet::new(),
+            serde: Vec::new(),
             common: ModuleItemCommon {
                 docs: Docs::EMPTY,
                 deprecated: None,
@@ -235,6 +238,45 @@ impl Module {
         })
     }
 
+    /// Register serde support for the native type `T`.
+    ///
+    /// This allows values of the type to be serialized, and to be deserialized
+    /// through [`TypeSeed`]. This is what deriving [`Any`] with
+    /// `#[rune(serde)]` does.
+    ///
+    /// [`TypeSeed`]: crate::runtime::TypeSeed
+    /// [`Any`]: derive@crate::Any
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rune::{Any, Module};
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// #[derive(Any, Serialize, Deserialize)]
+    /// struct Point {
+    ///     x: i64,
+    ///     y: i64,
+    /// }
+    ///
+    /// let mut m = Module::new();
+    /// m.ty::<Point>()?;
+    /// m.serde::<Point>()?;
+    /// # Ok::<_, rune::support::Error>(())
+    /// ```
+    pub fn serde<T>(&mut self) -> Result<(), ContextError>
+    where
+        T: Any + serde::Serialize + serde::de::DeserializeOwned,
+    {
+        self.serde.try_push((
+            T::HASH,
+            T::ANY_TYPE_INFO,
+            Arc::new(AnySerdeImpl::<T>::new()),
+        ))?;
+
+        Ok(())
+    }
+
     /// Accessor to modify type metadata such as documentaiton, fields, variants.
     pub fn type_meta<T>(&mut self) -> Result<TypeMut<'_, T>, ContextError>
     where
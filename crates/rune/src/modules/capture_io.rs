@@ -22,7 +22,9 @@ use parking_lot::Mutex;
 use crate as rune;
 use crate::alloc::fmt::TryWrite;
 use crate::alloc::string::FromUtf8Error;
-use crate::alloc::{String, Vec};
+use crate::alloc::{self, String, Vec};
+#[cfg(feature = "std")]
+use crate::modules::io::{Input, Sink, Stderr, Stdin, Stdout};
 use crate::runtime::{InstAddress, Memory, Output, VmError, VmResult};
 use crate::{ContextError, Module, Value};
 
@@ -55,6 +57,49 @@ pub fn module(io: &CaptureIo) -> Result<Module, ContextError> {
 
     let o = io.clone();
 
+    module
+        .function("eprint", move |m: &str| {
+            match write!(o.inner.lock(), "{}", m) {
+                Ok(()) => VmResult::Ok(()),
+                Err(error) => VmResult::panic(error),
+            }
+        })
+        .build()?;
+
+    let o = io.clone();
+
+    module
+        .function("eprintln", move |m: &str| {
+            match writeln!(o.inner.lock(), "{}", m) {
+                Ok(()) => VmResult::Ok(()),
+                Err(error) => VmResult::panic(error),
+            }
+        })
+        .build()?;
+
+    #[cfg(feature = "std")]
+    {
+        let o = io.clone();
+
+        module
+            .function("stdin", move || Stdin::new(Input::Buffer(o.input.clone())))
+            .build()?;
+
+        let o = io.clone();
+
+        module
+            .function("stdout", move || Stdout::new(Sink::Buffer(o.inner.clone())))
+            .build()?;
+
+        let o = io.clone();
+
+        module
+            .function("stderr", move || Stderr::new(Sink::Buffer(o.inner.clone())))
+            .build()?;
+    }
+
+    let o = io.clone();
+
     module
         .raw_function("dbg", move |stack, addr, args, output| {
             let mut o = o.inner.lock();
@@ -66,9 +111,14 @@ pub fn module(io: &CaptureIo) -> Result<Module, ContextError> {
 }
 
 /// Type which captures output from rune scripts.
+///
+/// Everything written to standard output and standard error is captured into
+/// the same buffer, and standard input is read from the input provided through
+/// [`CaptureIo::push_input`].
 #[derive(Default, Clone)]
 pub struct CaptureIo {
     inner: Arc<Mutex<Vec<u8>>>,
+    input: Arc<Mutex<Vec<u8>>>,
 }
 
 impl CaptureIo {
@@ -77,6 +127,21 @@ impl CaptureIo {
         Self::default()
     }
 
+    /// Append to the input which scripts read from standard input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rune::modules::capture_io::CaptureIo;
+    ///
+    /// let io = CaptureIo::new();
+    /// io.push_input(b"first line\nsecond line\n")?;
+    /// # Ok::<_, rune::alloc::Error>(())
+    /// ```
+    pub fn push_input(&self, input: &[u8]) -> alloc::Result<()> {
+        self.input.lock().try_extend_from_slice(input)
+    }
+
     /// Test if capture is empty.
     pub fn is_empty(&self) -> bool {
         self.inner.lock().is_empty()
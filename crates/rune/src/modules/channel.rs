@@ -0,0 +1,403 @@
+//! Message passing between tasks.
+
+use core::cell::{Cell, RefCell};
+use core::future::poll_fn;
+use core::task::{Context, Poll, Waker};
+
+use ::rust_alloc::rc::Rc;
+
+use crate as rune;
+use crate::alloc::{self, Vec, VecDeque};
+use crate::runtime::{Ref, Value, VmResult};
+use crate::{Any, ContextError, Module};
+
+/// Message passing between tasks.
+///
+/// Channels are the preferred way for tasks to communicate with each other.
+/// Values sent over a channel are moved from the sending task to the receiving
+/// task, so no locking is needed to share them.
+///
+/// # Examples
+///
+/// ```rune
+/// use std::channel;
+///
+/// let (tx, rx) = channel::unbounded();
+///
+/// tx.send(1).await?;
+/// tx.send(2).await?;
+///
+/// assert_eq!(rx.recv().await, Some(1));
+/// assert_eq!(rx.recv().await, Some(2));
+/// ```
+#[rune::module(::std::channel)]
+pub fn module() -> Result<Module, ContextError> {
+    let mut m = Module::from_meta(self::module_meta)?;
+
+    m.function_meta(bounded)?;
+    m.function_meta(unbounded)?;
+
+    m.ty::<Sender>()?;
+    m.function_meta(Sender::send__meta)?;
+    m.function_meta(Sender::try_send__meta)?;
+    m.function_meta(Sender::is_closed__meta)?;
+    m.function_meta(Sender::clone__meta)?;
+    m.implement_trait::<Sender>(rune::item!(::std::clone::Clone))?;
+
+    m.ty::<Receiver>()?;
+    m.function_meta(Receiver::recv__meta)?;
+    m.function_meta(Receiver::try_recv__meta)?;
+    m.function_meta(Receiver::len__meta)?;
+    m.function_meta(Receiver::is_empty__meta)?;
+    Ok(m)
+}
+
+/// Construct a channel which holds at most `capacity` values at a time.
+///
+/// Sending to a full channel waits until the receiver has made room for the
+/// value. A capacity of zero is treated as a capacity of one.
+///
+/// # Examples
+///
+/// ```rune
+/// use std::channel;
+///
+/// let (tx, rx) = channel::bounded(1);
+///
+/// assert_eq!(tx.try_send(1), Ok(()));
+/// assert_eq!(tx.try_send(2), Err(2));
+/// assert_eq!(rx.try_recv(), Some(1));
+/// assert_eq!(tx.try_send(2), Ok(()));
+/// ```
+#[rune::function]
+fn bounded(capacity: usize) -> (Sender, Receiver) {
+    channel(Some(capacity.max(1)))
+}
+
+/// Construct a channel which can hold any number of values.
+///
+/// # Examples
+///
+/// ```rune
+/// use std::channel;
+///
+/// let (tx, rx) = channel::unbounded();
+///
+/// for n in 0..100 {
+///     assert_eq!(tx.try_send(n), Ok(()));
+/// }
+///
+/// assert_eq!(rx.len(), 100);
+/// ```
+#[rune::function]
+fn unbounded() -> (Sender, Receiver) {
+    channel(None)
+}
+
+fn channel(capacity: Option<usize>) -> (Sender, Receiver) {
+    let shared = Rc::new(Shared {
+        queue: RefCell::new(VecDeque::new()),
+        capacity,
+        senders: Cell::new(1),
+        receiver: Cell::new(true),
+        recv_waker: RefCell::new(None),
+        send_wakers: RefCell::new(Vec::new()),
+    });
+
+    let sender = Sender {
+        shared: shared.clone(),
+    };
+
+    (sender, Receiver { shared })
+}
+
+/// The sending half of a channel.
+///
+/// Senders can be cloned to send values from many tasks. Once every sender has
+/// been dropped, the receiver is notified that no more values will arrive.
+#[derive(Any)]
+#[rune(item = ::std::channel)]
+pub struct Sender {
+    shared: Rc<Shared>,
+}
+
+impl Sender {
+    /// Send a value over the channel, waiting for room if the channel is
+    /// bounded and full.
+    ///
+    /// If the receiver has been dropped the value is handed back as an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::channel;
+    ///
+    /// let (tx, rx) = channel::bounded(4);
+    /// tx.send(42).await?;
+    /// assert_eq!(rx.recv().await, Some(42));
+    /// ```
+    #[rune::function(keep, instance, path = Self::send)]
+    async fn send(this: Ref<Self>, value: Value) -> VmResult<Result<(), Value>> {
+        let mut value = Some(value);
+        poll_fn(|cx| this.shared.poll_send(cx, &mut value)).await
+    }
+
+    /// Try to send a value over the channel without waiting.
+    ///
+    /// If the channel is full or the receiver has been dropped the value is
+    /// handed back as an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::channel;
+    ///
+    /// let (tx, rx) = channel::bounded(1);
+    ///
+    /// assert_eq!(tx.try_send(1), Ok(()));
+    /// assert_eq!(tx.try_send(2), Err(2));
+    /// ```
+    #[rune::function(keep)]
+    fn try_send(&self, value: Value) -> VmResult<Result<(), Value>> {
+        if !self.shared.receiver.get() || self.shared.is_full() {
+            return VmResult::Ok(Err(value));
+        }
+
+        vm_try!(self.shared.push(value));
+        VmResult::Ok(Ok(()))
+    }
+
+    /// Test if the receiving half of the channel has been dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::channel;
+    ///
+    /// let (tx, rx) = channel::unbounded();
+    /// assert!(!tx.is_closed());
+    /// drop(rx);
+    /// assert!(tx.is_closed());
+    /// ```
+    #[rune::function(keep)]
+    fn is_closed(&self) -> bool {
+        !self.shared.receiver.get()
+    }
+
+    /// Clone the sender, allowing another task to send values over the same
+    /// channel.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::channel;
+    ///
+    /// let (tx, rx) = channel::unbounded();
+    /// let tx2 = tx.clone();
+    ///
+    /// tx.try_send(1)?;
+    /// tx2.try_send(2)?;
+    ///
+    /// assert_eq!(rx.try_recv(), Some(1));
+    /// assert_eq!(rx.try_recv(), Some(2));
+    /// ```
+    #[rune::function(keep, protocol = CLONE)]
+    fn clone(&self) -> Self {
+        self.shared.senders.set(self.shared.senders.get() + 1);
+
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl Drop for Sender {
+    fn drop(&mut self) {
+        let senders = self.shared.senders.get() - 1;
+        self.shared.senders.set(senders);
+
+        if senders == 0 {
+            self.shared.wake_receiver();
+        }
+    }
+}
+
+/// The receiving half of a channel.
+#[derive(Any)]
+#[rune(item = ::std::channel)]
+pub struct Receiver {
+    shared: Rc<Shared>,
+}
+
+impl Receiver {
+    /// Receive the next value from the channel, waiting until one is
+    /// available.
+    ///
+    /// Returns `None` once every sender has been dropped and the channel is
+    /// empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::channel;
+    ///
+    /// let (tx, rx) = channel::unbounded();
+    /// tx.send(1).await?;
+    /// drop(tx);
+    ///
+    /// assert_eq!(rx.recv().await, Some(1));
+    /// assert_eq!(rx.recv().await, None);
+    /// ```
+    #[rune::function(keep, instance, path = Self::recv)]
+    async fn recv(this: Ref<Self>) -> VmResult<Option<Value>> {
+        poll_fn(|cx| this.shared.poll_recv(cx)).await
+    }
+
+    /// Try to receive the next value from the channel without waiting.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::channel;
+    ///
+    /// let (tx, rx) = channel::unbounded();
+    /// assert_eq!(rx.try_recv(), None);
+    /// tx.try_send(1)?;
+    /// assert_eq!(rx.try_recv(), Some(1));
+    /// ```
+    #[rune::function(keep)]
+    fn try_recv(&self) -> Option<Value> {
+        self.shared.pop()
+    }
+
+    /// Get the number of values waiting in the channel.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::channel;
+    ///
+    /// let (tx, rx) = channel::unbounded();
+    /// tx.try_send(1)?;
+    /// assert_eq!(rx.len(), 1);
+    /// ```
+    #[rune::function(keep)]
+    fn len(&self) -> usize {
+        self.shared.queue.borrow().len()
+    }
+
+    /// Test if there are no values waiting in the channel.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::channel;
+    ///
+    /// let (tx, rx) = channel::unbounded();
+    /// assert!(rx.is_empty());
+    /// tx.try_send(1)?;
+    /// assert!(!rx.is_empty());
+    /// ```
+    #[rune::function(keep)]
+    fn is_empty(&self) -> bool {
+        self.shared.queue.borrow().is_empty()
+    }
+}
+
+impl Drop for Receiver {
+    fn drop(&mut self) {
+        self.shared.receiver.set(false);
+        self.shared.queue.borrow_mut().clear();
+        self.shared.wake_senders();
+    }
+}
+
+/// State shared between the halves of a channel.
+struct Shared {
+    queue: RefCell<VecDeque<Value>>,
+    capacity: Option<usize>,
+    /// The number of live senders.
+    senders: Cell<usize>,
+    /// Whether the receiver is still alive.
+    receiver: Cell<bool>,
+    recv_waker: RefCell<Option<Waker>>,
+    send_wakers: RefCell<Vec<Waker>>,
+}
+
+impl Shared {
+    fn is_full(&self) -> bool {
+        self.capacity
+            .is_some_and(|capacity| self.queue.borrow().len() >= capacity)
+    }
+
+    fn push(&self, value: Value) -> alloc::Result<()> {
+        self.queue.borrow_mut().try_push_back(value)?;
+        self.wake_receiver();
+        Ok(())
+    }
+
+    fn pop(&self) -> Option<Value> {
+        let value = self.queue.borrow_mut().pop_front()?;
+        self.wake_senders();
+        Some(value)
+    }
+
+    fn poll_send(
+        &self,
+        cx: &mut Context<'_>,
+        value: &mut Option<Value>,
+    ) -> Poll<VmResult<Result<(), Value>>> {
+        let Some(v) = value.take() else {
+            return Poll::Ready(VmResult::panic("Value has already been sent"));
+        };
+
+        if !self.receiver.get() {
+            return Poll::Ready(VmResult::Ok(Err(v)));
+        }
+
+        if !self.is_full() {
+            if let Err(error) = self.push(v) {
+                return Poll::Ready(VmResult::err(error));
+            }
+
+            return Poll::Ready(VmResult::Ok(Ok(())));
+        }
+
+        *value = Some(v);
+
+        if let Err(error) = self.send_wakers.borrow_mut().try_push(cx.waker().clone()) {
+            return Poll::Ready(VmResult::err(error));
+        }
+
+        Poll::Pending
+    }
+
+    fn poll_recv(&self, cx: &mut Context<'_>) -> Poll<VmResult<Option<Value>>> {
+        if let Some(value) = self.pop() {
+            return Poll::Ready(VmResult::Ok(Some(value)));
+        }
+
+        if self.senders.get() == 0 {
+            return Poll::Ready(VmResult::Ok(None));
+        }
+
+        *self.recv_waker.borrow_mut() = Some(cx.waker().clone());
+        Poll::Pending
+    }
+
+    fn wake_receiver(&self) {
+        if let Some(waker) = self.recv_waker.borrow_mut().take() {
+            waker.wake();
+        }
+    }
+
+    fn wake_senders(&self) {
+        // NB: Every waiting sender is woken up, since the first one to run
+        // might have been dropped without sending its value.
+        let wakers = core::mem::take(&mut *self.send_wakers.borrow_mut());
+
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
@@ -0,0 +1,492 @@
+use core::cmp::Ordering;
+use core::iter;
+use core::slice;
+
+use crate as rune;
+use crate::alloc;
+use crate::alloc::fmt::TryWrite;
+use crate::alloc::prelude::*;
+use crate::runtime::{
+    EnvProtocolCaller, Formatter, Iterator, ProtocolCaller, RawAnyGuard, Ref, Value, Vec, VmResult,
+};
+use crate::{Any, ContextError, Module};
+
+/// A priority queue implemented with a binary heap.
+#[rune::module(::std::collections::binary_heap)]
+pub fn module() -> Result<Module, ContextError> {
+    let mut m = Module::from_meta(self::module_meta)?;
+
+    m.ty::<BinaryHeap>()?;
+    m.function_meta(BinaryHeap::new__meta)?;
+    m.function_meta(BinaryHeap::with_capacity__meta)?;
+    m.function_meta(BinaryHeap::len__meta)?;
+    m.function_meta(BinaryHeap::is_empty__meta)?;
+    m.function_meta(BinaryHeap::push__meta)?;
+    m.function_meta(BinaryHeap::pop__meta)?;
+    m.function_meta(BinaryHeap::peek__meta)?;
+    m.function_meta(BinaryHeap::clear__meta)?;
+    m.function_meta(BinaryHeap::into_sorted_vec__meta)?;
+    m.function_meta(BinaryHeap::iter__meta)?;
+    m.function_meta(BinaryHeap::into_iter__meta)?;
+    m.function_meta(BinaryHeap::from_iter__meta)?;
+    m.function_meta(BinaryHeap::extend__meta)?;
+    m.function_meta(BinaryHeap::debug_fmt__meta)?;
+
+    m.function_meta(BinaryHeap::clone__meta)?;
+    m.implement_trait::<BinaryHeap>(rune::item!(::std::clone::Clone))?;
+
+    m.ty::<Iter>()?;
+    m.function_meta(Iter::next__meta)?;
+    m.function_meta(Iter::size_hint__meta)?;
+    m.implement_trait::<Iter>(rune::item!(::std::iter::Iterator))?;
+
+    m.function_meta(Iter::len__meta)?;
+    m.implement_trait::<Iter>(rune::item!(::std::iter::ExactSizeIterator))?;
+
+    Ok(m)
+}
+
+/// A priority queue implemented with a binary heap.
+///
+/// This will be a max-heap, elements are ordered through the [`CMP`]
+/// protocol.
+///
+/// It is a logic error for an element to be modified in such a way that its
+/// ordering relative to any other element, as determined by the [`CMP`]
+/// protocol, changes while it is in the heap.
+///
+/// # Examples
+///
+/// ```rune
+/// use std::collections::BinaryHeap;
+///
+/// let heap = BinaryHeap::new();
+///
+/// heap.push(1);
+/// heap.push(5);
+/// heap.push(2);
+///
+/// assert_eq!(heap.peek(), Some(5));
+/// assert_eq!(heap.len(), 3);
+///
+/// assert_eq!(heap.pop(), Some(5));
+/// assert_eq!(heap.pop(), Some(2));
+/// assert_eq!(heap.pop(), Some(1));
+/// assert_eq!(heap.pop(), None);
+/// ```
+#[derive(Any)]
+#[rune(item = ::std::collections::binary_heap)]
+pub(crate) struct BinaryHeap {
+    data: alloc::Vec<Value>,
+}
+
+impl BinaryHeap {
+    /// Creates an empty `BinaryHeap` as a max-heap.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BinaryHeap;
+    ///
+    /// let heap = BinaryHeap::new();
+    /// heap.push(4);
+    /// assert_eq!(heap.len(), 1);
+    /// ```
+    #[rune::function(keep, path = Self::new)]
+    fn new() -> Self {
+        Self {
+            data: alloc::Vec::new(),
+        }
+    }
+
+    /// Creates an empty `BinaryHeap` with at least the specified capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BinaryHeap;
+    ///
+    /// let heap = BinaryHeap::with_capacity(10);
+    /// heap.push(4);
+    /// assert_eq!(heap.len(), 1);
+    /// ```
+    #[rune::function(keep, path = Self::with_capacity)]
+    fn with_capacity(capacity: usize) -> VmResult<Self> {
+        VmResult::Ok(Self {
+            data: vm_try!(alloc::Vec::try_with_capacity(capacity)),
+        })
+    }
+
+    /// Returns the length of the binary heap.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BinaryHeap;
+    ///
+    /// let heap = BinaryHeap::from_iter([1, 3]);
+    /// assert_eq!(heap.len(), 2);
+    /// ```
+    #[rune::function(keep)]
+    fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Checks if the binary heap is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BinaryHeap;
+    ///
+    /// let heap = BinaryHeap::new();
+    /// assert!(heap.is_empty());
+    /// heap.push(1);
+    /// assert!(!heap.is_empty());
+    /// ```
+    #[rune::function(keep)]
+    fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Pushes an item onto the binary heap.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BinaryHeap;
+    ///
+    /// let heap = BinaryHeap::new();
+    /// heap.push(3);
+    /// heap.push(5);
+    /// heap.push(1);
+    ///
+    /// assert_eq!(heap.len(), 3);
+    /// assert_eq!(heap.peek(), Some(5));
+    /// ```
+    #[rune::function(keep)]
+    fn push(&mut self, item: Value) -> VmResult<()> {
+        self.push_with(item, &mut EnvProtocolCaller)
+    }
+
+    fn push_with(&mut self, item: Value, caller: &mut dyn ProtocolCaller) -> VmResult<()> {
+        vm_try!(self.data.try_push(item));
+        self.sift_up(self.data.len() - 1, caller)
+    }
+
+    /// Removes the greatest item from the binary heap and returns it, or
+    /// `None` if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BinaryHeap;
+    ///
+    /// let heap = BinaryHeap::from_iter([1, 3]);
+    ///
+    /// assert_eq!(heap.pop(), Some(3));
+    /// assert_eq!(heap.pop(), Some(1));
+    /// assert_eq!(heap.pop(), None);
+    /// ```
+    #[rune::function(keep)]
+    fn pop(&mut self) -> VmResult<Option<Value>> {
+        self.pop_with(&mut EnvProtocolCaller)
+    }
+
+    fn pop_with(&mut self, caller: &mut dyn ProtocolCaller) -> VmResult<Option<Value>> {
+        let Some(mut item) = self.data.pop() else {
+            return VmResult::Ok(None);
+        };
+
+        if let Some(first) = self.data.first_mut() {
+            core::mem::swap(&mut item, first);
+            vm_try!(self.sift_down(0, caller));
+        }
+
+        VmResult::Ok(Some(item))
+    }
+
+    /// Returns the greatest item in the binary heap, or `None` if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BinaryHeap;
+    ///
+    /// let heap = BinaryHeap::new();
+    /// assert_eq!(heap.peek(), None);
+    ///
+    /// heap.push(1);
+    /// heap.push(5);
+    /// heap.push(2);
+    /// assert_eq!(heap.peek(), Some(5));
+    /// ```
+    #[rune::function(keep)]
+    fn peek(&self) -> Option<Value> {
+        self.data.first().cloned()
+    }
+
+    /// Drops all items from the binary heap.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BinaryHeap;
+    ///
+    /// let heap = BinaryHeap::from_iter([1, 3]);
+    /// heap.clear();
+    /// assert!(heap.is_empty());
+    /// ```
+    #[rune::function(keep)]
+    fn clear(&mut self) {
+        self.data.clear();
+    }
+
+    /// Consumes the heap and returns a vector with its contents in ascending
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BinaryHeap;
+    ///
+    /// let heap = BinaryHeap::from_iter([1, 2, 4, 5, 7]);
+    /// heap.push(6);
+    /// heap.push(3);
+    ///
+    /// assert_eq!(heap.into_sorted_vec(), [1, 2, 3, 4, 5, 6, 7]);
+    /// ```
+    #[rune::function(keep)]
+    fn into_sorted_vec(mut self) -> VmResult<Vec> {
+        let mut caller = EnvProtocolCaller;
+        let mut out = vm_try!(alloc::Vec::try_with_capacity(self.data.len()));
+
+        while let Some(value) = vm_try!(self.pop_with(&mut caller)) {
+            vm_try!(out.try_push(value));
+        }
+
+        out.reverse();
+        VmResult::Ok(Vec::from(out))
+    }
+
+    /// Returns an iterator visiting all values in the underlying vector, in
+    /// arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BinaryHeap;
+    ///
+    /// let heap = BinaryHeap::from_iter([1, 2, 3, 4]);
+    ///
+    /// let values = heap.iter().collect::<Vec>();
+    /// values.sort();
+    /// assert_eq!(values, [1, 2, 3, 4]);
+    /// ```
+    #[rune::function(keep, instance, path = Self::iter)]
+    fn iter(this: Ref<Self>) -> Iter {
+        let (this, guard) = Ref::into_raw(this);
+        // SAFETY: We're holding onto the reference guard.
+        let iter = unsafe { this.as_ref().data.iter() };
+        Iter { iter, guard }
+    }
+
+    #[rune::function(keep, instance, protocol = INTO_ITER, path = Self)]
+    fn into_iter(this: Ref<Self>) -> Iter {
+        Self::iter(this)
+    }
+
+    /// Build a binary heap from an iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BinaryHeap;
+    ///
+    /// let heap = BinaryHeap::from_iter([3, 1, 4, 1, 5]);
+    /// assert_eq!(heap.len(), 5);
+    /// assert_eq!(heap.peek(), Some(5));
+    /// ```
+    #[rune::function(keep, path = Self::from_iter)]
+    fn from_iter(mut it: Iterator) -> VmResult<Self> {
+        let mut caller = EnvProtocolCaller;
+
+        let (lo, _) = vm_try!(it.size_hint());
+        let mut heap = vm_try!(Self::with_capacity(lo));
+
+        while let Some(value) = vm_try!(it.next()) {
+            vm_try!(heap.push_with(value, &mut caller));
+        }
+
+        VmResult::Ok(heap)
+    }
+
+    /// Extend the binary heap with the values of an iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BinaryHeap;
+    ///
+    /// let heap = BinaryHeap::from_iter([1]);
+    /// heap.extend([3, 2]);
+    /// assert_eq!(heap.pop(), Some(3));
+    /// assert_eq!(heap.len(), 2);
+    /// ```
+    #[rune::function(keep)]
+    fn extend(&mut self, value: Value) -> VmResult<()> {
+        let mut caller = EnvProtocolCaller;
+        let mut it = vm_try!(value.into_iter());
+
+        while let Some(value) = vm_try!(it.next()) {
+            vm_try!(self.push_with(value, &mut caller));
+        }
+
+        VmResult::Ok(())
+    }
+
+    /// Clone the binary heap.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BinaryHeap;
+    ///
+    /// let a = BinaryHeap::from_iter([1]);
+    /// let b = a.clone();
+    /// b.push(2);
+    ///
+    /// assert_eq!(a.len(), 1);
+    /// assert_eq!(b.len(), 2);
+    /// ```
+    #[rune::function(keep, instance, path = Self::clone, protocol = CLONE)]
+    fn clone(this: &BinaryHeap) -> VmResult<BinaryHeap> {
+        VmResult::Ok(Self {
+            data: vm_try!(this.data.try_clone()),
+        })
+    }
+
+    /// Debug format the binary heap.
+    ///
+    /// The elements are printed in the order they are stored in the underlying
+    /// vector.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BinaryHeap;
+    ///
+    /// let heap = BinaryHeap::from_iter([1]);
+    /// assert_eq!(format!("{:?}", heap), "[1]");
+    /// ```
+    #[rune::function(keep, protocol = DEBUG_FMT)]
+    fn debug_fmt(&self, f: &mut Formatter) -> VmResult<()> {
+        let mut caller = EnvProtocolCaller;
+        vm_try!(vm_write!(f, "["));
+
+        let mut it = self.data.iter().peekable();
+
+        while let Some(value) = it.next() {
+            vm_try!(value.debug_fmt_with(f, &mut caller));
+
+            if it.peek().is_some() {
+                vm_try!(vm_write!(f, ", "));
+            }
+        }
+
+        vm_try!(vm_write!(f, "]"));
+        VmResult::Ok(())
+    }
+
+    /// Move the element at `pos` up towards the root until its parent is
+    /// greater or equal to it.
+    fn sift_up(&mut self, mut pos: usize, caller: &mut dyn ProtocolCaller) -> VmResult<()> {
+        while pos > 0 {
+            let parent = (pos - 1) / 2;
+
+            if vm_try!(Value::cmp_with(&self.data[pos], &self.data[parent], caller))
+                != Ordering::Greater
+            {
+                break;
+            }
+
+            self.data.swap(pos, parent);
+            pos = parent;
+        }
+
+        VmResult::Ok(())
+    }
+
+    /// Move the element at `pos` down towards the leaves until both of its
+    /// children are less or equal to it.
+    fn sift_down(&mut self, mut pos: usize, caller: &mut dyn ProtocolCaller) -> VmResult<()> {
+        let len = self.data.len();
+
+        loop {
+            let mut child = 2 * pos + 1;
+
+            if child >= len {
+                break;
+            }
+
+            let right = child + 1;
+
+            if right < len
+                && vm_try!(Value::cmp_with(
+                    &self.data[right],
+                    &self.data[child],
+                    caller
+                )) == Ordering::Greater
+            {
+                child = right;
+            }
+
+            if vm_try!(Value::cmp_with(&self.data[child], &self.data[pos], caller))
+                != Ordering::Greater
+            {
+                break;
+            }
+
+            self.data.swap(pos, child);
+            pos = child;
+        }
+
+        VmResult::Ok(())
+    }
+}
+
+/// An iterator over the elements of a `BinaryHeap`.
+#[derive(Any)]
+#[rune(item = ::std::collections::binary_heap)]
+pub(crate) struct Iter {
+    iter: slice::Iter<'static, Value>,
+    // Drop must happen after the iterator.
+    #[allow(unused)]
+    guard: RawAnyGuard,
+}
+
+impl Iter {
+    #[rune::function(keep, protocol = NEXT)]
+    fn next(&mut self) -> Option<Value> {
+        self.iter.next().cloned()
+    }
+
+    #[rune::function(keep, protocol = SIZE_HINT)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[rune::function(keep, protocol = LEN)]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl iter::Iterator for Iter {
+    type Item = Value;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        Iter::next(self)
+    }
+}
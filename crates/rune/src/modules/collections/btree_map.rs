@@ -0,0 +1,818 @@
+use core::cmp::Ordering;
+use core::ops::Bound;
+
+use crate as rune;
+use crate::alloc::btree_map::{self, Entry};
+use crate::alloc::fmt::TryWrite;
+use crate::alloc::prelude::*;
+use crate::runtime::{
+    EnvProtocolCaller, Formatter, FromValue, Iterator, ProtocolCaller, RawAnyGuard, Ref, Value,
+    VmError, VmErrorKind, VmResult,
+};
+use crate::runtime::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+use crate::{Any, ContextError, Module, TypeHash};
+
+/// An ordered map based on a B-Tree.
+#[rune::module(::std::collections::btree_map)]
+pub fn module() -> Result<Module, ContextError> {
+    let mut m = Module::from_meta(self::module_meta)?;
+
+    m.ty::<BTreeMap>()?;
+    m.function_meta(BTreeMap::new__meta)?;
+    m.function_meta(BTreeMap::len__meta)?;
+    m.function_meta(BTreeMap::is_empty__meta)?;
+    m.function_meta(BTreeMap::insert__meta)?;
+    m.function_meta(BTreeMap::get__meta)?;
+    m.function_meta(BTreeMap::contains_key__meta)?;
+    m.function_meta(BTreeMap::remove__meta)?;
+    m.function_meta(BTreeMap::clear__meta)?;
+    m.function_meta(BTreeMap::first_key_value__meta)?;
+    m.function_meta(BTreeMap::last_key_value__meta)?;
+    m.function_meta(BTreeMap::pop_first__meta)?;
+    m.function_meta(BTreeMap::pop_last__meta)?;
+    m.function_meta(BTreeMap::range__meta)?;
+    m.function_meta(BTreeMap::iter__meta)?;
+    m.function_meta(BTreeMap::into_iter__meta)?;
+    m.function_meta(BTreeMap::from_iter__meta)?;
+    m.function_meta(BTreeMap::keys__meta)?;
+    m.function_meta(BTreeMap::values__meta)?;
+    m.function_meta(BTreeMap::extend__meta)?;
+    m.function_meta(BTreeMap::index_set__meta)?;
+    m.function_meta(BTreeMap::index_get__meta)?;
+    m.function_meta(BTreeMap::debug_fmt__meta)?;
+
+    m.function_meta(BTreeMap::clone__meta)?;
+    m.implement_trait::<BTreeMap>(rune::item!(::std::clone::Clone))?;
+
+    m.function_meta(BTreeMap::partial_eq__meta)?;
+    m.implement_trait::<BTreeMap>(rune::item!(::std::cmp::PartialEq))?;
+
+    m.function_meta(BTreeMap::eq__meta)?;
+    m.implement_trait::<BTreeMap>(rune::item!(::std::cmp::Eq))?;
+
+    m.ty::<Iter>()?;
+    m.function_meta(Iter::next)?;
+    m.function_meta(Iter::next_back)?;
+    m.implement_trait::<Iter>(rune::item!(::std::iter::Iterator))?;
+    m.implement_trait::<Iter>(rune::item!(::std::iter::DoubleEndedIterator))?;
+
+    m.ty::<Keys>()?;
+    m.function_meta(Keys::next)?;
+    m.function_meta(Keys::next_back)?;
+    m.implement_trait::<Keys>(rune::item!(::std::iter::Iterator))?;
+    m.implement_trait::<Keys>(rune::item!(::std::iter::DoubleEndedIterator))?;
+
+    m.ty::<Values>()?;
+    m.function_meta(Values::next)?;
+    m.function_meta(Values::next_back)?;
+    m.implement_trait::<Values>(rune::item!(::std::iter::Iterator))?;
+    m.implement_trait::<Values>(rune::item!(::std::iter::DoubleEndedIterator))?;
+
+    Ok(m)
+}
+
+/// An ordered map based on a [B-Tree].
+///
+/// Keys are ordered through the [`CMP`] protocol, so iterating over the map
+/// always produces its entries sorted by key. This makes the output of
+/// programs which use it deterministic.
+///
+/// It is a logic error for a key to be modified in such a way that its ordering
+/// relative to any other key, as determined by the [`CMP`] protocol, changes
+/// while it is in the map.
+///
+/// [B-Tree]: https://en.wikipedia.org/wiki/B-tree
+///
+/// # Examples
+///
+/// ```rune
+/// use std::collections::BTreeMap;
+///
+/// let m = BTreeMap::new();
+///
+/// m.insert("c", 3);
+/// m.insert("a", 1);
+/// m["b"] = 2;
+///
+/// assert_eq!(m.get("a"), Some(1));
+/// assert_eq!(m["b"], 2);
+/// assert_eq!(m.keys().collect::<Vec>(), ["a", "b", "c"]);
+/// ```
+#[derive(Any)]
+#[rune(item = ::std::collections::btree_map)]
+pub(crate) struct BTreeMap {
+    map: btree_map::BTreeMap<Value, Value>,
+}
+
+impl BTreeMap {
+    /// Makes a new, empty `BTreeMap`.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::new();
+    /// assert!(map.is_empty());
+    /// ```
+    #[rune::function(keep, path = Self::new)]
+    fn new() -> Self {
+        Self {
+            map: btree_map::BTreeMap::new(),
+        }
+    }
+
+    /// Returns the number of elements in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::new();
+    /// assert_eq!(map.len(), 0);
+    /// map.insert(1, "a");
+    /// assert_eq!(map.len(), 1);
+    /// ```
+    #[rune::function(keep)]
+    fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the map contains no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::new();
+    /// assert!(map.is_empty());
+    /// map.insert(1, "a");
+    /// assert!(!map.is_empty());
+    /// ```
+    #[rune::function(keep)]
+    fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Inserts a key-value pair into the map.
+    ///
+    /// If the map did not have this key present, `None` is returned. If the
+    /// map did have this key present, the value is updated and the old value
+    /// is returned. The key is not updated.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::new();
+    /// assert_eq!(map.insert(37, "a"), None);
+    /// assert_eq!(map.insert(37, "b"), Some("a"));
+    /// assert_eq!(map[37], "b");
+    /// ```
+    #[rune::function(keep)]
+    pub(crate) fn insert(&mut self, key: Value, value: Value) -> VmResult<Option<Value>> {
+        let caller: &mut dyn ProtocolCaller = &mut EnvProtocolCaller;
+        self.insert_with(key, value, caller)
+    }
+
+    pub(crate) fn insert_with(
+        &mut self,
+        key: Value,
+        value: Value,
+        caller: &mut dyn ProtocolCaller,
+    ) -> VmResult<Option<Value>> {
+        match vm_try!(self.map.entry_with(caller, key, cmp)) {
+            Entry::Occupied(mut entry) => VmResult::Ok(Some(entry.insert(value))),
+            Entry::Vacant(entry) => {
+                vm_try!(entry.try_insert(value));
+                VmResult::Ok(None)
+            }
+        }
+    }
+
+    /// Returns the value corresponding to the key.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::new();
+    /// map.insert(1, "a");
+    /// assert_eq!(map.get(1), Some("a"));
+    /// assert_eq!(map.get(2), None);
+    /// ```
+    #[rune::function(keep)]
+    fn get(&self, key: Value) -> VmResult<Option<Value>> {
+        let caller: &mut dyn ProtocolCaller = &mut EnvProtocolCaller;
+        let value = vm_try!(self.map.get_with(caller, &key, cmp));
+        VmResult::Ok(value.cloned())
+    }
+
+    /// Returns `true` if the map contains a value for the specified key.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::new();
+    /// map.insert(1, "a");
+    /// assert!(map.contains_key(1));
+    /// assert!(!map.contains_key(2));
+    /// ```
+    #[rune::function(keep)]
+    fn contains_key(&self, key: Value) -> VmResult<bool> {
+        let caller: &mut dyn ProtocolCaller = &mut EnvProtocolCaller;
+        VmResult::Ok(vm_try!(self.map.contains_key_with(caller, &key, cmp)))
+    }
+
+    /// Removes a key from the map, returning the value at the key if the key
+    /// was previously in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::new();
+    /// map.insert(1, "a");
+    /// assert_eq!(map.remove(1), Some("a"));
+    /// assert_eq!(map.remove(1), None);
+    /// ```
+    #[rune::function(keep)]
+    fn remove(&mut self, key: Value) -> VmResult<Option<Value>> {
+        let caller: &mut dyn ProtocolCaller = &mut EnvProtocolCaller;
+        let entry = vm_try!(self.map.remove_entry_with(caller, &key, cmp));
+        VmResult::Ok(entry.map(|(_, value)| value))
+    }
+
+    /// Clears the map, removing all elements.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeMap;
+    ///
+    /// let a = BTreeMap::new();
+    /// a.insert(1, "a");
+    /// a.clear();
+    /// assert!(a.is_empty());
+    /// ```
+    #[rune::function(keep)]
+    fn clear(&mut self) {
+        self.map.clear()
+    }
+
+    /// Returns the first key-value pair in the map. The key in this pair is
+    /// the minimum key in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::new();
+    /// assert_eq!(map.first_key_value(), None);
+    /// map.insert(2, "b");
+    /// map.insert(1, "a");
+    /// assert_eq!(map.first_key_value(), Some((1, "a")));
+    /// ```
+    #[rune::function(keep)]
+    fn first_key_value(&self) -> Option<(Value, Value)> {
+        let (key, value) = self.map.first_key_value()?;
+        Some((key.clone(), value.clone()))
+    }
+
+    /// Returns the last key-value pair in the map. The key in this pair is the
+    /// maximum key in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::new();
+    /// map.insert(1, "a");
+    /// map.insert(2, "b");
+    /// assert_eq!(map.last_key_value(), Some((2, "b")));
+    /// ```
+    #[rune::function(keep)]
+    fn last_key_value(&self) -> Option<(Value, Value)> {
+        let (key, value) = self.map.last_key_value()?;
+        Some((key.clone(), value.clone()))
+    }
+
+    /// Removes and returns the first element in the map. The key of this
+    /// element is the minimum key that was in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::from_iter([(2, "b"), (1, "a")]);
+    /// assert_eq!(map.pop_first(), Some((1, "a")));
+    /// assert_eq!(map.pop_first(), Some((2, "b")));
+    /// assert_eq!(map.pop_first(), None);
+    /// ```
+    #[rune::function(keep)]
+    fn pop_first(&mut self) -> Option<(Value, Value)> {
+        self.map.pop_first()
+    }
+
+    /// Removes and returns the last element in the map. The key of this
+    /// element is the maximum key that was in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::from_iter([(2, "b"), (1, "a")]);
+    /// assert_eq!(map.pop_last(), Some((2, "b")));
+    /// assert_eq!(map.pop_last(), Some((1, "a")));
+    /// assert_eq!(map.pop_last(), None);
+    /// ```
+    #[rune::function(keep)]
+    fn pop_last(&mut self) -> Option<(Value, Value)> {
+        self.map.pop_last()
+    }
+
+    /// Constructs a double-ended iterator over a sub-range of elements in the
+    /// map.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than its end, or if the
+    /// start and end are equal and both excluded.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::from_iter([(3, "a"), (5, "b"), (8, "c")]);
+    ///
+    /// assert_eq!(map.range(4..).collect::<Vec>(), [(5, "b"), (8, "c")]);
+    /// assert_eq!(map.range(..=5).collect::<Vec>(), [(3, "a"), (5, "b")]);
+    /// assert_eq!(map.range(4..8).next_back(), Some((5, "b")));
+    /// ```
+    #[rune::function(keep, instance, path = Self::range)]
+    fn range(this: Ref<Self>, range: Value) -> VmResult<Iter> {
+        let caller: &mut dyn ProtocolCaller = &mut EnvProtocolCaller;
+        let bounds = vm_try!(range_bounds(&range, caller));
+        let (iter, guard) = vm_try!(Self::range_iter(this, bounds, caller));
+        VmResult::Ok(Iter { iter, guard })
+    }
+
+    fn range_iter(
+        this: Ref<Self>,
+        bounds: (Bound<Value>, Bound<Value>),
+        caller: &mut dyn ProtocolCaller,
+    ) -> VmResult<(btree_map::Range<'static, Value, Value>, RawAnyGuard)> {
+        let (this, guard) = Ref::into_raw(this);
+
+        // SAFETY: We're holding onto the reference guard, which is returned
+        // alongside the iterator.
+        let map = unsafe { &this.as_ref().map };
+        let iter = vm_try!(map.range_with(caller, bounds, cmp));
+        VmResult::Ok((iter, guard))
+    }
+
+    /// Gets an iterator over the entries of the map, sorted by key.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::new();
+    /// map.insert(3, "c");
+    /// map.insert(1, "a");
+    /// map.insert(2, "b");
+    ///
+    /// assert_eq!(map.iter().collect::<Vec>(), [(1, "a"), (2, "b"), (3, "c")]);
+    /// ```
+    #[rune::function(keep, instance, path = Self::iter)]
+    fn iter(this: Ref<Self>) -> VmResult<Iter> {
+        let bounds = (Bound::Unbounded, Bound::Unbounded);
+        let (iter, guard) = vm_try!(Self::range_iter(this, bounds, &mut EnvProtocolCaller));
+        VmResult::Ok(Iter { iter, guard })
+    }
+
+    /// Gets an iterator over the keys of the map, in sorted order.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::from_iter([(2, "b"), (1, "a")]);
+    /// assert_eq!(map.keys().collect::<Vec>(), [1, 2]);
+    /// ```
+    #[rune::function(keep, instance, path = Self::keys)]
+    fn keys(this: Ref<Self>) -> VmResult<Keys> {
+        let bounds = (Bound::Unbounded, Bound::Unbounded);
+        let (iter, guard) = vm_try!(Self::range_iter(this, bounds, &mut EnvProtocolCaller));
+        VmResult::Ok(Keys { iter, guard })
+    }
+
+    /// Gets an iterator over the values of the map, in order by key.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::from_iter([(2, "b"), (1, "a")]);
+    /// assert_eq!(map.values().collect::<Vec>(), ["a", "b"]);
+    /// ```
+    #[rune::function(keep, instance, path = Self::values)]
+    fn values(this: Ref<Self>) -> VmResult<Values> {
+        let bounds = (Bound::Unbounded, Bound::Unbounded);
+        let (iter, guard) = vm_try!(Self::range_iter(this, bounds, &mut EnvProtocolCaller));
+        VmResult::Ok(Values { iter, guard })
+    }
+
+    /// Extend this map from an iterator of key-value pairs.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::new();
+    /// map.extend([(2, "b"), (1, "a")]);
+    /// assert_eq!(map.iter().collect::<Vec>(), [(1, "a"), (2, "b")]);
+    /// ```
+    #[rune::function(keep)]
+    fn extend(&mut self, value: Value) -> VmResult<()> {
+        let mut it = vm_try!(value.into_iter());
+
+        while let Some(value) = vm_try!(it.next()) {
+            let (key, value) = vm_try!(<(Value, Value)>::from_value(value));
+            vm_try!(self.insert(key, value));
+        }
+
+        VmResult::Ok(())
+    }
+
+    /// Clone the map.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeMap;
+    ///
+    /// let a = BTreeMap::from_iter([(1, "a")]);
+    /// let b = a.clone();
+    /// b.insert(2, "b");
+    ///
+    /// assert_eq!(a.len(), 1);
+    /// assert_eq!(b.len(), 2);
+    /// ```
+    #[rune::function(keep, instance, path = Self::clone, protocol = CLONE)]
+    fn clone(this: &BTreeMap) -> VmResult<BTreeMap> {
+        VmResult::Ok(Self {
+            map: vm_try!(this.map.try_clone()),
+        })
+    }
+
+    /// Convert a map from an iterator of key-value pairs.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::from_iter([("b", 2), ("a", 1)]);
+    /// assert_eq!(map.first_key_value(), Some(("a", 1)));
+    /// ```
+    #[rune::function(keep, path = Self::from_iter)]
+    fn from_iter(mut it: Iterator) -> VmResult<BTreeMap> {
+        let caller: &mut dyn ProtocolCaller = &mut EnvProtocolCaller;
+        let mut map = Self::new();
+
+        while let Some(value) = vm_try!(it.next()) {
+            let (key, value) = vm_try!(<(Value, Value)>::from_value(value));
+            vm_try!(map.insert_with(key, value, caller));
+        }
+
+        VmResult::Ok(map)
+    }
+
+    /// Inserts a key-value pair into the map.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::new();
+    /// map["a"] = 1;
+    /// assert_eq!(map["a"], 1);
+    /// ```
+    #[rune::function(keep, protocol = INDEX_SET)]
+    fn index_set(&mut self, key: Value, value: Value) -> VmResult<()> {
+        let _ = vm_try!(self.insert(key, value));
+        VmResult::Ok(())
+    }
+
+    /// Returns the value corresponding to the key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is not present in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```rune,should_panic
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::new();
+    /// let _ = map[1];
+    /// ```
+    ///
+    /// ```rune
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::new();
+    /// map[1] = "a";
+    /// assert_eq!(map[1], "a");
+    /// ```
+    #[rune::function(keep, protocol = INDEX_GET)]
+    fn index_get(&self, key: Value) -> VmResult<Value> {
+        use crate::runtime::TypeOf;
+
+        let caller: &mut dyn ProtocolCaller = &mut EnvProtocolCaller;
+
+        let Some(value) = vm_try!(self.map.get_with(caller, &key, cmp)) else {
+            return VmResult::err(VmErrorKind::MissingIndexKey {
+                target: Self::type_info(),
+            });
+        };
+
+        VmResult::Ok(value.clone())
+    }
+
+    /// Debug format the map.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::from_iter([(2, "b"), (1, "a")]);
+    /// assert_eq!(format!("{:?}", map), "{1: \"a\", 2: \"b\"}");
+    /// ```
+    #[rune::function(keep, protocol = DEBUG_FMT)]
+    fn debug_fmt(&self, f: &mut Formatter) -> VmResult<()> {
+        self.debug_fmt_with(f, &mut EnvProtocolCaller)
+    }
+
+    pub(crate) fn debug_fmt_with(
+        &self,
+        f: &mut Formatter,
+        caller: &mut dyn ProtocolCaller,
+    ) -> VmResult<()> {
+        vm_try!(vm_write!(f, "{{"));
+
+        let mut it = self.map.iter().peekable();
+
+        while let Some((key, value)) = it.next() {
+            vm_try!(key.debug_fmt_with(f, caller));
+            vm_try!(vm_write!(f, ": "));
+            vm_try!(value.debug_fmt_with(f, caller));
+
+            if it.peek().is_some() {
+                vm_try!(vm_write!(f, ", "));
+            }
+        }
+
+        vm_try!(vm_write!(f, "}}"));
+        VmResult::Ok(())
+    }
+
+    /// Perform a partial equality check over two maps.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeMap;
+    ///
+    /// let a = BTreeMap::from_iter([(1, 1.0), (2, 2.0)]);
+    /// let b = BTreeMap::from_iter([(2, 2.0), (1, 1.0)]);
+    /// let c = BTreeMap::from_iter([(1, 1.0), (2, f64::NAN)]);
+    ///
+    /// assert!(a == b);
+    /// assert!(a != c);
+    /// ```
+    #[rune::function(keep, protocol = PARTIAL_EQ)]
+    fn partial_eq(&self, other: &Self) -> VmResult<bool> {
+        self.eq_by(other, Value::partial_eq_with)
+    }
+
+    /// Perform a total equality check over two maps.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeMap;
+    /// use std::ops::eq;
+    ///
+    /// let a = BTreeMap::from_iter([(1, "a"), (2, "b")]);
+    /// let b = BTreeMap::from_iter([(2, "b"), (1, "a")]);
+    ///
+    /// assert!(eq(a, b));
+    /// ```
+    #[rune::function(keep, protocol = EQ)]
+    fn eq(&self, other: &Self) -> VmResult<bool> {
+        self.eq_by(other, Value::eq_with)
+    }
+
+    fn eq_by(
+        &self,
+        other: &Self,
+        eq: fn(&Value, &Value, &mut dyn ProtocolCaller) -> VmResult<bool>,
+    ) -> VmResult<bool> {
+        let caller: &mut dyn ProtocolCaller = &mut EnvProtocolCaller;
+
+        if self.map.len() != other.map.len() {
+            return VmResult::Ok(false);
+        }
+
+        // NB: Both maps are ordered, so entries can be compared pairwise.
+        for ((k1, v1), (k2, v2)) in self.map.iter().zip(other.map.iter()) {
+            if vm_try!(Value::cmp_with(k1, k2, caller)) != Ordering::Equal {
+                return VmResult::Ok(false);
+            }
+
+            if !vm_try!(eq(v1, v2, caller)) {
+                return VmResult::Ok(false);
+            }
+        }
+
+        VmResult::Ok(true)
+    }
+
+    /// An iterator visiting all key-value pairs in order by key.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::from_iter([(2, "b"), (1, "a")]);
+    /// let out = [];
+    ///
+    /// for (key, value) in map {
+    ///     out.push(key);
+    ///     out.push(value);
+    /// }
+    ///
+    /// assert_eq!(out, [1, "a", 2, "b"]);
+    /// ```
+    #[rune::function(keep, instance, protocol = INTO_ITER, path = Self)]
+    fn into_iter(this: Ref<Self>) -> VmResult<Iter> {
+        Self::iter(this)
+    }
+}
+
+/// Compare two keys through the `CMP` protocol.
+pub(super) fn cmp(
+    caller: &mut dyn ProtocolCaller,
+    a: &Value,
+    b: &Value,
+) -> Result<Ordering, VmError> {
+    Value::cmp_with(a, b, caller).into_result()
+}
+
+/// Convert a range value into a pair of bounds, checking that they describe a
+/// valid range.
+pub(super) fn range_bounds(
+    range: &Value,
+    caller: &mut dyn ProtocolCaller,
+) -> VmResult<(Bound<Value>, Bound<Value>)> {
+    let bounds = match range.type_hash() {
+        Range::HASH => {
+            let range = vm_try!(range.borrow_ref::<Range>());
+            (
+                Bound::Included(range.start.clone()),
+                Bound::Excluded(range.end.clone()),
+            )
+        }
+        RangeInclusive::HASH => {
+            let range = vm_try!(range.borrow_ref::<RangeInclusive>());
+            (
+                Bound::Included(range.start.clone()),
+                Bound::Included(range.end.clone()),
+            )
+        }
+        RangeFrom::HASH => {
+            let range = vm_try!(range.borrow_ref::<RangeFrom>());
+            (Bound::Included(range.start.clone()), Bound::Unbounded)
+        }
+        RangeTo::HASH => {
+            let range = vm_try!(range.borrow_ref::<RangeTo>());
+            (Bound::Unbounded, Bound::Excluded(range.end.clone()))
+        }
+        RangeToInclusive::HASH => {
+            let range = vm_try!(range.borrow_ref::<RangeToInclusive>());
+            (Bound::Unbounded, Bound::Included(range.end.clone()))
+        }
+        RangeFull::HASH => (Bound::Unbounded, Bound::Unbounded),
+        _ => return VmResult::expected::<Range>(range.type_info()),
+    };
+
+    let (start, end) = match &bounds {
+        (
+            Bound::Included(start) | Bound::Excluded(start),
+            Bound::Included(end) | Bound::Excluded(end),
+        ) => (start, end),
+        _ => return VmResult::Ok(bounds),
+    };
+
+    match vm_try!(Value::cmp_with(start, end, caller)) {
+        Ordering::Greater => VmResult::panic("Range start is greater than range end"),
+        Ordering::Equal if matches!(bounds, (Bound::Excluded(..), Bound::Excluded(..))) => {
+            VmResult::panic("Range start and end are equal and excluded")
+        }
+        _ => VmResult::Ok(bounds),
+    }
+}
+
+/// An iterator over the entries of a `BTreeMap`.
+#[derive(Any)]
+#[rune(item = ::std::collections::btree_map)]
+pub(crate) struct Iter {
+    iter: btree_map::Range<'static, Value, Value>,
+    // Drop must happen after the iterator.
+    #[allow(unused)]
+    guard: RawAnyGuard,
+}
+
+impl Iter {
+    #[rune::function(instance, protocol = NEXT)]
+    fn next(&mut self) -> Option<(Value, Value)> {
+        let (key, value) = self.iter.next()?;
+        Some((key.clone(), value.clone()))
+    }
+
+    #[rune::function(instance, protocol = NEXT_BACK)]
+    fn next_back(&mut self) -> Option<(Value, Value)> {
+        let (key, value) = self.iter.next_back()?;
+        Some((key.clone(), value.clone()))
+    }
+}
+
+/// An iterator over the keys of a `BTreeMap`.
+#[derive(Any)]
+#[rune(item = ::std::collections::btree_map)]
+pub(crate) struct Keys {
+    iter: btree_map::Range<'static, Value, Value>,
+    // Drop must happen after the iterator.
+    #[allow(unused)]
+    guard: RawAnyGuard,
+}
+
+impl Keys {
+    #[rune::function(instance, protocol = NEXT)]
+    fn next(&mut self) -> Option<Value> {
+        let (key, _) = self.iter.next()?;
+        Some(key.clone())
+    }
+
+    #[rune::function(instance, protocol = NEXT_BACK)]
+    fn next_back(&mut self) -> Option<Value> {
+        let (key, _) = self.iter.next_back()?;
+        Some(key.clone())
+    }
+}
+
+/// An iterator over the values of a `BTreeMap`.
+#[derive(Any)]
+#[rune(item = ::std::collections::btree_map)]
+pub(crate) struct Values {
+    iter: btree_map::Range<'static, Value, Value>,
+    // Drop must happen after the iterator.
+    #[allow(unused)]
+    guard: RawAnyGuard,
+}
+
+impl Values {
+    #[rune::function(instance, protocol = NEXT)]
+    fn next(&mut self) -> Option<Value> {
+        let (_, value) = self.iter.next()?;
+        Some(value.clone())
+    }
+
+    #[rune::function(instance, protocol = NEXT_BACK)]
+    fn next_back(&mut self) -> Option<Value> {
+        let (_, value) = self.iter.next_back()?;
+        Some(value.clone())
+    }
+}
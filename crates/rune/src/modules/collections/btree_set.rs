@@ -0,0 +1,551 @@
+use core::cmp::Ordering;
+use core::ops::Bound;
+
+use crate as rune;
+use crate::alloc::btree_map::{self, Entry};
+use crate::alloc::fmt::TryWrite;
+use crate::alloc::prelude::*;
+use crate::runtime::{
+    EnvProtocolCaller, Formatter, Iterator, ProtocolCaller, RawAnyGuard, Ref, Value, VmResult,
+};
+use crate::{Any, ContextError, Module};
+
+use super::btree_map::{cmp, range_bounds};
+
+/// An ordered set based on a B-Tree.
+#[rune::module(::std::collections::btree_set)]
+pub fn module() -> Result<Module, ContextError> {
+    let mut m = Module::from_meta(self::module_meta)?;
+
+    m.ty::<BTreeSet>()?;
+    m.function_meta(BTreeSet::new__meta)?;
+    m.function_meta(BTreeSet::len__meta)?;
+    m.function_meta(BTreeSet::is_empty__meta)?;
+    m.function_meta(BTreeSet::insert__meta)?;
+    m.function_meta(BTreeSet::contains__meta)?;
+    m.function_meta(BTreeSet::remove__meta)?;
+    m.function_meta(BTreeSet::clear__meta)?;
+    m.function_meta(BTreeSet::first__meta)?;
+    m.function_meta(BTreeSet::last__meta)?;
+    m.function_meta(BTreeSet::pop_first__meta)?;
+    m.function_meta(BTreeSet::pop_last__meta)?;
+    m.function_meta(BTreeSet::range__meta)?;
+    m.function_meta(BTreeSet::iter__meta)?;
+    m.function_meta(BTreeSet::into_iter__meta)?;
+    m.function_meta(BTreeSet::from_iter__meta)?;
+    m.function_meta(BTreeSet::extend__meta)?;
+    m.function_meta(BTreeSet::debug_fmt__meta)?;
+
+    m.function_meta(BTreeSet::clone__meta)?;
+    m.implement_trait::<BTreeSet>(rune::item!(::std::clone::Clone))?;
+
+    m.function_meta(BTreeSet::partial_eq__meta)?;
+    m.implement_trait::<BTreeSet>(rune::item!(::std::cmp::PartialEq))?;
+
+    m.function_meta(BTreeSet::eq__meta)?;
+    m.implement_trait::<BTreeSet>(rune::item!(::std::cmp::Eq))?;
+
+    m.ty::<Iter>()?;
+    m.function_meta(Iter::next)?;
+    m.function_meta(Iter::next_back)?;
+    m.implement_trait::<Iter>(rune::item!(::std::iter::Iterator))?;
+    m.implement_trait::<Iter>(rune::item!(::std::iter::DoubleEndedIterator))?;
+
+    Ok(m)
+}
+
+/// An ordered set based on a B-Tree.
+///
+/// Values are ordered through the [`CMP`] protocol, so iterating over the set
+/// always produces its values in sorted order.
+///
+/// It is a logic error for a value to be modified in such a way that its
+/// ordering relative to any other value, as determined by the [`CMP`]
+/// protocol, changes while it is in the set.
+///
+/// # Examples
+///
+/// ```rune
+/// use std::collections::BTreeSet;
+///
+/// let set = BTreeSet::from_iter([3, 1, 2, 1]);
+///
+/// assert_eq!(set.len(), 3);
+/// assert!(set.contains(2));
+/// assert_eq!(set.iter().collect::<Vec>(), [1, 2, 3]);
+/// ```
+#[derive(Any)]
+#[rune(item = ::std::collections::btree_set)]
+pub(crate) struct BTreeSet {
+    map: btree_map::BTreeMap<Value, ()>,
+}
+
+impl BTreeSet {
+    /// Makes a new, empty `BTreeSet`.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeSet;
+    ///
+    /// let set = BTreeSet::new();
+    /// assert!(set.is_empty());
+    /// ```
+    #[rune::function(keep, path = Self::new)]
+    fn new() -> Self {
+        Self {
+            map: btree_map::BTreeMap::new(),
+        }
+    }
+
+    /// Returns the number of elements in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeSet;
+    ///
+    /// let set = BTreeSet::new();
+    /// assert_eq!(set.len(), 0);
+    /// set.insert(1);
+    /// assert_eq!(set.len(), 1);
+    /// ```
+    #[rune::function(keep)]
+    fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the set contains no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeSet;
+    ///
+    /// let set = BTreeSet::new();
+    /// assert!(set.is_empty());
+    /// set.insert(1);
+    /// assert!(!set.is_empty());
+    /// ```
+    #[rune::function(keep)]
+    fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Adds a value to the set.
+    ///
+    /// Returns whether the value was newly inserted.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeSet;
+    ///
+    /// let set = BTreeSet::new();
+    /// assert!(set.insert(2));
+    /// assert!(!set.insert(2));
+    /// assert_eq!(set.len(), 1);
+    /// ```
+    #[rune::function(keep)]
+    fn insert(&mut self, value: Value) -> VmResult<bool> {
+        let caller: &mut dyn ProtocolCaller = &mut EnvProtocolCaller;
+        self.insert_with(value, caller)
+    }
+
+    pub(crate) fn insert_with(
+        &mut self,
+        value: Value,
+        caller: &mut dyn ProtocolCaller,
+    ) -> VmResult<bool> {
+        match vm_try!(self.map.entry_with(caller, value, cmp)) {
+            Entry::Occupied(..) => VmResult::Ok(false),
+            Entry::Vacant(entry) => {
+                vm_try!(entry.try_insert(()));
+                VmResult::Ok(true)
+            }
+        }
+    }
+
+    /// Returns `true` if the set contains a value.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeSet;
+    ///
+    /// let set = BTreeSet::from_iter([1, 2, 3]);
+    /// assert!(set.contains(1));
+    /// assert!(!set.contains(4));
+    /// ```
+    #[rune::function(keep)]
+    fn contains(&self, value: Value) -> VmResult<bool> {
+        let caller: &mut dyn ProtocolCaller = &mut EnvProtocolCaller;
+        VmResult::Ok(vm_try!(self.map.contains_key_with(caller, &value, cmp)))
+    }
+
+    /// If the set contains an element equal to the value, removes it from the
+    /// set. Returns whether such an element was present.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeSet;
+    ///
+    /// let set = BTreeSet::from_iter([2]);
+    /// assert!(set.remove(2));
+    /// assert!(!set.remove(2));
+    /// ```
+    #[rune::function(keep)]
+    fn remove(&mut self, value: Value) -> VmResult<bool> {
+        let caller: &mut dyn ProtocolCaller = &mut EnvProtocolCaller;
+        let entry = vm_try!(self.map.remove_entry_with(caller, &value, cmp));
+        VmResult::Ok(entry.is_some())
+    }
+
+    /// Clears the set, removing all values.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeSet;
+    ///
+    /// let set = BTreeSet::from_iter([1]);
+    /// set.clear();
+    /// assert!(set.is_empty());
+    /// ```
+    #[rune::function(keep)]
+    fn clear(&mut self) {
+        self.map.clear()
+    }
+
+    /// Returns the first element in the set, if any. This element is always
+    /// the minimum of all elements in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeSet;
+    ///
+    /// let set = BTreeSet::new();
+    /// assert_eq!(set.first(), None);
+    /// set.insert(2);
+    /// set.insert(1);
+    /// assert_eq!(set.first(), Some(1));
+    /// ```
+    #[rune::function(keep)]
+    fn first(&self) -> Option<Value> {
+        let (value, _) = self.map.first_key_value()?;
+        Some(value.clone())
+    }
+
+    /// Returns the last element in the set, if any. This element is always
+    /// the maximum of all elements in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeSet;
+    ///
+    /// let set = BTreeSet::from_iter([1, 2]);
+    /// assert_eq!(set.last(), Some(2));
+    /// ```
+    #[rune::function(keep)]
+    fn last(&self) -> Option<Value> {
+        let (value, _) = self.map.last_key_value()?;
+        Some(value.clone())
+    }
+
+    /// Removes the first element from the set and returns it, if any. The
+    /// first element is always the minimum element in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeSet;
+    ///
+    /// let set = BTreeSet::from_iter([2, 1]);
+    /// assert_eq!(set.pop_first(), Some(1));
+    /// assert_eq!(set.pop_first(), Some(2));
+    /// assert_eq!(set.pop_first(), None);
+    /// ```
+    #[rune::function(keep)]
+    fn pop_first(&mut self) -> Option<Value> {
+        let (value, ()) = self.map.pop_first()?;
+        Some(value)
+    }
+
+    /// Removes the last element from the set and returns it, if any. The last
+    /// element is always the maximum element in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeSet;
+    ///
+    /// let set = BTreeSet::from_iter([2, 1]);
+    /// assert_eq!(set.pop_last(), Some(2));
+    /// assert_eq!(set.pop_last(), Some(1));
+    /// assert_eq!(set.pop_last(), None);
+    /// ```
+    #[rune::function(keep)]
+    fn pop_last(&mut self) -> Option<Value> {
+        let (value, ()) = self.map.pop_last()?;
+        Some(value)
+    }
+
+    /// Constructs a double-ended iterator over a sub-range of elements in the
+    /// set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than its end, or if the
+    /// start and end are equal and both excluded.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeSet;
+    ///
+    /// let set = BTreeSet::from_iter([3, 5, 8]);
+    ///
+    /// assert_eq!(set.range(4..).collect::<Vec>(), [5, 8]);
+    /// assert_eq!(set.range(..=5).collect::<Vec>(), [3, 5]);
+    /// assert_eq!(set.range(..).next_back(), Some(8));
+    /// ```
+    #[rune::function(keep, instance, path = Self::range)]
+    fn range(this: Ref<Self>, range: Value) -> VmResult<Iter> {
+        let caller: &mut dyn ProtocolCaller = &mut EnvProtocolCaller;
+        let bounds = vm_try!(range_bounds(&range, caller));
+        Self::range_iter(this, bounds, caller)
+    }
+
+    fn range_iter(
+        this: Ref<Self>,
+        bounds: (Bound<Value>, Bound<Value>),
+        caller: &mut dyn ProtocolCaller,
+    ) -> VmResult<Iter> {
+        let (this, guard) = Ref::into_raw(this);
+
+        // SAFETY: We're holding onto the reference guard, which is stored
+        // alongside the iterator.
+        let map = unsafe { &this.as_ref().map };
+        let iter = vm_try!(map.range_with(caller, bounds, cmp));
+        VmResult::Ok(Iter { iter, guard })
+    }
+
+    /// Gets an iterator that visits the elements in the set in ascending
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeSet;
+    ///
+    /// let set = BTreeSet::from_iter([3, 1, 2]);
+    /// assert_eq!(set.iter().collect::<Vec>(), [1, 2, 3]);
+    /// ```
+    #[rune::function(keep, instance, path = Self::iter)]
+    fn iter(this: Ref<Self>) -> VmResult<Iter> {
+        let bounds = (Bound::Unbounded, Bound::Unbounded);
+        Self::range_iter(this, bounds, &mut EnvProtocolCaller)
+    }
+
+    /// Extend this set from an iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeSet;
+    ///
+    /// let set = BTreeSet::new();
+    /// set.extend([3, 1, 2]);
+    /// assert_eq!(set.iter().collect::<Vec>(), [1, 2, 3]);
+    /// ```
+    #[rune::function(keep)]
+    fn extend(&mut self, value: Value) -> VmResult<()> {
+        let caller: &mut dyn ProtocolCaller = &mut EnvProtocolCaller;
+        let mut it = vm_try!(value.into_iter());
+
+        while let Some(value) = vm_try!(it.next()) {
+            vm_try!(self.insert_with(value, caller));
+        }
+
+        VmResult::Ok(())
+    }
+
+    /// Clone the set.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeSet;
+    ///
+    /// let a = BTreeSet::from_iter([1]);
+    /// let b = a.clone();
+    /// b.insert(2);
+    ///
+    /// assert_eq!(a.len(), 1);
+    /// assert_eq!(b.len(), 2);
+    /// ```
+    #[rune::function(keep, instance, path = Self::clone, protocol = CLONE)]
+    fn clone(this: &BTreeSet) -> VmResult<BTreeSet> {
+        VmResult::Ok(Self {
+            map: vm_try!(this.map.try_clone()),
+        })
+    }
+
+    /// Convert a set from an iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeSet;
+    ///
+    /// let set = BTreeSet::from_iter(["b", "a"]);
+    /// assert_eq!(set.first(), Some("a"));
+    /// ```
+    #[rune::function(keep, path = Self::from_iter)]
+    fn from_iter(mut it: Iterator) -> VmResult<BTreeSet> {
+        let caller: &mut dyn ProtocolCaller = &mut EnvProtocolCaller;
+        let mut set = Self::new();
+
+        while let Some(value) = vm_try!(it.next()) {
+            vm_try!(set.insert_with(value, caller));
+        }
+
+        VmResult::Ok(set)
+    }
+
+    /// Debug format the set.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeSet;
+    ///
+    /// let set = BTreeSet::from_iter([2, 1]);
+    /// assert_eq!(format!("{:?}", set), "{1, 2}");
+    /// ```
+    #[rune::function(keep, protocol = DEBUG_FMT)]
+    fn debug_fmt(&self, f: &mut Formatter) -> VmResult<()> {
+        self.debug_fmt_with(f, &mut EnvProtocolCaller)
+    }
+
+    pub(crate) fn debug_fmt_with(
+        &self,
+        f: &mut Formatter,
+        caller: &mut dyn ProtocolCaller,
+    ) -> VmResult<()> {
+        vm_try!(vm_write!(f, "{{"));
+
+        let mut it = self.map.keys().peekable();
+
+        while let Some(value) = it.next() {
+            vm_try!(value.debug_fmt_with(f, caller));
+
+            if it.peek().is_some() {
+                vm_try!(vm_write!(f, ", "));
+            }
+        }
+
+        vm_try!(vm_write!(f, "}}"));
+        VmResult::Ok(())
+    }
+
+    /// Perform a partial equality check over two sets.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeSet;
+    ///
+    /// let a = BTreeSet::from_iter([1, 2]);
+    /// let b = BTreeSet::from_iter([2, 1]);
+    /// let c = BTreeSet::from_iter([1, 3]);
+    ///
+    /// assert!(a == b);
+    /// assert!(a != c);
+    /// ```
+    #[rune::function(keep, protocol = PARTIAL_EQ)]
+    fn partial_eq(&self, other: &Self) -> VmResult<bool> {
+        self.eq_with(other, &mut EnvProtocolCaller)
+    }
+
+    /// Perform a total equality check over two sets.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeSet;
+    /// use std::ops::eq;
+    ///
+    /// let a = BTreeSet::from_iter([1, 2]);
+    /// let b = BTreeSet::from_iter([2, 1]);
+    ///
+    /// assert!(eq(a, b));
+    /// ```
+    #[rune::function(keep, protocol = EQ)]
+    fn eq(&self, other: &Self) -> VmResult<bool> {
+        self.eq_with(other, &mut EnvProtocolCaller)
+    }
+
+    fn eq_with(&self, other: &Self, caller: &mut dyn ProtocolCaller) -> VmResult<bool> {
+        if self.map.len() != other.map.len() {
+            return VmResult::Ok(false);
+        }
+
+        // NB: Both sets are ordered, so values can be compared pairwise.
+        for (a, b) in self.map.keys().zip(other.map.keys()) {
+            if vm_try!(Value::cmp_with(a, b, caller)) != Ordering::Equal {
+                return VmResult::Ok(false);
+            }
+        }
+
+        VmResult::Ok(true)
+    }
+
+    /// Iterate over the set in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::collections::BTreeSet;
+    ///
+    /// let set = BTreeSet::from_iter([3, 1, 2]);
+    /// let out = [];
+    ///
+    /// for value in set {
+    ///     out.push(value);
+    /// }
+    ///
+    /// assert_eq!(out, [1, 2, 3]);
+    /// ```
+    #[rune::function(keep, instance, protocol = INTO_ITER, path = Self)]
+    fn into_iter(this: Ref<Self>) -> VmResult<Iter> {
+        Self::iter(this)
+    }
+}
+
+/// An iterator over the values of a `BTreeSet`.
+#[derive(Any)]
+#[rune(item = ::std::collections::btree_set)]
+pub(crate) struct Iter {
+    iter: btree_map::Range<'static, Value, ()>,
+    // Drop must happen after the iterator.
+    #[allow(unused)]
+    guard: RawAnyGuard,
+}
+
+impl Iter {
+    #[rune::function(instance, protocol = NEXT)]
+    fn next(&mut self) -> Option<Value> {
+        let (value, _) = self.iter.next()?;
+        Some(value.clone())
+    }
+
+    #[rune::function(instance, protocol = NEXT_BACK)]
+    fn next_back(&mut self) -> Option<Value> {
+        let (value, _) = self.iter.next_back()?;
+        Some(value.clone())
+    }
+}
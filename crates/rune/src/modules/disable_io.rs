@@ -1,4 +1,5 @@
-//! I/O methods which will cause any output to be ignored.
+//! I/O methods which will cause any output to be ignored, and any input to
+//! be empty.
 //!
 //! # Examples
 //!
@@ -12,16 +13,34 @@
 //! ```
 
 use crate as rune;
+#[cfg(feature = "std")]
+use crate::modules::io::{Input, Sink, Stderr, Stdin, Stdout};
 use crate::runtime::{InstAddress, Memory, Output, VmResult};
 use crate::{ContextError, Module};
 
-/// I/O methods which will cause any output to be ignored.
+/// I/O methods which will cause any output to be ignored, and any input to be
+/// empty.
 #[rune::module(::std::io)]
 pub fn module() -> Result<Module, ContextError> {
     let mut module = Module::from_meta(self::module_meta)?;
 
     module.function("print", move |_: &str| {}).build()?;
     module.function("println", move |_: &str| {}).build()?;
+    module.function("eprint", move |_: &str| {}).build()?;
+    module.function("eprintln", move |_: &str| {}).build()?;
+
+    #[cfg(feature = "std")]
+    {
+        module
+            .function("stdin", move || Stdin::new(Input::Empty))
+            .build()?;
+        module
+            .function("stdout", move || Stdout::new(Sink::Discard))
+            .build()?;
+        module
+            .function("stderr", move || Stderr::new(Sink::Discard))
+            .build()?;
+    }
 
     module
         .raw_function(
@@ -7,6 +7,10 @@ use crate as rune;
 use crate::runtime::{VmErrorKind, VmResult};
 use crate::{ContextError, Module};
 
+pub mod consts;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod math;
+
 /// Floating point numbers.
 ///
 /// This provides methods for computing over and parsing 64-bit floating pointer
@@ -24,20 +28,75 @@ pub fn module() -> Result<Module, ContextError> {
     m.function_meta(is_normal)?;
     m.function_meta(max__meta)?;
     m.function_meta(min__meta)?;
-    #[cfg(feature = "std")]
+    #[cfg(any(feature = "std", feature = "libm"))]
     m.function_meta(sqrt)?;
-    #[cfg(feature = "std")]
+    #[cfg(any(feature = "std", feature = "libm"))]
     m.function_meta(abs)?;
-    #[cfg(feature = "std")]
+    #[cfg(any(feature = "std", feature = "libm"))]
     m.function_meta(powf)?;
-    #[cfg(feature = "std")]
+    #[cfg(any(feature = "std", feature = "libm"))]
     m.function_meta(powi)?;
-    #[cfg(feature = "std")]
+    #[cfg(any(feature = "std", feature = "libm"))]
     m.function_meta(floor)?;
-    #[cfg(feature = "std")]
+    #[cfg(any(feature = "std", feature = "libm"))]
     m.function_meta(ceil)?;
-    #[cfg(feature = "std")]
+    #[cfg(any(feature = "std", feature = "libm"))]
     m.function_meta(round)?;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    m.function_meta(trunc)?;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    m.function_meta(fract)?;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    m.function_meta(signum)?;
+    m.function_meta(clamp)?;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    m.function_meta(mul_add)?;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    m.function_meta(hypot)?;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    m.function_meta(sin)?;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    m.function_meta(cos)?;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    m.function_meta(tan)?;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    m.function_meta(asin)?;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    m.function_meta(acos)?;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    m.function_meta(atan)?;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    m.function_meta(atan2)?;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    m.function_meta(sin_cos)?;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    m.function_meta(sinh)?;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    m.function_meta(cosh)?;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    m.function_meta(tanh)?;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    m.function_meta(asinh)?;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    m.function_meta(acosh)?;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    m.function_meta(atanh)?;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    m.function_meta(exp)?;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    m.function_meta(exp2)?;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    m.function_meta(ln)?;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    m.function_meta(log)?;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    m.function_meta(log10)?;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    m.function_meta(log2)?;
+    m.function_meta(to_degrees)?;
+    m.function_meta(to_radians)?;
+    m.function_meta(to_bits)?;
+    m.function_meta(from_bits)?;
     m.function_meta(to_integer)?;
 
     m.function_meta(clone__meta)?;
@@ -257,9 +316,9 @@ fn min(this: f64, other: f64) -> f64 {
 /// assert!(negative_zero.sqrt() == negative_zero);
 /// ```
 #[rune::function(instance)]
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "libm"))]
 fn sqrt(this: f64) -> f64 {
-    this.sqrt()
+    math::sqrt(this)
 }
 
 /// Computes the absolute value of `self`.
@@ -279,9 +338,9 @@ fn sqrt(this: f64) -> f64 {
 /// assert!(f64::NAN.abs().is_nan());
 /// ```
 #[rune::function(instance)]
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "libm"))]
 fn abs(this: f64) -> f64 {
-    this.abs()
+    math::abs(this)
 }
 
 /// Raises a number to a floating point power.
@@ -295,9 +354,9 @@ fn abs(this: f64) -> f64 {
 /// assert!(abs_difference < 1e-10);
 /// ```
 #[rune::function(instance)]
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "libm"))]
 fn powf(this: f64, other: f64) -> f64 {
-    this.powf(other)
+    math::powf(this, other)
 }
 
 /// Raises a number to an integer power.
@@ -315,9 +374,9 @@ fn powf(this: f64, other: f64) -> f64 {
 /// assert!(abs_difference < 1e-10);
 /// ```
 #[rune::function(instance)]
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "libm"))]
 fn powi(this: f64, other: i32) -> f64 {
-    this.powi(other)
+    math::powi(this, other)
 }
 
 /// Returns the largest integer less than or equal to `self`.
@@ -334,9 +393,9 @@ fn powi(this: f64, other: i32) -> f64 {
 /// assert!(h.floor() == -4.0);
 /// ```
 #[rune::function(instance)]
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "libm"))]
 fn floor(this: f64) -> f64 {
-    this.floor()
+    math::floor(this)
 }
 
 /// Returns the smallest integer greater than or equal to `self`.
@@ -351,9 +410,9 @@ fn floor(this: f64) -> f64 {
 /// assert_eq!(g.ceil(), 4.0);
 /// ```
 #[rune::function(instance)]
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "libm"))]
 fn ceil(this: f64) -> f64 {
-    this.ceil()
+    math::ceil(this)
 }
 
 /// Returns the nearest integer to `self`. If a value is half-way between two
@@ -375,9 +434,581 @@ fn ceil(this: f64) -> f64 {
 /// assert_eq!(j.round(), 5.0);
 /// ```
 #[rune::function(instance)]
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "libm"))]
 fn round(this: f64) -> f64 {
-    this.round()
+    math::round(this)
+}
+
+/// Returns the integer part of `self`. This means that non-integer numbers are
+/// always truncated towards zero.
+///
+/// # Examples
+///
+/// ```rune
+/// let f = 3.7_f64;
+/// let g = 3.0_f64;
+/// let h = -3.7_f64;
+///
+/// assert_eq!(f.trunc(), 3.0);
+/// assert_eq!(g.trunc(), 3.0);
+/// assert_eq!(h.trunc(), -3.0);
+/// ```
+#[rune::function(instance)]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn trunc(this: f64) -> f64 {
+    math::trunc(this)
+}
+
+/// Returns the fractional part of `self`.
+///
+/// # Examples
+///
+/// ```rune
+/// let x = 3.6_f64;
+/// let y = -3.6_f64;
+///
+/// assert!((x.fract() - 0.6).abs() < 1e-10);
+/// assert!((y.fract() - -0.6).abs() < 1e-10);
+/// ```
+#[rune::function(instance)]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn fract(this: f64) -> f64 {
+    this - math::trunc(this)
+}
+
+/// Returns a number that represents the sign of `self`.
+///
+/// - `1.0` if the number is positive, `+0.0` or `INFINITY`
+/// - `-1.0` if the number is negative, `-0.0` or `NEG_INFINITY`
+/// - NaN if the number is NaN
+///
+/// # Examples
+///
+/// ```rune
+/// let f = 3.5_f64;
+///
+/// assert_eq!(f.signum(), 1.0);
+/// assert_eq!(f64::NEG_INFINITY.signum(), -1.0);
+///
+/// assert!(f64::NAN.signum().is_nan());
+/// ```
+#[rune::function(instance)]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn signum(this: f64) -> f64 {
+    math::signum(this)
+}
+
+/// Restrict a value to a certain interval unless it is NaN.
+///
+/// Returns `max` if `self` is greater than `max`, and `min` if `self` is less
+/// than `min`. Otherwise this returns `self`.
+///
+/// Note that this function returns NaN if the initial value was NaN as well.
+///
+/// # Panics
+///
+/// Panics if `min > max`, `min` is NaN, or `max` is NaN.
+///
+/// # Examples
+///
+/// ```rune
+/// assert!((-3.0f64).clamp(-2.0, 1.0) == -2.0);
+/// assert!((0.0f64).clamp(-2.0, 1.0) == 0.0);
+/// assert!((2.0f64).clamp(-2.0, 1.0) == 1.0);
+/// assert!((f64::NAN).clamp(-2.0, 1.0).is_nan());
+/// ```
+#[rune::function(instance)]
+fn clamp(this: f64, min: f64, max: f64) -> VmResult<f64> {
+    if min.is_nan() || max.is_nan() || min > max {
+        return VmResult::panic("Expected `min` to be less than or equal to `max`");
+    }
+
+    VmResult::Ok(this.clamp(min, max))
+}
+
+/// Fused multiply-add. Computes `(self * a) + b` with only one rounding error,
+/// yielding a more accurate result than an unfused multiply-add.
+///
+/// # Examples
+///
+/// ```rune
+/// let m = 10.0_f64;
+/// let x = 4.0_f64;
+/// let b = 60.0_f64;
+///
+/// assert_eq!(m.mul_add(x, b), 100.0);
+/// ```
+#[rune::function(instance)]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn mul_add(this: f64, a: f64, b: f64) -> f64 {
+    math::mul_add(this, a, b)
+}
+
+/// Compute the distance between the origin and a point (`x`, `y`) on the
+/// Euclidean plane. Equivalently, compute the length of the hypotenuse of a
+/// right-angle triangle with other sides having length `x.abs()` and
+/// `y.abs()`.
+///
+/// # Examples
+///
+/// ```rune
+/// let x = 2.0_f64;
+/// let y = 3.0_f64;
+///
+/// // sqrt(x^2 + y^2)
+/// let abs_difference = (x.hypot(y) - (x.powi(2) + y.powi(2)).sqrt()).abs();
+///
+/// assert!(abs_difference < 1e-10);
+/// ```
+#[rune::function(instance)]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn hypot(this: f64, other: f64) -> f64 {
+    math::hypot(this, other)
+}
+
+/// Computes the sine of a number (in radians).
+///
+/// # Examples
+///
+/// ```rune
+/// use std::f64::consts::FRAC_PI_2;
+///
+/// let abs_difference = (FRAC_PI_2.sin() - 1.0).abs();
+/// assert!(abs_difference < 1e-10);
+/// ```
+#[rune::function(instance)]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn sin(this: f64) -> f64 {
+    math::sin(this)
+}
+
+/// Computes the cosine of a number (in radians).
+///
+/// # Examples
+///
+/// ```rune
+/// use std::f64::consts::TAU;
+///
+/// let abs_difference = (TAU.cos() - 1.0).abs();
+/// assert!(abs_difference < 1e-10);
+/// ```
+#[rune::function(instance)]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn cos(this: f64) -> f64 {
+    math::cos(this)
+}
+
+/// Computes the tangent of a number (in radians).
+///
+/// # Examples
+///
+/// ```rune
+/// use std::f64::consts::FRAC_PI_4;
+///
+/// let abs_difference = (FRAC_PI_4.tan() - 1.0).abs();
+/// assert!(abs_difference < 1e-14);
+/// ```
+#[rune::function(instance)]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn tan(this: f64) -> f64 {
+    math::tan(this)
+}
+
+/// Computes the arcsine of a number. Return value is in radians in the range
+/// [-pi/2, pi/2] or NaN if the number is outside the range [-1, 1].
+///
+/// # Examples
+///
+/// ```rune
+/// use std::f64::consts::FRAC_PI_2;
+///
+/// // asin(sin(pi/2))
+/// let abs_difference = (FRAC_PI_2.sin().asin() - FRAC_PI_2).abs();
+/// assert!(abs_difference < 1e-10);
+/// ```
+#[rune::function(instance)]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn asin(this: f64) -> f64 {
+    math::asin(this)
+}
+
+/// Computes the arccosine of a number. Return value is in radians in the range
+/// [0, pi] or NaN if the number is outside the range [-1, 1].
+///
+/// # Examples
+///
+/// ```rune
+/// use std::f64::consts::FRAC_PI_4;
+///
+/// // acos(cos(pi/4))
+/// let abs_difference = (FRAC_PI_4.cos().acos() - FRAC_PI_4).abs();
+/// assert!(abs_difference < 1e-10);
+/// ```
+#[rune::function(instance)]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn acos(this: f64) -> f64 {
+    math::acos(this)
+}
+
+/// Computes the arctangent of a number. Return value is in radians in the range
+/// [-pi/2, pi/2].
+///
+/// # Examples
+///
+/// ```rune
+/// let f = 1.0_f64;
+///
+/// // atan(tan(1))
+/// let abs_difference = (f.tan().atan() - 1.0).abs();
+/// assert!(abs_difference < 1e-10);
+/// ```
+#[rune::function(instance)]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn atan(this: f64) -> f64 {
+    math::atan(this)
+}
+
+/// Computes the four quadrant arctangent of `self` (`y`) and `other` (`x`) in
+/// radians.
+///
+/// * `x = 0`, `y = 0`: `0`
+/// * `x >= 0`: `arctan(y/x)` -> `[-pi/2, pi/2]`
+/// * `y >= 0`: `arctan(y/x) + pi` -> `(pi/2, pi]`
+/// * `y < 0`: `arctan(y/x) - pi` -> `(-pi, -pi/2)`
+///
+/// # Examples
+///
+/// ```rune
+/// use std::f64::consts::FRAC_PI_4;
+///
+/// // Positive angles measured counter-clockwise from positive x axis
+/// // -pi/4 radians (45 deg clockwise)
+/// let x1 = 3.0_f64;
+/// let y1 = -3.0_f64;
+///
+/// // 3pi/4 radians (135 deg counter-clockwise)
+/// let x2 = -3.0_f64;
+/// let y2 = 3.0_f64;
+///
+/// let abs_difference_1 = (y1.atan2(x1) - (-FRAC_PI_4)).abs();
+/// let abs_difference_2 = (y2.atan2(x2) - (3.0 * FRAC_PI_4)).abs();
+///
+/// assert!(abs_difference_1 < 1e-10);
+/// assert!(abs_difference_2 < 1e-10);
+/// ```
+#[rune::function(instance)]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn atan2(this: f64, other: f64) -> f64 {
+    math::atan2(this, other)
+}
+
+/// Simultaneously computes the sine and cosine of the number, `x`. Returns
+/// `(sin(x), cos(x))`.
+///
+/// # Examples
+///
+/// ```rune
+/// use std::f64::consts::FRAC_PI_4;
+///
+/// let (sin, cos) = FRAC_PI_4.sin_cos();
+///
+/// assert!((sin - FRAC_PI_4.sin()).abs() < 1e-10);
+/// assert!((cos - FRAC_PI_4.cos()).abs() < 1e-10);
+/// ```
+#[rune::function(instance)]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn sin_cos(this: f64) -> (f64, f64) {
+    (math::sin(this), math::cos(this))
+}
+
+/// Hyperbolic sine function.
+///
+/// # Examples
+///
+/// ```rune
+/// use std::f64::consts::E;
+///
+/// let x = 1.0_f64;
+/// let f = x.sinh();
+///
+/// // Solving sinh() at 1 gives `(e^2-1)/(2e)`
+/// let g = ((E * E) - 1.0) / (2.0 * E);
+/// assert!((f - g).abs() < 1e-10);
+/// ```
+#[rune::function(instance)]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn sinh(this: f64) -> f64 {
+    math::sinh(this)
+}
+
+/// Hyperbolic cosine function.
+///
+/// # Examples
+///
+/// ```rune
+/// use std::f64::consts::E;
+///
+/// let x = 1.0_f64;
+/// let f = x.cosh();
+///
+/// // Solving cosh() at 1 gives this result
+/// let g = ((E * E) + 1.0) / (2.0 * E);
+/// assert!((f - g).abs() < 1e-10);
+/// ```
+#[rune::function(instance)]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn cosh(this: f64) -> f64 {
+    math::cosh(this)
+}
+
+/// Hyperbolic tangent function.
+///
+/// # Examples
+///
+/// ```rune
+/// use std::f64::consts::E;
+///
+/// let x = 1.0_f64;
+/// let f = x.tanh();
+///
+/// // Solving tanh() at 1 gives `(1 - e^(-2))/(1 + e^(-2))`
+/// let g = (1.0 - E.powi(-2)) / (1.0 + E.powi(-2));
+/// assert!((f - g).abs() < 1e-10);
+/// ```
+#[rune::function(instance)]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn tanh(this: f64) -> f64 {
+    math::tanh(this)
+}
+
+/// Inverse hyperbolic sine function.
+///
+/// # Examples
+///
+/// ```rune
+/// let x = 1.0_f64;
+/// let f = x.sinh().asinh();
+///
+/// assert!((f - x).abs() < 1e-10);
+/// ```
+#[rune::function(instance)]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn asinh(this: f64) -> f64 {
+    math::asinh(this)
+}
+
+/// Inverse hyperbolic cosine function.
+///
+/// # Examples
+///
+/// ```rune
+/// let x = 1.0_f64;
+/// let f = x.cosh().acosh();
+///
+/// assert!((f - x).abs() < 1e-10);
+/// ```
+#[rune::function(instance)]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn acosh(this: f64) -> f64 {
+    math::acosh(this)
+}
+
+/// Inverse hyperbolic tangent function.
+///
+/// # Examples
+///
+/// ```rune
+/// let x = 0.5_f64;
+/// let f = x.tanh().atanh();
+///
+/// assert!((f - x).abs() < 1e-10);
+/// ```
+#[rune::function(instance)]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn atanh(this: f64) -> f64 {
+    math::atanh(this)
+}
+
+/// Returns `e^(self)`, (the exponential function).
+///
+/// # Examples
+///
+/// ```rune
+/// let one = 1.0_f64;
+/// // e^1
+/// let e = one.exp();
+///
+/// // ln(e) - 1 == 0
+/// let abs_difference = (e.ln() - 1.0).abs();
+///
+/// assert!(abs_difference < 1e-10);
+/// ```
+#[rune::function(instance)]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn exp(this: f64) -> f64 {
+    math::exp(this)
+}
+
+/// Returns `2^(self)`.
+///
+/// # Examples
+///
+/// ```rune
+/// let f = 2.0_f64;
+///
+/// // 2^2 - 4 == 0
+/// let abs_difference = (f.exp2() - 4.0).abs();
+///
+/// assert!(abs_difference < 1e-10);
+/// ```
+#[rune::function(instance)]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn exp2(this: f64) -> f64 {
+    math::exp2(this)
+}
+
+/// Returns the natural logarithm of the number.
+///
+/// # Examples
+///
+/// ```rune
+/// let one = 1.0_f64;
+/// // e^1
+/// let e = one.exp();
+///
+/// // ln(e) - 1 == 0
+/// let abs_difference = (e.ln() - 1.0).abs();
+///
+/// assert!(abs_difference < 1e-10);
+/// ```
+#[rune::function(instance)]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn ln(this: f64) -> f64 {
+    math::ln(this)
+}
+
+/// Returns the logarithm of the number with respect to an arbitrary base.
+///
+/// The result might not be correctly rounded owing to implementation details;
+/// `log2` can produce more accurate results for base 2, and `log10` can
+/// produce more accurate results for base 10.
+///
+/// # Examples
+///
+/// ```rune
+/// let twenty_five = 25.0_f64;
+///
+/// // log5(25) - 2 == 0
+/// let abs_difference = (twenty_five.log(5.0) - 2.0).abs();
+///
+/// assert!(abs_difference < 1e-10);
+/// ```
+#[rune::function(instance)]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn log(this: f64, base: f64) -> f64 {
+    math::ln(this) / math::ln(base)
+}
+
+/// Returns the base 10 logarithm of the number.
+///
+/// # Examples
+///
+/// ```rune
+/// let hundred = 100.0_f64;
+///
+/// // log10(100) - 2 == 0
+/// let abs_difference = (hundred.log10() - 2.0).abs();
+///
+/// assert!(abs_difference < 1e-10);
+/// ```
+#[rune::function(instance)]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn log10(this: f64) -> f64 {
+    math::log10(this)
+}
+
+/// Returns the base 2 logarithm of the number.
+///
+/// # Examples
+///
+/// ```rune
+/// let four = 4.0_f64;
+///
+/// // log2(4) - 2 == 0
+/// let abs_difference = (four.log2() - 2.0).abs();
+///
+/// assert!(abs_difference < 1e-10);
+/// ```
+#[rune::function(instance)]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn log2(this: f64) -> f64 {
+    math::log2(this)
+}
+
+/// Converts radians to degrees.
+///
+/// # Examples
+///
+/// ```rune
+/// use std::f64::consts::PI;
+///
+/// let angle = PI;
+///
+/// let abs_difference = (angle.to_degrees() - 180.0).abs();
+///
+/// assert!(abs_difference < 1e-10);
+/// ```
+#[rune::function(instance)]
+fn to_degrees(this: f64) -> f64 {
+    this.to_degrees()
+}
+
+/// Converts degrees to radians.
+///
+/// # Examples
+///
+/// ```rune
+/// use std::f64::consts::PI;
+///
+/// let angle = 180.0_f64;
+///
+/// let abs_difference = (angle.to_radians() - PI).abs();
+///
+/// assert!(abs_difference < 1e-10);
+/// ```
+#[rune::function(instance)]
+fn to_radians(this: f64) -> f64 {
+    this.to_radians()
+}
+
+/// Raw transmutation to `u64`.
+///
+/// This is identical to `transmute::<f64, u64>(self)` in Rust.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!((1.0f64).to_bits(), 0x3ff0000000000000u64);
+/// assert_eq!((12.5f64).to_bits(), 0x4029000000000000u64);
+/// ```
+#[rune::function(instance)]
+fn to_bits(this: f64) -> u64 {
+    this.to_bits()
+}
+
+/// Raw transmutation from `u64`.
+///
+/// This is identical to `transmute::<u64, f64>(v)` in Rust.
+///
+/// # Examples
+///
+/// ```rune
+/// let v = f64::from_bits(0x4029000000000000u64);
+/// assert_eq!(v, 12.5);
+/// ```
+#[rune::function]
+fn from_bits(v: u64) -> f64 {
+    f64::from_bits(v)
 }
 
 /// Clone a `f64`.
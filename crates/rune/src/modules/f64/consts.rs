@@ -0,0 +1,130 @@
+//! Basic mathematical constants.
+
+use core::f64::consts;
+
+use crate as rune;
+use crate::{ContextError, Module};
+
+/// Basic mathematical constants.
+///
+/// # Examples
+///
+/// ```rune
+/// use std::f64::consts::{PI, TAU};
+///
+/// assert_eq!(PI * 2.0, TAU);
+/// ```
+#[rune::module(::std::f64::consts)]
+pub fn module() -> Result<Module, ContextError> {
+    let mut m = Module::from_meta(self::module_meta)?;
+
+    m.constant("PI", consts::PI).build()?.docs(docstring! {
+        /// Archimedes' constant (π)
+    })?;
+
+    m.constant("TAU", consts::TAU).build()?.docs(docstring! {
+        /// The full circle constant (τ)
+        ///
+        /// Equal to 2π.
+    })?;
+
+    m.constant("FRAC_PI_2", consts::FRAC_PI_2)
+        .build()?
+        .docs(docstring! {
+            /// π/2
+        })?;
+
+    m.constant("FRAC_PI_3", consts::FRAC_PI_3)
+        .build()?
+        .docs(docstring! {
+            /// π/3
+        })?;
+
+    m.constant("FRAC_PI_4", consts::FRAC_PI_4)
+        .build()?
+        .docs(docstring! {
+            /// π/4
+        })?;
+
+    m.constant("FRAC_PI_6", consts::FRAC_PI_6)
+        .build()?
+        .docs(docstring! {
+            /// π/6
+        })?;
+
+    m.constant("FRAC_PI_8", consts::FRAC_PI_8)
+        .build()?
+        .docs(docstring! {
+            /// π/8
+        })?;
+
+    m.constant("FRAC_1_PI", consts::FRAC_1_PI)
+        .build()?
+        .docs(docstring! {
+            /// 1/π
+        })?;
+
+    m.constant("FRAC_2_PI", consts::FRAC_2_PI)
+        .build()?
+        .docs(docstring! {
+            /// 2/π
+        })?;
+
+    m.constant("FRAC_2_SQRT_PI", consts::FRAC_2_SQRT_PI)
+        .build()?
+        .docs(docstring! {
+            /// 2/sqrt(π)
+        })?;
+
+    m.constant("SQRT_2", consts::SQRT_2)
+        .build()?
+        .docs(docstring! {
+            /// sqrt(2)
+        })?;
+
+    m.constant("FRAC_1_SQRT_2", consts::FRAC_1_SQRT_2)
+        .build()?
+        .docs(docstring! {
+            /// 1/sqrt(2)
+        })?;
+
+    m.constant("E", consts::E).build()?.docs(docstring! {
+        /// Euler's number (e)
+    })?;
+
+    m.constant("LOG2_E", consts::LOG2_E)
+        .build()?
+        .docs(docstring! {
+            /// log<sub>2</sub>(e)
+        })?;
+
+    m.constant("LOG2_10", consts::LOG2_10)
+        .build()?
+        .docs(docstring! {
+            /// log<sub>2</sub>(10)
+        })?;
+
+    m.constant("LOG10_E", consts::LOG10_E)
+        .build()?
+        .docs(docstring! {
+            /// log<sub>10</sub>(e)
+        })?;
+
+    m.constant("LOG10_2", consts::LOG10_2)
+        .build()?
+        .docs(docstring! {
+            /// log<sub>10</sub>(2)
+        })?;
+
+    m.constant("LN_2", consts::LN_2).build()?.docs(docstring! {
+        /// ln(2)
+    })?;
+
+    m.constant("LN_10", consts::LN_10)
+        .build()?
+        .docs(docstring! {
+            /// ln(10)
+        })?;
+
+    Ok(m)
+}
@@ -0,0 +1,84 @@
+//! Floating point math which falls back to [`libm`] when the standard library
+//! isn't available.
+//!
+//! Without either the `std` or the `libm` feature these functions are not
+//! provided, and neither are the `f64` methods which depend on them.
+
+macro_rules! math {
+    ($($name:ident($($arg:ident),*) => $libm:ident;)*) => {
+        $(
+            #[inline]
+            pub(super) fn $name(this: f64 $(, $arg: f64)*) -> f64 {
+                #[cfg(feature = "std")]
+                {
+                    this.$name($($arg),*)
+                }
+
+                #[cfg(not(feature = "std"))]
+                {
+                    libm::$libm(this $(, $arg)*)
+                }
+            }
+        )*
+    };
+}
+
+math! {
+    sqrt() => sqrt;
+    abs() => fabs;
+    powf(n) => pow;
+    floor() => floor;
+    ceil() => ceil;
+    round() => round;
+    trunc() => trunc;
+    sin() => sin;
+    cos() => cos;
+    tan() => tan;
+    asin() => asin;
+    acos() => acos;
+    atan() => atan;
+    atan2(other) => atan2;
+    sinh() => sinh;
+    cosh() => cosh;
+    tanh() => tanh;
+    asinh() => asinh;
+    acosh() => acosh;
+    atanh() => atanh;
+    exp() => exp;
+    exp2() => exp2;
+    ln() => log;
+    log10() => log10;
+    log2() => log2;
+    hypot(other) => hypot;
+    mul_add(a, b) => fma;
+}
+
+#[inline]
+pub(super) fn powi(this: f64, n: i32) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        this.powi(n)
+    }
+
+    #[cfg(not(feature = "std"))]
+    {
+        libm::pow(this, f64::from(n))
+    }
+}
+
+#[inline]
+pub(super) fn signum(this: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        this.signum()
+    }
+
+    #[cfg(not(feature = "std"))]
+    {
+        if this.is_nan() {
+            f64::NAN
+        } else {
+            libm::copysign(1.0, this)
+        }
+    }
+}
@@ -0,0 +1,28 @@
+//! Exact-width 32-bit signed integers.
+
+use core::num::ParseIntError;
+
+use crate as rune;
+use crate::runtime::{Bytes, Value, VmResult};
+use crate::{ContextError, Module};
+
+/// Exact-width 32-bit signed integers.
+///
+/// Integers are stored as 64-bit values, so this provides functions which
+/// perform arithmetic and bit manipulation as if the values were exactly 32
+/// bits wide. Arguments which are out of bounds for the type cause an error.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(i32::wrapping_add(i32::MAX, 1), i32::MIN);
+/// assert_eq!(i32::checked_add(i32::MAX, 1), None);
+/// ```
+#[rune::module(::std::i32)]
+pub fn module() -> Result<Module, ContextError> {
+    let mut m = Module::from_meta(self::module_meta)?;
+    sized!(m, i32);
+    Ok(m)
+}
+
+sized_fns!(i32);
@@ -27,6 +27,40 @@ macro_rules! unsigned {
         $m.function_meta(saturating_sub)?;
         $m.function_meta(saturating_mul)?;
         $m.function_meta(saturating_pow)?;
+        $m.function_meta(saturating_div)?;
+
+        $m.function_meta(checked_neg)?;
+        $m.function_meta(checked_pow)?;
+        $m.function_meta(checked_shl)?;
+        $m.function_meta(checked_shr)?;
+
+        $m.function_meta(wrapping_neg)?;
+        $m.function_meta(wrapping_pow)?;
+        $m.function_meta(wrapping_shl)?;
+        $m.function_meta(wrapping_shr)?;
+
+        $m.function_meta(overflowing_add)?;
+        $m.function_meta(overflowing_sub)?;
+        $m.function_meta(overflowing_mul)?;
+        $m.function_meta(overflowing_pow)?;
+
+        $m.function_meta(count_ones)?;
+        $m.function_meta(count_zeros)?;
+        $m.function_meta(leading_zeros)?;
+        $m.function_meta(trailing_zeros)?;
+        $m.function_meta(leading_ones)?;
+        $m.function_meta(trailing_ones)?;
+        $m.function_meta(rotate_left)?;
+        $m.function_meta(rotate_right)?;
+        $m.function_meta(swap_bytes)?;
+        $m.function_meta(reverse_bits)?;
+
+        $m.function_meta(to_be_bytes)?;
+        $m.function_meta(to_le_bytes)?;
+        $m.function_meta(to_ne_bytes)?;
+        $m.function_meta(from_be_bytes)?;
+        $m.function_meta(from_le_bytes)?;
+        $m.function_meta(from_ne_bytes)?;
 
         $m.function_meta(to_string)?;
 
@@ -70,6 +104,16 @@ macro_rules! unsigned {
             #[doc = concat!(" assert_eq!(", $n, "::MAX, 9223372036854775807);")]
             /// ```
         })?;
+
+        $m.constant("BITS", <$ty>::BITS).build()?.docs(docstring! {
+            /// The size of this integer type in bits.
+            ///
+            /// # Examples
+            ///
+            /// ```rune
+            #[doc = concat!(" assert_eq!(", $n, "::BITS, 64);")]
+            /// ```
+        })?;
     };
 }
 
@@ -108,6 +152,10 @@ macro_rules! unsigned_fns {
             to_string {
                 #[doc = concat!(" assert_eq!(10", $n, ".to_string(), \"10\");")]
             },
+            checked_neg {
+                #[doc = concat!(" assert_eq!(0", $n, ".checked_neg(), Some(0));")]
+                #[doc = concat!(" assert_eq!(1", $n, ".checked_neg(), None);")]
+            },
         }
     };
 
@@ -120,6 +168,7 @@ macro_rules! unsigned_fns {
         saturating_add { $(#[$saturating_add:meta])* },
         saturating_sub { $(#[$saturating_sub:meta])* },
         to_string { $(#[$to_string:meta])* },
+        checked_neg { $(#[$checked_neg:meta])* },
     ) => {
         #[doc = concat!(" Parse an `", $n, "`.")]
         ///
@@ -454,264 +503,1344 @@ macro_rules! unsigned_fns {
             <$ty>::saturating_pow(this, rhs)
         }
 
-        #[doc = concat!(" Clone a `", $n, "`.")]
+        /// Saturating integer division. Computes `self / rhs`, saturating at the
+        /// numeric bounds instead of overflowing.
         ///
-        /// Note that since the type is copy, cloning has the same effect as assigning
-        /// it.
+        /// # Panics
+        ///
+        /// This function will panic if `rhs` is 0.
         ///
         /// # Examples
         ///
+        /// Basic usage:
+        ///
         /// ```rune
-        #[doc = concat!(" let a = 5", $n, ";")]
-        /// let b = a;
-        /// let c = a.clone();
+        #[doc = concat!(" assert_eq!(5", $n, ".saturating_div(2), 2);")]
+        /// ```
+        #[rune::function(instance)]
+        #[inline]
+        fn saturating_div(this: $ty, rhs: $ty) -> VmResult<$ty> {
+            if rhs == 0 {
+                return VmResult::err(VmErrorKind::DivideByZero);
+            }
+
+            VmResult::Ok(<$ty>::saturating_div(this, rhs))
+        }
+
+        /// Checked negation. Computes `-self`, returning `None` if the result
+        /// can't be represented.
         ///
-        /// a += 1;
+        /// # Examples
         ///
-        /// assert_eq!(a, 6);
-        /// assert_eq!(b, 5);
-        /// assert_eq!(c, 5);
+        /// Basic usage:
+        ///
+        /// ```rune
+        $(#[$checked_neg])*
         /// ```
-        #[rune::function(keep, instance, protocol = CLONE)]
+        #[rune::function(instance)]
         #[inline]
-        fn clone(this: $ty) -> $ty {
-            this
+        fn checked_neg(this: $ty) -> Option<$ty> {
+            <$ty>::checked_neg(this)
         }
 
-        /// Test two integers for partial equality.
+        /// Checked exponentiation. Computes `self.pow(exp)`, returning `None` if
+        /// overflow occurred.
         ///
         /// # Examples
         ///
+        /// Basic usage:
+        ///
         /// ```rune
-        #[doc = concat!("  assert_eq!(5", $n, " == 5, true);")]
-        #[doc = concat!("  assert_eq!(5", $n, " == 10, false);")]
-        #[doc = concat!("  assert_eq!(10", $n, " == 5, false);")]
+        #[doc = concat!(" assert_eq!(8", $n, ".checked_pow(2), Some(64));")]
+        #[doc = concat!(" assert_eq!(", $n, "::MAX.checked_pow(2), None);")]
         /// ```
-        #[rune::function(keep, instance, protocol = PARTIAL_EQ)]
+        #[rune::function(instance)]
         #[inline]
-        fn partial_eq(this: $ty, rhs: $ty) -> bool {
-            this.eq(&rhs)
+        fn checked_pow(this: $ty, exp: u32) -> Option<$ty> {
+            <$ty>::checked_pow(this, exp)
         }
 
-        /// Test two integers for total equality.
+        /// Checked shift left. Computes `self << rhs`, returning `None` if `rhs` is
+        /// larger than or equal to the number of bits in `self`.
         ///
         /// # Examples
         ///
-        /// ```rune
-        /// use std::ops::eq;
+        /// Basic usage:
         ///
-        #[doc = concat!("  assert_eq!(eq(5", $n, ", 5", $n, "), true);")]
-        #[doc = concat!("  assert_eq!(eq(5", $n, ", 10", $n, "), false);")]
-        #[doc = concat!("  assert_eq!(eq(10", $n, ", 5", $n, "), false);")]
+        /// ```rune
+        #[doc = concat!(" assert_eq!(0x1", $n, ".checked_shl(4), Some(0x10));")]
+        #[doc = concat!(" assert_eq!(0x1", $n, ".checked_shl(129), None);")]
         /// ```
-        #[rune::function(keep, instance, protocol = EQ)]
+        #[rune::function(instance)]
         #[inline]
-        fn eq(this: $ty, rhs: $ty) -> bool {
-            this.eq(&rhs)
+        fn checked_shl(this: $ty, rhs: u32) -> Option<$ty> {
+            <$ty>::checked_shl(this, rhs)
         }
 
-        /// Perform a partial ordered comparison between two integers.
+        /// Checked shift right. Computes `self >> rhs`, returning `None` if `rhs`
+        /// is larger than or equal to the number of bits in `self`.
         ///
         /// # Examples
         ///
-        /// ```rune
-        /// use std::cmp::Ordering;
-        /// use std::ops::partial_cmp;
+        /// Basic usage:
         ///
-        #[doc = concat!(" assert_eq!(partial_cmp(5", $n, ", 10", $n, "), Some(Ordering::Less));")]
-        #[doc = concat!(" assert_eq!(partial_cmp(10", $n, ", 5", $n, "), Some(Ordering::Greater));")]
-        #[doc = concat!(" assert_eq!(partial_cmp(5", $n, ", 5", $n, "), Some(Ordering::Equal));")]
+        /// ```rune
+        #[doc = concat!(" assert_eq!(0x10", $n, ".checked_shr(4), Some(0x1));")]
+        #[doc = concat!(" assert_eq!(0x10", $n, ".checked_shr(129), None);")]
         /// ```
-        #[rune::function(keep, instance, protocol = PARTIAL_CMP)]
+        #[rune::function(instance)]
         #[inline]
-        fn partial_cmp(this: $ty, rhs: $ty) -> Option<Ordering> {
-            this.partial_cmp(&rhs)
+        fn checked_shr(this: $ty, rhs: u32) -> Option<$ty> {
+            <$ty>::checked_shr(this, rhs)
         }
 
-        /// Perform a totally ordered comparison between two integers.
+        /// Wrapping (modular) negation. Computes `-self`, wrapping around at the
+        /// boundary of the type.
         ///
         /// # Examples
         ///
-        /// ```rune
-        /// use std::cmp::Ordering;
-        /// use std::ops::cmp;
+        /// Basic usage:
         ///
-        #[doc = concat!(" assert_eq!(cmp(5", $n, ", 10", $n, "), Ordering::Less);")]
-        #[doc = concat!(" assert_eq!(cmp(10", $n, ", 5", $n, "), Ordering::Greater);")]
-        #[doc = concat!(" assert_eq!(cmp(5", $n, ", 5", $n, "), Ordering::Equal);")]
+        /// ```rune
+        #[doc = concat!(" assert_eq!(0", $n, ".wrapping_neg(), 0);")]
+        #[doc = concat!(" assert_eq!(", $n, "::MAX.wrapping_neg(), ", $n, "::MIN + 1);")]
         /// ```
-        #[rune::function(keep, instance, protocol = CMP)]
+        #[rune::function(instance)]
         #[inline]
-        fn cmp(this: $ty, rhs: $ty) -> Ordering {
-            this.cmp(&rhs)
+        fn wrapping_neg(this: $ty) -> $ty {
+            <$ty>::wrapping_neg(this)
         }
 
-        /// Returns the number as a string.
+        /// Wrapping (modular) exponentiation. Computes `self.pow(exp)`, wrapping
+        /// around at the boundary of the type.
         ///
         /// # Examples
         ///
         /// Basic usage:
         ///
         /// ```rune
-        $(#[$to_string])*
+        #[doc = concat!(" assert_eq!(3", $n, ".wrapping_pow(4), 81);")]
+        #[doc = concat!(" assert_eq!(", $n, "::MAX.wrapping_pow(2), 1);")]
         /// ```
         #[rune::function(instance)]
         #[inline]
-        fn to_string(this: $ty) -> VmResult<alloc::String> {
-            VmResult::Ok(vm_try!(this.try_to_string()))
+        fn wrapping_pow(this: $ty, exp: u32) -> $ty {
+            <$ty>::wrapping_pow(this, exp)
         }
-    };
-}
 
-macro_rules! signed {
-    ($m:ident, $ty:ty) => {
-        unsigned!($m, $ty, stringify!($ty));
+        /// Panic-free bitwise shift-left; yields `self << mask(rhs)`, where `mask`
+        /// removes any high-order bits of `rhs` that would cause the shift to
+        /// exceed the bitwidth of the type.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(1", $n, ".wrapping_shl(7), 128);")]
+        #[doc = concat!(" assert_eq!(1", $n, ".wrapping_shl(128), 1);")]
+        /// ```
+        #[rune::function(instance)]
+        #[inline]
+        fn wrapping_shl(this: $ty, rhs: u32) -> $ty {
+            <$ty>::wrapping_shl(this, rhs)
+        }
 
-        $m.function_meta(abs)?;
-        $m.function_meta(saturating_abs)?;
-        $m.function_meta(signum)?;
-        $m.function_meta(is_positive)?;
-        $m.function_meta(is_negative)?;
-    };
-}
+        /// Panic-free bitwise shift-right; yields `self >> mask(rhs)`, where `mask`
+        /// removes any high-order bits of `rhs` that would cause the shift to
+        /// exceed the bitwidth of the type.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(128", $n, ".wrapping_shr(7), 1);")]
+        #[doc = concat!(" assert_eq!(128", $n, ".wrapping_shr(128), 128);")]
+        /// ```
+        #[rune::function(instance)]
+        #[inline]
+        fn wrapping_shr(this: $ty, rhs: u32) -> $ty {
+            <$ty>::wrapping_shr(this, rhs)
+        }
 
-macro_rules! signed_fns {
-    ($ty:ty) => {
-        signed_fns!($ty, stringify!($ty));
-    };
+        /// Calculates `self + rhs`.
+        ///
+        /// Returns a tuple of the addition along with a boolean indicating whether
+        /// an arithmetic overflow would occur. If an overflow would have occurred
+        /// then the wrapped value is returned.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(5", $n, ".overflowing_add(2), (7, false));")]
+        #[doc = concat!(" assert_eq!(", $n, "::MAX.overflowing_add(1), (", $n, "::MIN, true));")]
+        /// ```
+        #[rune::function(instance)]
+        #[inline]
+        fn overflowing_add(this: $ty, rhs: $ty) -> ($ty, bool) {
+            <$ty>::overflowing_add(this, rhs)
+        }
 
-    ($ty:ty, $n:expr) => {
-        unsigned_fns! {
-            inner $ty, $n,
-            checked_div {
-                #[doc = concat!(" assert_eq!((", $n, "::MIN + 1).checked_div(-1), Some(", $n, "::MAX));")]
-                #[doc = concat!(" assert_eq!(", $n, "::MIN.checked_div(-1), None);")]
-                #[doc = concat!(" assert_eq!(1", $n, ".checked_div(0), None);")]
-            },
-            saturating_pow {
-                /// assert_eq!((-4).saturating_pow(3), -64);
-                #[doc = concat!(" assert_eq!(", $n, "::MIN.saturating_pow(2), ", $n, "::MAX);")]
-                #[doc = concat!(" assert_eq!(", $n, "::MIN.saturating_pow(3), ", $n, "::MIN);")]
-            },
-            checked_rem {
-                #[doc = concat!(" assert_eq!(5", $n, ".checked_rem(2), Some(1));")]
-                #[doc = concat!(" assert_eq!(5", $n, ".checked_rem(0), None);")]
-                #[doc = concat!(" assert_eq!(", $n, "::MIN.checked_rem(-1), None);")]
-            },
-            wrapping_sub {
-                /// assert_eq!(0.wrapping_sub(127), -127);
-                #[doc = concat!(" assert_eq!((-2", $n, ").wrapping_sub(", $n, "::MAX), ", $n, "::MAX);")]
-            },
-            saturating_add {
-                /// assert_eq!(100.saturating_add(1), 101);
-                #[doc = concat!(" assert_eq!(", $n, "::MAX.saturating_add(100), ", $n, "::MAX);")]
-                #[doc = concat!(" assert_eq!(", $n, "::MIN.saturating_add(-1), ", $n, "::MIN);")]
-            },
-            saturating_sub {
-                /// assert_eq!(100.saturating_sub(127), -27);
-                #[doc = concat!(" assert_eq!(", $n, "::MIN.saturating_sub(100), ", $n, "::MIN);")]
-                #[doc = concat!(" assert_eq!(", $n, "::MAX.saturating_sub(-1), ", $n, "::MAX);")]
-            },
-            to_string {
-                #[doc = concat!(" assert_eq!((-10", $n, ").to_string(), \"-10\");")]
-                #[doc = concat!(" assert_eq!(10", $n, ".to_string(), \"10\");")]
-            },
+        /// Calculates `self - rhs`.
+        ///
+        /// Returns a tuple of the subtraction along with a boolean indicating
+        /// whether an arithmetic overflow would occur. If an overflow would have
+        /// occurred then the wrapped value is returned.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(5", $n, ".overflowing_sub(2), (3, false));")]
+        #[doc = concat!(" assert_eq!(", $n, "::MIN.overflowing_sub(1), (", $n, "::MAX, true));")]
+        /// ```
+        #[rune::function(instance)]
+        #[inline]
+        fn overflowing_sub(this: $ty, rhs: $ty) -> ($ty, bool) {
+            <$ty>::overflowing_sub(this, rhs)
         }
 
-        /// Computes the absolute value of `self`.
+        /// Calculates the multiplication of `self` and `rhs`.
         ///
-        /// # Overflow behavior
+        /// Returns a tuple of the multiplication along with a boolean indicating
+        /// whether an arithmetic overflow would occur. If an overflow would have
+        /// occurred then the wrapped value is returned.
         ///
-        #[doc = concat!(" The absolute value of `", $n, "::MIN` cannot be represented as an `int`,")]
-        /// and attempting to calculate it will cause an overflow. This means
-        #[doc = concat!(" that such code will wrap to `", $n, "::MIN` without a panic.")]
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(5", $n, ".overflowing_mul(2), (10, false));")]
+        #[doc = concat!(" assert!(", $n, "::MAX.overflowing_mul(2).1);")]
+        /// ```
+        #[rune::function(instance)]
+        #[inline]
+        fn overflowing_mul(this: $ty, rhs: $ty) -> ($ty, bool) {
+            <$ty>::overflowing_mul(this, rhs)
+        }
+
+        /// Raises self to the power of `exp`, using exponentiation by squaring.
+        ///
+        /// Returns a tuple of the exponentiation along with a bool indicating
+        /// whether an overflow happened.
         ///
         /// # Examples
         ///
         /// Basic usage:
         ///
         /// ```rune
-        /// assert_eq!(10.abs(), 10);
-        /// assert_eq!((-10).abs(), 10);
+        #[doc = concat!(" assert_eq!(3", $n, ".overflowing_pow(4), (81, false));")]
+        #[doc = concat!(" assert_eq!(", $n, "::MAX.overflowing_pow(2), (1, true));")]
         /// ```
         #[rune::function(instance)]
         #[inline]
-        fn abs(this: $ty) -> $ty {
-            <$ty>::wrapping_abs(this)
+        fn overflowing_pow(this: $ty, exp: u32) -> ($ty, bool) {
+            <$ty>::overflowing_pow(this, exp)
         }
 
-        /// Saturating absolute value. Computes `self.abs()`, returning `MAX` if `self
-        /// == MIN` instead of overflowing.
+        /// Returns the number of ones in the binary representation of `self`.
         ///
         /// # Examples
         ///
         /// Basic usage:
         ///
         /// ```rune
-        /// assert_eq!(100.saturating_abs(), 100);
-        /// assert_eq!((-100).saturating_abs(), 100);
-        #[doc = concat!(" assert_eq!(", $n, "::MIN.saturating_abs(), ", $n, "::MAX);")]
-        #[doc = concat!(" assert_eq!((", $n, "::MIN + 1).saturating_abs(), ", $n, "::MAX);")]
+        #[doc = concat!(" assert_eq!(0b01001100", $n, ".count_ones(), 3);")]
         /// ```
         #[rune::function(instance)]
         #[inline]
-        fn saturating_abs(this: $ty) -> $ty {
-            <$ty>::saturating_abs(this)
+        fn count_ones(this: $ty) -> u32 {
+            <$ty>::count_ones(this)
         }
 
-        /// Returns a number representing sign of `self`.
+        /// Returns the number of zeros in the binary representation of `self`.
         ///
-        /// - `0` if the number is zero
-        /// - `1` if the number is positive
-        /// - `-1` if the number is negative
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(0", $n, ".count_zeros(), 64);")]
+        #[doc = concat!(" assert_eq!(1", $n, ".count_zeros(), 63);")]
+        /// ```
+        #[rune::function(instance)]
+        #[inline]
+        fn count_zeros(this: $ty) -> u32 {
+            <$ty>::count_zeros(this)
+        }
+
+        /// Returns the number of leading zeros in the binary representation of
+        /// `self`.
         ///
         /// # Examples
         ///
         /// Basic usage:
         ///
         /// ```rune
-        /// assert_eq!(10.signum(), 1);
-        /// assert_eq!(0.signum(), 0);
-        /// assert_eq!((-10).signum(), -1);
+        #[doc = concat!(" assert_eq!(1", $n, ".leading_zeros(), 63);")]
+        #[doc = concat!(" assert_eq!(0", $n, ".leading_zeros(), 64);")]
         /// ```
         #[rune::function(instance)]
         #[inline]
-        fn signum(this: $ty) -> $ty {
-            <$ty>::signum(this)
+        fn leading_zeros(this: $ty) -> u32 {
+            <$ty>::leading_zeros(this)
         }
 
-        /// Returns `true` if `self` is positive and `false` if the number is zero or
-        /// negative.
+        /// Returns the number of trailing zeros in the binary representation of
+        /// `self`.
         ///
         /// # Examples
         ///
         /// Basic usage:
         ///
         /// ```rune
-        /// assert!(10.is_positive());
-        /// assert!(!(-10).is_positive());
+        #[doc = concat!(" assert_eq!(0b0101000", $n, ".trailing_zeros(), 3);")]
         /// ```
         #[rune::function(instance)]
         #[inline]
-        fn is_positive(this: $ty) -> bool {
-            <$ty>::is_positive(this)
+        fn trailing_zeros(this: $ty) -> u32 {
+            <$ty>::trailing_zeros(this)
         }
 
-        /// Returns `true` if `self` is negative and `false` if the number is zero or
-        /// positive.
+        /// Returns the number of leading ones in the binary representation of
+        /// `self`.
         ///
         /// # Examples
         ///
         /// Basic usage:
         ///
         /// ```rune
-        /// assert!((-10).is_negative());
-        /// assert!(!10.is_negative());
+        #[doc = concat!(" assert_eq!(0", $n, ".leading_ones(), 0);")]
+        #[doc = concat!(" assert_eq!(0", $n, ".wrapping_sub(1).leading_ones(), 64);")]
         /// ```
         #[rune::function(instance)]
         #[inline]
-        fn is_negative(this: $ty) -> bool {
-            <$ty>::is_negative(this)
+        fn leading_ones(this: $ty) -> u32 {
+            <$ty>::leading_ones(this)
         }
-    }
+
+        /// Returns the number of trailing ones in the binary representation of
+        /// `self`.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(0b1011", $n, ".trailing_ones(), 2);")]
+        #[doc = concat!(" assert_eq!(0", $n, ".wrapping_sub(1).trailing_ones(), 64);")]
+        /// ```
+        #[rune::function(instance)]
+        #[inline]
+        fn trailing_ones(this: $ty) -> u32 {
+            <$ty>::trailing_ones(this)
+        }
+
+        /// Shifts the bits to the left by a specified amount, `n`, wrapping the
+        /// truncated bits to the end of the resulting integer.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(0x10", $n, ".rotate_left(4), 0x100);")]
+        #[doc = concat!(" assert_eq!(1", $n, ".rotate_left(64), 1);")]
+        /// ```
+        #[rune::function(instance)]
+        #[inline]
+        fn rotate_left(this: $ty, n: u32) -> $ty {
+            <$ty>::rotate_left(this, n)
+        }
+
+        /// Shifts the bits to the right by a specified amount, `n`, wrapping the
+        /// truncated bits to the beginning of the resulting integer.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(0x100", $n, ".rotate_right(4), 0x10);")]
+        #[doc = concat!(" assert_eq!(1", $n, ".rotate_right(1), 1", $n, ".rotate_left(63));")]
+        /// ```
+        #[rune::function(instance)]
+        #[inline]
+        fn rotate_right(this: $ty, n: u32) -> $ty {
+            <$ty>::rotate_right(this, n)
+        }
+
+        /// Reverses the byte order of the integer.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(0x12", $n, ".swap_bytes(), 0x1200000000000000);")]
+        /// ```
+        #[rune::function(instance)]
+        #[inline]
+        fn swap_bytes(this: $ty) -> $ty {
+            <$ty>::swap_bytes(this)
+        }
+
+        /// Reverses the order of bits in the integer. The least significant bit
+        /// becomes the most significant bit, second least-significant bit becomes
+        /// second most-significant bit, etc.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(0b1000", $n, ".reverse_bits(), 0x1000000000000000);")]
+        /// ```
+        #[rune::function(instance)]
+        #[inline]
+        fn reverse_bits(this: $ty) -> $ty {
+            <$ty>::reverse_bits(this)
+        }
+
+        /// Return the memory representation of this integer as a byte array in
+        /// big-endian (network) byte order.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" let bytes = 0x0102030405060708", $n, ".to_be_bytes();")]
+        /// assert_eq!(bytes, b"\x01\x02\x03\x04\x05\x06\x07\x08");
+        /// ```
+        #[rune::function(instance)]
+        #[inline]
+        fn to_be_bytes(this: $ty) -> VmResult<Bytes> {
+            VmResult::Ok(vm_try!(Bytes::from_slice(<$ty>::to_be_bytes(this))))
+        }
+
+        /// Return the memory representation of this integer as a byte array in
+        /// little-endian byte order.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" let bytes = 0x0102030405060708", $n, ".to_le_bytes();")]
+        /// assert_eq!(bytes, b"\x08\x07\x06\x05\x04\x03\x02\x01");
+        /// ```
+        #[rune::function(instance)]
+        #[inline]
+        fn to_le_bytes(this: $ty) -> VmResult<Bytes> {
+            VmResult::Ok(vm_try!(Bytes::from_slice(<$ty>::to_le_bytes(this))))
+        }
+
+        /// Return the memory representation of this integer as a byte array in
+        /// native byte order.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" let bytes = 0x12", $n, ".to_ne_bytes();")]
+        #[doc = concat!(" assert_eq!(", $n, "::from_ne_bytes(bytes), Some(0x12));")]
+        /// ```
+        #[rune::function(instance)]
+        #[inline]
+        fn to_ne_bytes(this: $ty) -> VmResult<Bytes> {
+            VmResult::Ok(vm_try!(Bytes::from_slice(<$ty>::to_ne_bytes(this))))
+        }
+
+        /// Create an integer value from its representation as a byte array in
+        /// big endian.
+        ///
+        /// Returns `None` if the number of bytes doesn't match the size of the
+        /// integer.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" let value = ", $n, "::from_be_bytes(b\"\\x01\\x02\\x03\\x04\\x05\\x06\\x07\\x08\");")]
+        /// assert_eq!(value, Some(0x0102030405060708));
+        #[doc = concat!(" assert_eq!(", $n, "::from_be_bytes(b\"\\x01\"), None);")]
+        /// ```
+        #[rune::function]
+        #[inline]
+        fn from_be_bytes(bytes: &[u8]) -> Option<$ty> {
+            Some(<$ty>::from_be_bytes(bytes.try_into().ok()?))
+        }
+
+        /// Create an integer value from its representation as a byte array in
+        /// little endian.
+        ///
+        /// Returns `None` if the number of bytes doesn't match the size of the
+        /// integer.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" let value = ", $n, "::from_le_bytes(b\"\\x08\\x07\\x06\\x05\\x04\\x03\\x02\\x01\");")]
+        /// assert_eq!(value, Some(0x0102030405060708));
+        #[doc = concat!(" assert_eq!(", $n, "::from_le_bytes(b\"\\x01\"), None);")]
+        /// ```
+        #[rune::function]
+        #[inline]
+        fn from_le_bytes(bytes: &[u8]) -> Option<$ty> {
+            Some(<$ty>::from_le_bytes(bytes.try_into().ok()?))
+        }
+
+        /// Create an integer value from its memory representation as a byte array
+        /// in native endianness.
+        ///
+        /// Returns `None` if the number of bytes doesn't match the size of the
+        /// integer.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" let bytes = 0x12", $n, ".to_ne_bytes();")]
+        #[doc = concat!(" assert_eq!(", $n, "::from_ne_bytes(bytes), Some(0x12));")]
+        /// ```
+        #[rune::function]
+        #[inline]
+        fn from_ne_bytes(bytes: &[u8]) -> Option<$ty> {
+            Some(<$ty>::from_ne_bytes(bytes.try_into().ok()?))
+        }
+
+        #[doc = concat!(" Clone a `", $n, "`.")]
+        ///
+        /// Note that since the type is copy, cloning has the same effect as assigning
+        /// it.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" let a = 5", $n, ";")]
+        /// let b = a;
+        /// let c = a.clone();
+        ///
+        /// a += 1;
+        ///
+        /// assert_eq!(a, 6);
+        /// assert_eq!(b, 5);
+        /// assert_eq!(c, 5);
+        /// ```
+        #[rune::function(keep, instance, protocol = CLONE)]
+        #[inline]
+        fn clone(this: $ty) -> $ty {
+            this
+        }
+
+        /// Test two integers for partial equality.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!("  assert_eq!(5", $n, " == 5, true);")]
+        #[doc = concat!("  assert_eq!(5", $n, " == 10, false);")]
+        #[doc = concat!("  assert_eq!(10", $n, " == 5, false);")]
+        /// ```
+        #[rune::function(keep, instance, protocol = PARTIAL_EQ)]
+        #[inline]
+        fn partial_eq(this: $ty, rhs: $ty) -> bool {
+            this.eq(&rhs)
+        }
+
+        /// Test two integers for total equality.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        /// use std::ops::eq;
+        ///
+        #[doc = concat!("  assert_eq!(eq(5", $n, ", 5", $n, "), true);")]
+        #[doc = concat!("  assert_eq!(eq(5", $n, ", 10", $n, "), false);")]
+        #[doc = concat!("  assert_eq!(eq(10", $n, ", 5", $n, "), false);")]
+        /// ```
+        #[rune::function(keep, instance, protocol = EQ)]
+        #[inline]
+        fn eq(this: $ty, rhs: $ty) -> bool {
+            this.eq(&rhs)
+        }
+
+        /// Perform a partial ordered comparison between two integers.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        /// use std::cmp::Ordering;
+        /// use std::ops::partial_cmp;
+        ///
+        #[doc = concat!(" assert_eq!(partial_cmp(5", $n, ", 10", $n, "), Some(Ordering::Less));")]
+        #[doc = concat!(" assert_eq!(partial_cmp(10", $n, ", 5", $n, "), Some(Ordering::Greater));")]
+        #[doc = concat!(" assert_eq!(partial_cmp(5", $n, ", 5", $n, "), Some(Ordering::Equal));")]
+        /// ```
+        #[rune::function(keep, instance, protocol = PARTIAL_CMP)]
+        #[inline]
+        fn partial_cmp(this: $ty, rhs: $ty) -> Option<Ordering> {
+            this.partial_cmp(&rhs)
+        }
+
+        /// Perform a totally ordered comparison between two integers.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        /// use std::cmp::Ordering;
+        /// use std::ops::cmp;
+        ///
+        #[doc = concat!(" assert_eq!(cmp(5", $n, ", 10", $n, "), Ordering::Less);")]
+        #[doc = concat!(" assert_eq!(cmp(10", $n, ", 5", $n, "), Ordering::Greater);")]
+        #[doc = concat!(" assert_eq!(cmp(5", $n, ", 5", $n, "), Ordering::Equal);")]
+        /// ```
+        #[rune::function(keep, instance, protocol = CMP)]
+        #[inline]
+        fn cmp(this: $ty, rhs: $ty) -> Ordering {
+            this.cmp(&rhs)
+        }
+
+        /// Returns the number as a string.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```rune
+        $(#[$to_string])*
+        /// ```
+        #[rune::function(instance)]
+        #[inline]
+        fn to_string(this: $ty) -> VmResult<alloc::String> {
+            VmResult::Ok(vm_try!(this.try_to_string()))
+        }
+    };
+}
+
+macro_rules! signed {
+    ($m:ident, $ty:ty) => {
+        unsigned!($m, $ty, stringify!($ty));
+
+        $m.function_meta(abs)?;
+        $m.function_meta(saturating_abs)?;
+        $m.function_meta(signum)?;
+        $m.function_meta(is_positive)?;
+        $m.function_meta(is_negative)?;
+    };
+}
+
+macro_rules! signed_fns {
+    ($ty:ty) => {
+        signed_fns!($ty, stringify!($ty));
+    };
+
+    ($ty:ty, $n:expr) => {
+        unsigned_fns! {
+            inner $ty, $n,
+            checked_div {
+                #[doc = concat!(" assert_eq!((", $n, "::MIN + 1).checked_div(-1), Some(", $n, "::MAX));")]
+                #[doc = concat!(" assert_eq!(", $n, "::MIN.checked_div(-1), None);")]
+                #[doc = concat!(" assert_eq!(1", $n, ".checked_div(0), None);")]
+            },
+            saturating_pow {
+                /// assert_eq!((-4).saturating_pow(3), -64);
+                #[doc = concat!(" assert_eq!(", $n, "::MIN.saturating_pow(2), ", $n, "::MAX);")]
+                #[doc = concat!(" assert_eq!(", $n, "::MIN.saturating_pow(3), ", $n, "::MIN);")]
+            },
+            checked_rem {
+                #[doc = concat!(" assert_eq!(5", $n, ".checked_rem(2), Some(1));")]
+                #[doc = concat!(" assert_eq!(5", $n, ".checked_rem(0), None);")]
+                #[doc = concat!(" assert_eq!(", $n, "::MIN.checked_rem(-1), None);")]
+            },
+            wrapping_sub {
+                /// assert_eq!(0.wrapping_sub(127), -127);
+                #[doc = concat!(" assert_eq!((-2", $n, ").wrapping_sub(", $n, "::MAX), ", $n, "::MAX);")]
+            },
+            saturating_add {
+                /// assert_eq!(100.saturating_add(1), 101);
+                #[doc = concat!(" assert_eq!(", $n, "::MAX.saturating_add(100), ", $n, "::MAX);")]
+                #[doc = concat!(" assert_eq!(", $n, "::MIN.saturating_add(-1), ", $n, "::MIN);")]
+            },
+            saturating_sub {
+                /// assert_eq!(100.saturating_sub(127), -27);
+                #[doc = concat!(" assert_eq!(", $n, "::MIN.saturating_sub(100), ", $n, "::MIN);")]
+                #[doc = concat!(" assert_eq!(", $n, "::MAX.saturating_sub(-1), ", $n, "::MAX);")]
+            },
+            to_string {
+                #[doc = concat!(" assert_eq!((-10", $n, ").to_string(), \"-10\");")]
+                #[doc = concat!(" assert_eq!(10", $n, ".to_string(), \"10\");")]
+            },
+            checked_neg {
+                #[doc = concat!(" assert_eq!(5", $n, ".checked_neg(), Some(-5));")]
+                #[doc = concat!(" assert_eq!(", $n, "::MIN.checked_neg(), None);")]
+            },
+        }
+
+        /// Computes the absolute value of `self`.
+        ///
+        /// # Overflow behavior
+        ///
+        #[doc = concat!(" The absolute value of `", $n, "::MIN` cannot be represented as an `int`,")]
+        /// and attempting to calculate it will cause an overflow. This means
+        #[doc = concat!(" that such code will wrap to `", $n, "::MIN` without a panic.")]
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```rune
+        /// assert_eq!(10.abs(), 10);
+        /// assert_eq!((-10).abs(), 10);
+        /// ```
+        #[rune::function(instance)]
+        #[inline]
+        fn abs(this: $ty) -> $ty {
+            <$ty>::wrapping_abs(this)
+        }
+
+        /// Saturating absolute value. Computes `self.abs()`, returning `MAX` if `self
+        /// == MIN` instead of overflowing.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```rune
+        /// assert_eq!(100.saturating_abs(), 100);
+        /// assert_eq!((-100).saturating_abs(), 100);
+        #[doc = concat!(" assert_eq!(", $n, "::MIN.saturating_abs(), ", $n, "::MAX);")]
+        #[doc = concat!(" assert_eq!((", $n, "::MIN + 1).saturating_abs(), ", $n, "::MAX);")]
+        /// ```
+        #[rune::function(instance)]
+        #[inline]
+        fn saturating_abs(this: $ty) -> $ty {
+            <$ty>::saturating_abs(this)
+        }
+
+        /// Returns a number representing sign of `self`.
+        ///
+        /// - `0` if the number is zero
+        /// - `1` if the number is positive
+        /// - `-1` if the number is negative
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```rune
+        /// assert_eq!(10.signum(), 1);
+        /// assert_eq!(0.signum(), 0);
+        /// assert_eq!((-10).signum(), -1);
+        /// ```
+        #[rune::function(instance)]
+        #[inline]
+        fn signum(this: $ty) -> $ty {
+            <$ty>::signum(this)
+        }
+
+        /// Returns `true` if `self` is positive and `false` if the number is zero or
+        /// negative.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```rune
+        /// assert!(10.is_positive());
+        /// assert!(!(-10).is_positive());
+        /// ```
+        #[rune::function(instance)]
+        #[inline]
+        fn is_positive(this: $ty) -> bool {
+            <$ty>::is_positive(this)
+        }
+
+        /// Returns `true` if `self` is negative and `false` if the number is zero or
+        /// positive.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```rune
+        /// assert!((-10).is_negative());
+        /// assert!(!10.is_negative());
+        /// ```
+        #[rune::function(instance)]
+        #[inline]
+        fn is_negative(this: $ty) -> bool {
+            <$ty>::is_negative(this)
+        }
+    }
+}
+
+macro_rules! sized {
+    ($m:ident, $ty:ty) => {
+        sized!($m, $ty, stringify!($ty));
+    };
+
+    ($m:ident, $ty:ty, $n:expr) => {
+        $m.function_meta(parse)?;
+        $m.function_meta(try_from)?;
+        $m.function_meta(wrapping_from)?;
+
+        $m.function_meta(checked_add)?;
+        $m.function_meta(checked_sub)?;
+        $m.function_meta(checked_mul)?;
+        $m.function_meta(checked_div)?;
+        $m.function_meta(checked_rem)?;
+
+        $m.function_meta(wrapping_add)?;
+        $m.function_meta(wrapping_sub)?;
+        $m.function_meta(wrapping_mul)?;
+        $m.function_meta(wrapping_neg)?;
+        $m.function_meta(wrapping_shl)?;
+        $m.function_meta(wrapping_shr)?;
+
+        $m.function_meta(saturating_add)?;
+        $m.function_meta(saturating_sub)?;
+        $m.function_meta(saturating_mul)?;
+
+        $m.function_meta(overflowing_add)?;
+        $m.function_meta(overflowing_sub)?;
+        $m.function_meta(overflowing_mul)?;
+
+        $m.function_meta(count_ones)?;
+        $m.function_meta(leading_zeros)?;
+        $m.function_meta(trailing_zeros)?;
+        $m.function_meta(rotate_left)?;
+        $m.function_meta(rotate_right)?;
+        $m.function_meta(swap_bytes)?;
+        $m.function_meta(reverse_bits)?;
+
+        $m.function_meta(to_be_bytes)?;
+        $m.function_meta(to_le_bytes)?;
+        $m.function_meta(from_be_bytes)?;
+        $m.function_meta(from_le_bytes)?;
+
+        $m.constant("MIN", <$ty>::MIN).build()?.docs(docstring! {
+            /// The smallest value that can be represented by this integer type.
+            ///
+            /// # Examples
+            ///
+            /// ```rune
+            #[doc = concat!(" assert_eq!(", $n, "::try_from(", $n, "::MIN), Some(", $n, "::MIN));")]
+            /// ```
+        })?;
+
+        $m.constant("MAX", <$ty>::MAX).build()?.docs(docstring! {
+            /// The largest value that can be represented by this integer type.
+            ///
+            /// # Examples
+            ///
+            /// ```rune
+            #[doc = concat!(" assert_eq!(", $n, "::try_from(", $n, "::MAX), Some(", $n, "::MAX));")]
+            /// ```
+        })?;
+
+        $m.constant("BITS", <$ty>::BITS).build()?.docs(docstring! {
+            /// The size of this integer type in bits.
+            ///
+            /// # Examples
+            ///
+            /// ```rune
+            #[doc = concat!(" assert_eq!(", $n, "::to_be_bytes(0).len(), ", $n, "::BITS / 8);")]
+            /// ```
+        })?;
+    };
+}
+
+/// Functions operating over integers of an exact width.
+///
+/// Since the value model only stores 64-bit integers, these are associated
+/// functions which convert their arguments to the exact width, reporting an
+/// error if they are out of bounds, and which perform the operation in that
+/// width.
+macro_rules! sized_fns {
+    ($ty:ty) => {
+        sized_fns!($ty, stringify!($ty));
+    };
+
+    ($ty:ty, $n:expr) => {
+        #[doc = concat!(" Parse an `", $n, "`.")]
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(", $n, "::parse(\"10\")?, 10);")]
+        #[doc = concat!(" assert!(", $n, "::parse(\"100000000000\").is_err());")]
+        /// ```
+        #[rune::function]
+        fn parse(s: &str) -> Result<$ty, ParseIntError> {
+            str::parse::<$ty>(s)
+        }
+
+        #[doc = concat!(" Try to convert an integer into an `", $n, "`, returning `None` if")]
+        /// it's out of bounds.
+        ///
+        /// Passing a value which is not an integer is an error.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(", $n, "::try_from(100), Some(100));")]
+        #[doc = concat!(" assert_eq!(", $n, "::try_from(1 << 40), None);")]
+        /// ```
+        #[rune::function]
+        #[inline]
+        fn try_from(value: Value) -> VmResult<Option<$ty>> {
+            let value = vm_try!(value.as_integer::<i128>());
+            VmResult::Ok(<$ty>::try_from(value).ok())
+        }
+
+        #[doc = concat!(" Convert an integer into an `", $n, "`, truncating any bits which")]
+        /// doesn't fit.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(", $n, "::wrapping_from(100), 100);")]
+        #[doc = concat!(" assert_eq!(", $n, "::wrapping_from(1 << 40), 0);")]
+        /// ```
+        #[rune::function]
+        #[inline]
+        fn wrapping_from(value: Value) -> VmResult<$ty> {
+            VmResult::Ok(vm_try!(value.as_integer::<i128>()) as $ty)
+        }
+
+        /// Checked integer addition. Computes `a + b`, returning `None` if
+        /// overflow occurred.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(", $n, "::checked_add(", $n, "::MAX - 1, 1), Some(", $n, "::MAX));")]
+        #[doc = concat!(" assert_eq!(", $n, "::checked_add(", $n, "::MAX, 1), None);")]
+        /// ```
+        #[rune::function]
+        #[inline]
+        fn checked_add(a: $ty, b: $ty) -> Option<$ty> {
+            <$ty>::checked_add(a, b)
+        }
+
+        /// Checked integer subtraction. Computes `a - b`, returning `None` if
+        /// overflow occurred.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(", $n, "::checked_sub(", $n, "::MIN + 1, 1), Some(", $n, "::MIN));")]
+        #[doc = concat!(" assert_eq!(", $n, "::checked_sub(", $n, "::MIN, 1), None);")]
+        /// ```
+        #[rune::function]
+        #[inline]
+        fn checked_sub(a: $ty, b: $ty) -> Option<$ty> {
+            <$ty>::checked_sub(a, b)
+        }
+
+        /// Checked integer multiplication. Computes `a * b`, returning `None` if
+        /// overflow occurred.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(", $n, "::checked_mul(", $n, "::MAX, 1), Some(", $n, "::MAX));")]
+        #[doc = concat!(" assert_eq!(", $n, "::checked_mul(", $n, "::MAX, 2), None);")]
+        /// ```
+        #[rune::function]
+        #[inline]
+        fn checked_mul(a: $ty, b: $ty) -> Option<$ty> {
+            <$ty>::checked_mul(a, b)
+        }
+
+        /// Checked integer division. Computes `a / b`, returning `None` if
+        /// `b == 0` or the division results in overflow.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(", $n, "::checked_div(100, 2), Some(50));")]
+        #[doc = concat!(" assert_eq!(", $n, "::checked_div(1, 0), None);")]
+        /// ```
+        #[rune::function]
+        #[inline]
+        fn checked_div(a: $ty, b: $ty) -> Option<$ty> {
+            <$ty>::checked_div(a, b)
+        }
+
+        /// Checked integer remainder. Computes `a % b`, returning `None` if
+        /// `b == 0` or the division results in overflow.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(", $n, "::checked_rem(5, 2), Some(1));")]
+        #[doc = concat!(" assert_eq!(", $n, "::checked_rem(5, 0), None);")]
+        /// ```
+        #[rune::function]
+        #[inline]
+        fn checked_rem(a: $ty, b: $ty) -> Option<$ty> {
+            <$ty>::checked_rem(a, b)
+        }
+
+        /// Wrapping (modular) addition. Computes `a + b`, wrapping around at the
+        /// boundary of the type.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(", $n, "::wrapping_add(100, 27), 127);")]
+        #[doc = concat!(" assert_eq!(", $n, "::wrapping_add(", $n, "::MAX, 1), ", $n, "::MIN);")]
+        /// ```
+        #[rune::function]
+        #[inline]
+        fn wrapping_add(a: $ty, b: $ty) -> $ty {
+            <$ty>::wrapping_add(a, b)
+        }
+
+        /// Wrapping (modular) subtraction. Computes `a - b`, wrapping around at
+        /// the boundary of the type.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(", $n, "::wrapping_sub(100, 27), 73);")]
+        #[doc = concat!(" assert_eq!(", $n, "::wrapping_sub(", $n, "::MIN, 1), ", $n, "::MAX);")]
+        /// ```
+        #[rune::function]
+        #[inline]
+        fn wrapping_sub(a: $ty, b: $ty) -> $ty {
+            <$ty>::wrapping_sub(a, b)
+        }
+
+        /// Wrapping (modular) multiplication. Computes `a * b`, wrapping around at
+        /// the boundary of the type.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(", $n, "::wrapping_mul(10, 12), 120);")]
+        #[doc = concat!(" assert_eq!(", $n, "::wrapping_mul(", $n, "::MAX, ", $n, "::MAX), 1);")]
+        /// ```
+        #[rune::function]
+        #[inline]
+        fn wrapping_mul(a: $ty, b: $ty) -> $ty {
+            <$ty>::wrapping_mul(a, b)
+        }
+
+        /// Wrapping (modular) negation. Computes `-value`, wrapping around at the
+        /// boundary of the type.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(", $n, "::wrapping_neg(0), 0);")]
+        #[doc = concat!(" assert_eq!(", $n, "::wrapping_neg(", $n, "::MAX), ", $n, "::MIN + 1);")]
+        /// ```
+        #[rune::function]
+        #[inline]
+        fn wrapping_neg(value: $ty) -> $ty {
+            <$ty>::wrapping_neg(value)
+        }
+
+        /// Panic-free bitwise shift-left; yields `value << mask(rhs)`, where
+        /// `mask` removes any high-order bits of `rhs` that would cause the shift
+        /// to exceed the bitwidth of the type.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(", $n, "::wrapping_shl(1, 3), 8);")]
+        #[doc = concat!(" assert_eq!(", $n, "::wrapping_shl(1, ", $n, "::BITS), 1);")]
+        /// ```
+        #[rune::function]
+        #[inline]
+        fn wrapping_shl(value: $ty, rhs: u32) -> $ty {
+            <$ty>::wrapping_shl(value, rhs)
+        }
+
+        /// Panic-free bitwise shift-right; yields `value >> mask(rhs)`, where
+        /// `mask` removes any high-order bits of `rhs` that would cause the shift
+        /// to exceed the bitwidth of the type.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(", $n, "::wrapping_shr(8, 3), 1);")]
+        #[doc = concat!(" assert_eq!(", $n, "::wrapping_shr(8, ", $n, "::BITS), 8);")]
+        /// ```
+        #[rune::function]
+        #[inline]
+        fn wrapping_shr(value: $ty, rhs: u32) -> $ty {
+            <$ty>::wrapping_shr(value, rhs)
+        }
+
+        /// Saturating integer addition. Computes `a + b`, saturating at the
+        /// numeric bounds instead of overflowing.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(", $n, "::saturating_add(100, 1), 101);")]
+        #[doc = concat!(" assert_eq!(", $n, "::saturating_add(", $n, "::MAX, 1), ", $n, "::MAX);")]
+        /// ```
+        #[rune::function]
+        #[inline]
+        fn saturating_add(a: $ty, b: $ty) -> $ty {
+            <$ty>::saturating_add(a, b)
+        }
+
+        /// Saturating integer subtraction. Computes `a - b`, saturating at the
+        /// numeric bounds instead of overflowing.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(", $n, "::saturating_sub(100, 1), 99);")]
+        #[doc = concat!(" assert_eq!(", $n, "::saturating_sub(", $n, "::MIN, 1), ", $n, "::MIN);")]
+        /// ```
+        #[rune::function]
+        #[inline]
+        fn saturating_sub(a: $ty, b: $ty) -> $ty {
+            <$ty>::saturating_sub(a, b)
+        }
+
+        /// Saturating integer multiplication. Computes `a * b`, saturating at the
+        /// numeric bounds instead of overflowing.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(", $n, "::saturating_mul(10, 12), 120);")]
+        #[doc = concat!(" assert_eq!(", $n, "::saturating_mul(", $n, "::MAX, 2), ", $n, "::MAX);")]
+        /// ```
+        #[rune::function]
+        #[inline]
+        fn saturating_mul(a: $ty, b: $ty) -> $ty {
+            <$ty>::saturating_mul(a, b)
+        }
+
+        /// Calculates `a + b`.
+        ///
+        /// Returns a tuple of the addition along with a boolean indicating whether
+        /// an arithmetic overflow would occur. If an overflow would have occurred
+        /// then the wrapped value is returned.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(", $n, "::overflowing_add(5, 2), (7, false));")]
+        #[doc = concat!(" assert_eq!(", $n, "::overflowing_add(", $n, "::MAX, 1), (", $n, "::MIN, true));")]
+        /// ```
+        #[rune::function]
+        #[inline]
+        fn overflowing_add(a: $ty, b: $ty) -> ($ty, bool) {
+            <$ty>::overflowing_add(a, b)
+        }
+
+        /// Calculates `a - b`.
+        ///
+        /// Returns a tuple of the subtraction along with a boolean indicating
+        /// whether an arithmetic overflow would occur. If an overflow would have
+        /// occurred then the wrapped value is returned.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(", $n, "::overflowing_sub(5, 2), (3, false));")]
+        #[doc = concat!(" assert_eq!(", $n, "::overflowing_sub(", $n, "::MIN, 1), (", $n, "::MAX, true));")]
+        /// ```
+        #[rune::function]
+        #[inline]
+        fn overflowing_sub(a: $ty, b: $ty) -> ($ty, bool) {
+            <$ty>::overflowing_sub(a, b)
+        }
+
+        /// Calculates the multiplication of `a` and `b`.
+        ///
+        /// Returns a tuple of the multiplication along with a boolean indicating
+        /// whether an arithmetic overflow would occur. If an overflow would have
+        /// occurred then the wrapped value is returned.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(", $n, "::overflowing_mul(5, 2), (10, false));")]
+        #[doc = concat!(" assert_eq!(", $n, "::overflowing_mul(", $n, "::MAX, ", $n, "::MAX), (1, true));")]
+        /// ```
+        #[rune::function]
+        #[inline]
+        fn overflowing_mul(a: $ty, b: $ty) -> ($ty, bool) {
+            <$ty>::overflowing_mul(a, b)
+        }
+
+        /// Returns the number of ones in the binary representation of `value`.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(", $n, "::count_ones(0b01001100), 3);")]
+        /// ```
+        #[rune::function]
+        #[inline]
+        fn count_ones(value: $ty) -> u32 {
+            <$ty>::count_ones(value)
+        }
+
+        /// Returns the number of leading zeros in the binary representation of
+        /// `value`.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(", $n, "::leading_zeros(1), ", $n, "::BITS - 1);")]
+        #[doc = concat!(" assert_eq!(", $n, "::leading_zeros(0), ", $n, "::BITS);")]
+        /// ```
+        #[rune::function]
+        #[inline]
+        fn leading_zeros(value: $ty) -> u32 {
+            <$ty>::leading_zeros(value)
+        }
+
+        /// Returns the number of trailing zeros in the binary representation of
+        /// `value`.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(", $n, "::trailing_zeros(0b0101000), 3);")]
+        /// ```
+        #[rune::function]
+        #[inline]
+        fn trailing_zeros(value: $ty) -> u32 {
+            <$ty>::trailing_zeros(value)
+        }
+
+        /// Shifts the bits to the left by a specified amount, `n`, wrapping the
+        /// truncated bits to the end of the resulting integer.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(", $n, "::rotate_left(0b1, 3), 0b1000);")]
+        #[doc = concat!(" assert_eq!(", $n, "::rotate_left(1, ", $n, "::BITS), 1);")]
+        /// ```
+        #[rune::function]
+        #[inline]
+        fn rotate_left(value: $ty, n: u32) -> $ty {
+            <$ty>::rotate_left(value, n)
+        }
+
+        /// Shifts the bits to the right by a specified amount, `n`, wrapping the
+        /// truncated bits to the beginning of the resulting integer.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(", $n, "::rotate_right(0b1000, 3), 0b1);")]
+        #[doc = concat!(" assert_eq!(", $n, "::rotate_right(1, 1), ", $n, "::rotate_left(1, ", $n, "::BITS - 1));")]
+        /// ```
+        #[rune::function]
+        #[inline]
+        fn rotate_right(value: $ty, n: u32) -> $ty {
+            <$ty>::rotate_right(value, n)
+        }
+
+        /// Reverses the byte order of the integer.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" let value = ", $n, "::swap_bytes(0x12);")]
+        #[doc = concat!(" assert_eq!(", $n, "::swap_bytes(value), 0x12);")]
+        /// ```
+        #[rune::function]
+        #[inline]
+        fn swap_bytes(value: $ty) -> $ty {
+            <$ty>::swap_bytes(value)
+        }
+
+        /// Reverses the order of bits in the integer.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" assert_eq!(", $n, "::reverse_bits(1), ", $n, "::rotate_right(1, 1));")]
+        /// ```
+        #[rune::function]
+        #[inline]
+        fn reverse_bits(value: $ty) -> $ty {
+            <$ty>::reverse_bits(value)
+        }
+
+        /// Return the memory representation of this integer as a byte array in
+        /// big-endian (network) byte order.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" let bytes = ", $n, "::to_be_bytes(0x12);")]
+        #[doc = concat!(" assert_eq!(bytes.len(), ", $n, "::BITS / 8);")]
+        /// assert_eq!(bytes.last(), Some(0x12));
+        /// ```
+        #[rune::function]
+        #[inline]
+        fn to_be_bytes(value: $ty) -> VmResult<Bytes> {
+            VmResult::Ok(vm_try!(Bytes::from_slice(<$ty>::to_be_bytes(value))))
+        }
+
+        /// Return the memory representation of this integer as a byte array in
+        /// little-endian byte order.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" let bytes = ", $n, "::to_le_bytes(0x12);")]
+        #[doc = concat!(" assert_eq!(bytes.len(), ", $n, "::BITS / 8);")]
+        /// assert_eq!(bytes.into_vec()[0], 0x12);
+        /// ```
+        #[rune::function]
+        #[inline]
+        fn to_le_bytes(value: $ty) -> VmResult<Bytes> {
+            VmResult::Ok(vm_try!(Bytes::from_slice(<$ty>::to_le_bytes(value))))
+        }
+
+        /// Create an integer value from its representation as a byte array in
+        /// big endian.
+        ///
+        /// Returns `None` if the number of bytes doesn't match the size of the
+        /// integer.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" let bytes = ", $n, "::to_be_bytes(0x12);")]
+        #[doc = concat!(" assert_eq!(", $n, "::from_be_bytes(bytes), Some(0x12));")]
+        #[doc = concat!(" assert_eq!(", $n, "::from_be_bytes(b\"\"), None);")]
+        /// ```
+        #[rune::function]
+        #[inline]
+        fn from_be_bytes(bytes: &[u8]) -> Option<$ty> {
+            Some(<$ty>::from_be_bytes(bytes.try_into().ok()?))
+        }
+
+        /// Create an integer value from its representation as a byte array in
+        /// little endian.
+        ///
+        /// Returns `None` if the number of bytes doesn't match the size of the
+        /// integer.
+        ///
+        /// # Examples
+        ///
+        /// ```rune
+        #[doc = concat!(" let bytes = ", $n, "::to_le_bytes(0x12);")]
+        #[doc = concat!(" assert_eq!(", $n, "::from_le_bytes(bytes), Some(0x12));")]
+        #[doc = concat!(" assert_eq!(", $n, "::from_le_bytes(b\"\"), None);")]
+        /// ```
+        #[rune::function]
+        #[inline]
+        fn from_le_bytes(bytes: &[u8]) -> Option<$ty> {
+            Some(<$ty>::from_le_bytes(bytes.try_into().ok()?))
+        }
+    };
 }
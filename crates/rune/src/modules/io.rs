@@ -1,16 +1,26 @@
 //! I/O functions.
 
 #[cfg(feature = "std")]
-use std::io::{self, Write as _};
+use std::io::{self, BufRead as _, Read as _, Write as _};
+
+#[cfg(all(feature = "std", feature = "capture-io"))]
+use ::rust_alloc::sync::Arc;
+
+#[cfg(all(feature = "std", feature = "capture-io"))]
+use parking_lot::Mutex;
 
 use crate as rune;
 #[cfg(feature = "std")]
 use crate::alloc::fmt::TryWrite;
+#[cfg(all(feature = "std", feature = "capture-io"))]
+use crate::alloc::Vec;
 use crate::compile;
 use crate::macros::{quote, FormatArgs, MacroContext, TokenStream};
 use crate::parse::Parser;
 #[cfg(feature = "std")]
-use crate::runtime::{Formatter, InstAddress, Memory, Output, Panic, VmResult};
+use crate::runtime::{Bytes, Formatter, InstAddress, Memory, Output, Panic, VmResult};
+#[cfg(feature = "std")]
+use crate::Any;
 use crate::{ContextError, Module};
 
 /// I/O functions.
@@ -34,6 +44,10 @@ pub fn module(
         /// Their definitions can be omitted from the built-in standard library, and
         /// can then easily be defined by third party modules allowing for printing
         /// to be hooked up to whatever system you want.
+        ///
+        /// The same applies to the [eprint()] and [eprintln()] functions which
+        /// write to standard error, and to the [stdin()], [stdout()] and
+        /// [stderr()] functions which provide handles to the standard streams.
     })?;
 
     #[cfg(feature = "std")]
@@ -43,10 +57,37 @@ pub fn module(
     #[cfg(feature = "std")]
     module.function_meta(io_error_debug_fmt)?;
 
+    #[cfg(feature = "std")]
+    {
+        module.ty::<Stdin>()?;
+        module.function_meta(Stdin::read_line__meta)?;
+        module.function_meta(Stdin::read_to_string__meta)?;
+        module.function_meta(Stdin::lines__meta)?;
+
+        module.ty::<Lines>()?;
+        module.function_meta(Lines::next__meta)?;
+        module.implement_trait::<Lines>(rune::item!(::std::iter::Iterator))?;
+
+        module.ty::<Stdout>()?;
+        module.function_meta(Stdout::write_str__meta)?;
+        module.function_meta(Stdout::write_bytes__meta)?;
+        module.function_meta(Stdout::flush__meta)?;
+
+        module.ty::<Stderr>()?;
+        module.function_meta(Stderr::write_str__meta)?;
+        module.function_meta(Stderr::write_bytes__meta)?;
+        module.function_meta(Stderr::flush__meta)?;
+    }
+
     #[cfg(feature = "std")]
     if stdio {
         module.function_meta(print_impl)?;
         module.function_meta(println_impl)?;
+        module.function_meta(eprint_impl)?;
+        module.function_meta(eprintln_impl)?;
+        module.function_meta(stdin_impl)?;
+        module.function_meta(stdout_impl)?;
+        module.function_meta(stderr_impl)?;
 
         module
             .raw_function("dbg", dbg_impl)
@@ -77,6 +118,8 @@ pub fn module(
     module.macro_meta(dbg_macro)?;
     module.macro_meta(print_macro)?;
     module.macro_meta(println_macro)?;
+    module.macro_meta(eprint_macro)?;
+    module.macro_meta(eprintln_macro)?;
     Ok(module)
 }
 
@@ -224,3 +267,448 @@ fn println_impl(message: &str) -> VmResult<()> {
 
     VmResult::Ok(())
 }
+
+/// Prints to standard error.
+///
+/// Output printing is performed by calling the [`eprint()`] function, this is
+/// just a convenience wrapper around it which allows for formatting.
+///
+/// # Examples
+///
+/// ```rune
+/// let who = "World";
+/// eprint!("Hello {}!", who);
+/// ```
+#[rune::macro_(path = eprint)]
+pub(crate) fn eprint_macro(
+    cx: &mut MacroContext<'_, '_, '_>,
+    stream: &TokenStream,
+) -> compile::Result<TokenStream> {
+    let mut p = Parser::from_token_stream(stream, cx.input_span());
+    let args = p.parse_all::<FormatArgs>()?;
+    let expanded = args.expand(cx)?;
+    Ok(quote!(::std::io::eprint(#expanded)).into_token_stream(cx)?)
+}
+
+/// Prints to standard error.
+///
+/// This is the actual output hook, and if you install rune modules without
+/// `I/O` enabled this will not be defined. It is then up to someone else to
+/// provide an implementation.
+///
+/// See also the [`eprint!`] macro.
+///
+/// # Examples
+///
+/// ```rune
+/// eprint("Hi!");
+/// ```
+#[rune::function(path = eprint)]
+#[cfg(feature = "std")]
+fn eprint_impl(m: &str) -> VmResult<()> {
+    let stderr = io::stderr();
+    let mut stderr = stderr.lock();
+
+    if let Err(error) = write!(stderr, "{}", m) {
+        return VmResult::err(Panic::custom(error));
+    }
+
+    VmResult::Ok(())
+}
+
+/// Prints to standard error, with a newline.
+///
+/// Output printing is performed by calling the [`eprintln()`] function, this
+/// is just a convenience wrapper around it which allows for formatting.
+///
+/// # Examples
+///
+/// ```rune
+/// let who = "World";
+/// eprintln!("Hello {}!", who);
+/// ```
+#[rune::macro_(path = eprintln)]
+pub(crate) fn eprintln_macro(
+    cx: &mut MacroContext<'_, '_, '_>,
+    stream: &TokenStream,
+) -> compile::Result<TokenStream> {
+    let mut p = Parser::from_token_stream(stream, cx.input_span());
+    let args = p.parse_all::<FormatArgs>()?;
+    let expanded = args.expand(cx)?;
+    Ok(quote!(::std::io::eprintln(#expanded)).into_token_stream(cx)?)
+}
+
+/// Prints to standard error, with a newline.
+///
+/// This is the actual output hook, and if you install rune modules without
+/// `I/O` enabled this will not be defined. It is then up to someone else to
+/// provide an implementation.
+///
+/// # Examples
+///
+/// ```rune
+/// eprintln("Hi!");
+/// ```
+#[rune::function(path = eprintln)]
+#[cfg(feature = "std")]
+fn eprintln_impl(message: &str) -> VmResult<()> {
+    let stderr = io::stderr();
+    let mut stderr = stderr.lock();
+
+    if let Err(error) = writeln!(stderr, "{}", message) {
+        return VmResult::err(Panic::custom(error));
+    }
+
+    VmResult::Ok(())
+}
+
+/// Constructs a handle to the standard input of the current process.
+///
+/// This is the actual input hook, and if you install rune modules without
+/// `I/O` enabled this will not be defined. It is then up to someone else to
+/// provide an implementation.
+///
+/// # Examples
+///
+/// ```rune
+/// use std::io;
+///
+/// let stdin = io::stdin();
+///
+/// for line in stdin.lines() {
+///     println!("got: {}", line?);
+/// }
+/// ```
+#[rune::function(path = stdin)]
+#[cfg(feature = "std")]
+fn stdin_impl() -> Stdin {
+    Stdin::new(Input::Stdin)
+}
+
+/// Constructs a handle to the standard output of the current process.
+///
+/// This is the actual output hook, and if you install rune modules without
+/// `I/O` enabled this will not be defined. It is then up to someone else to
+/// provide an implementation.
+///
+/// # Examples
+///
+/// ```rune
+/// use std::io;
+///
+/// let stdout = io::stdout();
+/// stdout.write_bytes(b"Hello World\n")?;
+/// stdout.flush()?;
+/// ```
+#[rune::function(path = stdout)]
+#[cfg(feature = "std")]
+fn stdout_impl() -> Stdout {
+    Stdout::new(Sink::Stdout)
+}
+
+/// Constructs a handle to the standard error of the current process.
+///
+/// This is the actual output hook, and if you install rune modules without
+/// `I/O` enabled this will not be defined. It is then up to someone else to
+/// provide an implementation.
+///
+/// # Examples
+///
+/// ```rune
+/// use std::io;
+///
+/// let stderr = io::stderr();
+/// stderr.write_str("Something went wrong\n")?;
+/// ```
+#[rune::function(path = stderr)]
+#[cfg(feature = "std")]
+fn stderr_impl() -> Stderr {
+    Stderr::new(Sink::Stderr)
+}
+
+/// Where input is read from.
+#[cfg(feature = "std")]
+#[derive(Clone)]
+pub(crate) enum Input {
+    /// Read from the standard input of the process.
+    Stdin,
+    /// Read from a buffer provided by the host.
+    #[cfg(feature = "capture-io")]
+    Buffer(Arc<Mutex<Vec<u8>>>),
+    /// Input which is always empty.
+    #[cfg(feature = "disable-io")]
+    Empty,
+}
+
+#[cfg(feature = "std")]
+impl Input {
+    fn read_line(&self, buf: &mut ::rust_alloc::string::String) -> io::Result<usize> {
+        match self {
+            Input::Stdin => io::stdin().lock().read_line(buf),
+            #[cfg(feature = "capture-io")]
+            Input::Buffer(input) => {
+                let mut input = input.lock();
+
+                let n = match input.iter().position(|&b| b == b'\n') {
+                    Some(n) => n + 1,
+                    None => input.len(),
+                };
+
+                let line = core::str::from_utf8(&input[..n])
+                    .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+
+                buf.push_str(line);
+                input.drain(..n);
+                Ok(n)
+            }
+            #[cfg(feature = "disable-io")]
+            Input::Empty => Ok(0),
+        }
+    }
+
+    fn read_to_string(&self, buf: &mut ::rust_alloc::string::String) -> io::Result<usize> {
+        match self {
+            Input::Stdin => io::stdin().lock().read_to_string(buf),
+            #[cfg(feature = "capture-io")]
+            Input::Buffer(input) => {
+                let mut input = input.lock();
+
+                let string = core::str::from_utf8(&input)
+                    .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+
+                buf.push_str(string);
+                let n = input.len();
+                input.clear();
+                Ok(n)
+            }
+            #[cfg(feature = "disable-io")]
+            Input::Empty => Ok(0),
+        }
+    }
+}
+
+/// Where output is written to.
+#[cfg(feature = "std")]
+pub(crate) enum Sink {
+    /// Write to the standard output of the process.
+    Stdout,
+    /// Write to the standard error of the process.
+    Stderr,
+    /// Write to a buffer provided by the host.
+    #[cfg(feature = "capture-io")]
+    Buffer(Arc<Mutex<Vec<u8>>>),
+    /// Discard all output.
+    #[cfg(feature = "disable-io")]
+    Discard,
+}
+
+#[cfg(feature = "std")]
+impl Sink {
+    fn write_all(&self, bytes: &[u8]) -> io::Result<()> {
+        match self {
+            Sink::Stdout => io::stdout().lock().write_all(bytes),
+            Sink::Stderr => io::stderr().lock().write_all(bytes),
+            #[cfg(feature = "capture-io")]
+            Sink::Buffer(output) => output
+                .lock()
+                .try_extend_from_slice(bytes)
+                .map_err(io::Error::other),
+            #[cfg(feature = "disable-io")]
+            Sink::Discard => Ok(()),
+        }
+    }
+
+    fn flush(&self) -> io::Result<()> {
+        match self {
+            Sink::Stdout => io::stdout().lock().flush(),
+            Sink::Stderr => io::stderr().lock().flush(),
+            #[cfg(feature = "capture-io")]
+            Sink::Buffer(..) => Ok(()),
+            #[cfg(feature = "disable-io")]
+            Sink::Discard => Ok(()),
+        }
+    }
+}
+
+/// A handle to the standard input stream of a process.
+///
+/// Constructed through the [`stdin()`] function.
+#[derive(Any)]
+#[rune(item = ::std::io)]
+#[cfg(feature = "std")]
+pub(crate) struct Stdin {
+    input: Input,
+}
+
+#[cfg(feature = "std")]
+impl Stdin {
+    pub(crate) fn new(input: Input) -> Self {
+        Self { input }
+    }
+
+    /// Reads a line of input, including the line terminator.
+    ///
+    /// Returns an empty string once the end of input has been reached.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::io;
+    ///
+    /// let line = io::stdin().read_line()?;
+    ///
+    /// if line.is_empty() {
+    ///     println!("No more input");
+    /// }
+    /// ```
+    #[rune::function(keep, instance)]
+    fn read_line(&self) -> io::Result<::rust_alloc::string::String> {
+        let mut line = ::rust_alloc::string::String::new();
+        self.input.read_line(&mut line)?;
+        Ok(line)
+    }
+
+    /// Reads all remaining input into a string.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::io;
+    ///
+    /// let input = io::stdin().read_to_string()?;
+    /// println!("Read {} bytes", input.len());
+    /// ```
+    #[rune::function(keep, instance)]
+    fn read_to_string(&self) -> io::Result<::rust_alloc::string::String> {
+        let mut string = ::rust_alloc::string::String::new();
+        self.input.read_to_string(&mut string)?;
+        Ok(string)
+    }
+
+    /// Consumes this handle and returns an iterator over the lines of input.
+    ///
+    /// Line terminators are not included in the lines returned by the
+    /// iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::io;
+    ///
+    /// let count = 0;
+    ///
+    /// for line in io::stdin().lines() {
+    ///     let line = line?;
+    ///     count += 1;
+    /// }
+    ///
+    /// println!("Read {count} lines");
+    /// ```
+    #[rune::function(keep, instance)]
+    fn lines(self) -> Lines {
+        Lines { input: self.input }
+    }
+}
+
+/// An iterator over the lines of standard input.
+///
+/// Constructed through [`Stdin::lines`].
+#[derive(Any)]
+#[rune(item = ::std::io)]
+#[cfg(feature = "std")]
+pub(crate) struct Lines {
+    input: Input,
+}
+
+#[cfg(feature = "std")]
+impl Lines {
+    #[rune::function(keep, protocol = NEXT)]
+    fn next(&mut self) -> Option<io::Result<::rust_alloc::string::String>> {
+        let mut line = ::rust_alloc::string::String::new();
+
+        match self.input.read_line(&mut line) {
+            Ok(0) => None,
+            Ok(..) => {
+                if line.ends_with('\n') {
+                    line.pop();
+
+                    if line.ends_with('\r') {
+                        line.pop();
+                    }
+                }
+
+                Some(Ok(line))
+            }
+            Err(error) => Some(Err(error)),
+        }
+    }
+}
+
+macro_rules! output_stream {
+    ($name:ident, $stream:literal, $function:literal) => {
+        #[doc = concat!("A handle to the ", $stream, " stream of a process.")]
+        ///
+        #[doc = concat!("Constructed through the [`", $function, "()`] function.")]
+        #[derive(Any)]
+        #[rune(item = ::std::io)]
+        #[cfg(feature = "std")]
+        pub(crate) struct $name {
+            sink: Sink,
+        }
+
+        #[cfg(feature = "std")]
+        impl $name {
+            pub(crate) fn new(sink: Sink) -> Self {
+                Self { sink }
+            }
+
+            #[doc = concat!("Writes a string to ", $stream, ".")]
+            ///
+            /// # Examples
+            ///
+            /// ```rune
+            /// use std::io;
+            ///
+            #[doc = concat!("io::", $function, "().write_str(\"Hello World\\n\")?;")]
+            /// ```
+            #[rune::function(keep, instance)]
+            fn write_str(&self, string: &str) -> io::Result<()> {
+                self.sink.write_all(string.as_bytes())
+            }
+
+            #[doc = concat!("Writes bytes to ", $stream, ".")]
+            ///
+            /// # Examples
+            ///
+            /// ```rune
+            /// use std::io;
+            ///
+            #[doc = concat!("io::", $function, "().write_bytes(b\"Hello World\\n\")?;")]
+            /// ```
+            #[rune::function(keep, instance)]
+            fn write_bytes(&self, bytes: &Bytes) -> io::Result<()> {
+                self.sink.write_all(bytes.as_slice())
+            }
+
+            #[doc = concat!("Flushes ", $stream, ", ensuring that all buffered output")]
+            /// reaches its destination.
+            ///
+            /// # Examples
+            ///
+            /// ```rune
+            /// use std::io;
+            ///
+            #[doc = concat!("let out = io::", $function, "();")]
+            /// out.write_str("Progress: 50%")?;
+            /// out.flush()?;
+            /// ```
+            #[rune::function(keep, instance)]
+            fn flush(&self) -> io::Result<()> {
+                self.sink.flush()
+            }
+        }
+    };
+}
+
+output_stream!(Stdout, "standard output", "stdout");
+output_stream!(Stderr, "standard error", "stderr");
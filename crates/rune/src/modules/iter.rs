@@ -105,6 +105,86 @@ pub fn module() -> Result<Module, ContextError> {
     m.implement_trait::<Take>(rune::item!(::std::iter::DoubleEndedIterator))?;
     m.implement_trait::<Take>(rune::item!(::std::iter::ExactSizeIterator))?;
 
+    m.ty::<Zip>()?;
+    m.function_meta(Zip::next__meta)?;
+    m.function_meta(Zip::next_back__meta)?;
+    m.function_meta(Zip::size_hint__meta)?;
+    m.function_meta(Zip::len__meta)?;
+    m.implement_trait::<Zip>(rune::item!(::std::iter::Iterator))?;
+    m.implement_trait::<Zip>(rune::item!(::std::iter::DoubleEndedIterator))?;
+    m.implement_trait::<Zip>(rune::item!(::std::iter::ExactSizeIterator))?;
+
+    m.ty::<StepBy>()?;
+    m.function_meta(StepBy::next__meta)?;
+    m.function_meta(StepBy::next_back__meta)?;
+    m.function_meta(StepBy::size_hint__meta)?;
+    m.function_meta(StepBy::len__meta)?;
+    m.implement_trait::<StepBy>(rune::item!(::std::iter::Iterator))?;
+    m.implement_trait::<StepBy>(rune::item!(::std::iter::DoubleEndedIterator))?;
+    m.implement_trait::<StepBy>(rune::item!(::std::iter::ExactSizeIterator))?;
+
+    m.ty::<TakeWhile>()?;
+    m.function_meta(TakeWhile::next__meta)?;
+    m.function_meta(TakeWhile::size_hint__meta)?;
+    m.implement_trait::<TakeWhile>(rune::item!(::std::iter::Iterator))?;
+
+    m.ty::<SkipWhile>()?;
+    m.function_meta(SkipWhile::next__meta)?;
+    m.function_meta(SkipWhile::size_hint__meta)?;
+    m.implement_trait::<SkipWhile>(rune::item!(::std::iter::Iterator))?;
+
+    m.ty::<MapWhile>()?;
+    m.function_meta(MapWhile::next__meta)?;
+    m.function_meta(MapWhile::size_hint__meta)?;
+    m.implement_trait::<MapWhile>(rune::item!(::std::iter::Iterator))?;
+
+    m.ty::<Scan>()?;
+    m.function_meta(Scan::next__meta)?;
+    m.function_meta(Scan::size_hint__meta)?;
+    m.implement_trait::<Scan>(rune::item!(::std::iter::Iterator))?;
+
+    m.ty::<Inspect>()?;
+    m.function_meta(Inspect::next__meta)?;
+    m.function_meta(Inspect::next_back__meta)?;
+    m.function_meta(Inspect::size_hint__meta)?;
+    m.function_meta(Inspect::len__meta)?;
+    m.implement_trait::<Inspect>(rune::item!(::std::iter::Iterator))?;
+    m.implement_trait::<Inspect>(rune::item!(::std::iter::DoubleEndedIterator))?;
+    m.implement_trait::<Inspect>(rune::item!(::std::iter::ExactSizeIterator))?;
+
+    m.ty::<Cycle>()?;
+    m.function_meta(Cycle::next__meta)?;
+    m.function_meta(Cycle::size_hint__meta)?;
+    m.implement_trait::<Cycle>(rune::item!(::std::iter::Iterator))?;
+
+    m.ty::<Windows>()?;
+    m.function_meta(Windows::next__meta)?;
+    m.function_meta(Windows::size_hint__meta)?;
+    m.function_meta(Windows::len__meta)?;
+    m.implement_trait::<Windows>(rune::item!(::std::iter::Iterator))?;
+    m.implement_trait::<Windows>(rune::item!(::std::iter::ExactSizeIterator))?;
+
+    m.ty::<Chunks>()?;
+    m.function_meta(Chunks::next__meta)?;
+    m.function_meta(Chunks::next_back__meta)?;
+    m.function_meta(Chunks::size_hint__meta)?;
+    m.function_meta(Chunks::len__meta)?;
+    m.implement_trait::<Chunks>(rune::item!(::std::iter::Iterator))?;
+    m.implement_trait::<Chunks>(rune::item!(::std::iter::DoubleEndedIterator))?;
+    m.implement_trait::<Chunks>(rune::item!(::std::iter::ExactSizeIterator))?;
+
+    m.ty::<Flatten>()?;
+    m.function_meta(Flatten::next__meta)?;
+    m.function_meta(Flatten::next_back__meta)?;
+    m.function_meta(Flatten::size_hint__meta)?;
+    m.implement_trait::<Flatten>(rune::item!(::std::iter::Iterator))?;
+    m.implement_trait::<Flatten>(rune::item!(::std::iter::DoubleEndedIterator))?;
+
+    m.ty::<Dedup>()?;
+    m.function_meta(Dedup::next__meta)?;
+    m.function_meta(Dedup::size_hint__meta)?;
+    m.implement_trait::<Dedup>(rune::item!(::std::iter::Iterator))?;
+
     {
         let mut t = m.define_trait(["ExactSizeIterator"])?;
 
@@ -351,6 +431,185 @@ pub fn module() -> Result<Module, ContextError> {
                 })?;
                 cx.function("skip", move |iter: Value, n: usize| Skip { iter, n })?;
                 cx.function("take", move |iter: Value, n: usize| Take { iter, n })?;
+                cx.function("zip", |a: Value, b: Value| {
+                    let b = vm_try!(b.protocol_into_iter());
+                    VmResult::Ok(Zip { a, b })
+                })?;
+                cx.function("step_by", |iter: Value, step: usize| {
+                    if step == 0 {
+                        return VmResult::panic("Step must be non-zero");
+                    }
+
+                    VmResult::Ok(StepBy {
+                        iter,
+                        step: step - 1,
+                        first_take: true,
+                    })
+                })?;
+                cx.function("take_while", |iter: Value, f: Function| TakeWhile {
+                    iter,
+                    f,
+                    done: false,
+                })?;
+                cx.function("skip_while", |iter: Value, f: Function| SkipWhile {
+                    iter,
+                    f,
+                    done: false,
+                })?;
+                cx.function("map_while", |iter: Value, f: Function| MapWhile { iter, f })?;
+                cx.function("scan", |iter: Value, state: Value, f: Function| Scan {
+                    iter,
+                    state,
+                    f,
+                })?;
+                cx.function("inspect", |iter: Value, f: Function| Inspect { iter, f })?;
+                cx.function("cycle", |iter: Value| Cycle {
+                    iter: Some(iter),
+                    buffer: alloc::Vec::new(),
+                    index: 0,
+                })?;
+                cx.function("windows", |iter: Value, size: usize| {
+                    if size == 0 {
+                        return VmResult::panic("Window size must be non-zero");
+                    }
+
+                    VmResult::Ok(Windows {
+                        iter,
+                        size,
+                        window: alloc::VecDeque::new(),
+                    })
+                })?;
+                cx.function("chunks", |iter: Value, size: usize| {
+                    if size == 0 {
+                        return VmResult::panic("Chunk size must be non-zero");
+                    }
+
+                    VmResult::Ok(Chunks { iter, size })
+                })?;
+                cx.function("flatten", |iter: Value| Flatten {
+                    iter: Some(iter),
+                    frontiter: None,
+                    backiter: None,
+                })?;
+                cx.function("dedup", |iter: Value| Dedup {
+                    iter,
+                    pending: None,
+                })?;
+            }
+
+            {
+                let next = next.clone();
+
+                cx.function("position", move |iter: Value, f: Function| {
+                    let mut index = 0usize;
+
+                    loop {
+                        let Some(value) = vm_try!(next.call((iter.clone(),))) else {
+                            break VmResult::Ok(None);
+                        };
+
+                        if vm_try!(f.call::<bool>((value,))) {
+                            break VmResult::Ok(Some(index));
+                        }
+
+                        index += 1;
+                    }
+                })?;
+            }
+
+            {
+                let next = next.clone();
+
+                cx.function("last", move |iter: Value| {
+                    let mut last = None;
+
+                    while let Some(value) = vm_try!(next.call((iter.clone(),))) {
+                        last = Some(value);
+                    }
+
+                    VmResult::Ok(last)
+                })?;
+            }
+
+            {
+                let next = next.clone();
+
+                cx.function("min_by_key", move |iter: Value, f: Function| {
+                    let Some(mut min) = vm_try!(next.call((iter.clone(),))) else {
+                        return VmResult::Ok(None);
+                    };
+
+                    let mut min_key = vm_try!(f.call::<Value>((min.clone(),)));
+
+                    while let Some(value) = vm_try!(next.call((iter.clone(),))) {
+                        let key = vm_try!(f.call::<Value>((value.clone(),)));
+
+                        if vm_try!(Value::cmp(&key, &min_key)).is_lt() {
+                            min = value;
+                            min_key = key;
+                        }
+                    }
+
+                    VmResult::Ok(Some(min))
+                })?;
+            }
+
+            {
+                let next = next.clone();
+
+                cx.function("max_by_key", move |iter: Value, f: Function| {
+                    let Some(mut max) = vm_try!(next.call((iter.clone(),))) else {
+                        return VmResult::Ok(None);
+                    };
+
+                    let mut max_key = vm_try!(f.call::<Value>((max.clone(),)));
+
+                    while let Some(value) = vm_try!(next.call((iter.clone(),))) {
+                        let key = vm_try!(f.call::<Value>((value.clone(),)));
+
+                        if vm_try!(Value::cmp(&key, &max_key)).is_ge() {
+                            max = value;
+                            max_key = key;
+                        }
+                    }
+
+                    VmResult::Ok(Some(max))
+                })?;
+            }
+
+            {
+                let next = next.clone();
+
+                cx.function("partition", move |iter: Value, f: Function| {
+                    let mut left = Vec::new();
+                    let mut right = Vec::new();
+
+                    while let Some(value) = vm_try!(next.call((iter.clone(),))) {
+                        if vm_try!(f.call::<bool>((value.clone(),))) {
+                            vm_try!(left.push(value));
+                        } else {
+                            vm_try!(right.push(value));
+                        }
+                    }
+
+                    VmResult::Ok((left, right))
+                })?;
+            }
+
+            {
+                let next = next.with_return::<Option<(Value, Value)>>();
+
+                cx.function("unzip", move |iter: Value| {
+                    let mut left = Vec::new();
+                    let mut right = Vec::new();
+
+                    while let Some((a, b)) = vm_try!(next.call((iter.clone(),))) {
+                        vm_try!(left.push(a));
+                        vm_try!(right.push(b));
+                    }
+
+                    VmResult::Ok((left, right))
+                })?;
             }
 
             {
@@ -1360,429 +1619,920 @@ pub fn module() -> Result<Module, ContextError> {
                 /// ```
             })?;
 
-        macro_rules! sum_ops {
-            ($ty:ty) => {
-                t.function(Params::new("sum", [<$ty>::HASH]))?
-                    .argument_types::<(Value,)>()?
-                    .return_type::<$ty>()?
-                    .docs(docstring! {
-                        /// Sums the elements of an iterator.
-                        ///
-                        /// Takes each element, adds them together, and returns
-                        /// the result.
-                        ///
-                        /// An empty iterator returns the zero value of the
-                        /// type.
-                        ///
-                        /// `sum()` can be used to sum numerical built-in types,
-                        /// such as `i64`, `float` and `u64`. The first element
-                        /// returned by the iterator determines the type being
-                        /// summed.
-                        ///
-                        /// # Panics
-                        ///
-                        /// When calling `sum()` and a primitive integer type is
-                        /// being returned, this method will panic if the
-                        /// computation overflows.
-                        ///
-                        /// # Examples
-                        ///
-                        /// Basic usage:
-                        ///
-                        /// ```rune
-                        #[doc = concat!(" let a = [1", stringify!($ty), ", 2", stringify!($ty), ", 3", stringify!($ty), "];")]
-                        #[doc = concat!(" let sum = a.iter().sum::<", stringify!($ty), ">();")]
-                        ///
-                        #[doc = concat!(" assert_eq!(sum, 6", stringify!($ty), ");")]
-                        /// ```
-                    })?;
-            };
-        }
-
-        sum_ops!(u64);
-        sum_ops!(i64);
-        sum_ops!(f64);
-
-        macro_rules! integer_product_ops {
-            ($ty:ty) => {
-                t.function(Params::new("product", [<$ty>::HASH]))?
-                    .argument_types::<(Value,)>()?
-                    .return_type::<$ty>()?
-                    .docs(docstring! {
-                        /// Iterates over the entire iterator, multiplying all
-                        /// the elements
-                        ///
-                        /// An empty iterator returns the one value of the type.
-                        ///
-                        /// `sum()` can be used to sum numerical built-in types,
-                        /// such as `i64`, `f64` and `u64`. The first element
-                        /// returned by the iterator determines the type being
-                        /// multiplied.
-                        ///
-                        /// # Panics
-                        ///
-                        /// When calling `product()` and a primitive integer
-                        /// type is being returned, method will panic if the
-                        /// computation overflows.
-                        ///
-                        /// # Examples
-                        ///
-                        /// ```rune
-                        /// fn factorial(n) {
-                        #[doc = concat!("     (1", stringify!($ty), "..=n).iter().product::<", stringify!($ty), ">()")]
-                        /// }
-                        ///
-                        #[doc = concat!(" assert_eq!(factorial(0", stringify!($ty), "), 1", stringify!($ty), ");")]
-                        #[doc = concat!(" assert_eq!(factorial(1", stringify!($ty), "), 1", stringify!($ty), ");")]
-                        #[doc = concat!(" assert_eq!(factorial(5", stringify!($ty), "), 120", stringify!($ty), ");")]
-                        /// ```
-                    })?;
-            };
-        }
-
-        t.function(Params::new("collect", [Vec::HASH]))?
-            .return_type::<Vec>()?
+        t.function("zip")?
+            .argument_types::<(Value, Value)>()?
+            .return_type::<Zip>()?
             .docs(docstring! {
-                /// Collect the iterator as a [`Vec`].
+                /// 'Zips up' two iterators into a single iterator of pairs.
+                ///
+                /// `zip()` returns a new iterator that will iterate over two
+                /// other iterators, returning a tuple where the first element
+                /// comes from the first iterator, and the second element comes
+                /// from the second iterator.
+                ///
+                /// If either iterator returns [`None`], [`next`] from the
+                /// zipped iterator will return [`None`].
+                ///
+                /// Since the argument to `zip()` uses [`INTO_ITER`], we can
+                /// pass anything that can be converted into an [`Iterator`],
+                /// not just an [`Iterator`] itself.
+                ///
+                /// [`next`]: Iterator::next
+                /// [`INTO_ITER`]: protocol@INTO_ITER
                 ///
                 /// # Examples
                 ///
+                /// Basic usage:
+                ///
                 /// ```rune
-                /// use std::iter::range;
+                /// let a1 = [1, 2, 3];
+                /// let a2 = [4, 5, 6];
                 ///
-                /// assert_eq!((0..3).iter().collect::<Vec>(), [0, 1, 2]);
+                /// let iter = a1.iter().zip(a2);
+                ///
+                /// assert_eq!(iter.next(), Some((1, 4)));
+                /// assert_eq!(iter.next(), Some((2, 5)));
+                /// assert_eq!(iter.next(), Some((3, 6)));
+                /// assert_eq!(iter.next(), None);
                 /// ```
-            })?;
-
-        t.function(Params::new("collect", [VecDeque::HASH]))?
-            .return_type::<VecDeque>()?
-            .docs(docstring! {
-                /// Collect the iterator as a [`VecDeque`].
                 ///
-                /// # Examples
+                /// Zipping double-ended iterators of different lengths from
+                /// the back lines up their ends:
                 ///
                 /// ```rune
-                /// use std::collections::VecDeque;
+                /// let iter = [1, 2, 3].iter().zip([4, 5]);
                 ///
-                /// assert_eq!((0..3).iter().collect::<VecDeque>(), VecDeque::from::<Vec>([0, 1, 2]));
+                /// assert_eq!(iter.len(), 2);
+                /// assert_eq!(iter.next_back(), Some((2, 5)));
+                /// assert_eq!(iter.next_back(), Some((1, 4)));
+                /// assert_eq!(iter.next_back(), None);
                 /// ```
             })?;
 
-        t.function(Params::new("collect", [HashSet::HASH]))?
-            .return_type::<HashSet>()?
+        t.function("step_by")?
+            .argument_types::<(Value, usize)>()?
+            .return_type::<StepBy>()?
             .docs(docstring! {
-                /// Collect the iterator as a [`HashSet`].
+                /// Creates an iterator starting at the same point, but
+                /// stepping by the given amount at each iteration.
+                ///
+                /// Note that the first element of the iterator will always be
+                /// returned, regardless of the step given.
+                ///
+                /// # Panics
+                ///
+                /// The method will panic if the given step is `0`.
                 ///
                 /// # Examples
                 ///
                 /// ```rune
-                /// use std::collections::HashSet;
+                /// let a = [0, 1, 2, 3, 4, 5];
+                /// let iter = a.iter().step_by(2);
                 ///
-                /// let a = (0..3).iter().collect::<HashSet>();
-                /// let b = HashSet::from_iter([0, 1, 2]);
+                /// assert_eq!(iter.next(), Some(0));
+                /// assert_eq!(iter.next(), Some(2));
+                /// assert_eq!(iter.next(), Some(4));
+                /// assert_eq!(iter.next(), None);
                 ///
-                /// assert_eq!(a, b);
+                /// assert_eq!((0..10).iter().step_by(3).rev().collect::<Vec>(), [9, 6, 3, 0]);
                 /// ```
             })?;
 
-        t.function(Params::new("collect", [HashMap::HASH]))?
-            .return_type::<HashMap>()?
+        t.function("take_while")?
+            .argument_types::<(Value, Function)>()?
+            .return_type::<TakeWhile>()?
             .docs(docstring! {
-                /// Collect the iterator as a [`HashMap`].
+                /// Creates an iterator that yields elements based on a
+                /// predicate.
+                ///
+                /// `take_while()` takes a closure as an argument. It will call
+                /// this closure on each element of the iterator, and yield
+                /// elements while it returns `true`.
+                ///
+                /// After `false` is returned, `take_while()`'s job is over,
+                /// and the rest of the elements are ignored.
                 ///
                 /// # Examples
                 ///
                 /// ```rune
-                /// use std::collections::HashMap;
-                ///
-                /// let actual = (0..3).iter().map(|n| (n, n.to_string())).collect::<HashMap>();
+                /// let a = [-1, 0, 1, -2];
                 ///
-                /// let expected = HashMap::from_iter([
-                ///     (0, "0"),
-                ///     (1, "1"),
-                ///     (2, "2"),
-                /// ]);
+                /// let iter = a.iter().take_while(|x| x < 0);
                 ///
-                /// assert_eq!(actual, expected);
+                /// assert_eq!(iter.next(), Some(-1));
+                /// assert_eq!(iter.next(), None);
                 /// ```
             })?;
 
-        t.function(Params::new("collect", [Object::HASH]))?
-            .return_type::<HashMap>()?
+        t.function("skip_while")?
+            .argument_types::<(Value, Function)>()?
+            .return_type::<SkipWhile>()?
             .docs(docstring! {
-                /// Collect the iterator as an [`Object`].
+                /// Creates an iterator that skips elements based on a
+                /// predicate.
+                ///
+                /// `skip_while()` takes a closure as an argument. It will call
+                /// this closure on each element of the iterator, and ignore
+                /// elements until it returns `false`.
+                ///
+                /// After `false` is returned, `skip_while()`'s job is over,
+                /// and the rest of the elements are yielded.
                 ///
                 /// # Examples
                 ///
                 /// ```rune
-                /// assert_eq!([("first", 1), ("second", 2)].iter().collect::<Object>(), #{first: 1, second: 2});
+                /// let a = [-1, 0, 1, -2];
+                ///
+                /// let iter = a.iter().skip_while(|x| x < 0);
+                ///
+                /// assert_eq!(iter.next(), Some(0));
+                /// assert_eq!(iter.next(), Some(1));
+                /// assert_eq!(iter.next(), Some(-2));
+                /// assert_eq!(iter.next(), None);
                 /// ```
             })?;
 
-        t.function(Params::new("collect", [OwnedTuple::HASH]))?
-            .return_type::<OwnedTuple>()?
+        t.function("map_while")?
+            .argument_types::<(Value, Function)>()?
+            .return_type::<MapWhile>()?
             .docs(docstring! {
-                /// Collect the iterator as a [`Tuple`].
+                /// Creates an iterator that both yields elements based on a
+                /// predicate and maps.
+                ///
+                /// `map_while()` takes a closure as an argument. It will call
+                /// this closure on each element of the iterator, and yield
+                /// elements while it returns [`Some(_)`][`Some`].
                 ///
                 /// # Examples
                 ///
                 /// ```rune
-                /// assert_eq!((0..3).iter().collect::<Tuple>(), (0, 1, 2));
+                /// let a = ["1", "2", "three", "4"];
+                ///
+                /// let iter = a.iter().map_while(|s| s.parse::<i64>().ok());
+                ///
+                /// assert_eq!(iter.next(), Some(1));
+                /// assert_eq!(iter.next(), Some(2));
+                /// assert_eq!(iter.next(), None);
                 /// ```
             })?;
 
-        t.function(Params::new("collect", [String::HASH]))?
-            .return_type::<String>()?
+        t.function("scan")?
+            .argument_types::<(Value, Value, Function)>()?
+            .return_type::<Scan>()?
             .docs(docstring! {
-                /// Collect the iterator as a [`String`].
+                /// An iterator adapter which, like [`fold`], holds internal
+                /// state, but unlike [`fold`], produces a new iterator.
+                ///
+                /// `scan()` takes two arguments: an initial value which seeds
+                /// the internal state, and a closure with two arguments, the
+                /// current state and an element from the iterator.
+                ///
+                /// The closure returns `Some((state, value))` to update the
+                /// state and yield `value`, or [`None`] to stop iteration.
+                ///
+                /// [`fold`]: Iterator::fold
                 ///
                 /// # Examples
                 ///
                 /// ```rune
-                /// assert_eq!(["first", "second"].iter().collect::<String>(), "firstsecond");
+                /// let a = [1, 2, 3, 4];
+                ///
+                /// let iter = a.iter().scan(1, |state, x| {
+                ///     let state = state * x;
+                ///
+                ///     if state > 6 {
+                ///         return None;
+                ///     }
+                ///
+                ///     Some((state, -state))
+                /// });
+                ///
+                /// assert_eq!(iter.next(), Some(-1));
+                /// assert_eq!(iter.next(), Some(-2));
+                /// assert_eq!(iter.next(), Some(-6));
+                /// assert_eq!(iter.next(), None);
                 /// ```
             })?;
 
-        macro_rules! float_product_ops {
-            ($ty:ty) => {
-                t.function(Params::new("product", [<$ty>::HASH]))?
-                    .argument_types::<(Value,)>()?
-                    .return_type::<$ty>()?
-                    .docs(docstring! {
-                        /// Iterates over the entire iterator, multiplying all
-                        /// the elements
-                        ///
-                        /// An empty iterator returns the one value of the type.
-                        ///
-                        /// `sum()` can be used to sum numerical built-in types,
-                        /// such as `i64`, `f64` and `u64`. The first element
-                        /// returned by the iterator determines the type being
-                        /// multiplied.
-                        ///
-                        /// # Panics
-                        ///
-                        /// When calling `product()` and a primitive integer
-                        /// type is being returned, method will panic if the
-                        /// computation overflows.
-                        ///
-                        /// # Examples
-                        ///
-                        /// ```rune
-                        /// fn factorial(n) {
-                        #[doc = concat!("     (1..=n).iter().map(|n| n as ", stringify!($ty), ").product::<", stringify!($ty), ">()")]
-                        /// }
-                        ///
-                        #[doc = concat!(" assert_eq!(factorial(0), 1", stringify!($ty), ");")]
-                        #[doc = concat!(" assert_eq!(factorial(1), 1", stringify!($ty), ");")]
-                        #[doc = concat!(" assert_eq!(factorial(5), 120", stringify!($ty), ");")]
-                        /// ```
-                    })?;
-            };
-        }
-
-        integer_product_ops!(u64);
-        integer_product_ops!(i64);
-        float_product_ops!(f64);
-    }
-
-    {
-        let mut t = m.define_trait(["DoubleEndedIterator"])?;
-
-        t.docs(docstring! {
-            /// An iterator able to yield elements from both ends.
-            ///
-            /// Something that implements `DoubleEndedIterator` has one extra
-            /// capability over something that implements [`Iterator`]: the
-            /// ability to also take `Item`s from the back, as well as the
-            /// front.
-            ///
-            /// It is important to note that both back and forth work on the
-            /// same range, and do not cross: iteration is over when they meet
-            /// in the middle.
-            ///
-            /// In a similar fashion to the [`Iterator`] protocol, once a
-            /// `DoubleEndedIterator` returns [`None`] from a [`next_back()`],
-            /// calling it again may or may not ever return [`Some`] again.
-            /// [`next()`] and [`next_back()`] are interchangeable for this
-            /// purpose.
-            ///
-            /// [`next_back()`]: DoubleEndedIterator::next_back
-            /// [`next()`]: Iterator::next
-            ///
-            /// # Examples
-            ///
-            /// Basic usage:
-            ///
-            /// ```
-            /// let numbers = [1, 2, 3, 4, 5, 6];
-            ///
-            /// let iter = numbers.iter();
-            ///
-            /// assert_eq!(Some(1), iter.next());
-            /// assert_eq!(Some(6), iter.next_back());
-            /// assert_eq!(Some(5), iter.next_back());
-            /// assert_eq!(Some(2), iter.next());
-            /// assert_eq!(Some(3), iter.next());
-            /// assert_eq!(Some(4), iter.next());
-            /// assert_eq!(None, iter.next());
-            /// assert_eq!(None, iter.next_back());
-            /// ```
-        })?;
-
-        t.handler(|cx| {
-            let next_back = cx.find(&Protocol::NEXT_BACK)?;
-
-            cx.find_or_define(&Protocol::NTH_BACK, {
-                let next_back = next_back.clone();
-
-                move |iterator: Value, mut n: usize| loop {
-                    let mut memory = [iterator.clone()];
-
-                    vm_try!(next_back(
-                        &mut memory,
-                        InstAddress::ZERO,
-                        1,
-                        Output::keep(0)
-                    ));
-                    let [value] = memory;
-
-                    let Some(value) = vm_try!(Option::<Value>::from_value(value)) else {
-                        break VmResult::Ok(None);
-                    };
-
-                    if n == 0 {
-                        break VmResult::Ok(Some(value));
-                    }
-
-                    n -= 1;
-                }
-            })?;
-
-            cx.raw_function("rev", |stack, addr, len, out| {
-                let [value] = vm_try!(stack.slice_at(addr, len)) else {
-                    return VmResult::err(VmErrorKind::BadArgumentCount {
-                        actual: len,
-                        expected: 1,
-                    });
-                };
-
-                let rev = Rev {
-                    value: value.clone(),
-                };
-
-                vm_try!(out.store(stack, || rune::to_value(rev)));
-                VmResult::Ok(())
+        t.function("inspect")?
+            .argument_types::<(Value, Function)>()?
+            .return_type::<Inspect>()?
+            .docs(docstring! {
+                /// Does something with each element of an iterator, passing
+                /// the value on.
+                ///
+                /// When using iterators, you'll often chain several of them
+                /// together. While working on such code, you might want to
+                /// check out what's happening at various parts in the
+                /// pipeline. To do that, insert a call to `inspect()`.
+                ///
+                /// # Examples
+                ///
+                /// ```rune
+                /// let seen = [];
+                ///
+                /// let sum = [1, 4, 2, 3].iter()
+                ///     .inspect(|x| seen.push(x))
+                ///     .filter(|x| x % 2 == 0)
+                ///     .sum::<i64>();
+                ///
+                /// assert_eq!(sum, 6);
+                /// assert_eq!(seen, [1, 4, 2, 3]);
+                /// ```
             })?;
 
-            Ok(())
-        })?;
-
-        t.function("next_back")?
+        t.function("cycle")?
             .argument_types::<(Value,)>()?
-            .return_type::<Option<Value>>()?
+            .return_type::<Cycle>()?
             .docs(docstring! {
-                /// Removes and returns an element from the end of the iterator.
+                /// Repeats an iterator endlessly.
                 ///
-                /// Returns `None` when there are no more elements.
+                /// Instead of stopping at [`None`], the iterator will instead
+                /// start again, from the beginning. After iterating again, it
+                /// will start at the beginning again. And again. And again.
+                /// Forever. Note that in case the original iterator is empty,
+                /// the resulting iterator will also be empty.
                 ///
-                /// # Examples
+                /// Elements are remembered as they are produced by the
+                /// original iterator, so it doesn't need to be cloneable.
                 ///
-                /// Basic usage:
+                /// # Examples
                 ///
                 /// ```rune
-                /// let numbers = [1, 2, 3, 4, 5, 6];
+                /// let a = [1, 2, 3];
                 ///
-                /// let iter = numbers.iter();
+                /// let it = a.iter().cycle();
                 ///
-                /// assert_eq!(Some(1), iter.next());
-                /// assert_eq!(Some(6), iter.next_back());
-                /// assert_eq!(Some(5), iter.next_back());
-                /// assert_eq!(Some(2), iter.next());
-                /// assert_eq!(Some(3), iter.next());
-                /// assert_eq!(Some(4), iter.next());
-                /// assert_eq!(None, iter.next());
-                /// assert_eq!(None, iter.next_back());
+                /// assert_eq!(it.next(), Some(1));
+                /// assert_eq!(it.next(), Some(2));
+                /// assert_eq!(it.next(), Some(3));
+                /// assert_eq!(it.next(), Some(1));
+                /// assert_eq!(it.next(), Some(2));
+                /// assert_eq!(it.next(), Some(3));
+                /// assert_eq!(it.next(), Some(1));
                 /// ```
             })?;
 
-        t.function("nth_back")?
+        t.function("windows")?
             .argument_types::<(Value, usize)>()?
-            .return_type::<Option<Value>>()?
+            .return_type::<Windows>()?
             .docs(docstring! {
-                /// Returns the `n`th element from the end of the iterator.
-                ///
-                /// This is essentially the reversed version of
-                /// [`Iterator::nth()`]. Although like most indexing operations,
-                /// the count starts from zero, so `nth_back(0)` returns the
-                /// first value from the end, `nth_back(1)` the second, and so
-                /// on.
+                /// Creates an iterator over all contiguous windows of length
+                /// `size`. The windows overlap. If the iterator is shorter than
+                /// `size`, the iterator returns no values.
                 ///
-                /// Note that all elements between the end and the returned
-                /// element will be consumed, including the returned element.
-                /// This also means that calling `nth_back(0)` multiple times on
-                /// the same iterator will return different elements.
+                /// # Panics
                 ///
-                /// `nth_back()` will return [`None`] if `n` is greater than or
-                /// equal to the length of the iterator.
+                /// Panics if `size` is 0.
                 ///
                 /// # Examples
                 ///
-                /// Basic usage:
-                ///
                 /// ```rune
-                /// let a = [1, 2, 3];
-                /// assert_eq!(a.iter().nth_back(2), Some(1));
+                /// let iter = ['l', 'o', 'r', 'e', 'm'].iter().windows(3);
+                ///
+                /// assert_eq!(iter.next(), Some(['l', 'o', 'r']));
+                /// assert_eq!(iter.next(), Some(['o', 'r', 'e']));
+                /// assert_eq!(iter.next(), Some(['r', 'e', 'm']));
+                /// assert_eq!(iter.next(), None);
                 /// ```
+            })?;
+
+        t.function("chunks")?
+            .argument_types::<(Value, usize)>()?
+            .return_type::<Chunks>()?
+            .docs(docstring! {
+                /// Creates an iterator over `size` elements of the iterator at
+                /// a time, starting at the beginning.
                 ///
-                /// Calling `nth_back()` multiple times doesn't rewind the
-                /// iterator:
+                /// The chunks are vectors and do not overlap. If `size` does
+                /// not divide the length of the iterator, then the last chunk
+                /// will not have length `size`.
                 ///
-                /// ```rune
-                /// let a = [1, 2, 3];
+                /// # Panics
                 ///
-                /// let iter = a.iter();
+                /// Panics if `size` is 0.
                 ///
-                /// assert_eq!(iter.nth_back(1), Some(2));
-                /// assert_eq!(iter.nth_back(1), None);
+                /// # Examples
+                ///
+                /// ```rune
+                /// let iter = ['l', 'o', 'r', 'e', 'm'].iter().chunks(2);
+                ///
+                /// assert_eq!(iter.next(), Some(['l', 'o']));
+                /// assert_eq!(iter.next(), Some(['r', 'e']));
+                /// assert_eq!(iter.next(), Some(['m']));
+                /// assert_eq!(iter.next(), None);
                 /// ```
                 ///
-                /// Returning `None` if there are less than `n + 1` elements:
+                /// Iterating from the back yields the remainder first:
                 ///
                 /// ```rune
-                /// let a = [1, 2, 3];
-                /// assert_eq!(a.iter().nth_back(10), None);
+                /// let iter = [1, 2, 3, 4, 5].iter().chunks(2);
+                ///
+                /// assert_eq!(iter.next_back(), Some([5]));
+                /// assert_eq!(iter.next_back(), Some([3, 4]));
                 /// ```
             })?;
 
-        t.function("rev")?
+        t.function("flatten")?
             .argument_types::<(Value,)>()?
-            .return_type::<Rev>()?
+            .return_type::<Flatten>()?
             .docs(docstring! {
-                /// Reverses an iterator's direction.
+                /// Creates an iterator that flattens nested structure.
                 ///
-                /// Usually, iterators iterate from left to right. After using `rev()`, an
-                /// iterator will instead iterate from right to left.
-                ///
-                /// This is only possible if the iterator has an end, so `rev()` only works on
-                /// double-ended iterators.
+                /// This is useful when you have an iterator of iterators or an
+                /// iterator of things that can be turned into iterators and you
+                /// want to remove one level of indirection.
                 ///
                 /// # Examples
                 ///
                 /// ```rune
-                /// let a = [1, 2, 3];
+                /// let data = [[1, 2, 3, 4], [5, 6]];
+                /// let flattened = data.iter().flatten().collect::<Vec>();
+                /// assert_eq!(flattened, [1, 2, 3, 4, 5, 6]);
                 ///
-                /// let iter = a.iter().rev();
+                /// let iter = data.iter().flatten();
+                /// assert_eq!(iter.next_back(), Some(6));
+                /// ```
+            })?;
+
+        t.function("dedup")?
+            .argument_types::<(Value,)>()?
+            .return_type::<Dedup>()?
+            .docs(docstring! {
+                /// Creates an iterator which removes consecutive repeated
+                /// elements.
                 ///
-                /// assert_eq!(iter.next(), Some(3));
-                /// assert_eq!(iter.next(), Some(2));
-                /// assert_eq!(iter.next(), Some(1));
+                /// Elements are compared using the [`PARTIAL_EQ`] protocol,
+                /// and only the first element of every run of equal elements is
+                /// yielded.
                 ///
-                /// assert_eq!(iter.next(), None);
+                /// [`PARTIAL_EQ`]: protocol@PARTIAL_EQ
+                ///
+                /// # Examples
+                ///
+                /// ```rune
+                /// let a = [1, 1, 2, 3, 3, 3, 1];
+                /// assert_eq!(a.iter().dedup().collect::<Vec>(), [1, 2, 3, 1]);
                 /// ```
             })?;
-    }
-
-    m.function_meta(range)?;
+
+        t.function("position")?
+            .argument_types::<(Value, Function)>()?
+            .return_type::<Option<usize>>()?
+            .docs(docstring! {
+                /// Searches for an element in an iterator, returning its index.
+                ///
+                /// `position()` takes a closure that returns `true` or `false`.
+                /// It applies this closure to each element of the iterator, and
+                /// if one of them returns `true`, then `position()` returns
+                /// [`Some(index)`]. If all of them return `false`, it returns
+                /// [`None`].
+                ///
+                /// `position()` is short-circuiting; in other words, it will
+                /// stop processing as soon as it finds a `true`.
+                ///
+                /// [`Some(index)`]: Some
+                ///
+                /// # Examples
+                ///
+                /// ```rune
+                /// let a = [1, 2, 3];
+                ///
+                /// assert_eq!(a.iter().position(|x| x == 2), Some(1));
+                /// assert_eq!(a.iter().position(|x| x == 5), None);
+                /// ```
+            })?;
+
+        t.function("last")?
+            .argument_types::<(Value,)>()?
+            .return_type::<Option<Value>>()?
+            .docs(docstring! {
+                /// Consumes the iterator, returning the last element.
+                ///
+                /// This method will evaluate the iterator until it returns
+                /// [`None`]. While doing so, it keeps track of the current
+                /// element. After [`None`] is returned, `last()` will then
+                /// return the last element it saw.
+                ///
+                /// # Examples
+                ///
+                /// ```rune
+                /// let a = [1, 2, 3];
+                /// assert_eq!(a.iter().last(), Some(3));
+                ///
+                /// let a = [];
+                /// assert_eq!(a.iter().last(), None);
+                /// ```
+            })?;
+
+        t.function("min_by_key")?
+            .argument_types::<(Value, Function)>()?
+            .return_type::<Option<Value>>()?
+            .docs(docstring! {
+                /// Returns the element that gives the minimum value from the
+                /// specified function.
+                ///
+                /// If several elements are equally minimum, the first element
+                /// is returned. If the iterator is empty, [`None`] is returned.
+                ///
+                /// # Examples
+                ///
+                /// ```rune
+                /// let a = [-3, 0, 1, 5, -10];
+                /// assert_eq!(a.iter().min_by_key(|x| x.abs()), Some(0));
+                /// ```
+            })?;
+
+        t.function("max_by_key")?
+            .argument_types::<(Value, Function)>()?
+            .return_type::<Option<Value>>()?
+            .docs(docstring! {
+                /// Returns the element that gives the maximum value from the
+                /// specified function.
+                ///
+                /// If several elements are equally maximum, the last element is
+                /// returned. If the iterator is empty, [`None`] is returned.
+                ///
+                /// # Examples
+                ///
+                /// ```rune
+                /// let a = [-3, 0, 1, 5, -10];
+                /// assert_eq!(a.iter().max_by_key(|x| x.abs()), Some(-10));
+                /// ```
+            })?;
+
+        t.function("partition")?
+            .argument_types::<(Value, Function)>()?
+            .return_type::<(Vec, Vec)>()?
+            .docs(docstring! {
+                /// Consumes an iterator, creating two collections from it.
+                ///
+                /// The predicate passed to `partition()` can return `true`, or
+                /// `false`. `partition()` returns a pair, all of the elements
+                /// for which it returned `true`, and all of the elements for
+                /// which it returned `false`.
+                ///
+                /// # Examples
+                ///
+                /// ```rune
+                /// let a = [1, 2, 3];
+                ///
+                /// let (even, odd) = a.iter().partition(|n| n % 2 == 0);
+                ///
+                /// assert_eq!(even, [2]);
+                /// assert_eq!(odd, [1, 3]);
+                /// ```
+            })?;
+
+        t.function("unzip")?
+            .argument_types::<(Value,)>()?
+            .return_type::<(Vec, Vec)>()?
+            .docs(docstring! {
+                /// Converts an iterator of pairs into a pair of containers.
+                ///
+                /// `unzip()` consumes an entire iterator of pairs, producing
+                /// two collections: one from the left elements of the pairs,
+                /// and one from the right elements.
+                ///
+                /// This function is, in some sense, the opposite of [`zip`].
+                ///
+                /// [`zip`]: Iterator::zip
+                ///
+                /// # Examples
+                ///
+                /// ```rune
+                /// let a = [(1, 2), (3, 4), (5, 6)];
+                ///
+                /// let (left, right) = a.iter().unzip();
+                ///
+                /// assert_eq!(left, [1, 3, 5]);
+                /// assert_eq!(right, [2, 4, 6]);
+                /// ```
+            })?;
+
+        macro_rules! sum_ops {
+            ($ty:ty) => {
+                t.function(Params::new("sum", [<$ty>::HASH]))?
+                    .argument_types::<(Value,)>()?
+                    .return_type::<$ty>()?
+                    .docs(docstring! {
+                        /// Sums the elements of an iterator.
+                        ///
+                        /// Takes each element, adds them together, and returns
+                        /// the result.
+                        ///
+                        /// An empty iterator returns the zero value of the
+                        /// type.
+                        ///
+                        /// `sum()` can be used to sum numerical built-in types,
+                        /// such as `i64`, `float` and `u64`. The first element
+                        /// returned by the iterator determines the type being
+                        /// summed.
+                        ///
+                        /// # Panics
+                        ///
+                        /// When calling `sum()` and a primitive integer type is
+                        /// being returned, this method will panic if the
+                        /// computation overflows.
+                        ///
+                        /// # Examples
+                        ///
+                        /// Basic usage:
+                        ///
+                        /// ```rune
+                        #[doc = concat!(" let a = [1", stringify!($ty), ", 2", stringify!($ty), ", 3", stringify!($ty), "];")]
+                        #[doc = concat!(" let sum = a.iter().sum::<", stringify!($ty), ">();")]
+                        ///
+                        #[doc = concat!(" assert_eq!(sum, 6", stringify!($ty), ");")]
+                        /// ```
+                    })?;
+            };
+        }
+
+        sum_ops!(u64);
+        sum_ops!(i64);
+        sum_ops!(f64);
+
+        macro_rules! integer_product_ops {
+            ($ty:ty) => {
+                t.function(Params::new("product", [<$ty>::HASH]))?
+                    .argument_types::<(Value,)>()?
+                    .return_type::<$ty>()?
+                    .docs(docstring! {
+                        /// Iterates over the entire iterator, multiplying all
+                        /// the elements
+                        ///
+                        /// An empty iterator returns the one value of the type.
+                        ///
+                        /// `sum()` can be used to sum numerical built-in types,
+                        /// such as `i64`, `f64` and `u64`. The first element
+                        /// returned by the iterator determines the type being
+                        /// multiplied.
+                        ///
+                        /// # Panics
+                        ///
+                        /// When calling `product()` and a primitive integer
+                        /// type is being returned, method will panic if the
+                        /// computation overflows.
+                        ///
+                        /// # Examples
+                        ///
+                        /// ```rune
+                        /// fn factorial(n) {
+                        #[doc = concat!("     (1", stringify!($ty), "..=n).iter().product::<", stringify!($ty), ">()")]
+                        /// }
+                        ///
+                        #[doc = concat!(" assert_eq!(factorial(0", stringify!($ty), "), 1", stringify!($ty), ");")]
+                        #[doc = concat!(" assert_eq!(factorial(1", stringify!($ty), "), 1", stringify!($ty), ");")]
+                        #[doc = concat!(" assert_eq!(factorial(5", stringify!($ty), "), 120", stringify!($ty), ");")]
+                        /// ```
+                    })?;
+            };
+        }
+
+        t.function(Params::new("collect", [Vec::HASH]))?
+            .return_type::<Vec>()?
+            .docs(docstring! {
+                /// Collect the iterator as a [`Vec`].
+                ///
+                /// # Examples
+                ///
+                /// ```rune
+                /// use std::iter::range;
+                ///
+                /// assert_eq!((0..3).iter().collect::<Vec>(), [0, 1, 2]);
+                /// ```
+            })?;
+
+        t.function(Params::new("collect", [VecDeque::HASH]))?
+            .return_type::<VecDeque>()?
+            .docs(docstring! {
+                /// Collect the iterator as a [`VecDeque`].
+                ///
+                /// # Examples
+                ///
+                /// ```rune
+                /// use std::collections::VecDeque;
+                ///
+                /// assert_eq!((0..3).iter().collect::<VecDeque>(), VecDeque::from::<Vec>([0, 1, 2]));
+                /// ```
+            })?;
+
+        t.function(Params::new("collect", [HashSet::HASH]))?
+            .return_type::<HashSet>()?
+            .docs(docstring! {
+                /// Collect the iterator as a [`HashSet`].
+                ///
+                /// # Examples
+                ///
+                /// ```rune
+                /// use std::collections::HashSet;
+                ///
+                /// let a = (0..3).iter().collect::<HashSet>();
+                /// let b = HashSet::from_iter([0, 1, 2]);
+                ///
+                /// assert_eq!(a, b);
+                /// ```
+            })?;
+
+        t.function(Params::new("collect", [HashMap::HASH]))?
+            .return_type::<HashMap>()?
+            .docs(docstring! {
+                /// Collect the iterator as a [`HashMap`].
+                ///
+                /// # Examples
+                ///
+                /// ```rune
+                /// use std::collections::HashMap;
+                ///
+                /// let actual = (0..3).iter().map(|n| (n, n.to_string())).collect::<HashMap>();
+                ///
+                /// let expected = HashMap::from_iter([
+                ///     (0, "0"),
+                ///     (1, "1"),
+                ///     (2, "2"),
+                /// ]);
+                ///
+                /// assert_eq!(actual, expected);
+                /// ```
+            })?;
+
+        t.function(Params::new("collect", [Object::HASH]))?
+            .return_type::<HashMap>()?
+            .docs(docstring! {
+                /// Collect the iterator as an [`Object`].
+                ///
+                /// # Examples
+                ///
+                /// ```rune
+                /// assert_eq!([("first", 1), ("second", 2)].iter().collect::<Object>(), #{first: 1, second: 2});
+                /// ```
+            })?;
+
+        t.function(Params::new("collect", [OwnedTuple::HASH]))?
+            .return_type::<OwnedTuple>()?
+            .docs(docstring! {
+                /// Collect the iterator as a [`Tuple`].
+                ///
+                /// # Examples
+                ///
+                /// ```rune
+                /// assert_eq!((0..3).iter().collect::<Tuple>(), (0, 1, 2));
+                /// ```
+            })?;
+
+        t.function(Params::new("collect", [String::HASH]))?
+            .return_type::<String>()?
+            .docs(docstring! {
+                /// Collect the iterator as a [`String`].
+                ///
+                /// # Examples
+                ///
+                /// ```rune
+                /// assert_eq!(["first", "second"].iter().collect::<String>(), "firstsecond");
+                /// ```
+            })?;
+
+        macro_rules! float_product_ops {
+            ($ty:ty) => {
+                t.function(Params::new("product", [<$ty>::HASH]))?
+                    .argument_types::<(Value,)>()?
+                    .return_type::<$ty>()?
+                    .docs(docstring! {
+                        /// Iterates over the entire iterator, multiplying all
+                        /// the elements
+                        ///
+                        /// An empty iterator returns the one value of the type.
+                        ///
+                        /// `sum()` can be used to sum numerical built-in types,
+                        /// such as `i64`, `f64` and `u64`. The first element
+                        /// returned by the iterator determines the type being
+                        /// multiplied.
+                        ///
+                        /// # Panics
+                        ///
+                        /// When calling `product()` and a primitive integer
+                        /// type is being returned, method will panic if the
+                        /// computation overflows.
+                        ///
+                        /// # Examples
+                        ///
+                        /// ```rune
+                        /// fn factorial(n) {
+                        #[doc = concat!("     (1..=n).iter().map(|n| n as ", stringify!($ty), ").product::<", stringify!($ty), ">()")]
+                        /// }
+                        ///
+                        #[doc = concat!(" assert_eq!(factorial(0), 1", stringify!($ty), ");")]
+                        #[doc = concat!(" assert_eq!(factorial(1), 1", stringify!($ty), ");")]
+                        #[doc = concat!(" assert_eq!(factorial(5), 120", stringify!($ty), ");")]
+                        /// ```
+                    })?;
+            };
+        }
+
+        integer_product_ops!(u64);
+        integer_product_ops!(i64);
+        float_product_ops!(f64);
+    }
+
+    {
+        let mut t = m.define_trait(["DoubleEndedIterator"])?;
+
+        t.docs(docstring! {
+            /// An iterator able to yield elements from both ends.
+            ///
+            /// Something that implements `DoubleEndedIterator` has one extra
+            /// capability over something that implements [`Iterator`]: the
+            /// ability to also take `Item`s from the back, as well as the
+            /// front.
+            ///
+            /// It is important to note that both back and forth work on the
+            /// same range, and do not cross: iteration is over when they meet
+            /// in the middle.
+            ///
+            /// In a similar fashion to the [`Iterator`] protocol, once a
+            /// `DoubleEndedIterator` returns [`None`] from a [`next_back()`],
+            /// calling it again may or may not ever return [`Some`] again.
+            /// [`next()`] and [`next_back()`] are interchangeable for this
+            /// purpose.
+            ///
+            /// [`next_back()`]: DoubleEndedIterator::next_back
+            /// [`next()`]: Iterator::next
+            ///
+            /// # Examples
+            ///
+            /// Basic usage:
+            ///
+            /// ```
+            /// let numbers = [1, 2, 3, 4, 5, 6];
+            ///
+            /// let iter = numbers.iter();
+            ///
+            /// assert_eq!(Some(1), iter.next());
+            /// assert_eq!(Some(6), iter.next_back());
+            /// assert_eq!(Some(5), iter.next_back());
+            /// assert_eq!(Some(2), iter.next());
+            /// assert_eq!(Some(3), iter.next());
+            /// assert_eq!(Some(4), iter.next());
+            /// assert_eq!(None, iter.next());
+            /// assert_eq!(None, iter.next_back());
+            /// ```
+        })?;
+
+        t.handler(|cx| {
+            let next_back = cx.find(&Protocol::NEXT_BACK)?;
+
+            cx.find_or_define(&Protocol::NTH_BACK, {
+                let next_back = next_back.clone();
+
+                move |iterator: Value, mut n: usize| loop {
+                    let mut memory = [iterator.clone()];
+
+                    vm_try!(next_back(
+                        &mut memory,
+                        InstAddress::ZERO,
+                        1,
+                        Output::keep(0)
+                    ));
+                    let [value] = memory;
+
+                    let Some(value) = vm_try!(Option::<Value>::from_value(value)) else {
+                        break VmResult::Ok(None);
+                    };
+
+                    if n == 0 {
+                        break VmResult::Ok(Some(value));
+                    }
+
+                    n -= 1;
+                }
+            })?;
+
+            cx.raw_function("rev", |stack, addr, len, out| {
+                let [value] = vm_try!(stack.slice_at(addr, len)) else {
+                    return VmResult::err(VmErrorKind::BadArgumentCount {
+                        actual: len,
+                        expected: 1,
+                    });
+                };
+
+                let rev = Rev {
+                    value: value.clone(),
+                };
+
+                vm_try!(out.store(stack, || rune::to_value(rev)));
+                VmResult::Ok(())
+            })?;
+
+            Ok(())
+        })?;
+
+        t.function("next_back")?
+            .argument_types::<(Value,)>()?
+            .return_type::<Option<Value>>()?
+            .docs(docstring! {
+                /// Removes and returns an element from the end of the iterator.
+                ///
+                /// Returns `None` when there are no more elements.
+                ///
+                /// # Examples
+                ///
+                /// Basic usage:
+                ///
+                /// ```rune
+                /// let numbers = [1, 2, 3, 4, 5, 6];
+                ///
+                /// let iter = numbers.iter();
+                ///
+                /// assert_eq!(Some(1), iter.next());
+                /// assert_eq!(Some(6), iter.next_back());
+                /// assert_eq!(Some(5), iter.next_back());
+                /// assert_eq!(Some(2), iter.next());
+                /// assert_eq!(Some(3), iter.next());
+                /// assert_eq!(Some(4), iter.next());
+                /// assert_eq!(None, iter.next());
+                /// assert_eq!(None, iter.next_back());
+                /// ```
+            })?;
+
+        t.function("nth_back")?
+            .argument_types::<(Value, usize)>()?
+            .return_type::<Option<Value>>()?
+            .docs(docstring! {
+                /// Returns the `n`th element from the end of the iterator.
+                ///
+                /// This is essentially the reversed version of
+                /// [`Iterator::nth()`]. Although like most indexing operations,
+                /// the count starts from zero, so `nth_back(0)` returns the
+                /// first value from the end, `nth_back(1)` the second, and so
+                /// on.
+                ///
+                /// Note that all elements between the end and the returned
+                /// element will be consumed, including the returned element.
+                /// This also means that calling `nth_back(0)` multiple times on
+                /// the same iterator will return different elements.
+                ///
+                /// `nth_back()` will return [`None`] if `n` is greater than or
+                /// equal to the length of the iterator.
+                ///
+                /// # Examples
+                ///
+                /// Basic usage:
+                ///
+                /// ```rune
+                /// let a = [1, 2, 3];
+                /// assert_eq!(a.iter().nth_back(2), Some(1));
+                /// ```
+                ///
+                /// Calling `nth_back()` multiple times doesn't rewind the
+                /// iterator:
+                ///
+                /// ```rune
+                /// let a = [1, 2, 3];
+                ///
+                /// let iter = a.iter();
+                ///
+                /// assert_eq!(iter.nth_back(1), Some(2));
+                /// assert_eq!(iter.nth_back(1), None);
+                /// ```
+                ///
+                /// Returning `None` if there are less than `n + 1` elements:
+                ///
+                /// ```rune
+                /// let a = [1, 2, 3];
+                /// assert_eq!(a.iter().nth_back(10), None);
+                /// ```
+            })?;
+
+        t.function("rev")?
+            .argument_types::<(Value,)>()?
+            .return_type::<Rev>()?
+            .docs(docstring! {
+                /// Reverses an iterator's direction.
+                ///
+                /// Usually, iterators iterate from left to right. After using `rev()`, an
+                /// iterator will instead iterate from right to left.
+                ///
+                /// This is only possible if the iterator has an end, so `rev()` only works on
+                /// double-ended iterators.
+                ///
+                /// # Examples
+                ///
+                /// ```rune
+                /// let a = [1, 2, 3];
+                ///
+                /// let iter = a.iter().rev();
+                ///
+                /// assert_eq!(iter.next(), Some(3));
+                /// assert_eq!(iter.next(), Some(2));
+                /// assert_eq!(iter.next(), Some(1));
+                ///
+                /// assert_eq!(iter.next(), None);
+                /// ```
+            })?;
+    }
+
+    m.function_meta(range)?;
 
     m.ty::<Empty>()?;
     m.function_meta(Empty::next__meta)?;
@@ -1792,324 +2542,922 @@ pub fn module() -> Result<Module, ContextError> {
     m.implement_trait::<Empty>(rune::item!(::std::iter::DoubleEndedIterator))?;
     m.function_meta(empty)?;
 
-    m.ty::<Once>()?;
-    m.function_meta(Once::next__meta)?;
-    m.function_meta(Once::next_back__meta)?;
-    m.function_meta(Once::size_hint__meta)?;
-    m.implement_trait::<Once>(rune::item!(::std::iter::Iterator))?;
-    m.implement_trait::<Once>(rune::item!(::std::iter::DoubleEndedIterator))?;
-    m.function_meta(once)?;
-    Ok(m)
+    m.ty::<Once>()?;
+    m.function_meta(Once::next__meta)?;
+    m.function_meta(Once::next_back__meta)?;
+    m.function_meta(Once::size_hint__meta)?;
+    m.implement_trait::<Once>(rune::item!(::std::iter::Iterator))?;
+    m.implement_trait::<Once>(rune::item!(::std::iter::DoubleEndedIterator))?;
+    m.function_meta(once)?;
+    Ok(m)
+}
+
+/// Construct an iterator which produces no values.
+///
+/// # Examples
+///
+/// ```rune
+/// use std::iter::empty;
+///
+/// assert!(empty().next().is_none());
+/// assert_eq!(empty().collect::<Vec>(), []);
+/// ```
+#[rune::function]
+fn empty() -> Empty {
+    Empty
+}
+
+#[derive(Any)]
+#[rune(item = ::std::iter)]
+struct Empty;
+
+impl Empty {
+    #[rune::function(keep, protocol = NEXT)]
+    fn next(&mut self) -> Option<Value> {
+        None
+    }
+
+    #[rune::function(keep, protocol = NEXT_BACK)]
+    fn next_back(&mut self) -> Option<Value> {
+        None
+    }
+
+    #[rune::function(keep, protocol = SIZE_HINT)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(0))
+    }
+}
+
+/// Construct an iterator which produces a single `value` once.
+///
+/// # Examples
+///
+/// ```rune
+/// use std::iter::once;
+///
+/// assert!(once(42).next().is_some());
+/// assert_eq!(once(42).collect::<Vec>(), [42]);
+/// ```
+#[rune::function]
+fn once(value: Value) -> Once {
+    Once { value: Some(value) }
+}
+
+#[derive(Any)]
+#[rune(item = ::std::iter)]
+struct Once {
+    value: Option<Value>,
+}
+
+impl Once {
+    #[rune::function(keep, protocol = NEXT)]
+    fn next(&mut self) -> Option<Value> {
+        self.value.take()
+    }
+
+    #[rune::function(keep, protocol = NEXT_BACK)]
+    fn next_back(&mut self) -> Option<Value> {
+        self.value.take()
+    }
+
+    #[rune::function(keep, protocol = SIZE_HINT)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = usize::from(self.value.is_some());
+        (len, Some(len))
+    }
+}
+
+/// Produce an iterator which starts at the range `start` and ends at the value
+/// `end` (exclusive).
+///
+/// # Examples
+///
+/// ```rune
+/// use std::iter::range;
+///
+/// assert!(range(0, 3).next().is_some());
+/// assert_eq!(range(0, 3).collect::<Vec>(), [0, 1, 2]);
+/// ```
+#[rune::function(deprecated = "Use the `<from>..<to>` operator instead")]
+fn range(start: i64, end: i64) -> RangeIter<i64> {
+    RangeIter::new(start..end)
+}
+
+/// Fuse the iterator if the expression is `None`.
+macro_rules! fuse {
+    ($self:ident . $iter:ident . $($call:tt)+) => {
+        match $self.$iter {
+            Some(ref mut iter) => match vm_try!(iter.$($call)+) {
+                None => {
+                    $self.$iter = None;
+                    None
+                }
+                item => item,
+            },
+            None => None,
+        }
+    };
+}
+
+/// Try an iterator method without fusing,
+/// like an inline `.as_mut().and_then(...)`
+macro_rules! maybe {
+    ($self:ident . $iter:ident . $($call:tt)+) => {
+        match $self.$iter {
+            Some(ref mut iter) => vm_try!(iter.$($call)+),
+            None => None,
+        }
+    };
+}
+
+#[derive(Any, Debug)]
+#[rune(item = ::std::iter)]
+struct Chain {
+    a: Option<Value>,
+    b: Option<Value>,
+}
+
+impl Chain {
+    #[rune::function(keep, protocol = NEXT)]
+    #[inline]
+    fn next(&mut self) -> VmResult<Option<Value>> {
+        VmResult::Ok(match fuse!(self.a.protocol_next()) {
+            None => maybe!(self.b.protocol_next()),
+            item => item,
+        })
+    }
+
+    #[rune::function(keep, protocol = NEXT_BACK)]
+    #[inline]
+    fn next_back(&mut self) -> VmResult<Option<Value>> {
+        VmResult::Ok(match fuse!(self.b.protocol_next_back()) {
+            None => maybe!(self.a.protocol_next_back()),
+            item => item,
+        })
+    }
+
+    #[rune::function(keep, protocol = SIZE_HINT)]
+    #[inline]
+    fn size_hint(&self) -> VmResult<(usize, Option<usize>)> {
+        match self {
+            Self {
+                a: Some(a),
+                b: Some(b),
+            } => {
+                let (a_lower, a_upper) = vm_try!(a.protocol_size_hint());
+                let (b_lower, b_upper) = vm_try!(b.protocol_size_hint());
+
+                let lower = a_lower.saturating_add(b_lower);
+
+                let upper = match (a_upper, b_upper) {
+                    (Some(x), Some(y)) => x.checked_add(y),
+                    _ => None,
+                };
+
+                VmResult::Ok((lower, upper))
+            }
+            Self {
+                a: Some(a),
+                b: None,
+            } => a.protocol_size_hint(),
+            Self {
+                a: None,
+                b: Some(b),
+            } => b.protocol_size_hint(),
+            Self { a: None, b: None } => VmResult::Ok((0, Some(0))),
+        }
+    }
+
+    #[rune::function(keep, protocol = LEN)]
+    #[inline]
+    fn len(&self) -> VmResult<usize> {
+        match self {
+            Self {
+                a: Some(a),
+                b: Some(b),
+            } => {
+                let a_len = vm_try!(a.protocol_len());
+                let b_len = vm_try!(b.protocol_len());
+                VmResult::Ok(a_len.saturating_add(b_len))
+            }
+            Self {
+                a: Some(a),
+                b: None,
+            } => a.protocol_len(),
+            Self {
+                a: None,
+                b: Some(b),
+            } => b.protocol_len(),
+            Self { a: None, b: None } => VmResult::Ok(0),
+        }
+    }
+}
+
+#[derive(Any, Debug)]
+#[rune(item = ::std::iter)]
+struct Enumerate {
+    iter: Value,
+    count: usize,
+}
+
+impl Enumerate {
+    #[rune::function(keep, protocol = NEXT)]
+    #[inline]
+    fn next(&mut self) -> VmResult<Option<(usize, Value)>> {
+        if let Some(value) = vm_try!(self.iter.protocol_next()) {
+            let i = self.count;
+            self.count += 1;
+            return VmResult::Ok(Some((i, value)));
+        }
+
+        VmResult::Ok(None)
+    }
+
+    #[rune::function(keep, protocol = NEXT_BACK)]
+    #[inline]
+    fn next_back(&mut self) -> VmResult<Option<(usize, Value)>> {
+        if let Some(value) = vm_try!(self.iter.protocol_next_back()) {
+            let len = vm_try!(self.iter.protocol_len());
+            return VmResult::Ok(Some((self.count + len, value)));
+        }
+
+        VmResult::Ok(None)
+    }
+
+    #[rune::function(keep, protocol = SIZE_HINT)]
+    #[inline]
+    fn size_hint(&self) -> VmResult<(usize, Option<usize>)> {
+        self.iter.protocol_size_hint()
+    }
+
+    #[rune::function(keep, protocol = LEN)]
+    #[inline]
+    fn len(&self) -> VmResult<usize> {
+        self.iter.protocol_len()
+    }
+}
+
+#[derive(Any, Debug)]
+#[rune(item = ::std::iter)]
+struct Filter {
+    iter: Value,
+    f: Function,
+}
+
+impl Filter {
+    #[rune::function(keep, protocol = NEXT)]
+    #[inline]
+    fn next(&mut self) -> VmResult<Option<Value>> {
+        while let Some(value) = vm_try!(self.iter.protocol_next()) {
+            if vm_try!(self.f.call::<bool>((value.clone(),))) {
+                return VmResult::Ok(Some(value));
+            }
+        }
+
+        VmResult::Ok(None)
+    }
+
+    #[rune::function(keep, protocol = NEXT_BACK)]
+    #[inline]
+    fn next_back(&mut self) -> VmResult<Option<Value>> {
+        while let Some(value) = vm_try!(self.iter.protocol_next_back()) {
+            if vm_try!(self.f.call::<bool>((value.clone(),))) {
+                return VmResult::Ok(Some(value));
+            }
+        }
+
+        VmResult::Ok(None)
+    }
+
+    #[rune::function(keep, protocol = SIZE_HINT)]
+    #[inline]
+    fn size_hint(&self) -> VmResult<(usize, Option<usize>)> {
+        let (_, hi) = vm_try!(self.iter.protocol_size_hint());
+        VmResult::Ok((0, hi))
+    }
+}
+
+#[derive(Any, Debug)]
+#[rune(item = ::std::iter)]
+struct Map {
+    iter: Option<Value>,
+    f: Function,
+}
+
+impl Map {
+    #[rune::function(keep, protocol = NEXT)]
+    #[inline]
+    fn next(&mut self) -> VmResult<Option<Value>> {
+        if let Some(value) = fuse!(self.iter.protocol_next()) {
+            return VmResult::Ok(Some(vm_try!(self.f.call::<Value>((value.clone(),)))));
+        }
+
+        VmResult::Ok(None)
+    }
+
+    #[rune::function(keep, protocol = NEXT_BACK)]
+    #[inline]
+    fn next_back(&mut self) -> VmResult<Option<Value>> {
+        if let Some(value) = fuse!(self.iter.protocol_next_back()) {
+            return VmResult::Ok(Some(vm_try!(self.f.call::<Value>((value.clone(),)))));
+        }
+
+        VmResult::Ok(None)
+    }
+
+    #[rune::function(keep, protocol = SIZE_HINT)]
+    #[inline]
+    fn size_hint(&self) -> VmResult<(usize, Option<usize>)> {
+        let Some(iter) = &self.iter else {
+            return VmResult::Ok((0, Some(0)));
+        };
+
+        iter.protocol_size_hint()
+    }
+
+    #[rune::function(keep, protocol = LEN)]
+    #[inline]
+    fn len(&self) -> VmResult<usize> {
+        let Some(iter) = &self.iter else {
+            return VmResult::Ok(0);
+        };
+
+        iter.protocol_len()
+    }
+}
+
+#[derive(Any, Debug)]
+#[rune(item = ::std::iter)]
+struct FilterMap {
+    iter: Option<Value>,
+    f: Function,
+}
+
+impl FilterMap {
+    #[rune::function(keep, protocol = NEXT)]
+    #[inline]
+    fn next(&mut self) -> VmResult<Option<Value>> {
+        while let Some(value) = fuse!(self.iter.protocol_next()) {
+            if let Some(value) = vm_try!(self.f.call::<Option<Value>>((value.clone(),))) {
+                return VmResult::Ok(Some(value));
+            }
+        }
+
+        VmResult::Ok(None)
+    }
+
+    #[rune::function(keep, protocol = NEXT_BACK)]
+    #[inline]
+    fn next_back(&mut self) -> VmResult<Option<Value>> {
+        while let Some(value) = fuse!(self.iter.protocol_next_back()) {
+            if let Some(value) = vm_try!(self.f.call::<Option<Value>>((value.clone(),))) {
+                return VmResult::Ok(Some(value));
+            }
+        }
+
+        VmResult::Ok(None)
+    }
+}
+
+#[derive(Any, Debug)]
+#[rune(item = ::std::iter)]
+struct FlatMap {
+    map: Map,
+    frontiter: Option<Value>,
+    backiter: Option<Value>,
+}
+
+impl FlatMap {
+    #[rune::function(keep, protocol = NEXT)]
+    #[inline]
+    fn next(&mut self) -> VmResult<Option<Value>> {
+        loop {
+            if let Some(iter) = &mut self.frontiter {
+                match vm_try!(iter.protocol_next()) {
+                    None => self.frontiter = None,
+                    item @ Some(_) => return VmResult::Ok(item),
+                }
+            }
+
+            let Some(value) = vm_try!(self.map.next()) else {
+                return VmResult::Ok(match &mut self.backiter {
+                    Some(backiter) => vm_try!(backiter.protocol_next()),
+                    None => None,
+                });
+            };
+
+            self.frontiter = Some(vm_try!(value.protocol_into_iter()))
+        }
+    }
+
+    #[rune::function(keep, protocol = NEXT_BACK)]
+    #[inline]
+    fn next_back(&mut self) -> VmResult<Option<Value>> {
+        loop {
+            if let Some(ref mut iter) = self.backiter {
+                match vm_try!(iter.protocol_next_back()) {
+                    None => self.backiter = None,
+                    item @ Some(_) => return VmResult::Ok(item),
+                }
+            }
+
+            let Some(value) = vm_try!(self.map.next_back()) else {
+                return VmResult::Ok(match &mut self.frontiter {
+                    Some(frontiter) => vm_try!(frontiter.protocol_next_back()),
+                    None => None,
+                });
+            };
+
+            self.backiter = Some(vm_try!(value.protocol_into_iter()));
+        }
+    }
+
+    #[rune::function(keep, protocol = SIZE_HINT)]
+    #[inline]
+    fn size_hint(&self) -> VmResult<(usize, Option<usize>)> {
+        let (flo, fhi) = match &self.frontiter {
+            Some(iter) => vm_try!(iter.protocol_size_hint()),
+            None => (0, Some(0)),
+        };
+
+        let (blo, bhi) = match &self.backiter {
+            Some(iter) => vm_try!(iter.protocol_size_hint()),
+            None => (0, Some(0)),
+        };
+
+        let lo = flo.saturating_add(blo);
+
+        VmResult::Ok(match (vm_try!(self.map.size_hint()), fhi, bhi) {
+            ((0, Some(0)), Some(a), Some(b)) => (lo, a.checked_add(b)),
+            _ => (lo, None),
+        })
+    }
 }
 
-/// Construct an iterator which produces no values.
-///
-/// # Examples
-///
-/// ```rune
-/// use std::iter::empty;
-///
-/// assert!(empty().next().is_none());
-/// assert_eq!(empty().collect::<Vec>(), []);
-/// ```
-#[rune::function]
-fn empty() -> Empty {
-    Empty
+#[derive(Any, Debug)]
+#[rune(item = ::std::iter)]
+struct Peekable {
+    iter: Value,
+    peeked: Option<Option<Value>>,
+}
+
+impl Peekable {
+    #[rune::function(keep, protocol = NEXT)]
+    #[inline]
+    fn next(&mut self) -> VmResult<Option<Value>> {
+        VmResult::Ok(match self.peeked.take() {
+            Some(v) => v,
+            None => vm_try!(self.iter.protocol_next()),
+        })
+    }
+
+    #[rune::function(keep, protocol = NEXT_BACK)]
+    #[inline]
+    fn next_back(&mut self) -> VmResult<Option<Value>> {
+        VmResult::Ok(match self.peeked.as_mut() {
+            Some(v @ Some(_)) => vm_try!(self.iter.protocol_next_back()).or_else(|| v.take()),
+            Some(None) => None,
+            None => vm_try!(self.iter.protocol_next_back()),
+        })
+    }
+
+    #[rune::function(keep, protocol = SIZE_HINT)]
+    #[inline]
+    fn size_hint(&self) -> VmResult<(usize, Option<usize>)> {
+        let peek_len = match &self.peeked {
+            Some(None) => return VmResult::Ok((0, Some(0))),
+            Some(Some(_)) => 1,
+            None => 0,
+        };
+
+        let (lo, hi) = vm_try!(self.iter.protocol_size_hint());
+        let lo = lo.saturating_add(peek_len);
+
+        let hi = match hi {
+            Some(x) => x.checked_add(peek_len),
+            None => None,
+        };
+
+        VmResult::Ok((lo, hi))
+    }
+
+    #[rune::function(keep, protocol = LEN)]
+    #[inline]
+    fn len(&self) -> VmResult<usize> {
+        let peek_len = match &self.peeked {
+            Some(None) => return VmResult::Ok(0),
+            Some(Some(_)) => 1,
+            None => 0,
+        };
+
+        let len = vm_try!(self.iter.protocol_len());
+        VmResult::Ok(len.saturating_add(peek_len))
+    }
+
+    /// Returns a reference to the `next()` value without advancing the iterator.
+    ///
+    /// Like [`next`], if there is a value, it is wrapped in a `Some(T)`. But if the
+    /// iteration is over, `None` is returned.
+    ///
+    /// [`next`]: Iterator::next
+    ///
+    /// Because `peek()` returns a reference, and many iterators iterate over
+    /// references, there can be a possibly confusing situation where the return
+    /// value is a double reference. You can see this effect in the examples below.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rune
+    /// let xs = [1, 2, 3];
+    ///
+    /// let iter = xs.iter().peekable();
+    ///
+    /// // peek() lets us see into the future
+    /// assert_eq!(iter.peek(), Some(1));
+    /// assert_eq!(iter.next(), Some(1));
+    ///
+    /// assert_eq!(iter.next(), Some(2));
+    ///
+    /// // The iterator does not advance even if we `peek` multiple times
+    /// assert_eq!(iter.peek(), Some(3));
+    /// assert_eq!(iter.peek(), Some(3));
+    ///
+    /// assert_eq!(iter.next(), Some(3));
+    ///
+    /// // After the iterator is finished, so is `peek()`
+    /// assert_eq!(iter.peek(), None);
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[rune::function(keep)]
+    #[inline]
+    fn peek(&mut self) -> VmResult<Option<Value>> {
+        if let Some(v) = &self.peeked {
+            return VmResult::Ok(v.clone());
+        }
+
+        let value = vm_try!(self.iter.protocol_next());
+        self.peeked = Some(value.clone());
+        VmResult::Ok(value)
+    }
 }
 
-#[derive(Any)]
+#[derive(Any, Debug)]
 #[rune(item = ::std::iter)]
-struct Empty;
+struct Skip {
+    iter: Value,
+    n: usize,
+}
 
-impl Empty {
+impl Skip {
     #[rune::function(keep, protocol = NEXT)]
-    fn next(&mut self) -> Option<Value> {
-        None
+    #[inline]
+    fn next(&mut self) -> VmResult<Option<Value>> {
+        if self.n > 0 {
+            let old_n = self.n;
+            self.n = 0;
+
+            for _ in 0..old_n {
+                match vm_try!(self.iter.protocol_next()) {
+                    Some(..) => (),
+                    None => return VmResult::Ok(None),
+                }
+            }
+        }
+
+        self.iter.protocol_next()
     }
 
     #[rune::function(keep, protocol = NEXT_BACK)]
-    fn next_back(&mut self) -> Option<Value> {
-        None
+    #[inline]
+    fn next_back(&mut self) -> VmResult<Option<Value>> {
+        VmResult::Ok(if vm_try!(self.len()) > 0 {
+            vm_try!(self.iter.protocol_next_back())
+        } else {
+            None
+        })
     }
 
     #[rune::function(keep, protocol = SIZE_HINT)]
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        (0, Some(0))
+    #[inline]
+    fn size_hint(&self) -> VmResult<(usize, Option<usize>)> {
+        let (lower, upper) = vm_try!(self.iter.protocol_size_hint());
+        let lower = lower.saturating_sub(self.n);
+        let upper = upper.map(|x| x.saturating_sub(self.n));
+        VmResult::Ok((lower, upper))
     }
-}
 
-/// Construct an iterator which produces a single `value` once.
-///
-/// # Examples
-///
-/// ```rune
-/// use std::iter::once;
-///
-/// assert!(once(42).next().is_some());
-/// assert_eq!(once(42).collect::<Vec>(), [42]);
-/// ```
-#[rune::function]
-fn once(value: Value) -> Once {
-    Once { value: Some(value) }
+    #[rune::function(keep, protocol = LEN)]
+    #[inline]
+    fn len(&self) -> VmResult<usize> {
+        let len = vm_try!(self.iter.protocol_len());
+        VmResult::Ok(len.saturating_sub(self.n))
+    }
 }
 
-#[derive(Any)]
+#[derive(Any, Debug)]
 #[rune(item = ::std::iter)]
-struct Once {
-    value: Option<Value>,
+struct Take {
+    iter: Value,
+    n: usize,
 }
 
-impl Once {
+impl Take {
     #[rune::function(keep, protocol = NEXT)]
-    fn next(&mut self) -> Option<Value> {
-        self.value.take()
+    #[inline]
+    fn next(&mut self) -> VmResult<Option<Value>> {
+        if self.n == 0 {
+            return VmResult::Ok(None);
+        }
+
+        self.n -= 1;
+        self.iter.protocol_next()
     }
 
     #[rune::function(keep, protocol = NEXT_BACK)]
-    fn next_back(&mut self) -> Option<Value> {
-        self.value.take()
+    #[inline]
+    fn next_back(&mut self) -> VmResult<Option<Value>> {
+        if self.n == 0 {
+            VmResult::Ok(None)
+        } else {
+            let n = self.n;
+            self.n -= 1;
+            let len = vm_try!(self.iter.protocol_len());
+            self.iter.protocol_nth_back(len.saturating_sub(n))
+        }
     }
 
     #[rune::function(keep, protocol = SIZE_HINT)]
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        let len = usize::from(self.value.is_some());
-        (len, Some(len))
+    #[inline]
+    fn size_hint(&self) -> VmResult<(usize, Option<usize>)> {
+        if self.n == 0 {
+            return VmResult::Ok((0, Some(0)));
+        }
+
+        let (lower, upper) = vm_try!(self.iter.protocol_size_hint());
+
+        let lower = lower.min(self.n);
+
+        let upper = match upper {
+            Some(x) if x < self.n => Some(x),
+            _ => Some(self.n),
+        };
+
+        VmResult::Ok((lower, upper))
     }
-}
 
-/// Produce an iterator which starts at the range `start` and ends at the value
-/// `end` (exclusive).
-///
-/// # Examples
-///
-/// ```rune
-/// use std::iter::range;
-///
-/// assert!(range(0, 3).next().is_some());
-/// assert_eq!(range(0, 3).collect::<Vec>(), [0, 1, 2]);
-/// ```
-#[rune::function(deprecated = "Use the `<from>..<to>` operator instead")]
-fn range(start: i64, end: i64) -> RangeIter<i64> {
-    RangeIter::new(start..end)
+    #[rune::function(keep, protocol = LEN)]
+    #[inline]
+    fn len(&self) -> VmResult<usize> {
+        if self.n == 0 {
+            return VmResult::Ok(0);
+        }
+
+        let len = vm_try!(self.iter.protocol_len());
+        VmResult::Ok(len.min(self.n))
+    }
 }
 
-/// Fuse the iterator if the expression is `None`.
-macro_rules! fuse {
-    ($self:ident . $iter:ident . $($call:tt)+) => {
-        match $self.$iter {
-            Some(ref mut iter) => match vm_try!(iter.$($call)+) {
-                None => {
-                    $self.$iter = None;
-                    None
-                }
-                item => item,
-            },
-            None => None,
-        }
-    };
+#[derive(Any, Debug)]
+#[rune(item = ::std::iter)]
+struct Rev {
+    value: Value,
 }
 
-/// Try an iterator method without fusing,
-/// like an inline `.as_mut().and_then(...)`
-macro_rules! maybe {
-    ($self:ident . $iter:ident . $($call:tt)+) => {
-        match $self.$iter {
-            Some(ref mut iter) => vm_try!(iter.$($call)+),
-            None => None,
-        }
-    };
+impl Rev {
+    #[rune::function(keep, protocol = NEXT)]
+    #[inline]
+    fn next(&mut self) -> VmResult<Option<Value>> {
+        self.value.protocol_next_back()
+    }
+
+    #[rune::function(keep, protocol = NEXT_BACK)]
+    #[inline]
+    fn next_back(&mut self) -> VmResult<Option<Value>> {
+        self.value.protocol_next()
+    }
+
+    #[rune::function(keep, protocol = SIZE_HINT)]
+    #[inline]
+    fn size_hint(&self) -> VmResult<(usize, Option<usize>)> {
+        self.value.protocol_size_hint()
+    }
+
+    #[rune::function(keep, protocol = LEN)]
+    #[inline]
+    fn len(&self) -> VmResult<usize> {
+        self.value.protocol_len()
+    }
 }
 
 #[derive(Any, Debug)]
 #[rune(item = ::std::iter)]
-struct Chain {
-    a: Option<Value>,
-    b: Option<Value>,
+struct Zip {
+    a: Value,
+    b: Value,
 }
 
-impl Chain {
+impl Zip {
     #[rune::function(keep, protocol = NEXT)]
     #[inline]
-    fn next(&mut self) -> VmResult<Option<Value>> {
-        VmResult::Ok(match fuse!(self.a.protocol_next()) {
-            None => maybe!(self.b.protocol_next()),
-            item => item,
-        })
+    fn next(&mut self) -> VmResult<Option<(Value, Value)>> {
+        let Some(a) = vm_try!(self.a.protocol_next()) else {
+            return VmResult::Ok(None);
+        };
+
+        let Some(b) = vm_try!(self.b.protocol_next()) else {
+            return VmResult::Ok(None);
+        };
+
+        VmResult::Ok(Some((a, b)))
     }
 
     #[rune::function(keep, protocol = NEXT_BACK)]
     #[inline]
-    fn next_back(&mut self) -> VmResult<Option<Value>> {
-        VmResult::Ok(match fuse!(self.b.protocol_next_back()) {
-            None => maybe!(self.a.protocol_next_back()),
-            item => item,
+    fn next_back(&mut self) -> VmResult<Option<(Value, Value)>> {
+        let a_len = vm_try!(self.a.protocol_len());
+        let b_len = vm_try!(self.b.protocol_len());
+
+        // NB: Trim the longer iterator so that both ends line up.
+        for _ in b_len..a_len {
+            vm_try!(self.a.protocol_next_back());
+        }
+
+        for _ in a_len..b_len {
+            vm_try!(self.b.protocol_next_back());
+        }
+
+        let a = vm_try!(self.a.protocol_next_back());
+        let b = vm_try!(self.b.protocol_next_back());
+
+        VmResult::Ok(match (a, b) {
+            (Some(a), Some(b)) => Some((a, b)),
+            _ => None,
         })
     }
 
     #[rune::function(keep, protocol = SIZE_HINT)]
     #[inline]
     fn size_hint(&self) -> VmResult<(usize, Option<usize>)> {
-        match self {
-            Self {
-                a: Some(a),
-                b: Some(b),
-            } => {
-                let (a_lower, a_upper) = vm_try!(a.protocol_size_hint());
-                let (b_lower, b_upper) = vm_try!(b.protocol_size_hint());
+        let (a_lower, a_upper) = vm_try!(self.a.protocol_size_hint());
+        let (b_lower, b_upper) = vm_try!(self.b.protocol_size_hint());
 
-                let lower = a_lower.saturating_add(b_lower);
+        let lower = a_lower.min(b_lower);
 
-                let upper = match (a_upper, b_upper) {
-                    (Some(x), Some(y)) => x.checked_add(y),
-                    _ => None,
-                };
+        let upper = match (a_upper, b_upper) {
+            (Some(x), Some(y)) => Some(x.min(y)),
+            (Some(x), None) => Some(x),
+            (None, Some(y)) => Some(y),
+            (None, None) => None,
+        };
 
-                VmResult::Ok((lower, upper))
-            }
-            Self {
-                a: Some(a),
-                b: None,
-            } => a.protocol_size_hint(),
-            Self {
-                a: None,
-                b: Some(b),
-            } => b.protocol_size_hint(),
-            Self { a: None, b: None } => VmResult::Ok((0, Some(0))),
-        }
+        VmResult::Ok((lower, upper))
     }
 
     #[rune::function(keep, protocol = LEN)]
     #[inline]
     fn len(&self) -> VmResult<usize> {
-        match self {
-            Self {
-                a: Some(a),
-                b: Some(b),
-            } => {
-                let a_len = vm_try!(a.protocol_len());
-                let b_len = vm_try!(b.protocol_len());
-                VmResult::Ok(a_len.saturating_add(b_len))
-            }
-            Self {
-                a: Some(a),
-                b: None,
-            } => a.protocol_len(),
-            Self {
-                a: None,
-                b: Some(b),
-            } => b.protocol_len(),
-            Self { a: None, b: None } => VmResult::Ok(0),
-        }
+        let a_len = vm_try!(self.a.protocol_len());
+        let b_len = vm_try!(self.b.protocol_len());
+        VmResult::Ok(a_len.min(b_len))
     }
 }
 
 #[derive(Any, Debug)]
 #[rune(item = ::std::iter)]
-struct Enumerate {
+struct StepBy {
     iter: Value,
-    count: usize,
+    /// The step minus one.
+    step: usize,
+    first_take: bool,
 }
 
-impl Enumerate {
+impl StepBy {
+    /// The number of elements to skip from the back to reach the last element
+    /// which would be yielded from the front.
+    fn next_back_index(&self) -> VmResult<usize> {
+        let rem = vm_try!(self.iter.protocol_len()) % (self.step + 1);
+
+        VmResult::Ok(if self.first_take {
+            if rem == 0 {
+                self.step
+            } else {
+                rem - 1
+            }
+        } else {
+            rem
+        })
+    }
+
+    fn remaining(&self, n: usize) -> usize {
+        if self.first_take {
+            if n == 0 {
+                0
+            } else {
+                1 + (n - 1) / (self.step + 1)
+            }
+        } else {
+            n / (self.step + 1)
+        }
+    }
+
     #[rune::function(keep, protocol = NEXT)]
     #[inline]
-    fn next(&mut self) -> VmResult<Option<(usize, Value)>> {
-        if let Some(value) = vm_try!(self.iter.protocol_next()) {
-            let i = self.count;
-            self.count += 1;
-            return VmResult::Ok(Some((i, value)));
-        }
+    fn next(&mut self) -> VmResult<Option<Value>> {
+        let n = if self.first_take {
+            self.first_take = false;
+            0
+        } else {
+            self.step
+        };
 
-        VmResult::Ok(None)
+        self.iter.protocol_nth(n)
     }
 
     #[rune::function(keep, protocol = NEXT_BACK)]
     #[inline]
-    fn next_back(&mut self) -> VmResult<Option<(usize, Value)>> {
-        if let Some(value) = vm_try!(self.iter.protocol_next_back()) {
-            let len = vm_try!(self.iter.protocol_len());
-            return VmResult::Ok(Some((self.count + len, value)));
-        }
-
-        VmResult::Ok(None)
+    fn next_back(&mut self) -> VmResult<Option<Value>> {
+        let n = vm_try!(self.next_back_index());
+        self.iter.protocol_nth_back(n)
     }
 
     #[rune::function(keep, protocol = SIZE_HINT)]
     #[inline]
     fn size_hint(&self) -> VmResult<(usize, Option<usize>)> {
-        self.iter.protocol_size_hint()
+        let (lower, upper) = vm_try!(self.iter.protocol_size_hint());
+        VmResult::Ok((self.remaining(lower), upper.map(|n| self.remaining(n))))
     }
 
     #[rune::function(keep, protocol = LEN)]
     #[inline]
     fn len(&self) -> VmResult<usize> {
-        self.iter.protocol_len()
+        let len = vm_try!(self.iter.protocol_len());
+        VmResult::Ok(self.remaining(len))
     }
 }
 
 #[derive(Any, Debug)]
 #[rune(item = ::std::iter)]
-struct Filter {
+struct TakeWhile {
     iter: Value,
     f: Function,
+    done: bool,
 }
 
-impl Filter {
+impl TakeWhile {
     #[rune::function(keep, protocol = NEXT)]
     #[inline]
     fn next(&mut self) -> VmResult<Option<Value>> {
-        while let Some(value) = vm_try!(self.iter.protocol_next()) {
-            if vm_try!(self.f.call::<bool>((value.clone(),))) {
-                return VmResult::Ok(Some(value));
-            }
+        if self.done {
+            return VmResult::Ok(None);
         }
 
-        VmResult::Ok(None)
-    }
+        let Some(value) = vm_try!(self.iter.protocol_next()) else {
+            return VmResult::Ok(None);
+        };
 
-    #[rune::function(keep, protocol = NEXT_BACK)]
-    #[inline]
-    fn next_back(&mut self) -> VmResult<Option<Value>> {
-        while let Some(value) = vm_try!(self.iter.protocol_next_back()) {
-            if vm_try!(self.f.call::<bool>((value.clone(),))) {
-                return VmResult::Ok(Some(value));
-            }
+        if vm_try!(self.f.call::<bool>((value.clone(),))) {
+            return VmResult::Ok(Some(value));
         }
 
+        self.done = true;
         VmResult::Ok(None)
     }
 
     #[rune::function(keep, protocol = SIZE_HINT)]
     #[inline]
     fn size_hint(&self) -> VmResult<(usize, Option<usize>)> {
-        let (_, hi) = vm_try!(self.iter.protocol_size_hint());
-        VmResult::Ok((0, hi))
+        if self.done {
+            return VmResult::Ok((0, Some(0)));
+        }
+
+        let (_, upper) = vm_try!(self.iter.protocol_size_hint());
+        VmResult::Ok((0, upper))
     }
 }
 
 #[derive(Any, Debug)]
 #[rune(item = ::std::iter)]
-struct Map {
-    iter: Option<Value>,
+struct SkipWhile {
+    iter: Value,
     f: Function,
+    done: bool,
 }
 
-impl Map {
+impl SkipWhile {
     #[rune::function(keep, protocol = NEXT)]
     #[inline]
     fn next(&mut self) -> VmResult<Option<Value>> {
-        if let Some(value) = fuse!(self.iter.protocol_next()) {
-            return VmResult::Ok(Some(vm_try!(self.f.call::<Value>((value.clone(),)))));
+        if self.done {
+            return self.iter.protocol_next();
         }
 
-        VmResult::Ok(None)
-    }
-
-    #[rune::function(keep, protocol = NEXT_BACK)]
-    #[inline]
-    fn next_back(&mut self) -> VmResult<Option<Value>> {
-        if let Some(value) = fuse!(self.iter.protocol_next_back()) {
-            return VmResult::Ok(Some(vm_try!(self.f.call::<Value>((value.clone(),)))));
+        while let Some(value) = vm_try!(self.iter.protocol_next()) {
+            if !vm_try!(self.f.call::<bool>((value.clone(),))) {
+                self.done = true;
+                return VmResult::Ok(Some(value));
+            }
         }
 
         VmResult::Ok(None)
@@ -2118,283 +3466,292 @@ impl Map {
     #[rune::function(keep, protocol = SIZE_HINT)]
     #[inline]
     fn size_hint(&self) -> VmResult<(usize, Option<usize>)> {
-        let Some(iter) = &self.iter else {
-            return VmResult::Ok((0, Some(0)));
-        };
+        let (lower, upper) = vm_try!(self.iter.protocol_size_hint());
 
-        iter.protocol_size_hint()
+        if self.done {
+            return VmResult::Ok((lower, upper));
+        }
+
+        VmResult::Ok((0, upper))
     }
+}
 
-    #[rune::function(keep, protocol = LEN)]
+#[derive(Any, Debug)]
+#[rune(item = ::std::iter)]
+struct MapWhile {
+    iter: Value,
+    f: Function,
+}
+
+impl MapWhile {
+    #[rune::function(keep, protocol = NEXT)]
     #[inline]
-    fn len(&self) -> VmResult<usize> {
-        let Some(iter) = &self.iter else {
-            return VmResult::Ok(0);
+    fn next(&mut self) -> VmResult<Option<Value>> {
+        let Some(value) = vm_try!(self.iter.protocol_next()) else {
+            return VmResult::Ok(None);
         };
 
-        iter.protocol_len()
+        self.f.call::<Option<Value>>((value,))
+    }
+
+    #[rune::function(keep, protocol = SIZE_HINT)]
+    #[inline]
+    fn size_hint(&self) -> VmResult<(usize, Option<usize>)> {
+        let (_, upper) = vm_try!(self.iter.protocol_size_hint());
+        VmResult::Ok((0, upper))
     }
 }
 
 #[derive(Any, Debug)]
 #[rune(item = ::std::iter)]
-struct FilterMap {
-    iter: Option<Value>,
+struct Scan {
+    iter: Value,
+    state: Value,
     f: Function,
 }
 
-impl FilterMap {
+impl Scan {
     #[rune::function(keep, protocol = NEXT)]
     #[inline]
     fn next(&mut self) -> VmResult<Option<Value>> {
-        while let Some(value) = fuse!(self.iter.protocol_next()) {
-            if let Some(value) = vm_try!(self.f.call::<Option<Value>>((value.clone(),))) {
-                return VmResult::Ok(Some(value));
-            }
-        }
+        let Some(value) = vm_try!(self.iter.protocol_next()) else {
+            return VmResult::Ok(None);
+        };
 
-        VmResult::Ok(None)
+        let result = vm_try!(self
+            .f
+            .call::<Option<(Value, Value)>>((self.state.clone(), value)));
+
+        let Some((state, value)) = result else {
+            return VmResult::Ok(None);
+        };
+
+        self.state = state;
+        VmResult::Ok(Some(value))
     }
 
-    #[rune::function(keep, protocol = NEXT_BACK)]
+    #[rune::function(keep, protocol = SIZE_HINT)]
     #[inline]
-    fn next_back(&mut self) -> VmResult<Option<Value>> {
-        while let Some(value) = fuse!(self.iter.protocol_next_back()) {
-            if let Some(value) = vm_try!(self.f.call::<Option<Value>>((value.clone(),))) {
-                return VmResult::Ok(Some(value));
-            }
-        }
-
-        VmResult::Ok(None)
+    fn size_hint(&self) -> VmResult<(usize, Option<usize>)> {
+        let (_, upper) = vm_try!(self.iter.protocol_size_hint());
+        VmResult::Ok((0, upper))
     }
 }
 
 #[derive(Any, Debug)]
 #[rune(item = ::std::iter)]
-struct FlatMap {
-    map: Map,
-    frontiter: Option<Value>,
-    backiter: Option<Value>,
+struct Inspect {
+    iter: Value,
+    f: Function,
 }
 
-impl FlatMap {
+impl Inspect {
     #[rune::function(keep, protocol = NEXT)]
     #[inline]
     fn next(&mut self) -> VmResult<Option<Value>> {
-        loop {
-            if let Some(iter) = &mut self.frontiter {
-                match vm_try!(iter.protocol_next()) {
-                    None => self.frontiter = None,
-                    item @ Some(_) => return VmResult::Ok(item),
-                }
-            }
-
-            let Some(value) = vm_try!(self.map.next()) else {
-                return VmResult::Ok(match &mut self.backiter {
-                    Some(backiter) => vm_try!(backiter.protocol_next()),
-                    None => None,
-                });
-            };
+        let value = vm_try!(self.iter.protocol_next());
 
-            self.frontiter = Some(vm_try!(value.protocol_into_iter()))
+        if let Some(value) = &value {
+            vm_try!(self.f.call::<Value>((value.clone(),)));
         }
+
+        VmResult::Ok(value)
     }
 
     #[rune::function(keep, protocol = NEXT_BACK)]
     #[inline]
     fn next_back(&mut self) -> VmResult<Option<Value>> {
-        loop {
-            if let Some(ref mut iter) = self.backiter {
-                match vm_try!(iter.protocol_next_back()) {
-                    None => self.backiter = None,
-                    item @ Some(_) => return VmResult::Ok(item),
-                }
-            }
-
-            let Some(value) = vm_try!(self.map.next_back()) else {
-                return VmResult::Ok(match &mut self.frontiter {
-                    Some(frontiter) => vm_try!(frontiter.protocol_next_back()),
-                    None => None,
-                });
-            };
+        let value = vm_try!(self.iter.protocol_next_back());
 
-            self.backiter = Some(vm_try!(value.protocol_into_iter()));
+        if let Some(value) = &value {
+            vm_try!(self.f.call::<Value>((value.clone(),)));
         }
+
+        VmResult::Ok(value)
     }
 
     #[rune::function(keep, protocol = SIZE_HINT)]
     #[inline]
     fn size_hint(&self) -> VmResult<(usize, Option<usize>)> {
-        let (flo, fhi) = match &self.frontiter {
-            Some(iter) => vm_try!(iter.protocol_size_hint()),
-            None => (0, Some(0)),
+        self.iter.protocol_size_hint()
+    }
+
+    #[rune::function(keep, protocol = LEN)]
+    #[inline]
+    fn len(&self) -> VmResult<usize> {
+        self.iter.protocol_len()
+    }
+}
+
+#[derive(Any, Debug)]
+#[rune(item = ::std::iter)]
+struct Cycle {
+    iter: Option<Value>,
+    /// Elements seen so far, which are repeated once the iterator is
+    /// exhausted.
+    buffer: alloc::Vec<Value>,
+    index: usize,
+}
+
+impl Cycle {
+    #[rune::function(keep, protocol = NEXT)]
+    #[inline]
+    fn next(&mut self) -> VmResult<Option<Value>> {
+        if let Some(value) = fuse!(self.iter.protocol_next()) {
+            vm_try!(self.buffer.try_push(value.clone()));
+            return VmResult::Ok(Some(value));
+        }
+
+        let Some(value) = self.buffer.get(self.index) else {
+            return VmResult::Ok(None);
         };
 
-        let (blo, bhi) = match &self.backiter {
+        let value = value.clone();
+        self.index = (self.index + 1) % self.buffer.len();
+        VmResult::Ok(Some(value))
+    }
+
+    #[rune::function(keep, protocol = SIZE_HINT)]
+    #[inline]
+    fn size_hint(&self) -> VmResult<(usize, Option<usize>)> {
+        let (lower, upper) = match &self.iter {
             Some(iter) => vm_try!(iter.protocol_size_hint()),
             None => (0, Some(0)),
         };
 
-        let lo = flo.saturating_add(blo);
-
-        VmResult::Ok(match (vm_try!(self.map.size_hint()), fhi, bhi) {
-            ((0, Some(0)), Some(a), Some(b)) => (lo, a.checked_add(b)),
-            _ => (lo, None),
+        VmResult::Ok(match (self.buffer.is_empty(), lower, upper) {
+            (true, 0, Some(0)) => (0, Some(0)),
+            (true, 0, _) => (0, None),
+            _ => (usize::MAX, None),
         })
     }
 }
 
 #[derive(Any, Debug)]
 #[rune(item = ::std::iter)]
-struct Peekable {
+struct Windows {
     iter: Value,
-    peeked: Option<Option<Value>>,
+    size: usize,
+    window: alloc::VecDeque<Value>,
 }
 
-impl Peekable {
-    #[rune::function(keep, protocol = NEXT)]
-    #[inline]
-    fn next(&mut self) -> VmResult<Option<Value>> {
-        VmResult::Ok(match self.peeked.take() {
-            Some(v) => v,
-            None => vm_try!(self.iter.protocol_next()),
-        })
+impl Windows {
+    /// Calculate the number of windows left given the number of remaining
+    /// elements in the underlying iterator.
+    fn remaining(&self, n: usize) -> usize {
+        if self.window.len() == self.size {
+            n
+        } else {
+            self.window
+                .len()
+                .saturating_add(n)
+                .saturating_add(1)
+                .saturating_sub(self.size)
+        }
     }
 
-    #[rune::function(keep, protocol = NEXT_BACK)]
+    #[rune::function(keep, protocol = NEXT)]
     #[inline]
-    fn next_back(&mut self) -> VmResult<Option<Value>> {
-        VmResult::Ok(match self.peeked.as_mut() {
-            Some(v @ Some(_)) => vm_try!(self.iter.protocol_next_back()).or_else(|| v.take()),
-            Some(None) => None,
-            None => vm_try!(self.iter.protocol_next_back()),
-        })
+    fn next(&mut self) -> VmResult<Option<Vec>> {
+        if self.window.len() == self.size {
+            self.window.pop_front();
+        }
+
+        while self.window.len() < self.size {
+            let Some(value) = vm_try!(self.iter.protocol_next()) else {
+                return VmResult::Ok(None);
+            };
+
+            vm_try!(self.window.try_push_back(value));
+        }
+
+        let mut vec = vm_try!(Vec::with_capacity(self.size));
+
+        for value in self.window.iter() {
+            vm_try!(vec.push(value.clone()));
+        }
+
+        VmResult::Ok(Some(vec))
     }
 
     #[rune::function(keep, protocol = SIZE_HINT)]
     #[inline]
     fn size_hint(&self) -> VmResult<(usize, Option<usize>)> {
-        let peek_len = match &self.peeked {
-            Some(None) => return VmResult::Ok((0, Some(0))),
-            Some(Some(_)) => 1,
-            None => 0,
-        };
-
-        let (lo, hi) = vm_try!(self.iter.protocol_size_hint());
-        let lo = lo.saturating_add(peek_len);
-
-        let hi = match hi {
-            Some(x) => x.checked_add(peek_len),
-            None => None,
-        };
-
-        VmResult::Ok((lo, hi))
+        let (lower, upper) = vm_try!(self.iter.protocol_size_hint());
+        VmResult::Ok((self.remaining(lower), upper.map(|n| self.remaining(n))))
     }
 
     #[rune::function(keep, protocol = LEN)]
     #[inline]
     fn len(&self) -> VmResult<usize> {
-        let peek_len = match &self.peeked {
-            Some(None) => return VmResult::Ok(0),
-            Some(Some(_)) => 1,
-            None => 0,
-        };
-
         let len = vm_try!(self.iter.protocol_len());
-        VmResult::Ok(len.saturating_add(peek_len))
-    }
-
-    /// Returns a reference to the `next()` value without advancing the iterator.
-    ///
-    /// Like [`next`], if there is a value, it is wrapped in a `Some(T)`. But if the
-    /// iteration is over, `None` is returned.
-    ///
-    /// [`next`]: Iterator::next
-    ///
-    /// Because `peek()` returns a reference, and many iterators iterate over
-    /// references, there can be a possibly confusing situation where the return
-    /// value is a double reference. You can see this effect in the examples below.
-    ///
-    /// # Examples
-    ///
-    /// Basic usage:
-    ///
-    /// ```rune
-    /// let xs = [1, 2, 3];
-    ///
-    /// let iter = xs.iter().peekable();
-    ///
-    /// // peek() lets us see into the future
-    /// assert_eq!(iter.peek(), Some(1));
-    /// assert_eq!(iter.next(), Some(1));
-    ///
-    /// assert_eq!(iter.next(), Some(2));
-    ///
-    /// // The iterator does not advance even if we `peek` multiple times
-    /// assert_eq!(iter.peek(), Some(3));
-    /// assert_eq!(iter.peek(), Some(3));
-    ///
-    /// assert_eq!(iter.next(), Some(3));
-    ///
-    /// // After the iterator is finished, so is `peek()`
-    /// assert_eq!(iter.peek(), None);
-    /// assert_eq!(iter.next(), None);
-    /// ```
-    #[rune::function(keep)]
-    #[inline]
-    fn peek(&mut self) -> VmResult<Option<Value>> {
-        if let Some(v) = &self.peeked {
-            return VmResult::Ok(v.clone());
-        }
-
-        let value = vm_try!(self.iter.protocol_next());
-        self.peeked = Some(value.clone());
-        VmResult::Ok(value)
+        VmResult::Ok(self.remaining(len))
     }
 }
 
 #[derive(Any, Debug)]
 #[rune(item = ::std::iter)]
-struct Skip {
+struct Chunks {
     iter: Value,
-    n: usize,
+    size: usize,
 }
 
-impl Skip {
+impl Chunks {
     #[rune::function(keep, protocol = NEXT)]
     #[inline]
-    fn next(&mut self) -> VmResult<Option<Value>> {
-        if self.n > 0 {
-            let old_n = self.n;
-            self.n = 0;
+    fn next(&mut self) -> VmResult<Option<Vec>> {
+        let mut chunk = Vec::new();
 
-            for _ in 0..old_n {
-                match vm_try!(self.iter.protocol_next()) {
-                    Some(..) => (),
-                    None => return VmResult::Ok(None),
-                }
-            }
+        while chunk.len() < self.size {
+            let Some(value) = vm_try!(self.iter.protocol_next()) else {
+                break;
+            };
+
+            vm_try!(chunk.push(value));
         }
 
-        self.iter.protocol_next()
+        if chunk.is_empty() {
+            return VmResult::Ok(None);
+        }
+
+        VmResult::Ok(Some(chunk))
     }
 
     #[rune::function(keep, protocol = NEXT_BACK)]
     #[inline]
-    fn next_back(&mut self) -> VmResult<Option<Value>> {
-        VmResult::Ok(if vm_try!(self.len()) > 0 {
-            vm_try!(self.iter.protocol_next_back())
-        } else {
-            None
-        })
+    fn next_back(&mut self) -> VmResult<Option<Vec>> {
+        let len = vm_try!(self.iter.protocol_len());
+
+        if len == 0 {
+            return VmResult::Ok(None);
+        }
+
+        // NB: The last chunk holds the remainder, if any.
+        let n = match len % self.size {
+            0 => self.size,
+            rem => rem,
+        };
+
+        let mut chunk = vm_try!(alloc::Vec::try_with_capacity(n));
+
+        for _ in 0..n {
+            let Some(value) = vm_try!(self.iter.protocol_next_back()) else {
+                break;
+            };
+
+            vm_try!(chunk.try_push(value));
+        }
+
+        chunk.reverse();
+        VmResult::Ok(Some(Vec::from(chunk)))
     }
 
     #[rune::function(keep, protocol = SIZE_HINT)]
     #[inline]
     fn size_hint(&self) -> VmResult<(usize, Option<usize>)> {
         let (lower, upper) = vm_try!(self.iter.protocol_size_hint());
-        let lower = lower.saturating_sub(self.n);
-        let upper = upper.map(|x| x.saturating_sub(self.n));
+        let lower = lower.div_ceil(self.size);
+        let upper = upper.map(|n| n.div_ceil(self.size));
         VmResult::Ok((lower, upper))
     }
 
@@ -2402,102 +3759,128 @@ impl Skip {
     #[inline]
     fn len(&self) -> VmResult<usize> {
         let len = vm_try!(self.iter.protocol_len());
-        VmResult::Ok(len.saturating_sub(self.n))
+        VmResult::Ok(len.div_ceil(self.size))
     }
 }
 
 #[derive(Any, Debug)]
 #[rune(item = ::std::iter)]
-struct Take {
-    iter: Value,
-    n: usize,
+struct Flatten {
+    iter: Option<Value>,
+    frontiter: Option<Value>,
+    backiter: Option<Value>,
 }
 
-impl Take {
+impl Flatten {
     #[rune::function(keep, protocol = NEXT)]
     #[inline]
     fn next(&mut self) -> VmResult<Option<Value>> {
-        if self.n == 0 {
-            return VmResult::Ok(None);
-        }
+        loop {
+            if let Some(iter) = &mut self.frontiter {
+                match vm_try!(iter.protocol_next()) {
+                    None => self.frontiter = None,
+                    item @ Some(_) => return VmResult::Ok(item),
+                }
+            }
 
-        self.n -= 1;
-        self.iter.protocol_next()
+            let Some(value) = fuse!(self.iter.protocol_next()) else {
+                return VmResult::Ok(match &mut self.backiter {
+                    Some(backiter) => vm_try!(backiter.protocol_next()),
+                    None => None,
+                });
+            };
+
+            self.frontiter = Some(vm_try!(value.protocol_into_iter()));
+        }
     }
 
     #[rune::function(keep, protocol = NEXT_BACK)]
     #[inline]
     fn next_back(&mut self) -> VmResult<Option<Value>> {
-        if self.n == 0 {
-            VmResult::Ok(None)
-        } else {
-            let n = self.n;
-            self.n -= 1;
-            let len = vm_try!(self.iter.protocol_len());
-            self.iter.protocol_nth_back(len.saturating_sub(n))
+        loop {
+            if let Some(iter) = &mut self.backiter {
+                match vm_try!(iter.protocol_next_back()) {
+                    None => self.backiter = None,
+                    item @ Some(_) => return VmResult::Ok(item),
+                }
+            }
+
+            let Some(value) = fuse!(self.iter.protocol_next_back()) else {
+                return VmResult::Ok(match &mut self.frontiter {
+                    Some(frontiter) => vm_try!(frontiter.protocol_next_back()),
+                    None => None,
+                });
+            };
+
+            self.backiter = Some(vm_try!(value.protocol_into_iter()));
         }
     }
 
     #[rune::function(keep, protocol = SIZE_HINT)]
     #[inline]
     fn size_hint(&self) -> VmResult<(usize, Option<usize>)> {
-        if self.n == 0 {
-            return VmResult::Ok((0, Some(0)));
-        }
-
-        let (lower, upper) = vm_try!(self.iter.protocol_size_hint());
-
-        let lower = lower.min(self.n);
+        let (flo, fhi) = match &self.frontiter {
+            Some(iter) => vm_try!(iter.protocol_size_hint()),
+            None => (0, Some(0)),
+        };
 
-        let upper = match upper {
-            Some(x) if x < self.n => Some(x),
-            _ => Some(self.n),
+        let (blo, bhi) = match &self.backiter {
+            Some(iter) => vm_try!(iter.protocol_size_hint()),
+            None => (0, Some(0)),
         };
 
-        VmResult::Ok((lower, upper))
-    }
+        let lo = flo.saturating_add(blo);
 
-    #[rune::function(keep, protocol = LEN)]
-    #[inline]
-    fn len(&self) -> VmResult<usize> {
-        if self.n == 0 {
-            return VmResult::Ok(0);
-        }
+        let inner = match &self.iter {
+            Some(iter) => vm_try!(iter.protocol_size_hint()),
+            None => (0, Some(0)),
+        };
 
-        let len = vm_try!(self.iter.protocol_len());
-        VmResult::Ok(len.min(self.n))
+        VmResult::Ok(match (inner, fhi, bhi) {
+            ((0, Some(0)), Some(a), Some(b)) => (lo, a.checked_add(b)),
+            _ => (lo, None),
+        })
     }
 }
 
 #[derive(Any, Debug)]
 #[rune(item = ::std::iter)]
-struct Rev {
-    value: Value,
+struct Dedup {
+    iter: Value,
+    /// The first element of the next run of equal elements.
+    pending: Option<Value>,
 }
 
-impl Rev {
+impl Dedup {
     #[rune::function(keep, protocol = NEXT)]
     #[inline]
     fn next(&mut self) -> VmResult<Option<Value>> {
-        self.value.protocol_next_back()
-    }
+        let current = match self.pending.take() {
+            Some(value) => value,
+            None => match vm_try!(self.iter.protocol_next()) {
+                Some(value) => value,
+                None => return VmResult::Ok(None),
+            },
+        };
 
-    #[rune::function(keep, protocol = NEXT_BACK)]
-    #[inline]
-    fn next_back(&mut self) -> VmResult<Option<Value>> {
-        self.value.protocol_next()
+        while let Some(value) = vm_try!(self.iter.protocol_next()) {
+            if !vm_try!(Value::partial_eq(&current, &value)) {
+                self.pending = Some(value);
+                break;
+            }
+        }
+
+        VmResult::Ok(Some(current))
     }
 
     #[rune::function(keep, protocol = SIZE_HINT)]
     #[inline]
     fn size_hint(&self) -> VmResult<(usize, Option<usize>)> {
-        self.value.protocol_size_hint()
-    }
-
-    #[rune::function(keep, protocol = LEN)]
-    #[inline]
-    fn len(&self) -> VmResult<usize> {
-        self.value.protocol_len()
+        let pending = usize::from(self.pending.is_some());
+        let (lower, upper) = vm_try!(self.iter.protocol_size_hint());
+        let lower = lower.saturating_add(pending).min(1);
+        let upper = upper.and_then(|n| n.checked_add(pending));
+        VmResult::Ok((lower, upper))
     }
 }
 
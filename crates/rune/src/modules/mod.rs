@@ -18,6 +18,7 @@ pub mod any;
 pub mod bytes;
 #[cfg(feature = "capture-io")]
 pub mod capture_io;
+pub mod channel;
 pub mod char;
 pub mod clone;
 pub mod cmp;
@@ -29,6 +30,7 @@ pub mod f64;
 pub mod fmt;
 pub mod future;
 pub mod hash;
+pub mod i32;
 pub mod i64;
 pub mod io;
 pub mod iter;
@@ -42,7 +44,10 @@ pub mod result;
 pub mod slice;
 pub mod stream;
 pub mod string;
+pub mod task;
 pub mod test;
 pub mod tuple;
+pub mod u32;
 pub mod u64;
+pub mod u8;
 pub mod vec;
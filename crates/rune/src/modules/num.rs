@@ -1,18 +1,1262 @@
 //! Working with numbers.
 
+use core::cmp::Ordering;
+use core::hash::Hash as _;
 use core::num::{ParseFloatError, ParseIntError};
 
+use num::bigint::{ParseBigIntError, Sign};
+use num::{BigInt, FromPrimitive, Signed, ToPrimitive, Zero};
+
 use crate as rune;
-use crate::{ContextError, Module};
+use crate::alloc::fmt::TryWrite;
+use crate::alloc::prelude::*;
+use crate::alloc::String;
+use crate::runtime::{
+    Decimal, Formatter, Hasher, Inline, ParseDecimalError, Value, VmErrorKind, VmResult,
+};
+use crate::{ContextError, Module, TypeHash};
 
 /// Working with numbers.
 ///
 /// This module provides types generic for working over numbers, such as errors
 /// when a number cannot be parsed.
+///
+/// It also provides the arbitrary-precision [`BigInt`] and the fixed-point
+/// [`Decimal`] types, which can be written as literals using the `n` and `d`
+/// suffixes respectively.
+///
+/// # Examples
+///
+/// ```rune
+/// let a = 18446744073709551616n;
+/// assert_eq!(a * 2, 36893488147419103232n);
+///
+/// let b = 0.1d + 0.2d;
+/// assert_eq!(b, 0.3d);
+/// ```
 #[rune::module(::std::num)]
 pub fn module() -> Result<Module, ContextError> {
-    let mut module = Module::from_meta(self::module_meta)?;
-    module.ty::<ParseFloatError>()?;
-    module.ty::<ParseIntError>()?;
-    Ok(module)
+    let mut m = Module::from_meta(self::module_meta)?;
+    m.ty::<ParseFloatError>()?;
+    m.ty::<ParseIntError>()?;
+
+    {
+        m.ty::<BigInt>()?.docs(docstring! {
+            /// An arbitrary-precision integer.
+            ///
+            /// Big integers can be written as literals using the `n` suffix,
+            /// like `123n`.
+            ///
+            /// # Examples
+            ///
+            /// ```rune
+            /// let n = 2n.pow(100);
+            /// assert_eq!(n.to_string(), "1267650600228229401496703205376");
+            /// ```
+        })?;
+
+        m.ty::<ParseBigIntError>()?;
+
+        m.constant("ZERO", BigInt::zero())
+            .build_associated::<BigInt>()?
+            .docs(docstring! {
+                /// The big integer `0`.
+                ///
+                /// # Examples
+                ///
+                /// ```rune
+                /// use std::num::BigInt;
+                ///
+                /// assert_eq!(BigInt::ZERO, 0n);
+                /// ```
+            })?;
+
+        m.constant("ONE", BigInt::from(1))
+            .build_associated::<BigInt>()?
+            .docs(docstring! {
+                /// The big integer `1`.
+                ///
+                /// # Examples
+                ///
+                /// ```rune
+                /// use std::num::BigInt;
+                ///
+                /// assert_eq!(BigInt::ONE, 1n);
+                /// ```
+            })?;
+
+        m.function_meta(big_int_parse)?;
+        m.function_meta(big_int_from_i64)?;
+        m.function_meta(big_int_from_u64)?;
+        m.function_meta(big_int_from_f64)?;
+        m.function_meta(big_int_to_i64__meta)?;
+        m.function_meta(big_int_to_u64__meta)?;
+        m.function_meta(big_int_to_f64__meta)?;
+        m.function_meta(big_int_to_string__meta)?;
+        m.function_meta(big_int_abs__meta)?;
+        m.function_meta(big_int_pow__meta)?;
+        m.function_meta(big_int_signum__meta)?;
+        m.function_meta(big_int_is_zero__meta)?;
+
+        m.function_meta(big_int_add__meta)?;
+        m.function_meta(big_int_sub__meta)?;
+        m.function_meta(big_int_mul__meta)?;
+        m.function_meta(big_int_div__meta)?;
+        m.function_meta(big_int_rem__meta)?;
+        m.function_meta(big_int_add_assign__meta)?;
+        m.function_meta(big_int_sub_assign__meta)?;
+        m.function_meta(big_int_mul_assign__meta)?;
+        m.function_meta(big_int_div_assign__meta)?;
+        m.function_meta(big_int_rem_assign__meta)?;
+        m.function_meta(big_int_neg__meta)?;
+
+        m.function_meta(big_int_partial_eq__meta)?;
+        m.implement_trait::<BigInt>(rune::item!(::std::cmp::PartialEq))?;
+        m.function_meta(big_int_eq__meta)?;
+        m.implement_trait::<BigInt>(rune::item!(::std::cmp::Eq))?;
+        m.function_meta(big_int_partial_cmp__meta)?;
+        m.implement_trait::<BigInt>(rune::item!(::std::cmp::PartialOrd))?;
+        m.function_meta(big_int_cmp__meta)?;
+        m.implement_trait::<BigInt>(rune::item!(::std::cmp::Ord))?;
+        m.function_meta(big_int_hash__meta)?;
+        m.function_meta(big_int_clone__meta)?;
+        m.implement_trait::<BigInt>(rune::item!(::std::clone::Clone))?;
+        m.function_meta(big_int_display_fmt__meta)?;
+        m.function_meta(big_int_debug_fmt__meta)?;
+    }
+
+    {
+        m.ty::<Decimal>()?;
+        m.ty::<ParseDecimalError>()?;
+
+        m.constant("ZERO", Decimal::from(0))
+            .build_associated::<Decimal>()?
+            .docs(docstring! {
+                /// The decimal `0`.
+                ///
+                /// # Examples
+                ///
+                /// ```rune
+                /// use std::num::Decimal;
+                ///
+                /// assert_eq!(Decimal::ZERO, 0d);
+                /// ```
+            })?;
+
+        m.constant("ONE", Decimal::from(1))
+            .build_associated::<Decimal>()?
+            .docs(docstring! {
+                /// The decimal `1`.
+                ///
+                /// # Examples
+                ///
+                /// ```rune
+                /// use std::num::Decimal;
+                ///
+                /// assert_eq!(Decimal::ONE, 1d);
+                /// ```
+            })?;
+
+        m.function_meta(decimal_parse)?;
+        m.function_meta(decimal_from_i64)?;
+        m.function_meta(decimal_from_f64)?;
+        m.function_meta(decimal_to_i64__meta)?;
+        m.function_meta(decimal_to_f64__meta)?;
+        m.function_meta(decimal_to_big_int__meta)?;
+        m.function_meta(decimal_to_string__meta)?;
+        m.function_meta(decimal_scale__meta)?;
+        m.function_meta(decimal_abs__meta)?;
+        m.function_meta(decimal_pow__meta)?;
+        m.function_meta(decimal_round__meta)?;
+        m.function_meta(decimal_trunc__meta)?;
+        m.function_meta(decimal_floor__meta)?;
+        m.function_meta(decimal_ceil__meta)?;
+        m.function_meta(decimal_normalize__meta)?;
+        m.function_meta(decimal_is_zero__meta)?;
+
+        m.function_meta(decimal_add__meta)?;
+        m.function_meta(decimal_sub__meta)?;
+        m.function_meta(decimal_mul__meta)?;
+        m.function_meta(decimal_div__meta)?;
+        m.function_meta(decimal_rem__meta)?;
+        m.function_meta(decimal_add_assign__meta)?;
+        m.function_meta(decimal_sub_assign__meta)?;
+        m.function_meta(decimal_mul_assign__meta)?;
+        m.function_meta(decimal_div_assign__meta)?;
+        m.function_meta(decimal_rem_assign__meta)?;
+        m.function_meta(decimal_neg__meta)?;
+
+        m.function_meta(decimal_partial_eq__meta)?;
+        m.implement_trait::<Decimal>(rune::item!(::std::cmp::PartialEq))?;
+        m.function_meta(decimal_eq__meta)?;
+        m.implement_trait::<Decimal>(rune::item!(::std::cmp::Eq))?;
+        m.function_meta(decimal_partial_cmp__meta)?;
+        m.implement_trait::<Decimal>(rune::item!(::std::cmp::PartialOrd))?;
+        m.function_meta(decimal_cmp__meta)?;
+        m.implement_trait::<Decimal>(rune::item!(::std::cmp::Ord))?;
+        m.function_meta(decimal_hash__meta)?;
+        m.function_meta(decimal_clone__meta)?;
+        m.implement_trait::<Decimal>(rune::item!(::std::clone::Clone))?;
+        m.function_meta(decimal_display_fmt__meta)?;
+        m.function_meta(decimal_debug_fmt__meta)?;
+    }
+
+    Ok(m)
+}
+
+/// Coerce an operand into a big integer.
+///
+/// Integers and big integers are supported.
+fn as_big_int(value: &Value) -> VmResult<Option<BigInt>> {
+    match value.as_inline() {
+        Some(Inline::Signed(n)) => return VmResult::Ok(Some(BigInt::from(*n))),
+        Some(Inline::Unsigned(n)) => return VmResult::Ok(Some(BigInt::from(*n))),
+        _ => {}
+    }
+
+    if value.type_hash() != <BigInt as TypeHash>::HASH {
+        return VmResult::Ok(None);
+    }
+
+    let n = vm_try!(value.borrow_ref::<BigInt>());
+    VmResult::Ok(Some(n.clone()))
+}
+
+/// Coerce an operand into a big integer, erroring if it's not supported.
+fn big_int_operand(value: &Value) -> VmResult<BigInt> {
+    match vm_try!(as_big_int(value)) {
+        Some(n) => VmResult::Ok(n),
+        None => VmResult::expected::<BigInt>(value.type_info()),
+    }
+}
+
+/// Coerce an operand into a decimal.
+///
+/// Integers, big integers and decimals are supported.
+fn as_decimal(value: &Value) -> VmResult<Option<Decimal>> {
+    if value.type_hash() == Decimal::HASH {
+        let n = vm_try!(value.borrow_ref::<Decimal>());
+        return VmResult::Ok(Some(n.clone()));
+    }
+
+    VmResult::Ok(vm_try!(as_big_int(value)).map(Decimal::from))
+}
+
+/// Coerce an operand into a decimal, erroring if it's not supported.
+fn decimal_operand(value: &Value) -> VmResult<Decimal> {
+    match vm_try!(as_decimal(value)) {
+        Some(n) => VmResult::Ok(n),
+        None => VmResult::expected::<Decimal>(value.type_info()),
+    }
+}
+
+/// Parse a big integer from a string.
+///
+/// # Examples
+///
+/// ```rune
+/// use std::num::BigInt;
+///
+/// assert_eq!(BigInt::parse("-123456789012345678901234567890")?, -123456789012345678901234567890n);
+/// assert!(BigInt::parse("nope").is_err());
+/// ```
+#[rune::function(free, path = BigInt::parse)]
+fn big_int_parse(s: &str) -> Result<BigInt, ParseBigIntError> {
+    s.parse()
+}
+
+/// Convert a signed integer into a big integer.
+///
+/// # Examples
+///
+/// ```rune
+/// use std::num::BigInt;
+///
+/// assert_eq!(BigInt::from_i64(-42), -42n);
+/// ```
+#[rune::function(free, path = BigInt::from_i64)]
+fn big_int_from_i64(value: i64) -> BigInt {
+    BigInt::from(value)
+}
+
+/// Convert an unsigned integer into a big integer.
+///
+/// # Examples
+///
+/// ```rune
+/// use std::num::BigInt;
+///
+/// assert_eq!(BigInt::from_u64(u64::MAX), 18446744073709551615n);
+/// ```
+#[rune::function(free, path = BigInt::from_u64)]
+fn big_int_from_u64(value: u64) -> BigInt {
+    BigInt::from(value)
+}
+
+/// Convert a float into a big integer, truncating any fractional part.
+///
+/// Returns `None` if the float is not finite.
+///
+/// # Examples
+///
+/// ```rune
+/// use std::num::BigInt;
+///
+/// assert_eq!(BigInt::from_f64(1e20), Some(100000000000000000000n));
+/// assert_eq!(BigInt::from_f64(-2.7), Some(-2n));
+/// assert_eq!(BigInt::from_f64(f64::NAN), None);
+/// ```
+#[rune::function(free, path = BigInt::from_f64)]
+fn big_int_from_f64(value: f64) -> Option<BigInt> {
+    BigInt::from_f64(value)
+}
+
+/// Convert the big integer into a signed integer, returning `None` if it
+/// doesn't fit.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(42n.to_i64(), Some(42));
+/// assert_eq!(9223372036854775808n.to_i64(), None);
+/// ```
+#[rune::function(keep, instance, path = to_i64)]
+fn big_int_to_i64(this: &BigInt) -> Option<i64> {
+    this.to_i64()
+}
+
+/// Convert the big integer into an unsigned integer, returning `None` if it
+/// doesn't fit.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(42n.to_u64(), Some(42));
+/// assert_eq!((-1n).to_u64(), None);
+/// ```
+#[rune::function(keep, instance, path = to_u64)]
+fn big_int_to_u64(this: &BigInt) -> Option<u64> {
+    this.to_u64()
+}
+
+/// Convert the big integer into the closest float.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(42n.to_f64(), 42.0);
+/// ```
+#[rune::function(keep, instance, path = to_f64)]
+fn big_int_to_f64(this: &BigInt) -> f64 {
+    this.to_f64().unwrap_or(f64::NAN)
+}
+
+/// Convert the big integer into a string.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!((-123n).to_string(), "-123");
+/// ```
+#[rune::function(keep, instance, path = to_string)]
+fn big_int_to_string(this: &BigInt) -> VmResult<String> {
+    VmResult::Ok(vm_try!(this.try_to_string()))
+}
+
+/// Get the absolute value of the big integer.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!((-10n).abs(), 10n);
+/// ```
+#[rune::function(keep, instance, path = abs)]
+fn big_int_abs(this: &BigInt) -> BigInt {
+    this.abs()
+}
+
+/// Raise the big integer to the power of `exp`.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(10n.pow(20), 100000000000000000000n);
+/// ```
+#[rune::function(keep, instance, path = pow)]
+fn big_int_pow(this: &BigInt, exp: u32) -> BigInt {
+    this.pow(exp)
+}
+
+/// Get the sign of the big integer as `-1`, `0` or `1`.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!((-10n).signum(), -1);
+/// assert_eq!(0n.signum(), 0);
+/// assert_eq!(10n.signum(), 1);
+/// ```
+#[rune::function(keep, instance, path = signum)]
+fn big_int_signum(this: &BigInt) -> i64 {
+    match this.sign() {
+        Sign::Minus => -1,
+        Sign::NoSign => 0,
+        Sign::Plus => 1,
+    }
+}
+
+/// Test if the big integer is zero.
+///
+/// # Examples
+///
+/// ```rune
+/// assert!(0n.is_zero());
+/// assert!(!1n.is_zero());
+/// ```
+#[rune::function(keep, instance, path = is_zero)]
+fn big_int_is_zero(this: &BigInt) -> bool {
+    this.is_zero()
+}
+
+/// Add a big integer or an integer to the big integer.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(18446744073709551615n + 1, 18446744073709551616n);
+/// assert_eq!(1n + 2n, 3n);
+/// ```
+#[rune::function(keep, instance, protocol = ADD)]
+fn big_int_add(this: &BigInt, rhs: Value) -> VmResult<BigInt> {
+    VmResult::Ok(this + vm_try!(big_int_operand(&rhs)))
+}
+
+/// Subtract a big integer or an integer from the big integer.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(0n - 18446744073709551616n, -18446744073709551616n);
+/// ```
+#[rune::function(keep, instance, protocol = SUB)]
+fn big_int_sub(this: &BigInt, rhs: Value) -> VmResult<BigInt> {
+    VmResult::Ok(this - vm_try!(big_int_operand(&rhs)))
+}
+
+/// Multiply the big integer with a big integer or an integer.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(9223372036854775807n * 4, 36893488147419103228n);
+/// ```
+#[rune::function(keep, instance, protocol = MUL)]
+fn big_int_mul(this: &BigInt, rhs: Value) -> VmResult<BigInt> {
+    VmResult::Ok(this * vm_try!(big_int_operand(&rhs)))
+}
+
+/// Divide the big integer by a big integer or an integer, rounding towards
+/// zero.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(100000000000000000000n / 3, 33333333333333333333n);
+/// assert_eq!(-7n / 2, -3n);
+/// ```
+#[rune::function(keep, instance, protocol = DIV)]
+fn big_int_div(this: &BigInt, rhs: Value) -> VmResult<BigInt> {
+    let rhs = vm_try!(big_int_operand(&rhs));
+
+    if rhs.is_zero() {
+        return VmResult::err(VmErrorKind::DivideByZero);
+    }
+
+    VmResult::Ok(this / rhs)
+}
+
+/// Calculate the remainder of dividing the big integer by a big integer or an
+/// integer.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(100000000000000000000n % 3, 1n);
+/// assert_eq!(-7n % 2, -1n);
+/// ```
+#[rune::function(keep, instance, protocol = REM)]
+fn big_int_rem(this: &BigInt, rhs: Value) -> VmResult<BigInt> {
+    let rhs = vm_try!(big_int_operand(&rhs));
+
+    if rhs.is_zero() {
+        return VmResult::err(VmErrorKind::DivideByZero);
+    }
+
+    VmResult::Ok(this % rhs)
+}
+
+/// Add a big integer or an integer to the big integer in place.
+///
+/// # Examples
+///
+/// ```rune
+/// let n = 1n;
+/// n += u64::MAX;
+/// assert_eq!(n, 18446744073709551616n);
+/// ```
+#[rune::function(keep, instance, protocol = ADD_ASSIGN)]
+fn big_int_add_assign(this: &mut BigInt, rhs: Value) -> VmResult<()> {
+    *this += vm_try!(big_int_operand(&rhs));
+    VmResult::Ok(())
+}
+
+/// Subtract a big integer or an integer from the big integer in place.
+///
+/// # Examples
+///
+/// ```rune
+/// let n = 1n;
+/// n -= 2;
+/// assert_eq!(n, -1n);
+/// ```
+#[rune::function(keep, instance, protocol = SUB_ASSIGN)]
+fn big_int_sub_assign(this: &mut BigInt, rhs: Value) -> VmResult<()> {
+    *this -= vm_try!(big_int_operand(&rhs));
+    VmResult::Ok(())
+}
+
+/// Multiply the big integer with a big integer or an integer in place.
+///
+/// # Examples
+///
+/// ```rune
+/// let n = 10n;
+/// n *= 10n;
+/// assert_eq!(n, 100n);
+/// ```
+#[rune::function(keep, instance, protocol = MUL_ASSIGN)]
+fn big_int_mul_assign(this: &mut BigInt, rhs: Value) -> VmResult<()> {
+    *this *= vm_try!(big_int_operand(&rhs));
+    VmResult::Ok(())
+}
+
+/// Divide the big integer by a big integer or an integer in place.
+///
+/// # Examples
+///
+/// ```rune
+/// let n = 100n;
+/// n /= 7;
+/// assert_eq!(n, 14n);
+/// ```
+#[rune::function(keep, instance, protocol = DIV_ASSIGN)]
+fn big_int_div_assign(this: &mut BigInt, rhs: Value) -> VmResult<()> {
+    let rhs = vm_try!(big_int_operand(&rhs));
+
+    if rhs.is_zero() {
+        return VmResult::err(VmErrorKind::DivideByZero);
+    }
+
+    *this /= rhs;
+    VmResult::Ok(())
+}
+
+/// Calculate the remainder of dividing the big integer by a big integer or an
+/// integer in place.
+///
+/// # Examples
+///
+/// ```rune
+/// let n = 100n;
+/// n %= 7;
+/// assert_eq!(n, 2n);
+/// ```
+#[rune::function(keep, instance, protocol = REM_ASSIGN)]
+fn big_int_rem_assign(this: &mut BigInt, rhs: Value) -> VmResult<()> {
+    let rhs = vm_try!(big_int_operand(&rhs));
+
+    if rhs.is_zero() {
+        return VmResult::err(VmErrorKind::DivideByZero);
+    }
+
+    *this %= rhs;
+    VmResult::Ok(())
+}
+
+/// Negate the big integer.
+///
+/// # Examples
+///
+/// ```rune
+/// let n = 10n;
+/// assert_eq!(-n, -10n);
+/// ```
+#[rune::function(keep, instance, protocol = NEG)]
+fn big_int_neg(this: &BigInt) -> BigInt {
+    -this
+}
+
+/// Test the big integer for partial equality with a big integer or an
+/// integer.
+///
+/// # Examples
+///
+/// ```rune
+/// assert!(10n == 10n);
+/// assert!(10n == 10);
+/// assert!(10n != 11);
+/// ```
+#[rune::function(keep, instance, protocol = PARTIAL_EQ)]
+fn big_int_partial_eq(this: &BigInt, rhs: Value) -> VmResult<bool> {
+    let Some(rhs) = vm_try!(as_big_int(&rhs)) else {
+        return VmResult::Ok(false);
+    };
+
+    VmResult::Ok(*this == rhs)
+}
+
+/// Test the big integer for total equality with a big integer or an integer.
+///
+/// # Examples
+///
+/// ```rune
+/// use std::ops::eq;
+///
+/// assert!(eq(10n, 10n));
+/// assert!(!eq(10n, 11n));
+/// ```
+#[rune::function(keep, instance, protocol = EQ)]
+fn big_int_eq(this: &BigInt, rhs: Value) -> VmResult<bool> {
+    let Some(rhs) = vm_try!(as_big_int(&rhs)) else {
+        return VmResult::Ok(false);
+    };
+
+    VmResult::Ok(*this == rhs)
+}
+
+/// Perform a partial ordered comparison with a big integer or an integer.
+///
+/// # Examples
+///
+/// ```rune
+/// assert!(10n < 18446744073709551616n);
+/// assert!(10n > 5);
+/// ```
+#[rune::function(keep, instance, protocol = PARTIAL_CMP)]
+fn big_int_partial_cmp(this: &BigInt, rhs: Value) -> VmResult<Option<Ordering>> {
+    let Some(rhs) = vm_try!(as_big_int(&rhs)) else {
+        return VmResult::Ok(None);
+    };
+
+    VmResult::Ok(Some(this.cmp(&rhs)))
+}
+
+/// Perform a totally ordered comparison with a big integer or an integer.
+///
+/// # Examples
+///
+/// ```rune
+/// use std::cmp::Ordering;
+/// use std::ops::cmp;
+///
+/// assert_eq!(cmp(10n, 5n), Ordering::Greater);
+/// assert_eq!(cmp(5n, 10n), Ordering::Less);
+/// ```
+#[rune::function(keep, instance, protocol = CMP)]
+fn big_int_cmp(this: &BigInt, rhs: Value) -> VmResult<Ordering> {
+    let rhs = vm_try!(big_int_operand(&rhs));
+    VmResult::Ok(this.cmp(&rhs))
+}
+
+/// Hash the big integer.
+///
+/// # Examples
+///
+/// ```rune
+/// use std::ops::hash;
+///
+/// assert_eq!(hash(10n), hash(10n));
+/// assert_ne!(hash(10n), hash(11n));
+/// ```
+#[rune::function(keep, instance, protocol = HASH)]
+fn big_int_hash(this: &BigInt, hasher: &mut Hasher) {
+    this.hash(hasher);
+}
+
+/// Clone the big integer.
+///
+/// # Examples
+///
+/// ```rune
+/// let a = 10n;
+/// let b = a.clone();
+/// b += 1;
+///
+/// assert_eq!(a, 10n);
+/// assert_eq!(b, 11n);
+/// ```
+#[rune::function(keep, instance, protocol = CLONE)]
+fn big_int_clone(this: &BigInt) -> BigInt {
+    this.clone()
+}
+
+/// Write a display representation of the big integer.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(format!("{}", 18446744073709551616n), "18446744073709551616");
+/// ```
+#[rune::function(keep, instance, protocol = DISPLAY_FMT)]
+fn big_int_display_fmt(this: &BigInt, f: &mut Formatter) -> VmResult<()> {
+    rune::vm_write!(f, "{this}")
+}
+
+/// Write a debug representation of the big integer.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(format!("{:?}", -10n), "-10");
+/// ```
+#[rune::function(keep, instance, protocol = DEBUG_FMT)]
+fn big_int_debug_fmt(this: &BigInt, f: &mut Formatter) -> VmResult<()> {
+    rune::vm_write!(f, "{this:?}")
+}
+
+/// Parse a decimal from a string.
+///
+/// # Examples
+///
+/// ```rune
+/// use std::num::Decimal;
+///
+/// assert_eq!(Decimal::parse("1.10")?, 1.10d);
+/// assert_eq!(Decimal::parse("-2.5e3")?, -2500d);
+/// assert!(Decimal::parse("1.2.3").is_err());
+/// ```
+#[rune::function(free, path = Decimal::parse)]
+fn decimal_parse(s: &str) -> Result<Decimal, ParseDecimalError> {
+    s.parse()
+}
+
+/// Convert an integer into a decimal.
+///
+/// # Examples
+///
+/// ```rune
+/// use std::num::Decimal;
+///
+/// assert_eq!(Decimal::from_i64(-42), -42d);
+/// ```
+#[rune::function(free, path = Decimal::from_i64)]
+fn decimal_from_i64(value: i64) -> Decimal {
+    Decimal::from(value)
+}
+
+/// Convert a float into a decimal.
+///
+/// This uses the shortest representation of the float which converts back to
+/// the same value. Returns `None` if the float is not finite.
+///
+/// # Examples
+///
+/// ```rune
+/// use std::num::Decimal;
+///
+/// assert_eq!(Decimal::from_f64(0.1), Some(0.1d));
+/// assert_eq!(Decimal::from_f64(f64::INFINITY), None);
+/// ```
+#[rune::function(free, path = Decimal::from_f64)]
+fn decimal_from_f64(value: f64) -> Option<Decimal> {
+    Decimal::from_f64(value)
+}
+
+/// Convert the decimal into an integer, truncating any fractional digits.
+///
+/// Returns `None` if the integer part doesn't fit.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(2.75d.to_i64(), Some(2));
+/// assert_eq!((-2.75d).to_i64(), Some(-2));
+/// ```
+#[rune::function(keep, instance, path = to_i64)]
+fn decimal_to_i64(this: &Decimal) -> Option<i64> {
+    this.to_bigint().to_i64()
+}
+
+/// Convert the decimal into the closest float.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(0.1d.to_f64(), 0.1);
+/// ```
+#[rune::function(keep, instance, path = to_f64)]
+fn decimal_to_f64(this: &Decimal) -> f64 {
+    this.to_f64()
+}
+
+/// Convert the decimal into a big integer, truncating any fractional digits.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(123456789012345678901.99d.to_big_int(), 123456789012345678901n);
+/// ```
+#[rune::function(keep, instance, path = to_big_int)]
+fn decimal_to_big_int(this: &Decimal) -> BigInt {
+    this.to_bigint()
+}
+
+/// Convert the decimal into a string.
+///
+/// The number of fractional digits is preserved.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(1.10d.to_string(), "1.10");
+/// ```
+#[rune::function(keep, instance, path = to_string)]
+fn decimal_to_string(this: &Decimal) -> VmResult<String> {
+    VmResult::Ok(vm_try!(this.try_to_string()))
+}
+
+/// Get the number of fractional digits of the decimal.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(1.10d.scale(), 2);
+/// assert_eq!(42d.scale(), 0);
+/// ```
+#[rune::function(keep, instance, path = scale)]
+fn decimal_scale(this: &Decimal) -> u32 {
+    this.scale()
+}
+
+/// Get the absolute value of the decimal.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!((-1.5d).abs(), 1.5d);
+/// ```
+#[rune::function(keep, instance, path = abs)]
+fn decimal_abs(this: &Decimal) -> Decimal {
+    this.abs()
+}
+
+/// Raise the decimal to the power of `exp`.
+///
+/// Errors with an overflow if the scale of the result would exceed the largest
+/// supported scale.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(1.1d.pow(2), 1.21d);
+/// ```
+#[rune::function(keep, instance, path = pow)]
+fn decimal_pow(this: &Decimal, exp: u32) -> VmResult<Decimal> {
+    let Some(n) = this.checked_pow(exp) else {
+        return VmResult::err(VmErrorKind::Overflow);
+    };
+
+    VmResult::Ok(n)
+}
+
+/// Round the decimal to `dp` fractional digits, rounding half away from zero.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(2.345d.round(2).to_string(), "2.35");
+/// assert_eq!((-2.5d).round(0), -3d);
+/// ```
+#[rune::function(keep, instance, path = round)]
+fn decimal_round(this: &Decimal, dp: u32) -> Decimal {
+    this.round(dp)
+}
+
+/// Truncate the decimal into its integer part.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!((-2.7d).trunc(), -2d);
+/// ```
+#[rune::function(keep, instance, path = trunc)]
+fn decimal_trunc(this: &Decimal) -> Decimal {
+    this.trunc()
+}
+
+/// Get the largest integer less than or equal to the decimal.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(2.7d.floor(), 2d);
+/// assert_eq!((-2.1d).floor(), -3d);
+/// ```
+#[rune::function(keep, instance, path = floor)]
+fn decimal_floor(this: &Decimal) -> Decimal {
+    this.floor()
+}
+
+/// Get the smallest integer greater than or equal to the decimal.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(2.1d.ceil(), 3d);
+/// assert_eq!((-2.7d).ceil(), -2d);
+/// ```
+#[rune::function(keep, instance, path = ceil)]
+fn decimal_ceil(this: &Decimal) -> Decimal {
+    this.ceil()
+}
+
+/// Strip trailing zeros from the fractional digits of the decimal.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(1.2500d.normalize().to_string(), "1.25");
+/// ```
+#[rune::function(keep, instance, path = normalize)]
+fn decimal_normalize(this: &Decimal) -> Decimal {
+    this.normalize()
+}
+
+/// Test if the decimal is zero.
+///
+/// # Examples
+///
+/// ```rune
+/// assert!(0.00d.is_zero());
+/// assert!(!0.01d.is_zero());
+/// ```
+#[rune::function(keep, instance, path = is_zero)]
+fn decimal_is_zero(this: &Decimal) -> bool {
+    this.is_zero()
+}
+
+/// Add a decimal, big integer or integer to the decimal.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(0.1d + 0.2d, 0.3d);
+/// assert_eq!((1.10d + 1).to_string(), "2.10");
+/// ```
+#[rune::function(keep, instance, protocol = ADD)]
+fn decimal_add(this: &Decimal, rhs: Value) -> VmResult<Decimal> {
+    VmResult::Ok(this + &vm_try!(decimal_operand(&rhs)))
+}
+
+/// Subtract a decimal, big integer or integer from the decimal.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(0.3d - 0.1d, 0.2d);
+/// ```
+#[rune::function(keep, instance, protocol = SUB)]
+fn decimal_sub(this: &Decimal, rhs: Value) -> VmResult<Decimal> {
+    VmResult::Ok(this - &vm_try!(decimal_operand(&rhs)))
+}
+
+/// Multiply the decimal with a decimal, big integer or integer.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!((1.5d * 1.5d).to_string(), "2.25");
+/// assert_eq!(1.5d * 2, 3d);
+/// ```
+#[rune::function(keep, instance, protocol = MUL)]
+fn decimal_mul(this: &Decimal, rhs: Value) -> VmResult<Decimal> {
+    VmResult::Ok(this * &vm_try!(decimal_operand(&rhs)))
+}
+
+/// Divide the decimal by a decimal, big integer or integer.
+///
+/// Division is computed with at least 28 fractional digits, rounding half away
+/// from zero.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(1d / 8, 0.125d);
+/// assert_eq!((2d / 3).to_string(), "0.6666666666666666666666666667");
+/// ```
+#[rune::function(keep, instance, protocol = DIV)]
+fn decimal_div(this: &Decimal, rhs: Value) -> VmResult<Decimal> {
+    let rhs = vm_try!(decimal_operand(&rhs));
+
+    let Some(n) = this.checked_div(&rhs) else {
+        return VmResult::err(VmErrorKind::DivideByZero);
+    };
+
+    VmResult::Ok(n)
+}
+
+/// Calculate the remainder of dividing the decimal by a decimal, big integer
+/// or integer.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(7.5d % 2, 1.5d);
+/// assert_eq!(-7.5d % 2, -1.5d);
+/// ```
+#[rune::function(keep, instance, protocol = REM)]
+fn decimal_rem(this: &Decimal, rhs: Value) -> VmResult<Decimal> {
+    let rhs = vm_try!(decimal_operand(&rhs));
+
+    let Some(n) = this.checked_rem(&rhs) else {
+        return VmResult::err(VmErrorKind::DivideByZero);
+    };
+
+    VmResult::Ok(n)
+}
+
+/// Add a decimal, big integer or integer to the decimal in place.
+///
+/// # Examples
+///
+/// ```rune
+/// let n = 0.1d;
+/// n += 0.2d;
+/// assert_eq!(n, 0.3d);
+/// ```
+#[rune::function(keep, instance, protocol = ADD_ASSIGN)]
+fn decimal_add_assign(this: &mut Decimal, rhs: Value) -> VmResult<()> {
+    *this = &*this + &vm_try!(decimal_operand(&rhs));
+    VmResult::Ok(())
+}
+
+/// Subtract a decimal, big integer or integer from the decimal in place.
+///
+/// # Examples
+///
+/// ```rune
+/// let n = 1d;
+/// n -= 0.25d;
+/// assert_eq!(n, 0.75d);
+/// ```
+#[rune::function(keep, instance, protocol = SUB_ASSIGN)]
+fn decimal_sub_assign(this: &mut Decimal, rhs: Value) -> VmResult<()> {
+    *this = &*this - &vm_try!(decimal_operand(&rhs));
+    VmResult::Ok(())
+}
+
+/// Multiply the decimal with a decimal, big integer or integer in place.
+///
+/// # Examples
+///
+/// ```rune
+/// let n = 1.5d;
+/// n *= 3;
+/// assert_eq!(n, 4.5d);
+/// ```
+#[rune::function(keep, instance, protocol = MUL_ASSIGN)]
+fn decimal_mul_assign(this: &mut Decimal, rhs: Value) -> VmResult<()> {
+    *this = &*this * &vm_try!(decimal_operand(&rhs));
+    VmResult::Ok(())
+}
+
+/// Divide the decimal by a decimal, big integer or integer in place.
+///
+/// # Examples
+///
+/// ```rune
+/// let n = 1d;
+/// n /= 4;
+/// assert_eq!(n, 0.25d);
+/// ```
+#[rune::function(keep, instance, protocol = DIV_ASSIGN)]
+fn decimal_div_assign(this: &mut Decimal, rhs: Value) -> VmResult<()> {
+    let rhs = vm_try!(decimal_operand(&rhs));
+
+    let Some(n) = this.checked_div(&rhs) else {
+        return VmResult::err(VmErrorKind::DivideByZero);
+    };
+
+    *this = n;
+    VmResult::Ok(())
+}
+
+/// Calculate the remainder of dividing the decimal by a decimal, big integer
+/// or integer in place.
+///
+/// # Examples
+///
+/// ```rune
+/// let n = 7.5d;
+/// n %= 2;
+/// assert_eq!(n, 1.5d);
+/// ```
+#[rune::function(keep, instance, protocol = REM_ASSIGN)]
+fn decimal_rem_assign(this: &mut Decimal, rhs: Value) -> VmResult<()> {
+    let rhs = vm_try!(decimal_operand(&rhs));
+
+    let Some(n) = this.checked_rem(&rhs) else {
+        return VmResult::err(VmErrorKind::DivideByZero);
+    };
+
+    *this = n;
+    VmResult::Ok(())
+}
+
+/// Negate the decimal.
+///
+/// # Examples
+///
+/// ```rune
+/// let n = 1.5d;
+/// assert_eq!(-n, -1.5d);
+/// ```
+#[rune::function(keep, instance, protocol = NEG)]
+fn decimal_neg(this: &Decimal) -> Decimal {
+    -this
+}
+
+/// Test the decimal for partial equality with a decimal, big integer or
+/// integer.
+///
+/// Decimals are compared by value, so trailing zeros are ignored.
+///
+/// # Examples
+///
+/// ```rune
+/// assert!(1.10d == 1.1d);
+/// assert!(2.0d == 2);
+/// assert!(2.5d != 2);
+/// ```
+#[rune::function(keep, instance, protocol = PARTIAL_EQ)]
+fn decimal_partial_eq(this: &Decimal, rhs: Value) -> VmResult<bool> {
+    let Some(rhs) = vm_try!(as_decimal(&rhs)) else {
+        return VmResult::Ok(false);
+    };
+
+    VmResult::Ok(*this == rhs)
+}
+
+/// Test the decimal for total equality with a decimal, big integer or
+/// integer.
+///
+/// # Examples
+///
+/// ```rune
+/// use std::ops::eq;
+///
+/// assert!(eq(1.10d, 1.1d));
+/// assert!(!eq(1.1d, 1.2d));
+/// ```
+#[rune::function(keep, instance, protocol = EQ)]
+fn decimal_eq(this: &Decimal, rhs: Value) -> VmResult<bool> {
+    let Some(rhs) = vm_try!(as_decimal(&rhs)) else {
+        return VmResult::Ok(false);
+    };
+
+    VmResult::Ok(*this == rhs)
+}
+
+/// Perform a partial ordered comparison with a decimal, big integer or
+/// integer.
+///
+/// # Examples
+///
+/// ```rune
+/// assert!(1.5d < 2);
+/// assert!(1.05d < 1.1d);
+/// ```
+#[rune::function(keep, instance, protocol = PARTIAL_CMP)]
+fn decimal_partial_cmp(this: &Decimal, rhs: Value) -> VmResult<Option<Ordering>> {
+    let Some(rhs) = vm_try!(as_decimal(&rhs)) else {
+        return VmResult::Ok(None);
+    };
+
+    VmResult::Ok(Some(this.cmp(&rhs)))
+}
+
+/// Perform a totally ordered comparison with a decimal, big integer or
+/// integer.
+///
+/// # Examples
+///
+/// ```rune
+/// use std::cmp::Ordering;
+/// use std::ops::cmp;
+///
+/// assert_eq!(cmp(1.10d, 1.1d), Ordering::Equal);
+/// assert_eq!(cmp(1.1d, 1.2d), Ordering::Less);
+/// ```
+#[rune::function(keep, instance, protocol = CMP)]
+fn decimal_cmp(this: &Decimal, rhs: Value) -> VmResult<Ordering> {
+    let rhs = vm_try!(decimal_operand(&rhs));
+    VmResult::Ok(this.cmp(&rhs))
+}
+
+/// Hash the decimal.
+///
+/// Decimals which are equal hash the same, regardless of trailing zeros.
+///
+/// # Examples
+///
+/// ```rune
+/// use std::ops::hash;
+///
+/// assert_eq!(hash(1.10d), hash(1.1d));
+/// assert_ne!(hash(1.1d), hash(1.2d));
+/// ```
+#[rune::function(keep, instance, protocol = HASH)]
+fn decimal_hash(this: &Decimal, hasher: &mut Hasher) {
+    this.hash(hasher);
+}
+
+/// Clone the decimal.
+///
+/// # Examples
+///
+/// ```rune
+/// let a = 1.5d;
+/// let b = a.clone();
+/// b += 1;
+///
+/// assert_eq!(a, 1.5d);
+/// assert_eq!(b, 2.5d);
+/// ```
+#[rune::function(keep, instance, protocol = CLONE)]
+fn decimal_clone(this: &Decimal) -> Decimal {
+    this.clone()
+}
+
+/// Write a display representation of the decimal.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(format!("{}", 1.10d), "1.10");
+/// ```
+#[rune::function(keep, instance, protocol = DISPLAY_FMT)]
+fn decimal_display_fmt(this: &Decimal, f: &mut Formatter) -> VmResult<()> {
+    rune::vm_write!(f, "{this}")
+}
+
+/// Write a debug representation of the decimal.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(format!("{:?}", -0.5d), "-0.5");
+/// ```
+#[rune::function(keep, instance, protocol = DEBUG_FMT)]
+fn decimal_debug_fmt(this: &Decimal, f: &mut Formatter) -> VmResult<()> {
+    rune::vm_write!(f, "{this:?}")
 }
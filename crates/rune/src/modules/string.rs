@@ -8,15 +8,63 @@ use crate as rune;
 use crate::alloc::fmt::TryWrite;
 use crate::alloc::prelude::*;
 use crate::alloc::string::FromUtf8Error;
-use crate::alloc::{String, Vec};
+use crate::alloc::{self, String, Vec};
 use crate::compile::Named;
 use crate::runtime::{
-    Bytes, Formatter, FromValue, Function, Hasher, Inline, MaybeTypeOf, Panic, Range, RangeFrom,
-    RangeFull, RangeInclusive, RangeTo, RangeToInclusive, Ref, Repr, ToValue, TypeOf, Value,
-    VmErrorKind, VmResult,
+    self, Bytes, Formatter, FromValue, Function, Hasher, Inline, MaybeTypeOf, Panic, Range,
+    RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive, Ref, Repr, ToValue, TypeOf,
+    Value, VmErrorKind, VmResult,
 };
 use crate::{Any, ContextError, Module, TypeHash};
 
+/// Evaluate `$body` with `$pat` bound to a reference to the [`Pattern`] held
+/// by `$value`, returning an error from the enclosing function if the value
+/// can't be used as a pattern.
+macro_rules! with_pattern {
+    ($value:expr, |$pat:ident| $body:expr) => {
+        match $value.as_ref() {
+            Repr::Inline(Inline::Char(c)) => {
+                let $pat = c;
+                $body
+            }
+            Repr::Any(any) => match any.type_hash() {
+                String::HASH => {
+                    let string = vm_try!(any.borrow_ref::<String>());
+                    let $pat = &*string;
+                    $body
+                }
+                Function::HASH => {
+                    let f = vm_try!(any.borrow_ref::<Function>());
+                    let $pat = &*f;
+                    $body
+                }
+                runtime::Vec::HASH => {
+                    let chars = vm_try!(any.borrow_ref::<runtime::Vec>());
+
+                    for value in chars.iter() {
+                        vm_try!(char::from_value(value.clone()));
+                    }
+
+                    let $pat = &*chars;
+                    $body
+                }
+                _ => {
+                    return VmResult::err([
+                        VmErrorKind::expected::<String>(any.type_info()),
+                        VmErrorKind::bad_argument(0),
+                    ]);
+                }
+            },
+            _ => {
+                return VmResult::err([
+                    VmErrorKind::expected::<String>($value.type_info()),
+                    VmErrorKind::bad_argument(0),
+                ]);
+            }
+        }
+    };
+}
+
 /// Strings.
 ///
 /// Strings in Rune are declared with the literal `"string"` syntax, but can also be
@@ -59,6 +107,23 @@ pub fn module() -> Result<Module, ContextError> {
     m.associated_function("split_str", __rune_fn__split)?;
     m.function_meta(trim)?;
     m.function_meta(trim_end)?;
+    m.function_meta(trim_start)?;
+    m.function_meta(trim_matches)?;
+    m.function_meta(trim_start_matches)?;
+    m.function_meta(trim_end_matches)?;
+    m.function_meta(strip_prefix)?;
+    m.function_meta(strip_suffix)?;
+    m.function_meta(find)?;
+    m.function_meta(rfind)?;
+    m.function_meta(lines)?;
+    m.function_meta(split_whitespace)?;
+    m.function_meta(rsplit)?;
+    m.function_meta(splitn)?;
+    m.function_meta(char_indices)?;
+    m.function_meta(bytes)?;
+    m.function_meta(repeat)?;
+    m.function_meta(pad_start)?;
+    m.function_meta(pad_end)?;
     m.function_meta(replace)?;
     m.function_meta(is_empty)?;
     m.function_meta(chars)?;
@@ -67,6 +132,9 @@ pub fn module() -> Result<Module, ContextError> {
     m.function_meta(parse_char)?;
     m.function_meta(to_lowercase)?;
     m.function_meta(to_uppercase)?;
+    m.function_meta(to_ascii_lowercase)?;
+    m.function_meta(to_ascii_uppercase)?;
+    m.function_meta(eq_ignore_ascii_case)?;
 
     m.function_meta(add)?;
     m.function_meta(add_assign)?;
@@ -98,6 +166,34 @@ pub fn module() -> Result<Module, ContextError> {
     m.implement_trait::<Chars>(rune::item!(::std::iter::Iterator))?;
     m.implement_trait::<Chars>(rune::item!(::std::iter::DoubleEndedIterator))?;
 
+    m.ty::<CharIndices>()?;
+    m.function_meta(CharIndices::next__meta)?;
+    m.function_meta(CharIndices::next_back__meta)?;
+    m.implement_trait::<CharIndices>(rune::item!(::std::iter::Iterator))?;
+    m.implement_trait::<CharIndices>(rune::item!(::std::iter::DoubleEndedIterator))?;
+
+    m.ty::<StrBytes>()?;
+    m.function_meta(StrBytes::next__meta)?;
+    m.function_meta(StrBytes::next_back__meta)?;
+    m.function_meta(StrBytes::len__meta)?;
+    m.implement_trait::<StrBytes>(rune::item!(::std::iter::Iterator))?;
+    m.implement_trait::<StrBytes>(rune::item!(::std::iter::DoubleEndedIterator))?;
+
+    m.ty::<Lines>()?;
+    m.function_meta(Lines::next__meta)?;
+    m.implement_trait::<Lines>(rune::item!(::std::iter::Iterator))?;
+
+    m.ty::<SplitWhitespace>()?;
+    m.function_meta(SplitWhitespace::next__meta)?;
+    m.implement_trait::<SplitWhitespace>(rune::item!(::std::iter::Iterator))?;
+
+    m.ty::<Substrings>()?;
+    m.function_meta(Substrings::next__meta)?;
+    m.function_meta(Substrings::next_back__meta)?;
+    m.function_meta(Substrings::len__meta)?;
+    m.implement_trait::<Substrings>(rune::item!(::std::iter::Iterator))?;
+    m.implement_trait::<Substrings>(rune::item!(::std::iter::DoubleEndedIterator))?;
+
     macro_rules! split {
         ($ty:ty) => {
             m.ty::<Split<$ty>>()?;
@@ -109,6 +205,7 @@ pub fn module() -> Result<Module, ContextError> {
     split!(Function);
     split!(String);
     split!(char);
+    split!(runtime::Vec);
     Ok(m)
 }
 
@@ -316,9 +413,19 @@ fn len(this: &str) -> usize {
 /// assert!(bananas.starts_with("bana"));
 /// assert!(!bananas.starts_with("nana"));
 /// ```
+///
+/// Using a character or a closure as a pattern:
+///
+/// ```rune
+/// let bananas = "bananas";
+///
+/// assert!(bananas.starts_with('b'));
+/// assert!(bananas.starts_with(['a', 'b']));
+/// assert!(!bananas.starts_with(|c| c.is_uppercase()));
+/// ```
 #[rune::function(instance)]
-fn starts_with(this: &str, other: &str) -> bool {
-    this.starts_with(other)
+fn starts_with(this: &str, pat: Value) -> VmResult<bool> {
+    with_pattern!(pat, |p| VmResult::Ok(vm_try!(p.prefix_of(this)).is_some()))
 }
 
 /// Returns `true` if the given pattern matches a suffix of this string slice.
@@ -341,9 +448,18 @@ fn starts_with(this: &str, other: &str) -> bool {
 /// assert!(bananas.ends_with("anas"));
 /// assert!(!bananas.ends_with("nana"));
 /// ```
+///
+/// Using a character or a closure as a pattern:
+///
+/// ```rune
+/// let bananas = "bananas";
+///
+/// assert!(bananas.ends_with('s'));
+/// assert!(bananas.ends_with(|c| c.is_alphabetic()));
+/// ```
 #[rune::function(instance)]
-fn ends_with(this: &str, other: &str) -> bool {
-    this.ends_with(other)
+fn ends_with(this: &str, pat: Value) -> VmResult<bool> {
+    with_pattern!(pat, |p| VmResult::Ok(vm_try!(p.suffix_of(this)).is_some()))
 }
 
 /// Returns this `String`'s capacity, in bytes.
@@ -405,10 +521,12 @@ fn clear(this: &mut String) {
 ///
 /// assert!(bananas.contains("nana"));
 /// assert!(!bananas.contains("apples"));
+/// assert!(bananas.contains('n'));
+/// assert!(!bananas.contains(|c| c.is_numeric()));
 /// ```
 #[rune::function(instance)]
-fn contains(this: &str, other: &str) -> bool {
-    this.contains(other)
+fn contains(this: &str, pat: Value) -> VmResult<bool> {
+    with_pattern!(pat, |p| VmResult::Ok(vm_try!(p.find_in(this)).is_some()))
 }
 
 /// Appends the given [`char`] to the end of this `String`.
@@ -829,6 +947,9 @@ fn shrink_to_fit(s: &mut String) -> VmResult<()> {
 /// ```rune
 /// let v = "abc1defXghi".split(|c| c == '1' || c == 'X').collect::<Vec>();
 /// assert_eq!(v, ["abc", "def", "ghi"]);
+///
+/// let v = "abc1defXghi".split(['1', 'X']).collect::<Vec>();
+/// assert_eq!(v, ["abc", "def", "ghi"]);
 /// ```
 ///
 /// If a string contains multiple contiguous separators, you will end up
@@ -888,45 +1009,18 @@ fn shrink_to_fit(s: &mut String) -> VmResult<()> {
 /// [`split_whitespace`]: str::split_whitespace
 #[rune::function(instance, deprecated = "Use String::split instead")]
 fn split(this: Ref<str>, value: Value) -> VmResult<Value> {
-    match value.as_ref() {
-        Repr::Inline(Inline::Char(c)) => {
-            VmResult::Ok(vm_try!(rune::to_value(Split::new(this, *c))))
-        }
-        Repr::Inline(value) => VmResult::err([
-            VmErrorKind::expected::<String>(value.type_info()),
-            VmErrorKind::bad_argument(0),
-        ]),
-        Repr::Dynamic(value) => VmResult::err([
-            VmErrorKind::expected::<String>(value.type_info()),
-            VmErrorKind::bad_argument(0),
-        ]),
-        Repr::Any(value) => match value.type_hash() {
-            String::HASH => {
-                let s = vm_try!(value.borrow_ref::<String>());
-
-                let split = vm_try!(rune::to_value(Split::new(
-                    this,
-                    vm_try!(String::try_from(s.as_str()))
-                )));
-
-                VmResult::Ok(split)
-            }
-            Function::HASH => {
-                let f = vm_try!(value.borrow_ref::<Function>());
-                let split = vm_try!(rune::to_value(Split::new(this, vm_try!(f.try_clone()))));
-                VmResult::Ok(split)
-            }
-            _ => VmResult::err([
-                VmErrorKind::expected::<String>(value.type_info()),
-                VmErrorKind::bad_argument(0),
-            ]),
-        },
-    }
+    with_pattern!(value, |p| {
+        let split = Split::new(this, vm_try!(p.try_clone()));
+        VmResult::Ok(vm_try!(rune::to_value(split)))
+    })
 }
 
 /// Splits the string on the first occurrence of the specified delimiter and
 /// returns prefix before delimiter and suffix after delimiter.
 ///
+/// The pattern can be a `&str`, [`char`], an array of [`char`]s, or a function
+/// or closure that determines if a character matches.
+///
 /// # Examples
 ///
 /// ```rune
@@ -934,63 +1028,18 @@ fn split(this: Ref<str>, value: Value) -> VmResult<Value> {
 /// assert_eq!("cfg=".split_once('='), Some(("cfg", "")));
 /// assert_eq!("cfg=foo".split_once('='), Some(("cfg", "foo")));
 /// assert_eq!("cfg=foo=bar".split_once('='), Some(("cfg", "foo=bar")));
+/// assert_eq!("cfg: foo".split_once(": "), Some(("cfg", "foo")));
+/// assert_eq!("cfg:foo=bar".split_once([':', '=']), Some(("cfg", "foo=bar")));
 /// ```
 #[rune::function(instance)]
 fn split_once(this: &str, value: Value) -> VmResult<Option<(String, String)>> {
-    let outcome = match value.as_ref() {
-        Repr::Inline(Inline::Char(pat)) => this.split_once(*pat),
-        Repr::Inline(value) => {
-            return VmResult::err([
-                VmErrorKind::expected::<String>(value.type_info()),
-                VmErrorKind::bad_argument(0),
-            ]);
-        }
-        Repr::Dynamic(value) => {
-            return VmResult::err([
-                VmErrorKind::expected::<String>(value.type_info()),
-                VmErrorKind::bad_argument(0),
-            ]);
-        }
-        Repr::Any(value) => match value.type_hash() {
-            String::HASH => {
-                let s = vm_try!(value.borrow_ref::<String>());
-                this.split_once(s.as_str())
-            }
-            Function::HASH => {
-                let f = vm_try!(value.borrow_ref::<Function>());
-                let mut err = None;
-
-                let outcome = this.split_once(|c: char| match f.call::<bool>((c,)) {
-                    VmResult::Ok(b) => b,
-                    VmResult::Err(e) => {
-                        if err.is_none() {
-                            err = Some(e);
-                        }
-
-                        false
-                    }
-                });
-
-                if let Some(e) = err.take() {
-                    return VmResult::Err(e);
-                }
-
-                outcome
-            }
-            _ => {
-                return VmResult::err([
-                    VmErrorKind::expected::<String>(value.type_info()),
-                    VmErrorKind::bad_argument(0),
-                ]);
-            }
-        },
-    };
-
-    let Some((a, b)) = outcome else {
+    let Some((start, end)) = with_pattern!(value, |p| vm_try!(p.find_in(this))) else {
         return VmResult::Ok(None);
     };
 
-    VmResult::Ok(Some((vm_try!(a.try_to_owned()), vm_try!(b.try_to_owned()))))
+    let a = vm_try!(this[..start].try_to_owned());
+    let b = vm_try!(this[end..].try_to_owned());
+    VmResult::Ok(Some((a, b)))
 }
 
 /// Returns a string slice with leading and trailing whitespace removed.
@@ -1088,9 +1137,33 @@ fn is_empty(this: &str) -> bool {
 /// let s = "this is old";
 /// assert_eq!(s, s.replace("cookie monster", "little lamb"));
 /// ```
+///
+/// The pattern can also be a character, an array of characters or a closure:
+///
+/// ```rune
+/// let s = "a-b_c";
+///
+/// assert_eq!("a b_c", s.replace('-', " "));
+/// assert_eq!("a b c", s.replace(['-', '_'], " "));
+/// assert_eq!("-b_c", s.replace(|c| c.is_alphabetic() && c != 'b' && c != 'c', ""));
+/// ```
 #[rune::function(instance)]
-fn replace(a: &str, from: &str, to: &str) -> VmResult<String> {
-    VmResult::Ok(vm_try!(String::try_from(a.replace(from, to))))
+fn replace(a: &str, from: Value, to: &str) -> VmResult<String> {
+    let mut string = String::new();
+    let mut last = 0;
+
+    with_pattern!(from, |p| {
+        let mut matches = Matches::new(a, p);
+
+        while let Some((start, end)) = vm_try!(matches.next()) {
+            vm_try!(string.try_push_str(&a[last..start]));
+            vm_try!(string.try_push_str(to));
+            last = end;
+        }
+    });
+
+    vm_try!(string.try_push_str(&a[last..]));
+    VmResult::Ok(string)
 }
 
 /// Returns an iterator over the [`char`]s of a string slice.
@@ -1223,192 +1296,838 @@ fn get(this: &str, key: Value) -> VmResult<Option<String>> {
     VmResult::Ok(Some(vm_try!(slice.try_to_owned())))
 }
 
-/// The add operation for strings.
-#[rune::function(instance, protocol = ADD)]
-fn add(a: &str, b: &str) -> VmResult<String> {
-    let mut string = vm_try!(String::try_with_capacity(a.len() + b.len()));
-    vm_try!(string.try_push_str(a));
-    vm_try!(string.try_push_str(b));
-    VmResult::Ok(string)
-}
-
-/// The add assign operation for strings.
-#[rune::function(instance, protocol = ADD_ASSIGN)]
-fn add_assign(this: &mut String, other: &str) -> VmResult<()> {
-    vm_try!(this.try_push_str(other));
-    VmResult::Ok(())
-}
-
-/// Get a specific string index.
-#[rune::function(instance, protocol = INDEX_GET)]
-fn index_get(s: &str, key: Value) -> VmResult<String> {
-    match vm_try!(__rune_fn__get(s, key)) {
-        Some(slice) => VmResult::Ok(slice),
-        None => VmResult::err(Panic::custom("missing string slice")),
-    }
-}
-
-/// Parses this string into an integer.
+/// Returns the byte index of the first character of this string slice that
+/// matches the pattern.
 ///
-/// # Errors
+/// Returns [`None`] if the pattern doesn't match.
 ///
-/// Will return [`Err`] if it's not possible to parse this string slice into an
-/// integer.
+/// The pattern can be a `&str`, [`char`], an array of [`char`]s, or a function
+/// or closure that determines if a character matches.
 ///
 /// # Examples
 ///
-/// Basic usage
+/// Simple patterns:
 ///
 /// ```rune
-/// let four = "4".parse::<i64>()?;
-/// assert_eq!(4, four);
+/// let s = "Löwe 老虎 Léopard Gepardi";
+///
+/// assert_eq!(s.find('L'), Some(0));
+/// assert_eq!(s.find('é'), Some(14));
+/// assert_eq!(s.find("pard"), Some(17));
 /// ```
-#[rune::function(instance, path = parse::<i64>)]
-fn parse_int(s: &str) -> Result<i64, ParseIntError> {
-    str::parse::<i64>(s)
-}
-
-/// Parses this string into a float.
 ///
-/// # Errors
+/// More complex patterns using closures:
 ///
-/// Will return [`Err`] if it's not possible to parse this string slice into an
-/// float.
+/// ```rune
+/// let s = "Löwe 老虎 Léopard";
 ///
-/// # Examples
+/// assert_eq!(s.find(|c| c.is_whitespace()), Some(5));
+/// assert_eq!(s.find(['é', 'ö']), Some(1));
+/// ```
 ///
-/// Basic usage
+/// Not finding the pattern:
 ///
 /// ```rune
-/// let pi = "3.1415".parse::<f64>()?;
-/// assert_eq!(3.1415, pi);
+/// let s = "Löwe 老虎 Léopard";
+///
+/// assert_eq!(s.find('x'), None);
 /// ```
-#[rune::function(instance, path = parse::<f64>)]
-fn parse_float(s: &str) -> Result<f64, ParseFloatError> {
-    str::parse::<f64>(s)
+#[rune::function(instance)]
+fn find(this: &str, pat: Value) -> VmResult<Option<usize>> {
+    let found = with_pattern!(pat, |p| vm_try!(p.find_in(this)));
+    VmResult::Ok(found.map(|(start, _)| start))
 }
 
-/// Parses this string into a character.
+/// Returns the byte index for the first character of the last match of the
+/// pattern in this string slice.
 ///
-/// # Errors
+/// Returns [`None`] if the pattern doesn't match.
 ///
-/// Will return [`Err`] if it's not possible to parse this string slice into an
-/// integer.
+/// The pattern can be a `&str`, [`char`], an array of [`char`]s, or a function
+/// or closure that determines if a character matches.
 ///
 /// # Examples
 ///
-/// Basic usage
+/// Simple patterns:
 ///
 /// ```rune
-/// let a = "a".parse::<char>()?;
-/// assert_eq!('a', a);
+/// let s = "Löwe 老虎 Léopard Gepardi";
+///
+/// assert_eq!(s.rfind('L'), Some(13));
+/// assert_eq!(s.rfind('é'), Some(14));
+/// assert_eq!(s.rfind("pard"), Some(24));
 /// ```
-#[rune::function(instance, path = parse::<char>)]
-fn parse_char(s: &str) -> Result<char, char::ParseCharError> {
-    str::parse::<char>(s)
+///
+/// More complex patterns with closures:
+///
+/// ```rune
+/// let s = "Löwe 老虎 Léopard";
+///
+/// assert_eq!(s.rfind(|c| c.is_whitespace()), Some(12));
+/// assert_eq!(s.rfind(|c| c.is_numeric()), None);
+/// ```
+#[rune::function(instance)]
+fn rfind(this: &str, pat: Value) -> VmResult<Option<usize>> {
+    let found = with_pattern!(pat, |p| vm_try!(p.rfind_in(this)));
+    VmResult::Ok(found.map(|(start, _)| start))
 }
 
-/// Returns the lowercase equivalent of this string slice, as a new [`String`].
-///
-/// 'Lowercase' is defined according to the terms of the Unicode Derived Core Property
-/// `Lowercase`.
+/// Returns a string slice with leading whitespace removed.
 ///
-/// Since some characters can expand into multiple characters when changing
-/// the case, this function returns a [`String`] instead of modifying the
-/// parameter in-place.
+/// 'Whitespace' is defined according to the terms of the Unicode Derived Core
+/// Property `White_Space`, which includes newlines.
 ///
 /// # Examples
 ///
 /// Basic usage:
 ///
 /// ```rune
-/// let s = "HELLO";
+/// let s = "\n Hello\tworld\t\n";
 ///
-/// assert_eq!("hello", s.to_lowercase());
+/// assert_eq!("Hello\tworld\t\n", s.trim_start());
 /// ```
+#[rune::function(instance)]
+fn trim_start(this: &str) -> VmResult<String> {
+    VmResult::Ok(vm_try!(this.trim_start().try_to_owned()))
+}
+
+/// Returns a string slice with all prefixes and suffixes that match a pattern
+/// repeatedly removed.
 ///
-/// A tricky example, with sigma:
+/// The pattern can be a `&str`, [`char`], an array of [`char`]s, or a function
+/// or closure that determines if a character matches.
+///
+/// Unlike in Rust, a `&str` pattern is accepted. Prefixes are removed before
+/// suffixes.
+///
+/// # Examples
+///
+/// Simple patterns:
 ///
 /// ```rune
-/// let sigma = "Σ";
+/// assert_eq!("11foo1bar11".trim_matches('1'), "foo1bar");
+/// assert_eq!("123foo1bar123".trim_matches(|c| c.is_numeric()), "foo1bar");
+/// assert_eq!("12foo1bar12".trim_matches(['1', '2']), "foo1bar");
+/// assert_eq!("xyfooxybarxy".trim_matches("xy"), "fooxybar");
+/// ```
+#[rune::function(instance)]
+fn trim_matches(this: &str, pat: Value) -> VmResult<String> {
+    let trimmed = with_pattern!(pat, |p| {
+        let trimmed = vm_try!(p.trim_start(this));
+        vm_try!(p.trim_end(trimmed))
+    });
+
+    VmResult::Ok(vm_try!(trimmed.try_to_owned()))
+}
+
+/// Returns a string slice with all prefixes that match a pattern repeatedly
+/// removed.
 ///
-/// assert_eq!("σ", sigma.to_lowercase());
+/// The pattern can be a `&str`, [`char`], an array of [`char`]s, or a function
+/// or closure that determines if a character matches.
 ///
-/// // but at the end of a word, it's ς, not σ:
-/// let odysseus = "ὈΔΥΣΣΕΎΣ";
+/// # Examples
 ///
-/// assert_eq!("ὀδυσσεύς", odysseus.to_lowercase());
+/// ```rune
+/// assert_eq!("11foo1bar11".trim_start_matches('1'), "foo1bar11");
+/// assert_eq!("123foo1bar123".trim_start_matches(|c| c.is_numeric()), "foo1bar123");
+/// assert_eq!("xyxyfoo".trim_start_matches("xy"), "foo");
 /// ```
+#[rune::function(instance)]
+fn trim_start_matches(this: &str, pat: Value) -> VmResult<String> {
+    let trimmed = with_pattern!(pat, |p| vm_try!(p.trim_start(this)));
+    VmResult::Ok(vm_try!(trimmed.try_to_owned()))
+}
+
+/// Returns a string slice with all suffixes that match a pattern repeatedly
+/// removed.
 ///
-/// Languages without case are not changed:
+/// The pattern can be a `&str`, [`char`], an array of [`char`]s, or a function
+/// or closure that determines if a character matches.
+///
+/// # Examples
 ///
 /// ```rune
-/// let new_year = "农历新年";
+/// assert_eq!("11foo1bar11".trim_end_matches('1'), "11foo1bar");
+/// assert_eq!("123foo1bar123".trim_end_matches(|c| c.is_numeric()), "123foo1bar");
+/// assert_eq!("fooxyxy".trim_end_matches("xy"), "foo");
+/// ```
+#[rune::function(instance)]
+fn trim_end_matches(this: &str, pat: Value) -> VmResult<String> {
+    let trimmed = with_pattern!(pat, |p| vm_try!(p.trim_end(this)));
+    VmResult::Ok(vm_try!(trimmed.try_to_owned()))
+}
+
+/// Returns a string slice with the prefix removed.
 ///
-/// assert_eq!(new_year, new_year.to_lowercase());
+/// If the string starts with the pattern `prefix`, returns the substring after
+/// the prefix, wrapped in `Some`. Unlike `trim_start_matches`, this method
+/// removes the prefix exactly once.
+///
+/// If the string does not start with `prefix`, returns `None`.
+///
+/// The pattern can be a `&str`, [`char`], an array of [`char`]s, or a function
+/// or closure that determines if a character matches.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!("foo:bar".strip_prefix("foo:"), Some("bar"));
+/// assert_eq!("foo:bar".strip_prefix("bar"), None);
+/// assert_eq!("foofoo".strip_prefix("foo"), Some("foo"));
+/// assert_eq!("foofoo".strip_prefix('f'), Some("oofoo"));
 /// ```
 #[rune::function(instance)]
-fn to_lowercase(s: &str) -> VmResult<String> {
-    let mut lowercase = vm_try!(String::try_with_capacity(s.len()));
-    for (i, c) in s.char_indices() {
-        // Inlined code to from std::str to handle upper-case sigma,
-        // since it is the only Unicode character that is context-dependent
-        // See https://github.com/rust-lang/rust/issues/26035 for more context
-        if c == 'Σ' {
-            vm_try!(lowercase.try_push_str(map_uppercase_sigma(s, i)));
-        } else {
-            vm_try!(lowercase.try_extend(c.to_lowercase()));
-        }
-    }
+fn strip_prefix(this: &str, prefix: Value) -> VmResult<Option<String>> {
+    let Some(len) = with_pattern!(prefix, |p| vm_try!(p.prefix_of(this))) else {
+        return VmResult::Ok(None);
+    };
 
-    return VmResult::Ok(lowercase);
+    VmResult::Ok(Some(vm_try!(this[len..].try_to_owned())))
+}
 
-    fn map_uppercase_sigma(from: &str, i: usize) -> &'static str {
-        // See https://www.unicode.org/versions/Unicode7.0.0/ch03.pdf#G33992
-        // for the definition of `Final_Sigma`.
-        debug_assert!('Σ'.len_utf8() == 2);
-        let is_word_final = case_ignorable_then_cased(from[..i].chars().rev())
-            && !case_ignorable_then_cased(from[i + 2..].chars());
-        if is_word_final {
-            "ς"
-        } else {
-            "σ"
-        }
-    }
+/// Returns a string slice with the suffix removed.
+///
+/// If the string ends with the pattern `suffix`, returns the substring before
+/// the suffix, wrapped in `Some`. Unlike `trim_end_matches`, this method
+/// removes the suffix exactly once.
+///
+/// If the string does not end with `suffix`, returns `None`.
+///
+/// The pattern can be a `&str`, [`char`], an array of [`char`]s, or a function
+/// or closure that determines if a character matches.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!("bar:foo".strip_suffix(":foo"), Some("bar"));
+/// assert_eq!("bar:foo".strip_suffix("bar"), None);
+/// assert_eq!("foofoo".strip_suffix("foo"), Some("foo"));
+/// assert_eq!("foo1".strip_suffix(|c| c.is_numeric()), Some("foo"));
+/// ```
+#[rune::function(instance)]
+fn strip_suffix(this: &str, suffix: Value) -> VmResult<Option<String>> {
+    let Some(len) = with_pattern!(suffix, |p| vm_try!(p.suffix_of(this))) else {
+        return VmResult::Ok(None);
+    };
 
-    fn case_ignorable_then_cased<I: core::iter::Iterator<Item = char>>(mut iter: I) -> bool {
-        match iter.find(|&c| !unicode::case_ignorable::lookup(c)) {
-            Some(c) => unicode::cased::lookup(c),
-            None => false,
-        }
-    }
+    VmResult::Ok(Some(vm_try!(this[..this.len() - len].try_to_owned())))
 }
 
-/// Returns the uppercase equivalent of this string slice, as a new [`String`].
+/// Returns an iterator over the lines of a string, as string slices.
 ///
-/// 'Uppercase' is defined according to the terms of the Unicode Derived Core Property
-/// `Uppercase`.
+/// Lines are split at line endings that are either newlines (`\n`) or
+/// sequences of a carriage return followed by a line feed (`\r\n`).
 ///
-/// Since some characters can expand into multiple characters when changing
-/// the case, this function returns a [`String`] instead of modifying the
-/// parameter in-place.
+/// Line terminators are not included in the lines returned by the iterator.
+///
+/// The final line ending is optional. A string that ends with a final line
+/// ending will return the same lines as an otherwise identical string without
+/// a final line ending.
 ///
 /// # Examples
 ///
 /// Basic usage:
 ///
 /// ```rune
-/// let s = "hello";
+/// let text = "foo\r\nbar\n\nbaz\n";
+/// let lines = text.lines();
 ///
-/// assert_eq!("HELLO", s.to_uppercase());
+/// assert_eq!(Some("foo"), lines.next());
+/// assert_eq!(Some("bar"), lines.next());
+/// assert_eq!(Some(""), lines.next());
+/// assert_eq!(Some("baz"), lines.next());
+///
+/// assert_eq!(None, lines.next());
 /// ```
 ///
-/// Scripts without case are not changed:
+/// The final line does not require any ending:
 ///
 /// ```rune
-/// let new_year = "农历新年";
+/// let text = "foo\nbar\n\r\nbaz";
+/// let lines = text.lines().collect::<Vec>();
 ///
-/// assert_eq!(new_year, new_year.to_uppercase());
+/// assert_eq!(lines, ["foo", "bar", "", "baz"]);
+/// ```
+#[rune::function(instance)]
+fn lines(s: Ref<str>) -> Lines {
+    Lines {
+        string: s,
+        start: 0,
+    }
+}
+
+/// Splits a string slice by whitespace.
+///
+/// The iterator returned will return string slices that are sub-slices of the
+/// original string slice, separated by any amount of whitespace.
+///
+/// 'Whitespace' is defined according to the terms of the Unicode Derived Core
+/// Property `White_Space`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```rune
+/// let iter = "A few words".split_whitespace();
+///
+/// assert_eq!(Some("A"), iter.next());
+/// assert_eq!(Some("few"), iter.next());
+/// assert_eq!(Some("words"), iter.next());
+///
+/// assert_eq!(None, iter.next());
+/// ```
+///
+/// All kinds of whitespace are considered:
+///
+/// ```rune
+/// let words = " Mary   had\ta\u{2009}little  \n\t lamb".split_whitespace();
+/// assert_eq!(words.collect::<Vec>(), ["Mary", "had", "a", "little", "lamb"]);
+/// ```
+///
+/// If the string is empty or all whitespace, the iterator yields no string
+/// slices:
+///
+/// ```rune
+/// assert_eq!("".split_whitespace().next(), None);
+/// assert_eq!("   ".split_whitespace().next(), None);
+/// ```
+#[rune::function(instance)]
+fn split_whitespace(s: Ref<str>) -> SplitWhitespace {
+    SplitWhitespace {
+        string: s,
+        start: 0,
+    }
+}
+
+/// Returns an iterator over substrings of the given string slice, separated by
+/// characters matched by a pattern and yielded in reverse order.
+///
+/// The pattern can be a `&str`, [`char`], an array of [`char`]s, or a function
+/// or closure that determines if a character matches.
+///
+/// # Examples
+///
+/// Simple patterns:
+///
+/// ```rune
+/// let v = "Mary had a little lamb".rsplit(' ').collect::<Vec>();
+/// assert_eq!(v, ["lamb", "little", "a", "had", "Mary"]);
+///
+/// let v = "".rsplit('X').collect::<Vec>();
+/// assert_eq!(v, [""]);
+///
+/// let v = "lionXXtigerXleopard".rsplit('X').collect::<Vec>();
+/// assert_eq!(v, ["leopard", "tiger", "", "lion"]);
+///
+/// let v = "lion::tiger::leopard".rsplit("::").collect::<Vec>();
+/// assert_eq!(v, ["leopard", "tiger", "lion"]);
+/// ```
+///
+/// A more complex pattern, using a closure:
+///
+/// ```rune
+/// let v = "abc1defXghi".rsplit(|c| c == '1' || c == 'X').collect::<Vec>();
+/// assert_eq!(v, ["ghi", "def", "abc"]);
+/// ```
+#[rune::function(instance)]
+fn rsplit(this: &str, pat: Value) -> VmResult<Substrings> {
+    let mut parts = Vec::new();
+    let mut end = this.len();
+
+    with_pattern!(pat, |p| {
+        let mut matches = RMatches::new(this, p);
+
+        while let Some((start, to)) = vm_try!(matches.next()) {
+            vm_try!(parts.try_push(vm_try!(this[to..end].try_to_owned())));
+            end = start;
+        }
+    });
+
+    vm_try!(parts.try_push(vm_try!(this[..end].try_to_owned())));
+    VmResult::Ok(Substrings::new(parts))
+}
+
+/// Returns an iterator over substrings of the given string slice, separated by
+/// a pattern, restricted to returning at most `n` items.
+///
+/// If `n` substrings are returned, the last substring (the `n`th substring)
+/// will contain the remainder of the string.
+///
+/// The pattern can be a `&str`, [`char`], an array of [`char`]s, or a function
+/// or closure that determines if a character matches.
+///
+/// # Examples
+///
+/// Simple patterns:
+///
+/// ```rune
+/// let v = "Mary had a little lambda".splitn(3, ' ').collect::<Vec>();
+/// assert_eq!(v, ["Mary", "had", "a little lambda"]);
+///
+/// let v = "lionXXtigerXleopard".splitn(3, "X").collect::<Vec>();
+/// assert_eq!(v, ["lion", "", "tigerXleopard"]);
+///
+/// let v = "abcXdef".splitn(1, 'X').collect::<Vec>();
+/// assert_eq!(v, ["abcXdef"]);
+///
+/// let v = "".splitn(1, 'X').collect::<Vec>();
+/// assert_eq!(v, [""]);
+/// ```
+///
+/// A more complex pattern, using a closure:
+///
+/// ```rune
+/// let v = "abc1defXghi".splitn(2, |c| c == '1' || c == 'X').collect::<Vec>();
+/// assert_eq!(v, ["abc", "defXghi"]);
+/// ```
+#[rune::function(instance)]
+fn splitn(this: &str, n: usize, pat: Value) -> VmResult<Substrings> {
+    let mut parts = Vec::new();
+
+    if n == 0 {
+        return VmResult::Ok(Substrings::new(parts));
+    }
+
+    let mut last = 0;
+
+    with_pattern!(pat, |p| {
+        let mut matches = Matches::new(this, p);
+
+        while parts.len() + 1 < n {
+            let Some((start, end)) = vm_try!(matches.next()) else {
+                break;
+            };
+
+            vm_try!(parts.try_push(vm_try!(this[last..start].try_to_owned())));
+            last = end;
+        }
+    });
+
+    vm_try!(parts.try_push(vm_try!(this[last..].try_to_owned())));
+    VmResult::Ok(Substrings::new(parts))
+}
+
+/// Returns an iterator over the [`char`]s of a string slice, and their
+/// positions.
+///
+/// As a string slice consists of valid UTF-8, we can iterate through a string
+/// slice by [`char`]. This method returns an iterator of both these [`char`]s,
+/// as well as their byte positions.
+///
+/// The iterator yields tuples. The position is first, the [`char`] is second.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```rune
+/// let word = "goodbye";
+///
+/// let char_indices = word.char_indices();
+///
+/// assert_eq!(Some((0, 'g')), char_indices.next());
+/// assert_eq!(Some((1, 'o')), char_indices.next());
+/// assert_eq!(Some((6, 'e')), char_indices.next_back());
+/// ```
+///
+/// Positions are byte offsets:
+///
+/// ```rune
+/// let yes = "y̆es";
+///
+/// let char_indices = yes.char_indices();
+///
+/// assert_eq!(Some((0, 'y')), char_indices.next());
+/// assert_eq!(Some((1, '\u{0306}')), char_indices.next());
+/// assert_eq!(Some((3, 'e')), char_indices.next());
+/// assert_eq!(Some((4, 's')), char_indices.next());
+///
+/// assert_eq!(None, char_indices.next());
+/// ```
+#[rune::function(instance)]
+fn char_indices(s: Ref<str>) -> CharIndices {
+    CharIndices::new(s)
+}
+
+/// An iterator over the bytes of a string slice.
+///
+/// As a string slice consists of a sequence of bytes, we can iterate through a
+/// string slice by byte. This method returns such an iterator.
+///
+/// # Examples
+///
+/// ```rune
+/// let bytes = "bors".bytes();
+///
+/// assert_eq!(Some(b'b'), bytes.next());
+/// assert_eq!(Some(b'o'), bytes.next());
+/// assert_eq!(Some(b's'), bytes.next_back());
+/// assert_eq!(Some(b'r'), bytes.next());
+///
+/// assert_eq!(None, bytes.next());
+/// ```
+#[rune::function(instance)]
+fn bytes(s: Ref<str>) -> StrBytes {
+    StrBytes::new(s)
+}
+
+/// Creates a new [`String`] by repeating a string `n` times.
+///
+/// # Errors
+///
+/// Errors if the resulting string would be too large to allocate.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!("abc".repeat(4), "abcabcabcabc");
+/// assert_eq!("abc".repeat(0), "");
+/// ```
+#[rune::function(instance)]
+fn repeat(this: &str, n: usize) -> VmResult<String> {
+    if this.is_empty() {
+        return VmResult::Ok(String::new());
+    }
+
+    let Some(len) = this.len().checked_mul(n) else {
+        return VmResult::err(alloc::Error::CapacityOverflow);
+    };
+
+    let mut string = vm_try!(String::try_with_capacity(len));
+
+    for _ in 0..n {
+        vm_try!(string.try_push_str(this));
+    }
+
+    VmResult::Ok(string)
+}
+
+/// Pads the start of the string with the `fill` character until it is `width`
+/// characters long.
+///
+/// The width is counted in [`char`]s. If the string is already `width`
+/// characters or longer, it is returned unchanged.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!("5".pad_start(3, '0'), "005");
+/// assert_eq!("abc".pad_start(5, ' '), "  abc");
+/// assert_eq!("abcdef".pad_start(3, ' '), "abcdef");
+/// ```
+#[rune::function(instance)]
+fn pad_start(this: &str, width: usize, fill: char) -> VmResult<String> {
+    let padding = width.saturating_sub(this.chars().count());
+    let mut string = vm_try!(String::try_with_capacity(vm_try!(padded_len(
+        this, padding, fill
+    ))));
+    vm_try!(string.try_extend(core::iter::repeat(fill).take(padding)));
+    vm_try!(string.try_push_str(this));
+    VmResult::Ok(string)
+}
+
+/// Pads the end of the string with the `fill` character until it is `width`
+/// characters long.
+///
+/// The width is counted in [`char`]s. If the string is already `width`
+/// characters or longer, it is returned unchanged.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!("5".pad_end(3, '0'), "500");
+/// assert_eq!("abc".pad_end(5, '.'), "abc..");
+/// assert_eq!("abcdef".pad_end(3, ' '), "abcdef");
+/// ```
+#[rune::function(instance)]
+fn pad_end(this: &str, width: usize, fill: char) -> VmResult<String> {
+    let padding = width.saturating_sub(this.chars().count());
+    let mut string = vm_try!(String::try_with_capacity(vm_try!(padded_len(
+        this, padding, fill
+    ))));
+    vm_try!(string.try_push_str(this));
+    vm_try!(string.try_extend(core::iter::repeat(fill).take(padding)));
+    VmResult::Ok(string)
+}
+
+/// The length in bytes of `this` once padded with `padding` copies of `fill`.
+fn padded_len(this: &str, padding: usize, fill: char) -> alloc::Result<usize> {
+    padding
+        .checked_mul(fill.len_utf8())
+        .and_then(|len| len.checked_add(this.len()))
+        .ok_or(alloc::Error::CapacityOverflow)
+}
+
+/// Returns a copy of this string where each character is mapped to its ASCII
+/// lower case equivalent.
+///
+/// ASCII letters 'A' to 'Z' are mapped to 'a' to 'z', but non-ASCII letters
+/// are unchanged.
+///
+/// To lowercase ASCII characters in addition to non-ASCII characters, use
+/// [`to_lowercase`].
+///
+/// # Examples
+///
+/// ```rune
+/// let s = "Grüße, Jürgen ❤";
+///
+/// assert_eq!("grüße, jürgen ❤", s.to_ascii_lowercase());
+/// ```
+#[rune::function(instance)]
+fn to_ascii_lowercase(this: &str) -> VmResult<String> {
+    let mut string = vm_try!(String::try_with_capacity(this.len()));
+    vm_try!(string.try_extend(this.chars().map(|c| c.to_ascii_lowercase())));
+    VmResult::Ok(string)
+}
+
+/// Returns a copy of this string where each character is mapped to its ASCII
+/// upper case equivalent.
+///
+/// ASCII letters 'a' to 'z' are mapped to 'A' to 'Z', but non-ASCII letters
+/// are unchanged.
+///
+/// To uppercase ASCII characters in addition to non-ASCII characters, use
+/// [`to_uppercase`].
+///
+/// # Examples
+///
+/// ```rune
+/// let s = "Grüße, Jürgen ❤";
+///
+/// assert_eq!("GRüßE, JüRGEN ❤", s.to_ascii_uppercase());
+/// ```
+#[rune::function(instance)]
+fn to_ascii_uppercase(this: &str) -> VmResult<String> {
+    let mut string = vm_try!(String::try_with_capacity(this.len()));
+    vm_try!(string.try_extend(this.chars().map(|c| c.to_ascii_uppercase())));
+    VmResult::Ok(string)
+}
+
+/// Checks that two strings are an ASCII case-insensitive match.
+///
+/// Same as `to_ascii_lowercase(a) == to_ascii_lowercase(b)`, but without
+/// allocating and copying temporaries.
+///
+/// # Examples
+///
+/// ```rune
+/// assert!("Ferris".eq_ignore_ascii_case("FERRIS"));
+/// assert!("Ferrös".eq_ignore_ascii_case("FERRöS"));
+/// assert!(!"Ferrös".eq_ignore_ascii_case("FERRÖS"));
+/// ```
+#[rune::function(instance)]
+fn eq_ignore_ascii_case(this: &str, other: &str) -> bool {
+    this.eq_ignore_ascii_case(other)
+}
+
+/// The add operation for strings.
+#[rune::function(instance, protocol = ADD)]
+fn add(a: &str, b: &str) -> VmResult<String> {
+    let mut string = vm_try!(String::try_with_capacity(a.len() + b.len()));
+    vm_try!(string.try_push_str(a));
+    vm_try!(string.try_push_str(b));
+    VmResult::Ok(string)
+}
+
+/// The add assign operation for strings.
+#[rune::function(instance, protocol = ADD_ASSIGN)]
+fn add_assign(this: &mut String, other: &str) -> VmResult<()> {
+    vm_try!(this.try_push_str(other));
+    VmResult::Ok(())
+}
+
+/// Get a specific string index.
+///
+/// This supports slicing the string with any kind of range, where the range
+/// is expressed in bytes.
+///
+/// # Panics
+///
+/// Panics if the range is out of bounds, or if either end of it doesn't lie
+/// on a UTF-8 character boundary.
+///
+/// # Examples
+///
+/// ```rune
+/// let s = "hello world";
+///
+/// assert_eq!(s[0..5], "hello");
+/// assert_eq!(s[6..], "world");
+/// assert_eq!(s[..=4], "hello");
+/// assert_eq!(s[..], "hello world");
+/// ```
+#[rune::function(instance, protocol = INDEX_GET)]
+fn index_get(s: &str, key: Value) -> VmResult<String> {
+    match vm_try!(__rune_fn__get(s, key)) {
+        Some(slice) => VmResult::Ok(slice),
+        None => VmResult::err(Panic::custom("missing string slice")),
+    }
+}
+
+/// Parses this string into an integer.
+///
+/// # Errors
+///
+/// Will return [`Err`] if it's not possible to parse this string slice into an
+/// integer.
+///
+/// # Examples
+///
+/// Basic usage
+///
+/// ```rune
+/// let four = "4".parse::<i64>()?;
+/// assert_eq!(4, four);
+/// ```
+#[rune::function(instance, path = parse::<i64>)]
+fn parse_int(s: &str) -> Result<i64, ParseIntError> {
+    str::parse::<i64>(s)
+}
+
+/// Parses this string into a float.
+///
+/// # Errors
+///
+/// Will return [`Err`] if it's not possible to parse this string slice into an
+/// float.
+///
+/// # Examples
+///
+/// Basic usage
+///
+/// ```rune
+/// let pi = "3.1415".parse::<f64>()?;
+/// assert_eq!(3.1415, pi);
+/// ```
+#[rune::function(instance, path = parse::<f64>)]
+fn parse_float(s: &str) -> Result<f64, ParseFloatError> {
+    str::parse::<f64>(s)
+}
+
+/// Parses this string into a character.
+///
+/// # Errors
+///
+/// Will return [`Err`] if it's not possible to parse this string slice into an
+/// integer.
+///
+/// # Examples
+///
+/// Basic usage
+///
+/// ```rune
+/// let a = "a".parse::<char>()?;
+/// assert_eq!('a', a);
+/// ```
+#[rune::function(instance, path = parse::<char>)]
+fn parse_char(s: &str) -> Result<char, char::ParseCharError> {
+    str::parse::<char>(s)
+}
+
+/// Returns the lowercase equivalent of this string slice, as a new [`String`].
+///
+/// 'Lowercase' is defined according to the terms of the Unicode Derived Core Property
+/// `Lowercase`.
+///
+/// Since some characters can expand into multiple characters when changing
+/// the case, this function returns a [`String`] instead of modifying the
+/// parameter in-place.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```rune
+/// let s = "HELLO";
+///
+/// assert_eq!("hello", s.to_lowercase());
+/// ```
+///
+/// A tricky example, with sigma:
+///
+/// ```rune
+/// let sigma = "Σ";
+///
+/// assert_eq!("σ", sigma.to_lowercase());
+///
+/// // but at the end of a word, it's ς, not σ:
+/// let odysseus = "ὈΔΥΣΣΕΎΣ";
+///
+/// assert_eq!("ὀδυσσεύς", odysseus.to_lowercase());
+/// ```
+///
+/// Languages without case are not changed:
+///
+/// ```rune
+/// let new_year = "农历新年";
+///
+/// assert_eq!(new_year, new_year.to_lowercase());
+/// ```
+#[rune::function(instance)]
+fn to_lowercase(s: &str) -> VmResult<String> {
+    let mut lowercase = vm_try!(String::try_with_capacity(s.len()));
+    for (i, c) in s.char_indices() {
+        // Inlined code to from std::str to handle upper-case sigma,
+        // since it is the only Unicode character that is context-dependent
+        // See https://github.com/rust-lang/rust/issues/26035 for more context
+        if c == 'Σ' {
+            vm_try!(lowercase.try_push_str(map_uppercase_sigma(s, i)));
+        } else {
+            vm_try!(lowercase.try_extend(c.to_lowercase()));
+        }
+    }
+
+    return VmResult::Ok(lowercase);
+
+    fn map_uppercase_sigma(from: &str, i: usize) -> &'static str {
+        // See https://www.unicode.org/versions/Unicode7.0.0/ch03.pdf#G33992
+        // for the definition of `Final_Sigma`.
+        debug_assert!('Σ'.len_utf8() == 2);
+        let is_word_final = case_ignorable_then_cased(from[..i].chars().rev())
+            && !case_ignorable_then_cased(from[i + 2..].chars());
+        if is_word_final {
+            "ς"
+        } else {
+            "σ"
+        }
+    }
+
+    fn case_ignorable_then_cased<I: core::iter::Iterator<Item = char>>(mut iter: I) -> bool {
+        match iter.find(|&c| !unicode::case_ignorable::lookup(c)) {
+            Some(c) => unicode::cased::lookup(c),
+            None => false,
+        }
+    }
+}
+
+/// Returns the uppercase equivalent of this string slice, as a new [`String`].
+///
+/// 'Uppercase' is defined according to the terms of the Unicode Derived Core Property
+/// `Uppercase`.
+///
+/// Since some characters can expand into multiple characters when changing
+/// the case, this function returns a [`String`] instead of modifying the
+/// parameter in-place.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```rune
+/// let s = "hello";
+///
+/// assert_eq!("HELLO", s.to_uppercase());
+/// ```
+///
+/// Scripts without case are not changed:
+///
+/// ```rune
+/// let new_year = "农历新年";
+///
+/// assert_eq!(new_year, new_year.to_uppercase());
 /// ```
 ///
 /// One character can become multiple:
@@ -1459,53 +2178,296 @@ impl Chars {
     }
 }
 
-trait Pattern: 'static + TryClone + Named + FromValue + ToValue + MaybeTypeOf + TypeOf {
-    fn test(&self, tail: &str) -> VmResult<(bool, usize)>;
+#[derive(Any)]
+#[rune(item = ::std::string)]
+struct CharIndices {
+    string: Ref<str>,
+    start: usize,
+    end: usize,
+}
 
-    fn is_empty(&self) -> bool;
+impl CharIndices {
+    fn new(string: Ref<str>) -> Self {
+        let end = string.len();
+        Self {
+            string,
+            start: 0,
+            end,
+        }
+    }
+
+    #[rune::function(keep, protocol = NEXT)]
+    fn next(&mut self) -> Option<(usize, char)> {
+        let string = self.string.get(self.start..self.end)?;
+        let c = string.chars().next()?;
+        let index = self.start;
+        self.start += c.len_utf8();
+        Some((index, c))
+    }
+
+    #[rune::function(keep, protocol = NEXT_BACK)]
+    fn next_back(&mut self) -> Option<(usize, char)> {
+        let string = self.string.get(self.start..self.end)?;
+        let c = string.chars().next_back()?;
+        self.end -= c.len_utf8();
+        Some((self.end, c))
+    }
 }
 
-impl Pattern for String {
-    fn test(&self, tail: &str) -> VmResult<(bool, usize)> {
-        if tail.starts_with(self.as_str()) {
-            VmResult::Ok((true, self.len()))
-        } else {
-            let Some(c) = tail.chars().next() else {
-                return VmResult::Ok((false, 0));
-            };
+#[derive(Any)]
+#[rune(item = ::std::string, name = Bytes)]
+struct StrBytes {
+    string: Ref<str>,
+    start: usize,
+    end: usize,
+}
+
+impl StrBytes {
+    fn new(string: Ref<str>) -> Self {
+        let end = string.len();
+        Self {
+            string,
+            start: 0,
+            end,
+        }
+    }
 
-            VmResult::Ok((false, c.len_utf8()))
+    #[rune::function(keep, protocol = NEXT)]
+    fn next(&mut self) -> Option<u8> {
+        if self.start == self.end {
+            return None;
         }
+
+        let b = *self.string.as_bytes().get(self.start)?;
+        self.start += 1;
+        Some(b)
     }
 
-    #[inline]
-    fn is_empty(&self) -> bool {
-        String::is_empty(self)
+    #[rune::function(keep, protocol = NEXT_BACK)]
+    fn next_back(&mut self) -> Option<u8> {
+        if self.start == self.end {
+            return None;
+        }
+
+        self.end -= 1;
+        self.string.as_bytes().get(self.end).copied()
+    }
+
+    #[rune::function(keep, protocol = LEN)]
+    fn len(&self) -> usize {
+        self.end - self.start
+    }
+}
+
+#[derive(Any)]
+#[rune(item = ::std::string)]
+struct Lines {
+    string: Ref<str>,
+    start: usize,
+}
+
+impl Lines {
+    #[rune::function(keep, protocol = NEXT)]
+    fn next(&mut self) -> VmResult<Option<String>> {
+        let Some(rest) = self.string.get(self.start..) else {
+            return VmResult::Ok(None);
+        };
+
+        let Some(line) = rest.split_inclusive('\n').next() else {
+            return VmResult::Ok(None);
+        };
+
+        self.start += line.len();
+
+        let line = match line.strip_suffix('\n') {
+            Some(line) => line.strip_suffix('\r').unwrap_or(line),
+            None => line,
+        };
+
+        VmResult::Ok(Some(vm_try!(line.try_to_owned())))
+    }
+}
+
+#[derive(Any)]
+#[rune(item = ::std::string)]
+struct SplitWhitespace {
+    string: Ref<str>,
+    start: usize,
+}
+
+impl SplitWhitespace {
+    #[rune::function(keep, protocol = NEXT)]
+    fn next(&mut self) -> VmResult<Option<String>> {
+        let Some(rest) = self.string.get(self.start..) else {
+            return VmResult::Ok(None);
+        };
+
+        let trimmed = rest.trim_start();
+
+        if trimmed.is_empty() {
+            self.start = self.string.len();
+            return VmResult::Ok(None);
+        }
+
+        let len = trimmed.find(char::is_whitespace).unwrap_or(trimmed.len());
+        let word = &trimmed[..len];
+        self.start += rest.len() - trimmed.len() + len;
+        VmResult::Ok(Some(vm_try!(word.try_to_owned())))
+    }
+}
+
+/// An iterator over substrings of a string, as produced by [`rsplit`] and
+/// [`splitn`].
+#[derive(Any)]
+#[rune(item = ::std::string)]
+struct Substrings {
+    iter: crate::alloc::vec::IntoIter<String>,
+}
+
+impl Substrings {
+    fn new(parts: Vec<String>) -> Self {
+        Self {
+            iter: parts.into_iter(),
+        }
+    }
+
+    #[rune::function(keep, protocol = NEXT)]
+    fn next(&mut self) -> Option<String> {
+        self.iter.next()
+    }
+
+    #[rune::function(keep, protocol = NEXT_BACK)]
+    fn next_back(&mut self) -> Option<String> {
+        self.iter.next_back()
+    }
+
+    #[rune::function(keep, protocol = LEN)]
+    fn len(&self) -> usize {
+        self.iter.len()
     }
 }
 
-impl Pattern for char {
+/// A pattern which can be searched for in a string, mirroring the patterns
+/// accepted by Rust's `str` methods.
+///
+/// This is implemented for strings, characters, arrays of characters, and
+/// functions which determine if a character matches.
+trait Pattern: 'static + TryClone + Named + FromValue + ToValue + MaybeTypeOf + TypeOf {
+    /// Find the byte range of the first match in `haystack`.
+    fn find_in(&self, haystack: &str) -> VmResult<Option<(usize, usize)>>;
+
+    /// Find the byte range of the last match in `haystack`.
+    fn rfind_in(&self, haystack: &str) -> VmResult<Option<(usize, usize)>>;
+
+    /// The length of the match at the start of `haystack`, if any.
+    fn prefix_of(&self, haystack: &str) -> VmResult<Option<usize>>;
+
+    /// The length of the match at the end of `haystack`, if any.
+    fn suffix_of(&self, haystack: &str) -> VmResult<Option<usize>>;
+
+    /// Test if the pattern only matches the empty string.
+    fn is_empty(&self) -> bool;
+
+    /// Test if the pattern matches at the start of `tail`, returning the
+    /// length of the match or of the character to skip if it doesn't.
     fn test(&self, tail: &str) -> VmResult<(bool, usize)> {
+        if let Some(len) = vm_try!(self.prefix_of(tail)) {
+            return VmResult::Ok((true, len));
+        }
+
         let Some(c) = tail.chars().next() else {
             return VmResult::Ok((false, 0));
         };
 
-        VmResult::Ok((c == *self, c.len_utf8()))
+        VmResult::Ok((false, c.len_utf8()))
+    }
+
+    /// Repeatedly remove matching prefixes from `string`.
+    fn trim_start<'a>(&self, mut string: &'a str) -> VmResult<&'a str> {
+        while let Some(len @ 1..) = vm_try!(self.prefix_of(string)) {
+            string = &string[len..];
+        }
+
+        VmResult::Ok(string)
+    }
+
+    /// Repeatedly remove matching suffixes from `string`.
+    fn trim_end<'a>(&self, mut string: &'a str) -> VmResult<&'a str> {
+        while let Some(len @ 1..) = vm_try!(self.suffix_of(string)) {
+            string = &string[..string.len() - len];
+        }
+
+        VmResult::Ok(string)
+    }
+}
+
+impl Pattern for String {
+    fn find_in(&self, haystack: &str) -> VmResult<Option<(usize, usize)>> {
+        let found = haystack.find(self.as_str());
+        VmResult::Ok(found.map(|start| (start, start + self.len())))
+    }
+
+    fn rfind_in(&self, haystack: &str) -> VmResult<Option<(usize, usize)>> {
+        let found = haystack.rfind(self.as_str());
+        VmResult::Ok(found.map(|start| (start, start + self.len())))
+    }
+
+    fn prefix_of(&self, haystack: &str) -> VmResult<Option<usize>> {
+        VmResult::Ok(haystack.starts_with(self.as_str()).then_some(self.len()))
+    }
+
+    fn suffix_of(&self, haystack: &str) -> VmResult<Option<usize>> {
+        VmResult::Ok(haystack.ends_with(self.as_str()).then_some(self.len()))
     }
 
     #[inline]
     fn is_empty(&self) -> bool {
-        false
+        String::is_empty(self)
     }
 }
 
-impl Pattern for Function {
-    fn test(&self, tail: &str) -> VmResult<(bool, usize)> {
-        let Some(c) = tail.chars().next() else {
-            return VmResult::Ok((false, 0));
-        };
+/// A pattern which matches one character at a time.
+trait CharPattern: 'static + TryClone + Named + FromValue + ToValue + MaybeTypeOf + TypeOf {
+    /// Test if the pattern matches the given character.
+    fn matches(&self, c: char) -> VmResult<bool>;
+}
+
+impl<T> Pattern for T
+where
+    T: CharPattern,
+{
+    fn find_in(&self, haystack: &str) -> VmResult<Option<(usize, usize)>> {
+        for (start, c) in haystack.char_indices() {
+            if vm_try!(self.matches(c)) {
+                return VmResult::Ok(Some((start, start + c.len_utf8())));
+            }
+        }
+
+        VmResult::Ok(None)
+    }
 
-        VmResult::Ok((vm_try!(self.call::<bool>((c,))), c.len_utf8()))
+    fn rfind_in(&self, haystack: &str) -> VmResult<Option<(usize, usize)>> {
+        for (start, c) in haystack.char_indices().rev() {
+            if vm_try!(self.matches(c)) {
+                return VmResult::Ok(Some((start, start + c.len_utf8())));
+            }
+        }
+
+        VmResult::Ok(None)
+    }
+
+    fn prefix_of(&self, haystack: &str) -> VmResult<Option<usize>> {
+        match haystack.chars().next() {
+            Some(c) if vm_try!(self.matches(c)) => VmResult::Ok(Some(c.len_utf8())),
+            _ => VmResult::Ok(None),
+        }
+    }
+
+    fn suffix_of(&self, haystack: &str) -> VmResult<Option<usize>> {
+        match haystack.chars().next_back() {
+            Some(c) if vm_try!(self.matches(c)) => VmResult::Ok(Some(c.len_utf8())),
+            _ => VmResult::Ok(None),
+        }
     }
 
     #[inline]
@@ -1514,6 +2476,116 @@ impl Pattern for Function {
     }
 }
 
+impl CharPattern for char {
+    #[inline]
+    fn matches(&self, c: char) -> VmResult<bool> {
+        VmResult::Ok(*self == c)
+    }
+}
+
+impl CharPattern for runtime::Vec {
+    fn matches(&self, c: char) -> VmResult<bool> {
+        let found = self
+            .iter()
+            .any(|value| matches!(value.as_inline(), Some(Inline::Char(other)) if *other == c));
+
+        VmResult::Ok(found)
+    }
+}
+
+impl CharPattern for Function {
+    #[inline]
+    fn matches(&self, c: char) -> VmResult<bool> {
+        self.call::<bool>((c,))
+    }
+}
+
+/// The non-overlapping matches of a pattern in a string, searched for from
+/// the front.
+struct Matches<'a, P> {
+    haystack: &'a str,
+    pattern: &'a P,
+    from: Option<usize>,
+}
+
+impl<'a, P> Matches<'a, P>
+where
+    P: Pattern,
+{
+    fn new(haystack: &'a str, pattern: &'a P) -> Self {
+        Self {
+            haystack,
+            pattern,
+            from: Some(0),
+        }
+    }
+
+    fn next(&mut self) -> VmResult<Option<(usize, usize)>> {
+        let Some(from) = self.from.take() else {
+            return VmResult::Ok(None);
+        };
+
+        let Some((start, end)) = vm_try!(self.pattern.find_in(&self.haystack[from..])) else {
+            return VmResult::Ok(None);
+        };
+
+        let (start, end) = (from + start, from + end);
+
+        // An empty match can only be followed by a match after the next
+        // character.
+        self.from = if start == end {
+            let next = self.haystack[end..].chars().next();
+            next.map(|c| end + c.len_utf8())
+        } else {
+            Some(end)
+        };
+
+        VmResult::Ok(Some((start, end)))
+    }
+}
+
+/// The non-overlapping matches of a pattern in a string, searched for from
+/// the back.
+struct RMatches<'a, P> {
+    haystack: &'a str,
+    pattern: &'a P,
+    to: Option<usize>,
+}
+
+impl<'a, P> RMatches<'a, P>
+where
+    P: Pattern,
+{
+    fn new(haystack: &'a str, pattern: &'a P) -> Self {
+        Self {
+            haystack,
+            pattern,
+            to: Some(haystack.len()),
+        }
+    }
+
+    fn next(&mut self) -> VmResult<Option<(usize, usize)>> {
+        let Some(to) = self.to.take() else {
+            return VmResult::Ok(None);
+        };
+
+        let Some((start, end)) = vm_try!(self.pattern.rfind_in(&self.haystack[..to])) else {
+            return VmResult::Ok(None);
+        };
+
+        // An empty match can only be followed by a match before the previous
+        // character.
+        self.to = if start == end {
+            let prev = self.haystack[..start].chars().next_back();
+            prev.map(|c| start - c.len_utf8())
+        } else {
+            Some(start)
+        };
+
+        VmResult::Ok(Some((start, end)))
+    }
+}
+
 #[derive(Any)]
 #[rune(item = ::std::string)]
 struct Split<T>
@@ -0,0 +1,124 @@
+//! Working with tasks and their execution.
+
+use core::future::poll_fn;
+
+use ::rust_alloc::rc::Rc;
+
+use crate as rune;
+use crate::runtime::{self, Future, JoinState, Task, Value, VmErrorKind, VmResult};
+use crate::{Any, ContextError, Module};
+
+/// Working with tasks and their execution.
+#[rune::module(::std::task)]
+pub fn module() -> Result<Module, ContextError> {
+    let mut module = Module::from_meta(self::module_meta)?;
+    module.function_meta(is_cancelled)?;
+    module.function_meta(spawn)?;
+
+    module.ty::<JoinHandle>()?;
+    module.function_meta(JoinHandle::is_finished__meta)?;
+    module.function_meta(JoinHandle::into_future__meta)?;
+    Ok(module)
+}
+
+/// Test if the current execution has been cancelled.
+///
+/// Executions are cancelled by the host through a cancellation token. When the
+/// host requests a graceful cancellation this returns `true`, allowing
+/// long-running loops to shut down on their own terms.
+///
+/// If the execution has no cancellation token associated with it, this always
+/// returns `false`.
+///
+/// # Examples
+///
+/// ```rune
+/// use std::task;
+///
+/// assert!(!task::is_cancelled());
+/// ```
+#[rune::function]
+fn is_cancelled() -> bool {
+    match runtime::env::cancellation() {
+        Some(cancellation) => cancellation.is_cancel_requested(),
+        None => false,
+    }
+}
+
+/// Spawn a future as a concurrent task, returning a handle which can be
+/// awaited to get the result of the task.
+///
+/// The task is handed to the spawner supplied by the host, so it keeps running
+/// even if the handle is dropped. Spawning errors if the host hasn't supplied a
+/// spawner.
+///
+/// # Examples
+///
+/// ```rune,no_run
+/// use std::task;
+///
+/// let task = task::spawn(async { 1 + 2 });
+/// assert_eq!(task.await, 3);
+/// ```
+#[rune::function]
+fn spawn(future: Value) -> VmResult<JoinHandle> {
+    let future = vm_try!(future.into_future());
+
+    let Some(spawner) = runtime::env::spawner() else {
+        return VmResult::err(VmErrorKind::MissingSpawner);
+    };
+
+    let state = Rc::new(JoinState::new());
+
+    let task = Task::new({
+        let state = state.clone();
+
+        async move {
+            let result = future.await;
+            state.complete(result);
+        }
+    });
+
+    if let Err(error) = spawner.spawn(task) {
+        return VmResult::err(VmErrorKind::SpawnFailed { error });
+    }
+
+    VmResult::Ok(JoinHandle { state })
+}
+
+/// A handle to a task started with [`spawn`].
+///
+/// Awaiting the handle waits for the task to finish and produces its result.
+/// The result can only be awaited once.
+#[derive(Any)]
+#[rune(item = ::std::task)]
+pub struct JoinHandle {
+    state: Rc<JoinState>,
+}
+
+impl JoinHandle {
+    /// Test if the task has finished.
+    ///
+    /// # Examples
+    ///
+    /// ```rune,no_run
+    /// use std::task;
+    ///
+    /// let task = task::spawn(async { 42 });
+    ///
+    /// if !task.is_finished() {
+    ///     println!("Task is still running");
+    /// }
+    /// ```
+    #[rune::function(keep)]
+    fn is_finished(&self) -> bool {
+        self.state.is_finished()
+    }
+
+    /// Convert the handle into a future which waits for the task to finish.
+    #[rune::function(keep, protocol = INTO_FUTURE)]
+    fn into_future(self) -> VmResult<Future> {
+        let state = self.state;
+        VmResult::Ok(vm_try!(Future::new(poll_fn(move |cx| state.poll_join(cx)))))
+    }
+}
@@ -0,0 +1,28 @@
+//! Exact-width 32-bit unsigned integers.
+
+use core::num::ParseIntError;
+
+use crate as rune;
+use crate::runtime::{Bytes, Value, VmResult};
+use crate::{ContextError, Module};
+
+/// Exact-width 32-bit unsigned integers.
+///
+/// Integers are stored as 64-bit values, so this provides functions which
+/// perform arithmetic and bit manipulation as if the values were exactly 32
+/// bits wide. Arguments which are out of bounds for the type cause an error.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(u32::wrapping_add(u32::MAX, 1), u32::MIN);
+/// assert_eq!(u32::checked_add(u32::MAX, 1), None);
+/// ```
+#[rune::module(::std::u32)]
+pub fn module() -> Result<Module, ContextError> {
+    let mut m = Module::from_meta(self::module_meta)?;
+    sized!(m, u32);
+    Ok(m)
+}
+
+sized_fns!(u32);
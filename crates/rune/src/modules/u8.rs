@@ -0,0 +1,28 @@
+//! Exact-width 8-bit unsigned integers.
+
+use core::num::ParseIntError;
+
+use crate as rune;
+use crate::runtime::{Bytes, Value, VmResult};
+use crate::{ContextError, Module};
+
+/// Exact-width 8-bit unsigned integers.
+///
+/// Integers are stored as 64-bit values, so this provides functions which
+/// perform arithmetic and bit manipulation as if the values were exactly 8
+/// bits wide. Arguments which are out of bounds for the type cause an error.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(u8::wrapping_add(u8::MAX, 1), u8::MIN);
+/// assert_eq!(u8::checked_add(u8::MAX, 1), None);
+/// ```
+#[rune::module(::std::u8)]
+pub fn module() -> Result<Module, ContextError> {
+    let mut m = Module::from_meta(self::module_meta)?;
+    sized!(m, u8);
+    Ok(m)
+}
+
+sized_fns!(u8);
@@ -9,6 +9,8 @@ pub struct RawEnv {
     pub(crate) context: Option<NonNull<()>>,
     pub(crate) unit: Option<NonNull<()>>,
     pub(crate) diagnostics: Option<NonNull<()>>,
+    pub(crate) cancellation: Option<NonNull<()>>,
+    pub(crate) spawner: Option<NonNull<()>>,
 }
 
 impl RawEnv {
@@ -18,6 +20,8 @@ impl RawEnv {
             context: None,
             unit: None,
             diagnostics: None,
+            cancellation: None,
+            spawner: None,
         }
     }
 }
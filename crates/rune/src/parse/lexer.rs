@@ -250,9 +250,18 @@ impl<'a> Lexer<'a> {
                     self.iter.next();
                 }
                 c if c.is_alphanumeric() => {
-                    if split.is_none()
-                        && matches!((c, base), ('u' | 'i', _) | ('f', ast::NumberBase::Decimal))
-                    {
+                    // NB: The single character `n` and `d` suffixes are only
+                    // considered if they terminate the literal.
+                    let is_suffix = match (c, base) {
+                        ('u' | 'i', _) | ('f', ast::NumberBase::Decimal) => true,
+                        ('n', _) | ('d', ast::NumberBase::Decimal) => !self
+                            .iter
+                            .peek2()
+                            .is_some_and(|c| c.is_alphanumeric() || c == '_'),
+                        _ => false,
+                    };
+
+                    if split.is_none() && is_suffix {
                         split = Some(self.iter.pos());
                     }
 
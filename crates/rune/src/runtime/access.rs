@@ -93,6 +93,11 @@ impl Snapshot {
         self.0 & MASK != 0
     }
 
+    /// Test if the value has been taken.
+    pub(crate) fn is_taken(&self) -> bool {
+        self.0 & MOVED != 0
+    }
+
     /// The number of times a value is shared.
     pub(crate) fn shared(&self) -> usize {
         self.0 & !MASK
@@ -358,6 +358,12 @@ impl AnyObj {
         }
     }
 
+    /// Get the address of the shared object, used to identify it.
+    #[inline]
+    pub(crate) fn addr(&self) -> usize {
+        self.shared.as_ptr() as usize
+    }
+
     /// Get a reference to the interior value while checking for shared access.
     ///
     /// This prevents other exclusive accesses from being performed while the
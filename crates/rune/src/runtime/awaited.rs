@@ -1,4 +1,8 @@
-use crate::runtime::{Future, Output, Select, Vm, VmResult};
+use core::future::Future as _;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use crate::runtime::{CancellationToken, Future, Output, Select, Vm, VmResult};
 
 /// A stored await task.
 #[derive(Debug)]
@@ -11,14 +15,23 @@ pub(crate) enum Awaited {
 
 impl Awaited {
     /// Wait for the given awaited into the specified virtual machine.
+    ///
+    /// If the virtual machine has an associated cancellation token, the wait
+    /// is aborted once it is cancelled.
     pub(crate) async fn into_vm(self, vm: &mut Vm) -> VmResult<()> {
+        let cancellation = vm.cancellation().cloned();
+
         match self {
             Self::Future(future, out) => {
-                let value = vm_try!(future.await.with_vm(vm));
+                let value = vm_try!(CancellationToken::guard(cancellation, future)
+                    .await
+                    .with_vm(vm));
                 vm_try!(out.store(vm.stack_mut(), value));
             }
             Self::Select(select, value_addr) => {
-                let (ip, value) = vm_try!(select.await.with_vm(vm));
+                let (ip, value) = vm_try!(CancellationToken::guard(cancellation, select)
+                    .await
+                    .with_vm(vm));
                 vm.set_ip(ip);
                 vm_try!(value_addr.store(vm.stack_mut(), || value));
             }
@@ -26,4 +39,44 @@ impl Awaited {
 
         VmResult::Ok(())
     }
+
+    /// Poll the awaited value, storing the result into the specified virtual
+    /// machine once it's ready.
+    ///
+    /// This is used when the awaited value is driven by something other than
+    /// an async executor, like a [`Scheduler`].
+    ///
+    /// [`Scheduler`]: crate::runtime::Scheduler
+    pub(crate) fn poll(&mut self, cx: &mut Context<'_>, vm: &mut Vm) -> Poll<VmResult<()>> {
+        let result = match self {
+            Self::Future(future, out) => {
+                let Poll::Ready(result) = Pin::new(future).poll(cx) else {
+                    return Poll::Pending;
+                };
+
+                match result.with_vm(vm) {
+                    VmResult::Ok(value) => out.store(vm.stack_mut(), value),
+                    VmResult::Err(error) => return Poll::Ready(VmResult::Err(error)),
+                }
+            }
+            Self::Select(select, value_addr) => {
+                let Poll::Ready(result) = Pin::new(select).poll(cx) else {
+                    return Poll::Pending;
+                };
+
+                match result.with_vm(vm) {
+                    VmResult::Ok((ip, value)) => {
+                        vm.set_ip(ip);
+                        value_addr.store(vm.stack_mut(), || value)
+                    }
+                    VmResult::Err(error) => return Poll::Ready(VmResult::Err(error)),
+                }
+            }
+        };
+
+        Poll::Ready(match result {
+            Ok(()) => VmResult::Ok(()),
+            Err(error) => VmResult::err(error),
+        })
+    }
 }
@@ -0,0 +1,208 @@
+//! Script backtraces captured by virtual machine errors.
+
+use core::fmt;
+
+use ::rust_alloc::sync::Arc;
+
+use serde::Serialize;
+
+use crate::alloc;
+use crate::alloc::fmt::TryWrite;
+use crate::alloc::prelude::*;
+use crate::alloc::String;
+use crate::ast::Span;
+use crate::runtime::vm_error::VmErrorNative;
+use crate::runtime::{DebugInfo, DebugInst, Unit};
+use crate::{Hash, SourceId, Sources};
+
+/// The kind of a [`BacktraceFrame`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum BacktraceFrameKind {
+    /// A function defined in a script.
+    Script,
+    /// A native function provided by a module.
+    Native,
+}
+
+/// A single frame in the backtrace of a [`VmError`].
+///
+/// See [`VmError::backtrace`].
+///
+/// [`VmError`]: crate::runtime::VmError
+/// [`VmError::backtrace`]: crate::runtime::VmError::backtrace
+#[derive(Debug, Serialize)]
+#[non_exhaustive]
+pub struct BacktraceFrame {
+    /// The kind of function the frame belongs to.
+    pub kind: BacktraceFrameKind,
+    /// The hash of the function, if known.
+    pub hash: Option<Hash>,
+    /// The name of the function, if known.
+    pub function: Option<String>,
+    /// The location in the source the frame was executing, if known.
+    ///
+    /// This is only available for script frames in units which were compiled
+    /// with debug information.
+    pub location: Option<BacktraceLocation>,
+}
+
+impl fmt::Display for BacktraceFrame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.function, self.kind) {
+            (Some(function), _) => function.fmt(f)?,
+            (None, BacktraceFrameKind::Script) => write!(f, "<unknown>")?,
+            (None, BacktraceFrameKind::Native) => write!(f, "<native>")?,
+        }
+
+        if let Some(location) = &self.location {
+            write!(f, " at {location}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The source location of a [`BacktraceFrame`].
+#[derive(Debug, Serialize)]
+#[non_exhaustive]
+pub struct BacktraceLocation {
+    /// The identifier of the source.
+    pub source_id: SourceId,
+    /// The name of the source.
+    pub source: String,
+    /// The one-based line of the location.
+    pub line: usize,
+    /// The one-based column of the location.
+    pub column: usize,
+}
+
+impl fmt::Display for BacktraceLocation {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.source, self.line, self.column)
+    }
+}
+
+/// A raw frame in a backtrace which hasn't been resolved against sources.
+pub(crate) enum RawFrame<'a> {
+    /// A frame executing a script function.
+    Script {
+        /// The unit being executed.
+        unit: &'a Arc<Unit>,
+        /// The instruction being executed.
+        ip: usize,
+        /// If the frame is a caller, in which case `ip` is the return address.
+        caller: bool,
+    },
+    /// A frame executing a native function.
+    Native(&'a VmErrorNative),
+}
+
+impl RawFrame<'_> {
+    /// Get the debug instruction associated with a script frame.
+    pub(crate) fn debug_inst(&self) -> Option<&DebugInst> {
+        let Self::Script { unit, ip, caller } = *self else {
+            return None;
+        };
+
+        let debug_info = unit.debug_info()?;
+
+        if !caller {
+            return debug_info.instruction_at(ip);
+        }
+
+        // NB: The return address of a caller points to the instruction
+        // following the call, so we look for the closest instruction before
+        // it.
+        let (_, debug_inst) = debug_info
+            .instructions
+            .iter()
+            .filter(|(at, _)| **at < ip)
+            .max_by_key(|(at, _)| **at)?;
+
+        Some(debug_inst)
+    }
+
+    /// Get the source id and span associated with the frame.
+    pub(crate) fn span(&self) -> Option<(SourceId, Span)> {
+        let debug_inst = self.debug_inst()?;
+        Some((debug_inst.source_id, debug_inst.span))
+    }
+
+    /// Resolve the raw frame against the given sources.
+    pub(crate) fn resolve(&self, sources: &Sources) -> alloc::Result<BacktraceFrame> {
+        match *self {
+            Self::Script { unit, ip, .. } => {
+                let signature = unit
+                    .debug_info()
+                    .and_then(|debug_info| debug_info.function_containing(ip));
+
+                let (hash, function) = match signature {
+                    Some((hash, signature)) => (Some(hash), Some(signature.try_to_string()?)),
+                    None => (None, None),
+                };
+
+                let location = match self.span() {
+                    Some((source_id, span)) => location(sources, source_id, span)?,
+                    None => None,
+                };
+
+                Ok(BacktraceFrame {
+                    kind: BacktraceFrameKind::Script,
+                    hash,
+                    function,
+                    location,
+                })
+            }
+            Self::Native(native) => Ok(BacktraceFrame {
+                kind: BacktraceFrameKind::Native,
+                hash: Some(native.hash),
+                function: native.name.try_clone()?,
+                location: None,
+            }),
+        }
+    }
+}
+
+/// Resolve the name of a native function being called from the given unit.
+///
+/// Instance functions are resolved using the type of the instance and the
+/// hash of the name of the function being called.
+pub(crate) fn native_name(
+    debug_info: Option<&DebugInfo>,
+    hash: Hash,
+    instance: Option<(&dyn fmt::Display, Hash)>,
+) -> Option<String> {
+    let debug_info = debug_info?;
+
+    if let Some(item) = debug_info.item_for_hash(hash) {
+        return item.try_to_string().ok();
+    }
+
+    let (instance, name) = instance?;
+    let ident = debug_info.ident_for_hash(name)?;
+    let mut string = String::new();
+    write!(string, "{instance}::{ident}").ok()?;
+    Some(string)
+}
+
+fn location(
+    sources: &Sources,
+    source_id: SourceId,
+    span: Span,
+) -> alloc::Result<Option<BacktraceLocation>> {
+    let Some(source) = sources.get(source_id) else {
+        return Ok(None);
+    };
+
+    let (line, column) = source.pos_to_utf8_linecol(span.start.into_usize());
+
+    Ok(Some(BacktraceLocation {
+        source_id,
+        source: source.name().try_to_owned()?,
+        line: line.saturating_add(1),
+        column: column.saturating_add(1),
+    }))
+}
@@ -17,6 +17,13 @@ use crate::alloc::callable::Callable;
 
 use pin_project::pin_project;
 
+/// Bit set in the stored budget when it's a slice, in which case virtual
+/// machines which exhaust it during asynchronous execution yield instead of
+/// erroring.
+///
+/// A budget of `usize::MAX` is unlimited and never a slice.
+const SLICE: usize = 1 << (usize::BITS - 1);
+
 /// Wrapper for something being [budgeted].
 ///
 /// See [with].
@@ -26,6 +33,8 @@ use pin_project::pin_project;
 pub struct Budget<T> {
     /// Instruction budget.
     budget: usize,
+    /// If the budget is refilled every time the value is polled.
+    slice: bool,
     /// The thing being budgeted.
     #[pin]
     value: T,
@@ -85,7 +94,60 @@ pub struct Budget<T> {
 /// ```
 pub fn with<T>(budget: usize, value: T) -> Budget<T> {
     tracing::trace!(?budget);
-    Budget { budget, value }
+
+    Budget {
+        budget: clamp(budget),
+        slice: false,
+        value,
+    }
+}
+
+/// Wrap the given value with a budget which is refilled every time it's
+/// polled.
+///
+/// In contrast to [`with`], a virtual machine which exhausts the budget while
+/// executing asynchronously yields to the executor rather than erroring, and
+/// resumes with a fresh budget the next time it's polled. This is used to
+/// preempt tasks which would otherwise never give up control, like a task
+/// spinning in a loop.
+///
+/// # Examples
+///
+/// ```
+/// use rune::runtime::budget;
+///
+/// let f = budget::slice(1, || {
+///     let mut budget = budget::acquire();
+///     assert!(budget.take());
+///     assert!(!budget.take());
+/// });
+///
+/// f.call();
+/// ```
+pub fn slice<T>(budget: usize, value: T) -> Budget<T> {
+    tracing::trace!(?budget);
+
+    Budget {
+        budget: clamp(budget),
+        slice: true,
+        value,
+    }
+}
+
+/// Clamp a budget so that it doesn't overlap with the [`SLICE`] bit.
+#[inline]
+fn clamp(budget: usize) -> usize {
+    if budget == usize::MAX {
+        budget
+    } else {
+        budget.min(!SLICE)
+    }
+}
+
+/// Test if the budget currently in effect is a [`slice`].
+pub(crate) fn is_slice() -> bool {
+    let budget = self::no_std::rune_budget_get();
+    budget != usize::MAX && budget & SLICE != 0
 }
 
 /// Acquire the current budget.
@@ -111,7 +173,7 @@ impl BudgetGuard {
             return true;
         }
 
-        if self.0 == 0 {
+        if self.0 & !SLICE == 0 {
             return false;
         }
 
@@ -126,6 +188,18 @@ impl Drop for BudgetGuard {
     }
 }
 
+impl<T> Budget<T> {
+    /// The budget as it's stored for the current thread.
+    #[inline]
+    fn stored(&self) -> usize {
+        if self.slice {
+            self.budget | SLICE
+        } else {
+            self.budget
+        }
+    }
+}
+
 impl<T> Budget<T>
 where
     T: Callable,
@@ -144,7 +218,7 @@ where
 
     #[inline]
     fn call(self) -> Self::Output {
-        let _guard = BudgetGuard(self::no_std::rune_budget_replace(self.budget));
+        let _guard = BudgetGuard(self::no_std::rune_budget_replace(self.stored()));
         self.value.call()
     }
 }
@@ -156,11 +230,16 @@ where
     type Output = T::Output;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let stored = self.stored();
         let this = self.project();
 
-        let _guard = BudgetGuard(self::no_std::rune_budget_replace(*this.budget));
+        let _guard = BudgetGuard(self::no_std::rune_budget_replace(stored));
         let poll = this.value.poll(cx);
-        *this.budget = self::no_std::rune_budget_get();
+
+        if !*this.slice {
+            *this.budget = self::no_std::rune_budget_get();
+        }
+
         poll
     }
 }
@@ -0,0 +1,324 @@
+//! Cooperative cancellation of virtual machine executions.
+//!
+//! A [`CancellationToken`] is associated with a [`Vm`] through
+//! [`Vm::set_cancellation`] or [`VmExecution::cancellation_token`]. Once
+//! cancelled, the virtual machine stops when it's next resumed, at an await
+//! point or within a bounded number of instructions with an error for which
+//! [`VmError::is_cancelled`] returns `true`.
+//!
+//! Cancellation can also be requested gracefully through
+//! [`CancellationToken::request_cancel`], in which case the execution keeps
+//! running but scripts can observe the request through
+//! `std::task::is_cancelled` and shut down on their own terms.
+//!
+//! [`Vm`]: crate::Vm
+//! [`Vm::set_cancellation`]: crate::Vm::set_cancellation
+//! [`VmExecution::cancellation_token`]: crate::runtime::VmExecution::cancellation_token
+//! [`VmError::is_cancelled`]: crate::runtime::VmError::is_cancelled
+
+#[cfg_attr(feature = "std", path = "cancellation/std.rs")]
+mod no_std;
+
+use core::fmt;
+use core::future::Future;
+use core::pin::{pin, Pin};
+use core::sync::atomic::{AtomicU8, Ordering};
+use core::task::{Context, Poll, Waker};
+
+use ::rust_alloc::sync::Arc;
+use ::rust_alloc::vec::Vec;
+
+use futures_util::future::{select, Either};
+
+use crate::runtime::{VmErrorKind, VmResult};
+
+use self::no_std::Lock;
+
+/// No cancellation has been requested.
+const NONE: u8 = 0;
+/// Cancellation has been requested, but execution may continue.
+const REQUESTED: u8 = 1;
+/// Execution has been cancelled.
+const CANCELLED: u8 = 2;
+
+pub(crate) struct Shared {
+    state: AtomicU8,
+    waiters: Waiters,
+}
+
+/// The wakers of every task waiting for cancellation.
+///
+/// Each [`Cancelled`] future owns one slot, which it keeps until it's dropped.
+/// Cancelling takes the wakers out of their slots but leaves the slots in
+/// place, so that the key held by a future stays valid.
+struct Waiters {
+    slots: Lock<Slots>,
+}
+
+#[derive(Default)]
+struct Slots {
+    wakers: Vec<Option<Waker>>,
+    free: Vec<usize>,
+}
+
+impl Waiters {
+    fn new() -> Self {
+        Self {
+            slots: Lock::new(Slots::default()),
+        }
+    }
+
+    /// Register or update the waker stored under `key`.
+    fn register(&self, key: &mut Option<usize>, waker: &Waker) {
+        self.slots.with(|slots| {
+            if let Some(index) = *key {
+                match &mut slots.wakers[index] {
+                    Some(existing) if existing.will_wake(waker) => {}
+                    slot => *slot = Some(waker.clone()),
+                }
+
+                return;
+            }
+
+            let waker = Some(waker.clone());
+
+            let index = match slots.free.pop() {
+                Some(index) => {
+                    slots.wakers[index] = waker;
+                    index
+                }
+                None => {
+                    slots.wakers.push(waker);
+                    slots.wakers.len() - 1
+                }
+            };
+
+            *key = Some(index);
+        })
+    }
+
+    /// Release the slot stored under `key`.
+    fn remove(&self, key: usize) {
+        self.slots.with(|slots| {
+            slots.wakers[key] = None;
+            slots.free.push(key);
+        })
+    }
+
+    /// Wake every registered waiter.
+    fn wake_all(&self) {
+        let wakers = self.slots.with(|slots| {
+            slots
+                .wakers
+                .iter_mut()
+                .filter_map(Option::take)
+                .collect::<Vec<_>>()
+        });
+
+        // NB: wake outside of the lock, since waking might re-enter.
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
+
+/// A handle used to cooperatively cancel an execution.
+///
+/// Cloning the token produces a handle to the same underlying cancellation
+/// state, so a clone can be handed to another thread or task which is
+/// responsible for cancelling the execution.
+///
+/// # Examples
+///
+/// ```
+/// use rune::Vm;
+/// use std::sync::Arc;
+///
+/// let mut sources = rune::sources! {
+///     entry => {
+///         pub fn main() {
+///             loop {}
+///         }
+///     }
+/// };
+///
+/// let unit = rune::prepare(&mut sources).build()?;
+///
+/// let mut vm = Vm::without_runtime(Arc::new(unit));
+/// let mut execution = vm.execute(["main"], ())?;
+///
+/// let token = execution.cancellation_token();
+/// token.cancel();
+///
+/// let error = execution.complete().into_result().unwrap_err();
+/// assert!(error.is_cancelled());
+/// # Ok::<_, rune::support::Error>(())
+/// ```
+#[derive(Clone)]
+pub struct CancellationToken {
+    shared: Arc<Shared>,
+}
+
+impl CancellationToken {
+    /// Construct a new token which has not been cancelled.
+    pub fn new() -> Self {
+        Self {
+            shared: Arc::new(Shared {
+                state: AtomicU8::new(NONE),
+                waiters: Waiters::new(),
+            }),
+        }
+    }
+
+    /// Cancel every execution associated with this token.
+    ///
+    /// Every execution or task currently waiting on the token is woken up so
+    /// that it can observe the cancellation.
+    pub fn cancel(&self) {
+        self.shared.state.store(CANCELLED, Ordering::Release);
+        self.shared.waiters.wake_all();
+    }
+
+    /// Request that executions associated with this token shut down
+    /// gracefully.
+    ///
+    /// In contrast to [`cancel`], this doesn't stop the execution. It's up to
+    /// the script to observe the request through `std::task::is_cancelled`.
+    ///
+    /// [`cancel`]: Self::cancel
+    pub fn request_cancel(&self) {
+        let _ = self.shared.state.compare_exchange(
+            NONE,
+            REQUESTED,
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        );
+    }
+
+    /// Test if the token has been cancelled through [`cancel`].
+    ///
+    /// [`cancel`]: Self::cancel
+    #[inline]
+    pub fn is_cancelled(&self) -> bool {
+        self.shared.state.load(Ordering::Acquire) == CANCELLED
+    }
+
+    /// Test if cancellation has been requested through either [`cancel`] or
+    /// [`request_cancel`].
+    ///
+    /// [`cancel`]: Self::cancel
+    /// [`request_cancel`]: Self::request_cancel
+    #[inline]
+    pub fn is_cancel_requested(&self) -> bool {
+        self.shared.state.load(Ordering::Acquire) != NONE
+    }
+
+    /// Wait until the token has been cancelled.
+    pub fn cancelled(&self) -> Cancelled<'_> {
+        Cancelled {
+            token: self,
+            key: None,
+        }
+    }
+
+    /// Drive the given future to completion, unless the token is cancelled in
+    /// which case an error is returned instead.
+    pub(crate) async fn guard<F, T>(this: Option<Self>, future: F) -> VmResult<T>
+    where
+        F: Future<Output = VmResult<T>>,
+    {
+        let Some(this) = this else {
+            return future.await;
+        };
+
+        let future = pin!(future);
+        let cancelled = pin!(this.cancelled());
+
+        match select(future, cancelled).await {
+            Either::Left((result, _)) => result,
+            Either::Right(..) => VmResult::err(VmErrorKind::Cancelled),
+        }
+    }
+
+    /// Convert the token into a raw pointer to its shared state.
+    pub(crate) fn into_raw(self) -> *const Shared {
+        Arc::into_raw(self.shared)
+    }
+
+    /// Clone a token from a raw pointer.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must have been produced by [`CancellationToken::into_raw`]
+    /// and still be live.
+    pub(crate) unsafe fn clone_from_raw(ptr: *const Shared) -> Self {
+        Arc::increment_strong_count(ptr);
+
+        Self {
+            shared: Arc::from_raw(ptr),
+        }
+    }
+
+    /// Drop a token from a raw pointer.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must have been produced by [`CancellationToken::into_raw`].
+    pub(crate) unsafe fn drop_raw(ptr: *const Shared) {
+        drop(Arc::from_raw(ptr));
+    }
+}
+
+impl Default for CancellationToken {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for CancellationToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CancellationToken")
+            .field("cancelled", &self.is_cancelled())
+            .field("requested", &self.is_cancel_requested())
+            .finish()
+    }
+}
+
+/// Future returned by [`CancellationToken::cancelled`].
+pub struct Cancelled<'a> {
+    token: &'a CancellationToken,
+    key: Option<usize>,
+}
+
+impl Future for Cancelled<'_> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        if this.token.is_cancelled() {
+            return Poll::Ready(());
+        }
+
+        this.token
+            .shared
+            .waiters
+            .register(&mut this.key, cx.waker());
+
+        // NB: check again in case we raced with a call to `cancel`.
+        if this.token.is_cancelled() {
+            return Poll::Ready(());
+        }
+
+        Poll::Pending
+    }
+}
+
+impl Drop for Cancelled<'_> {
+    fn drop(&mut self) {
+        if let Some(key) = self.key.take() {
+            self.token.shared.waiters.remove(key);
+        }
+    }
+}
@@ -0,0 +1,25 @@
+// In no-std environments, the waiters are protected by a critical section.
+//
+// The implementor must provide a critical section implementation, which is
+// already required by the `once_cell` dependency.
+use core::cell::RefCell;
+
+use critical_section::Mutex;
+
+/// A lock protecting the state of waiters.
+pub(super) struct Lock<T> {
+    inner: Mutex<RefCell<T>>,
+}
+
+impl<T> Lock<T> {
+    pub(super) fn new(value: T) -> Self {
+        Self {
+            inner: Mutex::new(RefCell::new(value)),
+        }
+    }
+
+    /// Call the given closure with exclusive access to the locked value.
+    pub(super) fn with<O>(&self, f: impl FnOnce(&mut T) -> O) -> O {
+        critical_section::with(|cs| f(&mut self.inner.borrow_ref_mut(cs)))
+    }
+}
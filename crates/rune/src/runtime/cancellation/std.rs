@@ -0,0 +1,22 @@
+use std::sync::{Mutex, PoisonError};
+
+/// A lock protecting the state of waiters.
+pub(super) struct Lock<T> {
+    inner: Mutex<T>,
+}
+
+impl<T> Lock<T> {
+    pub(super) fn new(value: T) -> Self {
+        Self {
+            inner: Mutex::new(value),
+        }
+    }
+
+    /// Call the given closure with exclusive access to the locked value.
+    pub(super) fn with<O>(&self, f: impl FnOnce(&mut T) -> O) -> O {
+        // NB: the locked value is kept consistent by every operation, so a
+        // panic while it's held doesn't poison it.
+        let mut guard = self.inner.lock().unwrap_or_else(PoisonError::into_inner);
+        f(&mut guard)
+    }
+}
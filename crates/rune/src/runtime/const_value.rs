@@ -290,6 +290,14 @@ impl ConstValue {
 
                     ConstValueKind::Object(const_object)
                 }
+                runtime::BIG_INT_HASH => {
+                    let n = value.borrow_ref::<num::BigInt>()?;
+                    return n.clone().to_const_value();
+                }
+                runtime::DECIMAL_HASH => {
+                    let n = value.borrow_ref::<runtime::Decimal>()?;
+                    return n.clone().to_const_value();
+                }
                 _ => {
                     return Err(RuntimeError::from(VmErrorKind::ConstNotSupported {
                         actual: value.type_info(),
@@ -468,6 +476,16 @@ impl FromConstValue for ConstValue {
     }
 }
 
+impl FromConstValue for String {
+    #[inline]
+    fn from_const_value(value: ConstValue) -> Result<Self, RuntimeError> {
+        match value.kind {
+            ConstValueKind::String(string) => Ok(string),
+            kind => Err(RuntimeError::expected::<String>(kind.type_info())),
+        }
+    }
+}
+
 /// Implementation of a constant constructor.
 ///
 /// Do not implement manually, this is provided when deriving
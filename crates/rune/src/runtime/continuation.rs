@@ -0,0 +1,806 @@
+//! Serializable continuations of virtual machine executions.
+//!
+//! A [`VmContinuation`] captures everything needed to resume an execution at a
+//! later point, possibly in a different process: the call frames, the stack,
+//! the execution state and every value reachable from them. It implements
+//! [`Serialize`] and [`Deserialize`] and is restored against a unit with the
+//! same [`Unit::fingerprint`].
+
+use core::fmt;
+
+use ::rust_alloc::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::alloc::{self, HashMap, String, Vec};
+use crate::runtime::range::RangeIter;
+use crate::runtime::range_from::RangeFromIter;
+use crate::runtime::range_inclusive::RangeInclusiveIter;
+use crate::runtime::slice::Iter as SliceIter;
+use crate::runtime::{
+    AnyObj, Bytes, Call, ExecutionState, Function, Generator, Inline, InstAddress, Isolated,
+    Object, Output, OwnedTuple, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo,
+    RangeToInclusive, Ref, Repr, RttiKind, RuntimeContext, RuntimeError, Stack, TypeInfo, Unit,
+    Value, Vm, VmExecution,
+};
+use crate::{Hash, TypeHash};
+
+/// An error raised when snapshotting or restoring a [`VmContinuation`].
+#[derive(Debug)]
+pub struct ContinuationError {
+    kind: ContinuationErrorKind,
+}
+
+impl ContinuationError {
+    #[inline]
+    fn new(kind: ContinuationErrorKind) -> Self {
+        Self { kind }
+    }
+}
+
+#[derive(Debug)]
+enum ContinuationErrorKind {
+    Unsupported { type_info: TypeInfo },
+    ForeignUnit { type_info: TypeInfo },
+    Cyclic,
+    NestedExecution,
+    UnitMismatch { expected: Hash, actual: Hash },
+    MissingRtti { hash: Hash },
+    BadReference { index: usize },
+    BadRange,
+    Runtime { error: RuntimeError },
+    Alloc { error: alloc::Error },
+}
+
+impl fmt::Display for ContinuationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ContinuationErrorKind::Unsupported { type_info } => {
+                write!(f, "Values of type `{type_info}` cannot be snapshotted")
+            }
+            ContinuationErrorKind::ForeignUnit { type_info } => {
+                write!(
+                    f,
+                    "Value of type `{type_info}` belongs to a different unit and cannot be snapshotted"
+                )
+            }
+            ContinuationErrorKind::Cyclic => {
+                write!(f, "Cyclic values cannot be snapshotted")
+            }
+            ContinuationErrorKind::NestedExecution => {
+                write!(
+                    f,
+                    "Executions which have called into another unit cannot be snapshotted"
+                )
+            }
+            ContinuationErrorKind::UnitMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "Continuation was taken from unit `{expected}`, but restored against `{actual}`"
+                )
+            }
+            ContinuationErrorKind::MissingRtti { hash } => {
+                write!(f, "Missing runtime type information for `{hash}`")
+            }
+            ContinuationErrorKind::BadReference { index } => {
+                write!(f, "Continuation references missing value `{index}`")
+            }
+            ContinuationErrorKind::BadRange => {
+                write!(
+                    f,
+                    "Continuation contains a range iterator with mismatched bounds"
+                )
+            }
+            ContinuationErrorKind::Runtime { error } => error.fmt(f),
+            ContinuationErrorKind::Alloc { error } => error.fmt(f),
+        }
+    }
+}
+
+impl core::error::Error for ContinuationError {}
+
+impl From<alloc::Error> for ContinuationError {
+    #[inline]
+    fn from(error: alloc::Error) -> Self {
+        Self::new(ContinuationErrorKind::Alloc { error })
+    }
+}
+
+impl<E> From<E> for ContinuationErrorKind
+where
+    RuntimeError: From<E>,
+{
+    #[inline]
+    fn from(error: E) -> Self {
+        Self::Runtime {
+            error: RuntimeError::from(error),
+        }
+    }
+}
+
+/// A serializable snapshot of a suspended [`VmExecution`].
+///
+/// See [`VmExecution::snapshot`] and [`VmExecution::restore`].
+///
+/// # Examples
+///
+/// ```
+/// use rune::{Context, Vm};
+/// use rune::runtime::{GeneratorState, VmContinuation, VmExecution};
+/// use std::sync::Arc;
+///
+/// let mut sources = rune::sources! {
+///     entry => {
+///         pub fn main() {
+///             let total = [1, 2];
+///             let value = yield total;
+///             total.push(value);
+///             total
+///         }
+///     }
+/// };
+///
+/// let context = Context::with_default_modules()?;
+/// let runtime = Arc::new(context.runtime()?);
+/// let unit = Arc::new(rune::prepare(&mut sources).with_context(&context).build()?);
+///
+/// let mut vm = Vm::new(runtime.clone(), unit.clone());
+/// let mut execution = vm.execute(["main"], ())?;
+/// assert!(matches!(execution.resume().into_result()?, GeneratorState::Yielded(..)));
+///
+/// let continuation = execution.snapshot()?;
+/// let bytes = serde_json::to_vec(&continuation)?;
+///
+/// let continuation: VmContinuation = serde_json::from_slice(&bytes)?;
+/// let mut execution = VmExecution::restore(runtime, unit, continuation)?;
+///
+/// let GeneratorState::Complete(value) = execution.resume_with(rune::to_value(3)?).into_result()? else {
+///     panic!("expected completion");
+/// };
+///
+/// let value: Vec<i64> = rune::from_value(value)?;
+/// assert_eq!(value, [1, 2, 3]);
+/// # Ok::<_, rune::support::Error>(())
+/// ```
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VmContinuation {
+    /// The fingerprint of the unit the continuation was taken from.
+    unit: Hash,
+    /// Shared values reachable from the execution, every value only refers to
+    /// values which precede it.
+    values: ::rust_alloc::vec::Vec<Node>,
+    /// The snapshotted execution.
+    execution: ExecutionNode,
+}
+
+impl VmContinuation {
+    /// The fingerprint of the unit that the continuation was taken from.
+    ///
+    /// See [`Unit::fingerprint`].
+    pub fn unit_fingerprint(&self) -> Hash {
+        self.unit
+    }
+
+    /// Snapshot the given execution.
+    pub(crate) fn from_execution<T>(execution: &VmExecution<T>) -> Result<Self, ContinuationError>
+    where
+        T: AsRef<Vm> + AsMut<Vm>,
+    {
+        let vm = execution.vm();
+        let unit = vm.unit().clone();
+
+        let mut encoder = Encoder {
+            unit: &unit,
+            values: ::rust_alloc::vec::Vec::new(),
+            seen: HashMap::new(),
+            visiting: HashMap::new(),
+        };
+
+        let execution = encoder
+            .execution(execution)
+            .map_err(ContinuationError::new)?;
+
+        Ok(Self {
+            unit: unit.fingerprint()?,
+            values: encoder.values,
+            execution,
+        })
+    }
+
+    /// Restore the continuation into an execution using the given context and
+    /// unit.
+    pub(crate) fn into_execution(
+        self,
+        context: Arc<RuntimeContext>,
+        unit: Arc<Unit>,
+    ) -> Result<VmExecution<Vm>, ContinuationError> {
+        let actual = unit.fingerprint()?;
+
+        if self.unit != actual {
+            return Err(ContinuationError::new(
+                ContinuationErrorKind::UnitMismatch {
+                    expected: self.unit,
+                    actual,
+                },
+            ));
+        }
+
+        let mut decoder = Decoder {
+            context: &context,
+            unit: &unit,
+            values: Vec::new(),
+        };
+
+        for node in self.values {
+            let value = decoder.node(node).map_err(ContinuationError::new)?;
+            decoder.values.try_push(value)?;
+        }
+
+        decoder
+            .execution(self.execution)
+            .map_err(ContinuationError::new)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ExecutionNode {
+    state: StateNode,
+    ip: usize,
+    stack: ::rust_alloc::vec::Vec<Slot>,
+    top: usize,
+    call_frames: ::rust_alloc::vec::Vec<CallFrameNode>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+enum StateNode {
+    Initial,
+    Resumed(Output),
+    Suspended,
+    Exited(Option<InstAddress>),
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CallFrameNode {
+    ip: usize,
+    top: usize,
+    isolated: bool,
+    out: Output,
+}
+
+/// A single value slot.
+#[derive(Debug, Serialize, Deserialize)]
+enum Slot {
+    /// An inline value.
+    Inline(Inline),
+    /// A reference to a shared value.
+    Ref(usize),
+}
+
+/// A shared value.
+#[derive(Debug, Serialize, Deserialize)]
+enum Node {
+    String(::rust_alloc::string::String),
+    Bytes(::rust_alloc::vec::Vec<u8>),
+    Vec(::rust_alloc::vec::Vec<Slot>),
+    Tuple(::rust_alloc::vec::Vec<Slot>),
+    Object(::rust_alloc::vec::Vec<(::rust_alloc::string::String, Slot)>),
+    Option(Option<Slot>),
+    Result(Result<Slot, Slot>),
+    Dynamic {
+        rtti: Hash,
+        fields: ::rust_alloc::vec::Vec<Slot>,
+    },
+    Function {
+        offset: usize,
+        call: Call,
+        args: usize,
+        hash: Hash,
+        environment: Option<::rust_alloc::vec::Vec<Slot>>,
+    },
+    Generator(Option<ExecutionNode>),
+    Range(Slot, Slot),
+    RangeFrom(Slot),
+    RangeFull,
+    RangeInclusive(Slot, Slot),
+    RangeTo(Slot),
+    RangeToInclusive(Slot),
+    RangeIter {
+        start: Inline,
+        end: Inline,
+    },
+    RangeFromIter {
+        start: Inline,
+    },
+    RangeInclusiveIter {
+        start: Inline,
+        end: Inline,
+        exhausted: bool,
+    },
+    /// The values which remain in an iterator over a vector.
+    SliceIter(::rust_alloc::vec::Vec<Slot>),
+}
+
+struct Encoder<'a> {
+    unit: &'a Arc<Unit>,
+    values: ::rust_alloc::vec::Vec<Node>,
+    /// Values which have already been encoded, keyed by their address.
+    seen: HashMap<usize, usize>,
+    /// Values which are currently being encoded, used to detect cycles.
+    visiting: HashMap<usize, ()>,
+}
+
+impl Encoder<'_> {
+    fn execution<T>(
+        &mut self,
+        execution: &VmExecution<T>,
+    ) -> Result<ExecutionNode, ContinuationErrorKind>
+    where
+        T: AsRef<Vm> + AsMut<Vm>,
+    {
+        if execution.has_states() {
+            return Err(ContinuationErrorKind::NestedExecution);
+        }
+
+        let vm = execution.vm();
+
+        if !vm.is_same_unit(self.unit) {
+            return Err(ContinuationErrorKind::NestedExecution);
+        }
+
+        let state = match execution.state() {
+            ExecutionState::Initial => StateNode::Initial,
+            ExecutionState::Resumed(out) => StateNode::Resumed(out),
+            ExecutionState::Suspended => StateNode::Suspended,
+            ExecutionState::Exited(addr) => StateNode::Exited(addr),
+        };
+
+        let (values, top) = vm.stack().as_parts();
+        let mut stack = ::rust_alloc::vec::Vec::with_capacity(values.len());
+
+        for value in values {
+            stack.push(self.slot(value)?);
+        }
+
+        let call_frames = vm
+            .call_frames()
+            .iter()
+            .map(|frame| CallFrameNode {
+                ip: frame.ip,
+                top: frame.top,
+                isolated: matches!(frame.isolated, Isolated::Isolated),
+                out: frame.out,
+            })
+            .collect();
+
+        Ok(ExecutionNode {
+            state,
+            ip: vm.ip(),
+            stack,
+            top,
+            call_frames,
+        })
+    }
+
+    fn slots<'v>(
+        &mut self,
+        values: impl IntoIterator<Item = &'v Value>,
+    ) -> Result<::rust_alloc::vec::Vec<Slot>, ContinuationErrorKind> {
+        let mut slots = ::rust_alloc::vec::Vec::new();
+
+        for value in values {
+            slots.push(self.slot(value)?);
+        }
+
+        Ok(slots)
+    }
+
+    fn slot(&mut self, value: &Value) -> Result<Slot, ContinuationErrorKind> {
+        let (addr, snapshot) = match value.as_ref() {
+            Repr::Inline(value) => return Ok(Slot::Inline(*value)),
+            Repr::Dynamic(value) => (value.addr(), value.snapshot()),
+            Repr::Any(value) => (value.addr(), value.snapshot()),
+        };
+
+        // NB: values which have been moved out of can't be observed by the
+        // execution any longer.
+        if snapshot.is_taken() {
+            return Ok(Slot::Inline(Inline::Empty));
+        }
+
+        if let Some(&index) = self.seen.get(&addr) {
+            return Ok(Slot::Ref(index));
+        }
+
+        if self.visiting.try_insert(addr, ())?.is_some() {
+            return Err(ContinuationErrorKind::Cyclic);
+        }
+
+        let node = self.node(value)?;
+        self.visiting.remove(&addr);
+
+        let index = self.values.len();
+        self.values.push(node);
+        self.seen.try_insert(addr, index)?;
+        Ok(Slot::Ref(index))
+    }
+
+    fn node(&mut self, value: &Value) -> Result<Node, ContinuationErrorKind> {
+        let node = match value.as_ref() {
+            Repr::Inline(..) => unreachable!("inline values are not shared"),
+            Repr::Dynamic(value) => {
+                let fields = value.borrow_ref()?;
+
+                Node::Dynamic {
+                    rtti: value.rtti().hash,
+                    fields: self.slots(fields.iter())?,
+                }
+            }
+            Repr::Any(value) => match value.type_hash() {
+                String::HASH => {
+                    let string = value.borrow_ref::<String>()?;
+                    Node::String(string.as_str().into())
+                }
+                Bytes::HASH => {
+                    let bytes = value.borrow_ref::<Bytes>()?;
+                    Node::Bytes(bytes.as_slice().into())
+                }
+                crate::runtime::Vec::HASH => {
+                    let vec = value.borrow_ref::<crate::runtime::Vec>()?;
+                    Node::Vec(self.slots(vec.iter())?)
+                }
+                OwnedTuple::HASH => {
+                    let tuple = value.borrow_ref::<OwnedTuple>()?;
+                    Node::Tuple(self.slots(tuple.iter())?)
+                }
+                Object::HASH => {
+                    let object = value.borrow_ref::<Object>()?;
+                    let mut entries = ::rust_alloc::vec::Vec::new();
+
+                    for (key, value) in object.iter() {
+                        entries.push((key.as_str().into(), self.slot(value)?));
+                    }
+
+                    Node::Object(entries)
+                }
+                Option::<Value>::HASH => {
+                    let option = value.borrow_ref::<Option<Value>>()?;
+
+                    Node::Option(match &*option {
+                        Some(value) => Some(self.slot(value)?),
+                        None => None,
+                    })
+                }
+                Result::<Value, Value>::HASH => {
+                    let result = value.borrow_ref::<Result<Value, Value>>()?;
+
+                    Node::Result(match &*result {
+                        Ok(value) => Ok(self.slot(value)?),
+                        Err(value) => Err(self.slot(value)?),
+                    })
+                }
+                Function::HASH => {
+                    let function = value.borrow_ref::<Function>()?;
+
+                    let Some(parts) = function.as_offset(self.unit) else {
+                        return Err(ContinuationErrorKind::ForeignUnit {
+                            type_info: value.type_info(),
+                        });
+                    };
+
+                    let environment = match parts.environment {
+                        Some(environment) => Some(self.slots(environment)?),
+                        None => None,
+                    };
+
+                    Node::Function {
+                        offset: parts.offset,
+                        call: parts.call,
+                        args: parts.args,
+                        hash: parts.hash,
+                        environment,
+                    }
+                }
+                Generator::<Vm>::HASH => {
+                    let generator = value.borrow_ref::<Generator<Vm>>()?;
+
+                    Node::Generator(match generator.execution() {
+                        Some(execution) => Some(self.execution(execution)?),
+                        None => None,
+                    })
+                }
+                Range::HASH => {
+                    let range = value.borrow_ref::<Range>()?;
+                    Node::Range(self.slot(&range.start)?, self.slot(&range.end)?)
+                }
+                RangeFrom::HASH => {
+                    let range = value.borrow_ref::<RangeFrom>()?;
+                    Node::RangeFrom(self.slot(&range.start)?)
+                }
+                RangeFull::HASH => Node::RangeFull,
+                RangeInclusive::HASH => {
+                    let range = value.borrow_ref::<RangeInclusive>()?;
+                    Node::RangeInclusive(self.slot(&range.start)?, self.slot(&range.end)?)
+                }
+                RangeTo::HASH => {
+                    let range = value.borrow_ref::<RangeTo>()?;
+                    Node::RangeTo(self.slot(&range.end)?)
+                }
+                RangeToInclusive::HASH => {
+                    let range = value.borrow_ref::<RangeToInclusive>()?;
+                    Node::RangeToInclusive(self.slot(&range.end)?)
+                }
+                SliceIter::HASH => {
+                    let iter = value.borrow_ref::<SliceIter>()?;
+                    Node::SliceIter(self.slots(iter.as_slice())?)
+                }
+                _ => match range_iter(value)? {
+                    Some(node) => node,
+                    None => {
+                        return Err(ContinuationErrorKind::Unsupported {
+                            type_info: value.type_info(),
+                        });
+                    }
+                },
+            },
+        };
+
+        Ok(node)
+    }
+}
+
+/// Encode the state of an iterator over a range of integers or characters.
+fn range_iter(value: &AnyObj) -> Result<Option<Node>, ContinuationErrorKind> {
+    macro_rules! bounds {
+        ($($ty:ty => $variant:ident),* $(,)?) => {
+            $(
+                if value.type_hash() == RangeIter::<$ty>::HASH {
+                    let iter = value.borrow_ref::<RangeIter<$ty>>()?;
+                    let range = iter.as_range();
+
+                    return Ok(Some(Node::RangeIter {
+                        start: Inline::$variant(range.start),
+                        end: Inline::$variant(range.end),
+                    }));
+                }
+
+                if value.type_hash() == RangeFromIter::<$ty>::HASH {
+                    let iter = value.borrow_ref::<RangeFromIter<$ty>>()?;
+
+                    return Ok(Some(Node::RangeFromIter {
+                        start: Inline::$variant(iter.as_range().start),
+                    }));
+                }
+
+                if value.type_hash() == RangeInclusiveIter::<$ty>::HASH {
+                    let iter = value.borrow_ref::<RangeInclusiveIter<$ty>>()?;
+                    let range = iter.as_range();
+
+                    return Ok(Some(Node::RangeInclusiveIter {
+                        start: Inline::$variant(*range.start()),
+                        end: Inline::$variant(*range.end()),
+                        exhausted: range.is_empty(),
+                    }));
+                }
+            )*
+        };
+    }
+
+    bounds!(i64 => Signed, u64 => Unsigned, char => Char);
+    Ok(None)
+}
+
+struct Decoder<'a> {
+    context: &'a Arc<RuntimeContext>,
+    unit: &'a Arc<Unit>,
+    values: Vec<Value>,
+}
+
+impl Decoder<'_> {
+    fn execution(&self, node: ExecutionNode) -> Result<VmExecution<Vm>, ContinuationErrorKind> {
+        let mut values = Vec::try_with_capacity(node.stack.len())?;
+
+        for slot in node.stack {
+            values.try_push(self.slot(slot)?)?;
+        }
+
+        let mut call_frames = Vec::try_with_capacity(node.call_frames.len())?;
+
+        for frame in node.call_frames {
+            call_frames.try_push(crate::runtime::CallFrame {
+                ip: frame.ip,
+                top: frame.top,
+                isolated: Isolated::new(frame.isolated),
+                out: frame.out,
+            })?;
+        }
+
+        let stack = Stack::from_parts(values, node.top);
+        let mut vm = Vm::with_stack(self.context.clone(), self.unit.clone(), stack);
+        vm.set_ip(node.ip);
+        vm.set_call_frames(call_frames);
+
+        let state = match node.state {
+            StateNode::Initial => ExecutionState::Initial,
+            StateNode::Resumed(out) => ExecutionState::Resumed(out),
+            StateNode::Suspended => ExecutionState::Suspended,
+            StateNode::Exited(addr) => ExecutionState::Exited(addr),
+        };
+
+        Ok(VmExecution::with_state(vm, state))
+    }
+
+    fn slots(
+        &self,
+        slots: ::rust_alloc::vec::Vec<Slot>,
+    ) -> Result<Vec<Value>, ContinuationErrorKind> {
+        let mut values = Vec::try_with_capacity(slots.len())?;
+
+        for slot in slots {
+            values.try_push(self.slot(slot)?)?;
+        }
+
+        Ok(values)
+    }
+
+    fn slot(&self, slot: Slot) -> Result<Value, ContinuationErrorKind> {
+        match slot {
+            Slot::Inline(value) => Ok(Value::from(value)),
+            Slot::Ref(index) => match self.values.get(index) {
+                Some(value) => Ok(value.clone()),
+                None => Err(ContinuationErrorKind::BadReference { index }),
+            },
+        }
+    }
+
+    fn node(&self, node: Node) -> Result<Value, ContinuationErrorKind> {
+        let value = match node {
+            Node::String(string) => Value::try_from(String::try_from(string)?)?,
+            Node::Bytes(bytes) => Value::try_from(Bytes::from_vec(Vec::try_from(bytes)?))?,
+            Node::Vec(slots) => Value::vec(self.slots(slots)?)?,
+            Node::Tuple(slots) => Value::tuple(self.slots(slots)?)?,
+            Node::Object(entries) => {
+                let mut object = Object::with_capacity(entries.len())?;
+
+                for (key, slot) in entries {
+                    object.insert(String::try_from(key)?, self.slot(slot)?)?;
+                }
+
+                Value::try_from(object)?
+            }
+            Node::Option(option) => {
+                let option = match option {
+                    Some(slot) => Some(self.slot(slot)?),
+                    None => None,
+                };
+
+                Value::try_from(option)?
+            }
+            Node::Result(result) => {
+                let result = match result {
+                    Ok(slot) => Ok(self.slot(slot)?),
+                    Err(slot) => Err(self.slot(slot)?),
+                };
+
+                Value::try_from(result)?
+            }
+            Node::Dynamic { rtti, fields } => {
+                let Some(rtti) = self.unit.lookup_rtti(&rtti) else {
+                    return Err(ContinuationErrorKind::MissingRtti { hash: rtti });
+                };
+
+                let fields = self.slots(fields)?;
+
+                match rtti.kind {
+                    RttiKind::Empty => Value::empty_struct(rtti.clone())?,
+                    RttiKind::Tuple | RttiKind::Struct => {
+                        Value::from(crate::runtime::Dynamic::new(rtti.clone(), fields)?)
+                    }
+                }
+            }
+            Node::Function {
+                offset,
+                call,
+                args,
+                hash,
+                environment,
+            } => {
+                let function = match environment {
+                    Some(environment) => Function::from_vm_closure(
+                        self.context.clone(),
+                        self.unit.clone(),
+                        offset,
+                        call,
+                        args,
+                        self.slots(environment)?.try_into_boxed_slice()?,
+                        hash,
+                    ),
+                    None => Function::from_vm_offset(
+                        self.context.clone(),
+                        self.unit.clone(),
+                        offset,
+                        call,
+                        args,
+                        hash,
+                    ),
+                };
+
+                Value::new(function)?
+            }
+            Node::Generator(execution) => {
+                let generator = match execution {
+                    Some(execution) => Generator::from_execution(self.execution(execution)?),
+                    None => Generator::complete(),
+                };
+
+                Value::new(generator)?
+            }
+            Node::Range(start, end) => Value::new(Range::new(self.slot(start)?, self.slot(end)?))?,
+            Node::RangeFrom(start) => Value::new(RangeFrom::new(self.slot(start)?))?,
+            Node::RangeFull => Value::new(RangeFull::new())?,
+            Node::RangeInclusive(start, end) => {
+                Value::new(RangeInclusive::new(self.slot(start)?, self.slot(end)?))?
+            }
+            Node::RangeTo(end) => Value::new(RangeTo::new(self.slot(end)?))?,
+            Node::RangeToInclusive(end) => Value::new(RangeToInclusive::new(self.slot(end)?))?,
+            Node::RangeIter { start, end } => match (start, end) {
+                (Inline::Signed(start), Inline::Signed(end)) => {
+                    Value::new(RangeIter::new(start..end))?
+                }
+                (Inline::Unsigned(start), Inline::Unsigned(end)) => {
+                    Value::new(RangeIter::new(start..end))?
+                }
+                (Inline::Char(start), Inline::Char(end)) => Value::new(RangeIter::new(start..end))?,
+                _ => return Err(ContinuationErrorKind::BadRange),
+            },
+            Node::RangeFromIter { start } => match start {
+                Inline::Signed(start) => Value::new(RangeFromIter::new(start..))?,
+                Inline::Unsigned(start) => Value::new(RangeFromIter::new(start..))?,
+                Inline::Char(start) => Value::new(RangeFromIter::new(start..))?,
+                _ => return Err(ContinuationErrorKind::BadRange),
+            },
+            Node::RangeInclusiveIter {
+                start,
+                end,
+                exhausted,
+            } => match (start, end) {
+                (Inline::Signed(start), Inline::Signed(end)) => {
+                    Value::new(RangeInclusiveIter::new(inclusive(start, end, exhausted)))?
+                }
+                (Inline::Unsigned(start), Inline::Unsigned(end)) => {
+                    Value::new(RangeInclusiveIter::new(inclusive(start, end, exhausted)))?
+                }
+                (Inline::Char(start), Inline::Char(end)) => {
+                    Value::new(RangeInclusiveIter::new(inclusive(start, end, exhausted)))?
+                }
+                _ => return Err(ContinuationErrorKind::BadRange),
+            },
+            Node::SliceIter(slots) => {
+                // NB: the remaining values are copied into a vector of their
+                // own, since the vector being iterated over can't be
+                // recovered from the iterator.
+                let vec = Value::vec(self.slots(slots)?)?;
+                let vec = vec.into_ref::<crate::runtime::Vec>()?;
+                Value::new(SliceIter::new(Ref::map(vec, |vec| &**vec)))?
+            }
+        };
+
+        Ok(value)
+    }
+}
+
+/// Reconstruct an inclusive range, which remembers whether it has been
+/// exhausted in addition to its bounds.
+fn inclusive<T>(start: T, end: T, exhausted: bool) -> core::ops::RangeInclusive<T>
+where
+    core::ops::RangeInclusive<T>: Iterator,
+    T: Copy,
+{
+    if !exhausted {
+        return start..=end;
+    }
+
+    // An exhausted range is one which has yielded its last element.
+    let mut range = end..=end;
+    range.next();
+    range
+}
@@ -9,7 +9,7 @@ use crate::alloc::prelude::*;
 use crate::alloc::{Box, HashMap, Vec};
 use crate::ast::Span;
 use crate::runtime::DebugLabel;
-use crate::{Hash, ItemBuf, SourceId};
+use crate::{Hash, Item, ItemBuf, SourceId};
 
 /// Debug information about a unit.
 #[derive(Debug, TryClone, Default, Serialize, Deserialize)]
@@ -23,6 +23,9 @@ pub struct DebugInfo {
     pub functions_rev: HashMap<usize, Hash>,
     /// Hash to identifier.
     pub hash_to_ident: HashMap<Hash, Box<str>>,
+    /// Hash to the item of a function being called.
+    #[serde(default)]
+    pub hash_to_item: HashMap<Hash, ItemBuf>,
 }
 
 impl DebugInfo {
@@ -38,10 +41,27 @@ impl DebugInfo {
         Some((hash, signature))
     }
 
+    /// Get the function which contains the given instruction pointer.
+    pub fn function_containing(&self, ip: usize) -> Option<(Hash, &DebugSignature)> {
+        let (_, hash) = self
+            .functions_rev
+            .iter()
+            .filter(|(offset, _)| **offset <= ip)
+            .max_by_key(|(offset, _)| **offset)?;
+
+        let signature = self.functions.get(hash)?;
+        Some((*hash, signature))
+    }
+
     /// Access an identifier for the given hash - if it exists.
     pub fn ident_for_hash(&self, hash: Hash) -> Option<&str> {
         Some(self.hash_to_ident.get(&hash)?)
     }
+
+    /// Access the item of a called function for the given hash - if it exists.
+    pub fn item_for_hash(&self, hash: Hash) -> Option<&Item> {
+        Some(self.hash_to_item.get(&hash)?)
+    }
 }
 
 /// Debug information for every instruction.
@@ -0,0 +1,773 @@
+//! An arbitrary-precision fixed-point decimal number.
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash;
+use core::str::FromStr;
+
+use num::{BigInt, Integer, Signed, ToPrimitive, Zero};
+use serde::de;
+use serde::ser;
+
+use crate as rune;
+use crate::alloc::prelude::*;
+use crate::alloc::{self, String};
+use crate::hash::Hash;
+use crate::runtime::{
+    Bytes, ConstConstruct, ConstValue, ConstValueKind, RuntimeError, ToConstValue, Value,
+};
+use crate::{Any, TypeHash};
+
+/// The number of fractional digits which are computed when dividing two
+/// decimals.
+const DIVISION_SCALE: u32 = 28;
+
+/// An arbitrary-precision fixed-point decimal number.
+///
+/// A decimal is stored as an integer mantissa and a scale, which is the number
+/// of digits following the decimal point. So `1.10` has a mantissa of `110` and
+/// a scale of `2`.
+///
+/// Addition, subtraction and remainder are exact. Multiplication is exact
+/// unless the scale of the product exceeds [`Decimal::MAX_SCALE`], in which
+/// case it's rounded to that scale. Division is computed with at least 28
+/// fractional digits. Any rounding is half away from zero.
+///
+/// Decimals compare numerically, so `1.1` and `1.10` are equal even though they
+/// are displayed differently.
+///
+/// # Examples
+///
+/// ```
+/// use rune::runtime::Decimal;
+///
+/// let a: Decimal = "1.10".parse()?;
+/// let b: Decimal = "2.205".parse()?;
+///
+/// assert_eq!((&a + &b).to_string(), "3.305");
+/// assert_eq!(a, "1.1".parse()?);
+/// # Ok::<_, rune::support::Error>(())
+/// ```
+#[derive(Any, Clone)]
+#[rune(item = ::std::num)]
+pub struct Decimal {
+    mantissa: BigInt,
+    scale: u32,
+}
+
+impl Decimal {
+    /// The largest scale which can be produced by parsing, multiplication or
+    /// [`checked_pow`].
+    ///
+    /// Exponents in parsed decimals are subject to the same limit, so that a
+    /// literal like `1e4000000000` can't be used to exhaust memory. Products
+    /// with a larger scale are rounded, so that repeated multiplication can't
+    /// grow the scale without bounds.
+    ///
+    /// [`checked_pow`]: Self::checked_pow
+    pub const MAX_SCALE: u32 = 1 << 16;
+
+    /// Construct a new decimal out of a mantissa and a scale.
+    ///
+    /// The value of the decimal is `mantissa * 10^-scale`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rune::runtime::Decimal;
+    ///
+    /// let d = Decimal::new(110, 2);
+    /// assert_eq!(d.to_string(), "1.10");
+    /// ```
+    pub fn new(mantissa: impl Into<BigInt>, scale: u32) -> Self {
+        Self {
+            mantissa: mantissa.into(),
+            scale,
+        }
+    }
+
+    /// Get the mantissa of the decimal.
+    pub fn mantissa(&self) -> &BigInt {
+        &self.mantissa
+    }
+
+    /// Get the scale of the decimal, which is the number of digits following
+    /// the decimal point.
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    /// Test if the decimal is zero.
+    pub fn is_zero(&self) -> bool {
+        self.mantissa.is_zero()
+    }
+
+    /// Test if the decimal is negative.
+    pub fn is_negative(&self) -> bool {
+        self.mantissa.is_negative()
+    }
+
+    /// Convert a floating point number into a decimal.
+    ///
+    /// This uses the shortest representation which converts back into the same
+    /// floating point number, so `0.1` becomes exactly `0.1`. Returns `None` if
+    /// the number is not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rune::runtime::Decimal;
+    ///
+    /// let d = Decimal::from_f64(0.1).unwrap();
+    /// assert_eq!(d.to_string(), "0.1");
+    /// assert!(Decimal::from_f64(f64::NAN).is_none());
+    /// ```
+    pub fn from_f64(value: f64) -> Option<Self> {
+        if !value.is_finite() {
+            return None;
+        }
+
+        value.try_to_string().ok()?.parse().ok()
+    }
+
+    /// Convert the decimal into the closest floating point number.
+    pub fn to_f64(&self) -> f64 {
+        match self.try_to_string() {
+            Ok(string) => string.parse().unwrap_or(f64::NAN),
+            Err(..) => f64::NAN,
+        }
+    }
+
+    /// Convert the decimal into an integer, truncating any fractional digits.
+    pub fn to_bigint(&self) -> BigInt {
+        &self.mantissa / pow10(self.scale)
+    }
+
+    /// Get the absolute value of the decimal.
+    pub fn abs(&self) -> Self {
+        Self::new(self.mantissa.abs(), self.scale)
+    }
+
+    /// Round the decimal to `dp` fractional digits, rounding half away from
+    /// zero.
+    ///
+    /// Decimals which already have `dp` or fewer fractional digits are
+    /// returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rune::runtime::Decimal;
+    ///
+    /// let d: Decimal = "2.345".parse()?;
+    /// assert_eq!(d.round(2).to_string(), "2.35");
+    /// assert_eq!(d.round(5).to_string(), "2.345");
+    /// # Ok::<_, rune::support::Error>(())
+    /// ```
+    pub fn round(&self, dp: u32) -> Self {
+        if dp >= self.scale {
+            return self.clone();
+        }
+
+        let divisor = pow10(self.scale - dp);
+        Self::new(div_round(&self.mantissa, &divisor), dp)
+    }
+
+    /// Truncate the decimal into its integer part.
+    pub fn trunc(&self) -> Self {
+        Self::new(self.to_bigint(), 0)
+    }
+
+    /// Get the largest integer less than or equal to the decimal.
+    pub fn floor(&self) -> Self {
+        Self::new(self.mantissa.div_floor(&pow10(self.scale)), 0)
+    }
+
+    /// Get the smallest integer greater than or equal to the decimal.
+    pub fn ceil(&self) -> Self {
+        let neg = -&self.mantissa;
+        Self::new(-neg.div_floor(&pow10(self.scale)), 0)
+    }
+
+    /// Strip any trailing zeros from the fractional part of the decimal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rune::runtime::Decimal;
+    ///
+    /// let d: Decimal = "1.2500".parse()?;
+    /// assert_eq!(d.normalize().to_string(), "1.25");
+    /// # Ok::<_, rune::support::Error>(())
+    /// ```
+    pub fn normalize(&self) -> Self {
+        let mut this = self.clone();
+        this.trim(0);
+        this
+    }
+
+    /// Raise the decimal to the power of `exp`, returning `None` if the scale
+    /// of the result would exceed [`MAX_SCALE`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rune::runtime::Decimal;
+    ///
+    /// let d: Decimal = "1.1".parse()?;
+    /// assert_eq!(d.checked_pow(2).unwrap().to_string(), "1.21");
+    ///
+    /// let d: Decimal = "0.1".parse()?;
+    /// assert!(d.checked_pow(u32::MAX).is_none());
+    /// # Ok::<_, rune::support::Error>(())
+    /// ```
+    ///
+    /// [`MAX_SCALE`]: Self::MAX_SCALE
+    pub fn checked_pow(&self, exp: u32) -> Option<Self> {
+        let scale = self.scale.checked_mul(exp)?;
+
+        if scale > Self::MAX_SCALE {
+            return None;
+        }
+
+        Some(Self::new(
+            num::pow(self.mantissa.clone(), exp as usize),
+            scale,
+        ))
+    }
+
+    /// Divide two decimals, returning `None` if `rhs` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rune::runtime::Decimal;
+    ///
+    /// let a: Decimal = "1".parse()?;
+    /// let b: Decimal = "8".parse()?;
+    /// assert_eq!(a.checked_div(&b).unwrap().to_string(), "0.125");
+    /// # Ok::<_, rune::support::Error>(())
+    /// ```
+    pub fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        if rhs.mantissa.is_zero() {
+            return None;
+        }
+
+        let min_scale = self.scale.max(rhs.scale);
+        let scale = min_scale.max(DIVISION_SCALE);
+        let numerator = &self.mantissa * pow10(scale + rhs.scale - self.scale);
+
+        let mut out = Self::new(div_round(&numerator, &rhs.mantissa), scale);
+        out.trim(min_scale);
+        Some(out)
+    }
+
+    /// Calculate the remainder of dividing two decimals, returning `None` if
+    /// `rhs` is zero.
+    ///
+    /// The remainder has the same sign as `self`.
+    pub fn checked_rem(&self, rhs: &Self) -> Option<Self> {
+        if rhs.mantissa.is_zero() {
+            return None;
+        }
+
+        let (a, b, scale) = align(self, rhs);
+        Some(Self::new(a % b, scale))
+    }
+
+    /// Remove trailing zeros down to the given scale.
+    fn trim(&mut self, min_scale: u32) {
+        let ten = BigInt::from(10);
+
+        while self.scale > min_scale && !self.mantissa.is_zero() {
+            let (q, r) = self.mantissa.div_rem(&ten);
+
+            if !r.is_zero() {
+                break;
+            }
+
+            self.mantissa = q;
+            self.scale -= 1;
+        }
+
+        if self.mantissa.is_zero() {
+            self.scale = self.scale.min(min_scale);
+        }
+    }
+}
+
+/// Compute `10^exp`.
+fn pow10(exp: u32) -> BigInt {
+    num::pow(BigInt::from(10), exp as usize)
+}
+
+/// Divide `n` by `d`, rounding half away from zero.
+fn div_round(n: &BigInt, d: &BigInt) -> BigInt {
+    let (q, r) = n.div_rem(d);
+
+    if (r.abs() * 2u32) >= d.abs() {
+        if n.is_negative() != d.is_negative() {
+            q - 1
+        } else {
+            q + 1
+        }
+    } else {
+        q
+    }
+}
+
+/// Align the mantissas of two decimals to a common scale.
+fn align(a: &Decimal, b: &Decimal) -> (BigInt, BigInt, u32) {
+    match a.scale.cmp(&b.scale) {
+        Ordering::Equal => (a.mantissa.clone(), b.mantissa.clone(), a.scale),
+        Ordering::Less => (
+            &a.mantissa * pow10(b.scale - a.scale),
+            b.mantissa.clone(),
+            b.scale,
+        ),
+        Ordering::Greater => (
+            a.mantissa.clone(),
+            &b.mantissa * pow10(a.scale - b.scale),
+            a.scale,
+        ),
+    }
+}
+
+impl From<BigInt> for Decimal {
+    #[inline]
+    fn from(value: BigInt) -> Self {
+        Self::new(value, 0)
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl From<$ty> for Decimal {
+                #[inline]
+                fn from(value: $ty) -> Self {
+                    Self::new(value, 0)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+
+impl core::ops::Add for &Decimal {
+    type Output = Decimal;
+
+    fn add(self, rhs: Self) -> Decimal {
+        let (a, b, scale) = align(self, rhs);
+        Decimal::new(a + b, scale)
+    }
+}
+
+impl core::ops::Sub for &Decimal {
+    type Output = Decimal;
+
+    fn sub(self, rhs: Self) -> Decimal {
+        let (a, b, scale) = align(self, rhs);
+        Decimal::new(a - b, scale)
+    }
+}
+
+impl core::ops::Mul for &Decimal {
+    type Output = Decimal;
+
+    fn mul(self, rhs: Self) -> Decimal {
+        let mantissa = &self.mantissa * &rhs.mantissa;
+        let scale = u64::from(self.scale) + u64::from(rhs.scale);
+
+        let max = u64::from(Decimal::MAX_SCALE);
+
+        if scale <= max {
+            return Decimal::new(mantissa, scale as u32);
+        }
+
+        // NB: round the product so that its scale doesn't grow without bounds.
+        let excess = u32::try_from(scale - max).unwrap_or(u32::MAX);
+        Decimal::new(div_round(&mantissa, &pow10(excess)), Decimal::MAX_SCALE)
+    }
+}
+
+impl core::ops::Neg for &Decimal {
+    type Output = Decimal;
+
+    fn neg(self) -> Decimal {
+        Decimal::new(-&self.mantissa, self.scale)
+    }
+}
+
+impl PartialEq for Decimal {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Decimal {}
+
+impl PartialOrd for Decimal {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.scale == other.scale {
+            return self.mantissa.cmp(&other.mantissa);
+        }
+
+        let (a, b, _) = align(self, other);
+        a.cmp(&b)
+    }
+}
+
+impl hash::Hash for Decimal {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: hash::Hasher,
+    {
+        // NB: Numerically equal decimals must hash the same.
+        let this = self.normalize();
+        this.mantissa.hash(state);
+        this.scale.hash(state);
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = self
+            .mantissa
+            .magnitude()
+            .try_to_string()
+            .map_err(|_| fmt::Error)?;
+
+        let scale = self.scale as usize;
+
+        if self.mantissa.is_negative() {
+            f.write_str("-")?;
+        }
+
+        if scale == 0 {
+            return f.write_str(&digits);
+        }
+
+        if digits.len() > scale {
+            let (int, frac) = digits.split_at(digits.len() - scale);
+            return write!(f, "{int}.{frac}");
+        }
+
+        f.write_str("0.")?;
+
+        for _ in digits.len()..scale {
+            f.write_str("0")?;
+        }
+
+        f.write_str(&digits)
+    }
+}
+
+impl fmt::Debug for Decimal {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/// An error raised when a decimal could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq, Any)]
+#[rune(item = ::std::num)]
+#[non_exhaustive]
+pub struct ParseDecimalError;
+
+impl fmt::Display for ParseDecimalError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid decimal literal")
+    }
+}
+
+impl core::error::Error for ParseDecimalError {}
+
+impl FromStr for Decimal {
+    type Err = ParseDecimalError;
+
+    /// Parse a decimal in the form `[+-]digits[.digits][e[+-]digits]`.
+    ///
+    /// Underscores are permitted as digit separators. Decimals whose scale or
+    /// exponent exceeds [`Decimal::MAX_SCALE`] are rejected.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, s) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+
+        let (s, exp) = match s.find(['e', 'E']) {
+            Some(n) => (&s[..n], parse_exp(&s[n + 1..]).ok_or(ParseDecimalError)?),
+            None => (s, 0),
+        };
+
+        let (int, frac) = match s.split_once('.') {
+            Some((int, frac)) => (int, frac),
+            None => (s, ""),
+        };
+
+        let mut digits = String::new();
+        let mut scale = 0i64;
+
+        for (part, fractional) in [(int, false), (frac, true)] {
+            for c in part.chars() {
+                match c {
+                    '0'..='9' => {
+                        digits.try_push(c).map_err(|_| ParseDecimalError)?;
+
+                        if fractional {
+                            scale += 1;
+                        }
+                    }
+                    '_' => {}
+                    _ => return Err(ParseDecimalError),
+                }
+            }
+        }
+
+        if digits.is_empty() {
+            return Err(ParseDecimalError);
+        }
+
+        let mut mantissa = BigInt::from_str(&digits).map_err(|_| ParseDecimalError)?;
+
+        if negative {
+            mantissa = -mantissa;
+        }
+
+        let scale = scale.checked_sub(exp).ok_or(ParseDecimalError)?;
+        let max = i64::from(Self::MAX_SCALE);
+
+        if !(-max..=max).contains(&scale) {
+            return Err(ParseDecimalError);
+        }
+
+        if scale < 0 {
+            let exp = u32::try_from(-scale).map_err(|_| ParseDecimalError)?;
+            return Ok(Self::new(mantissa * pow10(exp), 0));
+        }
+
+        let scale = u32::try_from(scale).map_err(|_| ParseDecimalError)?;
+        Ok(Self::new(mantissa, scale))
+    }
+}
+
+/// Parse the exponent of a decimal in the form `[+-]digits`, where underscores
+/// are permitted as digit separators.
+fn parse_exp(s: &str) -> Option<i64> {
+    let (negative, s) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+
+    let mut exp = 0i64;
+    let mut any = false;
+
+    for c in s.chars() {
+        match c {
+            '0'..='9' => {
+                let digit = i64::from(c as u8 - b'0');
+                exp = exp.checked_mul(10)?;
+
+                exp = if negative {
+                    exp.checked_sub(digit)?
+                } else {
+                    exp.checked_add(digit)?
+                };
+
+                any = true;
+            }
+            '_' => {}
+            _ => return None,
+        }
+    }
+
+    any.then_some(exp)
+}
+
+impl ToPrimitive for Decimal {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        self.to_bigint().to_i64()
+    }
+
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        self.to_bigint().to_u64()
+    }
+
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        Some(Decimal::to_f64(self))
+    }
+}
+
+impl ToConstValue for Decimal {
+    fn to_const_value(self) -> Result<ConstValue, RuntimeError> {
+        let mantissa = ConstValue::from(mantissa_to_bytes(&self.mantissa)?);
+        ConstValue::for_struct(Self::HASH, [mantissa, self.scale.to_const_value()?])
+    }
+
+    #[inline]
+    fn construct() -> Option<::rust_alloc::sync::Arc<dyn ConstConstruct>> {
+        Some(::rust_alloc::sync::Arc::new(DecimalConstruct))
+    }
+}
+
+impl ToConstValue for BigInt {
+    fn to_const_value(self) -> Result<ConstValue, RuntimeError> {
+        let mantissa = ConstValue::from(mantissa_to_bytes(&self)?);
+        ConstValue::for_struct(<BigInt as TypeHash>::HASH, [mantissa])
+    }
+
+    #[inline]
+    fn construct() -> Option<::rust_alloc::sync::Arc<dyn ConstConstruct>> {
+        Some(::rust_alloc::sync::Arc::new(BigIntConstruct))
+    }
+}
+
+/// Store an integer as its two's complement bytes in little-endian order,
+/// which is how constant numbers and literals like `10n` and `1.10d` are
+/// stored so that they don't have to be parsed at runtime.
+fn mantissa_to_bytes(n: &BigInt) -> alloc::Result<Bytes> {
+    Bytes::from_slice(n.to_signed_bytes_le())
+}
+
+/// Access the bytes of a stored integer in a constant value.
+fn const_mantissa(value: &ConstValue) -> Result<BigInt, RuntimeError> {
+    let ConstValueKind::Bytes(bytes) = value.as_kind() else {
+        return Err(RuntimeError::expected::<Bytes>(value.type_info()));
+    };
+
+    Ok(BigInt::from_signed_bytes_le(bytes.as_slice()))
+}
+
+/// Access the bytes of a stored integer in a runtime value.
+fn runtime_mantissa(value: &Value) -> Result<BigInt, RuntimeError> {
+    let bytes = value.borrow_ref::<Bytes>()?;
+    Ok(BigInt::from_signed_bytes_le(bytes.as_slice()))
+}
+
+/// Constant constructor for [`BigInt`].
+struct BigIntConstruct;
+
+impl ConstConstruct for BigIntConstruct {
+    fn const_construct(&self, values: &[ConstValue]) -> Result<Value, RuntimeError> {
+        let [mantissa] = values else {
+            return Err(RuntimeError::bad_argument_count(values.len(), 1));
+        };
+
+        Ok(Value::new(const_mantissa(mantissa)?)?)
+    }
+
+    fn runtime_construct(&self, values: &mut [Value]) -> Result<Value, RuntimeError> {
+        let [mantissa] = values else {
+            return Err(RuntimeError::bad_argument_count(values.len(), 1));
+        };
+
+        Ok(Value::new(runtime_mantissa(mantissa)?)?)
+    }
+}
+
+/// Constant constructor for [`Decimal`].
+struct DecimalConstruct;
+
+impl ConstConstruct for DecimalConstruct {
+    fn const_construct(&self, values: &[ConstValue]) -> Result<Value, RuntimeError> {
+        let [mantissa, scale] = values else {
+            return Err(RuntimeError::bad_argument_count(values.len(), 2));
+        };
+
+        let decimal = Decimal::new(const_mantissa(mantissa)?, scale.as_integer()?);
+        Ok(Value::new(decimal)?)
+    }
+
+    fn runtime_construct(&self, values: &mut [Value]) -> Result<Value, RuntimeError> {
+        let [mantissa, scale] = values else {
+            return Err(RuntimeError::bad_argument_count(values.len(), 2));
+        };
+
+        let decimal = Decimal::new(runtime_mantissa(mantissa)?, scale.as_integer()?);
+        Ok(Value::new(decimal)?)
+    }
+}
+
+/// The hash of the constant constructor for [`Decimal`] literals.
+pub(crate) const DECIMAL_HASH: Hash = Decimal::HASH;
+
+/// The hash of the constant constructor for [`BigInt`] literals.
+pub(crate) const BIG_INT_HASH: Hash = <BigInt as TypeHash>::HASH;
+
+impl ser::Serialize for Decimal {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> de::Deserialize<'de> for Decimal {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl de::Visitor<'_> for Visitor {
+            type Value = Decimal;
+
+            #[inline]
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a decimal number")
+            }
+
+            #[inline]
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                v.parse().map_err(E::custom)
+            }
+
+            #[inline]
+            fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(Decimal::from(v))
+            }
+
+            #[inline]
+            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(Decimal::from(v))
+            }
+
+            #[inline]
+            fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Decimal::from_f64(v).ok_or_else(|| E::custom("decimal must be finite"))
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
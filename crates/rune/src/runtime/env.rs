@@ -15,6 +15,8 @@ mod no_std;
 
 use ::rust_alloc::sync::Arc;
 
+use crate::runtime::cancellation::{self, CancellationToken};
+use crate::runtime::spawn::{self, Spawner};
 use crate::runtime::vm_diagnostics::VmDiagnosticsObj;
 use crate::runtime::{RuntimeContext, Unit, VmErrorKind, VmResult};
 
@@ -80,6 +82,27 @@ where
     c(&context, &unit, diagnostics)
 }
 
+/// Get the cancellation token associated with the current environment, if any.
+pub(crate) fn cancellation() -> Option<CancellationToken> {
+    let env = self::no_std::rune_env_get();
+    let cancellation = env.cancellation?;
+
+    // Safety: the cancellation token can only be registered through
+    // [`Guard`], which makes sure that it is live for the duration of the
+    // registration.
+    Some(unsafe { CancellationToken::clone_from_raw(cancellation.as_ptr().cast_const()) })
+}
+
+/// Get the spawner associated with the current environment, if any.
+pub(crate) fn spawner() -> Option<Spawner> {
+    let env = self::no_std::rune_env_get();
+    let spawner = env.spawner?;
+
+    // Safety: the spawner can only be registered through [`Guard`], which
+    // makes sure that it is live for the duration of the registration.
+    Some(unsafe { Spawner::clone_from_raw(spawner.as_ptr().cast_const()) })
+}
+
 pub(crate) struct Guard {
     env: Env,
 }
@@ -94,12 +117,17 @@ impl Guard {
         context: Arc<RuntimeContext>,
         unit: Arc<Unit>,
         diagnostics: Option<NonNull<VmDiagnosticsObj>>,
+        cancellation: Option<CancellationToken>,
+        spawner: Option<Spawner>,
     ) -> Guard {
         let env = unsafe {
             self::no_std::rune_env_replace(Env {
                 context: Some(NonNull::new_unchecked(Arc::into_raw(context).cast_mut())),
                 unit: Some(NonNull::new_unchecked(Arc::into_raw(unit).cast_mut())),
                 diagnostics,
+                cancellation: cancellation
+                    .map(|c| NonNull::new_unchecked(CancellationToken::into_raw(c).cast_mut())),
+                spawner: spawner.map(|s| NonNull::new_unchecked(Spawner::into_raw(s).cast_mut())),
             })
         };
 
@@ -119,6 +147,14 @@ impl Drop for Guard {
             if let Some(unit) = old_env.unit {
                 drop(Arc::from_raw(unit.as_ptr().cast_const()));
             }
+
+            if let Some(cancellation) = old_env.cancellation {
+                CancellationToken::drop_raw(cancellation.as_ptr().cast_const());
+            }
+
+            if let Some(spawner) = old_env.spawner {
+                Spawner::drop_raw(spawner.as_ptr().cast_const());
+            }
         }
     }
 }
@@ -128,6 +164,8 @@ struct Env {
     context: Option<NonNull<RuntimeContext>>,
     unit: Option<NonNull<Unit>>,
     diagnostics: Option<NonNull<VmDiagnosticsObj>>,
+    cancellation: Option<NonNull<cancellation::Shared>>,
+    spawner: Option<NonNull<spawn::Inner>>,
 }
 
 impl Env {
@@ -136,6 +174,8 @@ impl Env {
             context: None,
             unit: None,
             diagnostics: None,
+            cancellation: None,
+            spawner: None,
         }
     }
 }
@@ -33,6 +33,8 @@ unsafe fn from_env(env: Env) -> RawEnv {
         context: env.context.map(|ptr| ptr.cast()),
         unit: env.unit.map(|ptr| ptr.cast()),
         diagnostics: env.diagnostics.map(|ptr| ptr.cast()),
+        cancellation: env.cancellation.map(|ptr| ptr.cast()),
+        spawner: env.spawner.map(|ptr| ptr.cast()),
     }
 }
 
@@ -41,5 +43,7 @@ unsafe fn from_raw_env(env: RawEnv) -> Env {
         context: env.context.map(|ptr| ptr.cast()),
         unit: env.unit.map(|ptr| ptr.cast()),
         diagnostics: env.diagnostics.map(|ptr| ptr.cast()),
+        cancellation: env.cancellation.map(|ptr| ptr.cast()),
+        spawner: env.spawner.map(|ptr| ptr.cast()),
     }
 }
@@ -250,6 +250,30 @@ impl Function {
         Self(FunctionImpl::from_unit_struct(rtti))
     }
 
+    /// Access the parts of a function which is defined at an offset in the
+    /// given unit.
+    ///
+    /// Returns `None` if the function is not defined in the given unit.
+    pub(crate) fn as_offset(&self, unit: &Arc<Unit>) -> Option<FnOffsetParts<'_>> {
+        let (fn_offset, environment) = match &self.0.inner {
+            Inner::FnOffset(fn_offset) => (fn_offset, None),
+            Inner::FnClosureOffset(closure) => (&closure.fn_offset, Some(&closure.environment[..])),
+            _ => return None,
+        };
+
+        if !Arc::ptr_eq(&fn_offset.unit, unit) {
+            return None;
+        }
+
+        Some(FnOffsetParts {
+            offset: fn_offset.offset,
+            call: fn_offset.call,
+            args: fn_offset.args,
+            hash: fn_offset.hash,
+            environment,
+        })
+    }
+
     /// Create a function pointer from an offset.
     pub(crate) fn from_tuple_struct(rtti: Arc<Rtti>, args: usize) -> Self {
         Self(FunctionImpl::from_tuple_struct(rtti, args))
@@ -843,6 +867,15 @@ impl fmt::Debug for FnHandler {
     }
 }
 
+/// The parts of a function defined at an offset in a unit.
+pub(crate) struct FnOffsetParts<'a> {
+    pub(crate) offset: usize,
+    pub(crate) call: Call,
+    pub(crate) args: usize,
+    pub(crate) hash: Hash,
+    pub(crate) environment: Option<&'a [Value]>,
+}
+
 #[derive(Clone, TryClone)]
 struct FnOffset {
     context: Arc<RuntimeContext>,
@@ -870,6 +903,8 @@ impl FnOffset {
         let mut vm = Vm::new(self.context.clone(), self.unit.clone());
 
         vm.set_ip(self.offset);
+        vm.set_cancellation(runtime::env::cancellation());
+        vm.set_spawner(runtime::env::spawner());
         let _guard = vm_try!(unsafe { args.guarded_into_stack(vm.stack_mut()) });
         vm_try!(extra.into_stack(vm.stack_mut()));
 
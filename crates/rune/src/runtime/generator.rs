@@ -51,6 +51,16 @@ where
         }
     }
 
+    /// Construct a generator which has already completed.
+    pub(crate) fn complete() -> Self {
+        Self { execution: None }
+    }
+
+    /// Access the execution of the generator, if it hasn't completed.
+    pub(crate) fn execution(&self) -> Option<&VmExecution<T>> {
+        self.execution.as_ref()
+    }
+
     /// Get the next value produced by this stream.
     #[allow(clippy::should_implement_trait)]
     pub fn next(&mut self) -> VmResult<Option<Value>> {
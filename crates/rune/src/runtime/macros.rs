@@ -78,6 +78,12 @@ macro_rules! range_iter {
                 Self { iter }
             }
 
+            /// Access the range which is being iterated over.
+            #[inline]
+            pub(crate) fn as_range(&self) -> &core::ops::$range<$ty> {
+                &self.iter
+            }
+
             #[rune::function(instance, keep, protocol = NEXT)]
             #[inline]
             pub(crate) fn next(&mut self) -> Option<$ty> {
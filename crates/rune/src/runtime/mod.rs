@@ -42,6 +42,12 @@ pub use self::bytes::Bytes;
 mod call;
 pub use self::call::Call;
 
+pub(crate) mod cancellation;
+pub use self::cancellation::{CancellationToken, Cancelled};
+
+mod continuation;
+pub use self::continuation::{ContinuationError, VmContinuation};
+
 mod const_value;
 pub use self::const_value::{
     from_const_value, to_const_value, ConstConstruct, ConstValue, FromConstValue, ToConstValue,
@@ -51,7 +57,7 @@ pub(crate) use self::const_value::{ConstContext, ConstValueKind, EmptyConstConte
 pub mod debug;
 pub use self::debug::{DebugInfo, DebugInst};
 
-mod env;
+pub(crate) mod env;
 
 pub mod format;
 pub use self::format::{Format, FormatSpec};
@@ -162,10 +168,10 @@ pub use self::unit::{Unit, UnitStorage};
 
 mod value;
 pub use self::value::{
-    Accessor, EmptyStruct, Inline, RawValueGuard, Rtti, Struct, TupleStruct, TypeValue, Value,
-    ValueMutGuard, ValueRefGuard,
+    Accessor, AnySerde, EmptyStruct, Inline, RawValueGuard, Rtti, Struct, TupleStruct, TypeSeed,
+    TypeValue, Value, ValueMutGuard, ValueRefGuard,
 };
-pub(crate) use self::value::{Dynamic, DynamicTakeError, Repr, RttiKind};
+pub(crate) use self::value::{AnySerdeImpl, Dynamic, DynamicTakeError, Repr, RttiKind};
 
 pub mod slice;
 
@@ -185,6 +191,9 @@ pub(crate) use self::vm_call::VmCall;
 pub(crate) mod vm_diagnostics;
 pub(crate) use self::vm_diagnostics::{VmDiagnostics, VmDiagnosticsObj};
 
+pub(crate) mod backtrace;
+pub use self::backtrace::{BacktraceFrame, BacktraceFrameKind, BacktraceLocation};
+
 mod vm_error;
 #[cfg(feature = "emit")]
 pub(crate) use self::vm_error::VmErrorAt;
@@ -192,9 +201,16 @@ pub use self::vm_error::{try_result, RuntimeError, TryFromResult, VmError, VmRes
 pub(crate) use self::vm_error::{VmErrorKind, VmIntegerRepr};
 
 mod vm_execution;
-pub(crate) use self::vm_execution::ExecutionState;
+pub(crate) use self::vm_execution::{ExecutionState, VmSlice};
 pub use self::vm_execution::{VmExecution, VmSendExecution};
 
+pub(crate) mod spawn;
+pub(crate) use self::spawn::JoinState;
+pub use self::spawn::{SpawnError, Spawner, Task};
+
+mod scheduler;
+pub use self::scheduler::{JoinHandle, Scheduler};
+
 mod vm_halt;
 pub(crate) use self::vm_halt::{VmHalt, VmHaltInfo};
 
@@ -204,6 +220,10 @@ pub use self::fmt::Formatter;
 mod control_flow;
 pub use self::control_flow::ControlFlow;
 
+mod decimal;
+pub use self::decimal::{Decimal, ParseDecimalError};
+pub(crate) use self::decimal::{BIG_INT_HASH, DECIMAL_HASH};
+
 #[cfg(feature = "alloc")]
 mod hasher;
 #[cfg(feature = "alloc")]
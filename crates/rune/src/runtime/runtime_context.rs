@@ -5,7 +5,7 @@ use ::rust_alloc::sync::Arc;
 use crate as rune;
 use crate::alloc::prelude::*;
 use crate::hash;
-use crate::runtime::{ConstConstruct, ConstValue, InstAddress, Memory, Output, VmResult};
+use crate::runtime::{AnySerde, ConstConstruct, ConstValue, InstAddress, Memory, Output, VmResult};
 use crate::Hash;
 
 /// A type-reduced function handler.
@@ -26,6 +26,8 @@ pub struct RuntimeContext {
     constants: hash::Map<ConstValue>,
     /// Constant constructors.
     construct: hash::Map<Arc<dyn ConstConstruct>>,
+    /// Serde implementations of native types.
+    serde: hash::Map<Arc<dyn AnySerde>>,
 }
 
 assert_impl!(RuntimeContext: Send + Sync);
@@ -35,11 +37,13 @@ impl RuntimeContext {
         functions: hash::Map<Arc<FunctionHandler>>,
         constants: hash::Map<ConstValue>,
         construct: hash::Map<Arc<dyn ConstConstruct>>,
+        serde: hash::Map<Arc<dyn AnySerde>>,
     ) -> Self {
         Self {
             functions,
             constants,
             construct,
+            serde,
         }
     }
 
@@ -60,6 +64,12 @@ impl RuntimeContext {
     pub(crate) fn construct(&self, hash: &Hash) -> Option<&dyn ConstConstruct> {
         Some(&**self.construct.get(hash)?)
     }
+
+    /// Read the serde implementation of a native type.
+    #[inline]
+    pub(crate) fn serde(&self, hash: &Hash) -> Option<&dyn AnySerde> {
+        Some(&**self.serde.get(hash)?)
+    }
 }
 
 impl fmt::Debug for RuntimeContext {
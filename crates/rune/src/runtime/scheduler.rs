@@ -0,0 +1,499 @@
+//! A cooperative scheduler for running many lightweight script tasks.
+//!
+//! See [`Scheduler`].
+
+use core::cell::RefCell;
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::task::{Context, Poll, Waker};
+
+use ::rust_alloc::rc::Rc;
+use ::rust_alloc::sync::Arc;
+use ::rust_alloc::task::Wake;
+
+use futures_util::task::AtomicWaker;
+
+use crate::alloc::{self, Vec};
+use crate::runtime::{
+    budget, spawn, Awaited, CancellationToken, JoinState, SpawnError, Spawner, Value, Vm,
+    VmErrorKind, VmExecution, VmResult, VmSlice,
+};
+
+/// The default number of instructions a task is allowed to execute before it
+/// has to give way to other tasks.
+const DEFAULT_SLICE: usize = 1024;
+
+/// A cooperative scheduler which multiplexes many [`VmExecution`]s on a
+/// single thread.
+///
+/// Every task is given a budget slice of instructions to execute before it's
+/// preempted and the next task gets to run. Tasks are run round-robin in the
+/// order in which they were spawned, and every task which is able to make
+/// progress runs exactly once per [`tick`] before any task gets to run again.
+///
+/// Tasks which await a future are parked until the future has woken them up,
+/// after which the result is stored and the task resumes where it left off.
+/// Tasks which yield give up the remainder of their slice and are resumed
+/// with a unit value on the next tick.
+///
+/// The scheduler also acts as the [`Spawner`] of the executions spawned onto
+/// it, so tasks started by scripts through `std::task::spawn` are run
+/// alongside them. Scripts running in such tasks are given the same slice and
+/// yield once it's exhausted, so a spinning task can't starve the others.
+///
+/// # Threading
+///
+/// The scheduler is single-threaded and doesn't come with a pool of its own.
+/// Script values can't be sent across threads, so neither can the tasks using
+/// them, and tasks are never moved between threads. To spread work over a
+/// small pool of threads, run one scheduler per thread and distribute
+/// executions between them, for example through [`Vm::send_execute`].
+///
+/// [`Vm::send_execute`]: crate::Vm::send_execute
+///
+/// [`tick`]: Scheduler::tick
+///
+/// # Examples
+///
+/// ```
+/// use rune::{Context, Vm};
+/// use rune::runtime::Scheduler;
+/// use std::sync::Arc;
+///
+/// let mut sources = rune::sources! {
+///     entry => {
+///         pub fn count(n) {
+///             let total = 0;
+///
+///             for value in 0..n {
+///                 total += value;
+///             }
+///
+///             total
+///         }
+///     }
+/// };
+///
+/// let context = Context::with_default_modules()?;
+/// let runtime = Arc::new(context.runtime()?);
+/// let unit = Arc::new(rune::prepare(&mut sources).with_context(&context).build()?);
+///
+/// let mut scheduler = Scheduler::with_slice(100);
+/// let mut handles = Vec::new();
+///
+/// for n in [10, 1000, 100] {
+///     let vm = Vm::new(runtime.clone(), unit.clone());
+///     handles.push(scheduler.spawn(vm.owned_execute(["count"], (n,))?)?);
+/// }
+///
+/// scheduler.run_until_stalled();
+/// assert!(scheduler.is_empty());
+///
+/// let mut results = Vec::new();
+///
+/// for handle in handles {
+///     let value = handle.try_join().expect("task should be finished").into_result()?;
+///     results.push(rune::from_value::<i64>(value)?);
+/// }
+///
+/// assert_eq!(results, [45, 499500, 4950]);
+/// # Ok::<_, rune::support::Error>(())
+/// ```
+pub struct Scheduler {
+    /// The number of instructions every task is allowed to run per slice.
+    slice: usize,
+    /// Tasks being scheduled in round-robin order.
+    tasks: Vec<Task>,
+    /// Tasks spawned by scripts which haven't been scheduled yet.
+    spawned: Rc<RefCell<Vec<spawn::Task>>>,
+    /// State shared with wakers.
+    shared: Arc<Shared>,
+}
+
+impl Scheduler {
+    /// Construct a new scheduler with the default slice.
+    pub fn new() -> Self {
+        Self::with_slice(DEFAULT_SLICE)
+    }
+
+    /// Construct a new scheduler where every task is allowed to run `slice`
+    /// instructions before it's preempted.
+    pub fn with_slice(slice: usize) -> Self {
+        Self {
+            slice: slice.max(1),
+            tasks: Vec::new(),
+            spawned: Rc::new(RefCell::new(Vec::new())),
+            shared: Arc::new(Shared {
+                notified: AtomicBool::new(false),
+                waker: AtomicWaker::new(),
+            }),
+        }
+    }
+
+    /// Spawn an execution onto the scheduler, returning a handle which can be
+    /// used to join it.
+    ///
+    /// The task is not started until the scheduler is ticked. If the virtual
+    /// machine of the execution doesn't have a spawner, the scheduler is
+    /// installed as its spawner.
+    pub fn spawn(&mut self, mut execution: VmExecution<Vm>) -> alloc::Result<JoinHandle> {
+        let token = execution.cancellation_token();
+
+        if execution.vm().spawner().is_none() {
+            execution.vm_mut().set_spawner(Some(self.spawner()));
+        }
+
+        let wake = self.wake();
+        let join = Rc::new(JoinState::new());
+
+        self.tasks.try_push(Task {
+            kind: TaskKind::Execution {
+                execution,
+                awaited: None,
+                join: join.clone(),
+            },
+            wake: wake.clone(),
+            finished: false,
+        })?;
+
+        Ok(JoinHandle {
+            shared: join,
+            wake,
+            token,
+        })
+    }
+
+    /// Get a spawner which schedules tasks onto this scheduler.
+    ///
+    /// Tasks spawned through it are picked up on the next tick.
+    pub fn spawner(&self) -> Spawner {
+        let spawned = self.spawned.clone();
+        let shared = self.shared.clone();
+
+        Spawner::new(move |task| {
+            if spawned.borrow_mut().try_push(task).is_err() {
+                return Err(SpawnError::new("Failed to allocate task"));
+            }
+
+            shared.notified.store(true, Ordering::Release);
+            shared.waker.wake();
+            Ok(())
+        })
+    }
+
+    fn wake(&self) -> Arc<TaskWake> {
+        Arc::new(TaskWake {
+            woken: AtomicBool::new(false),
+            shared: self.shared.clone(),
+        })
+    }
+
+    /// Move tasks spawned by scripts into the set of scheduled tasks.
+    fn schedule_spawned(&mut self) {
+        let spawned = core::mem::take(&mut *self.spawned.borrow_mut());
+
+        for future in spawned {
+            // NB: Spawned futures are polled once on the next tick so that
+            // they get to register their waker.
+            let wake = self.wake();
+            wake.woken.store(true, Ordering::Release);
+
+            let task = Task {
+                kind: TaskKind::Future(future),
+                wake,
+                finished: false,
+            };
+
+            // NB: If we fail to allocate, the task is dropped which is
+            // observed by anyone joining it as a task that never finishes.
+            _ = self.tasks.try_push(task);
+        }
+    }
+
+    /// Get the number of tasks which have not yet finished.
+    ///
+    /// This includes tasks spawned by scripts which haven't been scheduled
+    /// yet.
+    pub fn len(&self) -> usize {
+        self.tasks.len() + self.spawned.borrow().len()
+    }
+
+    /// Test if the scheduler has no tasks left to run.
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty() && self.spawned.borrow().is_empty()
+    }
+
+    /// Run every task which is able to make progress for one slice.
+    ///
+    /// Returns the number of tasks which made progress. Tasks which are
+    /// waiting for a future which hasn't woken them up are skipped.
+    pub fn tick(&mut self) -> usize {
+        self.shared.notified.store(false, Ordering::Release);
+        self.schedule_spawned();
+
+        let mut progressed = 0;
+
+        for task in self.tasks.iter_mut() {
+            if task.poll(self.slice) {
+                progressed += 1;
+            }
+        }
+
+        self.tasks.retain(|task| !task.finished);
+        progressed
+    }
+
+    /// Tick the scheduler until no task is able to make progress.
+    ///
+    /// Once this returns, every remaining task is waiting for a future to
+    /// complete.
+    pub fn run_until_stalled(&mut self) {
+        while !self.is_empty() && self.tick() > 0 {}
+    }
+
+    /// Run the scheduler until every task has finished.
+    ///
+    /// When every remaining task is waiting for a future, this waits until one
+    /// of them is woken up.
+    pub async fn run(&mut self) {
+        while !self.is_empty() {
+            if self.tick() == 0 {
+                Notified {
+                    shared: &self.shared,
+                }
+                .await;
+            }
+        }
+    }
+}
+
+impl Default for Scheduler {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Scheduler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Scheduler")
+            .field("slice", &self.slice)
+            .field("tasks", &self.len())
+            .finish()
+    }
+}
+
+/// A handle to a task spawned on a [`Scheduler`].
+///
+/// The handle can be polled as a future to wait for the task to finish, or
+/// checked without blocking through [`JoinHandle::try_join`].
+pub struct JoinHandle {
+    shared: Rc<JoinState>,
+    wake: Arc<TaskWake>,
+    token: CancellationToken,
+}
+
+impl JoinHandle {
+    /// Test if the task has finished.
+    pub fn is_finished(&self) -> bool {
+        self.shared.is_finished()
+    }
+
+    /// Take the result of the task if it has finished.
+    ///
+    /// The result can only be taken once, subsequent calls return `None`.
+    pub fn try_join(&self) -> Option<VmResult<Value>> {
+        self.shared.take()
+    }
+
+    /// Abort the task.
+    ///
+    /// The task stops the next time it's scheduled, after which joining it
+    /// produces an error for which [`VmError::is_cancelled`] returns `true`.
+    ///
+    /// [`VmError::is_cancelled`]: crate::runtime::VmError::is_cancelled
+    pub fn abort(&self) {
+        self.token.cancel();
+        self.wake.wake_by_ref();
+    }
+}
+
+impl Future for JoinHandle {
+    type Output = VmResult<Value>;
+
+    #[inline]
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.shared.poll_join(cx)
+    }
+}
+
+impl fmt::Debug for JoinHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JoinHandle")
+            .field("finished", &self.is_finished())
+            .finish()
+    }
+}
+
+enum TaskKind {
+    /// A virtual machine execution spawned by the host.
+    Execution {
+        execution: VmExecution<Vm>,
+        /// The value the task is currently waiting for.
+        awaited: Option<Awaited>,
+        join: Rc<JoinState>,
+    },
+    /// A future spawned by a script.
+    Future(spawn::Task),
+}
+
+struct Task {
+    kind: TaskKind,
+    wake: Arc<TaskWake>,
+    /// Set once the task has finished.
+    finished: bool,
+}
+
+impl Task {
+    /// Poll the task, returning `true` if it made progress.
+    fn poll(&mut self, slice: usize) -> bool {
+        match &mut self.kind {
+            TaskKind::Execution {
+                execution,
+                awaited,
+                join,
+            } => match poll_execution(execution, awaited, &self.wake, slice) {
+                Poll::Pending => false,
+                Poll::Ready(None) => true,
+                Poll::Ready(Some(result)) => {
+                    self.finished = true;
+                    join.complete(result);
+                    true
+                }
+            },
+            TaskKind::Future(future) => {
+                if !self.wake.woken.swap(false, Ordering::AcqRel) {
+                    return false;
+                }
+
+                let waker = Waker::from(self.wake.clone());
+                let mut cx = Context::from_waker(&waker);
+
+                // NB: scripts running in the spawned future yield once they've
+                // exhausted their slice.
+                let poll = budget::slice(slice, || Pin::new(future).poll(&mut cx)).call();
+
+                if poll.is_ready() {
+                    self.finished = true;
+                }
+
+                true
+            }
+        }
+    }
+}
+
+/// Poll an execution for one slice.
+///
+/// Returns `Poll::Pending` if the execution couldn't make progress and
+/// `Poll::Ready(None)` if it made progress without finishing.
+fn poll_execution(
+    execution: &mut VmExecution<Vm>,
+    awaited: &mut Option<Awaited>,
+    wake: &Arc<TaskWake>,
+    slice: usize,
+) -> Poll<Option<VmResult<Value>>> {
+    if let Some(current) = awaited {
+        let vm = execution.vm_mut();
+
+        if vm
+            .cancellation()
+            .is_some_and(CancellationToken::is_cancelled)
+        {
+            return Poll::Ready(Some(VmResult::err(VmErrorKind::Cancelled).with_vm(vm)));
+        }
+
+        if !wake.woken.swap(false, Ordering::AcqRel) {
+            return Poll::Pending;
+        }
+
+        let waker = Waker::from(wake.clone());
+        let mut cx = Context::from_waker(&waker);
+
+        // NB: the awaited value might be a script future which is running a
+        // virtual machine of its own, so it's given a slice as well.
+        match budget::slice(slice, || current.poll(&mut cx, vm)).call() {
+            // NB: the task was woken up, so polling it counts as progress even
+            // if the awaited value isn't ready yet.
+            Poll::Pending => return Poll::Ready(None),
+            Poll::Ready(VmResult::Err(error)) => return Poll::Ready(Some(VmResult::Err(error))),
+            Poll::Ready(VmResult::Ok(())) => {
+                *awaited = None;
+            }
+        }
+    }
+
+    match budget::with(slice, || execution.run_slice()).call() {
+        VmResult::Ok(VmSlice::Limited | VmSlice::Yielded) => Poll::Ready(None),
+        VmResult::Ok(VmSlice::Awaited(current)) => {
+            // NB: Poll the awaited value on the next tick so that the waker is
+            // registered.
+            wake.woken.store(true, Ordering::Release);
+            *awaited = Some(current);
+            Poll::Ready(None)
+        }
+        VmResult::Ok(VmSlice::Complete(value)) => Poll::Ready(Some(VmResult::Ok(value))),
+        VmResult::Err(error) => Poll::Ready(Some(VmResult::Err(error))),
+    }
+}
+
+struct Shared {
+    /// Set when a task has been woken up since the last tick.
+    notified: AtomicBool,
+    /// Waker for the future running the scheduler.
+    waker: AtomicWaker,
+}
+
+struct TaskWake {
+    woken: AtomicBool,
+    shared: Arc<Shared>,
+}
+
+impl Wake for TaskWake {
+    #[inline]
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.woken.store(true, Ordering::Release);
+        self.shared.notified.store(true, Ordering::Release);
+        self.shared.waker.wake();
+    }
+}
+
+/// Future which waits until a task has been woken up.
+struct Notified<'a> {
+    shared: &'a Shared,
+}
+
+impl Future for Notified<'_> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.shared.notified.load(Ordering::Acquire) {
+            return Poll::Ready(());
+        }
+
+        self.shared.waker.register(cx.waker());
+
+        // NB: check again in case we raced with a wakeup.
+        if self.shared.notified.load(Ordering::Acquire) {
+            return Poll::Ready(());
+        }
+
+        Poll::Pending
+    }
+}
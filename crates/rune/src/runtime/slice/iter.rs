@@ -22,6 +22,11 @@ impl Iter {
         }
     }
 
+    /// Access the values which remain to be iterated over.
+    pub(crate) fn as_slice(&self) -> &[Value] {
+        self.vec.get(self.front..self.back).unwrap_or_default()
+    }
+
     #[rune::function(instance, keep, protocol = NEXT)]
     #[inline]
     fn next(&mut self) -> Option<Value> {
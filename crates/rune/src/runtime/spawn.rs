@@ -0,0 +1,239 @@
+//! Spawning concurrent tasks from scripts.
+//!
+//! Rune doesn't come with an executor of its own. Instead, the host supplies a
+//! [`Spawner`] through [`Vm::set_spawner`] which is handed every [`Task`]
+//! spawned by scripts through `std::task::spawn`.
+//!
+//! [`Vm::set_spawner`]: crate::Vm::set_spawner
+
+use core::cell::{Cell, RefCell};
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+
+use ::rust_alloc::boxed::Box;
+use ::rust_alloc::rc::Rc;
+
+use crate::runtime::{Value, VmResult};
+
+/// A task spawned by a script.
+///
+/// The task must be polled to completion by the [`Spawner`] it was handed to,
+/// at which point the result is made available to any script holding the join
+/// handle of the task.
+///
+/// Tasks hold on to script values, so they can't be sent across threads. With
+/// tokio, they can be spawned using `tokio::task::spawn_local`.
+pub struct Task {
+    future: Pin<Box<dyn Future<Output = ()>>>,
+}
+
+impl Task {
+    /// Construct a new task from the given future.
+    pub(crate) fn new<F>(future: F) -> Self
+    where
+        F: 'static + Future<Output = ()>,
+    {
+        Self {
+            future: Box::pin(future),
+        }
+    }
+}
+
+impl Future for Task {
+    type Output = ();
+
+    #[inline]
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.future.as_mut().poll(cx)
+    }
+}
+
+impl fmt::Debug for Task {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Task").finish_non_exhaustive()
+    }
+}
+
+/// An error raised by a [`Spawner`] which is unable to accept a task.
+#[derive(Debug, PartialEq, Eq)]
+pub struct SpawnError {
+    message: &'static str,
+}
+
+impl SpawnError {
+    /// Construct a new spawn error with the given message.
+    pub const fn new(message: &'static str) -> Self {
+        Self { message }
+    }
+}
+
+impl fmt::Display for SpawnError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.message.fmt(f)
+    }
+}
+
+impl core::error::Error for SpawnError {}
+
+pub(crate) struct Inner {
+    spawn: Box<dyn Fn(Task) -> Result<(), SpawnError>>,
+}
+
+/// A host-supplied spawner for tasks started by scripts.
+///
+/// Like the tasks it's handed, a spawner is bound to the thread it was created
+/// on. Virtual machines which are sent to other threads through
+/// [`Vm::send_execute`] therefore don't keep their spawner.
+///
+/// [`Vm::send_execute`]: crate::Vm::send_execute
+///
+/// # Examples
+///
+/// ```
+/// use rune::{Context, Vm};
+/// use rune::runtime::Spawner;
+/// use std::sync::Arc;
+///
+/// let mut sources = rune::sources! {
+///     entry => {
+///         pub async fn main() {
+///             let task = std::task::spawn(async { 1 + 2 });
+///             task.await
+///         }
+///     }
+/// };
+///
+/// let context = Context::with_default_modules()?;
+/// let unit = rune::prepare(&mut sources).with_context(&context).build()?;
+///
+/// let mut vm = Vm::new(Arc::new(context.runtime()?), Arc::new(unit));
+///
+/// vm.set_spawner(Some(Spawner::new(|task| {
+///     tokio::task::spawn_local(task);
+///     Ok(())
+/// })));
+///
+/// let runtime = tokio::runtime::Builder::new_current_thread().build()?;
+/// let local = tokio::task::LocalSet::new();
+/// let value = local.block_on(&runtime, vm.async_call(["main"], ()))?;
+///
+/// let value: i64 = rune::from_value(value)?;
+/// assert_eq!(value, 3);
+/// # Ok::<_, rune::support::Error>(())
+/// ```
+#[derive(Clone)]
+pub struct Spawner {
+    inner: Rc<Inner>,
+}
+
+impl Spawner {
+    /// Construct a spawner from the given function, which is called with every
+    /// task spawned by scripts.
+    pub fn new<F>(spawn: F) -> Self
+    where
+        F: 'static + Fn(Task) -> Result<(), SpawnError>,
+    {
+        Self {
+            inner: Rc::new(Inner {
+                spawn: Box::new(spawn),
+            }),
+        }
+    }
+
+    /// Spawn the given task.
+    #[inline]
+    pub fn spawn(&self, task: Task) -> Result<(), SpawnError> {
+        (self.inner.spawn)(task)
+    }
+
+    /// Convert the spawner into a raw pointer.
+    pub(crate) fn into_raw(self) -> *const Inner {
+        Rc::into_raw(self.inner)
+    }
+
+    /// Clone a spawner from a raw pointer.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must have been produced by [`Spawner::into_raw`] and still
+    /// be live.
+    pub(crate) unsafe fn clone_from_raw(ptr: *const Inner) -> Self {
+        Rc::increment_strong_count(ptr);
+
+        Self {
+            inner: Rc::from_raw(ptr),
+        }
+    }
+
+    /// Drop a spawner from a raw pointer.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must have been produced by [`Spawner::into_raw`].
+    pub(crate) unsafe fn drop_raw(ptr: *const Inner) {
+        drop(Rc::from_raw(ptr));
+    }
+}
+
+impl fmt::Debug for Spawner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Spawner").finish_non_exhaustive()
+    }
+}
+
+/// State shared between a running task and the handles used to join it.
+pub(crate) struct JoinState {
+    result: RefCell<Option<VmResult<Value>>>,
+    finished: Cell<bool>,
+    waker: RefCell<Option<Waker>>,
+}
+
+impl JoinState {
+    pub(crate) const fn new() -> Self {
+        Self {
+            result: RefCell::new(None),
+            finished: Cell::new(false),
+            waker: RefCell::new(None),
+        }
+    }
+
+    /// Test if the task has finished.
+    #[inline]
+    pub(crate) fn is_finished(&self) -> bool {
+        self.finished.get()
+    }
+
+    /// Take the result of the task if it has finished.
+    #[inline]
+    pub(crate) fn take(&self) -> Option<VmResult<Value>> {
+        self.result.borrow_mut().take()
+    }
+
+    /// Complete the task with the given result, waking up anyone waiting for
+    /// it.
+    pub(crate) fn complete(&self, result: VmResult<Value>) {
+        *self.result.borrow_mut() = Some(result);
+        self.finished.set(true);
+
+        if let Some(waker) = self.waker.borrow_mut().take() {
+            waker.wake();
+        }
+    }
+
+    /// Poll for the result of the task.
+    pub(crate) fn poll_join(&self, cx: &mut Context<'_>) -> Poll<VmResult<Value>> {
+        if let Some(result) = self.take() {
+            return Poll::Ready(result);
+        }
+
+        if self.is_finished() {
+            return Poll::Ready(VmResult::panic("Task result has already been taken"));
+        }
+
+        *self.waker.borrow_mut() = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
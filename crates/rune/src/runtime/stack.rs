@@ -395,6 +395,17 @@ impl Stack {
         }
     }
 
+    /// Construct a stack from its raw parts.
+    pub(crate) fn from_parts(stack: Vec<Value>, top: usize) -> Self {
+        Self { stack, top }
+    }
+
+    /// Access the raw parts of the stack, being all values and the top of the
+    /// current stack frame.
+    pub(crate) fn as_parts(&self) -> (&[Value], usize) {
+        (&self.stack, self.top)
+    }
+
     /// Drain the current stack down to the current stack bottom.
     pub(crate) fn drain(&mut self) -> impl DoubleEndedIterator<Item = Value> + '_ {
         self.stack.drain(self.top..)
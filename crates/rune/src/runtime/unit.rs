@@ -5,6 +5,7 @@
 
 #[cfg(feature = "byte-code")]
 mod byte_code;
+mod fingerprint;
 mod storage;
 
 use core::fmt;
@@ -213,6 +214,15 @@ impl<S> Unit<S> {
         self.logic.rtti.get(hash)
     }
 
+    /// Iterate over the run-time information of all variants of the given
+    /// enum.
+    pub(crate) fn iter_variant_rtti(&self, hash: Hash) -> impl Iterator<Item = &Arc<Rtti>> {
+        self.logic
+            .rtti
+            .values()
+            .filter(move |rtti| rtti.hash == hash && rtti.variant_hash != Hash::EMPTY)
+    }
+
     /// Lookup a function in the unit.
     #[inline]
     pub(crate) fn function(&self, hash: &Hash) -> Option<&UnitFn> {
@@ -250,6 +260,49 @@ where
     pub(crate) fn iter_instructions(&self) -> impl Iterator<Item = (usize, Inst)> + '_ {
         self.logic.storage.iter()
     }
+
+    /// Calculate a fingerprint of the logic in this unit.
+    ///
+    /// The fingerprint is stable across processes, so it can be used to
+    /// determine if two separately compiled units are equivalent. This is used
+    /// to verify that a [`VmContinuation`] is restored against the same unit as
+    /// it was taken from.
+    ///
+    /// Note that this is computed on demand and is proportional to the size of
+    /// the unit.
+    ///
+    /// [`VmContinuation`]: crate::runtime::VmContinuation
+    pub fn fingerprint(&self) -> alloc::Result<Hash> {
+        let mut buf = Vec::new();
+
+        for (ip, inst) in self.logic.storage.iter() {
+            fingerprint::encode(&mut buf, &(ip, inst))?;
+        }
+
+        let mut functions = self.logic.functions.iter().try_collect::<Vec<_>>()?;
+        functions.sort_by_key(|(hash, _)| **hash);
+
+        for (hash, function) in functions {
+            fingerprint::encode(&mut buf, &(hash, function))?;
+        }
+
+        let mut rtti = self.logic.rtti.iter().try_collect::<Vec<_>>()?;
+        rtti.sort_by_key(|(hash, _)| **hash);
+
+        for (hash, rtti) in rtti {
+            fingerprint::encode(&mut buf, &(hash, &rtti.item, rtti.kind))?;
+        }
+
+        for string in &self.logic.static_strings {
+            fingerprint::encode(&mut buf, string.as_str())?;
+        }
+
+        for bytes in &self.logic.static_bytes {
+            fingerprint::encode(&mut buf, bytes.as_slice())?;
+        }
+
+        Ok(Hash::static_bytes(&buf))
+    }
 }
 
 /// The kind and necessary information on registered functions.
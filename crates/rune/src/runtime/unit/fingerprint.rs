@@ -0,0 +1,477 @@
+//! The structured encoding used to fingerprint a unit.
+//!
+//! Values are encoded through their [`Serialize`] implementations into a
+//! compact binary form. Numbers are encoded in little-endian order, sequences
+//! and strings are prefixed with their length and enum variants with their
+//! index. The encoding therefore only depends on the structure of the values,
+//! and not on how they happen to be formatted for debugging.
+
+use core::fmt;
+
+use serde::ser::{self, Serialize};
+
+use crate::alloc::{self, Vec};
+
+/// Append the encoding of `value` to `out`.
+pub(super) fn encode<T>(out: &mut Vec<u8>, value: &T) -> alloc::Result<()>
+where
+    T: ?Sized + Serialize,
+{
+    match value.serialize(&mut Encoder { out }) {
+        Ok(()) => Ok(()),
+        Err(Error::Alloc(error)) => Err(error),
+        // NB: none of the values stored in a unit raise custom errors when
+        // serialized.
+        Err(Error::Custom) => Err(alloc::Error::FormatError),
+    }
+}
+
+#[derive(Debug)]
+enum Error {
+    Alloc(alloc::Error),
+    Custom,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Alloc(error) => error.fmt(f),
+            Error::Custom => write!(f, "Value could not be encoded"),
+        }
+    }
+}
+
+impl ser::StdError for Error {}
+
+impl ser::Error for Error {
+    #[inline]
+    fn custom<T>(_: T) -> Self
+    where
+        T: fmt::Display,
+    {
+        Error::Custom
+    }
+}
+
+impl From<alloc::Error> for Error {
+    #[inline]
+    fn from(error: alloc::Error) -> Self {
+        Error::Alloc(error)
+    }
+}
+
+struct Encoder<'a> {
+    out: &'a mut Vec<u8>,
+}
+
+impl<'a> Encoder<'a> {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) -> Result<(), Error> {
+        self.out.try_extend_from_slice(bytes)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn len(&mut self, len: usize) -> Result<(), Error> {
+        self.write(&(len as u64).to_le_bytes())
+    }
+
+    #[inline]
+    fn variant(&mut self, index: u32) -> Result<(), Error> {
+        self.write(&index.to_le_bytes())
+    }
+
+    /// Start a sequence of values whose length is written once it's known.
+    fn counted<'b>(&'b mut self) -> Result<Compound<'b, 'a>, Error> {
+        let at = self.out.len();
+        self.len(0)?;
+
+        Ok(Compound {
+            encoder: self,
+            count: Some((at, 0)),
+        })
+    }
+
+    #[inline]
+    fn fixed<'b>(&'b mut self) -> Compound<'b, 'a> {
+        Compound {
+            encoder: self,
+            count: None,
+        }
+    }
+}
+
+impl<'b, 'a> ser::Serializer for &'b mut Encoder<'a> {
+    type Ok = ();
+    type Error = Error;
+    type SerializeSeq = Compound<'b, 'a>;
+    type SerializeTuple = Compound<'b, 'a>;
+    type SerializeTupleStruct = Compound<'b, 'a>;
+    type SerializeTupleVariant = Compound<'b, 'a>;
+    type SerializeMap = Compound<'b, 'a>;
+    type SerializeStruct = Compound<'b, 'a>;
+    type SerializeStructVariant = Compound<'b, 'a>;
+
+    #[inline]
+    fn serialize_bool(self, v: bool) -> Result<(), Error> {
+        self.write(&[u8::from(v)])
+    }
+
+    #[inline]
+    fn serialize_i8(self, v: i8) -> Result<(), Error> {
+        self.write(&v.to_le_bytes())
+    }
+
+    #[inline]
+    fn serialize_i16(self, v: i16) -> Result<(), Error> {
+        self.write(&v.to_le_bytes())
+    }
+
+    #[inline]
+    fn serialize_i32(self, v: i32) -> Result<(), Error> {
+        self.write(&v.to_le_bytes())
+    }
+
+    #[inline]
+    fn serialize_i64(self, v: i64) -> Result<(), Error> {
+        self.write(&v.to_le_bytes())
+    }
+
+    #[inline]
+    fn serialize_i128(self, v: i128) -> Result<(), Error> {
+        self.write(&v.to_le_bytes())
+    }
+
+    #[inline]
+    fn serialize_u8(self, v: u8) -> Result<(), Error> {
+        self.write(&[v])
+    }
+
+    #[inline]
+    fn serialize_u16(self, v: u16) -> Result<(), Error> {
+        self.write(&v.to_le_bytes())
+    }
+
+    #[inline]
+    fn serialize_u32(self, v: u32) -> Result<(), Error> {
+        self.write(&v.to_le_bytes())
+    }
+
+    #[inline]
+    fn serialize_u64(self, v: u64) -> Result<(), Error> {
+        self.write(&v.to_le_bytes())
+    }
+
+    #[inline]
+    fn serialize_u128(self, v: u128) -> Result<(), Error> {
+        self.write(&v.to_le_bytes())
+    }
+
+    #[inline]
+    fn serialize_f32(self, v: f32) -> Result<(), Error> {
+        self.write(&v.to_bits().to_le_bytes())
+    }
+
+    #[inline]
+    fn serialize_f64(self, v: f64) -> Result<(), Error> {
+        self.write(&v.to_bits().to_le_bytes())
+    }
+
+    #[inline]
+    fn serialize_char(self, v: char) -> Result<(), Error> {
+        self.write(&u32::from(v).to_le_bytes())
+    }
+
+    #[inline]
+    fn serialize_str(self, v: &str) -> Result<(), Error> {
+        self.serialize_bytes(v.as_bytes())
+    }
+
+    #[inline]
+    fn serialize_bytes(self, v: &[u8]) -> Result<(), Error> {
+        self.len(v.len())?;
+        self.write(v)
+    }
+
+    #[inline]
+    fn serialize_none(self) -> Result<(), Error> {
+        self.write(&[0])
+    }
+
+    #[inline]
+    fn serialize_some<T>(self, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.write(&[1])?;
+        value.serialize(self)
+    }
+
+    #[inline]
+    fn serialize_unit(self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_unit_struct(self, _: &'static str) -> Result<(), Error> {
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        index: u32,
+        _: &'static str,
+    ) -> Result<(), Error> {
+        self.variant(index)
+    }
+
+    #[inline]
+    fn serialize_newtype_struct<T>(self, _: &'static str, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self)
+    }
+
+    #[inline]
+    fn serialize_newtype_variant<T>(
+        self,
+        _: &'static str,
+        index: u32,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.variant(index)?;
+        value.serialize(self)
+    }
+
+    #[inline]
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Error> {
+        self.counted()
+    }
+
+    #[inline]
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, Error> {
+        Ok(self.fixed())
+    }
+
+    #[inline]
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, Error> {
+        Ok(self.fixed())
+    }
+
+    #[inline]
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        index: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, Error> {
+        self.variant(index)?;
+        Ok(self.fixed())
+    }
+
+    #[inline]
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Error> {
+        self.counted()
+    }
+
+    #[inline]
+    fn serialize_struct(self, _: &'static str, _: usize) -> Result<Self::SerializeStruct, Error> {
+        Ok(self.fixed())
+    }
+
+    #[inline]
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        index: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, Error> {
+        self.variant(index)?;
+        Ok(self.fixed())
+    }
+
+    #[inline]
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
+
+/// Encoder for compound values.
+///
+/// Sequences and maps keep track of the offset of their length prefix and the
+/// number of elements written, so that the prefix can be filled in at the end
+/// even if the length isn't known up front.
+struct Compound<'b, 'a> {
+    encoder: &'b mut Encoder<'a>,
+    count: Option<(usize, u64)>,
+}
+
+impl Compound<'_, '_> {
+    #[inline]
+    fn element<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        if let Some((_, count)) = &mut self.count {
+            *count += 1;
+        }
+
+        value.serialize(&mut *self.encoder)
+    }
+
+    #[inline]
+    fn end(self) -> Result<(), Error> {
+        if let Some((at, count)) = self.count {
+            self.encoder.out[at..at + 8].copy_from_slice(&count.to_le_bytes());
+        }
+
+        Ok(())
+    }
+}
+
+impl ser::SerializeSeq for Compound<'_, '_> {
+    type Ok = ();
+    type Error = Error;
+
+    #[inline]
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.element(value)
+    }
+
+    #[inline]
+    fn end(self) -> Result<(), Error> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeTuple for Compound<'_, '_> {
+    type Ok = ();
+    type Error = Error;
+
+    #[inline]
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.element(value)
+    }
+
+    #[inline]
+    fn end(self) -> Result<(), Error> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for Compound<'_, '_> {
+    type Ok = ();
+    type Error = Error;
+
+    #[inline]
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.element(value)
+    }
+
+    #[inline]
+    fn end(self) -> Result<(), Error> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeTupleVariant for Compound<'_, '_> {
+    type Ok = ();
+    type Error = Error;
+
+    #[inline]
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.element(value)
+    }
+
+    #[inline]
+    fn end(self) -> Result<(), Error> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeMap for Compound<'_, '_> {
+    type Ok = ();
+    type Error = Error;
+
+    #[inline]
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.element(key)
+    }
+
+    #[inline]
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(&mut *self.encoder)
+    }
+
+    #[inline]
+    fn end(self) -> Result<(), Error> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeStruct for Compound<'_, '_> {
+    type Ok = ();
+    type Error = Error;
+
+    #[inline]
+    fn serialize_field<T>(&mut self, _: &'static str, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.element(value)
+    }
+
+    #[inline]
+    fn end(self) -> Result<(), Error> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeStructVariant for Compound<'_, '_> {
+    type Ok = ();
+    type Error = Error;
+
+    #[inline]
+    fn serialize_field<T>(&mut self, _: &'static str, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.element(value)
+    }
+
+    #[inline]
+    fn end(self) -> Result<(), Error> {
+        Compound::end(self)
+    }
+}
@@ -8,6 +8,8 @@ mod inline;
 pub use self::inline::Inline;
 
 mod serde;
+pub(crate) use self::serde::AnySerdeImpl;
+pub use self::serde::{AnySerde, TypeSeed};
 
 mod rtti;
 pub(crate) use self::rtti::RttiKind;
@@ -1421,6 +1423,16 @@ impl Value {
         VmResult::Ok(vm_try!(FromValue::from_value(value)))
     }
 
+    pub(crate) fn protocol_nth(&self, n: usize) -> VmResult<Option<Value>> {
+        let value = vm_try!(EnvProtocolCaller.call_protocol_fn(
+            &Protocol::NTH,
+            self.clone(),
+            &mut Some((n,))
+        ));
+
+        VmResult::Ok(vm_try!(FromValue::from_value(value)))
+    }
+
     pub(crate) fn protocol_nth_back(&self, n: usize) -> VmResult<Option<Value>> {
         let value = vm_try!(EnvProtocolCaller.call_protocol_fn(
             &Protocol::NTH_BACK,
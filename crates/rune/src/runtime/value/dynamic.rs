@@ -131,6 +131,12 @@ impl<H, T> Dynamic<H, T> {
         unsafe { &self.shared.as_ref().rtti }
     }
 
+    /// Get the address of the shared value, used to identify it.
+    #[inline]
+    pub(crate) fn addr(&self) -> usize {
+        self.shared.as_ptr() as usize
+    }
+
     /// Borrow the interior data array by reference.
     #[inline]
     pub(crate) fn borrow_ref(&self) -> Result<BorrowRef<[T]>, AccessError> {
@@ -1,9 +1,19 @@
 use core::fmt;
+use core::marker::PhantomData;
+
+use rust_alloc::sync::Arc;
 
 use crate::alloc;
 use crate::alloc::prelude::*;
-use crate::runtime::{self, Bytes, Inline, Object, OwnedTuple, Repr, RttiKind, Vec};
-use crate::TypeHash;
+use crate::compile::meta;
+use crate::runtime::{
+    self, env, AnyObj, AnyObjError, Bytes, Decimal, Dynamic, FromValue, Function, Inline,
+    MaybeTypeOf, Object, OwnedTuple, Repr, Rtti, RttiKind, RuntimeContext, RuntimeError, Type,
+    Unit, UnitFn, Vec, VmResult,
+};
+use crate::{Any, Hash, TypeHash};
+
+use num::{BigInt, ToPrimitive};
 
 use serde::de::{self, Deserialize as _, Error as _};
 use serde::ser::{self, Error as _, SerializeMap as _, SerializeSeq as _};
@@ -38,20 +48,7 @@ impl ser::Serialize for Value {
                 Inline::Type(..) => Err(ser::Error::custom("cannot serialize types")),
                 Inline::Ordering(..) => Err(ser::Error::custom("cannot serialize orderings")),
             },
-            Repr::Dynamic(value) => match value.rtti().kind {
-                RttiKind::Empty => Err(ser::Error::custom(format!(
-                    "cannot serialize empty struct {}",
-                    value.rtti().item
-                ))),
-                RttiKind::Tuple => Err(ser::Error::custom(format!(
-                    "cannot serialize tuple struct {}",
-                    value.rtti().item
-                ))),
-                RttiKind::Struct => Err(ser::Error::custom(format!(
-                    "cannot serialize struct {}",
-                    value.rtti().item
-                ))),
-            },
+            Repr::Dynamic(value) => serialize_dynamic(value, serializer),
             Repr::Any(value) => match value.type_hash() {
                 Option::<Value>::HASH => {
                     let option = value
@@ -97,12 +94,163 @@ impl ser::Serialize for Value {
 
                     serializer.end()
                 }
-                _ => Err(ser::Error::custom("cannot serialize external references")),
+                BigInt::HASH => {
+                    let n = value.borrow_ref::<BigInt>().map_err(S::Error::custom)?;
+
+                    // NB: Big integers are serialized as plain integers when
+                    // they fit, and as strings otherwise.
+                    if let Some(n) = n.to_i64() {
+                        serializer.serialize_i64(n)
+                    } else if let Some(n) = n.to_u64() {
+                        serializer.serialize_u64(n)
+                    } else {
+                        serializer.collect_str(&*n)
+                    }
+                }
+                Decimal::HASH => {
+                    let n = value.borrow_ref::<Decimal>().map_err(S::Error::custom)?;
+                    Decimal::serialize(&n, serializer)
+                }
+                hash => {
+                    let result = env::shared(move |context, _| {
+                        let Some(serde) = context.serde(&hash) else {
+                            return VmResult::Ok(Err(S::Error::custom(
+                                "cannot serialize external references",
+                            )));
+                        };
+
+                        VmResult::Ok(serialize_any(serde, value, serializer))
+                    });
+
+                    match result {
+                        VmResult::Ok(result) => result,
+                        VmResult::Err(..) => {
+                            Err(S::Error::custom("cannot serialize external references"))
+                        }
+                    }
+                }
+            },
+        }
+    }
+}
+
+/// Serialize a script-defined struct, tuple struct or variant.
+///
+/// Structs are serialized as maps keyed by field name, tuple structs as
+/// sequences, and empty structs as units. Tuple structs with a single field are
+/// serialized as their field, like serde does for newtypes. Variants are
+/// serialized in serde's externally tagged form.
+fn serialize_dynamic<S>(value: &Dynamic<Arc<Rtti>, Value>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: ser::Serializer,
+{
+    let rtti = value.rtti();
+    let fields = value.borrow_ref().map_err(S::Error::custom)?;
+
+    if rtti.variant_hash == Hash::EMPTY {
+        return ser::Serialize::serialize(
+            &DynamicFields {
+                rtti,
+                fields: &fields,
             },
+            serializer,
+        );
+    }
+
+    let Some(name) = rtti.item.base_name() else {
+        return Err(S::Error::custom(format!(
+            "cannot serialize variant {} without a name",
+            rtti.item
+        )));
+    };
+
+    if let RttiKind::Empty = rtti.kind {
+        return serializer.serialize_str(name);
+    }
+
+    let mut serializer = serializer.serialize_map(Some(1))?;
+    serializer.serialize_entry(
+        name,
+        &DynamicFields {
+            rtti,
+            fields: &fields,
+        },
+    )?;
+    serializer.end()
+}
+
+/// The fields of a dynamic value.
+struct DynamicFields<'a> {
+    rtti: &'a Rtti,
+    fields: &'a [Value],
+}
+
+impl ser::Serialize for DynamicFields<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        match self.rtti.kind {
+            RttiKind::Empty => serializer.serialize_unit(),
+            RttiKind::Tuple => {
+                if let [value] = self.fields {
+                    return value.serialize(serializer);
+                }
+
+                let mut serializer = serializer.serialize_seq(Some(self.fields.len()))?;
+
+                for value in self.fields {
+                    serializer.serialize_element(value)?;
+                }
+
+                serializer.end()
+            }
+            RttiKind::Struct => {
+                let mut names = alloc::Vec::new();
+                names
+                    .try_resize(self.fields.len(), "")
+                    .map_err(S::Error::custom)?;
+
+                for (name, &index) in self.rtti.fields.iter() {
+                    if let Some(slot) = names.get_mut(index) {
+                        *slot = name.as_ref();
+                    }
+                }
+
+                let mut serializer = serializer.serialize_map(Some(self.fields.len()))?;
+
+                for (name, value) in names.iter().zip(self.fields) {
+                    serializer.serialize_entry(name, value)?;
+                }
+
+                serializer.end()
+            }
         }
     }
 }
 
+/// Serialize a native value through its registered serde implementation.
+fn serialize_any<S>(serde: &dyn AnySerde, value: &AnyObj, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: ser::Serializer,
+{
+    let mut serializer = Some(serializer);
+    let mut output = None;
+
+    serde
+        .with_serialize(value, &mut |value| {
+            if let Some(serializer) = serializer.take() {
+                output = Some(erased_serde::serialize(value, serializer));
+            }
+        })
+        .map_err(S::Error::custom)?;
+
+    match output {
+        Some(output) => output,
+        None => Err(S::Error::custom("native value was not serialized")),
+    }
+}
+
 struct VmVisitor;
 
 impl<'de> de::Visitor<'de> for VmVisitor {
@@ -188,7 +336,10 @@ impl<'de> de::Visitor<'de> for VmVisitor {
     where
         E: de::Error,
     {
-        Ok(Value::from(v as i64))
+        match i64::try_from(v) {
+            Ok(v) => Ok(Value::from(v)),
+            Err(..) => Value::new(BigInt::from(v)).map_err(E::custom),
+        }
     }
 
     #[inline]
@@ -220,7 +371,10 @@ impl<'de> de::Visitor<'de> for VmVisitor {
     where
         E: de::Error,
     {
-        Ok(Value::from(v as i64))
+        match i64::try_from(v) {
+            Ok(v) => Ok(Value::from(v)),
+            Err(..) => Value::new(BigInt::from(v)).map_err(E::custom),
+        }
     }
 
     #[inline]
@@ -228,7 +382,10 @@ impl<'de> de::Visitor<'de> for VmVisitor {
     where
         E: de::Error,
     {
-        Ok(Value::from(v as i64))
+        match i64::try_from(v) {
+            Ok(v) => Ok(Value::from(v)),
+            Err(..) => Value::new(BigInt::from(v)).map_err(E::custom),
+        }
     }
 
     #[inline]
@@ -313,3 +470,418 @@ impl<'de> de::Visitor<'de> for VmVisitor {
         Value::try_from(object).map_err(V::Error::custom)
     }
 }
+
+/// Serde support for a native type stored in an [`AnyObj`].
+///
+/// Do not implement manually, this is provided by [`Module::serde`] and when
+/// deriving [`Any`] with `#[rune(serde)]`.
+///
+/// [`Module::serde`]: crate::Module::serde
+/// [`Any`]: derive@crate::Any
+pub trait AnySerde: 'static + Send + Sync {
+    /// Borrow the value and call the given closure with something which can be
+    /// serialized.
+    #[doc(hidden)]
+    fn with_serialize(
+        &self,
+        value: &AnyObj,
+        f: &mut dyn FnMut(&dyn erased_serde::Serialize),
+    ) -> Result<(), AnyObjError>;
+
+    /// Deserialize a new value.
+    #[doc(hidden)]
+    fn deserialize(
+        &self,
+        deserializer: &mut dyn erased_serde::Deserializer<'_>,
+    ) -> Result<Value, erased_serde::Error>;
+}
+
+/// The serde implementation for a native type `T`.
+pub(crate) struct AnySerdeImpl<T>(PhantomData<fn() -> T>);
+
+impl<T> AnySerdeImpl<T> {
+    pub(crate) const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> AnySerde for AnySerdeImpl<T>
+where
+    T: Any + ser::Serialize + de::DeserializeOwned,
+{
+    fn with_serialize(
+        &self,
+        value: &AnyObj,
+        f: &mut dyn FnMut(&dyn erased_serde::Serialize),
+    ) -> Result<(), AnyObjError> {
+        let value = value.borrow_ref::<T>()?;
+        f(&*value);
+        Ok(())
+    }
+
+    fn deserialize(
+        &self,
+        deserializer: &mut dyn erased_serde::Deserializer<'_>,
+    ) -> Result<Value, erased_serde::Error> {
+        let value = erased_serde::deserialize::<T>(deserializer)?;
+        Value::new(value).map_err(<erased_serde::Error as de::Error>::custom)
+    }
+}
+
+/// A [`DeserializeSeed`] which deserializes a value of a specific type.
+///
+/// Script-defined structs and enums are deserialized from the same
+/// representation they are serialized into, where every field is deserialized
+/// as a dynamic [`Value`]. Native types must have serde support registered,
+/// like through `#[rune(serde)]`. Any other type is deserialized dynamically and
+/// checked against the expected type.
+///
+/// Type information is looked up in the environment of the running virtual
+/// machine, so this can only be used from inside of a native function.
+///
+/// [`DeserializeSeed`]: serde::de::DeserializeSeed
+///
+/// # Examples
+///
+/// ```
+/// use rune::Value;
+/// use rune::runtime::TypeSeed;
+/// use serde::de::DeserializeSeed;
+///
+/// #[rune::function]
+/// fn from_json(string: &str, ty: TypeSeed) -> Result<Value, String> {
+///     let mut deserializer = serde_json::Deserializer::from_str(string);
+///     ty.deserialize(&mut deserializer).map_err(|error| error.to_string())
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct TypeSeed {
+    hash: Hash,
+}
+
+impl TypeSeed {
+    /// Construct a seed which deserializes a value of the given type.
+    pub fn new(ty: Type) -> Self {
+        Self {
+            hash: ty.into_hash(),
+        }
+    }
+}
+
+/// Convert a value which refers to a type into a seed.
+///
+/// This accepts a [`Type`], the constructor of a tuple struct or an empty
+/// struct, since these are what the name of a script-defined type evaluates to.
+impl FromValue for TypeSeed {
+    fn from_value(value: Value) -> Result<Self, RuntimeError> {
+        let hash = match value.as_ref() {
+            Repr::Inline(Inline::Type(ty)) => ty.into_hash(),
+            Repr::Dynamic(value)
+                if matches!(value.rtti().kind, RttiKind::Empty)
+                    && value.rtti().variant_hash == Hash::EMPTY =>
+            {
+                value.rtti().hash
+            }
+            Repr::Any(value) if value.type_hash() == Function::HASH => {
+                value.borrow_ref::<Function>()?.type_hash()
+            }
+            _ => return Err(RuntimeError::expected::<Type>(value.type_info())),
+        };
+
+        Ok(Self { hash })
+    }
+}
+
+impl MaybeTypeOf for TypeSeed {
+    #[inline]
+    fn maybe_type_of() -> alloc::Result<meta::DocType> {
+        Ok(meta::DocType::empty())
+    }
+}
+
+impl<'de> de::DeserializeSeed<'de> for TypeSeed {
+    type Value = Value;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        let result = env::shared(move |context, unit| {
+            VmResult::Ok(deserialize_type(context, unit, self.hash, deserializer))
+        });
+
+        match result {
+            VmResult::Ok(result) => result,
+            VmResult::Err(error) => Err(D::Error::custom(error)),
+        }
+    }
+}
+
+fn deserialize_type<'de, D>(
+    context: &RuntimeContext,
+    unit: &Unit,
+    hash: Hash,
+    deserializer: D,
+) -> Result<Value, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    if let Some(rtti) = unit.lookup_rtti(&hash) {
+        return de::DeserializeSeed::deserialize(DynamicSeed { unit, rtti }, deserializer);
+    }
+
+    if let Some(serde) = context.serde(&hash) {
+        let mut deserializer = <dyn erased_serde::Deserializer>::erase(deserializer);
+        return serde
+            .deserialize(&mut deserializer)
+            .map_err(D::Error::custom);
+    }
+
+    if unit.iter_variant_rtti(hash).next().is_some() {
+        return deserializer.deserialize_any(EnumVisitor { unit, hash });
+    }
+
+    let value = Value::deserialize(deserializer)?;
+
+    if value.type_hash() != hash {
+        return Err(D::Error::custom(format!(
+            "expected value of type {hash}, but found {}",
+            value.type_info()
+        )));
+    }
+
+    Ok(value)
+}
+
+/// Deserialize the fields of a script-defined struct or variant.
+struct DynamicSeed<'a> {
+    unit: &'a Unit,
+    rtti: &'a Arc<Rtti>,
+}
+
+impl DynamicSeed<'_> {
+    /// The number of fields in a tuple struct or variant, which is the number
+    /// of arguments its constructor takes.
+    fn tuple_len(&self) -> Option<usize> {
+        let hash = if self.rtti.variant_hash == Hash::EMPTY {
+            self.rtti.hash
+        } else {
+            self.rtti.variant_hash
+        };
+
+        match self.unit.function(&hash)? {
+            UnitFn::TupleStruct { args, .. } => Some(*args),
+            _ => None,
+        }
+    }
+}
+
+impl<'de> de::DeserializeSeed<'de> for DynamicSeed<'_> {
+    type Value = Value;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        match self.rtti.kind {
+            RttiKind::Empty => {
+                <()>::deserialize(deserializer)?;
+                Value::empty_struct(self.rtti.clone()).map_err(D::Error::custom)
+            }
+            RttiKind::Tuple => {
+                let Some(len) = self.tuple_len() else {
+                    return Err(D::Error::custom(format!(
+                        "missing constructor for {}",
+                        self.rtti.item
+                    )));
+                };
+
+                if len == 1 {
+                    let value = Value::deserialize(deserializer)?;
+                    return Value::tuple_struct(self.rtti.clone(), [value])
+                        .map_err(D::Error::custom);
+                }
+
+                deserializer.deserialize_seq(TupleVisitor {
+                    rtti: self.rtti,
+                    len,
+                })
+            }
+            RttiKind::Struct => deserializer.deserialize_map(StructVisitor { rtti: self.rtti }),
+        }
+    }
+}
+
+struct TupleVisitor<'a> {
+    rtti: &'a Arc<Rtti>,
+    len: usize,
+}
+
+impl<'de> de::Visitor<'de> for TupleVisitor<'_> {
+    type Value = Value;
+
+    #[inline]
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "tuple struct {} with {} fields",
+            self.rtti.item, self.len
+        )
+    }
+
+    fn visit_seq<V>(self, mut visitor: V) -> Result<Self::Value, V::Error>
+    where
+        V: de::SeqAccess<'de>,
+    {
+        let mut values = alloc::Vec::try_with_capacity(self.len).map_err(V::Error::custom)?;
+
+        while let Some(value) = visitor.next_element()? {
+            if values.len() == self.len {
+                return Err(V::Error::invalid_length(self.len + 1, &self));
+            }
+
+            values.try_push(value).map_err(V::Error::custom)?;
+        }
+
+        if values.len() != self.len {
+            return Err(V::Error::invalid_length(values.len(), &self));
+        }
+
+        Value::tuple_struct(self.rtti.clone(), values).map_err(V::Error::custom)
+    }
+}
+
+struct StructVisitor<'a> {
+    rtti: &'a Arc<Rtti>,
+}
+
+impl<'de> de::Visitor<'de> for StructVisitor<'_> {
+    type Value = Value;
+
+    #[inline]
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "struct {}", self.rtti.item)
+    }
+
+    fn visit_map<V>(self, mut visitor: V) -> Result<Self::Value, V::Error>
+    where
+        V: de::MapAccess<'de>,
+    {
+        let len = self.rtti.fields.len();
+
+        let mut values = alloc::Vec::new();
+        values
+            .try_resize_with(len, || None)
+            .map_err(V::Error::custom)?;
+
+        while let Some(key) = visitor.next_key::<alloc::String>()? {
+            let Some(&index) = self.rtti.fields.get(key.as_str()) else {
+                return Err(V::Error::custom(format!(
+                    "unknown field `{key}` in struct {}",
+                    self.rtti.item
+                )));
+            };
+
+            let Some(slot) = values.get_mut(index) else {
+                return Err(V::Error::custom(format!("bad field index {index}")));
+            };
+
+            if slot.is_some() {
+                return Err(V::Error::custom(format!("duplicate field `{key}`")));
+            }
+
+            *slot = Some(visitor.next_value::<Value>()?);
+        }
+
+        let mut fields = alloc::Vec::try_with_capacity(len).map_err(V::Error::custom)?;
+
+        for (index, value) in values.into_iter().enumerate() {
+            let Some(value) = value else {
+                let name = self
+                    .rtti
+                    .fields
+                    .iter()
+                    .find(|(_, &i)| i == index)
+                    .map(|(name, _)| name.as_ref())
+                    .unwrap_or("?");
+
+                return Err(V::Error::custom(format!(
+                    "missing field `{name}` in struct {}",
+                    self.rtti.item
+                )));
+            };
+
+            fields.try_push(value).map_err(V::Error::custom)?;
+        }
+
+        Value::tuple_struct(self.rtti.clone(), fields).map_err(V::Error::custom)
+    }
+}
+
+/// Deserialize an externally tagged variant of a script-defined enum.
+struct EnumVisitor<'a> {
+    unit: &'a Unit,
+    hash: Hash,
+}
+
+impl<'a> EnumVisitor<'a> {
+    fn variant<E>(&self, name: &str) -> Result<&'a Arc<Rtti>, E>
+    where
+        E: de::Error,
+    {
+        let variant = self
+            .unit
+            .iter_variant_rtti(self.hash)
+            .find(|rtti| rtti.item.base_name() == Some(name));
+
+        let Some(variant) = variant else {
+            return Err(E::custom(format!("unknown variant `{name}`")));
+        };
+
+        Ok(variant)
+    }
+}
+
+impl<'de> de::Visitor<'de> for EnumVisitor<'_> {
+    type Value = Value;
+
+    #[inline]
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an externally tagged enum variant")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        let rtti = self.variant::<E>(v)?;
+
+        let RttiKind::Empty = rtti.kind else {
+            return Err(E::custom(format!("variant `{v}` has fields")));
+        };
+
+        Value::empty_struct(rtti.clone()).map_err(E::custom)
+    }
+
+    fn visit_map<V>(self, mut visitor: V) -> Result<Self::Value, V::Error>
+    where
+        V: de::MapAccess<'de>,
+    {
+        let Some(key) = visitor.next_key::<alloc::String>()? else {
+            return Err(V::Error::custom("expected a variant"));
+        };
+
+        let rtti = self.variant::<V::Error>(key.as_str())?;
+
+        let value = visitor.next_value_seed(DynamicSeed {
+            unit: self.unit,
+            rtti,
+        })?;
+
+        if visitor.next_key::<de::IgnoredAny>()?.is_some() {
+            return Err(V::Error::custom("expected a single variant"));
+        }
+
+        Ok(value)
+    }
+}
@@ -12,22 +12,27 @@ use crate::hash;
 use crate::hash::{Hash, IntoHash, ToTypeHash};
 use crate::modules::{option, result};
 use crate::runtime;
+use crate::runtime::backtrace;
 
 mod ops;
 use self::ops::*;
 
 use super::{
-    budget, Args, Awaited, BorrowMut, Bytes, Call, ControlFlow, DynArgs, DynGuardedArgs, Dynamic,
-    Format, FormatSpec, Formatter, FromValue, Function, Future, Generator, GeneratorState,
-    GuardedArgs, Inline, Inst, InstAddress, InstArithmeticOp, InstBitwiseOp, InstOp, InstRange,
-    InstShiftOp, InstTarget, InstValue, InstVariant, Object, Output, OwnedTuple, Pair, Panic,
-    Protocol, ProtocolCaller, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo,
-    RangeToInclusive, Repr, RttiKind, RuntimeContext, Select, SelectFuture, Stack, Stream, Type,
-    TypeCheck, TypeHash, TypeInfo, TypeOf, Unit, UnitFn, UnitStorage, Value, Vec, VmDiagnostics,
-    VmDiagnosticsObj, VmError, VmErrorKind, VmExecution, VmHalt, VmIntegerRepr, VmResult,
-    VmSendExecution,
+    budget, Args, Awaited, BorrowMut, Bytes, Call, CancellationToken, ControlFlow, DynArgs,
+    DynGuardedArgs, Dynamic, Format, FormatSpec, Formatter, FromValue, Function, Future, Generator,
+    GeneratorState, GuardedArgs, Inline, Inst, InstAddress, InstArithmeticOp, InstBitwiseOp,
+    InstOp, InstRange, InstShiftOp, InstTarget, InstValue, InstVariant, Object, Output, OwnedTuple,
+    Pair, Panic, Protocol, ProtocolCaller, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo,
+    RangeToInclusive, Repr, RttiKind, RuntimeContext, Select, SelectFuture, Spawner, Stack, Stream,
+    Type, TypeCheck, TypeHash, TypeInfo, TypeOf, Unit, UnitFn, UnitStorage, Value, Vec,
+    VmDiagnostics, VmDiagnosticsObj, VmError, VmErrorKind, VmExecution, VmHalt, VmIntegerRepr,
+    VmResult, VmSendExecution,
 };
 
+/// The number of instructions executed between checks of the cancellation
+/// token.
+const CANCELLATION_INTERVAL: usize = 1024;
+
 /// Helper to take a value, replacing the old one with empty.
 #[inline(always)]
 fn take(value: &mut Value) -> Value {
@@ -125,6 +130,10 @@ pub struct Vm {
     stack: Stack,
     /// Frames relative to the stack.
     call_frames: alloc::Vec<CallFrame>,
+    /// Token used to cooperatively cancel the virtual machine.
+    cancellation: Option<CancellationToken>,
+    /// Spawner used for tasks spawned by scripts.
+    spawner: Option<Spawner>,
 }
 
 impl Vm {
@@ -150,6 +159,8 @@ impl Vm {
             last_ip_len: 0,
             stack,
             call_frames: alloc::Vec::new(),
+            cancellation: None,
+            spawner: None,
         }
     }
 
@@ -187,6 +198,12 @@ impl Vm {
         &self.call_frames
     }
 
+    /// Replace the call frames of the virtual machine.
+    #[inline]
+    pub(crate) fn set_call_frames(&mut self, call_frames: alloc::Vec<CallFrame>) {
+        self.call_frames = call_frames;
+    }
+
     /// Get the stack.
     #[inline]
     pub fn stack(&self) -> &Stack {
@@ -246,6 +263,44 @@ impl Vm {
         &self.unit
     }
 
+    /// Associate a cancellation token with the virtual machine.
+    ///
+    /// Once the token is cancelled the virtual machine stops executing when it's
+    /// next resumed, at an await point or within a bounded number of
+    /// instructions with an error for which [`VmError::is_cancelled`] returns
+    /// `true`.
+    ///
+    /// Virtual machines without a token of their own inherit the token of the
+    /// virtual machine which is calling into them.
+    #[inline]
+    pub fn set_cancellation(&mut self, cancellation: Option<CancellationToken>) {
+        self.cancellation = cancellation;
+    }
+
+    /// Access the cancellation token associated with the virtual machine.
+    #[inline]
+    pub fn cancellation(&self) -> Option<&CancellationToken> {
+        self.cancellation.as_ref()
+    }
+
+    /// Associate a spawner with the virtual machine, which is used to run
+    /// tasks spawned by scripts through `std::task::spawn`.
+    ///
+    /// Virtual machines without a spawner of their own inherit the spawner of
+    /// the virtual machine which is calling into them.
+    ///
+    /// See [`Spawner`] for an example.
+    #[inline]
+    pub fn set_spawner(&mut self, spawner: Option<Spawner>) {
+        self.spawner = spawner;
+    }
+
+    /// Access the spawner associated with the virtual machine.
+    #[inline]
+    pub fn spawner(&self) -> Option<&Spawner> {
+        self.spawner.as_ref()
+    }
+
     /// Access the current instruction pointer.
     #[inline]
     pub fn ip(&self) -> usize {
@@ -397,6 +452,9 @@ impl Vm {
     /// This is accomplished by preventing values escaping from being
     /// non-exclusively sent with the execution or escaping the execution. We
     /// only support encoding arguments which themselves are `Send`.
+    ///
+    /// Spawners are bound to the thread they were created on, so any
+    /// [`Spawner`] associated with the virtual machine is removed.
     pub fn send_execute(
         mut self,
         name: impl ToTypeHash,
@@ -405,12 +463,29 @@ impl Vm {
         // Safety: make sure the stack is clear, preventing any values from
         // being sent along with the virtual machine.
         self.stack.clear();
+        // Safety: the spawner isn't `Send`, so it can't come along either.
+        self.spawner = None;
 
         self.set_entrypoint(name, args.count())?;
         args.into_stack(&mut self.stack).into_result()?;
         Ok(VmSendExecution(VmExecution::new(self)))
     }
 
+    /// An `execute` variant that returns an execution which owns the virtual
+    /// machine, allowing it to be stored and resumed later such as when it's
+    /// spawned on a [`Scheduler`].
+    ///
+    /// [`Scheduler`]: crate::runtime::Scheduler
+    pub fn owned_execute(
+        mut self,
+        name: impl ToTypeHash,
+        args: impl Args,
+    ) -> Result<VmExecution<Self>, VmError> {
+        self.set_entrypoint(name, args.count())?;
+        args.into_stack(&mut self.stack).into_result()?;
+        Ok(VmExecution::new(self))
+    }
+
     /// Call the given function immediately, returning the produced value.
     ///
     /// This function permits for using references since it doesn't defer its
@@ -1084,6 +1159,19 @@ impl Vm {
         VmResult::Ok(())
     }
 
+    /// Construct a virtual machine for a nested call, which inherits the
+    /// cancellation token and spawner of this one.
+    fn nested(&self, stack: Stack, ip: usize) -> Self {
+        let mut vm = Self::with_stack(self.context.clone(), self.unit.clone(), stack);
+        vm.ip = ip;
+        vm.cancellation = self
+            .cancellation
+            .clone()
+            .or_else(runtime::env::cancellation);
+        vm.spawner = self.spawner.clone().or_else(runtime::env::spawner);
+        vm
+    }
+
     /// Construct a future from calling an async function.
     fn call_generator_fn(
         &mut self,
@@ -1096,8 +1184,7 @@ impl Vm {
 
         if let Some(at) = out.as_addr() {
             let stack = values.iter_mut().map(take).try_collect::<Stack>()?;
-            let mut vm = Self::with_stack(self.context.clone(), self.unit.clone(), stack);
-            vm.ip = offset;
+            let vm = self.nested(stack, offset);
             *self.stack.at_mut(at)? = Value::try_from(Generator::new(vm))?;
         } else {
             values.iter_mut().for_each(consume);
@@ -1118,8 +1205,7 @@ impl Vm {
 
         if let Some(at) = out.as_addr() {
             let stack = values.iter_mut().map(take).try_collect::<Stack>()?;
-            let mut vm = Self::with_stack(self.context.clone(), self.unit.clone(), stack);
-            vm.ip = offset;
+            let vm = self.nested(stack, offset);
             *self.stack.at_mut(at)? = Value::try_from(Stream::new(vm))?;
         } else {
             values.iter_mut().for_each(consume);
@@ -1140,8 +1226,7 @@ impl Vm {
 
         if let Some(at) = out.as_addr() {
             let stack = values.iter_mut().map(take).try_collect::<Stack>()?;
-            let mut vm = Self::with_stack(self.context.clone(), self.unit.clone(), stack);
-            vm.ip = offset;
+            let vm = self.nested(stack, offset);
             let mut execution = vm.into_execution();
             let future = Future::new(async move { execution.async_complete().await })?;
             *self.stack.at_mut(at)? = Value::try_from(future)?;
@@ -1456,6 +1541,22 @@ impl Vm {
         let value = match value.as_ref() {
             Repr::Inline(Inline::Float(value)) => Value::from(-value),
             Repr::Inline(Inline::Signed(value)) => Value::from(-value),
+            Repr::Any(..) => {
+                let value = value.clone();
+
+                if let CallResult::Unsupported(value) = vm_try!(self.call_instance_fn(
+                    Isolated::None,
+                    value,
+                    &Protocol::NEG,
+                    &mut (),
+                    out
+                )) {
+                    let operand = value.type_info();
+                    return err(VmErrorKind::UnsupportedUnaryOperation { op: "-", operand });
+                }
+
+                return VmResult::Ok(());
+            }
             actual => {
                 let operand = actual.type_info();
                 return err(VmErrorKind::UnsupportedUnaryOperation { op: "-", operand });
@@ -2841,7 +2942,12 @@ impl Vm {
                 return err(VmErrorKind::MissingFunction { hash });
             };
 
-            vm_try!(handler(&mut self.stack, addr, args, out));
+            vm_try!(
+                handler(&mut self.stack, addr, args, out).with_native(hash, || {
+                    backtrace::native_name(self.unit.debug_info(), hash, None)
+                })
+            );
+
             return VmResult::Ok(());
         };
 
@@ -2908,11 +3014,19 @@ impl Vm {
     ) -> VmResult<()> {
         let instance = self.stack.at(addr);
         let type_hash = instance.type_hash();
-        let hash = Hash::associated_function(type_hash, hash);
+        let name = hash;
+        let hash = Hash::associated_function(type_hash, name);
 
         if let Some(handler) = self.context.function(&hash) {
+            let type_info = instance.type_info();
             vm_try!(self.called_function_hook(hash));
-            vm_try!(handler(&mut self.stack, addr, args, out));
+
+            vm_try!(
+                handler(&mut self.stack, addr, args, out).with_native(hash, || {
+                    backtrace::native_name(self.unit.debug_info(), hash, Some((&type_info, name)))
+                })
+            );
+
             return VmResult::Ok(());
         }
 
@@ -3013,7 +3127,14 @@ impl Vm {
     where
         F: FnOnce() -> T,
     {
-        let _guard = runtime::env::Guard::new(self.context.clone(), self.unit.clone(), None);
+        let _guard = runtime::env::Guard::new(
+            self.context.clone(),
+            self.unit.clone(),
+            None,
+            self.cancellation.clone(),
+            self.spawner.clone(),
+        );
+
         f()
     }
 
@@ -3029,17 +3150,52 @@ impl Vm {
             None => None,
         };
 
+        // NB: virtual machines without a token of their own inherit the one
+        // from the environment they are being called from.
+        let cancellation = match &self.cancellation {
+            Some(cancellation) => Some(cancellation.clone()),
+            None => runtime::env::cancellation(),
+        };
+
+        let spawner = match &self.spawner {
+            Some(spawner) => Some(spawner.clone()),
+            None => runtime::env::spawner(),
+        };
+
         // NB: set up environment so that native function can access context and
         // unit.
-        let _guard = runtime::env::Guard::new(self.context.clone(), self.unit.clone(), diagnostics);
+        let _guard = runtime::env::Guard::new(
+            self.context.clone(),
+            self.unit.clone(),
+            diagnostics,
+            cancellation.clone(),
+            spawner,
+        );
 
         let mut budget = budget::acquire();
 
+        // NB: the token is checked when entering the loop, which happens every
+        // time the execution is resumed after a yield, await or exhausted
+        // budget, and then once every interval.
+        let mut until_cancellation_check = 0;
+
         loop {
             if !budget.take() {
                 return VmResult::Ok(VmHalt::Limited);
             }
 
+            if until_cancellation_check == 0 {
+                if let Some(cancellation) = &cancellation {
+                    if cancellation.is_cancelled() {
+                        return VmResult::err(VmErrorKind::Cancelled);
+                    }
+                }
+
+                until_cancellation_check = CANCELLATION_INTERVAL;
+            }
+
+            until_cancellation_check -= 1;
+
             let Some((inst, inst_len)) = vm_try!(self.unit.instruction_at(self.ip)) else {
                 return VmResult::err(VmErrorKind::IpOutOfBounds {
                     ip: self.ip,
@@ -3360,6 +3516,8 @@ impl TryClone for Vm {
             last_ip_len: self.last_ip_len,
             stack: self.stack.try_clone()?,
             call_frames: self.call_frames.try_clone()?,
+            cancellation: self.cancellation.clone(),
+            spawner: self.spawner.clone(),
         })
     }
 }
@@ -6,15 +6,17 @@ use ::rust_alloc::sync::Arc;
 
 use crate::alloc::error::CustomError;
 use crate::alloc::prelude::*;
-use crate::alloc::{self, String};
+use crate::alloc::{self, String, Vec};
 use crate::compile::meta;
+use crate::runtime::backtrace::RawFrame;
 use crate::runtime::unit::{BadInstruction, BadJump};
-use crate::{Any, Hash, ItemBuf};
+use crate::{Any, Hash, ItemBuf, Sources};
 
 use super::{
-    AccessError, AccessErrorKind, AnyObjError, AnyObjErrorKind, AnyTypeInfo, BoxedPanic, CallFrame,
-    DynArgsUsed, DynamicTakeError, ExecutionState, MaybeTypeOf, Panic, Protocol, SliceError,
-    StackError, StaticString, TypeInfo, TypeOf, Unit, Vm, VmHaltInfo,
+    AccessError, AccessErrorKind, AnyObjError, AnyObjErrorKind, AnyTypeInfo, BacktraceFrame,
+    BoxedPanic, CallFrame, DynArgsUsed, DynamicTakeError, ExecutionState, MaybeTypeOf, Panic,
+    Protocol, SliceError, SpawnError, StackError, StaticString, TypeInfo, TypeOf, Unit, Vm,
+    VmHaltInfo,
 };
 
 /// A virtual machine error which includes tracing information.
@@ -36,6 +38,7 @@ impl VmError {
                 },
                 chain: ::rust_alloc::vec::Vec::new(),
                 stacktrace: ::rust_alloc::vec::Vec::new(),
+                natives: ::rust_alloc::vec::Vec::new(),
             }),
         }
     }
@@ -72,11 +75,111 @@ impl VmError {
         Self::from(VmErrorKind::Overflow)
     }
 
+    /// Test if the error was caused by the execution being cancelled through a
+    /// [`CancellationToken`].
+    ///
+    /// [`CancellationToken`]: crate::runtime::CancellationToken
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self.inner.error.kind, VmErrorKind::Cancelled)
+    }
+
     /// Get the first error location.
     pub fn first_location(&self) -> Option<&VmErrorLocation> {
         self.inner.stacktrace.first()
     }
 
+    /// Get the script call stack at the time of the error, with the innermost
+    /// frame first.
+    ///
+    /// Every frame is resolved against the given `sources` to provide its
+    /// file, line and column. This is the structured form of the backtrace
+    /// which is rendered by [`VmError::emit`].
+    ///
+    /// [`VmError::emit`]: crate::runtime::VmError::emit
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rune::{Context, Diagnostics, Vm};
+    /// use rune::runtime::BacktraceFrameKind;
+    /// use std::sync::Arc;
+    ///
+    /// let mut sources = rune::sources! {
+    ///     entry => {
+    ///         fn inner(value) {
+    ///             value.parse::<i64>()?
+    ///         }
+    ///
+    ///         pub fn main() {
+    ///             inner(#{})
+    ///         }
+    ///     }
+    /// };
+    ///
+    /// let context = Context::with_default_modules()?;
+    /// let unit = rune::prepare(&mut sources).with_context(&context).build()?;
+    ///
+    /// let mut vm = Vm::new(Arc::new(context.runtime()?), Arc::new(unit));
+    /// let error = vm.call(["main"], ()).unwrap_err();
+    ///
+    /// let backtrace = error.backtrace(&sources)?;
+    /// assert_eq!(backtrace.len(), 2);
+    ///
+    /// let frame = &backtrace[0];
+    /// assert_eq!(frame.kind, BacktraceFrameKind::Script);
+    /// assert_eq!(frame.function.as_deref(), Some("inner(value)"));
+    /// assert!(frame.location.is_some());
+    ///
+    /// assert_eq!(backtrace[1].function.as_deref(), Some("main()"));
+    /// # Ok::<_, rune::support::Error>(())
+    /// ```
+    pub fn backtrace(&self, sources: &Sources) -> alloc::Result<Vec<BacktraceFrame>> {
+        let mut frames = Vec::new();
+
+        for frame in self.raw_backtrace() {
+            frames.try_push(frame.resolve(sources)?)?;
+        }
+
+        Ok(frames)
+    }
+
+    /// Iterate over the raw frames of the backtrace, innermost first.
+    pub(crate) fn raw_backtrace(&self) -> impl Iterator<Item = RawFrame<'_>> + '_ {
+        let inner = &*self.inner;
+
+        let natives = move |index: usize| {
+            inner
+                .natives
+                .iter()
+                .filter(move |native| native.index == index)
+                .map(RawFrame::Native)
+        };
+
+        let scripts = inner
+            .stacktrace
+            .iter()
+            .enumerate()
+            .flat_map(move |(index, l)| {
+                let current = RawFrame::Script {
+                    unit: &l.unit,
+                    ip: l.ip,
+                    caller: false,
+                };
+
+                let callers = l.frames.iter().rev().map(move |v| RawFrame::Script {
+                    unit: &l.unit,
+                    ip: v.ip,
+                    caller: true,
+                });
+
+                let frames = [current].into_iter().chain(callers);
+
+                natives(index).chain(frames)
+            });
+
+        scripts.chain(natives(inner.stacktrace.len()))
+    }
+
     pub(crate) fn into_kind(self) -> VmErrorKind {
         self.inner.error.kind
     }
@@ -95,6 +198,7 @@ impl fmt::Debug for VmError {
             .field("error", &self.inner.error)
             .field("chain", &self.inner.chain)
             .field("stacktrace", &self.inner.stacktrace)
+            .field("natives", &self.inner.natives)
             .finish()
     }
 }
@@ -196,11 +300,24 @@ impl fmt::Display for VmErrorAt {
     }
 }
 
+/// A native function which was being called when an error was raised.
+#[derive(Debug)]
+pub(crate) struct VmErrorNative {
+    /// Index into the stacktrace of the location which called the native
+    /// function.
+    pub(crate) index: usize,
+    /// The hash of the native function.
+    pub(crate) hash: Hash,
+    /// The name of the native function, if it could be determined.
+    pub(crate) name: Option<String>,
+}
+
 #[non_exhaustive]
 pub(crate) struct VmErrorInner {
     pub(crate) error: VmErrorAt,
     pub(crate) chain: ::rust_alloc::vec::Vec<VmErrorAt>,
     pub(crate) stacktrace: ::rust_alloc::vec::Vec<VmErrorLocation>,
+    pub(crate) natives: ::rust_alloc::vec::Vec<VmErrorNative>,
 }
 
 /// A result produced by the virtual machine.
@@ -292,6 +409,29 @@ impl<T> VmResult<T> {
         }
     }
 
+    /// Record that the error was raised by the native function with the given
+    /// hash.
+    #[inline]
+    pub(crate) fn with_native<F>(self, hash: Hash, name: F) -> Self
+    where
+        F: FnOnce() -> Option<String>,
+    {
+        match self {
+            Self::Ok(ok) => Self::Ok(ok),
+            Self::Err(mut err) => {
+                let index = err.inner.stacktrace.len();
+
+                err.inner.natives.push(VmErrorNative {
+                    index,
+                    hash,
+                    name: name(),
+                });
+
+                Self::Err(err)
+            }
+        }
+    }
+
     /// Add auxilliary errors if appropriate.
     #[inline]
     pub(crate) fn with_error<E, O>(self, error: E) -> Self
@@ -391,6 +531,7 @@ impl<const N: usize> From<[VmErrorKind; N]> for VmError {
                 },
                 chain,
                 stacktrace: ::rust_alloc::vec::Vec::new(),
+                natives: ::rust_alloc::vec::Vec::new(),
             }),
         }
     }
@@ -661,6 +802,11 @@ pub(crate) enum VmErrorKind {
     Halted {
         halt: VmHaltInfo,
     },
+    Cancelled,
+    MissingSpawner,
+    SpawnFailed {
+        error: SpawnError,
+    },
     Overflow,
     Underflow,
     DivideByZero,
@@ -888,6 +1034,11 @@ impl fmt::Display for VmErrorKind {
             VmErrorKind::Panic { reason } => write!(f, "Panicked: {reason}"),
             VmErrorKind::NoRunningVm {} => write!(f, "No running virtual machines"),
             VmErrorKind::Halted { halt } => write!(f, "Halted for unexpected reason `{halt}`"),
+            VmErrorKind::Cancelled {} => write!(f, "Execution was cancelled"),
+            VmErrorKind::MissingSpawner {} => {
+                write!(f, "No spawner is available to spawn tasks with")
+            }
+            VmErrorKind::SpawnFailed { error } => write!(f, "Failed to spawn task: {error}"),
             VmErrorKind::Overflow {} => write!(f, "Numerical overflow"),
             VmErrorKind::Underflow {} => write!(f, "Numerical underflow"),
             VmErrorKind::DivideByZero {} => write!(f, "Division by zero"),
@@ -1,14 +1,16 @@
 use core::fmt;
-use core::future::Future;
+use core::future::{poll_fn, Future};
 use core::mem::{replace, take};
+use core::task::Poll;
 
 use ::rust_alloc::sync::Arc;
 
 use crate::alloc::prelude::*;
 use crate::runtime::budget;
 use crate::runtime::{
-    Generator, GeneratorState, InstAddress, Output, RuntimeContext, Stream, Unit, Value, Vm,
-    VmErrorKind, VmHalt, VmHaltInfo, VmResult,
+    Awaited, CancellationToken, ContinuationError, Generator, GeneratorState, InstAddress, Output,
+    RuntimeContext, Stream, Unit, Value, Vm, VmContinuation, VmErrorKind, VmHalt, VmHaltInfo,
+    VmResult,
 };
 use crate::shared::AssertSend;
 
@@ -42,6 +44,21 @@ impl fmt::Display for ExecutionState {
     }
 }
 
+/// The outcome of running an execution for a budgeted slice.
+///
+/// See [`VmExecution::run_slice`].
+#[derive(Debug)]
+pub(crate) enum VmSlice {
+    /// The execution ran out of budget.
+    Limited,
+    /// The execution yielded, and will be resumed with a unit value.
+    Yielded,
+    /// The execution is waiting for the given value.
+    Awaited(Awaited),
+    /// The execution completed with the given value.
+    Complete(Value),
+}
+
 #[derive(TryClone)]
 #[try_clone(crate)]
 pub(crate) struct VmExecutionState {
@@ -78,6 +95,26 @@ where
         }
     }
 
+    /// Construct an execution from a virtual machine in the given state.
+    pub(crate) fn with_state(head: T, state: ExecutionState) -> Self {
+        Self {
+            head,
+            state,
+            states: Vec::new(),
+        }
+    }
+
+    /// Get the current state of the execution.
+    pub(crate) fn state(&self) -> ExecutionState {
+        self.state
+    }
+
+    /// Test if the execution has suspended states from calling into other
+    /// virtual machines.
+    pub(crate) fn has_states(&self) -> bool {
+        !self.states.is_empty()
+    }
+
     /// Test if the current execution state is resumed.
     pub(crate) fn is_resumed(&self) -> bool {
         matches!(self.state, ExecutionState::Resumed(..))
@@ -162,6 +199,84 @@ where
         self.head.as_mut()
     }
 
+    /// Get a token which can be used to cooperatively cancel the execution.
+    ///
+    /// If the virtual machine doesn't have a token associated with it, a new
+    /// one is constructed and associated with it. Once cancelled, the
+    /// execution stops when it's next resumed, at an await point or within a
+    /// bounded number of instructions with an error for which
+    /// [`VmError::is_cancelled`] returns `true`.
+    ///
+    /// Scripts can observe cancellation requested through
+    /// [`CancellationToken::request_cancel`] using `std::task::is_cancelled`,
+    /// allowing long-running loops to shut down gracefully.
+    ///
+    /// ```
+    /// use rune::{Context, Vm};
+    /// use std::sync::Arc;
+    ///
+    /// let mut sources = rune::sources! {
+    ///     entry => {
+    ///         pub fn main() {
+    ///             let n = 0;
+    ///
+    ///             while !std::task::is_cancelled() {
+    ///                 n += 1;
+    ///             }
+    ///
+    ///             n
+    ///         }
+    ///     }
+    /// };
+    ///
+    /// let context = Context::with_default_modules()?;
+    /// let runtime = Arc::new(context.runtime()?);
+    /// let unit = rune::prepare(&mut sources).with_context(&context).build()?;
+    ///
+    /// let mut vm = Vm::new(runtime, Arc::new(unit));
+    ///
+    /// let mut execution = vm.execute(["main"], ())?;
+    /// execution.cancellation_token().request_cancel();
+    /// let n: i64 = rune::from_value(execution.complete().into_result()?)?;
+    /// assert_eq!(n, 0);
+    ///
+    /// let mut execution = vm.execute(["main"], ())?;
+    /// execution.cancellation_token().cancel();
+    /// let error = execution.complete().into_result().unwrap_err();
+    /// assert!(error.is_cancelled());
+    /// # Ok::<_, rune::support::Error>(())
+    /// ```
+    ///
+    /// [`VmError::is_cancelled`]: crate::runtime::VmError::is_cancelled
+    pub fn cancellation_token(&mut self) -> CancellationToken {
+        let vm = self.head.as_mut();
+
+        if let Some(cancellation) = vm.cancellation() {
+            return cancellation.clone();
+        }
+
+        let cancellation = CancellationToken::new();
+        vm.set_cancellation(Some(cancellation.clone()));
+        cancellation
+    }
+
+    /// Snapshot the current execution into a serializable continuation.
+    ///
+    /// The continuation captures the call frames, the stack, the state of the
+    /// execution and all values reachable from them. It can be restored
+    /// through [`VmExecution::restore`] against the same unit.
+    ///
+    /// This errors if any reachable value can't be snapshotted, like native
+    /// types or functions defined in another unit, or if the execution is
+    /// currently calling into another unit. Ranges and iterators over ranges
+    /// and vectors are supported, where an iterator over a vector is restored
+    /// as an iterator over a copy of its remaining values.
+    ///
+    /// See [`VmContinuation`] for an example.
+    pub fn snapshot(&self) -> Result<VmContinuation, ContinuationError> {
+        VmContinuation::from_execution(self)
+    }
+
     /// Complete the current execution without support for async instructions.
     ///
     /// This will error if the execution is suspended through yielding.
@@ -268,6 +383,12 @@ where
                     self.state = ExecutionState::Resumed(out);
                     return VmResult::Ok(GeneratorState::Yielded(value));
                 }
+                VmHalt::Limited if budget::is_slice() => {
+                    // NB: give way to the executor, which refills the slice
+                    // the next time we're polled.
+                    yield_now().await;
+                    continue;
+                }
                 halt => {
                     return VmResult::err(VmErrorKind::Halted {
                         halt: halt.into_info(),
@@ -440,6 +561,48 @@ where
         VmResult::Ok(None)
     }
 
+    /// Run the execution until it either completes, yields, awaits or runs out
+    /// of the budget which is currently in effect.
+    ///
+    /// In contrast to [`async_step`], awaited values are handed back to the
+    /// caller to drive.
+    ///
+    /// [`async_step`]: Self::async_step
+    pub(crate) fn run_slice(&mut self) -> VmResult<VmSlice> {
+        if let ExecutionState::Resumed(out) = replace(&mut self.state, ExecutionState::Suspended) {
+            vm_try!(out.store(self.head.as_mut().stack_mut(), Value::unit()));
+        }
+
+        loop {
+            let vm = self.head.as_mut();
+
+            match vm_try!(vm.run(None).with_vm(vm)) {
+                VmHalt::Exited(addr) => {
+                    self.state = ExecutionState::Exited(addr);
+                }
+                VmHalt::Awaited(awaited) => {
+                    return VmResult::Ok(VmSlice::Awaited(awaited));
+                }
+                VmHalt::VmCall(vm_call) => {
+                    vm_try!(vm_call.into_execution(self));
+                    continue;
+                }
+                VmHalt::Yielded(_, out) => {
+                    self.state = ExecutionState::Resumed(out);
+                    return VmResult::Ok(VmSlice::Yielded);
+                }
+                VmHalt::Limited => return VmResult::Ok(VmSlice::Limited),
+            }
+
+            if self.states.is_empty() {
+                let value = vm_try!(self.end());
+                return VmResult::Ok(VmSlice::Complete(value));
+            }
+
+            vm_try!(self.pop_state());
+        }
+    }
+
     /// End execution and perform debug checks.
     pub(crate) fn end(&mut self) -> VmResult<Value> {
         let ExecutionState::Exited(addr) = self.state else {
@@ -487,11 +650,30 @@ where
     }
 }
 
+impl VmExecution<Vm> {
+    /// Restore an execution from a continuation produced by
+    /// [`VmExecution::snapshot`].
+    ///
+    /// The `unit` must have the same [`Unit::fingerprint`] as the unit the
+    /// continuation was taken from, or an error is raised.
+    ///
+    /// See [`VmContinuation`] for an example.
+    pub fn restore(
+        context: Arc<RuntimeContext>,
+        unit: Arc<Unit>,
+        continuation: VmContinuation,
+    ) -> Result<Self, ContinuationError> {
+        continuation.into_execution(context, unit)
+    }
+}
+
 impl VmExecution<&mut Vm> {
     /// Convert the current execution into one which owns its virtual machine.
     pub fn into_owned(self) -> VmExecution<Vm> {
         let stack = take(self.head.stack_mut());
-        let head = Vm::with_stack(self.head.context().clone(), self.head.unit().clone(), stack);
+        let mut head = Vm::with_stack(self.head.context().clone(), self.head.unit().clone(), stack);
+        head.set_cancellation(self.head.cancellation().cloned());
+        head.set_spawner(self.head.spawner().cloned());
 
         VmExecution {
             head,
@@ -577,3 +759,18 @@ where
         })
     }
 }
+
+/// Yield to the executor once, asking to be polled again.
+fn yield_now() -> impl Future<Output = ()> {
+    let mut yielded = false;
+
+    poll_fn(move |cx| {
+        if yielded {
+            return Poll::Ready(());
+        }
+
+        yielded = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    })
+}
@@ -40,13 +40,16 @@ use core::fmt;
 
 use ::rust_alloc::string::String;
 use ::rust_alloc::sync::Arc;
+use ::rust_alloc::vec::Vec;
+
+use std::sync::Mutex;
 
 use anyhow::{Context as _, Error, Result};
 
 use crate::runtime::{Args, VmError};
 use crate::{
-    alloc, termcolor, BuildError, Context, Diagnostics, FromValue, Hash, Options, Source, Sources,
-    Unit, Vm,
+    alloc, termcolor, BuildError, Context, Diagnostics, FromValue, Hash, Module, Options, Source,
+    Sources, Unit, Vm,
 };
 
 /// An error that can be raised during testing.
@@ -179,6 +182,37 @@ pub fn sources(source: &str) -> Sources {
     sources
 }
 
+/// Construct a virtual machine for the given source, which is compiled as a
+/// module rather than a script so that its functions can be called by name.
+pub(crate) fn vm_for(context: &Context, source: &str) -> Result<Vm> {
+    let mut sources = sources(source);
+    let mut diagnostics = Diagnostics::new();
+    Ok(vm(context, &mut sources, &mut diagnostics, false)?)
+}
+
+/// The values passed to the native `record` function installed by [`logged`].
+pub(crate) type Log = Arc<Mutex<Vec<i64>>>;
+
+/// Construct a virtual machine for the given source, with a native `record`
+/// function which appends its argument to the returned log.
+pub(crate) fn logged(source: &str) -> Result<(Log, Vm)> {
+    let log = Log::default();
+
+    let mut m = Module::new();
+
+    m.function("record", {
+        let log = log.clone();
+        move |id: i64| log.lock().unwrap().push(id)
+    })
+    .build()?;
+
+    let mut context = Context::with_default_modules()?;
+    context.install(m)?;
+
+    let vm = vm_for(&context, source)?;
+    Ok((log, vm))
+}
+
 /// Run the given source with diagnostics being printed to stderr.
 pub fn run<T>(context: &Context, source: &str, args: impl Args, script: bool) -> Result<T>
 where
@@ -437,6 +471,8 @@ mod builtin_macros;
 #[cfg(not(miri))]
 mod capture;
 #[cfg(not(miri))]
+mod capture_io;
+#[cfg(not(miri))]
 mod comments;
 #[cfg(not(miri))]
 mod compiler_docs;
@@ -518,12 +554,20 @@ mod vm_assign_exprs;
 #[cfg(not(miri))]
 mod vm_async_block;
 #[cfg(not(miri))]
+mod vm_backtrace;
+#[cfg(not(miri))]
+mod vm_big_numbers;
+#[cfg(not(miri))]
 mod vm_blocks;
 #[cfg(not(miri))]
+mod vm_cancellation;
+#[cfg(not(miri))]
 mod vm_closures;
 #[cfg(not(miri))]
 mod vm_const_exprs;
 #[cfg(not(miri))]
+mod vm_continuation;
+#[cfg(not(miri))]
 mod vm_early_termination;
 #[cfg(not(miri))]
 mod vm_function;
@@ -536,8 +580,18 @@ mod vm_literals;
 #[cfg(not(miri))]
 mod vm_not_used;
 #[cfg(not(miri))]
+mod vm_ordered_collections;
+#[cfg(not(miri))]
 mod vm_result;
 #[cfg(not(miri))]
+mod vm_scheduler;
+#[cfg(not(miri))]
+mod vm_serde;
+#[cfg(not(miri))]
+mod vm_spawn;
+#[cfg(not(miri))]
+mod vm_string_patterns;
+#[cfg(not(miri))]
 mod vm_test_from_value_derive;
 #[cfg(not(miri))]
 mod vm_test_imports;
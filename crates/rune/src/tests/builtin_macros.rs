@@ -52,6 +52,12 @@ macro_rules! test_case {
         let string = capture!($($prefix)* print!($($format)*));
         assert_eq!(string, $expected, "Expecting print!");
 
+        let string = capture!($($prefix)* eprintln!($($format)*));
+        assert_eq!(string, concat!($expected, "\n"), "Expecting eprintln!");
+
+        let string = capture!($($prefix)* eprint!($($format)*));
+        assert_eq!(string, $expected, "Expecting eprint!");
+
         let string: String = rune!($($prefix)* format!($($format)*));
         assert_eq!(string, $expected, "Expecting format!");
     }}
@@ -0,0 +1,94 @@
+#![cfg(feature = "capture-io")]
+
+prelude!();
+
+use crate::modules::capture_io::{self, CaptureIo};
+
+fn capture(input: &str, source: &str) -> Result<String> {
+    let capture = CaptureIo::new();
+    capture.push_input(input.as_bytes())?;
+
+    let mut context = Context::with_config(false)?;
+    context.install(capture_io::module(&capture)?)?;
+
+    let mut sources = Sources::new();
+    sources.insert(Source::memory(source)?)?;
+
+    let unit = prepare(&mut sources).with_context(&context).build()?;
+
+    let mut vm = Vm::new(Arc::new(context.runtime()?), Arc::new(unit));
+    vm.call(["main"], ())?;
+    Ok(capture.drain_utf8()?.into_std())
+}
+
+#[test]
+fn stdin_read_line() -> Result<()> {
+    let output = capture(
+        "first\nsecond\n",
+        r#"
+        pub fn main() {
+            let stdin = std::io::stdin();
+            print!("{:?}", stdin.read_line()?);
+            print!("{:?}", stdin.read_line()?);
+            print!("{:?}", stdin.read_line()?);
+        }
+        "#,
+    )?;
+
+    assert_eq!(output, r#""first\n""second\n""""#);
+    Ok(())
+}
+
+#[test]
+fn stdin_lines() -> Result<()> {
+    let output = capture(
+        "a\r\nb\n\nc",
+        r#"
+        pub fn main() {
+            for line in std::io::stdin().lines() {
+                println!("[{}]", line?);
+            }
+        }
+        "#,
+    )?;
+
+    assert_eq!(output, "[a]\n[b]\n[]\n[c]\n");
+    Ok(())
+}
+
+#[test]
+fn stdin_read_to_string() -> Result<()> {
+    let output = capture(
+        "one\ntwo",
+        r#"
+        pub fn main() {
+            let stdin = std::io::stdin();
+            stdin.read_line()?;
+            print!("{}", stdin.read_to_string()?);
+        }
+        "#,
+    )?;
+
+    assert_eq!(output, "two");
+    Ok(())
+}
+
+#[test]
+fn output_streams() -> Result<()> {
+    let output = capture(
+        "",
+        r#"
+        pub fn main() {
+            use std::io;
+
+            io::stdout().write_str("out ")?;
+            io::stderr().write_bytes(b"err ")?;
+            eprintln!("{}", 42);
+            io::stdout().flush()?;
+        }
+        "#,
+    )?;
+
+    assert_eq!(output, "out err 42\n");
+    Ok(())
+}
@@ -147,3 +147,120 @@ fn test_flat_map() {
 
     assert_eq!(actual, expected);
 }
+
+#[test]
+fn test_zip() {
+    let values: Vec<(i64, char)> = rune! {
+        let it = [1, 2, 3].iter().zip(['a', 'b']);
+        assert_eq!(it.size_hint(), (2, Some(2)));
+        it.collect::<Vec>()
+    };
+
+    assert_eq!(values, vec![(1, 'a'), (2, 'b')]);
+}
+
+#[test]
+fn test_step_by() {
+    let values: Vec<i64> = rune! {
+        let it = (0..10).iter().step_by(3);
+        assert_eq!(it.len(), 4);
+        assert_eq!(it.next_back(), Some(9));
+        it.collect::<Vec>()
+    };
+
+    assert_eq!(values, vec![0, 3, 6]);
+}
+
+#[test]
+fn test_step_by_zero() {
+    assert_vm_error!(
+        "[1, 2, 3].iter().step_by(0)",
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "Step must be non-zero");
+        }
+    );
+}
+
+#[test]
+fn test_generator_adapters() {
+    let values: Vec<(i64, i64)> = rune! {
+        fn numbers() {
+            for n in 0..10 {
+                yield n;
+            }
+        }
+
+        numbers()
+            .iter()
+            .take_while(|n| n < 8)
+            .skip_while(|n| n < 2)
+            .step_by(2)
+            .zip(numbers().iter().map_while(|n| if n < 3 { Some(n * 10) } else { None }))
+            .collect::<Vec>()
+    };
+
+    assert_eq!(values, vec![(2, 0), (4, 10), (6, 20)]);
+}
+
+#[test]
+fn test_windows_and_chunks() {
+    let _: () = rune! {
+        let windows = (0..4).iter().windows(2);
+        assert_eq!(windows.len(), 3);
+        assert_eq!(windows.collect::<Vec>(), [[0, 1], [1, 2], [2, 3]]);
+
+        let chunks = (0..5).iter().chunks(2);
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks.next_back(), Some([4]));
+        assert_eq!(chunks.collect::<Vec>(), [[0, 1], [2, 3]]);
+
+        assert_eq!((0..2).iter().windows(3).next(), None);
+    };
+}
+
+#[test]
+fn test_scan_inspect_cycle() {
+    let _: () = rune! {
+        let seen = [];
+
+        let sums = [1, 2, 3].iter()
+            .inspect(|n| seen.push(n))
+            .scan(0, |acc, n| Some((acc + n, acc + n)))
+            .collect::<Vec>();
+
+        assert_eq!(sums, [1, 3, 6]);
+        assert_eq!(seen, [1, 2, 3]);
+
+        assert_eq!([1, 2].iter().cycle().take(5).collect::<Vec>(), [1, 2, 1, 2, 1]);
+        assert_eq!([].iter().cycle().next(), None);
+    };
+}
+
+#[test]
+fn test_flatten_dedup() {
+    let _: () = rune! {
+        let it = [[1, 1], [], [2, 3, 3]].iter().flatten();
+        assert_eq!(it.next_back(), Some(3));
+        assert_eq!(it.dedup().collect::<Vec>(), [1, 2, 3]);
+    };
+}
+
+#[test]
+fn test_consumers() {
+    let _: () = rune! {
+        let a = [3, -7, 2, 7];
+
+        assert_eq!(a.iter().position(|n| n < 0), Some(1));
+        assert_eq!(a.iter().last(), Some(7));
+        assert_eq!(a.iter().min_by_key(|n| n.abs()), Some(2));
+        assert_eq!(a.iter().max_by_key(|n| n.abs()), Some(7));
+
+        let (small, large) = a.iter().partition(|n| n < 3);
+        assert_eq!(small, [-7, 2]);
+        assert_eq!(large, [3, 7]);
+
+        let (left, right) = a.iter().enumerate().unzip();
+        assert_eq!(left, [0, 1, 2, 3]);
+        assert_eq!(right, a);
+    };
+}
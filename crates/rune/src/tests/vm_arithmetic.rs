@@ -221,3 +221,49 @@ fn i8() {
     op_tests!(i8, 0b1100i8 >> 2 = 0b1100i8 >> 2);
     op_tests!(i64, !0b10100i8 = !0b10100i64);
 }
+
+#[test]
+fn overflowing() {
+    let out: (i64, bool) = rune!(i64::MAX.overflowing_add(1));
+    assert_eq!(out, (i64::MIN, true));
+
+    let out: (u64, bool) = rune!(0u64.overflowing_sub(1));
+    assert_eq!(out, (u64::MAX, true));
+
+    let out: u32 = rune!(0x0102u64.count_ones());
+    assert_eq!(out, 2);
+}
+
+#[test]
+fn sized() {
+    let out: u8 = rune!(u8::wrapping_add(250, 10));
+    assert_eq!(out, 4);
+
+    let out: (i32, bool) = rune!(i32::overflowing_sub(i32::MIN, 1));
+    assert_eq!(out, (i32::MAX, true));
+
+    let out: u32 = rune!(u32::rotate_left(0x80000000, 1));
+    assert_eq!(out, 1);
+
+    let out: Option<u8> = rune!(u8::try_from(300));
+    assert_eq!(out, None);
+
+    let out: Option<u32> = rune!(u32::try_from(u64::MAX));
+    assert_eq!(out, None);
+
+    let out: i32 = rune!(i32::parse("-42")?);
+    assert_eq!(out, -42);
+
+    let out: u32 = rune!(u32::from_be_bytes(b"\x01\x02\x03\x04")?);
+    assert_eq!(out, 0x01020304);
+
+    assert_vm_error!(
+        "u8::wrapping_add(256, 1)",
+        ValueToIntegerCoercionError { .. } => {}
+    );
+
+    assert_vm_error!(
+        "u8::try_from(1.0)",
+        ExpectedNumber { .. } => {}
+    );
+}
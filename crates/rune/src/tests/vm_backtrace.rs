@@ -0,0 +1,134 @@
+prelude!();
+
+use crate::runtime::{BacktraceFrameKind, VmError};
+use crate::termcolor;
+
+fn call(source: &str) -> Result<(Sources, VmError)> {
+    let context = Context::with_default_modules()?;
+    let mut sources = crate::tests::sources(source);
+    let mut diagnostics = Diagnostics::new();
+    let mut vm = crate::tests::vm(&context, &mut sources, &mut diagnostics, false)?;
+    let error = vm.call(["main"], ()).unwrap_err();
+    Ok((sources, error))
+}
+
+fn emit(error: &VmError, sources: &Sources) -> Result<String> {
+    let mut buffer = termcolor::Buffer::no_color();
+    error.emit(&mut buffer, sources)?;
+    Ok(String::from_utf8(buffer.into_inner())?)
+}
+
+#[test]
+fn script_frames() -> Result<()> {
+    let (sources, error) = call(
+        r#"
+        fn inner(value) {
+            value + 1
+        }
+
+        fn outer() {
+            inner(#{})
+        }
+
+        pub fn main() {
+            outer()
+        }
+        "#,
+    )?;
+
+    let backtrace = error.backtrace(&sources)?;
+
+    let frames = backtrace
+        .iter()
+        .map(|frame| {
+            let location = frame.location.as_ref().unwrap();
+            (
+                frame.kind,
+                frame.function.as_deref().unwrap(),
+                location.line,
+                location.column,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        frames,
+        [
+            (BacktraceFrameKind::Script, "inner(value)", 3, 13),
+            (BacktraceFrameKind::Script, "outer()", 7, 13),
+            (BacktraceFrameKind::Script, "main()", 11, 13),
+        ]
+    );
+
+    let output = emit(&error, &sources)?;
+    assert!(output.contains("   0: inner(value)\n             at main:3:13\n"));
+    assert!(output.contains("   2: main()\n             at main:11:13\n"));
+    Ok(())
+}
+
+#[test]
+fn native_frames() -> Result<()> {
+    let (sources, error) = call(
+        r#"
+        fn inner() {
+            let values = [];
+            values.remove(10);
+        }
+
+        pub fn main() {
+            inner();
+            panic("boom");
+        }
+        "#,
+    )?;
+
+    let backtrace = error.backtrace(&sources)?;
+    assert_eq!(backtrace.len(), 3);
+
+    let native = &backtrace[0];
+    assert_eq!(native.kind, BacktraceFrameKind::Native);
+    assert_eq!(native.function.as_deref(), Some("::std::vec::Vec::remove"));
+    assert!(native.location.is_none());
+
+    assert_eq!(backtrace[1].function.as_deref(), Some("inner()"));
+    assert_eq!(backtrace[2].function.as_deref(), Some("main()"));
+
+    let output = emit(&error, &sources)?;
+    assert!(output.contains("   0: ::std::vec::Vec::remove (native)\n"));
+
+    let (sources, error) = call(
+        r#"
+        pub fn main() {
+            panic("boom");
+        }
+        "#,
+    )?;
+
+    let backtrace = error.backtrace(&sources)?;
+    assert_eq!(backtrace.len(), 2);
+    assert_eq!(backtrace[0].kind, BacktraceFrameKind::Native);
+    assert_eq!(backtrace[0].function.as_deref(), Some("::std::panic"));
+    Ok(())
+}
+
+#[test]
+fn serialize_frames() -> Result<()> {
+    let (sources, error) = call(
+        r#"
+        pub fn main() {
+            panic("boom");
+        }
+        "#,
+    )?;
+
+    let backtrace = error.backtrace(&sources)?;
+    let value = serde_json::to_value(&backtrace)?;
+
+    assert_eq!(value[0]["kind"], "native");
+    assert_eq!(value[0]["function"], "::std::panic");
+    assert_eq!(value[1]["kind"], "script");
+    assert_eq!(value[1]["location"]["source"], "main");
+    assert_eq!(value[1]["location"]["line"], 3);
+    assert_eq!(value[1]["location"]["column"], 13);
+    Ok(())
+}
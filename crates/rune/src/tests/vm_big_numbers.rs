@@ -0,0 +1,155 @@
+prelude!();
+
+use num::BigInt;
+
+use crate::runtime::Decimal;
+
+use ErrorKind::*;
+use VmErrorKind::*;
+
+#[test]
+fn big_int_literals() {
+    let out: BigInt = eval("18446744073709551616n");
+    assert_eq!(out, BigInt::from(u64::MAX) + 1);
+
+    let out: BigInt = eval("-18446744073709551616n");
+    assert_eq!(out, BigInt::from(-1) - u64::MAX);
+
+    let out: BigInt = eval("0xffn");
+    assert_eq!(out, BigInt::from(255));
+
+    let out: BigInt = eval("1_000n");
+    assert_eq!(out, BigInt::from(1000));
+}
+
+#[test]
+fn big_int_arithmetic() {
+    let out: BigInt = eval("9223372036854775807n * 2 + 2");
+    assert_eq!(out, BigInt::from(u64::MAX) + 1);
+
+    let out: BigInt = eval("let n = 10n; n -= 20; -n");
+    assert_eq!(out, BigInt::from(10));
+
+    let out: bool = eval("2n.pow(64) == 18446744073709551616n");
+    assert!(out);
+
+    let out: bool = eval("10n > 5 && 5n < 10n && 10n == 10");
+    assert!(out);
+
+    assert_vm_error!("1n / 0", DivideByZero => {});
+    assert_vm_error!("let n = 1n; n %= 0n;", DivideByZero => {});
+}
+
+#[test]
+fn decimal_literals() {
+    let out: Decimal = eval("1.10d");
+    assert_eq!(out.to_string(), "1.10");
+
+    let out: Decimal = eval("-0.001d");
+    assert_eq!(out.to_string(), "-0.001");
+
+    let out: Decimal = eval("42d");
+    assert_eq!(out.to_string(), "42");
+
+    let out: Decimal = eval("1.5e3d");
+    assert_eq!(out.to_string(), "1500");
+}
+
+#[test]
+fn decimal_arithmetic() {
+    let out: bool = eval("0.1d + 0.2d == 0.3d");
+    assert!(out);
+
+    let out: String = eval("(1.10d * 3).to_string()");
+    assert_eq!(out, "3.30");
+
+    let out: String = eval("(10d / 4).to_string()");
+    assert_eq!(out, "2.5");
+
+    let out: String = eval("(1d / 3).round(4).to_string()");
+    assert_eq!(out, "0.3333");
+
+    let out: String = eval("let n = 1.5d; n -= 2n; n.to_string()");
+    assert_eq!(out, "-0.5");
+
+    assert_vm_error!("1d / 0d", DivideByZero => {});
+    assert_vm_error!("0.1d.pow(70000)", Overflow => {});
+}
+
+#[test]
+fn conversions() {
+    let out: (Option<i64>, Option<i64>, f64) =
+        eval("(10n.to_i64(), 18446744073709551616n.to_i64(), 2.5d.to_f64())");
+    assert_eq!(out, (Some(10), None, 2.5));
+
+    let out: String = eval("std::num::Decimal::from_f64(0.1)?.to_string()");
+    assert_eq!(out, "0.1");
+
+    let out: BigInt = eval(r#"std::num::BigInt::parse("-123456789012345678901234567890")?"#);
+    assert_eq!(
+        out,
+        "-123456789012345678901234567890".parse::<BigInt>().unwrap()
+    );
+}
+
+#[test]
+fn constants() {
+    let out: BigInt = eval("const N = 18446744073709551616n; N");
+    assert_eq!(out, BigInt::from(u64::MAX) + 1);
+
+    let out: Decimal = eval("const N = 1.10d; N");
+    assert_eq!(out.to_string(), "1.10");
+
+    let out: bool = eval("std::num::BigInt::ONE + std::num::BigInt::ZERO == 1");
+    assert!(out);
+}
+
+#[test]
+fn decimal_limits() {
+    assert!("1e65536".parse::<Decimal>().is_ok());
+    assert!("1e-65536".parse::<Decimal>().is_ok());
+    assert!("1e65537".parse::<Decimal>().is_err());
+    assert!("1e4000000000".parse::<Decimal>().is_err());
+    assert!("1e-9223372036854775808".parse::<Decimal>().is_err());
+
+    let d: Decimal = "0.01".parse().unwrap();
+    assert!(d.checked_pow(Decimal::MAX_SCALE / 2).is_some());
+    assert!(d.checked_pow(Decimal::MAX_SCALE / 2 + 1).is_none());
+    assert!(d.checked_pow(u32::MAX).is_none());
+
+    let d = Decimal::new(5, Decimal::MAX_SCALE);
+    let product = &d * &"0.1".parse::<Decimal>().unwrap();
+    assert_eq!(product.scale(), Decimal::MAX_SCALE);
+    assert_eq!(product, Decimal::new(1, Decimal::MAX_SCALE));
+
+    assert_eq!("1e1_0".parse::<Decimal>(), "10000000000".parse());
+    assert!("1e".parse::<Decimal>().is_err());
+}
+
+#[test]
+fn bad_literals() {
+    assert_errors! {
+        "1.5n",
+        span!(0, 4), BadNumberLiteral
+    };
+
+    assert_errors! {
+        "1u7",
+        span!(1, 3), UnsupportedSuffix
+    };
+}
+
+#[test]
+fn serde() -> Result<()> {
+    let value: Value = eval("[18446744073709551616n, 42n, 1.10d]");
+    let json = serde_json::to_string(&value)?;
+    assert_eq!(json, r#"["18446744073709551616",42,"1.10"]"#);
+
+    let value: Value = serde_json::from_str("18446744073709551615")?;
+    let value = crate::from_value::<BigInt>(value)?;
+    assert_eq!(value, BigInt::from(u64::MAX));
+
+    let value: Decimal = serde_json::from_str(r#""-1.25""#)?;
+    assert_eq!(value.to_string(), "-1.25");
+    Ok(())
+}
@@ -0,0 +1,208 @@
+prelude!();
+
+use core::future::{pending, Future};
+use core::pin::pin;
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::task::Context as TaskContext;
+
+use futures_util::task::{waker, ArcWake};
+
+use crate::runtime::CancellationToken;
+use crate::tests::vm_for;
+
+#[derive(Default)]
+struct Woken(AtomicBool);
+
+impl ArcWake for Woken {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        arc_self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn cancel_before_start() -> Result<()> {
+    let context = Context::with_default_modules()?;
+    let mut vm = vm_for(&context, "pub fn main() { 42 }")?;
+    let mut execution = vm.execute(["main"], ())?;
+    execution.cancellation_token().cancel();
+
+    let error = execution.complete().into_result().unwrap_err();
+    assert!(error.is_cancelled());
+    Ok(())
+}
+
+#[test]
+fn cancel_running_loop() -> Result<()> {
+    let context = Context::with_default_modules()?;
+    let mut vm = vm_for(&context, "pub fn main() { loop {} }")?;
+    let mut execution = vm.execute(["main"], ())?;
+
+    let token = execution.cancellation_token();
+    let canceller = std::thread::spawn(move || token.cancel());
+
+    let error = execution.complete().into_result().unwrap_err();
+    assert!(error.is_cancelled());
+    canceller.join().unwrap();
+    Ok(())
+}
+
+#[test]
+fn cancel_observed_by_script() -> Result<()> {
+    let mut m = Module::new();
+    let token = CancellationToken::new();
+
+    m.function("request", {
+        let token = token.clone();
+        move || token.request_cancel()
+    })
+    .build()?;
+
+    let mut context = Context::with_default_modules()?;
+    context.install(m)?;
+
+    let mut vm = vm_for(
+        &context,
+        r#"
+        pub fn main() {
+            let before = std::task::is_cancelled();
+            request();
+            (before, std::task::is_cancelled())
+        }
+        "#,
+    )?;
+
+    vm.set_cancellation(Some(token));
+
+    let output: (bool, bool) = from_value(vm.call(["main"], ())?)?;
+    assert_eq!(output, (false, true));
+    Ok(())
+}
+
+#[test]
+fn cancel_nested_function() -> Result<()> {
+    let mut m = Module::new();
+    let token = CancellationToken::new();
+
+    m.function("cancel", {
+        let token = token.clone();
+        move || token.cancel()
+    })
+    .build()?;
+
+    let mut context = Context::with_default_modules()?;
+    context.install(m)?;
+
+    let mut vm = vm_for(
+        &context,
+        r#"
+        pub fn main() {
+            let values = [1, 2, 3];
+            values.iter().map(|v| { cancel(); v }).collect::<Vec>()
+        }
+        "#,
+    )?;
+
+    vm.set_cancellation(Some(token));
+
+    let error = vm.call(["main"], ()).unwrap_err();
+    assert!(error.is_cancelled());
+    Ok(())
+}
+
+#[test]
+fn cancel_at_await_point() -> Result<()> {
+    let mut m = Module::new();
+    m.function("pending", || pending::<()>()).build()?;
+
+    let mut context = Context::with_default_modules()?;
+    context.install(m)?;
+
+    let mut vm = vm_for(
+        &context,
+        r#"
+        pub async fn main() {
+            pending().await;
+        }
+        "#,
+    )?;
+
+    let mut execution = vm.execute(["main"], ())?;
+    let token = execution.cancellation_token();
+
+    let error = block_on(async move {
+        let complete = core::pin::pin!(execution.async_complete());
+        let cancel = async move { token.cancel() };
+        let (result, ()) = futures_util::future::join(complete, cancel).await;
+        result
+    })
+    .into_result()
+    .unwrap_err();
+
+    assert!(error.is_cancelled());
+    Ok(())
+}
+
+#[test]
+fn cancel_wakes_every_waiter() {
+    let token = CancellationToken::new();
+
+    let first = Arc::new(Woken::default());
+    let second = Arc::new(Woken::default());
+    let first_waker = waker(first.clone());
+    let second_waker = waker(second.clone());
+
+    let mut a = pin!(token.cancelled());
+    let mut b = pin!(token.cancelled());
+
+    assert!(a
+        .as_mut()
+        .poll(&mut TaskContext::from_waker(&first_waker))
+        .is_pending());
+    assert!(b
+        .as_mut()
+        .poll(&mut TaskContext::from_waker(&second_waker))
+        .is_pending());
+
+    token.cancel();
+
+    assert!(first.0.load(Ordering::SeqCst));
+    assert!(second.0.load(Ordering::SeqCst));
+
+    assert!(a
+        .as_mut()
+        .poll(&mut TaskContext::from_waker(&first_waker))
+        .is_ready());
+    assert!(b
+        .as_mut()
+        .poll(&mut TaskContext::from_waker(&second_waker))
+        .is_ready());
+}
+
+#[test]
+fn dropped_waiter_is_not_woken() {
+    let token = CancellationToken::new();
+
+    let dropped = Arc::new(Woken::default());
+    let kept = Arc::new(Woken::default());
+
+    {
+        let mut cancelled = pin!(token.cancelled());
+        let waker = waker(dropped.clone());
+        assert!(cancelled
+            .as_mut()
+            .poll(&mut TaskContext::from_waker(&waker))
+            .is_pending());
+    }
+
+    let mut cancelled = pin!(token.cancelled());
+    let waker = waker(kept.clone());
+    assert!(cancelled
+        .as_mut()
+        .poll(&mut TaskContext::from_waker(&waker))
+        .is_pending());
+
+    token.cancel();
+
+    assert!(!dropped.0.load(Ordering::SeqCst));
+    assert!(kept.0.load(Ordering::SeqCst));
+}
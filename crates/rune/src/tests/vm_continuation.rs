@@ -0,0 +1,227 @@
+prelude!();
+
+use crate::runtime::{GeneratorState, VmContinuation, VmExecution};
+use crate::tests::vm_for;
+
+fn round_trip(continuation: VmContinuation) -> Result<VmContinuation> {
+    let bytes = serde_json::to_vec(&continuation)?;
+    Ok(serde_json::from_slice(&bytes)?)
+}
+
+#[test]
+fn resume_after_restore() -> Result<()> {
+    let context = Context::with_default_modules()?;
+
+    let mut vm = vm_for(
+        &context,
+        r#"
+        struct Point { x, y }
+
+        pub fn main() {
+            let shared = [1];
+            let point = Point { x: shared, y: shared };
+            let add = |v| v + 10;
+            let n = yield "ready";
+            point.x.push(add(n));
+            (point.y, #{ a: Some(n) }, Ok("done"))
+        }
+        "#,
+    )?;
+
+    let (runtime, unit) = (vm.context().clone(), vm.unit().clone());
+    let mut execution = vm.execute(["main"], ())?;
+
+    let GeneratorState::Yielded(value) = execution.resume().into_result()? else {
+        panic!("expected yield");
+    };
+
+    assert_eq!(from_value::<String>(value)?, "ready");
+
+    let continuation = round_trip(execution.snapshot()?)?;
+    drop(execution);
+
+    let mut execution = VmExecution::restore(runtime, unit, continuation)?;
+
+    let GeneratorState::Complete(value) = execution.resume_with(Value::from(2i64)).into_result()?
+    else {
+        panic!("expected completion");
+    };
+
+    let (shared, object, result): (Vec<i64>, Object, Result<String, ()>) = from_value(value)?;
+    // Aliasing between `point.x` and `point.y` is preserved.
+    assert_eq!(shared, [1, 12]);
+    assert_eq!(
+        from_value::<Option<i64>>(object.get("a").unwrap().clone())?,
+        Some(2)
+    );
+    assert_eq!(result, Ok(String::from("done")));
+    Ok(())
+}
+
+#[test]
+fn generator_values() -> Result<()> {
+    let context = Context::with_default_modules()?;
+
+    let mut vm = vm_for(
+        &context,
+        r#"
+        fn counter() {
+            yield 1;
+            yield 2;
+        }
+
+        pub fn main() {
+            let g = counter();
+            let first = g.next();
+            yield ();
+            [first, g.next(), g.next()]
+        }
+        "#,
+    )?;
+
+    let (runtime, unit) = (vm.context().clone(), vm.unit().clone());
+    let mut execution = vm.execute(["main"], ())?;
+    let _ = execution.resume().into_result()?;
+
+    let continuation = round_trip(execution.snapshot()?)?;
+    let mut execution = VmExecution::restore(runtime, unit, continuation)?;
+    let value = execution.complete().into_result()?;
+
+    let value: Vec<Option<i64>> = from_value(value)?;
+    assert_eq!(value, [Some(1), Some(2), None]);
+    Ok(())
+}
+
+#[test]
+fn ranges_and_iterators() -> Result<()> {
+    let context = Context::with_default_modules()?;
+
+    let mut vm = vm_for(
+        &context,
+        r#"
+        pub fn main() {
+            let range = 1..3;
+            let from = 1..;
+            let full = ..;
+            let inclusive = 1..=3;
+            let to = ..3;
+            let to_inclusive = ..=3;
+
+            let numbers = range.iter();
+            numbers.next();
+
+            let counter = (10..).iter();
+            counter.next();
+
+            let chars = ('a'..='c').iter();
+            chars.next();
+            chars.next();
+            chars.next();
+
+            let values = [1, 2, 3, 4].iter();
+            values.next();
+            values.next_back();
+
+            yield ();
+
+            let contains = [
+                range.contains(2),
+                from.contains(100),
+                full.contains(-5),
+                inclusive.contains(3),
+                to.contains(3),
+                to_inclusive.contains(3),
+            ];
+
+            (
+                contains,
+                numbers.collect::<Vec>(),
+                counter.next(),
+                (chars.next(), values.collect::<Vec>()),
+            )
+        }
+        "#,
+    )?;
+
+    let (runtime, unit) = (vm.context().clone(), vm.unit().clone());
+    let mut execution = vm.execute(["main"], ())?;
+    let _ = execution.resume().into_result()?;
+
+    let continuation = round_trip(execution.snapshot()?)?;
+    let mut execution = VmExecution::restore(runtime, unit, continuation)?;
+    let value = execution.complete().into_result()?;
+
+    type Output = (Vec<bool>, Vec<i64>, Option<i64>, (Option<char>, Vec<i64>));
+    let (contains, numbers, counter, (chars, values)) = from_value::<Output>(value)?;
+    assert_eq!(contains, [true, true, true, true, false, true]);
+    assert_eq!(numbers, [2]);
+    assert_eq!(counter, Some(11));
+    assert_eq!(chars, None);
+    assert_eq!(values, [2, 3]);
+    Ok(())
+}
+
+#[test]
+fn unsupported_values() -> Result<()> {
+    let context = Context::with_default_modules()?;
+
+    let mut vm = vm_for(
+        &context,
+        r#"
+        pub fn main() {
+            let entries = #{ a: 1 }.iter();
+            yield ();
+            entries
+        }
+        "#,
+    )?;
+    let mut execution = vm.execute(["main"], ())?;
+    let _ = execution.resume().into_result()?;
+
+    let error = execution.snapshot().unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Values of type `::std::object::Iter` cannot be snapshotted"
+    );
+    Ok(())
+}
+
+#[test]
+fn stable_fingerprint() -> Result<()> {
+    let source = r#"
+        struct Point { x, y }
+
+        pub fn main() {
+            let point = Point { x: 1, y: 2.5 };
+            let x = `${point.x}`;
+            yield x;
+            [b"bytes", point.y]
+        }
+    "#;
+
+    let context = Context::with_default_modules()?;
+    let a = vm_for(&context, source)?;
+    let b = vm_for(&context, source)?;
+    assert_eq!(a.unit().fingerprint()?, b.unit().fingerprint()?);
+
+    let c = vm_for(&context, &source.replace("b\"bytes\"", "b\"other\""))?;
+    assert_ne!(a.unit().fingerprint()?, c.unit().fingerprint()?);
+    Ok(())
+}
+
+#[test]
+fn unit_mismatch() -> Result<()> {
+    let context = Context::with_default_modules()?;
+    let mut vm = vm_for(&context, "pub fn main() { yield 1; }")?;
+    let other = vm_for(&context, "pub fn main() { yield 2; }")?;
+
+    let (runtime, unit) = (vm.context().clone(), vm.unit().clone());
+    let mut execution = vm.execute(["main"], ())?;
+    let _ = execution.resume().into_result()?;
+
+    let continuation = execution.snapshot()?;
+    assert_eq!(continuation.unit_fingerprint(), unit.fingerprint()?);
+
+    assert!(VmExecution::restore(runtime, other.unit().clone(), continuation).is_err());
+    Ok(())
+}
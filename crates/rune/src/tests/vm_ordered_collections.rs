@@ -0,0 +1,129 @@
+prelude!();
+
+use VmErrorKind::*;
+
+#[test]
+fn btree_map_ordering() {
+    let out: Vec<(String, i64)> = rune! {
+        use std::collections::BTreeMap;
+
+        let map = BTreeMap::new();
+
+        for (i, key) in ["delta", "alpha", "charlie", "bravo"].iter().enumerate() {
+            map.insert(key, i);
+        }
+
+        map.iter().collect::<Vec>()
+    };
+
+    assert_eq!(
+        out,
+        [
+            (String::from("alpha"), 1),
+            (String::from("bravo"), 3),
+            (String::from("charlie"), 2),
+            (String::from("delta"), 0),
+        ]
+    );
+}
+
+#[test]
+fn btree_map_tuple_keys() {
+    let out: Vec<(i64, i64)> = rune! {
+        use std::collections::BTreeMap;
+
+        let map = BTreeMap::from_iter([((2, 1), "c"), ((1, 2), "b"), ((1, 1), "a")]);
+        map.range((1, 2)..).map(|(k, v)| k).collect::<Vec>()
+    };
+
+    assert_eq!(out, [(1, 2), (2, 1)]);
+}
+
+#[test]
+fn btree_map_range_bounds() {
+    let out: (Vec<i64>, Vec<i64>, Vec<i64>) = rune! {
+        use std::collections::BTreeMap;
+
+        let map = BTreeMap::from_iter((0..10).iter().map(|n| (n, n * n)));
+
+        (
+            map.range(2..5).map(|(k, v)| v).collect::<Vec>(),
+            map.range(..=2).rev().map(|(k, v)| k).collect::<Vec>(),
+            map.range(8..).map(|(k, v)| k).collect::<Vec>(),
+        )
+    };
+
+    assert_eq!(out, (vec![4, 9, 16], vec![2, 1, 0], vec![8, 9]));
+
+    assert_vm_error!(
+        r#"
+        use std::collections::BTreeMap;
+        let map = BTreeMap::new();
+        map.range(5..2)
+        "#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "Range start is greater than range end");
+        }
+    );
+}
+
+#[test]
+fn btree_map_incomparable_keys() {
+    assert_vm_error!(
+        r#"
+        use std::collections::BTreeMap;
+        let map = BTreeMap::new();
+        map.insert(1, 1);
+        map.insert("a", 2);
+        "#,
+        Expected { .. } => {}
+    );
+}
+
+#[test]
+fn btree_set_operations() {
+    let out: (Vec<i64>, Option<i64>, Option<i64>, usize) = rune! {
+        use std::collections::BTreeSet;
+
+        let set = BTreeSet::from_iter([5, 3, 9, 3, 1]);
+        let first = set.pop_first();
+        let last = set.pop_last();
+        (set.iter().collect::<Vec>(), first, last, set.len())
+    };
+
+    assert_eq!(out, (vec![3, 5], Some(1), Some(9), 2));
+}
+
+#[test]
+fn binary_heap_order() {
+    let out: Vec<i64> = rune! {
+        use std::collections::BinaryHeap;
+
+        let heap = BinaryHeap::from_iter([4, 8, 1, 9, 2, 7, 3]);
+        let out = [];
+
+        while let Some(value) = heap.pop() {
+            out.push(value);
+        }
+
+        out
+    };
+
+    assert_eq!(out, [9, 8, 7, 4, 3, 2, 1]);
+}
+
+#[test]
+fn binary_heap_priorities() {
+    let out: Vec<String> = rune! {
+        use std::collections::BinaryHeap;
+
+        let heap = BinaryHeap::new();
+        heap.push((1, "low"));
+        heap.push((10, "high"));
+        heap.push((5, "medium"));
+
+        heap.into_sorted_vec().iter().rev().map(|(_, name)| name).collect::<Vec>()
+    };
+
+    assert_eq!(out, ["high", "medium", "low"]);
+}
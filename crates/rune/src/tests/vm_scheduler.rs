@@ -0,0 +1,224 @@
+prelude!();
+
+use crate::runtime::Scheduler;
+use crate::tests::logged;
+
+#[test]
+fn yielding_tasks_interleave() -> Result<()> {
+    let (log, vm) = logged(
+        r#"
+        pub fn task(id) {
+            for _ in 0..3 {
+                record(id);
+                yield;
+            }
+
+            id
+        }
+        "#,
+    )?;
+
+    let mut scheduler = Scheduler::new();
+    let mut handles = Vec::new();
+
+    for id in 0..3i64 {
+        let task = Vm::new(vm.context().clone(), vm.unit().clone());
+        handles.push(scheduler.spawn(task.owned_execute(["task"], (id,))?)?);
+    }
+
+    scheduler.run_until_stalled();
+    assert!(scheduler.is_empty());
+
+    assert_eq!(*log.lock().unwrap(), [0, 1, 2, 0, 1, 2, 0, 1, 2]);
+
+    for (id, handle) in handles.into_iter().enumerate() {
+        assert!(handle.is_finished());
+        let value = handle.try_join().unwrap().into_result()?;
+        assert_eq!(crate::from_value::<usize>(value)?, id);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn busy_tasks_share_time() -> Result<()> {
+    let (log, vm) = logged(
+        r#"
+        pub fn task(id) {
+            loop {
+                record(id);
+            }
+        }
+        "#,
+    )?;
+
+    let mut scheduler = Scheduler::with_slice(64);
+    let mut handles = Vec::new();
+
+    for id in 0..2i64 {
+        let task = Vm::new(vm.context().clone(), vm.unit().clone());
+        handles.push(scheduler.spawn(task.owned_execute(["task"], (id,))?)?);
+    }
+
+    for _ in 0..10 {
+        assert_eq!(scheduler.tick(), 2);
+    }
+
+    let log = log.lock().unwrap();
+    let first = log.iter().filter(|id| **id == 0).count();
+    let second = log.iter().filter(|id| **id == 1).count();
+
+    assert!(first > 0);
+    assert_eq!(first, second);
+
+    for handle in handles {
+        handle.abort();
+    }
+
+    scheduler.run_until_stalled();
+    assert!(scheduler.is_empty());
+    Ok(())
+}
+
+#[test]
+fn spinning_spawned_task() -> Result<()> {
+    let (log, vm) = logged(
+        r#"
+        pub async fn spin() {
+            std::task::spawn(async {
+                loop {
+                    record(0);
+                }
+            });
+        }
+
+        pub fn count() {
+            for _ in 0..3 {
+                record(1);
+                yield;
+            }
+        }
+        "#,
+    )?;
+
+    let mut scheduler = Scheduler::with_slice(64);
+
+    let spin = Vm::new(vm.context().clone(), vm.unit().clone());
+    scheduler.spawn(spin.owned_execute(["spin"], ())?)?;
+    let count = scheduler.spawn(vm.owned_execute(["count"], ())?)?;
+
+    for _ in 0..10 {
+        scheduler.tick();
+    }
+
+    assert!(count.is_finished());
+    assert_eq!(scheduler.len(), 1);
+
+    let log = log.lock().unwrap();
+    assert!(log.contains(&0));
+    assert_eq!(log.iter().filter(|id| **id == 1).count(), 3);
+    Ok(())
+}
+
+#[test]
+fn spinning_awaited_future() -> Result<()> {
+    let (log, vm) = logged(
+        r#"
+        pub async fn task() {
+            async {
+                loop {
+                    record(0);
+                }
+            }.await
+        }
+        "#,
+    )?;
+
+    let mut scheduler = Scheduler::with_slice(64);
+    let handle = scheduler.spawn(vm.owned_execute(["task"], ())?)?;
+
+    for _ in 0..10 {
+        assert_eq!(scheduler.tick(), 1);
+    }
+
+    assert!(!handle.is_finished());
+    assert!(!log.lock().unwrap().is_empty());
+    Ok(())
+}
+
+#[test]
+fn abort_task() -> Result<()> {
+    let (_, vm) = logged(
+        r#"
+        pub fn task() {
+            loop {}
+        }
+        "#,
+    )?;
+
+    let mut scheduler = Scheduler::with_slice(16);
+    let handle = scheduler.spawn(vm.owned_execute(["task"], ())?)?;
+
+    assert_eq!(scheduler.tick(), 1);
+    assert!(!handle.is_finished());
+
+    handle.abort();
+    scheduler.run_until_stalled();
+
+    let error = handle.try_join().unwrap().into_result().unwrap_err();
+    assert!(error.is_cancelled());
+    Ok(())
+}
+
+#[test]
+fn failing_task() -> Result<()> {
+    let (_, vm) = logged(
+        r#"
+        pub fn task() {
+            panic("boom")
+        }
+        "#,
+    )?;
+
+    let mut scheduler = Scheduler::new();
+    let handle = scheduler.spawn(vm.owned_execute(["task"], ())?)?;
+
+    scheduler.run_until_stalled();
+
+    let error = handle.try_join().unwrap().into_result().unwrap_err();
+    assert_eq!(error.to_string(), "Panicked: boom");
+    assert!(handle.try_join().is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn awaiting_tasks() -> Result<()> {
+    let (log, vm) = logged(
+        r#"
+        pub async fn task(id) {
+            let value = async { id * 10 }.await;
+            record(value);
+            value
+        }
+        "#,
+    )?;
+
+    let mut scheduler = Scheduler::new();
+    let mut handles = Vec::new();
+
+    for id in 1..=3i64 {
+        let task = Vm::new(vm.context().clone(), vm.unit().clone());
+        handles.push(scheduler.spawn(task.owned_execute(["task"], (id,))?)?);
+    }
+
+    scheduler.run().await;
+
+    assert_eq!(*log.lock().unwrap(), [10, 20, 30]);
+
+    for (id, handle) in (1..=3i64).zip(handles) {
+        let value = handle.await.into_result()?;
+        assert_eq!(crate::from_value::<i64>(value)?, id * 10);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,235 @@
+//! Tests for serializing and deserializing values with serde.
+
+prelude!();
+
+use runtime::TypeSeed;
+use serde::de::DeserializeSeed;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Any, Serialize, Deserialize)]
+#[rune(item = ::native, serde)]
+struct Point {
+    #[rune(get)]
+    x: i64,
+    #[rune(get)]
+    y: i64,
+}
+
+fn module() -> Result<Module, ContextError> {
+    let mut m = Module::with_crate("native")?;
+    m.ty::<Point>()?;
+
+    m.function("point", |x: i64, y: i64| Point { x, y })
+        .build()?;
+
+    m.function("to_json", |value: Value| {
+        serde_json::to_string(&value).map_err(|error| error.to_string())
+    })
+    .build()?;
+
+    m.function("from_json", |string: &str, ty: TypeSeed| {
+        let mut deserializer = serde_json::Deserializer::from_str(string);
+
+        ty.deserialize(&mut deserializer)
+            .map_err(|error| error.to_string())
+    })
+    .build()?;
+
+    Ok(m)
+}
+
+#[test]
+fn serialize_structs() {
+    let m = module().unwrap();
+
+    let (a, b, c, d): (String, String, String, String) = rune_n! {
+        mod m,
+        (),
+        struct Named { b, a }
+        struct Tuple(a, b);
+        struct Newtype(a);
+        struct Empty;
+
+        pub fn main() {
+            (
+                native::to_json(Named { b: 1, a: "two" })?,
+                native::to_json(Tuple(1, [2]))?,
+                native::to_json(Newtype(#{ "x": 1 }))?,
+                native::to_json(Empty)?,
+            )
+        }
+    };
+
+    assert_eq!(a, r#"{"b":1,"a":"two"}"#);
+    assert_eq!(b, "[1,[2]]");
+    assert_eq!(c, r#"{"x":1}"#);
+    assert_eq!(d, "null");
+}
+
+#[test]
+fn serialize_variants() {
+    let m = module().unwrap();
+
+    let (a, b, c, d): (String, String, String, String) = rune_n! {
+        mod m,
+        (),
+        enum Shape {
+            Circle { radius },
+            Rect(w, h),
+            Line(len),
+            Empty,
+        }
+
+        pub fn main() {
+            (
+                native::to_json(Shape::Circle { radius: 2 })?,
+                native::to_json(Shape::Rect(1, 2))?,
+                native::to_json(Shape::Line(3))?,
+                native::to_json(Shape::Empty)?,
+            )
+        }
+    };
+
+    assert_eq!(a, r#"{"Circle":{"radius":2}}"#);
+    assert_eq!(b, r#"{"Rect":[1,2]}"#);
+    assert_eq!(c, r#"{"Line":3}"#);
+    assert_eq!(d, r#""Empty""#);
+}
+
+#[test]
+fn serialize_native() {
+    let m = module().unwrap();
+
+    let (a, b): (String, String) = rune_n! {
+        mod m,
+        (),
+        pub fn main() {
+            (
+                native::to_json(native::point(1, 2))?,
+                native::to_json([native::point(3, 4)])?,
+            )
+        }
+    };
+
+    assert_eq!(a, r#"{"x":1,"y":2}"#);
+    assert_eq!(b, r#"[{"x":3,"y":4}]"#);
+}
+
+#[test]
+fn deserialize_structs() {
+    let m = module().unwrap();
+
+    let value: bool = rune_n! {
+        mod m,
+        (),
+        struct Named { a, b }
+        struct Tuple(a, b);
+        struct Newtype(a);
+        struct Empty;
+
+        pub fn main() {
+            let named = native::from_json("{\"b\":[1],\"a\":\"x\"}", Named)?;
+            assert_eq!(named.a, "x");
+            assert_eq!(named.b, [1]);
+
+            let tuple = native::from_json("[1,2]", Tuple)?;
+            assert_eq!(tuple.0, 1);
+            assert_eq!(tuple.1, 2);
+
+            let newtype = native::from_json("42", Newtype)?;
+            assert_eq!(newtype.0, 42);
+
+            let empty = native::from_json("null", Empty)?;
+            assert!(empty is Empty);
+            true
+        }
+    };
+
+    assert!(value);
+}
+
+#[test]
+fn deserialize_variants() {
+    let m = module().unwrap();
+
+    let value: bool = rune_n! {
+        mod m,
+        (),
+        enum Shape {
+            Circle { radius },
+            Rect(w, h),
+            Line(len),
+            Empty,
+        }
+
+        pub fn main() {
+            let circle = native::from_json("{\"Circle\":{\"radius\":2}}", Shape)?;
+            let rect = native::from_json("{\"Rect\":[1,2]}", Shape)?;
+            let line = native::from_json("{\"Line\":3}", Shape)?;
+            let empty = native::from_json("\"Empty\"", Shape)?;
+
+            match (circle, rect, line, empty) {
+                (Shape::Circle { radius: 2 }, Shape::Rect(1, 2), Shape::Line(3), Shape::Empty) => true,
+                _ => false,
+            }
+        }
+    };
+
+    assert!(value);
+}
+
+#[test]
+fn deserialize_native() {
+    let m = module().unwrap();
+
+    let (x, y): (i64, i64) = rune_n! {
+        mod m,
+        (),
+        pub fn main() {
+            let point = native::from_json("{\"x\":1,\"y\":2}", native::Point)?;
+            let json = native::to_json(point)?;
+            let point = native::from_json(json, native::Point)?;
+            (point.x, point.y)
+        }
+    };
+
+    assert_eq!((x, y), (1, 2));
+}
+
+#[test]
+fn deserialize_errors() {
+    let m = module().unwrap();
+
+    let (a, b, c, d): (String, String, String, String) = rune_n! {
+        mod m,
+        (),
+        struct Named { a, b }
+        struct Tuple(a, b);
+        enum Shape { Circle { radius } }
+
+        fn err(result) {
+            match result {
+                Err(error) => error,
+                Ok(value) => panic!("unexpected value {value:?}"),
+            }
+        }
+
+        pub fn main() {
+            (
+                err(native::from_json("{\"a\":1}", Named)),
+                err(native::from_json("{\"a\":1,\"b\":2,\"c\":3}", Named)),
+                err(native::from_json("[1,2,3]", Tuple)),
+                err(native::from_json("{\"Square\":{}}", Shape)),
+            )
+        }
+    };
+
+    assert!(a.starts_with("missing field `b` in struct Named"), "{a}");
+    assert!(b.starts_with("unknown field `c` in struct Named"), "{b}");
+    assert!(b.contains("line 1"), "{b}");
+    assert!(
+        c.starts_with("invalid length 3, expected tuple struct Tuple with 2 fields"),
+        "{c}"
+    );
+    assert!(d.starts_with("unknown variant `Square`"), "{d}");
+}
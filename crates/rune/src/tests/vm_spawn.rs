@@ -0,0 +1,191 @@
+prelude!();
+
+use crate::runtime::{Scheduler, Spawner, VmErrorKind};
+use crate::tests::logged;
+
+/// Run `main` to completion, running spawned tasks on a local task set.
+fn run_main(vm: &mut Vm) -> Result<Value> {
+    vm.set_spawner(Some(Spawner::new(|task| {
+        tokio::task::spawn_local(task);
+        Ok(())
+    })));
+
+    let runtime = tokio::runtime::Builder::new_current_thread().build()?;
+    let local = tokio::task::LocalSet::new();
+    Ok(local.block_on(&runtime, vm.async_call(["main"], ()))?)
+}
+
+#[test]
+fn spawn_without_spawner() -> Result<()> {
+    let (_, mut vm) = logged(
+        r#"
+        pub fn main() {
+            std::task::spawn(async { 42 })
+        }
+        "#,
+    )?;
+
+    let error = vm.call(["main"], ()).unwrap_err();
+    assert!(matches!(error.into_kind(), VmErrorKind::MissingSpawner));
+    Ok(())
+}
+
+#[test]
+fn send_execute_drops_spawner() -> Result<()> {
+    let (_, mut vm) = logged(
+        r#"
+        pub fn main() {
+            std::task::spawn(async { 42 })
+        }
+        "#,
+    )?;
+
+    vm.set_spawner(Some(Spawner::new(|_| Ok(()))));
+
+    let execution = vm.send_execute(["main"], ())?;
+    let runtime = tokio::runtime::Builder::new_current_thread().build()?;
+    let error = runtime
+        .block_on(execution.async_complete())
+        .into_result()
+        .unwrap_err();
+    assert!(matches!(error.into_kind(), VmErrorKind::MissingSpawner));
+    Ok(())
+}
+
+#[test]
+fn spawn_and_join() -> Result<()> {
+    let (_, mut vm) = logged(
+        r#"
+        async fn work(n) {
+            n * 2
+        }
+
+        pub async fn main() {
+            let tasks = [];
+
+            for n in 0..4 {
+                tasks.push(std::task::spawn(work(n)));
+            }
+
+            let total = 0;
+
+            for task in tasks {
+                total += task.await;
+            }
+
+            total
+        }
+        "#,
+    )?;
+
+    let value = run_main(&mut vm)?;
+    assert_eq!(crate::from_value::<i64>(value)?, 12);
+    Ok(())
+}
+
+#[test]
+fn nested_spawn() -> Result<()> {
+    let (_, mut vm) = logged(
+        r#"
+        pub async fn main() {
+            let outer = std::task::spawn(async {
+                let inner = std::task::spawn(async { 40 });
+                inner.await + 2
+            });
+
+            outer.await
+        }
+        "#,
+    )?;
+
+    let value = run_main(&mut vm)?;
+    assert_eq!(crate::from_value::<i64>(value)?, 42);
+    Ok(())
+}
+
+#[test]
+fn channel_between_tasks() -> Result<()> {
+    let (log, mut vm) = logged(
+        r#"
+        use std::channel;
+
+        pub async fn main() {
+            let (tx, rx) = channel::bounded(2);
+
+            let producer = std::task::spawn(async move {
+                for n in 0..5 {
+                    tx.send(n).await?;
+                }
+
+                Ok(())
+            });
+
+            while let Some(n) = rx.recv().await {
+                record(n);
+            }
+
+            producer.await
+        }
+        "#,
+    )?;
+
+    let value = run_main(&mut vm)?;
+    crate::from_value::<Result<(), Value>>(value)?.unwrap();
+    assert_eq!(*log.lock().unwrap(), [0, 1, 2, 3, 4]);
+    Ok(())
+}
+
+#[test]
+fn send_to_dropped_receiver() -> Result<()> {
+    let (_, mut vm) = logged(
+        r#"
+        use std::channel;
+
+        pub async fn main() {
+            let (tx, rx) = channel::unbounded();
+            drop(rx);
+            tx.send(42).await
+        }
+        "#,
+    )?;
+
+    let value = run_main(&mut vm)?;
+    let result = crate::from_value::<Result<(), i64>>(value)?;
+    assert_eq!(result, Err(42));
+    Ok(())
+}
+
+#[test]
+fn scheduler_runs_spawned_tasks() -> Result<()> {
+    let (log, vm) = logged(
+        r#"
+        use std::channel;
+
+        pub fn main() {
+            let (tx, rx) = channel::bounded(1);
+
+            std::task::spawn(async move {
+                while let Some(n) = rx.recv().await {
+                    record(n);
+                }
+            });
+
+            std::task::spawn(async move {
+                for n in 0..3 {
+                    tx.send(n).await;
+                }
+            });
+        }
+        "#,
+    )?;
+
+    let mut scheduler = Scheduler::new();
+    let handle = scheduler.spawn(vm.owned_execute(["main"], ())?)?;
+
+    scheduler.run_until_stalled();
+    assert!(scheduler.is_empty());
+    handle.try_join().unwrap().into_result()?;
+
+    assert_eq!(*log.lock().unwrap(), [0, 1, 2]);
+    Ok(())
+}
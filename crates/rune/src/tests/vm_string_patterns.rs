@@ -0,0 +1,197 @@
+prelude!();
+
+use VmErrorKind::*;
+
+#[test]
+fn string_find_patterns() {
+    let out: (Option<usize>, Option<usize>, Option<usize>, Option<usize>) = rune! {
+        let s = "hello world";
+        (s.find("o"), s.rfind('o'), s.find(|c| c.is_whitespace()), s.find(['w', 'x']))
+    };
+
+    assert_eq!(out, (Some(4), Some(7), Some(5), Some(6)));
+}
+
+#[test]
+fn string_trim_and_strip() {
+    let out: (String, String, String) = rune! {
+        let s = "--abc--";
+        (s.trim_matches('-'), s.trim_start_matches("--"), s.trim_end_matches(|c| c == '-'))
+    };
+
+    assert_eq!(
+        out,
+        (
+            String::from("abc"),
+            String::from("abc--"),
+            String::from("--abc")
+        )
+    );
+
+    let out: (Option<String>, Option<String>) = rune! {
+        let s = "--abc--";
+        (s.strip_prefix('-'), s.strip_suffix("abc"))
+    };
+
+    assert_eq!(out, (Some(String::from("-abc--")), None));
+}
+
+#[test]
+fn string_iterators() {
+    let out: (Vec<String>, Vec<String>, Vec<String>) = rune! {
+        (
+            "a\r\nb\n\nc".lines().collect::<Vec>(),
+            "  a \t b  ".split_whitespace().collect::<Vec>(),
+            "a,b,c".rsplit(',').collect::<Vec>(),
+        )
+    };
+
+    assert_eq!(out.0, ["a", "b", "", "c"]);
+    assert_eq!(out.1, ["a", "b"]);
+    assert_eq!(out.2, ["c", "b", "a"]);
+
+    let out: (Vec<(usize, char)>, Vec<u8>) = rune! {
+        ("aé".char_indices().collect::<Vec>(), "ab".bytes().collect::<Vec>())
+    };
+
+    assert_eq!(out.0, [(0, 'a'), (1, 'é')]);
+    assert_eq!(out.1, [b'a', b'b']);
+}
+
+#[test]
+fn string_splitn() {
+    let out: Vec<String> = rune! {
+        "a b c d".splitn(3, ' ').collect::<Vec>()
+    };
+
+    assert_eq!(out, ["a", "b", "c d"]);
+}
+
+#[test]
+fn string_ascii_and_padding() {
+    let out: (String, String, String) = rune! {
+        ("ab".repeat(3), "7".pad_start(3, '0'), "7".pad_end(3, '.'))
+    };
+
+    assert_eq!(
+        out,
+        (
+            String::from("ababab"),
+            String::from("007"),
+            String::from("7..")
+        )
+    );
+
+    let out: (String, bool) = rune! {
+        ("Äb".to_ascii_uppercase(), "HeLLo".eq_ignore_ascii_case("hello"))
+    };
+
+    assert_eq!(out, (String::from("ÄB"), true));
+
+    let out: String = rune! {
+        "".repeat(9223372036854775807)
+    };
+
+    assert_eq!(out, "");
+
+    assert_vm_error!(
+        r#"
+        "7".pad_start(9223372036854775807, '€')
+        "#,
+        AllocError { .. } => {}
+    );
+
+    assert_vm_error!(
+        r#"
+        "7".pad_end(9223372036854775807, '€')
+        "#,
+        AllocError { .. } => {}
+    );
+}
+
+#[test]
+fn string_range_index() {
+    let out: (String, String, String) = rune! {
+        let s = "hello world";
+        (s[..5], s[6..], s[2..=3])
+    };
+
+    assert_eq!(
+        out,
+        (
+            String::from("hello"),
+            String::from("world"),
+            String::from("ll")
+        )
+    );
+
+    assert_vm_error!(
+        r#"
+        let s = "é";
+        s[1..]
+        "#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "missing string slice");
+        }
+    );
+}
+
+#[test]
+fn string_patterns_everywhere() {
+    let out: (Vec<String>, Option<(String, String)>, String, bool) = rune! {
+        (
+            "a-b_c".split(['-', '_']).collect::<Vec>(),
+            "key: value".split_once(|c| c == ':'),
+            "a-b_c".replace(['-', '_'], ""),
+            "bananas".ends_with(['s', 'x']),
+        )
+    };
+
+    assert_eq!(out.0, ["a", "b", "c"]);
+    assert_eq!(out.1, Some((String::from("key"), String::from(" value"))));
+    assert_eq!(out.2, "abc");
+    assert!(out.3);
+}
+
+#[test]
+fn string_empty_pattern() {
+    let out: (String, Vec<String>, Vec<String>, String) = rune! {
+        (
+            "abc".replace("", "-"),
+            "abc".rsplit("").collect::<Vec>(),
+            "abc".splitn(3, "").collect::<Vec>(),
+            "abc".trim_matches(""),
+        )
+    };
+
+    assert_eq!(out.0, "-a-b-c-");
+    assert_eq!(out.1, ["", "c", "b", "a", ""]);
+    assert_eq!(out.2, ["", "a", "bc"]);
+    assert_eq!(out.3, "abc");
+}
+
+#[test]
+fn string_pattern_errors() {
+    assert_vm_error!(
+        r#"
+        "abc".find(|c| { panic!("boom") })
+        "#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "boom");
+        }
+    );
+
+    assert_vm_error!(
+        r#"
+        "abc".find(1)
+        "#,
+        Expected { .. } => {}
+    );
+
+    assert_vm_error!(
+        r#"
+        "abc".split(['a', 1])
+        "#,
+        Expected { .. } => {}
+    );
+}